@@ -43,6 +43,16 @@ const NO_STRIDE: usize = 0;
 ///    be equal to a sequence 1, 2, 3, 4 at steps 0, 8, 16, 24. That is, value at step 0 should be
 ///    equal to 1, value at step 8 should be equal to 2 etc.
 ///
+/// Assertions against multiple consecutive columns at the same step can be created via
+/// [Assertion::vector()] function, which expands them into a list of single assertions.
+///
+/// Assertions can also be defined independently of the length of an execution trace:
+/// [Assertion::last_step()] creates a single assertion against the last step of the trace, and
+/// [Assertion::every()] creates a periodic assertion starting at the first step of the trace.
+/// The last step is resolved against the actual trace length when the assertion is applied or
+/// when boundary constraints are built (see [Assertion::resolve()]), and thus, the same AIR can be
+/// used with traces of different lengths.
+///
 /// Note that single and periodic assertions are succinct. That is, a verifier can evaluate them
 /// very efficiently. However, sequence assertions have liner complexity in the number of
 /// asserted values. Though, unless many thousands of values are asserted, practical impact of
@@ -53,6 +63,9 @@ pub struct Assertion<E: FieldElement> {
     pub(super) first_step: usize,
     pub(super) stride: usize,
     pub(super) values: Vec<E>,
+    /// True if this assertion is placed against the last step of an execution trace, and has not
+    /// been resolved against a trace length yet; `first_step` is set to zero for such assertions.
+    pub(super) on_last_step: bool,
 }
 
 impl<E: FieldElement> Assertion<E> {
@@ -68,9 +81,40 @@ impl<E: FieldElement> Assertion<E> {
             first_step: step,
             stride: NO_STRIDE,
             values: vec![value],
+            on_last_step: false,
+        }
+    }
+
+    /// Returns an assertion against the last step of an execution trace.
+    ///
+    /// The returned assertion requires that the value in the specified `column` at the last step
+    /// of the execution trace is equal to the provided `value`, regardless of the length of the
+    /// trace.
+    ///
+    /// The step of the returned assertion is unknown until the assertion is resolved against a
+    /// trace length via [Assertion::resolve()]; this is done automatically when boundary
+    /// constraints of an AIR are built.
+    pub fn last_step(column: usize, value: E) -> Self {
+        Assertion {
+            column,
+            first_step: 0,
+            stride: NO_STRIDE,
+            values: vec![value],
+            on_last_step: true,
         }
     }
 
+    /// Returns an single-value assertion against every `stride`-th cell of a single column,
+    /// starting with the first step of an execution trace.
+    ///
+    /// This is equivalent to [Assertion::periodic()] with `first_step` set to 0.
+    ///
+    /// # Panics
+    /// Panics if `stride` is not a power of two, or is smaller than 2.
+    pub fn every(column: usize, stride: usize, value: E) -> Self {
+        Self::periodic(column, 0, stride, value)
+    }
+
     /// Returns an single-value assertion against multiple cells of a single column.
     ///
     /// The returned assertion requires that values in the specified `column` must be equal to
@@ -88,6 +132,7 @@ impl<E: FieldElement> Assertion<E> {
             first_step,
             stride,
             values: vec![value],
+            on_last_step: false,
         }
     }
 
@@ -119,9 +164,34 @@ impl<E: FieldElement> Assertion<E> {
             first_step,
             stride: if values.len() == 1 { NO_STRIDE } else { stride },
             values,
+            on_last_step: false,
         }
     }
 
+    /// Returns a list of single-value assertions against consecutive columns at the same step.
+    ///
+    /// The returned assertions require that the value in column `first_column + i` at the
+    /// specified `step` is equal to `values[i]` for all `i` in `0..values.len()`. This is useful
+    /// for asserting a value spread over multiple columns (e.g., a hash digest).
+    ///
+    /// The assertions are validated against the width of the execution trace in the same way as
+    /// any other assertion; thus, an AIR whose trace is narrower than
+    /// `first_column + values.len()` columns will fail to build its boundary constraints.
+    ///
+    /// # Panics
+    /// Panics if `values` is empty.
+    pub fn vector(first_column: usize, step: usize, values: Vec<E>) -> Vec<Self> {
+        assert!(
+            !values.is_empty(),
+            "invalid assertion for column {first_column}: number of asserted values must be greater than zero"
+        );
+        values
+            .into_iter()
+            .enumerate()
+            .map(|(i, value)| Self::single(first_column + i, step, value))
+            .collect()
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -133,7 +203,15 @@ impl<E: FieldElement> Assertion<E> {
     /// Returns the first step of the execution trace against which this assertion is placed.
     ///
     /// For single value assertions this is equivalent to the assertion step.
+    ///
+    /// # Panics
+    /// Panics if this assertion was created via [Assertion::last_step()] and has not been
+    /// resolved against a trace length via [Assertion::resolve()].
     pub fn first_step(&self) -> usize {
+        assert!(
+            !self.on_last_step,
+            "step of an assertion against the last step is unknown until the assertion is resolved"
+        );
         self.first_step
     }
 
@@ -151,6 +229,12 @@ impl<E: FieldElement> Assertion<E> {
         &self.values
     }
 
+    /// Returns true if this assertion was created via [Assertion::last_step()] and has not been
+    /// resolved against a trace length yet.
+    pub fn is_last_step(&self) -> bool {
+        self.on_last_step
+    }
+
     /// Returns true if this is a single-value assertion (one value, one step).
     pub fn is_single(&self) -> bool {
         self.stride == NO_STRIDE
@@ -169,13 +253,48 @@ impl<E: FieldElement> Assertion<E> {
     // PUBLIC METHODS
     // --------------------------------------------------------------------------------------------
 
+    /// Returns this assertion with its steps resolved against an execution trace of the
+    /// specified length.
+    ///
+    /// For assertions created via [Assertion::last_step()], the returned assertion is placed
+    /// against step `trace_length - 1`; all other assertions are returned unchanged.
+    ///
+    /// # Panics
+    /// Panics if `trace_length` is zero.
+    pub fn resolve(self, trace_length: usize) -> Self {
+        assert!(trace_length > 0, "trace length must be greater than zero");
+        if self.on_last_step {
+            Self {
+                first_step: trace_length - 1,
+                on_last_step: false,
+                ..self
+            }
+        } else {
+            self
+        }
+    }
+
     /// Checks if this assertion overlaps with the provided assertion.
     ///
-    /// Overlap is defined as asserting a value for the same step in the same column.
+    /// Overlap is defined as asserting a value for the same step in the same column. Two
+    /// assertions against the last step in the same column always overlap; otherwise, since the
+    /// last step of an execution trace is not known until the assertions are resolved, assertions
+    /// created via [Assertion::last_step()] must be resolved via [Assertion::resolve()] before
+    /// they are checked for overlap.
+    ///
+    /// # Panics
+    /// Panics if exactly one of the assertions is an unresolved assertion against the last step.
     pub fn overlaps_with(&self, other: &Assertion<E>) -> bool {
         if self.column != other.column {
             return false;
         }
+        if self.on_last_step || other.on_last_step {
+            assert!(
+                self.on_last_step && other.on_last_step,
+                "assertions against the last step must be resolved before checking for overlap"
+            );
+            return true;
+        }
         if self.first_step == other.first_step {
             return true;
         }
@@ -228,7 +347,7 @@ impl<E: FieldElement> Assertion<E> {
             return Err(AssertionError::TraceLengthNotPowerOfTwo(trace_length));
         }
         if self.is_single() {
-            if self.first_step >= trace_length {
+            if !self.on_last_step && self.first_step >= trace_length {
                 return Err(AssertionError::TraceLengthTooShort(
                     (self.first_step + 1).next_power_of_two(),
                     trace_length,
@@ -260,7 +379,12 @@ impl<E: FieldElement> Assertion<E> {
             panic!("invalid trace length: {err}");
         });
         if self.is_single() {
-            f(self.first_step, self.values[0]);
+            let step = if self.on_last_step {
+                trace_length - 1
+            } else {
+                self.first_step
+            };
+            f(step, self.values[0]);
         } else if self.is_periodic() {
             for i in 0..(trace_length / self.stride) {
                 f(self.first_step + self.stride * i, self.values[0]);
@@ -299,11 +423,14 @@ impl<E: FieldElement> Assertion<E> {
 // =================================================================================================
 
 /// We define ordering of assertions to be first by stride, then by first_step, and finally by
-/// column in ascending order.
+/// column in ascending order. Unresolved assertions against the last step are ordered after all
+/// other single-value assertions.
 impl<E: FieldElement> Ord for Assertion<E> {
     fn cmp(&self, other: &Self) -> Ordering {
         if self.stride == other.stride {
-            if self.first_step == other.first_step {
+            if self.on_last_step != other.on_last_step {
+                self.on_last_step.cmp(&other.on_last_step)
+            } else if self.first_step == other.first_step {
                 self.column.partial_cmp(&other.column).unwrap()
             } else {
                 self.first_step.partial_cmp(&other.first_step).unwrap()
@@ -324,6 +451,7 @@ impl<E: FieldElement> Display for Assertion<E> {
     fn fmt(&self, f: &mut Formatter) -> core::fmt::Result {
         write!(f, "(column={}, ", self.column)?;
         match self.stride {
+            0 if self.on_last_step => write!(f, "step=last, ")?,
             0 => write!(f, "step={}, ", self.first_step)?,
             _ => {
                 let second_step = self.first_step + self.stride;
@@ -3,7 +3,7 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use alloc::vec::Vec;
+use alloc::{string::ToString, vec::Vec};
 
 use math::{fields::f128::BaseElement, FieldElement};
 use rand_utils::{rand_value, rand_vector};
@@ -35,6 +35,60 @@ fn single_assertion() {
     assert_eq!(Err(AssertionError::TraceLengthTooShort(16, 8)), a.validate_trace_length(8));
 }
 
+#[test]
+fn last_step_assertion() {
+    let value = rand_value::<BaseElement>();
+    let a = Assertion::last_step(1, value);
+    assert!(a.is_single());
+    assert!(a.is_last_step());
+    assert_eq!("(column=1, step=last, value=", &a.to_string()[..28]);
+
+    // the assertion is placed against the last step of traces of any length
+    for trace_length in [8, 16, 1024] {
+        assert_eq!(1, a.get_num_steps(trace_length));
+        assert_eq!(Ok(()), a.validate_trace_length(trace_length));
+        a.apply(trace_length, |step, val| {
+            assert_eq!(trace_length - 1, step);
+            assert_eq!(value, val);
+        });
+        assert_eq!(Assertion::single(1, trace_length - 1, value), a.clone().resolve(trace_length));
+    }
+
+    // resolving an assertion which is not placed against the last step does not change it
+    let b = Assertion::single(1, 15, value);
+    assert_eq!(b, b.clone().resolve(16));
+    assert!(a.clone().resolve(16).overlaps_with(&b));
+    assert!(!a.clone().resolve(32).overlaps_with(&b));
+    assert!(!a.clone().resolve(16).is_last_step());
+    assert_eq!(15, a.clone().resolve(16).first_step());
+
+    // assertions against the last step overlap with each other regardless of trace length
+    assert!(a.overlaps_with(&Assertion::last_step(1, value)));
+    assert!(!a.overlaps_with(&Assertion::last_step(2, value)));
+
+    // usize::MAX is not treated as the last step
+    let c = Assertion::single(1, usize::MAX, value);
+    assert!(!c.is_last_step());
+    assert_ne!(a, c);
+}
+
+#[test]
+#[should_panic(
+    expected = "step of an assertion against the last step is unknown until the assertion is resolved"
+)]
+fn last_step_assertion_unresolved_first_step() {
+    Assertion::last_step(1, BaseElement::ONE).first_step();
+}
+
+#[test]
+#[should_panic(
+    expected = "assertions against the last step must be resolved before checking for overlap"
+)]
+fn last_step_assertion_unresolved_overlap() {
+    let a = Assertion::last_step(1, BaseElement::ONE);
+    a.overlaps_with(&Assertion::single(1, 15, BaseElement::ONE));
+}
+
 // PERIODIC ASSERTIONS
 // ================================================================================================
 
@@ -67,6 +121,15 @@ fn periodic_assertion() {
     assert_eq!(Err(AssertionError::TraceLengthTooShort(16, 8)), a.validate_trace_length(8));
 }
 
+#[test]
+fn every_assertion() {
+    let value = rand_value::<BaseElement>();
+    let a = Assertion::every(3, 4, value);
+    assert_eq!(Assertion::periodic(3, 0, 4, value), a);
+    assert_eq!(2, a.get_num_steps(8));
+    assert_eq!(8, a.get_num_steps(32));
+}
+
 #[test]
 #[should_panic(
     expected = "invalid assertion for column 0: stride must be a power of two, but was 3"
@@ -176,6 +239,38 @@ fn sequence_assertion_num_values_not_power_of_two() {
         Assertion::sequence(3, 2, 4, vec![BaseElement::ONE, BaseElement::ZERO, BaseElement::ONE]);
 }
 
+// VECTOR ASSERTIONS
+// ================================================================================================
+#[test]
+fn vector_assertion() {
+    let values = rand_vector::<BaseElement>(4);
+    let assertions = Assertion::vector(2, 7, values.clone());
+    assert_eq!(4, assertions.len());
+
+    for (i, a) in assertions.iter().enumerate() {
+        assert_eq!(Assertion::single(2 + i, 7, values[i]), *a);
+        assert!(a.is_single());
+    }
+
+    // the last assertion is placed against column 5; so, the trace must have at least 6 columns
+    assert!(assertions.iter().all(|a| a.validate_trace_width(6).is_ok()));
+    assert_eq!(
+        Err(AssertionError::TraceWidthTooShort(5, 5)),
+        assertions[3].validate_trace_width(5)
+    );
+
+    // assertions against consecutive columns at the same step do not overlap
+    assert!(!assertions[0].overlaps_with(&assertions[1]));
+}
+
+#[test]
+#[should_panic(
+    expected = "invalid assertion for column 3: number of asserted values must be greater than zero"
+)]
+fn vector_assertion_empty_values() {
+    let _ = Assertion::<BaseElement>::vector(3, 0, Vec::new());
+}
+
 // OVERLAPPING ASSERTIONS
 // ================================================================================================
 
@@ -196,6 +196,7 @@ fn prepare_assertions<E: FieldElement>(
     let mut result = BTreeSet::<Assertion<E>>::new();
 
     for assertion in assertions.into_iter() {
+        let assertion = assertion.resolve(trace_length);
         assertion.validate_trace_width(trace_width).unwrap_or_else(|err| {
             panic!("assertion {assertion} is invalid: {err}");
         });
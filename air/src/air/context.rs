@@ -26,6 +26,7 @@ pub struct AirContext<B: StarkField> {
     pub(super) trace_domain_generator: B,
     pub(super) lde_domain_generator: B,
     pub(super) num_transition_exemptions: usize,
+    pub(super) num_virtual_columns: usize,
 }
 
 impl<B: StarkField> AirContext<B> {
@@ -170,6 +171,7 @@ impl<B: StarkField> AirContext<B> {
             trace_domain_generator: B::get_root_of_unity(trace_length.ilog2()),
             lde_domain_generator: B::get_root_of_unity(lde_domain_size.ilog2()),
             num_transition_exemptions: 1,
+            num_virtual_columns: 0,
         }
     }
 
@@ -181,6 +183,11 @@ impl<B: StarkField> AirContext<B> {
         &self.trace_info
     }
 
+    /// Returns the proof options for an instance of a computation.
+    pub fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+
     /// Returns length of the execution trace for an instance of a computation.
     ///
     /// This is guaranteed to be a power of two greater than or equal to 8.
@@ -259,6 +266,16 @@ impl<B: StarkField> AirContext<B> {
         self.num_transition_exemptions
     }
 
+    /// Returns the number of virtual columns of the main trace segment.
+    ///
+    /// Virtual columns are not a part of the execution trace; instead, their values are computed
+    /// from the columns of the main trace segment via [Air::evaluate_virtual_columns()](
+    /// crate::Air::evaluate_virtual_columns) whenever transition constraints are evaluated. The
+    /// default value is 0.
+    pub fn num_virtual_columns(&self) -> usize {
+        self.num_virtual_columns
+    }
+
     /// Returns the number of columns needed to store the constraint composition polynomial.
     ///
     /// This is the maximum of:
@@ -359,4 +376,14 @@ impl<B: StarkField> AirContext<B> {
         self.num_transition_exemptions = n;
         self
     }
+
+    /// Sets the number of virtual columns of the main trace segment for this context.
+    ///
+    /// Values of virtual columns are computed via [Air::evaluate_virtual_columns()](
+    /// crate::Air::evaluate_virtual_columns), which must be overridden if the number of virtual
+    /// columns is greater than zero.
+    pub fn set_num_virtual_columns(mut self, n: usize) -> Self {
+        self.num_virtual_columns = n;
+        self
+    }
 }
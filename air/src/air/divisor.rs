@@ -23,7 +23,30 @@ use crate::air::Assertion;
 /// numerator: `[(a, 1), (b, 2)]`, exemptions: `[3]`.
 ///
 /// A divisor cannot be instantiated directly, and instead must be created either for an
-/// [Assertion] or for a transition constraint.
+/// [Assertion] via [ConstraintDivisor::from_assertion()] or for a transition constraint via
+/// [ConstraintDivisor::from_transition()]. Once created, the divisor can be evaluated at any
+/// point (not necessarily in the trace domain) via [ConstraintDivisor::evaluate_at()]. These
+/// methods do not depend on a specific [Air](crate::Air), and thus, can be used to compute
+/// divisor values independently of the prover and the verifier. For example:
+///
+/// ```
+/// # use math::{fields::f64::BaseElement, FieldElement, StarkField};
+/// # use winter_air::{Assertion, ConstraintDivisor};
+/// let trace_length = 8_usize;
+/// let g = BaseElement::get_root_of_unity(trace_length.ilog2());
+/// let x = BaseElement::new(5);
+///
+/// // the divisor of transition constraints with one exemption point is (x^8 - 1) / (x - g^7)
+/// let divisor = ConstraintDivisor::<BaseElement>::from_transition(trace_length, 1);
+/// assert_eq!(7, divisor.degree());
+/// assert_eq!((x.exp(8) - BaseElement::ONE) / (x - g.exp(7)), divisor.evaluate_at(x));
+///
+/// // the divisor of an assertion against step 3 is (x - g^3), and it vanishes at g^3
+/// let assertion = Assertion::single(0, 3, BaseElement::ONE);
+/// let divisor = ConstraintDivisor::from_assertion(&assertion, trace_length);
+/// assert_eq!(x - g.exp(3), divisor.evaluate_at(x));
+/// assert_eq!(BaseElement::ZERO, divisor.evaluate_at(g.exp(3)));
+/// ```
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ConstraintDivisor<B: StarkField> {
     pub(super) numerator: Vec<(usize, B)>,
@@ -85,7 +108,7 @@ impl<B: StarkField> ConstraintDivisor<B> {
     ///   where $j$ is the length of interval between asserted steps (e.g. 8).
     ///
     /// # Panics
-    /// Panics of the specified `trace_length` is inconsistent with the specified `assertion`.
+    /// Panics if the specified `trace_length` is inconsistent with the specified `assertion`.
     pub fn from_assertion<E>(assertion: &Assertion<E>, trace_length: usize) -> Self
     where
         E: FieldElement<BaseField = B>,
@@ -123,6 +146,9 @@ impl<B: StarkField> ConstraintDivisor<B> {
     // EVALUATORS
     // --------------------------------------------------------------------------------------------
     /// Evaluates the divisor polynomial at the provided `x` coordinate.
+    ///
+    /// The `x` coordinate can be any element of field `E`. However, if `x` is one of the exemption
+    /// points of this divisor, the result is undefined (since the denominator evaluates to zero).
     pub fn evaluate_at<E: FieldElement<BaseField = B>>(&self, x: E) -> E {
         // compute the numerator value
         let mut numerator = E::ONE;
@@ -176,7 +202,10 @@ fn get_trace_domain_value_at<B: StarkField>(trace_length: usize, step: usize) ->
 
 #[cfg(test)]
 mod tests {
-    use math::{fields::f128::BaseElement, polynom};
+    use math::{
+        fields::{f128::BaseElement, QuadExtension},
+        polynom,
+    };
 
     use super::*;
 
@@ -226,6 +255,37 @@ mod tests {
         assert_eq!(expected, div.evaluate_at(BaseElement::new(4)));
     }
 
+    #[test]
+    fn constraint_divisor_from_transition() {
+        let n = 16_usize;
+        let g = BaseElement::get_root_of_unity(n.trailing_zeros());
+
+        // z(x) = (x^16 - 1) / ((x - g^13) * (x - g^14) * (x - g^15))
+        let divisor = ConstraintDivisor::<BaseElement>::from_transition(n, 3);
+        assert_eq!(13, divisor.degree());
+        assert_eq!(&[(n, BaseElement::ONE)], divisor.numerator());
+        assert_eq!(&[g.exp(13), g.exp(14), g.exp(15)], divisor.exemptions());
+
+        // the divisor can be evaluated at points outside of the trace domain, including points in
+        // an extension field
+        let x = BaseElement::new(7);
+        let expected = (x.exp(n as u128) - BaseElement::ONE)
+            / ((x - g.exp(13)) * (x - g.exp(14)) * (x - g.exp(15)));
+        assert_eq!(expected, divisor.evaluate_at(x));
+
+        let x = QuadExtension::new(BaseElement::new(7), BaseElement::new(3));
+        let g = QuadExtension::from(g);
+        let expected = (x.exp(n as u128) - QuadExtension::ONE)
+            / ((x - g.exp(13)) * (x - g.exp(14)) * (x - g.exp(15)));
+        assert_eq!(expected, divisor.evaluate_at(x));
+
+        // the divisor vanishes on all steps of the trace except for the exempted ones
+        let g = BaseElement::get_root_of_unity(n.trailing_zeros());
+        for i in 0..n - 3 {
+            assert_eq!(BaseElement::ZERO, divisor.evaluate_at(g.exp(i as u128)));
+        }
+    }
+
     #[test]
     fn constraint_divisor_equivalence() {
         let n = 8_usize;
@@ -0,0 +1,247 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::{string::String, vec::Vec};
+
+use crypto::{RandomCoin, RandomCoinError};
+use math::{ExtensionOf, FieldElement, StarkField, ToElements};
+
+use super::{
+    Air, AirContext, Assertion, AuxRandElements, EvaluationFrame,
+    LagrangeConstraintsCompositionCoefficients, LagrangeKernelConstraints,
+    LagrangeKernelRandElements, TraceInfo, TransitionConstraints,
+};
+use crate::ProofOptions;
+
+// INPUTS WITH ASSERTIONS
+// ================================================================================================
+
+/// Public inputs of an [AirWithAssertions] consisting of public inputs of the underlying AIR
+/// and a set of additional assertions against the main trace segment.
+///
+/// The additional assertions are serialized into field elements together with the public inputs,
+/// and thus, they are bound to the proof via the public coin.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputsWithAssertions<P, B: StarkField> {
+    /// Public inputs of the underlying AIR.
+    pub inputs: P,
+    /// Assertions against the main trace segment in addition to the ones defined by the
+    /// underlying AIR.
+    pub assertions: Vec<Assertion<B>>,
+}
+
+impl<P, B: StarkField> InputsWithAssertions<P, B> {
+    /// Returns new public inputs consisting of the specified `inputs` of the underlying AIR and
+    /// the specified additional `assertions`.
+    pub fn new(inputs: P, assertions: Vec<Assertion<B>>) -> Self {
+        Self { inputs, assertions }
+    }
+}
+
+impl<P: ToElements<B>, B: StarkField> ToElements<B> for InputsWithAssertions<P, B> {
+    fn to_elements(&self) -> Vec<B> {
+        let mut result = self.inputs.to_elements();
+        result.push(B::from(self.assertions.len() as u32));
+        for assertion in self.assertions.iter() {
+            result.push(B::from(assertion.column as u32));
+            result.push(B::from(assertion.on_last_step as u32));
+            result.push(B::from(assertion.first_step as u32));
+            result.push(B::from(assertion.stride as u32));
+            result.push(B::from(assertion.values.len() as u32));
+            result.extend_from_slice(&assertion.values);
+        }
+        result
+    }
+}
+
+// AIR WITH ASSERTIONS
+// ================================================================================================
+
+/// An AIR which extends the underlying AIR `A` with a set of additional assertions against the
+/// main trace segment.
+///
+/// The additional assertions are supplied as a part of public inputs (see
+/// [InputsWithAssertions]), and are enforced in the same way as the assertions returned from
+/// [Air::get_assertions()] of the underlying AIR. This allows a single AIR to be used with
+/// boundary conditions which differ between deployments (e.g., a deployment-specific public
+/// output) without changing the AIR itself. All other aspects of the computation are delegated to
+/// the underlying AIR, except for [Air::get_boundary_constraints()], which is not delegated
+/// because it must account for the additional assertions.
+///
+/// To generate a proof against the additional assertions, the prover must use this AIR (i.e.,
+/// `AirWithAssertions<A>`) and supply the assertions via its public inputs; such a proof can then
+/// be verified via `verify_with_assertions()` function of the verifier using the same assertions.
+pub struct AirWithAssertions<A: Air> {
+    air: A,
+    context: AirContext<A::BaseField>,
+    assertions: Vec<Assertion<A::BaseField>>,
+}
+
+impl<A: Air> AirWithAssertions<A> {
+    /// Returns the underlying AIR.
+    pub fn inner(&self) -> &A {
+        &self.air
+    }
+
+    /// Returns the assertions enforced in addition to the ones defined by the underlying AIR.
+    pub fn extra_assertions(&self) -> &[Assertion<A::BaseField>] {
+        &self.assertions
+    }
+
+    /// Checks that the additional assertions are valid in the context of the computation.
+    ///
+    /// # Errors
+    /// Returns an error if any of the additional assertions is placed against a column or a step
+    /// which does not exist in the execution trace, or if it overlaps with any other assertion
+    /// against the main trace segment (including the assertions of the underlying AIR).
+    pub fn validate_assertions(&self) -> Result<(), String> {
+        let trace_info = self.context.trace_info();
+        let mut assertions = self
+            .air
+            .get_assertions()
+            .into_iter()
+            .map(|assertion| assertion.resolve(trace_info.length()))
+            .collect::<Vec<_>>();
+        for assertion in self.assertions.iter() {
+            let assertion = assertion.clone().resolve(trace_info.length());
+            assertion
+                .validate_trace_width(trace_info.main_trace_width())
+                .and_then(|_| assertion.validate_trace_length(trace_info.length()))
+                .map_err(|err| format!("assertion {assertion} is invalid: {err}"))?;
+            if let Some(other) = assertions
+                .iter()
+                .find(|other| other.column == assertion.column && other.overlaps_with(&assertion))
+            {
+                return Err(format!("assertion {assertion} overlaps with assertion {other}"));
+            }
+            assertions.push(assertion);
+        }
+        Ok(())
+    }
+}
+
+impl<A: Air> Air for AirWithAssertions<A> {
+    type BaseField = A::BaseField;
+    type PublicInputs = InputsWithAssertions<A::PublicInputs, A::BaseField>;
+    type GkrProof = A::GkrProof;
+    type GkrVerifier = A::GkrVerifier;
+
+    // REQUIRED METHODS
+    // --------------------------------------------------------------------------------------------
+
+    fn new(trace_info: TraceInfo, pub_inputs: Self::PublicInputs, options: ProofOptions) -> Self {
+        let InputsWithAssertions { inputs, assertions } = pub_inputs;
+        let air = A::new(trace_info, inputs, options);
+
+        let mut context = air.context().clone();
+        context.num_main_assertions += assertions.len();
+
+        Self { air, context, assertions }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        self.air.evaluate_transition(frame, periodic_values, result)
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        let mut result = self.air.get_assertions();
+        result.extend_from_slice(&self.assertions);
+        result
+    }
+
+    // AUXILIARY TRACE CONSTRAINTS
+    // --------------------------------------------------------------------------------------------
+
+    fn evaluate_aux_transition<F, E>(
+        &self,
+        main_frame: &EvaluationFrame<F>,
+        aux_frame: &EvaluationFrame<E>,
+        periodic_values: &[F],
+        aux_rand_elements: &AuxRandElements<E>,
+        result: &mut [E],
+    ) where
+        F: FieldElement<BaseField = Self::BaseField>,
+        E: FieldElement<BaseField = Self::BaseField> + ExtensionOf<F>,
+    {
+        self.air.evaluate_aux_transition(
+            main_frame,
+            aux_frame,
+            periodic_values,
+            aux_rand_elements,
+            result,
+        )
+    }
+
+    fn get_aux_assertions<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        aux_rand_elements: &AuxRandElements<E>,
+    ) -> Vec<Assertion<E>> {
+        self.air.get_aux_assertions(aux_rand_elements)
+    }
+
+    fn get_gkr_proof_verifier<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+    ) -> Self::GkrVerifier {
+        self.air.get_gkr_proof_verifier::<E>()
+    }
+
+    // PROVIDED METHODS
+    // --------------------------------------------------------------------------------------------
+
+    fn get_aux_rand_elements<E, R>(&self, public_coin: &mut R) -> Result<Vec<E>, RandomCoinError>
+    where
+        E: FieldElement<BaseField = Self::BaseField>,
+        R: RandomCoin<BaseField = Self::BaseField>,
+    {
+        self.air.get_aux_rand_elements(public_coin)
+    }
+
+    fn get_lagrange_kernel_constraints<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        lagrange_composition_coefficients: LagrangeConstraintsCompositionCoefficients<E>,
+        lagrange_kernel_rand_elements: &LagrangeKernelRandElements<E>,
+    ) -> Option<LagrangeKernelConstraints<E>> {
+        self.air.get_lagrange_kernel_constraints(
+            lagrange_composition_coefficients,
+            lagrange_kernel_rand_elements,
+        )
+    }
+
+    fn get_periodic_column_values(&self) -> Vec<Vec<Self::BaseField>> {
+        self.air.get_periodic_column_values()
+    }
+
+    fn constraint_names(&self) -> Vec<&'static str> {
+        self.air.constraint_names()
+    }
+
+    fn evaluate_virtual_columns<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        row: &[E],
+        result: &mut [E],
+    ) {
+        self.air.evaluate_virtual_columns(row, result)
+    }
+
+    fn get_periodic_column_polys(&self) -> Vec<Vec<Self::BaseField>> {
+        self.air.get_periodic_column_polys()
+    }
+
+    fn get_transition_constraints<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        composition_coefficients: &[E],
+    ) -> TransitionConstraints<E> {
+        self.air.get_transition_constraints(composition_coefficients)
+    }
+}
@@ -26,7 +26,9 @@ mod boundary;
 pub use boundary::{BoundaryConstraint, BoundaryConstraintGroup, BoundaryConstraints};
 
 mod transition;
-pub use transition::{EvaluationFrame, TransitionConstraintDegree, TransitionConstraints};
+pub use transition::{
+    EvaluationFrame, Selector, TransitionConstraintDegree, TransitionConstraints,
+};
 
 mod lagrange;
 pub use lagrange::{
@@ -42,6 +44,9 @@ pub use coefficients::{
 
 mod divisor;
 pub use divisor::ConstraintDivisor;
+
+mod extra_assertions;
+pub use extra_assertions::{AirWithAssertions, InputsWithAssertions};
 use utils::{Deserializable, Serializable};
 
 #[cfg(test)]
@@ -100,6 +105,10 @@ const MIN_CYCLE_LENGTH: usize = 2;
 ///   should evaluation to all zeros.
 /// * For any invalid transition, at least one constraint must evaluate to a non-zero value.
 ///
+/// If transition constraints depend on public inputs, the public inputs passed to [Air::new()]
+/// can be stored as a field of the struct implementing this trait and exposed via
+/// [Air::public_inputs()] method. They can then be accessed during constraint evaluation.
+///
 /// **Note:** since transition constraints define algebraic relations, they should be
 /// described using only algebraic operations: additions, subtractions, and multiplications
 /// (divisions can be emulated using inverse of multiplication).
@@ -147,6 +156,11 @@ const MIN_CYCLE_LENGTH: usize = 2;
 ///   at step 0 must be equal to 1, at step 8 must be equal to 2, at step 16 must be equal to 3
 ///   etc.*
 ///
+/// Assertions against the last step of an execution trace can be defined via
+/// [Assertion::last_step()] without referring to the trace length; such assertions are resolved
+/// against the actual length of the trace when boundary constraints are built. Together with
+/// [Assertion::every()], this allows defining assertions which do not depend on the trace length.
+///
 /// ### Periodic values
 /// Sometimes, it may be useful to define a column in an execution trace which contains a set of
 /// repeating values. For example, let's say we have a column which contains value 1 on every
@@ -158,6 +172,26 @@ const MIN_CYCLE_LENGTH: usize = 2;
 /// step of the computation will be supplied to the [Air::evaluate_transition()] method via the
 /// `periodic_values` parameter.
 ///
+/// ### Virtual columns
+/// Some constraints are easier to express over values derived from several trace columns (e.g.,
+/// a sum of two columns) than over the columns themselves. Instead of adding such values to the
+/// execution trace, they can be described as *virtual columns* of the main trace segment. Values
+/// of virtual columns are not committed to by the prover; instead, they are computed from the
+/// current and the next rows of the main trace segment every time transition constraints are
+/// evaluated (by the prover over the constraint evaluation domain, and by the verifier at the
+/// out-of-domain point).
+///
+/// To define virtual columns for your computation, set their number via
+/// [AirContext::set_num_virtual_columns()] and override [Air::evaluate_virtual_columns()] method.
+/// Values of virtual columns will be available in [Air::evaluate_transition()] and
+/// [Air::evaluate_aux_transition()] methods via [EvaluationFrame::current_virtual()] and
+/// [EvaluationFrame::next_virtual()] methods of the main trace frame.
+///
+/// Note that the degree of a virtual column is the degree of the expression which defines it.
+/// Thus, a virtual column which is a linear combination of trace columns has degree 1, and
+/// degrees of transition constraints which reference virtual columns should be specified
+/// accordingly.
+///
 /// ### Randomized AIR
 /// Randomized AIR is a powerful extension of AIR which enables, among other things, multiset and
 /// permutation checks similar to the ones available in PLONKish systems. These, in turn, allow
@@ -184,14 +218,49 @@ const MIN_CYCLE_LENGTH: usize = 2;
 /// * Override [Air::get_aux_assertions()] method. This method is similar to the
 ///   [Air::get_assertions()] method, but it should return assertions against columns of the
 ///   auxiliary trace segment.
+///
+/// ### Mixing base field and extension field columns
+/// Columns of the main trace segment always contain elements of the base field, while columns of
+/// the auxiliary trace segment contain elements of the field in which the protocol is executed
+/// (i.e., an extension of the base field when [ProofOptions] specify a field extension). Thus,
+/// for computations which require only a few extension field columns, these columns can be placed
+/// into the auxiliary trace segment while all other columns remain in the (cheaper) main trace
+/// segment.
+///
+/// The auxiliary trace segment does not need to depend on randomness: if the number of random
+/// elements for the auxiliary segment specified in [`crate::TraceInfo`] is zero, the auxiliary
+/// segment is built from the main trace segment only, and its columns act as regular witness
+/// columns defined over the extension field.
+///
+/// When constraints mix columns of both segments, the following rules apply:
+/// * [Air::evaluate_transition()] receives only the main trace frame; the frame is defined over
+///   the base field during constraint evaluation by the prover, and over the extension field
+///   during out-of-domain evaluation.
+/// * [Air::evaluate_aux_transition()] receives both frames: the main trace frame is defined over
+///   field `F` and the auxiliary trace frame is defined over field `E`, where `E` is always an
+///   extension of `F`. Values from the main frame can be lifted into `E` via `E::from()`, and
+///   values of `E` can be multiplied by values of `F` directly via [ExtensionOf::mul_base()].
+///   Thus, auxiliary transition constraints can reference columns of both segments.
+/// * Assertions returned from [Air::get_assertions()] are defined over the base field and can
+///   be placed only against the main trace segment, while assertions returned from
+///   [Air::get_aux_assertions()] are defined over field `E` and can be placed only against the
+///   auxiliary trace segment.
 pub trait Air: Send + Sync {
     /// Base field for the computation described by this AIR. STARK protocol for this computation
     /// may be executed in the base field, or in an extension of the base fields as specified
     /// by [ProofOptions] struct.
-    type BaseField: StarkField + ExtensibleField<2> + ExtensibleField<3>;
+    type BaseField: StarkField + ExtensibleField<2> + ExtensibleField<3> + ExtensibleField<6>;
 
     /// A type defining shape of public inputs for the computation described by this protocol.
     /// This could be any type as long as it can be serialized into a sequence of field elements.
+    ///
+    /// Both the prover and the verifier absorb public inputs into the public coin as field
+    /// elements: the coin is seeded with a hash (computed via [ElementHasher::hash_elements()]
+    /// of the coin's hash function) of the proof context elements followed by the elements
+    /// returned from [ToElements::to_elements()]. Thus, with an algebraic hash function, the
+    /// initial state of the transcript can be computed inside a recursive verifier.
+    ///
+    /// [ElementHasher::hash_elements()]: crypto::ElementHasher::hash_elements
     type PublicInputs: ToElements<Self::BaseField> + Send;
 
     /// An GKR proof object. If not needed, set to `()`.
@@ -236,6 +305,20 @@ pub trait Air: Send + Sync {
     /// Returns a set of assertions against a concrete execution trace of this computation.
     fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>>;
 
+    // PUBLIC INPUTS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns public inputs for this instance of the computation, or `None` if this AIR does not
+    /// retain its public inputs.
+    ///
+    /// This provides typed access to public inputs during constraint evaluation (e.g., in
+    /// [Air::evaluate_transition()]). AIRs which retain the public inputs passed to [Air::new()]
+    /// (or to [Air::with_context()]) should override this method to return them. The default
+    /// implementation returns `None`.
+    fn public_inputs(&self) -> Option<&Self::PublicInputs> {
+        None
+    }
+
     // AUXILIARY TRACE CONSTRAINTS
     // --------------------------------------------------------------------------------------------
 
@@ -318,6 +401,26 @@ pub trait Air: Send + Sync {
     // PROVIDED METHODS
     // --------------------------------------------------------------------------------------------
 
+    /// Returns new instance of AIR for this computation instantiated from a precomputed context
+    /// and the provided public inputs.
+    ///
+    /// This can be used to avoid rebuilding the context when many instances of the same
+    /// computation are verified (see `verify_with_context()` function of the verifier). A context
+    /// can be reused across instances with different public inputs as long as everything it
+    /// describes (i.e., trace info, proof options, transition constraint degrees, number of
+    /// assertions, number of transition exemptions, and number of virtual columns) does not
+    /// depend on the values of public inputs.
+    ///
+    /// The default implementation ignores the context (except for its trace info and proof
+    /// options) and builds the AIR via [Air::new()]. Implementations for which building the
+    /// context is expensive should override this method to reuse the provided context.
+    fn with_context(context: &AirContext<Self::BaseField>, pub_inputs: Self::PublicInputs) -> Self
+    where
+        Self: Sized,
+    {
+        Self::new(context.trace_info().clone(), pub_inputs, context.options().clone())
+    }
+
     /// Returns a vector of field elements required for construction of the auxiliary trace segment
     /// (except the Lagrange kernel column, if any).
     ///
@@ -364,6 +467,41 @@ pub trait Air: Send + Sync {
         Vec::new()
     }
 
+    /// Returns human-readable names of transition constraints of this computation.
+    ///
+    /// Names are used only for diagnostics (e.g., when an execution trace is validated against
+    /// this AIR in debug mode), and are not a part of the protocol. The names are expected to be
+    /// in the order of the constraints: names of the main transition constraints come first,
+    /// followed by names of the auxiliary transition constraints.
+    ///
+    /// The default implementation of this method returns an empty vector. In this case (as well
+    /// as for constraints beyond the end of the returned vector), constraints are identified by
+    /// their indices only.
+    fn constraint_names(&self) -> Vec<&'static str> {
+        Vec::new()
+    }
+
+    /// Evaluates virtual columns of the main trace segment at the specified `row`.
+    ///
+    /// The values should be written into the `result` slice in the order of the virtual columns;
+    /// the length of the `result` slice will be equal to the number of virtual columns specified
+    /// via [AirContext::set_num_virtual_columns()]. The `row` slice contains values of all
+    /// columns of the main trace segment at a single step of the computation.
+    ///
+    /// The default implementation of this method does nothing. It must be overridden for AIRs
+    /// which define virtual columns.
+    ///
+    /// We define type `E` separately from `Self::BaseField` to allow evaluation of virtual
+    /// columns over the out-of-domain evaluation frame, which may be defined over an extension
+    /// field (when extension fields are used).
+    #[allow(unused_variables)]
+    fn evaluate_virtual_columns<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        row: &[E],
+        result: &mut [E],
+    ) {
+    }
+
     /// Returns polynomial for all periodic columns.
     ///
     /// These polynomials are interpolated from the values returned from the
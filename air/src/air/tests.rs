@@ -6,11 +6,13 @@
 use alloc::{collections::BTreeMap, vec::Vec};
 
 use crypto::{hashers::Blake3_256, DefaultRandomCoin, RandomCoin};
-use math::{fields::f64::BaseElement, get_power_series, polynom, FieldElement, StarkField};
+use math::{
+    fields::f64::BaseElement, get_power_series, polynom, FieldElement, StarkField, ToElements,
+};
 
 use super::{
-    Air, AirContext, Assertion, EvaluationFrame, ProofOptions, TraceInfo,
-    TransitionConstraintDegree,
+    Air, AirContext, AirWithAssertions, Assertion, EvaluationFrame, InputsWithAssertions,
+    ProofOptions, Selector, TraceInfo, TransitionConstraintDegree,
 };
 use crate::FieldExtension;
 
@@ -57,6 +59,61 @@ fn get_periodic_column_polys_num_values_not_power_of_two() {
 
 // TODO
 
+#[test]
+fn selector_select() {
+    let selector = Selector::new(1);
+    let a = BaseElement::new(3);
+    let b = BaseElement::new(5);
+
+    let frame = EvaluationFrame::from_rows(
+        vec![BaseElement::new(7), BaseElement::ONE],
+        vec![BaseElement::ZERO, BaseElement::ZERO],
+    );
+    assert_eq!(BaseElement::ONE, selector.value(&frame));
+    assert_eq!(a, selector.select(&frame, a, b));
+    assert_eq!(BaseElement::ZERO, selector.enforce_binary(&frame));
+
+    let frame = EvaluationFrame::from_rows(
+        vec![BaseElement::new(7), BaseElement::ZERO],
+        vec![BaseElement::ZERO, BaseElement::ZERO],
+    );
+    assert_eq!(b, selector.select(&frame, a, b));
+    assert_eq!(BaseElement::ZERO, selector.enforce_binary(&frame));
+
+    let frame = EvaluationFrame::from_rows(
+        vec![BaseElement::new(7), BaseElement::new(2)],
+        vec![BaseElement::ZERO, BaseElement::ZERO],
+    );
+    assert_eq!(BaseElement::new(2), selector.enforce_binary(&frame));
+}
+
+#[test]
+fn selector_degree() {
+    assert_eq!(TransitionConstraintDegree::new(2), Selector::binary_degree());
+
+    let a = TransitionConstraintDegree::new(1);
+    let b = TransitionConstraintDegree::new(2);
+    assert_eq!(TransitionConstraintDegree::new(3), Selector::select_degree(&a, &b));
+    assert_eq!(TransitionConstraintDegree::new(3), Selector::select_degree(&b, &a));
+
+    // a periodic column contributes less than a trace column
+    let a = TransitionConstraintDegree::with_cycles(1, vec![32]);
+    let b = TransitionConstraintDegree::new(2);
+    assert_eq!(TransitionConstraintDegree::new(3), Selector::select_degree(&a, &b));
+
+    let a = TransitionConstraintDegree::with_cycles(2, vec![8]);
+    let b = TransitionConstraintDegree::new(2);
+    let expected = TransitionConstraintDegree::with_cycles(3, vec![8]);
+    assert_eq!(expected, Selector::select_degree(&a, &b));
+
+    // the resulting degree should match the degree of the selected constraint
+    for trace_length in [64, 128, 1024] {
+        let expected = a.get_evaluation_degree(trace_length) + trace_length - 1;
+        let degree = Selector::select_degree(&a, &b).get_evaluation_degree(trace_length);
+        assert_eq!(expected, degree);
+    }
+}
+
 // BOUNDARY CONSTRAINTS
 // ================================================================================================
 
@@ -188,6 +245,126 @@ fn get_boundary_constraints() {
     assert_eq!(expected_cc[&5], constraint.cc().clone());
 }
 
+// PUBLIC INPUTS
+// ================================================================================================
+
+#[test]
+fn public_inputs_in_air() {
+    let pub_inputs = StepInputs {
+        start: BaseElement::new(3),
+        step: BaseElement::new(5),
+    };
+    let air = StepAir::new(TraceInfo::new(1, 8), pub_inputs.clone(), build_options());
+    assert_eq!(Some(&pub_inputs), air.public_inputs());
+
+    // public inputs should be available during transition constraint evaluation
+    let mut result = [BaseElement::ZERO];
+    let frame = EvaluationFrame::from_rows(vec![BaseElement::new(3)], vec![BaseElement::new(8)]);
+    air.evaluate_transition(&frame, &[], &mut result);
+    assert_eq!(BaseElement::ZERO, result[0]);
+
+    let frame = EvaluationFrame::from_rows(vec![BaseElement::new(3)], vec![BaseElement::new(9)]);
+    air.evaluate_transition(&frame, &[], &mut result);
+    assert_eq!(BaseElement::ONE, result[0]);
+
+    // an AIR built from a shared context should expose its own public inputs
+    let other_inputs = StepInputs {
+        start: BaseElement::new(4),
+        step: BaseElement::new(6),
+    };
+    let other_air = StepAir::with_context(air.context(), other_inputs.clone());
+    assert_eq!(Some(&other_inputs), other_air.public_inputs());
+    assert!(air.context() == other_air.context());
+}
+
+#[test]
+fn public_inputs_not_in_air() {
+    let air = MockAir::with_periodic_columns(Vec::new(), 16);
+    assert_eq!(None, air.public_inputs());
+}
+
+// EXTRA ASSERTIONS
+// ================================================================================================
+
+#[test]
+fn air_with_assertions() {
+    let pub_inputs = StepInputs {
+        start: BaseElement::new(3),
+        step: BaseElement::new(5),
+    };
+    let extra = vec![Assertion::single(0, 7, BaseElement::new(38))];
+    let inputs = InputsWithAssertions::new(pub_inputs.clone(), extra.clone());
+
+    // additional assertions should be bound to the public inputs
+    let mut expected_elements = pub_inputs.to_elements();
+    expected_elements.extend_from_slice(&[1, 0, 0, 7, 0, 1, 38].map(BaseElement::new));
+    assert_eq!(expected_elements, inputs.to_elements());
+
+    let air = AirWithAssertions::<StepAir>::new(TraceInfo::new(1, 8), inputs, build_options());
+    assert_eq!(2, air.context().num_assertions());
+    assert_eq!(1, air.inner().context().num_assertions());
+    assert_eq!(&extra[..], air.extra_assertions());
+    assert_eq!(
+        vec![Assertion::single(0, 0, BaseElement::new(3)), extra[0].clone()],
+        air.get_assertions()
+    );
+    assert_eq!(Ok(()), air.validate_assertions());
+
+    // transition constraints should still have access to public inputs of the underlying AIR
+    let mut result = [BaseElement::ZERO];
+    let frame = EvaluationFrame::from_rows(vec![BaseElement::new(3)], vec![BaseElement::new(8)]);
+    air.evaluate_transition(&frame, &[], &mut result);
+    assert_eq!(BaseElement::ZERO, result[0]);
+
+    // boundary constraints should include the additional assertions
+    let coefficients = vec![BaseElement::ONE; air.context().num_assertions()];
+    let constraints = air.get_boundary_constraints::<BaseElement>(None, &coefficients);
+    let num_constraints: usize = constraints
+        .main_constraints()
+        .iter()
+        .map(|group| group.constraints().len())
+        .sum();
+    assert_eq!(2, num_constraints);
+}
+
+#[test]
+fn air_with_assertions_invalid() {
+    let pub_inputs = StepInputs {
+        start: BaseElement::new(3),
+        step: BaseElement::new(5),
+    };
+    let build_air = |assertions: Vec<Assertion<BaseElement>>| {
+        let inputs = InputsWithAssertions::new(pub_inputs.clone(), assertions);
+        AirWithAssertions::<StepAir>::new(TraceInfo::new(1, 8), inputs, build_options())
+    };
+
+    // column out of bounds
+    let air = build_air(vec![Assertion::single(1, 7, BaseElement::ONE)]);
+    assert!(air.validate_assertions().is_err());
+
+    // step out of bounds
+    let air = build_air(vec![Assertion::single(0, 8, BaseElement::ONE)]);
+    assert!(air.validate_assertions().is_err());
+
+    // overlap with an assertion of the underlying AIR
+    let air = build_air(vec![Assertion::periodic(0, 0, 4, BaseElement::ONE)]);
+    assert!(air.validate_assertions().is_err());
+
+    // overlap between assertions against the last step and the same step given explicitly
+    let air = build_air(vec![
+        Assertion::last_step(0, BaseElement::ONE),
+        Assertion::single(0, 7, BaseElement::ONE),
+    ]);
+    assert!(air.validate_assertions().is_err());
+
+    // overlap between additional assertions
+    let air = build_air(vec![
+        Assertion::single(0, 5, BaseElement::ONE),
+        Assertion::periodic(0, 1, 2, BaseElement::ONE),
+    ]);
+    assert!(air.validate_assertions().is_err());
+}
+
 // MOCK AIR
 // ================================================================================================
 
@@ -259,9 +436,69 @@ impl Air for MockAir {
     }
 }
 
+// STEP AIR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+struct StepInputs {
+    start: BaseElement,
+    step: BaseElement,
+}
+
+impl ToElements<BaseElement> for StepInputs {
+    fn to_elements(&self) -> Vec<BaseElement> {
+        vec![self.start, self.step]
+    }
+}
+
+/// An AIR which exposes its public inputs via [Air::public_inputs()].
+struct StepAir {
+    context: AirContext<BaseElement>,
+    pub_inputs: StepInputs,
+}
+
+impl Air for StepAir {
+    type BaseField = BaseElement;
+    type PublicInputs = StepInputs;
+    type GkrProof = ();
+    type GkrVerifier = ();
+
+    fn new(trace_info: TraceInfo, pub_inputs: StepInputs, options: ProofOptions) -> Self {
+        let degrees = vec![TransitionConstraintDegree::new(1)];
+        let context = AirContext::new(trace_info, degrees, 1, options);
+        StepAir { context, pub_inputs }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+
+    fn public_inputs(&self) -> Option<&StepInputs> {
+        Some(&self.pub_inputs)
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        vec![Assertion::single(0, 0, self.pub_inputs.start)]
+    }
+
+    fn evaluate_transition<E: FieldElement + From<Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let step = E::from(self.pub_inputs.step);
+        result[0] = frame.next()[0] - frame.current()[0] - step;
+    }
+}
+
 // UTILITY FUNCTIONS
 // ================================================================================================
 
+pub fn build_options() -> ProofOptions {
+    ProofOptions::new(32, 8, 0, FieldExtension::None, 4, 31)
+}
+
 pub fn build_context<B: StarkField>(
     trace_length: usize,
     trace_width: usize,
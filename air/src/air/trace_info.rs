@@ -8,18 +8,38 @@ use alloc::{string::ToString, vec::Vec};
 use math::{StarkField, ToElements};
 use utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 
+use crate::proof::elements::{ElementReader, ElementWriter};
+
 // CONSTANTS
 // ================================================================================================
 
+/// Flag set in the serialized trace length byte if the trace info declares constant columns.
+///
+/// Trace lengths are serialized as powers of two, and thus, the most significant bit of the trace
+/// length byte is never used otherwise. Trace infos without constant columns are serialized in
+/// the same way as before constant columns were introduced.
+const CONSTANT_COLUMNS_FLAG: u8 = 1 << 7;
+
 // TRACE INFO
 // ================================================================================================
 /// Information about a specific execution trace.
 ///
 /// Trace info consists of the number of columns for all trace segments, trace length, the number of
 /// random elements needed to generate the auxiliary segment (excluding the Lagrange kernel column),
-/// and optional custom metadata. Currently, a trace can consist of at most two segments: the main
-/// segment and one auxiliary segment. Metadata is just a vector of bytes and can store any values
-/// up to 64KB in size.
+/// optional custom metadata, and an optional list of constant columns of the main segment.
+/// Currently, a trace can consist of at most two segments: the main segment and one auxiliary
+/// segment. Metadata is just a vector of bytes and can store any values up to 64KB in size.
+///
+/// A constant column is a column of the main trace segment which contains the same value in every
+/// row. Values of constant columns are included in the trace info (and thus, are known to the
+/// verifier in advance). This means that the prover does not need to commit to such columns, and
+/// the verifier can reconstruct their values at any queried position on its own. For traces with
+/// many padding or constant columns, this reduces the cost of building the trace commitment as
+/// well as the size of the proof. Constant columns can be declared via
+/// [TraceInfo::with_constant_columns()].
+///
+/// Only columns which are constant in every row are supported; columns which are constant except
+/// for a small number of rows must be committed to in full.
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct TraceInfo {
     main_segment_width: usize,
@@ -27,13 +47,15 @@ pub struct TraceInfo {
     num_aux_segment_rands: usize,
     trace_length: usize,
     trace_meta: Vec<u8>,
+    constant_columns: Vec<(usize, Vec<u8>)>,
 }
 
 impl TraceInfo {
     /// Smallest allowed execution trace length; currently set at 8.
     pub const MIN_TRACE_LENGTH: usize = 8;
-    /// Maximum number of columns in an execution trace (across all segments); currently set at 255.
-    pub const MAX_TRACE_WIDTH: usize = 255;
+    /// Maximum number of columns in an execution trace (across all segments); currently set at
+    /// 65536.
+    pub const MAX_TRACE_WIDTH: usize = 1 << 16;
     /// Maximum number of bytes in trace metadata; currently set at 65535.
     pub const MAX_META_LENGTH: usize = 65535;
     /// Maximum number of random elements in the auxiliary trace segment; currently set to 255.
@@ -48,7 +70,7 @@ impl TraceInfo {
     ///
     /// # Panics
     /// Panics if:
-    /// * Trace width is zero or greater than 255.
+    /// * Trace width is zero or greater than 65536.
     /// * Trace length is smaller than 8 or is not a power of two.
     pub fn new(width: usize, length: usize) -> Self {
         Self::with_meta(width, length, vec![])
@@ -60,7 +82,7 @@ impl TraceInfo {
     ///
     /// # Panics
     /// Panics if:
-    /// * Trace width is zero or greater than 255.
+    /// * Trace width is zero or greater than 65536.
     /// * Trace length is smaller than 8 or is not a power of two.
     /// * Length of `meta` is greater than 65535;
     pub fn with_meta(width: usize, length: usize, meta: Vec<u8>) -> Self {
@@ -71,16 +93,15 @@ impl TraceInfo {
     /// Creates a new [TraceInfo] with main and auxiliary segments.
     ///
     /// Note: `num_aux_segment_rands` refers to the random elements needed to generate all auxiliary
-    /// columns other than the Lagrange kernel one.
+    /// columns other than the Lagrange kernel one. This can be zero for auxiliary segments which do
+    /// not depend on randomness (e.g., segments which consist of extension field witness columns or
+    /// only of the Lagrange kernel column).
     ///
     /// # Panics
     /// Panics if:
     /// * The width of the first trace segment is zero.
-    /// * Total width of all trace segments is greater than 255.
+    /// * Total width of all trace segments is greater than 65536.
     /// * Trace length is smaller than 8 or is not a power of two.
-    /// * A zero entry in auxiliary segment width array is followed by a non-zero entry.
-    /// * Number of random elements for the auxiliary trace segment of non-zero width is set to
-    ///   zero.
     /// * Number of random elements for the auxiliary trace segment of zero width is set to
     ///   non-zero.
     /// * Number of random elements for any auxiliary trace segment is greater than 255.
@@ -108,9 +129,10 @@ impl TraceInfo {
             trace_meta.len()
         );
 
-        // validate trace segment widths
+        // validate trace segment widths; the sum saturates so that segment widths which overflow
+        // usize are rejected as well
         assert!(main_segment_width > 0, "main trace segment must consist of at least one column");
-        let full_width = main_segment_width + aux_segment_width;
+        let full_width = main_segment_width.saturating_add(aux_segment_width);
         assert!(
             full_width <= TraceInfo::MAX_TRACE_WIDTH,
             "total number of columns in the trace cannot be greater than {}, but was {}",
@@ -138,15 +160,78 @@ impl TraceInfo {
             num_aux_segment_rands,
             trace_length,
             trace_meta,
+            constant_columns: Vec::new(),
         }
     }
 
+    /// Returns this [TraceInfo] with the specified columns of the main trace segment declared as
+    /// constant.
+    ///
+    /// Each entry in `columns` is a tuple containing the index of a column in the main trace
+    /// segment and the value this column holds in every row of the trace. Constant columns are
+    /// excluded from the commitment to the main trace segment.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * Column indexes are not sorted in strictly increasing order.
+    /// * Any of the column indexes is outside of the main trace segment.
+    /// * All columns of the main trace segment are declared as constant.
+    pub fn with_constant_columns<B: StarkField>(mut self, columns: &[(usize, B)]) -> Self {
+        assert!(
+            columns.len() < self.main_segment_width,
+            "at least one column of the main trace segment must not be constant"
+        );
+        for pair in columns.windows(2) {
+            assert!(
+                pair[0].0 < pair[1].0,
+                "constant column indexes must be in strictly increasing order"
+            );
+        }
+        if let Some(&(column, _)) = columns.last() {
+            assert!(
+                column < self.main_segment_width,
+                "constant column index must be smaller than {}, but was {}",
+                self.main_segment_width,
+                column
+            );
+        }
+
+        self.constant_columns =
+            columns.iter().map(|(column, value)| (*column, value.to_bytes())).collect();
+        self
+    }
+
+    /// Returns this [TraceInfo] with an auxiliary trace segment of the specified width which
+    /// requires the specified number of random elements.
+    ///
+    /// Trace length, metadata, and constant columns of the main trace segment are preserved. The
+    /// values of the auxiliary segment are not a part of the trace; they are built by the prover
+    /// after the main trace segment has been committed to.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * This [TraceInfo] already describes an auxiliary trace segment.
+    /// * Any of the conditions listed for [TraceInfo::new_multi_segment()] is violated.
+    pub fn with_aux_segment(self, aux_segment_width: usize, num_aux_segment_rands: usize) -> Self {
+        assert!(!self.is_multi_segment(), "trace already has an auxiliary segment");
+
+        let mut info = Self::new_multi_segment(
+            self.main_segment_width,
+            aux_segment_width,
+            num_aux_segment_rands,
+            self.trace_length,
+            self.trace_meta,
+        );
+        info.constant_columns = self.constant_columns;
+        info
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
     /// Returns the total number of columns in an execution trace.
     ///
-    /// This is guaranteed to be between 1 and 255.
+    /// This is guaranteed to be between 1 and 65536.
     pub fn width(&self) -> usize {
         self.main_segment_width + self.aux_segment_width
     }
@@ -170,11 +255,37 @@ impl TraceInfo {
 
     /// Returns the number of columns in the main segment of an execution trace.
     ///
-    /// This is guaranteed to be between 1 and 255.
+    /// This is guaranteed to be between 1 and [TraceInfo::MAX_TRACE_WIDTH].
     pub fn main_trace_width(&self) -> usize {
         self.main_segment_width
     }
 
+    /// Returns the number of columns in the main segment of an execution trace which are
+    /// committed to by the prover.
+    ///
+    /// This is the width of the main trace segment less the number of constant columns, and thus,
+    /// is guaranteed to be between 1 and [TraceInfo::MAX_TRACE_WIDTH].
+    pub fn main_trace_commitment_width(&self) -> usize {
+        self.main_segment_width - self.constant_columns.len()
+    }
+
+    /// Returns the number of constant columns in the main segment of an execution trace.
+    pub fn num_constant_columns(&self) -> usize {
+        self.constant_columns.len()
+    }
+
+    /// Returns indexes and values of constant columns of the main trace segment sorted by column
+    /// index.
+    ///
+    /// # Errors
+    /// Returns an error if any of the constant values is not a valid element of the field `B`.
+    pub fn constant_columns<B: StarkField>(&self) -> Result<Vec<(usize, B)>, DeserializationError> {
+        self.constant_columns
+            .iter()
+            .map(|(column, bytes)| Ok((*column, B::read_from_bytes(bytes)?)))
+            .collect()
+    }
+
     /// Returns the number of columns in the auxiliary segment of an execution trace.
     pub fn aux_segment_width(&self) -> usize {
         self.aux_segment_width
@@ -208,6 +319,14 @@ impl TraceInfo {
     pub fn get_num_aux_segment_rand_elements(&self) -> usize {
         self.num_aux_segment_rands
     }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns true if widths of trace segments cannot be encoded in 8 bits each.
+    pub(crate) fn is_wide(&self) -> bool {
+        self.main_segment_width > u8::MAX as usize || self.aux_segment_width > u8::MAX as usize
+    }
 }
 
 impl<E: StarkField> ToElements<E> for TraceInfo {
@@ -215,15 +334,27 @@ impl<E: StarkField> ToElements<E> for TraceInfo {
         let mut result = Vec::new();
 
         // main segment width, number of auxiliary segments, and parameters of the first auxiliary
-        // segment (if present) go into the first field element; we assume that each parameter can
-        // be encoded in 8 bits (which is enforced by the constructor)
-        let mut buf = self.main_segment_width as u32;
+        // segment (if present) go into the first field element; each parameter is encoded in 8
+        // bits. for wide traces, segment widths do not fit into 8 bits, and thus, they are set to
+        // zero in the first element (the main segment width is never zero otherwise) and are
+        // appended as separate elements.
+        let is_wide = self.is_wide();
+        let (main_width, aux_width) = if is_wide {
+            (0, 0)
+        } else {
+            (self.main_segment_width as u32, self.aux_segment_width as u32)
+        };
+        let mut buf = main_width;
         buf = (buf << 8) | self.num_aux_segments() as u32;
         if self.num_aux_segments() == 1 {
-            buf = (buf << 8) | self.aux_segment_width as u32;
+            buf = (buf << 8) | aux_width;
             buf = (buf << 8) | self.num_aux_segment_rands as u32;
         }
         result.push(E::from(buf));
+        if is_wide {
+            result.push(E::from(self.main_segment_width as u32));
+            result.push(E::from(self.aux_segment_width as u32));
+        }
 
         // We assume here that the trace length is never greater than 2^32.
         result.push(E::from(self.trace_length as u32));
@@ -237,33 +368,138 @@ impl<E: StarkField> ToElements<E> for TraceInfo {
             }
         }
 
+        // constant columns are encoded as the column index followed by the bytes of the column
+        // value broken into chunks in the same way as trace metadata
+        for (column, bytes) in self.constant_columns.iter() {
+            result.push(E::from(*column as u32));
+            for chunk in bytes.chunks(E::ELEMENT_BYTES - 1) {
+                result.push(E::from_bytes_with_padding(chunk));
+            }
+        }
+
         result
     }
 }
 
+// FIELD ELEMENT ENCODING
+// ================================================================================================
+
+impl TraceInfo {
+    /// Writes this trace info into the `target`.
+    ///
+    /// The trace info is laid out as the widths of the main and the auxiliary trace segments, the
+    /// number of random elements for the auxiliary segment, the trace length, the trace metadata
+    /// (as bytes), and the number of constant columns followed by the index and the value of
+    /// each constant column.
+    ///
+    /// # Panics
+    /// Panics if values of constant columns are not valid elements of field `B`.
+    pub(crate) fn write_elements_into<B: StarkField>(&self, target: &mut ElementWriter<B>) {
+        target.write_len(self.main_segment_width);
+        target.write_len(self.aux_segment_width);
+        target.write_len(self.num_aux_segment_rands);
+        target.write_len(self.trace_length);
+        target.write_bytes(&self.trace_meta);
+        let constant_columns = self
+            .constant_columns::<B>()
+            .expect("constant column values are not valid elements");
+        target.write_len(constant_columns.len());
+        for (column, value) in constant_columns {
+            target.write_len(column);
+            target.write_element(value);
+        }
+    }
+
+    /// Reads trace info written by [TraceInfo::write_elements_into()] from the `source`.
+    ///
+    /// The trace info is validated in the same way as when it is read from bytes.
+    pub(crate) fn read_elements_from<B: StarkField>(
+        source: &mut ElementReader<B>,
+    ) -> Result<Self, DeserializationError> {
+        let main_segment_width = source.read_len(Self::MAX_TRACE_WIDTH)?;
+        let aux_segment_width = source.read_len(Self::MAX_TRACE_WIDTH)?;
+        let num_aux_segment_rands = source.read_u8()? as usize;
+        let trace_length = source.read_u32()? as usize;
+        if !trace_length.is_power_of_two() {
+            return Err(DeserializationError::InvalidValue(format!(
+                "trace length must be a power of two, but was {trace_length}"
+            )));
+        }
+        let trace_meta = source.read_bytes()?;
+        if trace_meta.len() > Self::MAX_META_LENGTH {
+            return Err(DeserializationError::InvalidValue(format!(
+                "trace metadata cannot be longer than {} bytes, but was {}",
+                Self::MAX_META_LENGTH,
+                trace_meta.len()
+            )));
+        }
+        let num_constant_columns = source.read_len(main_segment_width)?;
+        let mut constant_columns = Vec::with_capacity(num_constant_columns);
+        for _ in 0..num_constant_columns {
+            let column = source.read_len(main_segment_width)?;
+            constant_columns.push((column, source.read_element()?.to_bytes()));
+        }
+
+        // validate the trace info by reading it from its byte serialization; since the byte
+        // encoding cannot represent some invalid values (e.g., it contains only the log2 of the
+        // trace length), the result must also match the decoded trace info
+        let info = TraceInfo {
+            main_segment_width,
+            aux_segment_width,
+            num_aux_segment_rands,
+            trace_length,
+            trace_meta,
+            constant_columns,
+        };
+        if TraceInfo::read_from_bytes(&info.to_bytes())? != info {
+            return Err(DeserializationError::InvalidValue("trace info is not valid".to_string()));
+        }
+        Ok(info)
+    }
+}
+
 impl Serializable for TraceInfo {
     /// Serializes `self` and writes the resulting bytes into the `target`.
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
-        // store segments
-        target.write_u8(self.main_segment_width as u8);
-
-        debug_assert!(
-            self.aux_segment_width <= u8::MAX as usize,
-            "aux segment width does not fit into u8 value"
-        );
-        target.write_u8(self.aux_segment_width as u8);
+        // store segments; widths of wide traces are written as u32 values preceded by a zero byte
+        // (which is never a valid width of the main segment)
+        let is_wide = self.is_wide();
+        if is_wide {
+            target.write_u8(0);
+            target.write_u32(self.main_segment_width as u32);
+            target.write_u32(self.aux_segment_width as u32);
+        } else {
+            target.write_u8(self.main_segment_width as u8);
+            target.write_u8(self.aux_segment_width as u8);
+        }
         debug_assert!(
             self.num_aux_segment_rands <= u8::MAX as usize,
             "aux segment random element count does not fit into u8 value"
         );
         target.write_u8(self.num_aux_segment_rands as u8);
 
-        // store trace length as power of two
-        target.write_u8(self.trace_length.ilog2() as u8);
+        // store trace length as power of two; the most significant bit marks whether constant
+        // columns follow trace meta
+        let has_constant_columns = !self.constant_columns.is_empty();
+        let mut trace_length = self.trace_length.ilog2() as u8;
+        if has_constant_columns {
+            trace_length |= CONSTANT_COLUMNS_FLAG;
+        }
+        target.write_u8(trace_length);
 
         // store trace meta
         target.write_u16(self.trace_meta.len() as u16);
         target.write_bytes(&self.trace_meta);
+
+        // store constant columns; for wide traces, column indexes are written as u32 values
+        if has_constant_columns {
+            write_column_index(target, self.constant_columns.len(), is_wide);
+            for (column, bytes) in self.constant_columns.iter() {
+                write_column_index(target, *column, is_wide);
+                target.write_u8(bytes.len() as u8);
+                target.write_bytes(bytes);
+            }
+        }
     }
 }
 
@@ -274,18 +510,31 @@ impl Deserializable for TraceInfo {
     /// Returns an error of a valid [`TraceInfo`] struct could not be read from the specified
     /// `source`.
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
-        let main_segment_width = source.read_u8()? as usize;
+        // read trace segment widths; a zero byte indicates that the widths are encoded as u32
+        // values, which is allowed only for widths which do not fit into u8 values
+        let mut main_segment_width = source.read_u8()? as usize;
+        let is_wide = main_segment_width == 0;
+        let aux_segment_width = if is_wide {
+            main_segment_width = source.read_u32()? as usize;
+            let aux_segment_width = source.read_u32()? as usize;
+            if main_segment_width <= u8::MAX as usize && aux_segment_width <= u8::MAX as usize {
+                return Err(DeserializationError::InvalidValue(
+                    "trace segment widths which fit into u8 values must be encoded as such"
+                        .to_string(),
+                ));
+            }
+            aux_segment_width
+        } else {
+            source.read_u8()? as usize
+        };
         if main_segment_width == 0 {
             return Err(DeserializationError::InvalidValue(
                 "main trace segment width must be greater than zero".to_string(),
             ));
         }
 
-        // read auxiliary trace segment width
-        let aux_segment_width = source.read_u8()? as usize;
-
         let full_trace_width = main_segment_width + aux_segment_width;
-        if full_trace_width >= TraceInfo::MAX_TRACE_WIDTH {
+        if full_trace_width > TraceInfo::MAX_TRACE_WIDTH {
             return Err(DeserializationError::InvalidValue(format!(
                 "full trace width cannot be greater than {}, but was {}",
                 TraceInfo::MAX_TRACE_WIDTH,
@@ -295,9 +544,10 @@ impl Deserializable for TraceInfo {
 
         // read and validate number of random elements for the auxiliary trace segment
         let num_aux_segment_rands = source.read_u8()? as usize;
-        if aux_segment_width != 0 && num_aux_segment_rands == 0 {
+        if aux_segment_width == 0 && num_aux_segment_rands != 0 {
             return Err(DeserializationError::InvalidValue(
-                "a non-empty trace segment must require at least one random element".to_string(),
+                "number of random elements for an empty auxiliary trace segment must be zero"
+                    .to_string(),
             ));
         } else if num_aux_segment_rands > TraceInfo::MAX_RAND_SEGMENT_ELEMENTS {
             return Err(DeserializationError::InvalidValue(format!(
@@ -309,6 +559,8 @@ impl Deserializable for TraceInfo {
 
         // read and validate trace length (which was stored as a power of two)
         let trace_length = source.read_u8()?;
+        let has_constant_columns = trace_length & CONSTANT_COLUMNS_FLAG != 0;
+        let trace_length = trace_length & !CONSTANT_COLUMNS_FLAG;
         if trace_length < TraceInfo::MIN_TRACE_LENGTH.ilog2() as u8 {
             return Err(DeserializationError::InvalidValue(format!(
                 "trace length cannot be smaller than 2^{}, but was 2^{}",
@@ -326,13 +578,75 @@ impl Deserializable for TraceInfo {
             vec![]
         };
 
-        Ok(Self::new_multi_segment(
+        // read and validate constant columns
+        let num_constant_columns = if has_constant_columns {
+            read_column_index(source, is_wide)?
+        } else {
+            0
+        };
+        if has_constant_columns && num_constant_columns == 0 {
+            return Err(DeserializationError::InvalidValue(
+                "number of constant columns must be greater than zero when flagged".to_string(),
+            ));
+        }
+        if num_constant_columns >= main_segment_width {
+            return Err(DeserializationError::InvalidValue(format!(
+                "number of constant columns must be smaller than {main_segment_width}, but was \
+                {num_constant_columns}"
+            )));
+        }
+        let mut constant_columns = Vec::with_capacity(num_constant_columns);
+        for _ in 0..num_constant_columns {
+            let column = read_column_index(source, is_wide)?;
+            if column >= main_segment_width {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "constant column index must be smaller than {main_segment_width}, but was \
+                    {column}"
+                )));
+            }
+            if constant_columns.last().is_some_and(|(prev, _)| *prev >= column) {
+                return Err(DeserializationError::InvalidValue(
+                    "constant column indexes must be in strictly increasing order".to_string(),
+                ));
+            }
+            let num_value_bytes = source.read_u8()? as usize;
+            constant_columns.push((column, source.read_vec(num_value_bytes)?));
+        }
+
+        let mut info = Self::new_multi_segment(
             main_segment_width,
             aux_segment_width,
             num_aux_segment_rands,
             trace_length,
             trace_meta,
-        ))
+        );
+        info.constant_columns = constant_columns;
+
+        Ok(info)
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Writes a column index (or a number of columns) as a u8 value, or as a u32 value for wide traces.
+fn write_column_index<W: ByteWriter>(target: &mut W, index: usize, is_wide: bool) {
+    if is_wide {
+        target.write_u32(index as u32);
+    } else {
+        target.write_u8(index as u8);
+    }
+}
+
+/// Reads a column index (or a number of columns) written by [write_column_index()].
+fn read_column_index<R: ByteReader>(
+    source: &mut R,
+    is_wide: bool,
+) -> Result<usize, DeserializationError> {
+    if is_wide {
+        Ok(source.read_u32()? as usize)
+    } else {
+        Ok(source.read_u8()? as usize)
     }
 }
 
@@ -341,7 +655,10 @@ impl Deserializable for TraceInfo {
 
 #[cfg(test)]
 mod tests {
+    use alloc::vec::Vec;
+
     use math::{fields::f64::BaseElement, FieldElement};
+    use utils::{Deserializable, Serializable};
 
     use super::{ToElements, TraceInfo};
 
@@ -391,4 +708,167 @@ mod tests {
 
         assert_eq!(expected, info.to_elements());
     }
+
+    #[test]
+    fn trace_info_constant_columns() {
+        let constants = [(1, BaseElement::ZERO), (3, BaseElement::new(7))];
+        let info = TraceInfo::new(5, 64).with_constant_columns(&constants);
+
+        assert_eq!(5, info.main_trace_width());
+        assert_eq!(3, info.main_trace_commitment_width());
+        assert_eq!(2, info.num_constant_columns());
+        assert_eq!(constants.to_vec(), info.constant_columns::<BaseElement>().unwrap());
+
+        // constant columns are appended to the elements of the trace info; since the value of a
+        // column takes 8 bytes, it is split into chunks of 7 and 1 bytes
+        let mut expected: Vec<BaseElement> = TraceInfo::new(5, 64).to_elements();
+        for (column, value) in constants {
+            expected.push(BaseElement::from(column as u32));
+            expected.push(BaseElement::new(value.as_int()));
+            expected.push(BaseElement::ZERO);
+        }
+        assert_eq!(expected, info.to_elements());
+
+        // constant columns survive serialization round trip
+        let bytes = info.to_bytes();
+        assert_eq!(info, TraceInfo::read_from_bytes(&bytes).unwrap());
+
+        // trace infos without constant columns do not encode them, and constant columns are
+        // flagged in the trace length byte
+        let bytes_without_constants = TraceInfo::new(5, 64).to_bytes();
+        assert_eq!(vec![5, 0, 0, 6, 0, 0], bytes_without_constants);
+        assert_eq!(6 | super::CONSTANT_COLUMNS_FLAG, bytes[3]);
+
+        // a flagged but empty list of constant columns is rejected
+        let mut bytes = bytes_without_constants;
+        bytes[3] |= super::CONSTANT_COLUMNS_FLAG;
+        bytes.push(0);
+        assert!(TraceInfo::read_from_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one column of the main trace segment must not be constant")]
+    fn trace_info_all_columns_constant() {
+        TraceInfo::new(2, 64)
+            .with_constant_columns(&[(0, BaseElement::ONE), (1, BaseElement::ONE)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "constant column indexes must be in strictly increasing order")]
+    fn trace_info_unsorted_constant_columns() {
+        TraceInfo::new(4, 64)
+            .with_constant_columns(&[(2, BaseElement::ONE), (1, BaseElement::ONE)]);
+    }
+
+    #[test]
+    fn trace_info_with_aux_segment() {
+        let constants = [(1, BaseElement::new(7))];
+        let info = TraceInfo::with_meta(4, 64, vec![1, 2, 3])
+            .with_constant_columns(&constants)
+            .with_aux_segment(2, 3);
+
+        let mut expected = TraceInfo::new_multi_segment(4, 2, 3, 64, vec![1, 2, 3]);
+        expected = expected.with_constant_columns(&constants);
+        assert_eq!(expected, info);
+        assert_eq!(2, info.aux_segment_width());
+        assert_eq!(3, info.get_num_aux_segment_rand_elements());
+        assert_eq!(constants.to_vec(), info.constant_columns::<BaseElement>().unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "trace already has an auxiliary segment")]
+    fn trace_info_with_aux_segment_twice() {
+        TraceInfo::new(4, 64).with_aux_segment(2, 3).with_aux_segment(1, 1);
+    }
+
+    #[test]
+    fn trace_info_aux_segment_without_rands() {
+        // auxiliary segments which do not require random elements survive serialization round trip
+        let info = TraceInfo::new_multi_segment(4, 2, 0, 64, vec![]);
+        let bytes = info.to_bytes();
+        assert_eq!(info, TraceInfo::read_from_bytes(&bytes).unwrap());
+
+        // but an empty auxiliary segment must not require random elements
+        let mut bytes = TraceInfo::new(4, 64).to_bytes();
+        bytes[2] = 1;
+        assert!(TraceInfo::read_from_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn trace_info_wide_trace() {
+        let constants = [(0, BaseElement::ONE), (280, BaseElement::new(7))];
+        let info =
+            TraceInfo::new_multi_segment(300, 2, 1, 64, vec![]).with_constant_columns(&constants);
+
+        // wide trace info survives serialization round trip
+        let bytes = info.to_bytes();
+        assert_eq!(0, bytes[0]);
+        assert_eq!(info, TraceInfo::read_from_bytes(&bytes).unwrap());
+
+        // segment widths are appended to the first element
+        let elements: Vec<BaseElement> = info.to_elements();
+        let first_ele = u32::from_le_bytes([1, 0, 1, 0]);
+        assert_eq!(
+            &[BaseElement::from(first_ele), BaseElement::new(300), BaseElement::new(2)],
+            &elements[..3]
+        );
+
+        // widths which fit into a u8 value must not use the wide encoding
+        let mut bytes = vec![0];
+        bytes.extend_from_slice(&20_u32.to_le_bytes());
+        bytes.extend_from_slice(&0_u32.to_le_bytes());
+        bytes.extend_from_slice(&TraceInfo::new(20, 64).to_bytes()[2..]);
+        assert!(TraceInfo::read_from_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn trace_info_width_limits() {
+        // traces with a full width of 255 columns or more which fit into the u8 encoding used to
+        // be rejected during deserialization; they now survive serialization round trip
+        for info in [TraceInfo::new(255, 64), TraceInfo::new_multi_segment(200, 100, 1, 64, vec![])]
+        {
+            let bytes = info.to_bytes();
+            assert_ne!(0, bytes[0]);
+            assert_eq!(info, TraceInfo::read_from_bytes(&bytes).unwrap());
+        }
+
+        // the maximum trace width is accepted, but wider traces are rejected
+        let info = TraceInfo::new_multi_segment(TraceInfo::MAX_TRACE_WIDTH - 1, 1, 1, 64, vec![]);
+        let mut bytes = info.to_bytes();
+        assert_eq!(info, TraceInfo::read_from_bytes(&bytes).unwrap());
+        bytes[5..9].copy_from_slice(&2_u32.to_le_bytes());
+        assert!(TraceInfo::read_from_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "total number of columns in the trace cannot be greater than 65536")]
+    fn trace_info_too_wide() {
+        TraceInfo::new_multi_segment(TraceInfo::MAX_TRACE_WIDTH, 1, 0, 64, vec![]);
+    }
+
+    #[test]
+    #[should_panic(expected = "total number of columns in the trace cannot be greater than 65536")]
+    fn trace_info_aux_segment_width_overflow() {
+        TraceInfo::new_multi_segment(2, usize::MAX, 1, 64, vec![]);
+    }
+
+    #[test]
+    #[should_panic(expected = "main trace segment must consist of at least one column")]
+    fn trace_info_empty_main_segment() {
+        TraceInfo::new_multi_segment(0, 2, 1, 64, vec![]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "number of random elements for an empty auxiliary trace segment must be zero"
+    )]
+    fn trace_info_rands_without_aux_segment() {
+        TraceInfo::new_multi_segment(4, 0, 1, 64, vec![]);
+    }
+
+    #[test]
+    #[should_panic(expected = "number of random elements required by a segment cannot exceed 255")]
+    fn trace_info_too_many_aux_segment_rands() {
+        TraceInfo::new_multi_segment(4, 2, TraceInfo::MAX_RAND_SEGMENT_ELEMENTS + 1, 64, vec![]);
+    }
 }
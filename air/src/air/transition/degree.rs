@@ -67,6 +67,37 @@ impl TransitionConstraintDegree {
         TransitionConstraintDegree { base: base_degree, cycles }
     }
 
+    /// Returns a degree descriptor for a constraint computed as `s * a + (1 - s) * b`, where `s`
+    /// is a trace column, and `a` and `b` are constraints with degrees `when_true` and
+    /// `when_false` respectively.
+    ///
+    /// The resulting degree is the larger of the two degrees with the base degree incremented by
+    /// one. If the degrees involve periodic columns, the larger degree is the one which expands
+    /// to a higher degree for long enough execution traces.
+    pub fn select(when_true: &Self, when_false: &Self) -> Self {
+        // all cycle lengths are powers of two; thus, multiplying the per-step degree of both
+        // descriptors by the largest cycle length yields integers which can be compared exactly
+        let max_cycle = when_true.cycles.iter().chain(when_false.cycles.iter()).max();
+        let scale = max_cycle.copied().unwrap_or(1);
+        let growth = |degree: &Self| {
+            degree.base * scale + degree.cycles.iter().map(|c| scale - scale / c).sum::<usize>()
+        };
+
+        // compare the coefficients of the highest term, and then the constant term (which is
+        // equal to -base); thus, in case of a tie, the descriptor with a smaller base is selected
+        let dominant = match growth(when_true).cmp(&growth(when_false)) {
+            cmp::Ordering::Greater => when_true,
+            cmp::Ordering::Less => when_false,
+            cmp::Ordering::Equal if when_true.base <= when_false.base => when_true,
+            cmp::Ordering::Equal => when_false,
+        };
+
+        TransitionConstraintDegree {
+            base: dominant.base + 1,
+            cycles: dominant.cycles.clone(),
+        }
+    }
+
     /// Computes a degree to which this degree description expands in the context of execution
     /// trace of the specified length.
     ///
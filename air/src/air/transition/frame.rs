@@ -6,6 +6,7 @@
 use alloc::vec::Vec;
 
 use super::FieldElement;
+use crate::Air;
 
 // EVALUATION FRAME
 // ================================================================================================
@@ -15,10 +16,15 @@ use super::FieldElement;
 /// In the current implementation, an evaluation frame always contains two consecutive rows of the
 /// execution trace. It is passed in as one of the parameters into
 /// [Air::evaluate_transition()](crate::Air::evaluate_transition) function.
+///
+/// A frame of the main trace segment also contains values of virtual columns (if any) at both
+/// rows; these values are computed via [EvaluationFrame::evaluate_virtual_columns()].
 #[derive(Debug, Clone)]
 pub struct EvaluationFrame<E: FieldElement> {
     current: Vec<E>,
     next: Vec<E>,
+    current_virtual: Vec<E>,
+    next_virtual: Vec<E>,
 }
 
 impl<E: FieldElement> EvaluationFrame<E> {
@@ -34,6 +40,8 @@ impl<E: FieldElement> EvaluationFrame<E> {
         EvaluationFrame {
             current: vec![E::ZERO; num_columns],
             next: vec![E::ZERO; num_columns],
+            current_virtual: Vec::new(),
+            next_virtual: Vec::new(),
         }
     }
 
@@ -46,7 +54,12 @@ impl<E: FieldElement> EvaluationFrame<E> {
     pub fn from_rows(current: Vec<E>, next: Vec<E>) -> Self {
         assert!(!current.is_empty(), "a row must contain at least one value");
         assert_eq!(current.len(), next.len(), "number of values in the rows must be the same");
-        Self { current, next }
+        Self {
+            current,
+            next,
+            current_virtual: Vec::new(),
+            next_virtual: Vec::new(),
+        }
     }
 
     // ROW ACCESSORS
@@ -64,6 +77,24 @@ impl<E: FieldElement> EvaluationFrame<E> {
         &self.next
     }
 
+    /// Returns values of virtual columns at the current row.
+    ///
+    /// The returned slice is empty unless virtual columns have been computed via
+    /// [EvaluationFrame::evaluate_virtual_columns()].
+    #[inline(always)]
+    pub fn current_virtual(&self) -> &[E] {
+        &self.current_virtual
+    }
+
+    /// Returns values of virtual columns at the next row.
+    ///
+    /// The returned slice is empty unless virtual columns have been computed via
+    /// [EvaluationFrame::evaluate_virtual_columns()].
+    #[inline(always)]
+    pub fn next_virtual(&self) -> &[E] {
+        &self.next_virtual
+    }
+
     // DATA MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -78,4 +109,24 @@ impl<E: FieldElement> EvaluationFrame<E> {
     pub fn next_mut(&mut self) -> &mut [E] {
         &mut self.next
     }
+
+    /// Computes values of virtual columns defined by the specified `air` at both rows of this
+    /// frame.
+    ///
+    /// This should be called every time the rows of a main trace frame are updated. If the `air`
+    /// does not define any virtual columns, this is a no-op.
+    pub fn evaluate_virtual_columns<A>(&mut self, air: &A)
+    where
+        A: Air<BaseField = E::BaseField>,
+    {
+        let num_virtual_columns = air.context().num_virtual_columns();
+        if num_virtual_columns == 0 {
+            return;
+        }
+
+        self.current_virtual.resize(num_virtual_columns, E::ZERO);
+        self.next_virtual.resize(num_virtual_columns, E::ZERO);
+        air.evaluate_virtual_columns(&self.current, &mut self.current_virtual);
+        air.evaluate_virtual_columns(&self.next, &mut self.next_virtual);
+    }
 }
@@ -13,6 +13,9 @@ pub use frame::EvaluationFrame;
 mod degree;
 pub use degree::TransitionConstraintDegree;
 
+mod selector;
+pub use selector::Selector;
+
 // CONSTANTS
 // ================================================================================================
 
@@ -0,0 +1,102 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use super::{EvaluationFrame, FieldElement, TransitionConstraintDegree};
+
+// SELECTOR
+// ================================================================================================
+/// A helper for building transition constraints which branch on a boolean trace column.
+///
+/// A selector refers to a column of the execution trace which is expected to contain only zeros
+/// and ones. Given two constraints `a` and `b`, the selector can be used to build a combined
+/// constraint which enforces `a` at the steps where the selector column is set to one, and `b`
+/// at the steps where it is set to zero. The combined constraint is computed as:
+///
+/// `s * a + (1 - s) * b`
+///
+/// where `s` is the value of the selector column in the current row of the evaluation frame.
+///
+/// Note that the combined constraint is sound only if the selector column is guaranteed to be
+/// binary. This can be enforced by an additional constraint returned from
+/// [Selector::enforce_binary()].
+///
+/// The degrees of the constraints produced by a selector can be computed via
+/// [Selector::select_degree()] and [Selector::binary_degree()] methods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Selector {
+    column: usize,
+}
+
+impl Selector {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    /// Returns a new selector for the specified column of the execution trace.
+    pub fn new(column: usize) -> Self {
+        Self { column }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the index of the trace column used by this selector.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// Returns the value of the selector column in the current row of the specified frame.
+    ///
+    /// # Panics
+    /// Panics if the selector column is out of bounds for the specified frame.
+    pub fn value<E: FieldElement>(&self, frame: &EvaluationFrame<E>) -> E {
+        frame.current()[self.column]
+    }
+
+    // CONSTRAINT EVALUATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a combined constraint which evaluates to `when_true` at the steps where the
+    /// selector column is one, and to `when_false` at the steps where the selector column is zero.
+    ///
+    /// # Panics
+    /// Panics if the selector column is out of bounds for the specified frame.
+    pub fn select<E: FieldElement>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        when_true: E,
+        when_false: E,
+    ) -> E {
+        let s = self.value(frame);
+        s * when_true + (E::ONE - s) * when_false
+    }
+
+    /// Returns a constraint which evaluates to zero if and only if the selector column in the
+    /// current row of the specified frame is either zero or one.
+    ///
+    /// # Panics
+    /// Panics if the selector column is out of bounds for the specified frame.
+    pub fn enforce_binary<E: FieldElement>(&self, frame: &EvaluationFrame<E>) -> E {
+        let s = self.value(frame);
+        s.square() - s
+    }
+
+    // CONSTRAINT DEGREES
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the degree of a constraint produced by [Selector::select()] method for the
+    /// constraints of the specified degrees.
+    ///
+    /// See [TransitionConstraintDegree::select()] for details.
+    pub fn select_degree(
+        when_true: &TransitionConstraintDegree,
+        when_false: &TransitionConstraintDegree,
+    ) -> TransitionConstraintDegree {
+        TransitionConstraintDegree::select(when_true, when_false)
+    }
+
+    /// Returns the degree of a constraint produced by [Selector::enforce_binary()] method.
+    pub fn binary_degree() -> TransitionConstraintDegree {
+        TransitionConstraintDegree::new(2)
+    }
+}
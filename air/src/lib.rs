@@ -32,21 +32,24 @@
 #[macro_use]
 extern crate alloc;
 
+#[cfg(feature = "compression")]
+extern crate std;
+
 pub mod proof;
 
 mod errors;
 pub use errors::AssertionError;
 
 mod options;
-pub use options::{FieldExtension, PartitionOptions, ProofOptions};
+pub use options::{FieldExtension, PartitionOptions, ProofOptions, SecurityLevel};
 
 mod air;
 pub use air::{
-    Air, AirContext, Assertion, AuxRandElements, BoundaryConstraint, BoundaryConstraintGroup,
-    BoundaryConstraints, ConstraintCompositionCoefficients, ConstraintDivisor,
-    DeepCompositionCoefficients, EvaluationFrame, GkrRandElements, GkrVerifier,
-    LagrangeConstraintsCompositionCoefficients, LagrangeKernelBoundaryConstraint,
-    LagrangeKernelConstraints, LagrangeKernelEvaluationFrame, LagrangeKernelRandElements,
-    LagrangeKernelTransitionConstraints, TraceInfo, TransitionConstraintDegree,
-    TransitionConstraints,
+    Air, AirContext, AirWithAssertions, Assertion, AuxRandElements, BoundaryConstraint,
+    BoundaryConstraintGroup, BoundaryConstraints, ConstraintCompositionCoefficients,
+    ConstraintDivisor, DeepCompositionCoefficients, EvaluationFrame, GkrRandElements, GkrVerifier,
+    InputsWithAssertions, LagrangeConstraintsCompositionCoefficients,
+    LagrangeKernelBoundaryConstraint, LagrangeKernelConstraints, LagrangeKernelEvaluationFrame,
+    LagrangeKernelRandElements, LagrangeKernelTransitionConstraints, Selector, TraceInfo,
+    TransitionConstraintDegree, TransitionConstraints,
 };
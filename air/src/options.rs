@@ -6,10 +6,19 @@
 use alloc::vec::Vec;
 use core::cmp;
 
+use crypto::Hasher;
 use fri::FriOptions;
 use math::{FieldElement, StarkField, ToElements};
 use utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 
+use crate::{
+    proof::{
+        elements::{ElementReader, ElementWriter},
+        get_conjectured_security, get_proven_security, powf, Context,
+    },
+    TraceInfo,
+};
+
 // CONSTANTS
 // ================================================================================================
 
@@ -40,6 +49,10 @@ const FRI_MAX_REMAINDER_DEGREE: usize = 255;
 /// achieve ~100 bits of soundness, and a cubic extension must be used to achieve 128+ bits
 /// of soundness.
 ///
+/// A degree 6 extension ([FieldExtension::Sextic]) is currently supported only for the 64-bit
+/// field, where it is built as a tower of a quadratic extension over the cubic extension (see
+/// the documentation of the `math` crate for details).
+///
 /// However, increasing extension degree will increase proof generation time and proof size by
 /// as much as 50%.
 #[repr(u8)]
@@ -51,6 +64,8 @@ pub enum FieldExtension {
     Quadratic = 2,
     /// Composition polynomial is constructed in the cubic extension of the base field.
     Cubic = 3,
+    /// Composition polynomial is constructed in the degree 6 extension of the base field.
+    Sextic = 6,
 }
 
 /// STARK protocol parameters.
@@ -69,7 +84,9 @@ pub enum FieldExtension {
 ///    time decrease the number of queries in such a way that the proofs become smaller.
 /// 4. Grinding factor - higher values increase proof soundness, but also may increase proof
 ///    generation time. More precisely, conjectured proof soundness is bounded by
-///    `num_queries * log2(blowup_factor) + grinding_factor`.
+///    `num_queries * log2(blowup_factor) + grinding_factor`. Grinding is disabled when grinding
+///    factor is set to 0, and can be toggled independently of other parameters via
+///    [ProofOptions::with_grinding_factor()]. See [ProofOptions::grinding_factor()] for details.
 ///
 /// Another important parameter in defining STARK security level, which is not a part of [ProofOptions]
 /// is the hash function used in the protocol. The soundness of a STARK proof is limited by the
@@ -94,6 +111,19 @@ pub struct ProofOptions {
     partition_options: PartitionOptions,
 }
 
+/// Security level (in bits) provided by a set of [ProofOptions].
+///
+/// Security levels are ordered first by conjectured security and then by proven security. That
+/// is, a security level is higher than another if it provides more bits of conjectured security,
+/// or the same number of bits of conjectured security and more bits of proven security.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct SecurityLevel {
+    /// Conjectured security level (in bits).
+    pub conjectured: u32,
+    /// Proven security level (in bits).
+    pub proven: u32,
+}
+
 // PROOF OPTIONS IMPLEMENTATION
 // ================================================================================================
 impl ProofOptions {
@@ -185,6 +215,23 @@ impl ProofOptions {
         self
     }
 
+    /// Updates the provided [ProofOptions] instance with the specified grinding factor.
+    ///
+    /// All other parameters (including the number of queries) remain unchanged. Setting grinding
+    /// factor to 0 disables grinding.
+    ///
+    /// # Panics
+    /// Panics if `grinding_factor` is greater than 32.
+    pub const fn with_grinding_factor(mut self, grinding_factor: u32) -> ProofOptions {
+        assert!(
+            grinding_factor <= MAX_GRINDING_FACTOR,
+            "grinding factor cannot be greater than 32"
+        );
+        self.grinding_factor = grinding_factor as u8;
+
+        self
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -214,6 +261,17 @@ impl ProofOptions {
     /// they try to change a commitment. Thus, higher grinding factor makes it more difficult to
     /// forge a STARK proof. However, setting grinding factor too high (e.g. higher than 20) will
     /// adversely affect prover time.
+    ///
+    /// Grinding factor of 0 means that grinding is disabled. Otherwise, grinding contributes to
+    /// proof soundness as follows:
+    /// - For conjectured security, `grinding_factor` bits are added to the security provided by
+    ///   the queries, but only if the queries alone provide at least 80 bits of security.
+    /// - For proven security, `grinding_factor` bits are added to the soundness of the query
+    ///   phase of FRI.
+    ///
+    /// In both cases, the overall security level is still bounded by the size of the field and by
+    /// the collision resistance of the hash function. See [ProofOptions::min_grinding_factor()]
+    /// for computing the grinding factor needed to reach a given security level.
     pub const fn grinding_factor(&self) -> u32 {
         self.grinding_factor as u32
     }
@@ -246,6 +304,207 @@ impl ProofOptions {
     pub fn partition_options(&self) -> PartitionOptions {
         self.partition_options
     }
+
+    // SECURITY
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the security level these options provide for a trace of the specified length.
+    ///
+    /// The security level is computed in the same way as by
+    /// [Proof::security_level()](crate::proof::Proof::security_level) for base field `B` and hash
+    /// function `H`.
+    pub fn security_level<B: StarkField, H: Hasher>(&self, trace_length: usize) -> SecurityLevel {
+        SecurityLevel {
+            conjectured: get_conjectured_security(
+                self,
+                B::MODULUS_BITS,
+                trace_length,
+                H::COLLISION_RESISTANCE,
+            ),
+            proven: get_proven_security(
+                self,
+                B::MODULUS_BITS,
+                trace_length,
+                H::COLLISION_RESISTANCE,
+            ),
+        }
+    }
+
+    /// Compares the security levels these and the `other` options provide for a trace of the
+    /// specified length.
+    ///
+    /// The options are compared by their [SecurityLevel] computed for base field `B` and hash
+    /// function `H`; thus, options which differ only in parameters which do not affect security
+    /// (e.g., FRI folding factor) compare as equal.
+    pub fn cmp_security<B: StarkField, H: Hasher>(
+        &self,
+        other: &Self,
+        trace_length: usize,
+    ) -> cmp::Ordering {
+        self.security_level::<B, H>(trace_length)
+            .cmp(&other.security_level::<B, H>(trace_length))
+    }
+
+    /// Returns the highest security level provided by any of the specified options for a trace
+    /// of the specified length, or None if `options` is empty.
+    ///
+    /// Security levels are computed for base field `B` and hash function `H`.
+    pub fn max_security_of<B: StarkField, H: Hasher>(
+        options: &[ProofOptions],
+        trace_length: usize,
+    ) -> Option<SecurityLevel> {
+        options.iter().map(|options| options.security_level::<B, H>(trace_length)).max()
+    }
+
+    /// Returns the options which provide the highest security level for a trace of the specified
+    /// length, or None if `options` is empty.
+    ///
+    /// Security levels are computed for base field `B` and hash function `H`. If several options
+    /// provide the same highest security level, the first of them is returned.
+    pub fn strongest_of<B: StarkField, H: Hasher>(
+        options: &[ProofOptions],
+        trace_length: usize,
+    ) -> Option<&ProofOptions> {
+        options
+            .iter()
+            .rev()
+            .max_by_key(|options| options.security_level::<B, H>(trace_length))
+    }
+
+    /// Returns the smallest grinding factor which, together with all other parameters of these
+    /// options, provides at least `target_security` bits of security for a trace of the specified
+    /// length, or None if no grinding factor can provide such a security level.
+    ///
+    /// The security level is computed in the same way as by
+    /// [Proof::security_level()](crate::proof::Proof::security_level) for base field `B` and hash
+    /// function `H`: when `conjectured` is true, conjectured security level is used; otherwise,
+    /// proven security level is used. The grinding factor of these options is ignored.
+    ///
+    /// This can be used to reach a security target with fewer queries (and thus, smaller proofs)
+    /// by fixing the number of queries and the blowup factor and then adding the required amount
+    /// of grinding via [ProofOptions::with_grinding_factor()].
+    pub fn min_grinding_factor<B: StarkField, H: Hasher>(
+        &self,
+        trace_length: usize,
+        target_security: u32,
+        conjectured: bool,
+    ) -> Option<u32> {
+        (0..=MAX_GRINDING_FACTOR).find(|&grinding_factor| {
+            let options = self.clone().with_grinding_factor(grinding_factor);
+            let security = if conjectured {
+                get_conjectured_security(
+                    &options,
+                    B::MODULUS_BITS,
+                    trace_length,
+                    H::COLLISION_RESISTANCE,
+                )
+            } else {
+                get_proven_security(
+                    &options,
+                    B::MODULUS_BITS,
+                    trace_length,
+                    H::COLLISION_RESISTANCE,
+                )
+            };
+            security >= target_security
+        })
+    }
+
+    // PROOF SIZE
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns an estimate of the size (in bytes) of a serialized STARK proof generated with these
+    /// options for a trace described by `trace_info`, using base field `B` and hash function `H`.
+    ///
+    /// The estimate accounts for the proof context, trace and constraint commitments, trace and
+    /// constraint queries together with their Merkle authentication paths, the out-of-domain
+    /// frame, FRI layer queries together with the FRI remainder, and the proof-of-work nonce.
+    ///
+    /// The estimate assumes that all queried positions are distinct and uniformly distributed
+    /// over the LDE domain, and uses the expected number of nodes in Merkle authentication paths
+    /// for such positions. In practice, serialized proofs are within a few percent of this
+    /// estimate, and are usually smaller when some of the query positions coincide.
+    ///
+    /// Width of the constraint composition polynomial depends on the degree of transition
+    /// constraints and thus cannot be derived from the trace dimensions; it must be provided via
+    /// `num_composition_columns` (see
+    /// [AirContext::num_constraint_composition_columns()](crate::AirContext::num_constraint_composition_columns)).
+    /// Columns of the Lagrange kernel and GKR proofs are not included in the estimate.
+    pub fn estimate_proof_size<B: StarkField, H: Hasher>(
+        &self,
+        trace_info: &TraceInfo,
+        num_composition_columns: usize,
+    ) -> usize {
+        let base_bytes = B::ELEMENT_BYTES;
+        let ext_bytes = base_bytes * self.field_extension().degree() as usize;
+        let digest_bytes = H::Digest::default().to_bytes().len();
+
+        let lde_domain_size = trace_info.length() * self.blowup_factor();
+        let num_queries = self.num_queries();
+        let fri_options = self.to_fri_options();
+        let folding_factor = fri_options.folding_factor();
+        let num_fri_layers = fri_options.num_fri_layers(lde_domain_size);
+
+        // expected number of distinct values among `num_draws` uniformly random values from a
+        // set of the specified size
+        let num_distinct =
+            |num_draws: usize, size: f64| size * (1.0 - powf(1.0 - 1.0 / size, num_draws as f64));
+
+        // a batch opening of `num_queries` distinct leaves requires a sibling for every node on
+        // the authentication paths unless the sibling is on a path itself; here, we use the
+        // expected number of such siblings for uniformly random leaves
+        let batch_proof_bytes = |num_leaves: usize, num_queries: usize| {
+            let mut num_nodes = 0.0;
+            let mut num_level_nodes = num_queries as f64;
+            let mut level_size = num_leaves as f64;
+            while level_size > 1.0 {
+                level_size /= 2.0;
+                let num_parents = num_distinct(num_queries, level_size);
+                num_nodes += 2.0 * num_parents - num_level_nodes;
+                num_level_nodes = num_parents;
+            }
+            2 + num_queries + num_nodes as usize * digest_bytes
+        };
+        // queried values are followed by their batch opening proof; both are length-prefixed
+        let queries_bytes = |row_bytes: usize| {
+            8 + num_queries * row_bytes + batch_proof_bytes(lde_domain_size, num_queries)
+        };
+
+        // proof context and the number of unique queries
+        let mut result = Context::new::<B>(trace_info.clone(), self.clone()).to_bytes().len() + 1;
+
+        // commitments to trace segments, constraint evaluations, and FRI layers
+        let num_commitments = trace_info.num_segments() + 1 + num_fri_layers;
+        result += 2 + num_commitments * digest_bytes;
+
+        // trace and constraint queries; constant columns of the main segment are not committed to
+        result += queries_bytes(trace_info.main_trace_commitment_width() * base_bytes);
+        if trace_info.is_multi_segment() {
+            result += queries_bytes(trace_info.aux_segment_width() * ext_bytes);
+        }
+        result += queries_bytes(num_composition_columns * ext_bytes);
+
+        // out-of-domain frame: trace states at z and z * g, and constraint evaluations at z
+        let trace_width = trace_info.main_trace_width() + trace_info.aux_segment_width();
+        result += 6 + 2 * trace_width * ext_bytes + num_composition_columns * ext_bytes;
+
+        // FRI layers: each query opens a coset of `folding_factor` values in every layer, but
+        // queries may fall into the same coset once they are folded
+        result += 1;
+        let mut domain_size = lde_domain_size;
+        for _ in 0..num_fri_layers {
+            let num_leaves = domain_size / folding_factor;
+            let num_layer_queries = num_distinct(num_queries, num_leaves as f64) as usize;
+            result += 8
+                + num_layer_queries * folding_factor * ext_bytes
+                + batch_proof_bytes(num_leaves, num_layer_queries);
+            domain_size = num_leaves;
+        }
+
+        // FRI remainder, number of partitions, proof-of-work nonce, and an empty GKR proof
+        result += 2 + fri_options.fri_remainder_size(lde_domain_size) * ext_bytes + 1;
+        result + 8 + 1
+    }
 }
 
 impl<E: StarkField> ToElements<E> for ProofOptions {
@@ -264,6 +523,34 @@ impl<E: StarkField> ToElements<E> for ProofOptions {
     }
 }
 
+// FIELD ELEMENT ENCODING
+// ================================================================================================
+
+impl ProofOptions {
+    /// Writes these proof options into the `target`.
+    ///
+    /// Each option is written as a single element in the same order as in the byte serialization
+    /// of the options: the number of queries, the blowup factor, the grinding factor, the field
+    /// extension degree, the FRI folding factor, the FRI remainder max degree, the number of
+    /// partitions, and the hash rate.
+    pub(crate) fn write_elements_into<B: StarkField>(&self, target: &mut ElementWriter<B>) {
+        for byte in self.to_bytes() {
+            target.write_u32(byte as u32);
+        }
+    }
+
+    /// Reads proof options written by [ProofOptions::write_elements_into()] from the `source`.
+    pub(crate) fn read_elements_from<B: StarkField>(
+        source: &mut ElementReader<B>,
+    ) -> Result<Self, DeserializationError> {
+        let mut bytes = Vec::with_capacity(8);
+        for _ in 0..8 {
+            bytes.push(source.read_u8()?);
+        }
+        ProofOptions::read_from_bytes(&bytes)
+    }
+}
+
 impl Serializable for ProofOptions {
     /// Serializes `self` and writes the resulting bytes into the `target`.
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
@@ -311,6 +598,7 @@ impl FieldExtension {
             Self::None => 1,
             Self::Quadratic => 2,
             Self::Cubic => 3,
+            Self::Sextic => 6,
         }
     }
 }
@@ -334,6 +622,7 @@ impl Deserializable for FieldExtension {
             1 => Ok(FieldExtension::None),
             2 => Ok(FieldExtension::Quadratic),
             3 => Ok(FieldExtension::Cubic),
+            6 => Ok(FieldExtension::Sextic),
             value => Err(DeserializationError::InvalidValue(format!(
                 "value {value} cannot be deserialized as FieldExtension enum"
             ))),
@@ -411,9 +700,10 @@ impl Default for PartitionOptions {
 
 #[cfg(test)]
 mod tests {
+    use crypto::hashers::Blake3_256;
     use math::fields::{f64::BaseElement, CubeExtension};
 
-    use super::{FieldExtension, PartitionOptions, ProofOptions, ToElements};
+    use super::{FieldExtension, PartitionOptions, ProofOptions, SecurityLevel, ToElements};
 
     #[test]
     fn proof_options_to_elements() {
@@ -448,6 +738,93 @@ mod tests {
         assert_eq!(expected, options.to_elements());
     }
 
+    #[test]
+    fn proof_options_with_grinding_factor() {
+        let options = ProofOptions::new(27, 8, 0, FieldExtension::Cubic, 8, 255);
+        let with_grinding = options.clone().with_grinding_factor(16);
+
+        assert_eq!(16, with_grinding.grinding_factor());
+        assert_eq!(options, with_grinding.with_grinding_factor(0));
+    }
+
+    #[test]
+    fn proof_options_min_grinding_factor() {
+        type H = Blake3_256<BaseElement>;
+        let trace_length = 1 << 20;
+
+        // 27 queries with blowup factor 8 provide 81 bits of conjectured security, and thus, 20
+        // bits of grinding are needed to reach 100 bits of security
+        let options = ProofOptions::new(27, 8, 0, FieldExtension::Cubic, 8, 255);
+        assert_eq!(
+            Some(20),
+            options.min_grinding_factor::<BaseElement, H>(trace_length, 100, true)
+        );
+        assert_eq!(Some(0), options.min_grinding_factor::<BaseElement, H>(trace_length, 80, true));
+
+        // grinding factor of the options is ignored
+        let with_grinding = options.clone().with_grinding_factor(32);
+        assert_eq!(
+            Some(20),
+            with_grinding.min_grinding_factor::<BaseElement, H>(trace_length, 100, true)
+        );
+
+        // security level beyond the collision resistance of the hash function cannot be reached
+        assert_eq!(None, options.min_grinding_factor::<BaseElement, H>(trace_length, 130, true));
+
+        // proven security is lower than conjectured security, and thus, more grinding is needed
+        // to reach the same security level
+        assert_eq!(
+            Some(22),
+            options.min_grinding_factor::<BaseElement, H>(trace_length, 60, false)
+        );
+        assert_eq!(None, options.min_grinding_factor::<BaseElement, H>(trace_length, 80, false));
+    }
+
+    #[test]
+    fn proof_options_security_ordering() {
+        type H = Blake3_256<BaseElement>;
+        let trace_length = 1 << 20;
+
+        let weak = ProofOptions::new(27, 8, 0, FieldExtension::Cubic, 8, 255);
+        let strong = ProofOptions::new(27, 8, 20, FieldExtension::Cubic, 8, 255);
+        let same_as_strong = ProofOptions::new(27, 8, 20, FieldExtension::Cubic, 4, 127);
+
+        let level = weak.security_level::<BaseElement, H>(trace_length);
+        assert_eq!(80, level.conjectured);
+        assert!(level.proven < level.conjectured);
+
+        assert!(
+            weak.security_level::<BaseElement, H>(trace_length)
+                < strong.security_level::<BaseElement, H>(trace_length)
+        );
+        assert_eq!(
+            core::cmp::Ordering::Less,
+            weak.cmp_security::<BaseElement, H>(&strong, trace_length)
+        );
+        assert_eq!(
+            core::cmp::Ordering::Equal,
+            strong.cmp_security::<BaseElement, H>(&same_as_strong, trace_length)
+        );
+
+        // the strongest options are selected; ties are resolved in favor of the first options
+        let options = [weak.clone(), same_as_strong.clone(), strong.clone()];
+        assert_eq!(
+            Some(strong.security_level::<BaseElement, H>(trace_length)),
+            ProofOptions::max_security_of::<BaseElement, H>(&options, trace_length)
+        );
+        assert_eq!(
+            Some(&same_as_strong),
+            ProofOptions::strongest_of::<BaseElement, H>(&options, trace_length)
+        );
+        assert_eq!(None, ProofOptions::max_security_of::<BaseElement, H>(&[], trace_length));
+        assert_eq!(None, ProofOptions::strongest_of::<BaseElement, H>(&[], trace_length));
+
+        // conjectured security takes precedence over proven security
+        let a = SecurityLevel { conjectured: 100, proven: 50 };
+        let b = SecurityLevel { conjectured: 99, proven: 90 };
+        assert!(a > b);
+    }
+
     #[test]
     fn correct_partition_sizes() {
         type E1 = BaseElement;
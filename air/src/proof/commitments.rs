@@ -5,11 +5,13 @@
 
 use alloc::vec::Vec;
 
-use crypto::Hasher;
+use crypto::{AlgebraicSponge, Hasher};
 use utils::{
     ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable, SliceReader,
 };
 
+use super::elements::{ElementReader, ElementWriter};
+
 // COMMITMENTS
 // ================================================================================================
 /// Commitments made by the prover during commit phase of the protocol.
@@ -85,6 +87,111 @@ impl Commitments {
         }
         Ok((trace_commitments, constraint_commitment, fri_commitments))
     }
+
+    /// Parses only the extended execution trace commitments from the serialized commitments.
+    ///
+    /// The commitments are returned in the order of trace segments (i.e., the main trace segment
+    /// commitment first, followed by auxiliary trace segment commitments). Commitments which
+    /// follow the trace commitments are not parsed, and thus, are not validated.
+    ///
+    /// # Errors
+    /// Returns an error if the bytes stored in self could not be parsed into the requested number
+    /// of trace commitments.
+    pub fn parse_trace_commitments<H: Hasher>(
+        &self,
+        num_trace_segments: usize,
+    ) -> Result<Vec<H::Digest>, DeserializationError> {
+        SliceReader::new(&self.0).read_many(num_trace_segments)
+    }
+
+    /// Parses the serialized commitments into distinct parts for a proof in which the auxiliary
+    /// trace segment was committed to using hash function `AH`, while all other commitments were
+    /// made using hash function `H`.
+    ///
+    /// The parts are (in the order in which they appear in the tuple):
+    /// 1. Main trace segment commitment.
+    /// 2. Auxiliary trace segment commitment, or None if `num_trace_segments` is 1.
+    /// 3. Constraint composition polynomial evaluation commitment.
+    /// 4. FRI layer commitments.
+    ///
+    /// # Errors
+    /// Returns an error if the bytes stored in self could not be parsed into the requested number
+    /// of commitments, or if there are any unconsumed bytes remaining after the parsing completes.
+    #[allow(clippy::type_complexity)]
+    pub fn parse_with_aux_hash<H: Hasher, AH: Hasher>(
+        self,
+        num_trace_segments: usize,
+        num_fri_layers: usize,
+    ) -> Result<(H::Digest, Option<AH::Digest>, H::Digest, Vec<H::Digest>), DeserializationError>
+    {
+        let mut reader = SliceReader::new(&self.0);
+
+        // parse trace commitments; there can be at most one auxiliary trace segment
+        let main_trace_commitment = reader.read()?;
+        let aux_trace_commitment = match num_trace_segments {
+            1 => None,
+            2 => Some(reader.read()?),
+            _ => {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "expected at most 2 trace segments, but {num_trace_segments} were specified"
+                )))
+            },
+        };
+
+        // parse constraint evaluation commitment:
+        let constraint_commitment = reader.read()?;
+
+        // read FRI commitments (+ 1 for remainder polynomial commitment)
+        let fri_commitments = reader.read_many(num_fri_layers + 1)?;
+
+        // make sure we consumed all available commitment bytes
+        if reader.has_more_bytes() {
+            return Err(DeserializationError::UnconsumedBytes);
+        }
+        Ok((
+            main_trace_commitment,
+            aux_trace_commitment,
+            constraint_commitment,
+            fri_commitments,
+        ))
+    }
+}
+
+// FIELD ELEMENT ENCODING
+// ================================================================================================
+
+impl Commitments {
+    /// Writes these commitments into the `target` as a sequence of digests.
+    ///
+    /// # Panics
+    /// Panics if these commitments cannot be read as a sequence of digests of hash function `H`.
+    pub(crate) fn write_elements_into<H: AlgebraicSponge>(
+        &self,
+        target: &mut ElementWriter<H::BaseField>,
+    ) {
+        let mut reader = SliceReader::new(&self.0);
+        let mut digests = Vec::new();
+        while reader.has_more_bytes() {
+            digests.push(reader.read::<H::Digest>().expect("commitments are not valid digests"));
+        }
+        target.write_digests::<H>(&digests);
+    }
+
+    /// Reads commitments written by [Commitments::write_elements_into()] from the `source`.
+    pub(crate) fn read_elements_from<H: AlgebraicSponge>(
+        source: &mut ElementReader<H::BaseField>,
+    ) -> Result<Self, DeserializationError> {
+        let mut bytes = Vec::new();
+        bytes.write_many(source.read_digests::<H>()?);
+        if bytes.len() >= u16::MAX as usize {
+            return Err(DeserializationError::InvalidValue(format!(
+                "{} bytes of commitments exceed the maximum of {} bytes",
+                bytes.len(),
+                u16::MAX - 1
+            )));
+        }
+        Ok(Commitments(bytes))
+    }
 }
 
 // SERIALIZATION
@@ -5,11 +5,44 @@
 
 use alloc::{string::ToString, vec::Vec};
 
+use crypto::Hasher;
 use math::{StarkField, ToElements};
 use utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 
+use super::{
+    elements::{ElementReader, ElementWriter},
+    get_conjectured_security, get_proven_security,
+};
 use crate::{ProofOptions, TraceInfo};
 
+// CONSTANTS
+// ================================================================================================
+
+/// Magic bytes with which a serialized proof context (and thus, a serialized proof) starts.
+pub const PROOF_MAGIC: [u8; 3] = *b"WNF";
+
+/// Version of the proof serialization format.
+///
+/// The version is written right after the [PROOF_MAGIC] bytes, and is incremented every time the
+/// serialization format of proofs changes. Proofs serialized using a different version of the
+/// format are rejected during deserialization;
+/// [Proof::read_format_version()](super::Proof::read_format_version) can be used to determine the
+/// version of a serialized proof (e.g., to route it to a decoder which was used to serialize it).
+pub const PROOF_FORMAT_VERSION: u8 = 1;
+
+/// Flag set in the serialized context if the context specifies a hash function identifier.
+pub const CONTEXT_FLAG_HASH_ID: u8 = 1 << 0;
+
+/// Flag set in the serialized context if the context specifies a proof-of-work identifier.
+pub const CONTEXT_FLAG_POW_ID: u8 = 1 << 1;
+
+/// Flag set in the serialized context if the context specifies an auxiliary segment hash function
+/// identifier.
+pub const CONTEXT_FLAG_AUX_HASH_ID: u8 = 1 << 2;
+
+/// All flags which can be set in the serialized context.
+const CONTEXT_FLAGS: u8 = CONTEXT_FLAG_HASH_ID | CONTEXT_FLAG_POW_ID | CONTEXT_FLAG_AUX_HASH_ID;
+
 // PROOF CONTEXT
 // ================================================================================================
 /// Basic metadata about a specific execution of a computation.
@@ -18,6 +51,9 @@ pub struct Context {
     trace_info: TraceInfo,
     field_modulus_bytes: Vec<u8>,
     options: ProofOptions,
+    hash_id: Option<u8>,
+    pow_id: Option<u8>,
+    aux_hash_id: Option<u8>,
 }
 
 impl Context {
@@ -42,9 +78,49 @@ impl Context {
             trace_info,
             field_modulus_bytes: B::get_modulus_le_bytes(),
             options,
+            hash_id: None,
+            pow_id: None,
+            aux_hash_id: None,
         }
     }
 
+    /// Returns this context with the identifier of the hash function used to generate the proof
+    /// set to the specified `hash_id`.
+    ///
+    /// The identifier is chosen by the application, and is bound to the proof in the same way as
+    /// the rest of the context. It is not interpreted by the prover or the verifier, but it can be
+    /// used to select the hash function with which a proof should be verified (e.g., via
+    /// `HashRegistry` of the verifier crate).
+    pub fn with_hash_id(mut self, hash_id: u8) -> Self {
+        self.hash_id = Some(hash_id);
+        self
+    }
+
+    /// Returns this context with the identifier of the proof-of-work algorithm used to generate
+    /// the proof set to the specified `pow_id`.
+    ///
+    /// Similarly to the hash function identifier, the proof-of-work identifier is chosen by the
+    /// application and is bound to the proof in the same way as the rest of the context. The
+    /// verifier uses it to make sure that the proof-of-work is checked using the same algorithm
+    /// as the one used by the prover.
+    pub fn with_pow_id(mut self, pow_id: u8) -> Self {
+        self.pow_id = Some(pow_id);
+        self
+    }
+
+    /// Returns this context with the identifier of the hash function used to commit to the
+    /// auxiliary trace segment set to the specified `aux_hash_id`.
+    ///
+    /// The identifier is chosen by the application in the same way as the hash function
+    /// identifier, and should be specified only for proofs in which the auxiliary trace segment
+    /// is committed to using a different hash function than the rest of the proof. The verifier
+    /// uses it to make sure that the auxiliary segment is checked using the matching hash
+    /// function.
+    pub fn with_aux_hash_id(mut self, aux_hash_id: u8) -> Self {
+        self.aux_hash_id = Some(aux_hash_id);
+        self
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -84,6 +160,48 @@ impl Context {
     pub fn options(&self) -> &ProofOptions {
         &self.options
     }
+
+    /// Returns the identifier of the hash function used to generate a proof in this context, or
+    /// None if the identifier was not specified.
+    pub fn hash_id(&self) -> Option<u8> {
+        self.hash_id
+    }
+
+    /// Returns the identifier of the proof-of-work algorithm used to generate a proof in this
+    /// context, or None if the default algorithm was used.
+    pub fn pow_id(&self) -> Option<u8> {
+        self.pow_id
+    }
+
+    /// Returns the identifier of the hash function used to commit to the auxiliary trace segment
+    /// of a proof in this context, or None if the auxiliary segment was committed to using the
+    /// same hash function as the rest of the proof.
+    pub fn aux_hash_id(&self) -> Option<u8> {
+        self.aux_hash_id
+    }
+
+    /// Returns security level (in bits) of a proof generated in this context.
+    ///
+    /// When `conjectured` is true, conjectured security level is returned; otherwise, provable
+    /// security level is returned. See [Proof::security_level()](super::Proof::security_level)
+    /// for more info.
+    pub fn security_level<H: Hasher>(&self, conjectured: bool) -> u32 {
+        if conjectured {
+            get_conjectured_security(
+                &self.options,
+                self.num_modulus_bits(),
+                self.trace_info.length(),
+                H::COLLISION_RESISTANCE,
+            )
+        } else {
+            get_proven_security(
+                &self.options,
+                self.num_modulus_bits(),
+                self.trace_info.length(),
+                H::COLLISION_RESISTANCE,
+            )
+        }
+    }
 }
 
 impl<E: StarkField> ToElements<E> for Context {
@@ -96,6 +214,10 @@ impl<E: StarkField> ToElements<E> for Context {
     /// - grinding factor [1 element].
     /// - blowup factor [1 element].
     /// - number of queries [1 element].
+    /// - hash function identifier [1 element], only if the identifier is specified.
+    /// - proof-of-work identifier offset by 2^8 [1 element], only if the identifier is specified.
+    /// - auxiliary segment hash function identifier offset by 2^9 [1 element], only if the
+    ///   identifier is specified.
     fn to_elements(&self) -> Vec<E> {
         // convert trace layout
         let mut result = self.trace_info.to_elements();
@@ -109,21 +231,107 @@ impl<E: StarkField> ToElements<E> for Context {
         // convert proof options to elements
         result.append(&mut self.options.to_elements());
 
+        if let Some(hash_id) = self.hash_id {
+            result.push(E::from(hash_id as u32));
+        }
+
+        // the proof-of-work identifier is offset by 2^8 so that it cannot be confused with the
+        // hash function identifier
+        if let Some(pow_id) = self.pow_id {
+            result.push(E::from(pow_id as u32 + 256));
+        }
+
+        // similarly, the auxiliary segment hash function identifier is offset by 2^9
+        if let Some(aux_hash_id) = self.aux_hash_id {
+            result.push(E::from(aux_hash_id as u32 + 512));
+        }
+
         result
     }
 }
 
+// FIELD ELEMENT ENCODING
+// ================================================================================================
+
+impl Context {
+    /// Writes this context into the `target`.
+    ///
+    /// The context is laid out as the trace info, the proof options, and the hash function,
+    /// proof-of-work, and auxiliary segment hash function identifiers; an identifier is written as
+    /// zero if it is not specified, or as one followed by the identifier otherwise. The field
+    /// modulus is not written since it is implied by the field of the `target`.
+    pub(crate) fn write_elements_into<B: StarkField>(&self, target: &mut ElementWriter<B>) {
+        self.trace_info.write_elements_into(target);
+        self.options.write_elements_into(target);
+        for id in [self.hash_id, self.pow_id, self.aux_hash_id] {
+            match id {
+                Some(id) => {
+                    target.write_u32(1);
+                    target.write_u32(id as u32);
+                },
+                None => target.write_u32(0),
+            }
+        }
+    }
+
+    /// Reads a context written by [Context::write_elements_into()] from the `source`.
+    pub(crate) fn read_elements_from<B: StarkField>(
+        source: &mut ElementReader<B>,
+    ) -> Result<Self, DeserializationError> {
+        let trace_info = TraceInfo::read_elements_from(source)?;
+        let options = ProofOptions::read_elements_from(source)?;
+        let mut read_id = || match source.read_u32()? {
+            0 => Ok(None),
+            1 => Ok(Some(source.read_u8()?)),
+            flag => {
+                Err(DeserializationError::InvalidValue(format!("invalid identifier flag {flag}")))
+            },
+        };
+        let hash_id = read_id()?;
+        let pow_id = read_id()?;
+        let aux_hash_id = read_id()?;
+
+        Ok(Context {
+            trace_info,
+            field_modulus_bytes: B::get_modulus_le_bytes(),
+            options,
+            hash_id,
+            pow_id,
+            aux_hash_id,
+        })
+    }
+}
+
 // SERIALIZATION
 // ================================================================================================
 
 impl Serializable for Context {
     /// Serializes `self` and writes the resulting bytes into the `target`.
+    ///
+    /// The serialized context starts with [PROOF_MAGIC] bytes followed by [PROOF_FORMAT_VERSION].
+    /// The proof options are followed by a flags byte marking which of the optional identifiers
+    /// are specified ([CONTEXT_FLAG_HASH_ID], [CONTEXT_FLAG_POW_ID], and
+    /// [CONTEXT_FLAG_AUX_HASH_ID]), and then by a single byte for each of the specified
+    /// identifiers in the same order.
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_bytes(&PROOF_MAGIC);
+        target.write_u8(PROOF_FORMAT_VERSION);
         self.trace_info.write_into(target);
         assert!(self.field_modulus_bytes.len() < u8::MAX as usize);
         target.write_u8(self.field_modulus_bytes.len() as u8);
         target.write_bytes(&self.field_modulus_bytes);
         self.options.write_into(target);
+
+        let ids = [
+            (CONTEXT_FLAG_HASH_ID, self.hash_id),
+            (CONTEXT_FLAG_POW_ID, self.pow_id),
+            (CONTEXT_FLAG_AUX_HASH_ID, self.aux_hash_id),
+        ];
+        let flags = ids.iter().filter(|(_, id)| id.is_some()).fold(0, |acc, (flag, _)| acc | flag);
+        target.write_u8(flags);
+        for id in ids.into_iter().filter_map(|(_, id)| id) {
+            target.write_u8(id);
+        }
     }
 }
 
@@ -132,7 +340,15 @@ impl Deserializable for Context {
     ///
     /// # Errors
     /// Returns an error of a valid Context struct could not be read from the specified `source`.
+    /// In particular, [DeserializationError::UnsupportedVersion] is returned if the context was
+    /// serialized using a format version other than [PROOF_FORMAT_VERSION].
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        // read and validate magic bytes and format version
+        let version = read_format_version(source)?;
+        if version != PROOF_FORMAT_VERSION {
+            return Err(DeserializationError::UnsupportedVersion(version));
+        }
+
         // read and validate trace info
         let trace_info = TraceInfo::read_from(source)?;
 
@@ -148,8 +364,47 @@ impl Deserializable for Context {
         // read options
         let options = ProofOptions::read_from(source)?;
 
-        Ok(Context { trace_info, field_modulus_bytes, options })
+        // read hash function, proof-of-work, and auxiliary segment hash function identifiers
+        let flags = source.read_u8()?;
+        if flags & !CONTEXT_FLAGS != 0 {
+            return Err(DeserializationError::InvalidValue(format!(
+                "invalid proof context flags {flags:#010b}"
+            )));
+        }
+        let mut read_id = |flag: u8| match flags & flag {
+            0 => Ok(None),
+            _ => source.read_u8().map(Some),
+        };
+        let hash_id = read_id(CONTEXT_FLAG_HASH_ID)?;
+        let pow_id = read_id(CONTEXT_FLAG_POW_ID)?;
+        let aux_hash_id = read_id(CONTEXT_FLAG_AUX_HASH_ID)?;
+
+        Ok(Context {
+            trace_info,
+            field_modulus_bytes,
+            options,
+            hash_id,
+            pow_id,
+            aux_hash_id,
+        })
+    }
+}
+
+/// Reads [PROOF_MAGIC] bytes followed by the format version from the specified `source`, and
+/// returns the version.
+///
+/// # Errors
+/// Returns an error if the `source` does not start with [PROOF_MAGIC] bytes.
+pub(super) fn read_format_version<R: ByteReader>(
+    source: &mut R,
+) -> Result<u8, DeserializationError> {
+    let magic = source.read_array::<3>()?;
+    if magic != PROOF_MAGIC {
+        return Err(DeserializationError::InvalidValue(
+            "proof context does not start with the expected magic bytes".to_string(),
+        ));
     }
+    source.read_u8()
 }
 
 // TESTS
@@ -217,5 +472,31 @@ mod tests {
             TraceInfo::new_multi_segment(main_width, aux_width, aux_rands, trace_length, vec![]);
         let context = Context::new::<BaseElement>(trace_info, options);
         assert_eq!(expected, context.to_elements());
+
+        // the hash function identifier, when specified, is appended to the elements
+        let mut expected_with_hash_id = expected.clone();
+        expected_with_hash_id.push(BaseElement::from(7_u32));
+        assert_eq!(expected_with_hash_id, context.clone().with_hash_id(7).to_elements());
+
+        // the proof-of-work identifier is appended after it, and is distinct from a hash function
+        // identifier with the same value
+        let mut expected_with_pow_id = expected.clone();
+        expected_with_pow_id.push(BaseElement::from(7_u32 + 256));
+        assert_eq!(expected_with_pow_id, context.clone().with_pow_id(7).to_elements());
+        expected_with_hash_id.push(BaseElement::from(3_u32 + 256));
+        assert_eq!(
+            expected_with_hash_id,
+            context.clone().with_hash_id(7).with_pow_id(3).to_elements()
+        );
+
+        // the auxiliary segment hash function identifier is appended last
+        let mut expected_with_aux_hash_id = expected;
+        expected_with_aux_hash_id.push(BaseElement::from(7_u32 + 512));
+        assert_eq!(expected_with_aux_hash_id, context.clone().with_aux_hash_id(7).to_elements());
+        expected_with_hash_id.push(BaseElement::from(5_u32 + 512));
+        assert_eq!(
+            expected_with_hash_id,
+            context.with_hash_id(7).with_pow_id(3).with_aux_hash_id(5).to_elements()
+        );
     }
 }
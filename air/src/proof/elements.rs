@@ -0,0 +1,313 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::{format, vec::Vec};
+
+use crypto::{AlgebraicSponge, BatchMerkleProof};
+use fri::FriProof;
+use math::StarkField;
+use utils::{
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable, SliceReader,
+};
+
+// ELEMENT WRITER
+// ================================================================================================
+
+/// Encodes proof components into a sequence of base field elements.
+///
+/// Four kinds of values are supported:
+/// * Integers, which are encoded as a single element (or as two 32-bit limbs for `u64` values).
+/// * Field element values, which are encoded as the number of base field elements followed by
+///   the elements themselves. Elements of extension fields are encoded as their base field
+///   coefficients.
+/// * Digests of an algebraic hash function, which are encoded as the field elements of which the
+///   digests consist (see [AlgebraicSponge::digest_as_elements()]).
+/// * Opaque bytes (e.g., trace metadata), which are encoded as the number of bytes followed by the
+///   bytes packed into elements; see [bytes_per_element()] for details.
+pub(crate) struct ElementWriter<B: StarkField> {
+    elements: Vec<B>,
+}
+
+impl<B: StarkField> ElementWriter<B> {
+    pub fn new() -> Self {
+        Self { elements: Vec::new() }
+    }
+
+    pub fn into_elements(self) -> Vec<B> {
+        self.elements
+    }
+
+    /// Writes a single base field element.
+    pub fn write_element(&mut self, value: B) {
+        self.elements.push(value);
+    }
+
+    /// Writes a single integer.
+    pub fn write_u32(&mut self, value: u32) {
+        self.elements.push(B::from(value));
+    }
+
+    /// Writes a length or a count.
+    ///
+    /// # Panics
+    /// Panics if `value` does not fit into 32 bits.
+    pub fn write_len(&mut self, value: usize) {
+        self.write_u32(value.try_into().expect("length does not fit into 32 bits"));
+    }
+
+    /// Writes a 64-bit integer as two elements: the low 32 bits followed by the high 32 bits.
+    pub fn write_u64(&mut self, value: u64) {
+        self.write_u32(value as u32);
+        self.write_u32((value >> 32) as u32);
+    }
+
+    /// Writes field element values serialized into `bytes`.
+    ///
+    /// # Panics
+    /// Panics if `bytes` cannot be read as a sequence of elements of field `B`.
+    pub fn write_values(&mut self, bytes: &[u8]) {
+        assert_eq!(
+            bytes.len() % B::ELEMENT_BYTES,
+            0,
+            "number of value bytes must be a multiple of the base field element size"
+        );
+        let num_values = bytes.len() / B::ELEMENT_BYTES;
+        self.write_len(num_values);
+        let values = SliceReader::new(bytes)
+            .read_many::<B>(num_values)
+            .expect("values are not valid base field elements");
+        self.elements.extend_from_slice(&values);
+    }
+
+    /// Writes opaque `bytes`.
+    pub fn write_bytes(&mut self, bytes: &[u8]) {
+        self.write_len(bytes.len());
+        for chunk in bytes.chunks(bytes_per_element::<B>()) {
+            self.elements.push(B::from_bytes_with_padding(chunk));
+        }
+    }
+
+    /// Writes the number of `digests` followed by the elements of each digest.
+    pub fn write_digests<H: AlgebraicSponge<BaseField = B>>(&mut self, digests: &[H::Digest]) {
+        self.write_len(digests.len());
+        for digest in digests.iter() {
+            self.elements.extend_from_slice(H::digest_as_elements(digest));
+        }
+    }
+
+    /// Writes a batch Merkle proof serialized into `bytes`.
+    ///
+    /// The proof is laid out as the depth of the tree and the number of node vectors, followed by
+    /// the nodes of each vector (as digests).
+    ///
+    /// # Panics
+    /// Panics if `bytes` cannot be read as a batch Merkle proof for hash function `H`.
+    pub fn write_batch_proof<H: AlgebraicSponge<BaseField = B>>(&mut self, bytes: &[u8]) {
+        let proof = BatchMerkleProof::<H>::read_from_bytes(bytes)
+            .expect("opening proof is not a valid batch Merkle proof");
+        self.write_u32(proof.depth as u32);
+        self.write_len(proof.nodes.len());
+        for nodes in proof.nodes.iter() {
+            self.write_digests::<H>(nodes);
+        }
+    }
+}
+
+// ELEMENT READER
+// ================================================================================================
+
+/// Decodes proof components from a sequence of base field elements written by an
+/// [ElementWriter].
+pub(crate) struct ElementReader<'a, B: StarkField> {
+    source: &'a [B],
+    pos: usize,
+}
+
+impl<'a, B: StarkField> ElementReader<'a, B> {
+    pub fn new(source: &'a [B]) -> Self {
+        Self { source, pos: 0 }
+    }
+
+    /// Returns an error if not all elements of the source have been read.
+    pub fn check_eor(&self) -> Result<(), DeserializationError> {
+        if self.pos != self.source.len() {
+            return Err(DeserializationError::UnconsumedBytes);
+        }
+        Ok(())
+    }
+
+    pub fn read_u32(&mut self) -> Result<u32, DeserializationError> {
+        let bytes = self.read_element()?.to_le_bytes();
+        let (value, rest) = bytes.split_at(4);
+        if rest.iter().any(|&byte| byte != 0) {
+            return Err(DeserializationError::InvalidValue(
+                "element does not encode a 32-bit integer".into(),
+            ));
+        }
+        Ok(u32::from_le_bytes(value.try_into().expect("slice is 4 bytes long")))
+    }
+
+    pub fn read_u8(&mut self) -> Result<u8, DeserializationError> {
+        let value = self.read_u32()?;
+        value.try_into().map_err(|_| {
+            DeserializationError::InvalidValue(format!("value {value} does not fit into 8 bits"))
+        })
+    }
+
+    /// Reads a length or a count, and checks that it does not exceed `max`.
+    pub fn read_len(&mut self, max: usize) -> Result<usize, DeserializationError> {
+        let len = self.read_u32()? as usize;
+        if len > max {
+            return Err(DeserializationError::InvalidValue(format!(
+                "length {len} exceeds the maximum of {max}"
+            )));
+        }
+        Ok(len)
+    }
+
+    pub fn read_u64(&mut self) -> Result<u64, DeserializationError> {
+        let low = self.read_u32()? as u64;
+        let high = self.read_u32()? as u64;
+        Ok(low | (high << 32))
+    }
+
+    /// Reads field element values and returns their serialized bytes.
+    pub fn read_values(&mut self) -> Result<Vec<u8>, DeserializationError> {
+        let num_values = self.read_len(self.remaining())?;
+        let mut result = Vec::with_capacity(num_values * B::ELEMENT_BYTES);
+        for _ in 0..num_values {
+            result.write(self.read_element()?);
+        }
+        Ok(result)
+    }
+
+    /// Reads opaque bytes.
+    pub fn read_bytes(&mut self) -> Result<Vec<u8>, DeserializationError> {
+        let bytes_per_element = bytes_per_element::<B>();
+        let num_bytes = self.read_len(self.remaining().saturating_mul(bytes_per_element))?;
+        let mut result = Vec::with_capacity(num_bytes);
+        while result.len() < num_bytes {
+            let chunk_len = bytes_per_element.min(num_bytes - result.len());
+            let bytes = self.read_element()?.to_le_bytes();
+            let (chunk, padding) = bytes.split_at(chunk_len);
+            if padding.iter().any(|&byte| byte != 0) {
+                return Err(DeserializationError::InvalidValue(
+                    "element does not encode a chunk of bytes".into(),
+                ));
+            }
+            result.extend_from_slice(chunk);
+        }
+        Ok(result)
+    }
+
+    /// Reads digests written by [ElementWriter::write_digests()].
+    pub fn read_digests<H: AlgebraicSponge<BaseField = B>>(
+        &mut self,
+    ) -> Result<Vec<H::Digest>, DeserializationError> {
+        let num_digests = self.read_len(self.remaining() / H::DIGEST_SIZE)?;
+        let mut result = Vec::with_capacity(num_digests);
+        for _ in 0..num_digests {
+            let elements = &self.source[self.pos..self.pos + H::DIGEST_SIZE];
+            self.pos += H::DIGEST_SIZE;
+            result.push(H::digest_from_elements(elements).expect("slice has the digest size"));
+        }
+        Ok(result)
+    }
+
+    /// Reads a batch Merkle proof written by [ElementWriter::write_batch_proof()] and returns its
+    /// serialized bytes.
+    pub fn read_batch_proof<H: AlgebraicSponge<BaseField = B>>(
+        &mut self,
+    ) -> Result<Vec<u8>, DeserializationError> {
+        let depth = self.read_u8()?;
+        let num_node_vectors = self.read_len(self.remaining())?;
+        let mut nodes = Vec::with_capacity(num_node_vectors);
+        for _ in 0..num_node_vectors {
+            nodes.push(self.read_digests::<H>()?);
+        }
+        Ok(BatchMerkleProof::<H> { nodes, depth }.to_bytes())
+    }
+
+    pub fn read_element(&mut self) -> Result<B, DeserializationError> {
+        let element = self.source.get(self.pos).ok_or(DeserializationError::UnexpectedEOF)?;
+        self.pos += 1;
+        Ok(*element)
+    }
+
+    fn remaining(&self) -> usize {
+        self.source.len() - self.pos
+    }
+}
+
+// FRI PROOF
+// ================================================================================================
+
+/// Writes the specified FRI proof into the `target`.
+///
+/// The proof is laid out as the number of layers, followed by the query values (as field element
+/// values) and the batch Merkle proof of each layer, the remainder polynomial coefficients (as
+/// field element values), and the log2 of the number of partitions.
+pub(crate) fn write_fri_proof<H: AlgebraicSponge>(
+    proof: &FriProof,
+    target: &mut ElementWriter<H::BaseField>,
+) {
+    // the components of a FRI proof are accessed via its byte serialization
+    let bytes = utils::Serializable::to_bytes(proof);
+    let mut source = SliceReader::new(&bytes);
+    let mut read_fri_proof_bytes = || -> Result<(), DeserializationError> {
+        let num_layers = source.read_u8()?;
+        target.write_u32(num_layers as u32);
+        for _ in 0..num_layers {
+            let num_value_bytes = source.read_u32()? as usize;
+            target.write_values(&source.read_vec(num_value_bytes)?);
+            let num_path_bytes = source.read_u32()? as usize;
+            target.write_batch_proof::<H>(&source.read_vec(num_path_bytes)?);
+        }
+        let num_remainder_bytes = source.read_u16()? as usize;
+        target.write_values(&source.read_vec(num_remainder_bytes)?);
+        target.write_u32(source.read_u8()? as u32);
+        Ok(())
+    };
+    read_fri_proof_bytes().expect("FRI proof serialization is malformed");
+}
+
+/// Reads a FRI proof written by [write_fri_proof()] from the specified `source`.
+pub(crate) fn read_fri_proof<H: AlgebraicSponge>(
+    source: &mut ElementReader<H::BaseField>,
+) -> Result<FriProof, DeserializationError> {
+    let mut bytes = Vec::new();
+    let num_layers = source.read_u8()?;
+    bytes.write_u8(num_layers);
+    for _ in 0..num_layers {
+        let values = source.read_values()?;
+        bytes.write_u32(values.len() as u32);
+        bytes.write_bytes(&values);
+        let paths = source.read_batch_proof::<H>()?;
+        bytes.write_u32(paths.len() as u32);
+        bytes.write_bytes(&paths);
+    }
+    let remainder = source.read_values()?;
+    let num_remainder_bytes: u16 = remainder
+        .len()
+        .try_into()
+        .map_err(|_| DeserializationError::InvalidValue("FRI remainder is too large".into()))?;
+    bytes.write_u16(num_remainder_bytes);
+    bytes.write_bytes(&remainder);
+    bytes.write_u8(source.read_u8()?);
+
+    FriProof::read_from_bytes(&bytes)
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the number of bytes packed into a single element of field `B`.
+///
+/// This is the largest number of bytes which is guaranteed to encode an integer smaller than the
+/// field modulus: 7 bytes for elements of 62-bit and 64-bit fields, and 15 bytes for elements of
+/// 128-bit fields. Bytes are packed in little-endian order, with the last element zero-padded.
+fn bytes_per_element<B: StarkField>() -> usize {
+    ((B::MODULUS_BITS - 1) / 8) as usize
+}
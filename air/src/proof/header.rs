@@ -0,0 +1,68 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+
+use utils::{ByteReader, Deserializable, DeserializationError};
+
+use super::{read_gkr_proof, Commitments, Context, OodFrame};
+
+// PROOF HEADER
+// ================================================================================================
+
+/// Components of a STARK proof needed by the verifier before it can draw query positions.
+///
+/// When a proof is serialized using the streaming format (see
+/// [Proof::write_streaming_into()](super::Proof::write_streaming_into)), the header is written
+/// first, and is followed by trace and constraint queries and by the FRI proof.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ProofHeader {
+    /// Basic metadata about the execution of the computation described by the proof.
+    pub context: Context,
+    /// Number of unique queries made by the verifier.
+    pub num_unique_queries: u8,
+    /// Commitments made by the prover during the commit phase of the protocol.
+    pub commitments: Commitments,
+    /// Optionally, an auxiliary (non-STARK) proof that was generated during auxiliary trace
+    /// generation.
+    pub gkr_proof: Option<Vec<u8>>,
+    /// Trace and constraint polynomial evaluations at an out-of-domain point.
+    pub ood_frame: OodFrame,
+    /// Number of partitions used when committing to the FRI layers.
+    pub fri_num_partitions: usize,
+    /// Proof-of-work nonce for query seed grinding.
+    pub pow_nonce: u64,
+}
+
+impl Deserializable for ProofHeader {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let context = Context::read_from(source)?;
+        let num_unique_queries = source.read_u8()?;
+        let commitments = Commitments::read_from(source)?;
+        let gkr_proof = read_gkr_proof(source)?;
+        let ood_frame = OodFrame::read_for_trace_from(context.trace_info(), source)?;
+
+        // the number of partitions is stored as a power of 2
+        let fri_num_partitions_log2 = source.read_u8()?;
+        if fri_num_partitions_log2 as u32 >= usize::BITS {
+            return Err(DeserializationError::InvalidValue(format!(
+                "number of FRI partitions cannot be 2^{fri_num_partitions_log2}"
+            )));
+        }
+        let fri_num_partitions = 1 << fri_num_partitions_log2;
+
+        let pow_nonce = source.read_u64()?;
+
+        Ok(ProofHeader {
+            context,
+            num_unique_queries,
+            commitments,
+            gkr_proof,
+            ood_frame,
+            fri_num_partitions,
+            pow_nonce,
+        })
+    }
+}
@@ -6,30 +6,44 @@
 //! Contains STARK proof struct and associated components.
 
 use alloc::vec::Vec;
-use core::cmp;
+use core::{
+    cmp,
+    fmt::{self, Display, Formatter},
+};
 
-use crypto::{Hasher, MerkleTree};
+use crypto::{AlgebraicSponge, ElementHasher, Hasher, MerkleTree};
 use fri::FriProof;
-use math::FieldElement;
-use utils::{ByteReader, Deserializable, DeserializationError, Serializable, SliceReader};
+use math::{FieldElement, StarkField};
+use utils::{
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable, SliceReader,
+};
 
-use crate::{ProofOptions, TraceInfo};
+use crate::{FieldExtension, ProofOptions, TraceInfo};
 
 mod context;
-pub use context::Context;
+pub use context::{
+    Context, CONTEXT_FLAG_AUX_HASH_ID, CONTEXT_FLAG_HASH_ID, CONTEXT_FLAG_POW_ID,
+    PROOF_FORMAT_VERSION, PROOF_MAGIC,
+};
 
 mod commitments;
 pub use commitments::Commitments;
 
 mod queries;
-pub use queries::Queries;
+pub use queries::{Queries, QueryOpening, QueryOpenings};
 
 mod ood_frame;
-pub use ood_frame::{OodFrame, TraceOodFrame};
+pub use ood_frame::{OodEvaluations, OodFrame, TraceOodFrame};
 
 mod table;
 pub use table::Table;
 
+mod header;
+pub use header::ProofHeader;
+
+pub(crate) mod elements;
+use elements::{ElementReader, ElementWriter};
+
 #[cfg(test)]
 mod tests;
 
@@ -39,6 +53,22 @@ mod tests;
 const GRINDING_CONTRIBUTION_FLOOR: u32 = 80;
 const MAX_PROXIMITY_PARAMETER: u64 = 1000;
 
+/// Default maximum size of a decompressed proof (in bytes) accepted by
+/// [Proof::from_bytes_compressed()]; currently set to 16 MB.
+#[cfg(feature = "compression")]
+pub const DEFAULT_MAX_PROOF_SIZE: usize = 1 << 24;
+
+/// Magic bytes at the start of every proof serialized via [Proof::to_bytes_compressed()].
+///
+/// The magic bytes are followed by a single byte identifying the compression method; currently,
+/// the only supported method is [COMPRESSION_METHOD_ZLIB].
+#[cfg(feature = "compression")]
+pub const COMPRESSED_PROOF_MAGIC: [u8; 3] = *b"WNZ";
+
+/// Identifier of the zlib (DEFLATE) compression method used by [Proof::to_bytes_compressed()].
+#[cfg(feature = "compression")]
+pub const COMPRESSION_METHOD_ZLIB: u8 = 1;
+
 // PROOF
 // ================================================================================================
 /// A proof generated by Winterfell prover.
@@ -53,9 +83,21 @@ const MAX_PROXIMITY_PARAMETER: u64 = 1000;
 ///
 /// A proof can be serialized into a sequence of bytes using [to_bytes()](Proof::to_bytes) function,
 /// and deserialized from a sequence of bytes using [from_bytes()](Proof::from_bytes) function.
+/// A proof generated using an algebraic hash function can also be encoded as a sequence of base
+/// field elements using [to_elements()](Proof::to_elements) function (e.g., to be absorbed by a
+/// recursive verifier), and decoded via [from_elements()](Proof::from_elements) function.
+/// To reject untrusted proofs above a given size before parsing them, a proof can be deserialized
+/// via [from_bytes_with_limit()](Proof::from_bytes_with_limit) function.
+/// When the `compression` feature is enabled, a proof can also be serialized into a compressed
+/// sequence of bytes using [to_bytes_compressed()](Proof::to_bytes_compressed) function.
 ///
 /// To estimate soundness of a proof (in bits), [security_level()](Proof::security_level) function
 /// can be used.
+///
+/// A proof also implements [Display] which outputs a one-line summary of the proof's parameters
+/// and size, e.g.: `Proof{trace=8x2^14, ext=quadratic, queries=28, blowup=8, fri_layers=5,
+/// size=42KB}`. Here, trace width includes columns in all trace segments, and size is the size of
+/// the serialized proof rounded up to the nearest kilobyte.
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Proof {
     /// Basic metadata about the execution of the computation described by this proof.
@@ -97,6 +139,187 @@ impl Proof {
         self.context.lde_domain_size()
     }
 
+    /// Returns the out-of-domain evaluations of trace and constraint composition polynomials
+    /// contained in this proof.
+    ///
+    /// `E` must be the field in which the proof was generated (i.e., the extension of the base
+    /// field specified by [ProofOptions::field_extension()]). See [OodFrame::evaluations()] for
+    /// more info.
+    ///
+    /// # Errors
+    /// Returns an error if the out-of-domain frame of this proof could not be parsed.
+    pub fn ood_evaluations<E: FieldElement>(
+        &self,
+    ) -> Result<OodEvaluations<E>, DeserializationError> {
+        self.ood_frame.evaluations(self.trace_info())
+    }
+
+    /// Returns the commitments to the extended execution trace segments contained in this proof.
+    ///
+    /// The commitments are returned in the order of trace segments: the commitment to the main
+    /// trace segment comes first, followed by the commitments to auxiliary trace segments (if
+    /// any). `H` must be the hash function with which the trace was committed to. The commitments
+    /// are read from the proof as is, and thus, matching a commitment against an independently
+    /// computed value does not imply that the proof is valid.
+    ///
+    /// # Errors
+    /// Returns an error if the trace commitments could not be parsed from this proof.
+    pub fn trace_commitments<H: Hasher>(&self) -> Result<Vec<H::Digest>, DeserializationError> {
+        self.commitments.parse_trace_commitments::<H>(self.trace_info().num_segments())
+    }
+
+    // QUERY OPENINGS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns an iterator over the openings of the main trace segment rows queried at the
+    /// specified `positions`.
+    ///
+    /// `H` must be the hash function with which the main trace segment was committed to, and the
+    /// `positions` must be the de-duplicated query positions drawn by the verifier from the public
+    /// coin (in the order in which they were drawn). Constant columns of the trace are not
+    /// committed to, and thus, their values are not included in the openings. The openings are
+    /// read from the proof as is; they are not checked against the trace commitment.
+    ///
+    /// # Errors
+    /// Returns an error if the proof does not contain main trace queries, or if they could not be
+    /// parsed into openings at `positions` (see [Queries::parse_openings()]).
+    ///
+    /// # Panics
+    /// Panics if `positions` is empty.
+    pub fn main_trace_openings<H: ElementHasher>(
+        &self,
+        positions: &[usize],
+    ) -> Result<QueryOpenings<H::BaseField, H>, DeserializationError> {
+        let queries = self.trace_queries.first().ok_or_else(|| {
+            DeserializationError::InvalidValue("proof does not contain main trace queries".into())
+        })?;
+        queries.clone().parse_openings::<H::BaseField, H>(
+            positions,
+            self.lde_domain_size(),
+            self.trace_info().main_trace_commitment_width(),
+            self.options().partition_options(),
+        )
+    }
+
+    /// Returns an iterator over the openings of the auxiliary trace segment rows queried at the
+    /// specified `positions`, or None if the trace of the computation has a single segment.
+    ///
+    /// `E` must be the field in which the proof was generated, and `H` must be the hash function
+    /// with which the auxiliary trace segment was committed to. The `positions` must be the same
+    /// as for [main_trace_openings()](Proof::main_trace_openings).
+    ///
+    /// # Errors
+    /// Returns an error if the proof does not contain auxiliary trace queries, or if they could
+    /// not be parsed into openings at `positions` (see [Queries::parse_openings()]).
+    ///
+    /// # Panics
+    /// Panics if the trace has an auxiliary segment and `positions` is empty.
+    pub fn aux_trace_openings<E, H>(
+        &self,
+        positions: &[usize],
+    ) -> Result<Option<QueryOpenings<E, H>>, DeserializationError>
+    where
+        E: FieldElement,
+        H: ElementHasher<BaseField = E::BaseField>,
+    {
+        if !self.trace_info().is_multi_segment() {
+            return Ok(None);
+        }
+        let queries = self.trace_queries.get(1).ok_or_else(|| {
+            DeserializationError::InvalidValue(
+                "proof does not contain auxiliary trace queries".into(),
+            )
+        })?;
+        let openings = queries.clone().parse_openings::<E, H>(
+            positions,
+            self.lde_domain_size(),
+            self.trace_info().aux_segment_width(),
+            self.options().partition_options(),
+        )?;
+        Ok(Some(openings))
+    }
+
+    /// Returns an iterator over the openings of the constraint composition polynomial columns
+    /// queried at the specified `positions`.
+    ///
+    /// `E` must be the field in which the proof was generated, and `H` must be the hash function
+    /// with which the constraint evaluations were committed to. The `positions` must be the same
+    /// as for [main_trace_openings()](Proof::main_trace_openings). The number of constraint
+    /// composition columns is not recorded in the proof, and thus, it is inferred from the size
+    /// of the queried values.
+    ///
+    /// # Errors
+    /// Returns an error if `positions` is empty, or if the constraint queries could not be parsed
+    /// into openings at `positions` (see [Queries::parse_openings()]).
+    pub fn constraint_openings<E, H>(
+        &self,
+        positions: &[usize],
+    ) -> Result<QueryOpenings<E, H>, DeserializationError>
+    where
+        E: FieldElement,
+        H: ElementHasher<BaseField = E::BaseField>,
+    {
+        let values_per_query =
+            self.constraint_queries.num_values_per_query::<E>(positions.len())?;
+        self.constraint_queries.clone().parse_openings::<E, H>(
+            positions,
+            self.lde_domain_size(),
+            values_per_query,
+            self.options().partition_options(),
+        )
+    }
+
+    // PROOF PARTS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new proof assembled from the specified components.
+    ///
+    /// This is the inverse of [into_parts()](Proof::into_parts). No validation of the components
+    /// against each other is performed here; consistency of the assembled proof is checked by the
+    /// verifier.
+    pub fn from_parts(parts: ProofParts) -> Self {
+        let ProofParts {
+            context,
+            num_unique_queries,
+            commitments,
+            trace_queries,
+            constraint_queries,
+            ood_frame,
+            fri_proof,
+            pow_nonce,
+            gkr_proof,
+        } = parts;
+
+        Self {
+            context,
+            num_unique_queries,
+            commitments,
+            trace_queries,
+            constraint_queries,
+            ood_frame,
+            fri_proof,
+            pow_nonce,
+            gkr_proof,
+        }
+    }
+
+    /// Consumes this proof and returns its components.
+    ///
+    /// A proof can be re-assembled from these components via [from_parts()](Proof::from_parts).
+    pub fn into_parts(self) -> ProofParts {
+        ProofParts {
+            context: self.context,
+            num_unique_queries: self.num_unique_queries,
+            commitments: self.commitments,
+            trace_queries: self.trace_queries,
+            constraint_queries: self.constraint_queries,
+            ood_frame: self.ood_frame,
+            fri_proof: self.fri_proof,
+            pow_nonce: self.pow_nonce,
+            gkr_proof: self.gkr_proof,
+        }
+    }
+
     // SECURITY LEVEL
     // --------------------------------------------------------------------------------------------
     /// Returns security level of this proof (in bits).
@@ -106,21 +329,7 @@ impl Proof {
     /// 2x - 3x higher than the number of queries needed for conjectured security at the same
     /// security level.
     pub fn security_level<H: Hasher>(&self, conjectured: bool) -> u32 {
-        if conjectured {
-            get_conjectured_security(
-                self.context.options(),
-                self.context.num_modulus_bits(),
-                self.trace_info().length(),
-                H::COLLISION_RESISTANCE,
-            )
-        } else {
-            get_proven_security(
-                self.context.options(),
-                self.context.num_modulus_bits(),
-                self.trace_info().length(),
-                H::COLLISION_RESISTANCE,
-            )
-        }
+        self.context.security_level::<H>(conjectured)
     }
 
     // SERIALIZATION / DESERIALIZATION
@@ -131,6 +340,57 @@ impl Proof {
         Serializable::to_bytes(self)
     }
 
+    /// Serializes this proof into a vector of bytes using the streaming format.
+    ///
+    /// See [write_streaming_into()](Proof::write_streaming_into) for details.
+    pub fn to_streaming_bytes(&self) -> Vec<u8> {
+        let mut result = Vec::new();
+        self.write_streaming_into(&mut result);
+        result
+    }
+
+    /// Serializes this proof using the streaming format and writes the resulting bytes into the
+    /// specified `target`.
+    ///
+    /// In the streaming format, proof components are written in the order in which they are
+    /// consumed by the verifier. First, a [ProofHeader] containing all components needed to
+    /// derive query positions is written. The header is followed by trace queries (for each
+    /// trace segment), constraint queries, FRI layers (one at a time) and the FRI remainder.
+    /// This allows the verifier to read the proof incrementally, such that, beyond the header,
+    /// only a single component of the proof needs to be held in memory at a time.
+    ///
+    /// Proofs serialized in this way cannot be read via [from_bytes()](Proof::from_bytes).
+    pub fn write_streaming_into<W: ByteWriter>(&self, target: &mut W) {
+        // write the header
+        self.context.write_into(target);
+        target.write_u8(self.num_unique_queries);
+        self.commitments.write_into(target);
+        self.gkr_proof.write_into(target);
+        self.ood_frame.write_for_trace_into(self.trace_info(), target);
+        target.write_u8(self.fri_proof.num_partitions().trailing_zeros() as u8);
+        target.write_u64(self.pow_nonce);
+
+        // write the queries and the FRI proof
+        target.write_many(&self.trace_queries);
+        self.constraint_queries.write_into(target);
+        self.fri_proof.write_layers_into(target);
+    }
+
+    /// Returns the version of the serialization format of the proof serialized into the
+    /// specified `source`.
+    ///
+    /// This reads only the header of the proof, and thus, succeeds even for proofs which cannot
+    /// be read via [from_bytes()](Proof::from_bytes) because they were serialized using a
+    /// version of the format other than [PROOF_FORMAT_VERSION]. This applies to proofs serialized
+    /// using both the regular and the streaming formats.
+    ///
+    /// # Errors
+    /// Returns an error if the `source` does not start with [PROOF_MAGIC] bytes followed by the
+    /// format version.
+    pub fn read_format_version(source: &[u8]) -> Result<u8, DeserializationError> {
+        context::read_format_version(&mut SliceReader::new(source))
+    }
+
     /// Returns a STARK proof read from the specified `source`.
     ///
     /// # Errors
@@ -139,13 +399,243 @@ impl Proof {
         Deserializable::read_from_bytes(source)
     }
 
+    /// Returns a STARK proof read from the specified `source` which must not be larger than
+    /// `max_size` bytes.
+    ///
+    /// The size of the `source` is checked before any part of the proof is parsed.
+    ///
+    /// # Errors
+    /// Returns an error if the `source` is larger than `max_size` bytes, or if a valid STARK proof
+    /// could not be read from the specified `source`.
+    pub fn from_bytes_with_limit(
+        source: &[u8],
+        max_size: usize,
+    ) -> Result<Self, DeserializationError> {
+        if source.len() > max_size {
+            return Err(DeserializationError::InvalidValue(format!(
+                "proof size of {} bytes exceeds the maximum allowed size of {max_size} bytes",
+                source.len()
+            )));
+        }
+        Deserializable::read_from_bytes(source)
+    }
+
+    // COMPRESSION
+    // --------------------------------------------------------------------------------------------
+
+    /// Serializes this proof into a vector of bytes compressed using zlib.
+    ///
+    /// The result starts with [COMPRESSED_PROOF_MAGIC] bytes followed by the compression method
+    /// ([COMPRESSION_METHOD_ZLIB]), and the bytes of [to_bytes()](Proof::to_bytes) compressed
+    /// using this method. Since uncompressed proofs start with [PROOF_MAGIC] bytes, a compressed
+    /// proof cannot be mistaken for an uncompressed one.
+    #[cfg(feature = "compression")]
+    pub fn to_bytes_compressed(&self) -> Vec<u8> {
+        use std::io::Write;
+
+        let mut header = COMPRESSED_PROOF_MAGIC.to_vec();
+        header.push(COMPRESSION_METHOD_ZLIB);
+        let mut encoder = flate2::write::ZlibEncoder::new(header, flate2::Compression::default());
+        encoder.write_all(&self.to_bytes()).expect("failed to compress proof");
+        encoder.finish().expect("failed to compress proof")
+    }
+
+    /// Returns a STARK proof read from the specified `source` serialized via
+    /// [to_bytes_compressed()](Proof::to_bytes_compressed).
+    ///
+    /// The decompressed proof must not be larger than [DEFAULT_MAX_PROOF_SIZE] bytes; a different
+    /// limit can be applied via
+    /// [from_bytes_compressed_with_limit()](Proof::from_bytes_compressed_with_limit).
+    ///
+    /// # Errors
+    /// Returns an error if a valid STARK proof could not be decompressed and read from the
+    /// specified `source`.
+    #[cfg(feature = "compression")]
+    pub fn from_bytes_compressed(source: &[u8]) -> Result<Self, DeserializationError> {
+        Self::from_bytes_compressed_with_limit(source, DEFAULT_MAX_PROOF_SIZE)
+    }
+
+    /// Returns a STARK proof read from the specified `source` serialized via
+    /// [to_bytes_compressed()](Proof::to_bytes_compressed) which must not decompress into more
+    /// than `max_size` bytes.
+    ///
+    /// Decompression stops as soon as the decompressed proof exceeds `max_size` bytes, and thus,
+    /// a small `source` cannot cause an arbitrarily large decompression buffer to be allocated.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * The `source` does not start with [COMPRESSED_PROOF_MAGIC] bytes followed by a supported
+    ///   compression method.
+    /// * The `source` could not be decompressed, or any bytes remain in the `source` after the
+    ///   end of the compressed data.
+    /// * The decompressed proof is larger than `max_size` bytes, or a valid STARK proof could not
+    ///   be read from it.
+    #[cfg(feature = "compression")]
+    pub fn from_bytes_compressed_with_limit(
+        source: &[u8],
+        max_size: usize,
+    ) -> Result<Self, DeserializationError> {
+        use std::io::Read;
+
+        let source = source.strip_prefix(&COMPRESSED_PROOF_MAGIC).ok_or_else(|| {
+            DeserializationError::InvalidValue(
+                "source does not start with compressed proof magic bytes".into(),
+            )
+        })?;
+        let (&method, compressed) =
+            source.split_first().ok_or(DeserializationError::UnexpectedEOF)?;
+        if method != COMPRESSION_METHOD_ZLIB {
+            return Err(DeserializationError::InvalidValue(format!(
+                "proof compression method {method} is not supported"
+            )));
+        }
+
+        // decompress at most one byte more than the maximum proof size; proofs exceeding the
+        // maximum size are then rejected by from_bytes_with_limit()
+        let mut decoder = flate2::read::ZlibDecoder::new(compressed);
+        let mut bytes = Vec::new();
+        (&mut decoder)
+            .take(max_size as u64 + 1)
+            .read_to_end(&mut bytes)
+            .map_err(|err| {
+                DeserializationError::InvalidValue(format!("failed to decompress proof: {err}"))
+            })?;
+        if bytes.len() <= max_size && decoder.total_in() != compressed.len() as u64 {
+            return Err(DeserializationError::UnconsumedBytes);
+        }
+
+        Self::from_bytes_with_limit(&bytes, max_size)
+    }
+
+    // FIELD ELEMENT ENCODING
+    // --------------------------------------------------------------------------------------------
+
+    /// Encodes this proof as a sequence of elements of the base field of hash function `H`.
+    ///
+    /// This is intended for proofs generated using an algebraic hash function `H` (and
+    /// [MerkleTree] vector commitments), so that every component of the proof is a sequence of
+    /// native field elements which can be absorbed by a recursive verifier as is:
+    /// * Field element values (query values, out-of-domain evaluations, and FRI layer values and
+    ///   remainder) are written as elements of the base field, with elements of an extension field
+    ///   written as their base field coefficients. Values are prefixed with the number of base
+    ///   field elements they occupy.
+    /// * Digests (commitments and nodes of batch Merkle proofs) are written as the field elements
+    ///   of which they consist (see [AlgebraicSponge::digest_as_elements()]), prefixed with the
+    ///   number of digests.
+    /// * Integers (e.g., lengths, counts, and proof options) are written as one element each,
+    ///   except for the proof-of-work nonce which is written as two elements holding the low and
+    ///   the high 32 bits of the nonce.
+    /// * Application-defined bytes (trace metadata and the GKR proof) are written as the number of
+    ///   bytes followed by the bytes packed into elements in little-endian order, 7 bytes per
+    ///   element for 62-bit and 64-bit fields and 15 bytes per element for 128-bit fields.
+    ///
+    /// The components are laid out in the following order:
+    /// 1. Proof context: main and auxiliary trace segment widths, number of auxiliary random
+    ///    elements, trace length, trace metadata, number of constant columns followed by the index
+    ///    and the value of each column, the 8 proof options in the order of their byte
+    ///    serialization, and the hash function, proof-of-work, and auxiliary segment hash function
+    ///    identifiers (each as 0, or as 1 followed by the identifier). The field modulus is
+    ///    implied by `H`.
+    /// 2. Number of unique queries.
+    /// 3. Commitments, as a sequence of digests.
+    /// 4. Trace queries for each trace segment, followed by constraint queries. For each set of
+    ///    queries: the query values and the batch Merkle proof (the tree depth and the number of
+    ///    node vectors, followed by each vector as a sequence of digests).
+    /// 5. Out-of-domain frame: the number of frame rows followed by the trace states, the number
+    ///    of Lagrange kernel evaluations followed by the evaluations, and the constraint
+    ///    evaluations.
+    /// 6. FRI proof: the number of layers, the query values and the batch Merkle proof of each
+    ///    layer, the remainder, and the log2 of the number of partitions.
+    /// 7. Proof-of-work nonce.
+    /// 8. GKR proof: zero if there is no GKR proof, or one followed by the proof (as bytes).
+    ///
+    /// # Panics
+    /// Panics if the base field of `H` is not the base field of the protocol used to generate
+    /// this proof, or if the commitments, opening proofs, or field element values of this proof
+    /// are not valid digests or elements for `H` (e.g., because the proof was generated using a
+    /// different hash function).
+    pub fn to_elements<H: AlgebraicSponge>(&self) -> Vec<H::BaseField> {
+        assert_eq!(
+            self.context.field_modulus_bytes(),
+            H::BaseField::get_modulus_le_bytes(),
+            "proof was not generated over the specified base field"
+        );
+
+        let mut target = ElementWriter::<H::BaseField>::new();
+        self.context.write_elements_into(&mut target);
+        target.write_u32(self.num_unique_queries as u32);
+        self.commitments.write_elements_into::<H>(&mut target);
+        for queries in self.trace_queries.iter() {
+            queries.write_elements_into::<H>(&mut target);
+        }
+        self.constraint_queries.write_elements_into::<H>(&mut target);
+        self.ood_frame.write_elements_into(&mut target);
+        elements::write_fri_proof::<H>(&self.fri_proof, &mut target);
+        target.write_u64(self.pow_nonce);
+        match &self.gkr_proof {
+            Some(gkr_proof) => {
+                target.write_u32(1);
+                target.write_bytes(gkr_proof);
+            },
+            None => target.write_u32(0),
+        }
+        target.into_elements()
+    }
+
+    /// Returns a STARK proof decoded from the specified sequence of elements of the base field of
+    /// hash function `H`.
+    ///
+    /// See [to_elements()](Proof::to_elements) for the description of the encoding.
+    ///
+    /// # Errors
+    /// Returns an error if a valid STARK proof could not be decoded from the specified `source`,
+    /// or if any elements remain in the `source` after the proof has been decoded.
+    pub fn from_elements<H: AlgebraicSponge>(
+        source: &[H::BaseField],
+    ) -> Result<Self, DeserializationError> {
+        let mut source = ElementReader::new(source);
+
+        let context = Context::read_elements_from(&mut source)?;
+        let num_unique_queries = source.read_u8()?;
+        let commitments = Commitments::read_elements_from::<H>(&mut source)?;
+        let num_trace_segments = context.trace_info().num_segments();
+        let mut trace_queries = Vec::with_capacity(num_trace_segments);
+        for _ in 0..num_trace_segments {
+            trace_queries.push(Queries::read_elements_from::<H>(&mut source)?);
+        }
+        let constraint_queries = Queries::read_elements_from::<H>(&mut source)?;
+        let ood_frame = OodFrame::read_elements_from(context.trace_info(), &mut source)?;
+        let fri_proof = elements::read_fri_proof::<H>(&mut source)?;
+        let pow_nonce = source.read_u64()?;
+        let gkr_proof = match source.read_u32()? {
+            0 => None,
+            1 => Some(source.read_bytes()?),
+            flag => {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "invalid GKR proof flag {flag}"
+                )))
+            },
+        };
+        source.check_eor()?;
+
+        Ok(Proof {
+            context,
+            num_unique_queries,
+            commitments,
+            trace_queries,
+            constraint_queries,
+            ood_frame,
+            fri_proof,
+            pow_nonce,
+            gkr_proof,
+        })
+    }
+
     /// Creates a dummy `Proof` for use in tests.
     pub fn new_dummy() -> Self {
         use crypto::{hashers::Blake3_192 as DummyHasher, BatchMerkleProof};
         use math::fields::f64::BaseElement as DummyField;
 
-        use crate::FieldExtension;
-
         Self {
             context: Context::new::<DummyField>(
                 TraceInfo::new(1, 8),
@@ -166,17 +656,46 @@ impl Proof {
     }
 }
 
+// PROOF PARTS
+// ================================================================================================
+
+/// Components of a [Proof], as returned by [Proof::into_parts()] and consumed by
+/// [Proof::from_parts()].
+///
+/// The fields have the same meaning as the corresponding fields of the [Proof] struct.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ProofParts {
+    /// Basic metadata about the execution of the computation described by the proof.
+    pub context: Context,
+    /// Number of unique queries made by the verifier.
+    pub num_unique_queries: u8,
+    /// Commitments made by the prover during the commit phase of the protocol.
+    pub commitments: Commitments,
+    /// Decommitments of extended execution trace values (for all trace segments).
+    pub trace_queries: Vec<Queries>,
+    /// Decommitments of constraint composition polynomial evaluations.
+    pub constraint_queries: Queries,
+    /// Trace and constraint polynomial evaluations at an out-of-domain point.
+    pub ood_frame: OodFrame,
+    /// Low-degree proof for a DEEP composition polynomial.
+    pub fri_proof: FriProof,
+    /// Proof-of-work nonce for query seed grinding.
+    pub pow_nonce: u64,
+    /// Optionally, an auxiliary (non-STARK) proof.
+    pub gkr_proof: Option<Vec<u8>>,
+}
+
 // SERIALIZATION
 // ================================================================================================
 
 impl Serializable for Proof {
-    fn write_into<W: utils::ByteWriter>(&self, target: &mut W) {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
         self.context.write_into(target);
         target.write_u8(self.num_unique_queries);
         self.commitments.write_into(target);
         target.write_many(&self.trace_queries);
         self.constraint_queries.write_into(target);
-        self.ood_frame.write_into(target);
+        self.ood_frame.write_for_trace_into(self.context.trace_info(), target);
         self.fri_proof.write_into(target);
         self.pow_nonce.write_into(target);
         self.gkr_proof.write_into(target);
@@ -194,26 +713,70 @@ impl Deserializable for Proof {
             trace_queries.push(Queries::read_from(source)?);
         }
 
+        let constraint_queries = Queries::read_from(source)?;
+        let ood_frame = OodFrame::read_for_trace_from(context.trace_info(), source)?;
         let proof = Proof {
             context,
             num_unique_queries,
             commitments,
             trace_queries,
-            constraint_queries: Queries::read_from(source)?,
-            ood_frame: OodFrame::read_from(source)?,
+            constraint_queries,
+            ood_frame,
             fri_proof: FriProof::read_from(source)?,
             pow_nonce: source.read_u64()?,
-            gkr_proof: Option::<Vec<u8>>::read_from(source)?,
+            gkr_proof: read_gkr_proof(source)?,
         };
         Ok(proof)
     }
 }
 
+/// Reads an optional GKR proof from the specified `source`.
+///
+/// This is equivalent to `Option::<Vec<u8>>::read_from()`, but the length of the proof is checked
+/// against the number of bytes remaining in the `source` before the proof bytes are allocated.
+pub(crate) fn read_gkr_proof<R: ByteReader>(
+    source: &mut R,
+) -> Result<Option<Vec<u8>>, DeserializationError> {
+    if source.read_bool()? {
+        let num_bytes = source.read_usize()?;
+        Ok(Some(source.read_vec(num_bytes)?))
+    } else {
+        Ok(None)
+    }
+}
+
+// DISPLAY
+// ================================================================================================
+
+impl Display for Proof {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let trace_info = self.trace_info();
+        let options = self.options();
+        let extension = match options.field_extension() {
+            FieldExtension::None => "none",
+            FieldExtension::Quadratic => "quadratic",
+            FieldExtension::Cubic => "cubic",
+            FieldExtension::Sextic => "sextic",
+        };
+        write!(
+            f,
+            "Proof{{trace={}x2^{}, ext={}, queries={}, blowup={}, fri_layers={}, size={}KB}}",
+            trace_info.width(),
+            trace_info.length().ilog2(),
+            extension,
+            options.num_queries(),
+            options.blowup_factor(),
+            self.fri_proof.num_layers(),
+            self.to_bytes().len().div_ceil(1024),
+        )
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
 /// Computes conjectured security level for the specified proof parameters.
-fn get_conjectured_security(
+pub(crate) fn get_conjectured_security(
     options: &ProofOptions,
     base_field_bits: u32,
     trace_domain_size: usize,
@@ -236,7 +799,7 @@ fn get_conjectured_security(
 }
 
 /// Estimates proven security level for the specified proof parameters.
-fn get_proven_security(
+pub(crate) fn get_proven_security(
     options: &ProofOptions,
     base_field_bits: u32,
     trace_domain_size: usize,
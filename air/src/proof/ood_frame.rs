@@ -6,12 +6,13 @@
 use alloc::vec::Vec;
 
 use crypto::ElementHasher;
-use math::FieldElement;
+use math::{FieldElement, StarkField};
 use utils::{
     ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable, SliceReader,
 };
 
-use crate::{EvaluationFrame, LagrangeKernelEvaluationFrame};
+use super::elements::{ElementReader, ElementWriter};
+use crate::{EvaluationFrame, LagrangeKernelEvaluationFrame, TraceInfo};
 
 // OUT-OF-DOMAIN FRAME
 // ================================================================================================
@@ -28,7 +29,25 @@ use crate::{EvaluationFrame, LagrangeKernelEvaluationFrame};
 /// where *z* is an out-of-domain point and *g* is the generator of the trace domain.
 ///
 /// Internally, the evaluations are stored as a sequence of bytes. Thus, to retrieve the
-/// evaluations, [parse()](OodFrame::parse) function should be used.
+/// evaluations, [parse()](OodFrame::parse) or [evaluations()](OodFrame::evaluations) functions
+/// should be used.
+///
+/// The bytes are laid out in three sections, each of which is serialized with a u16 length
+/// prefix (or, within a proof of a trace whose segment widths do not fit into u8 values, with a
+/// u32 length prefix):
+/// 1. Trace states: a u8 frame size (always 2), followed by the evaluations of all main and
+///    auxiliary trace polynomials (excluding the Lagrange kernel column) with the evaluations at
+///    *z* and *z * g* interleaved for each column; that is, for main trace columns
+///    `a_1, ..., a_n` and auxiliary trace columns `c_1, ..., c_m`, the evaluations are stored as
+///    `a_1(z), a_1(z * g), ..., a_n(z), a_n(z * g), c_1(z), c_1(z * g), ..., c_m(z), c_m(z * g)`.
+/// 2. Lagrange kernel states: a u8 number of evaluations (zero if there is no Lagrange kernel
+///    column), followed by the evaluations of the Lagrange kernel column polynomial at
+///    *z*, *z * g*, *z * g^2*, ..., *z * g^(2^(v-1))*.
+/// 3. Constraint evaluations: evaluations of all constraint composition column polynomials at
+///    *z*.
+///
+/// All evaluations are elements of the extension field used by the protocol (see
+/// [ProofOptions::field_extension()](crate::ProofOptions::field_extension)).
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct OodFrame {
     trace_states: Vec<u8>,
@@ -173,50 +192,225 @@ impl OodFrame {
             evaluations,
         ))
     }
+
+    /// Returns the out-of-domain evaluations contained in `self` for an execution trace described
+    /// by the specified trace info.
+    ///
+    /// This is similar to [parse()](OodFrame::parse), but separates the evaluations of the main
+    /// and auxiliary trace segments, and infers the number of constraint evaluations from the
+    /// contents of the frame. See [OodFrame] for the description of the layout of the
+    /// evaluations.
+    ///
+    /// `E` must be the field in which the proof was generated (i.e., the extension of the base
+    /// field specified in the proof options).
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * Evaluations of the trace segments described by `trace_info` could not be parsed from
+    ///   the internal bytes.
+    /// * The frame does not contain any constraint evaluations, or the bytes of the constraint
+    ///   evaluations do not encode a whole number of field elements.
+    /// * Any unconsumed bytes remained after the parsing was complete.
+    pub fn evaluations<E: FieldElement>(
+        &self,
+        trace_info: &TraceInfo,
+    ) -> Result<OodEvaluations<E>, DeserializationError> {
+        let num_evaluations = self.evaluations.len() / E::ELEMENT_BYTES;
+        if num_evaluations == 0 || self.evaluations.len() % E::ELEMENT_BYTES != 0 {
+            return Err(DeserializationError::InvalidValue(format!(
+                "{} bytes of out-of-domain constraint evaluations do not encode a non-zero number \
+                of field elements",
+                self.evaluations.len()
+            )));
+        }
+
+        let main_trace_width = trace_info.main_trace_width();
+        let (trace_frame, constraint_evaluations) = self.clone().parse::<E>(
+            main_trace_width,
+            trace_info.aux_segment_width(),
+            num_evaluations,
+        )?;
+
+        let (mut main_current, mut main_next) =
+            (trace_frame.current_row().to_vec(), trace_frame.next_row().to_vec());
+        let aux_current = main_current.split_off(main_trace_width);
+        let aux_next = main_next.split_off(main_trace_width);
+
+        Ok(OodEvaluations {
+            main_current,
+            main_next,
+            aux_current,
+            aux_next,
+            lagrange_kernel: trace_frame
+                .lagrange_kernel_frame()
+                .map(|frame| frame.inner().to_vec()),
+            constraint_evaluations,
+        })
+    }
 }
 
-// SERIALIZATION
+// OUT-OF-DOMAIN EVALUATIONS
 // ================================================================================================
 
-impl Serializable for OodFrame {
-    /// Serializes `self` and writes the resulting bytes into the `target`.
-    fn write_into<W: ByteWriter>(&self, target: &mut W) {
-        // write trace rows
-        target.write_u16(self.trace_states.len() as u16);
-        target.write_bytes(&self.trace_states);
+/// Out-of-domain evaluations contained in an [OodFrame], separated by trace segment.
+///
+/// Here, *z* is the out-of-domain point and *g* is the generator of the trace domain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OodEvaluations<E: FieldElement> {
+    /// Evaluations of main trace segment polynomials at *z*.
+    pub main_current: Vec<E>,
+    /// Evaluations of main trace segment polynomials at *z * g*.
+    pub main_next: Vec<E>,
+    /// Evaluations of auxiliary trace segment polynomials (excluding the Lagrange kernel column)
+    /// at *z*; empty if the trace does not have an auxiliary segment.
+    pub aux_current: Vec<E>,
+    /// Evaluations of auxiliary trace segment polynomials (excluding the Lagrange kernel column)
+    /// at *z * g*; empty if the trace does not have an auxiliary segment.
+    pub aux_next: Vec<E>,
+    /// Evaluations of the Lagrange kernel column polynomial at *z*, *z * g*, *z * g^2*, ...,
+    /// *z * g^(2^(v-1))*, where `v == log(trace_len)`, or None if the trace does not have a
+    /// Lagrange kernel column.
+    pub lagrange_kernel: Option<Vec<E>>,
+    /// Evaluations of constraint composition column polynomials at *z*.
+    pub constraint_evaluations: Vec<E>,
+}
 
-        // write Lagrange kernel column trace rows
-        target.write_u16(self.lagrange_kernel_trace_states.len() as u16);
-        target.write_bytes(&self.lagrange_kernel_trace_states);
+// FIELD ELEMENT ENCODING
+// ================================================================================================
 
-        // write constraint evaluations row
-        target.write_u16(self.evaluations.len() as u16);
-        target.write_bytes(&self.evaluations)
+impl OodFrame {
+    /// Writes this out-of-domain frame into the `target`.
+    ///
+    /// The frame is laid out as the number of frame rows (zero if the trace states have not been
+    /// set) followed by the trace states, the number of Lagrange kernel evaluations followed by
+    /// the evaluations, and the constraint evaluations. The Lagrange kernel evaluations are
+    /// omitted when the trace states have not been set. All evaluations are written as field
+    /// element values.
+    pub(crate) fn write_elements_into<B: StarkField>(&self, target: &mut ElementWriter<B>) {
+        match (self.trace_states.split_first(), self.lagrange_kernel_trace_states.split_first()) {
+            (Some((&frame_size, trace_states)), Some((&num_lagrange_states, lagrange_states))) => {
+                target.write_u32(frame_size as u32);
+                target.write_values(trace_states);
+                target.write_u32(num_lagrange_states as u32);
+                target.write_values(lagrange_states);
+            },
+            _ => target.write_u32(0),
+        }
+        target.write_values(&self.evaluations);
     }
 
-    /// Returns an estimate of how many bytes are needed to represent self.
-    fn get_size_hint(&self) -> usize {
-        self.trace_states.len() + self.evaluations.len() + 4
+    /// Reads an out-of-domain frame written by [OodFrame::write_elements_into()] from the
+    /// `source`.
+    pub(crate) fn read_elements_from<B: StarkField>(
+        trace_info: &TraceInfo,
+        source: &mut ElementReader<B>,
+    ) -> Result<Self, DeserializationError> {
+        let mut trace_states = Vec::new();
+        let mut lagrange_kernel_trace_states = Vec::new();
+        let frame_size = source.read_u8()?;
+        if frame_size != 0 {
+            trace_states.write_u8(frame_size);
+            trace_states.write_bytes(&source.read_values()?);
+            lagrange_kernel_trace_states.write_u8(source.read_u8()?);
+            lagrange_kernel_trace_states.write_bytes(&source.read_values()?);
+        }
+        let evaluations = source.read_values()?;
+
+        // the byte serialization of the frame limits the length of each part to 16 bits unless
+        // the trace is wide
+        let max_len = if trace_info.is_wide() {
+            u32::MAX as usize
+        } else {
+            u16::MAX as usize
+        };
+        for part in [&trace_states, &lagrange_kernel_trace_states, &evaluations] {
+            if part.len() > max_len {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "{} bytes of out-of-domain frame exceed the maximum of {} bytes",
+                    part.len(),
+                    max_len
+                )));
+            }
+        }
+
+        Ok(OodFrame {
+            trace_states,
+            lagrange_kernel_trace_states,
+            evaluations,
+        })
     }
 }
 
-impl Deserializable for OodFrame {
-    /// Reads a OOD frame from the specified `source` and returns the result
+// SERIALIZATION
+// ================================================================================================
+
+impl OodFrame {
+    /// Serializes this frame for a proof of a computation with the specified trace info, and
+    /// writes the resulting bytes into the `target`.
+    ///
+    /// Length prefixes of the frame sections are written as u16 values, except for traces whose
+    /// segment widths do not fit into u8 values (see [TraceInfo]), for which u32 values are
+    /// written instead. Since wide traces could not be serialized before, this does not change
+    /// the encoding of any previously serialized proof.
+    ///
+    /// # Panics
+    /// Panics if length prefixes are written as u16 values and any section of this frame is
+    /// longer than [u16::MAX] bytes.
+    pub(crate) fn write_for_trace_into<W: ByteWriter>(
+        &self,
+        trace_info: &TraceInfo,
+        target: &mut W,
+    ) {
+        self.write_sections_into(target, trace_info.is_wide());
+    }
+
+    /// Reads an out-of-domain frame written by [OodFrame::write_for_trace_into()] from the
+    /// `source`.
     ///
     /// # Errors
     /// Returns an error of a valid OOD frame could not be read from the specified `source`.
-    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
-        // read trace rows
-        let num_trace_state_bytes = source.read_u16()? as usize;
-        let trace_states = source.read_vec(num_trace_state_bytes)?;
+    pub(crate) fn read_for_trace_from<R: ByteReader>(
+        trace_info: &TraceInfo,
+        source: &mut R,
+    ) -> Result<Self, DeserializationError> {
+        Self::read_sections_from(source, trace_info.is_wide())
+    }
 
-        // read Lagrange kernel column trace rows
-        let num_lagrange_state_bytes = source.read_u16()? as usize;
-        let lagrange_kernel_trace_states = source.read_vec(num_lagrange_state_bytes)?;
+    /// Writes trace states, Lagrange kernel trace states, and constraint evaluations of this
+    /// frame into the `target`, each prefixed with its length written as a u32 value if
+    /// `is_wide` is true, or as a u16 value otherwise.
+    fn write_sections_into<W: ByteWriter>(&self, target: &mut W, is_wide: bool) {
+        for section in [&self.trace_states, &self.lagrange_kernel_trace_states, &self.evaluations] {
+            if is_wide {
+                target.write_u32(section.len() as u32);
+            } else {
+                assert!(
+                    section.len() <= u16::MAX as usize,
+                    "out-of-domain frame section cannot be longer than {} bytes, but was {}",
+                    u16::MAX,
+                    section.len()
+                );
+                target.write_u16(section.len() as u16);
+            }
+            target.write_bytes(section);
+        }
+    }
 
-        // read constraint evaluations row
-        let num_constraint_evaluation_bytes = source.read_u16()? as usize;
-        let evaluations = source.read_vec(num_constraint_evaluation_bytes)?;
+    /// Reads sections of a frame written by [OodFrame::write_sections_into()] from the `source`.
+    fn read_sections_from<R: ByteReader>(
+        source: &mut R,
+        is_wide: bool,
+    ) -> Result<Self, DeserializationError> {
+        let mut sections = [Vec::new(), Vec::new(), Vec::new()];
+        for section in sections.iter_mut() {
+            let num_bytes = if is_wide {
+                source.read_u32()? as usize
+            } else {
+                source.read_u16()? as usize
+            };
+            *section = source.read_vec(num_bytes)?;
+        }
+        let [trace_states, lagrange_kernel_trace_states, evaluations] = sections;
 
         Ok(OodFrame {
             trace_states,
@@ -226,6 +420,38 @@ impl Deserializable for OodFrame {
     }
 }
 
+impl Serializable for OodFrame {
+    /// Serializes `self` and writes the resulting bytes into the `target`.
+    ///
+    /// Length prefixes of the frame sections are written as u16 values.
+    ///
+    /// # Panics
+    /// Panics if any section of this frame is longer than [u16::MAX] bytes.
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.write_sections_into(target, false);
+    }
+
+    /// Returns an estimate of how many bytes are needed to represent self.
+    fn get_size_hint(&self) -> usize {
+        self.trace_states.len()
+            + self.lagrange_kernel_trace_states.len()
+            + self.evaluations.len()
+            + 6
+    }
+}
+
+impl Deserializable for OodFrame {
+    /// Reads a OOD frame from the specified `source` and returns the result
+    ///
+    /// Length prefixes of the frame sections are expected to be u16 values.
+    ///
+    /// # Errors
+    /// Returns an error of a valid OOD frame could not be read from the specified `source`.
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Self::read_sections_from(source, false)
+    }
+}
+
 // OOD FRAME TRACE STATES
 // ================================================================================================
 
@@ -3,15 +3,21 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use alloc::vec::Vec;
+use alloc::vec::{self, Vec};
 
-use crypto::{ElementHasher, Hasher, VectorCommitment};
+use crypto::{
+    AlgebraicSponge, BatchMerkleProof, ElementHasher, Hasher, MerkleTree, VectorCommitment,
+};
 use math::FieldElement;
 use utils::{
     ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable, SliceReader,
 };
 
-use super::Table;
+use super::{
+    elements::{ElementReader, ElementWriter},
+    Table,
+};
+use crate::PartitionOptions;
 
 // QUERIES
 // ================================================================================================
@@ -28,7 +34,11 @@ use super::Table;
 ///
 /// Internally, all opening proofs and query values are stored as a sequence of bytes. Thus, to
 /// retrieve query values and their corresponding opening proofs, [parse()](Queries::parse)
-/// function should be used.
+/// function should be used. For queries committed to via a [MerkleTree], individual openings can
+/// also be iterated over via [parse_openings()](Queries::parse_openings); [Proof](super::Proof)
+/// exposes the same iteration for its trace and constraint queries with the parameters taken from
+/// the proof's trace info and options.
+
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Queries {
     opening_proof: Vec<u8>,
@@ -130,6 +140,170 @@ impl Queries {
 
         Ok((opening_proof, query_values))
     }
+
+    /// Converts internally stored bytes into an iterator over individual query openings against a
+    /// [MerkleTree] commitment.
+    ///
+    /// Each [QueryOpening] yielded by the iterator contains the position of the query in the
+    /// commitment domain, the values of all functions at this position, and the uncompressed
+    /// Merkle authentication path for the leaf at this position. The openings are yielded in the
+    /// same order as the `positions` are provided.
+    ///
+    /// The `positions` must be the same (de-duplicated) positions against which the queries were
+    /// opened; in a STARK proof these are drawn by the verifier from the public coin. The
+    /// `partition_options` must be the options used to hash rows of the committed functions into
+    /// Merkle tree leaves (i.e., [ProofOptions::partition_options()](crate::ProofOptions)).
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * The stored bytes could not be parsed into query values and a batch Merkle proof (see
+    ///   [parse()](Queries::parse)).
+    /// * The number of positions does not match the number of queries in the batch proof.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * `domain_size` is not a power of two.
+    /// * `positions` is empty.
+    /// * `values_per_query` is zero.
+    pub fn parse_openings<E, H>(
+        self,
+        positions: &[usize],
+        domain_size: usize,
+        values_per_query: usize,
+        partition_options: PartitionOptions,
+    ) -> Result<QueryOpenings<E, H>, DeserializationError>
+    where
+        E: FieldElement,
+        H: ElementHasher<BaseField = E::BaseField>,
+    {
+        let (opening_proof, values) =
+            self.parse::<E, H, MerkleTree<H>>(domain_size, positions.len(), values_per_query)?;
+
+        // hash the rows of queried values into Merkle tree leaves
+        let partition_size = partition_options.partition_size::<E>(values_per_query);
+        let leaves = values
+            .rows()
+            .map(|row| hash_row::<E, H>(row, partition_size))
+            .collect::<Vec<_>>();
+
+        // expand the batch proof into individual authentication paths
+        let openings = BatchMerkleProof::into_openings(opening_proof, &leaves, positions)
+            .map_err(|err| DeserializationError::InvalidValue(format!("{err}")))?;
+
+        Ok(QueryOpenings {
+            openings: positions
+                .iter()
+                .zip(openings)
+                .map(|(&position, (_, merkle_path))| (position, merkle_path))
+                .collect::<Vec<_>>()
+                .into_iter(),
+            values,
+            row_idx: 0,
+        })
+    }
+
+    /// Returns the number of values in each of the queries assuming that the queries contain
+    /// `num_queries` queries with values in field `E`.
+    ///
+    /// # Errors
+    /// Returns an error if the number of stored value bytes is not consistent with `num_queries`
+    /// queries with values in field `E`.
+    pub(super) fn num_values_per_query<E: FieldElement>(
+        &self,
+        num_queries: usize,
+    ) -> Result<usize, DeserializationError> {
+        let num_query_bytes = self.values.len() / num_queries.max(1);
+        if num_query_bytes == 0
+            || self.values.len() % num_queries != 0
+            || num_query_bytes % E::ELEMENT_BYTES != 0
+        {
+            return Err(DeserializationError::InvalidValue(format!(
+                "{} query value bytes cannot be split into {} queries",
+                self.values.len(),
+                num_queries
+            )));
+        }
+        Ok(num_query_bytes / E::ELEMENT_BYTES)
+    }
+}
+
+// QUERY OPENING
+// ================================================================================================
+/// A single decommitment parsed from [Queries].
+///
+/// Contains evaluations of all committed functions at a single position of the commitment domain
+/// together with the Merkle authentication path of the corresponding leaf.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct QueryOpening<E: FieldElement, H: Hasher> {
+    /// Position of the query in the commitment domain.
+    pub position: usize,
+    /// Evaluations of all committed functions at this position.
+    pub values: Vec<E>,
+    /// Authentication path of the leaf at this position, ordered from the leaf's sibling to the
+    /// child of the root.
+    pub merkle_path: Vec<H::Digest>,
+}
+
+// QUERY OPENINGS ITERATOR
+// ================================================================================================
+/// An iterator over the individual openings parsed from [Queries].
+///
+/// This struct is created by [Queries::parse_openings()].
+#[derive(Debug, Clone)]
+pub struct QueryOpenings<E: FieldElement, H: Hasher> {
+    openings: vec::IntoIter<(usize, Vec<H::Digest>)>,
+    values: Table<E>,
+    row_idx: usize,
+}
+
+impl<E: FieldElement, H: Hasher> Iterator for QueryOpenings<E, H> {
+    type Item = QueryOpening<E, H>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (position, merkle_path) = self.openings.next()?;
+        let values = self.values.get_row(self.row_idx).to_vec();
+        self.row_idx += 1;
+        Some(QueryOpening { position, values, merkle_path })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.openings.size_hint()
+    }
+}
+
+impl<E: FieldElement, H: Hasher> ExactSizeIterator for QueryOpenings<E, H> {}
+
+// FIELD ELEMENT ENCODING
+// ================================================================================================
+
+impl Queries {
+    /// Writes these queries into the `target`.
+    ///
+    /// The queries are laid out as the query values (as field element values) followed by the
+    /// batch Merkle proof.
+    ///
+    /// # Panics
+    /// Panics if the opening proof of these queries is not a batch Merkle proof for hash function
+    /// `H`, or if the query values are not valid elements of its base field.
+    pub(crate) fn write_elements_into<H: AlgebraicSponge>(
+        &self,
+        target: &mut ElementWriter<H::BaseField>,
+    ) {
+        target.write_values(&self.values);
+        target.write_batch_proof::<H>(&self.opening_proof);
+    }
+
+    /// Reads queries written by [Queries::write_elements_into()] from the `source`.
+    pub(crate) fn read_elements_from<H: AlgebraicSponge>(
+        source: &mut ElementReader<H::BaseField>,
+    ) -> Result<Self, DeserializationError> {
+        let values = source.read_values()?;
+        if values.is_empty() {
+            return Err(DeserializationError::InvalidValue("query values cannot be empty".into()));
+        }
+        let opening_proof = source.read_batch_proof::<H>()?;
+        Ok(Queries { opening_proof, values })
+    }
 }
 
 // SERIALIZATION
@@ -157,12 +331,33 @@ impl Deserializable for Queries {
     /// # Errors
     /// Returns an error of a valid query struct could not be read from the specified source.
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
-        // read values
-        let values = Vec::<_>::read_from(source)?;
+        // read values; we read the bytes via read_vec() rather than via Vec::read_from() so that
+        // the declared number of bytes is checked against the source before it is allocated
+        let num_value_bytes = source.read_usize()?;
+        let values = source.read_vec(num_value_bytes)?;
 
         // read paths
-        let paths = Vec::<_>::read_from(source)?;
+        let num_path_bytes = source.read_usize()?;
+        let paths = source.read_vec(num_path_bytes)?;
 
         Ok(Queries { opening_proof: paths, values })
     }
 }
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Hashes a row of queried values in batches where each batch is of size at most
+/// `partition_size`.
+fn hash_row<E, H>(row: &[E], partition_size: usize) -> H::Digest
+where
+    E: FieldElement,
+    H: ElementHasher<BaseField = E::BaseField>,
+{
+    if partition_size == row.len() {
+        H::hash_elements(row)
+    } else {
+        let buffer = row.chunks(partition_size).map(H::hash_elements).collect::<Vec<_>>();
+        H::merge_many(&buffer)
+    }
+}
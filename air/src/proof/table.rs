@@ -10,12 +10,13 @@ use math::FieldElement;
 use utils::ByteReader;
 
 use super::{DeserializationError, SliceReader};
+use crate::TraceInfo;
 
 // CONSTANTS
 // ================================================================================================
 
 const MAX_ROWS: usize = 255;
-const MAX_COLS: usize = 255;
+const MAX_COLS: usize = TraceInfo::MAX_TRACE_WIDTH;
 
 // TABLE
 // ================================================================================================
@@ -39,7 +40,7 @@ impl<E: FieldElement> Table<E> {
     /// # Panics
     /// Panics if:
     /// * Specified number of rows is 0 or greater than 255.
-    /// * Specified number of columns is 0 or greater than 255.
+    /// * Specified number of columns is 0 or greater than 65536.
     /// * Provided bytes do not encode valid field elements required to fill the table.
     pub fn from_bytes(
         bytes: &[u8],
@@ -53,7 +54,7 @@ impl<E: FieldElement> Table<E> {
         );
         assert!(num_cols > 0, "number of columns must be greater than 0");
         assert!(
-            num_cols < MAX_ROWS,
+            num_cols <= MAX_COLS,
             "number of columns cannot exceed {MAX_COLS}, but was {num_cols}"
         );
 
@@ -85,6 +86,7 @@ impl<E: FieldElement> Table<E> {
     }
 
     /// Returns an iterator over rows of this table.
+    #[allow(mismatched_lifetime_syntaxes)]
     pub fn rows(&self) -> RowIterator<E> {
         RowIterator::new(self)
     }
@@ -107,6 +109,37 @@ impl<E: FieldElement> Table<E> {
             unimplemented!("merging of multiple tables is not yet implemented")
         }
     }
+
+    /// Returns a new table with the specified constant columns inserted into every row of this
+    /// table.
+    ///
+    /// Each entry in `columns` is a tuple containing the index of the column in the resulting
+    /// table and the value of this column; the entries are expected to be sorted by column index.
+    ///
+    /// # Panics
+    /// Panics if any of the column indexes is greater than or equal to the width of the resulting
+    /// table.
+    pub fn with_constant_columns(self, columns: &[(usize, E)]) -> Table<E> {
+        if columns.is_empty() {
+            return self;
+        }
+
+        let row_width = self.row_width + columns.len();
+        let mut data = Vec::with_capacity(self.num_rows() * row_width);
+        for row in self.rows() {
+            let mut values = row.iter();
+            let mut constants = columns.iter().peekable();
+            for i in 0..row_width {
+                match constants.next_if(|(column, _)| *column == i) {
+                    Some((_, value)) => data.push(*value),
+                    None => data.push(*values.next().expect("not enough values in table row")),
+                }
+            }
+            assert!(constants.next().is_none(), "constant column index is out of bounds");
+        }
+
+        Self { data, row_width }
+    }
 }
 
 // COLUMN ITERATOR
@@ -3,9 +3,468 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use super::Proof;
+use alloc::{string::ToString, vec::Vec};
+
+use crypto::{
+    hashers::{Blake3_256, Rp62_248, Rp64_256},
+    AlgebraicSponge, BatchMerkleProof, ElementHasher, Hasher, MerkleTree, VectorCommitment,
+};
+use math::{fields::f64::BaseElement, FieldElement};
+use utils::{ByteWriter, Deserializable, DeserializationError, Serializable};
+
+use super::{
+    Commitments, Context, Proof, ProofHeader, Queries, CONTEXT_FLAG_AUX_HASH_ID,
+    CONTEXT_FLAG_HASH_ID, CONTEXT_FLAG_POW_ID, PROOF_FORMAT_VERSION, PROOF_MAGIC,
+};
+use crate::{PartitionOptions, TraceInfo};
+
+type Blake3 = Blake3_256<BaseElement>;
 
 #[test]
 pub fn starkproof_new_dummy_doesnt_panic() {
     let _ = Proof::new_dummy();
 }
+
+#[test]
+pub fn starkproof_display() {
+    let proof = Proof::new_dummy();
+    assert_eq!(
+        "Proof{trace=1x2^3, ext=none, queries=1, blowup=2, fri_layers=0, size=1KB}",
+        proof.to_string()
+    );
+}
+
+#[test]
+pub fn starkproof_from_bytes_with_limit() {
+    let bytes = build_dummy_proof_bytes();
+    assert!(Proof::from_bytes_with_limit(&bytes, bytes.len()).is_ok());
+    assert!(Proof::from_bytes_with_limit(&bytes, bytes.len() - 1).is_err());
+
+    // from_bytes() does not limit the proof size
+    assert!(Proof::from_bytes(&bytes).is_ok());
+}
+
+#[test]
+pub fn starkproof_from_bytes_oversized_length_prefix() {
+    // the dummy proof does not contain a GKR proof and so its last byte is the GKR proof flag;
+    // replace it with a GKR proof which declares a length far exceeding the source
+    let mut bytes = build_dummy_proof_bytes();
+    assert_eq!(Some(0), bytes.pop());
+    bytes.write_bool(true);
+    bytes.write_usize(1 << 60);
+    assert!(Proof::from_bytes(&bytes).is_err());
+}
+
+#[test]
+pub fn starkproof_format_version() {
+    let mut bytes = build_dummy_proof_bytes();
+    assert_eq!(&PROOF_MAGIC[..], &bytes[..PROOF_MAGIC.len()]);
+    assert_eq!(Ok(PROOF_FORMAT_VERSION), Proof::read_format_version(&bytes));
+    assert_eq!(
+        Ok(PROOF_FORMAT_VERSION),
+        Proof::read_format_version(&Proof::new_dummy().to_streaming_bytes())
+    );
+
+    // proofs serialized using an unknown format version are rejected
+    let unknown_version = PROOF_FORMAT_VERSION + 1;
+    bytes[PROOF_MAGIC.len()] = unknown_version;
+    assert_eq!(Ok(unknown_version), Proof::read_format_version(&bytes));
+    assert_eq!(
+        Err(DeserializationError::UnsupportedVersion(unknown_version)),
+        Proof::from_bytes(&bytes)
+    );
+
+    // bytes which do not start with the magic bytes are not recognized as proofs
+    bytes[0] ^= 1;
+    assert!(matches!(
+        Proof::read_format_version(&bytes),
+        Err(DeserializationError::InvalidValue(_))
+    ));
+    assert!(matches!(Proof::from_bytes(&bytes), Err(DeserializationError::InvalidValue(_))));
+    assert_eq!(Err(DeserializationError::UnexpectedEOF), Proof::read_format_version(&[]));
+}
+
+#[test]
+pub fn starkproof_hash_id() {
+    let mut proof = Proof::new_dummy();
+    proof.trace_queries.push(proof.constraint_queries.clone());
+    assert_eq!(None, proof.context.hash_id());
+    let bytes = proof.to_bytes();
+
+    // specifying a hash function identifier adds a single byte to the serialized proof and sets
+    // the corresponding context flag, but does not change the format version
+    proof.context = proof.context.with_hash_id(7);
+    assert_eq!(Some(7), proof.context.hash_id());
+    let bytes_with_hash_id = proof.to_bytes();
+    assert_eq!(bytes.len() + 1, bytes_with_hash_id.len());
+    assert_eq!(Ok(PROOF_FORMAT_VERSION), Proof::read_format_version(&bytes_with_hash_id));
+    assert_eq!(Some(&CONTEXT_FLAG_HASH_ID), proof.context.to_bytes().iter().nth_back(1));
+
+    let parsed = Proof::from_bytes(&bytes_with_hash_id).unwrap();
+    assert_eq!(Some(7), parsed.context.hash_id());
+    assert_eq!(proof, parsed);
+    assert_eq!(None, Proof::from_bytes(&bytes).unwrap().context.hash_id());
+}
+
+#[test]
+pub fn starkproof_pow_id() {
+    let mut proof = Proof::new_dummy();
+    proof.trace_queries.push(proof.constraint_queries.clone());
+    assert_eq!(None, proof.context.pow_id());
+
+    // specifying a proof-of-work identifier sets the corresponding context flag, and the
+    // identifier is preserved with or without a hash function identifier
+    proof.context = proof.context.with_pow_id(3);
+    let bytes = proof.to_bytes();
+    assert_eq!(Ok(PROOF_FORMAT_VERSION), Proof::read_format_version(&bytes));
+    assert_eq!(Some(&CONTEXT_FLAG_POW_ID), proof.context.to_bytes().iter().nth_back(1));
+    let parsed = Proof::from_bytes(&bytes).unwrap();
+    assert_eq!((None, Some(3)), (parsed.context.hash_id(), parsed.context.pow_id()));
+    assert_eq!(proof, parsed);
+
+    proof.context = proof.context.with_hash_id(7);
+    let bytes = proof.to_bytes();
+    assert_eq!(Ok(PROOF_FORMAT_VERSION), Proof::read_format_version(&bytes));
+    let parsed = Proof::from_bytes(&bytes).unwrap();
+    assert_eq!((Some(7), Some(3)), (parsed.context.hash_id(), parsed.context.pow_id()));
+    assert_eq!(proof, parsed);
+}
+
+#[test]
+pub fn starkproof_aux_hash_id() {
+    let mut proof = Proof::new_dummy();
+    proof.trace_queries.push(proof.constraint_queries.clone());
+    assert_eq!(None, proof.context.aux_hash_id());
+
+    // specifying an auxiliary segment hash function identifier sets the corresponding context
+    // flag, and the identifier is preserved together with other identifiers
+    proof.context = proof.context.with_aux_hash_id(5);
+    let bytes = proof.to_bytes();
+    assert_eq!(Ok(PROOF_FORMAT_VERSION), Proof::read_format_version(&bytes));
+    assert_eq!(Some(&CONTEXT_FLAG_AUX_HASH_ID), proof.context.to_bytes().iter().nth_back(1));
+    let parsed = Proof::from_bytes(&bytes).unwrap();
+    assert_eq!((None, None, Some(5)), ids(&parsed.context));
+    assert_eq!(proof, parsed);
+
+    proof.context = proof.context.with_hash_id(7).with_pow_id(3);
+    let bytes = proof.to_bytes();
+    assert_eq!(Ok(PROOF_FORMAT_VERSION), Proof::read_format_version(&bytes));
+    let parsed = Proof::from_bytes(&bytes).unwrap();
+    assert_eq!((Some(7), Some(3), Some(5)), ids(&parsed.context));
+    assert_eq!(proof, parsed);
+    assert_eq!(
+        Ok(proof.clone()),
+        Proof::from_elements::<Rp64_256>(&proof.to_elements::<Rp64_256>())
+    );
+
+    fn ids(context: &Context) -> (Option<u8>, Option<u8>, Option<u8>) {
+        (context.hash_id(), context.pow_id(), context.aux_hash_id())
+    }
+}
+
+#[test]
+pub fn context_flags() {
+    let context = Proof::new_dummy().context;
+    let mut bytes = context.to_bytes();
+    assert_eq!(Some(0), bytes.last().copied());
+    assert_eq!(Ok(context), Context::read_from_bytes(&bytes));
+
+    // unknown flags are rejected
+    *bytes.last_mut().unwrap() = 1 << 3;
+    assert!(matches!(
+        Context::read_from_bytes(&bytes),
+        Err(DeserializationError::InvalidValue(_))
+    ));
+
+    // flags which mark an identifier missing from the source are rejected
+    *bytes.last_mut().unwrap() = CONTEXT_FLAG_HASH_ID;
+    assert_eq!(Err(DeserializationError::UnexpectedEOF), Context::read_from_bytes(&bytes));
+}
+
+#[test]
+pub fn starkproof_into_parts_from_parts() {
+    let proof = Proof::new_dummy();
+    let parts = proof.clone().into_parts();
+    assert_eq!(proof.context, parts.context);
+    assert_eq!(proof.pow_nonce, parts.pow_nonce);
+
+    let reassembled = Proof::from_parts(parts);
+    assert_eq!(proof, reassembled);
+}
+
+#[test]
+pub fn starkproof_ood_frame_near_length_limit() {
+    // 8191 base field elements take 65528 bytes, which still fits into a u16 length prefix
+    let mut proof = Proof::new_dummy();
+    proof.trace_queries.push(proof.constraint_queries.clone());
+    proof.ood_frame.set_constraint_evaluations(&vec![BaseElement::ONE; 8191]);
+    assert_eq!(Ok(proof.clone()), Proof::from_bytes(&proof.to_bytes()));
+
+    // for traces with segments wider than 255 columns, sections of the frame are prefixed with
+    // u32 lengths, and thus, can be longer
+    let mut proof = Proof::new_dummy();
+    proof.trace_queries.push(proof.constraint_queries.clone());
+    proof.context = Context::new::<BaseElement>(TraceInfo::new(300, 8), proof.options().clone());
+    proof.ood_frame.set_constraint_evaluations(&vec![BaseElement::ONE; 8192]);
+    assert_eq!(Ok(proof.clone()), Proof::from_bytes(&proof.to_bytes()));
+    let header = ProofHeader::read_from_bytes(&proof.to_streaming_bytes()[..]);
+    assert_eq!(proof.ood_frame, header.unwrap().ood_frame);
+    assert_eq!(
+        Ok(proof.clone()),
+        Proof::from_elements::<Rp64_256>(&proof.to_elements::<Rp64_256>())
+    );
+}
+
+#[test]
+#[should_panic(expected = "out-of-domain frame section cannot be longer than 65535 bytes")]
+pub fn starkproof_ood_frame_exceeds_length_limit() {
+    let mut proof = Proof::new_dummy();
+    proof.ood_frame.set_constraint_evaluations(&vec![BaseElement::ONE; 8192]);
+    proof.to_bytes();
+}
+
+#[test]
+pub fn starkproof_to_elements_from_elements() {
+    let mut proof = Proof::new_dummy();
+    proof.trace_queries.push(proof.constraint_queries.clone());
+    proof.pow_nonce = u64::MAX - 1;
+    proof.gkr_proof = Some(vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+    let elements = proof.to_elements::<Rp64_256>();
+    assert_eq!(Ok(proof.clone()), Proof::from_elements::<Rp64_256>(&elements));
+
+    // trailing elements are rejected
+    let mut extended = elements.clone();
+    extended.push(BaseElement::ONE);
+    assert_eq!(
+        Err(DeserializationError::UnconsumedBytes),
+        Proof::from_elements::<Rp64_256>(&extended)
+    );
+
+    // truncated elements are rejected
+    assert!(Proof::from_elements::<Rp64_256>(&elements[..elements.len() - 1]).is_err());
+
+    // elements which do not encode a byte chunk are rejected; the last element holds the last
+    // two bytes of the GKR proof
+    let mut tampered = elements.clone();
+    *tampered.last_mut().unwrap() = -BaseElement::ONE;
+    assert!(Proof::from_elements::<Rp64_256>(&tampered).is_err());
+
+    // an invalid trace length is rejected; the trace length is the fourth element of the proof
+    let mut tampered = elements.clone();
+    tampered[3] = BaseElement::new(12);
+    assert!(Proof::from_elements::<Rp64_256>(&tampered).is_err());
+}
+
+#[test]
+pub fn starkproof_to_elements_native_digests() {
+    let digests = [Rp64_256::hash(&[1]), Rp64_256::hash(&[2]), Rp64_256::hash(&[3])];
+    let mut proof = Proof::new_dummy();
+    let trace_info = TraceInfo::with_meta(2, 8, vec![1, 2, 3])
+        .with_constant_columns(&[(1, BaseElement::new(5))]);
+    proof.context = Context::new::<BaseElement>(trace_info, proof.options().clone())
+        .with_hash_id(3)
+        .with_pow_id(4);
+    proof.commitments =
+        Commitments::new::<Rp64_256>(vec![digests[0]], digests[1], vec![digests[2]]);
+    proof.constraint_queries = Queries::new::<Rp64_256, BaseElement, MerkleTree<_>>(
+        BatchMerkleProof::<Rp64_256> {
+            nodes: vec![vec![digests[1], digests[2]]],
+            depth: 3,
+        },
+        vec![vec![BaseElement::ONE]],
+    );
+    proof.trace_queries.push(proof.constraint_queries.clone());
+    let elements = proof.to_elements::<Rp64_256>();
+    assert_eq!(Ok(proof), Proof::from_elements::<Rp64_256>(&elements));
+
+    // commitments and Merkle path nodes are written as the elements of the digests
+    let digest_elements = |digests: &[<Rp64_256 as Hasher>::Digest]| {
+        digests
+            .iter()
+            .flat_map(Rp64_256::digest_as_elements)
+            .copied()
+            .collect::<Vec<_>>()
+    };
+    let contains = |needle: &[BaseElement]| elements.windows(needle.len()).any(|w| w == needle);
+    let mut commitments = vec![BaseElement::new(3)];
+    commitments.extend(digest_elements(&digests));
+    assert!(contains(&commitments));
+    let mut nodes = vec![BaseElement::new(2)];
+    nodes.extend(digest_elements(&digests[1..]));
+    assert!(contains(&nodes));
+}
+
+#[test]
+#[should_panic(expected = "proof was not generated over the specified base field")]
+pub fn starkproof_to_elements_wrong_field() {
+    Proof::new_dummy().to_elements::<Rp62_248>();
+}
+
+#[cfg(feature = "compression")]
+#[test]
+pub fn starkproof_to_bytes_compressed() {
+    use super::{COMPRESSED_PROOF_MAGIC, COMPRESSION_METHOD_ZLIB, DEFAULT_MAX_PROOF_SIZE};
+
+    let mut proof = Proof::new_dummy();
+    proof.trace_queries.push(proof.constraint_queries.clone());
+    proof.gkr_proof = Some(vec![0; 1024]);
+
+    let bytes = proof.to_bytes_compressed();
+    assert_eq!(COMPRESSED_PROOF_MAGIC, bytes[..3]);
+    assert_eq!(COMPRESSION_METHOD_ZLIB, bytes[3]);
+    assert!(bytes.len() < proof.to_bytes().len());
+    assert_eq!(Ok(proof.clone()), Proof::from_bytes_compressed(&bytes));
+
+    // uncompressed proofs and unsupported compression methods are rejected
+    assert!(Proof::from_bytes_compressed(&proof.to_bytes()).is_err());
+    let mut tampered = bytes.clone();
+    tampered[3] = 2;
+    assert!(Proof::from_bytes_compressed(&tampered).is_err());
+
+    // truncated and extended sources are rejected
+    assert!(Proof::from_bytes_compressed(&bytes[..bytes.len() - 1]).is_err());
+    let mut extended = bytes.clone();
+    extended.push(0);
+    assert_eq!(
+        Err(DeserializationError::UnconsumedBytes),
+        Proof::from_bytes_compressed(&extended)
+    );
+
+    // proofs which decompress into more than the maximum proof size are rejected
+    let mut large_proof = proof;
+    large_proof.gkr_proof = Some(vec![0; DEFAULT_MAX_PROOF_SIZE]);
+    let bytes = large_proof.to_bytes_compressed();
+    assert!(bytes.len() < DEFAULT_MAX_PROOF_SIZE);
+    assert!(Proof::from_bytes_compressed(&bytes).is_err());
+
+    // a larger limit can be applied explicitly
+    let limit = large_proof.to_bytes().len();
+    assert_eq!(Ok(large_proof), Proof::from_bytes_compressed_with_limit(&bytes, limit));
+    assert!(Proof::from_bytes_compressed_with_limit(&bytes, limit - 1).is_err());
+}
+
+#[test]
+pub fn queries_parse_openings() {
+    let domain_size = 16;
+    let rows = (0..domain_size)
+        .map(|i| (0..3).map(|j| BaseElement::new((i * 3 + j) as u64)).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let leaves = rows.iter().map(|row| Blake3::hash_elements(row)).collect::<Vec<_>>();
+    let tree = MerkleTree::<Blake3>::new(leaves).unwrap();
+
+    let positions = [1, 4, 5, 11];
+    let (_, opening_proof) = tree.open_many(&positions).unwrap();
+    let query_values = positions.iter().map(|&p| rows[p].clone()).collect::<Vec<_>>();
+    let queries =
+        Queries::new::<Blake3, BaseElement, MerkleTree<Blake3>>(opening_proof, query_values);
+
+    let openings = queries
+        .parse_openings::<BaseElement, Blake3>(
+            &positions,
+            domain_size,
+            3,
+            PartitionOptions::default(),
+        )
+        .unwrap();
+    assert_eq!(positions.len(), openings.len());
+    for (opening, &position) in openings.zip(positions.iter()) {
+        assert_eq!(position, opening.position);
+        assert_eq!(rows[position], opening.values);
+        assert_eq!(tree.prove(position).unwrap().1, opening.merkle_path);
+
+        let leaf = Blake3::hash_elements(&opening.values);
+        assert!(MerkleTree::<Blake3>::verify(*tree.root(), position, leaf, &opening.merkle_path)
+            .is_ok());
+    }
+}
+
+#[test]
+pub fn queries_parse_openings_wrong_num_positions() {
+    let rows = (0..8).map(|i| vec![BaseElement::new(i)]).collect::<Vec<_>>();
+    let leaves = rows.iter().map(|row| Blake3::hash_elements(row)).collect::<Vec<_>>();
+    let tree = MerkleTree::<Blake3>::new(leaves).unwrap();
+
+    let (_, opening_proof) = tree.open_many(&[2, 3]).unwrap();
+    let queries = Queries::new::<Blake3, BaseElement, MerkleTree<Blake3>>(
+        opening_proof,
+        vec![rows[2].clone(), rows[3].clone()],
+    );
+
+    let result =
+        queries.parse_openings::<BaseElement, Blake3>(&[2], 8, 1, PartitionOptions::default());
+    assert!(result.is_err());
+}
+
+#[test]
+pub fn starkproof_query_openings() {
+    // the dummy proof describes a trace with a single column and an LDE domain of size 16
+    let mut proof = Proof::new_dummy();
+    let domain_size = proof.lde_domain_size();
+    let positions = [0, 3, 9];
+
+    let main_rows = (0..domain_size).map(|i| vec![BaseElement::new(i as u64)]).collect();
+    let (main_queries, main_tree) = build_queries(main_rows, &positions);
+    proof.trace_queries.push(main_queries);
+
+    let constraint_rows = (0..domain_size)
+        .map(|i| vec![BaseElement::new(i as u64), BaseElement::new(2 * i as u64)])
+        .collect();
+    let (constraint_queries, constraint_tree) = build_queries(constraint_rows, &positions);
+    proof.constraint_queries = constraint_queries;
+
+    for (openings, tree, width) in [
+        (proof.main_trace_openings::<Blake3>(&positions).unwrap(), main_tree, 1),
+        (
+            proof.constraint_openings::<BaseElement, Blake3>(&positions).unwrap(),
+            constraint_tree,
+            2,
+        ),
+    ] {
+        assert_eq!(positions.len(), openings.len());
+        for (opening, &position) in openings.zip(positions.iter()) {
+            assert_eq!(position, opening.position);
+            assert_eq!(width, opening.values.len());
+            let leaf = Blake3::hash_elements(&opening.values);
+            assert!(MerkleTree::<Blake3>::verify(
+                *tree.root(),
+                position,
+                leaf,
+                &opening.merkle_path
+            )
+            .is_ok());
+        }
+    }
+
+    // the trace has a single segment, and thus, there are no auxiliary trace openings
+    assert!(proof.aux_trace_openings::<BaseElement, Blake3>(&positions).unwrap().is_none());
+
+    // positions which do not match the number of queries are rejected
+    assert!(proof.main_trace_openings::<Blake3>(&positions[..2]).is_err());
+    assert!(proof.constraint_openings::<BaseElement, Blake3>(&positions[..2]).is_err());
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns queries opened at `positions` against a Merkle tree built from `rows`, together with
+/// the tree.
+fn build_queries(
+    rows: Vec<Vec<BaseElement>>,
+    positions: &[usize],
+) -> (Queries, MerkleTree<Blake3>) {
+    let leaves = rows.iter().map(|row| Blake3::hash_elements(row)).collect::<Vec<_>>();
+    let tree = MerkleTree::<Blake3>::new(leaves).unwrap();
+    let (_, opening_proof) = tree.open_many(positions).unwrap();
+    let query_values = positions.iter().map(|&p| rows[p].clone()).collect::<Vec<_>>();
+    let queries =
+        Queries::new::<Blake3, BaseElement, MerkleTree<Blake3>>(opening_proof, query_values);
+    (queries, tree)
+}
+
+/// Returns a serialized dummy proof which can be deserialized back into a [Proof].
+fn build_dummy_proof_bytes() -> Vec<u8> {
+    let mut proof = Proof::new_dummy();
+    proof.trace_queries.push(proof.constraint_queries.clone());
+    proof.to_bytes()
+}
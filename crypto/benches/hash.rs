@@ -7,13 +7,16 @@ use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion
 use math::fields::f128;
 use rand_utils::rand_value;
 use winter_crypto::{
-    hashers::{Blake3_256, Rp62_248, Rp64_256, RpJive64_256, Sha3_256},
+    hashers::{Blake2s_256, Blake3_256, Rp62_248, Rp64_256, RpJive64_256, Sha3_256},
     Hasher,
 };
 
 type Blake3 = Blake3_256<f128::BaseElement>;
 type Blake3Digest = <Blake3 as Hasher>::Digest;
 
+type Blake2s = Blake2s_256<f128::BaseElement>;
+type Blake2sDigest = <Blake2s as Hasher>::Digest;
+
 type Sha3 = Sha3_256<f128::BaseElement>;
 type Sha3Digest = <Sha3 as Hasher>::Digest;
 
@@ -39,6 +42,24 @@ fn blake3(c: &mut Criterion) {
     });
 }
 
+fn blake2s(c: &mut Criterion) {
+    let v: [Blake2sDigest; 2] = [Blake2s::hash(&[1u8]), Blake2s::hash(&[2u8])];
+    c.bench_function("hash_blake2s (cached)", |bench| bench.iter(|| Blake2s::merge(black_box(&v))));
+
+    c.bench_function("hash_blake2s (random)", |b| {
+        b.iter_batched(
+            || {
+                [
+                    Blake2s::hash(&rand_value::<u64>().to_le_bytes()),
+                    Blake2s::hash(&rand_value::<u64>().to_le_bytes()),
+                ]
+            },
+            |state| Blake2s::merge(&state),
+            BatchSize::SmallInput,
+        )
+    });
+}
+
 fn sha3(c: &mut Criterion) {
     let v: [Sha3Digest; 2] = [Sha3::hash(&[1u8]), Sha3::hash(&[2u8])];
     c.bench_function("hash_sha3 (cached)", |bench| bench.iter(|| Sha3::merge(black_box(&v))));
@@ -117,5 +138,5 @@ fn rescue_jive256(c: &mut Criterion) {
     });
 }
 
-criterion_group!(hash_group, blake3, sha3, rescue248, rescue256, rescue_jive256);
+criterion_group!(hash_group, blake3, blake2s, sha3, rescue248, rescue256, rescue_jive256);
 criterion_main!(hash_group);
@@ -3,11 +3,11 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
 use math::fields::f128::BaseElement;
 use rand_utils::rand_value;
 use utils::uninit_vector;
-use winter_crypto::{build_merkle_nodes, concurrent, hashers::Blake3_256, Hasher};
+use winter_crypto::{build_merkle_nodes, concurrent, hashers::Blake3_256, Hasher, MerkleTree};
 
 type Blake3 = Blake3_256<BaseElement>;
 type Blake3Digest = <Blake3 as Hasher>::Digest;
@@ -35,5 +35,30 @@ pub fn merkle_tree_construction(c: &mut Criterion) {
     }
 }
 
-criterion_group!(merkle_group, merkle_tree_construction,);
+pub fn merkle_tree_concurrency_threshold(c: &mut Criterion) {
+    let mut merkle_group = c.benchmark_group("merkle tree concurrency threshold");
+    merkle_group.sample_size(10);
+
+    static THRESHOLDS: [usize; 4] = [1024, 16384, 262144, 4194304];
+    const NUM_LEAVES: usize = 1 << 22;
+
+    let data: Vec<Blake3Digest> = (0..NUM_LEAVES)
+        .map(|_| Blake3::hash(&rand_value::<u128>().to_le_bytes()))
+        .collect();
+    for threshold in &THRESHOLDS {
+        merkle_group.bench_with_input(
+            BenchmarkId::new(format!("{NUM_LEAVES} leaves"), threshold),
+            &data,
+            |b, i| {
+                b.iter_batched(
+                    || i.clone(),
+                    |leaves| MerkleTree::<Blake3>::with_concurrency_threshold(leaves, *threshold),
+                    BatchSize::LargeInput,
+                )
+            },
+        );
+    }
+}
+
+criterion_group!(merkle_group, merkle_tree_construction, merkle_tree_concurrency_threshold);
 criterion_main!(merkle_group);
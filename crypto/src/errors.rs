@@ -69,11 +69,15 @@ impl core::error::Error for MerkleTreeError {}
 /// Defines errors which can occur when drawing values from a random coin.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum RandomCoinError {
-    /// A valid element could not be drawn from the field after the specified number of tries.
+    /// A valid element could not be drawn from the field after the specified number of tries;
+    /// that is, all attempts allowed for rejection sampling were exhausted.
     FailedToDrawFieldElement(usize),
     /// The required number of integer values could not be drawn from the specified domain after
     /// the specified number of tries.
     FailedToDrawIntegers(usize, usize, usize),
+    /// The maximum number of attempts to draw a value was set to zero, and thus, no value could
+    /// be drawn.
+    InvalidMaxAttempts,
 }
 
 impl fmt::Display for RandomCoinError {
@@ -88,6 +92,9 @@ impl fmt::Display for RandomCoinError {
                     "needed to draw {num_expected} integers from a domain, but drew only {num_actual} after {num_tries} tries"
                 )
             },
+            Self::InvalidMaxAttempts => {
+                write!(f, "maximum number of attempts to draw a value must be greater than zero")
+            },
         }
     }
 }
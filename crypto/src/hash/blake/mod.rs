@@ -6,7 +6,7 @@
 use core::{fmt::Debug, marker::PhantomData};
 
 use math::{FieldElement, StarkField};
-use utils::ByteWriter;
+use utils::{accounting, ByteWriter};
 
 use super::{ByteDigest, ElementHasher, Hasher};
 
@@ -27,18 +27,22 @@ impl<B: StarkField> Hasher for Blake3_256<B> {
     const COLLISION_RESISTANCE: u32 = 128;
 
     fn hash(bytes: &[u8]) -> Self::Digest {
+        accounting::record_hash();
         ByteDigest(*blake3::hash(bytes).as_bytes())
     }
 
     fn merge(values: &[Self::Digest; 2]) -> Self::Digest {
+        accounting::record_hash();
         ByteDigest(blake3::hash(ByteDigest::digests_as_bytes(values)).into())
     }
 
     fn merge_many(values: &[Self::Digest]) -> Self::Digest {
+        accounting::record_hash();
         ByteDigest(blake3::hash(ByteDigest::digests_as_bytes(values)).into())
     }
 
     fn merge_with_int(seed: Self::Digest, value: u64) -> Self::Digest {
+        accounting::record_hash();
         let mut data = [0; 40];
         data[..32].copy_from_slice(&seed.0);
         data[32..].copy_from_slice(&value.to_le_bytes());
@@ -50,6 +54,7 @@ impl<B: StarkField> ElementHasher for Blake3_256<B> {
     type BaseField = B;
 
     fn hash_elements<E: FieldElement<BaseField = Self::BaseField>>(elements: &[E]) -> Self::Digest {
+        accounting::record_hash();
         if B::IS_CANONICAL {
             // when element's internal and canonical representations are the same, we can hash
             // element bytes directly
@@ -79,21 +84,25 @@ impl<B: StarkField> Hasher for Blake3_192<B> {
     const COLLISION_RESISTANCE: u32 = 96;
 
     fn hash(bytes: &[u8]) -> Self::Digest {
+        accounting::record_hash();
         let result = blake3::hash(bytes);
         ByteDigest(result.as_bytes()[..24].try_into().unwrap())
     }
 
     fn merge(values: &[Self::Digest; 2]) -> Self::Digest {
+        accounting::record_hash();
         let result = blake3::hash(ByteDigest::digests_as_bytes(values));
         ByteDigest(result.as_bytes()[..24].try_into().unwrap())
     }
 
     fn merge_many(values: &[Self::Digest]) -> Self::Digest {
+        accounting::record_hash();
         let result = blake3::hash(ByteDigest::digests_as_bytes(values));
         ByteDigest(result.as_bytes()[..24].try_into().unwrap())
     }
 
     fn merge_with_int(seed: Self::Digest, value: u64) -> Self::Digest {
+        accounting::record_hash();
         let mut data = [0; 32];
         data[..24].copy_from_slice(&seed.0);
         data[24..].copy_from_slice(&value.to_le_bytes());
@@ -107,6 +116,7 @@ impl<B: StarkField> ElementHasher for Blake3_192<B> {
     type BaseField = B;
 
     fn hash_elements<E: FieldElement<BaseField = Self::BaseField>>(elements: &[E]) -> Self::Digest {
+        accounting::record_hash();
         if B::IS_CANONICAL {
             // when element's internal and canonical representations are the same, we can hash
             // element bytes directly
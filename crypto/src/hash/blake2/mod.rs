@@ -0,0 +1,102 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use core::{fmt::Debug, marker::PhantomData};
+
+use blake2::Digest;
+use math::{FieldElement, StarkField};
+use utils::{accounting, ByteWriter};
+
+use super::{ByteDigest, ElementHasher, Hasher};
+
+#[cfg(test)]
+mod tests;
+
+// BLAKE2s 256-BIT OUTPUT
+// ================================================================================================
+
+/// Implementation of the [Hasher](super::Hasher) trait for BLAKE2s hash function with 256-bit
+/// output.
+///
+/// BLAKE2s operates on 32-bit words, and thus, on platforms without wide SIMD support (e.g.,
+/// WebAssembly or 32-bit embedded targets) it may outperform BLAKE3.
+#[allow(non_camel_case_types)]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Blake2s_256<B: StarkField>(PhantomData<B>);
+
+impl<B: StarkField> Hasher for Blake2s_256<B> {
+    type Digest = ByteDigest<32>;
+
+    const COLLISION_RESISTANCE: u32 = 128;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        accounting::record_hash();
+        ByteDigest(blake2::Blake2s256::digest(bytes).into())
+    }
+
+    fn merge(values: &[Self::Digest; 2]) -> Self::Digest {
+        accounting::record_hash();
+        ByteDigest(blake2::Blake2s256::digest(ByteDigest::digests_as_bytes(values)).into())
+    }
+
+    fn merge_many(values: &[Self::Digest]) -> Self::Digest {
+        accounting::record_hash();
+        ByteDigest(blake2::Blake2s256::digest(ByteDigest::digests_as_bytes(values)).into())
+    }
+
+    fn merge_with_int(seed: Self::Digest, value: u64) -> Self::Digest {
+        accounting::record_hash();
+        let mut data = [0; 40];
+        data[..32].copy_from_slice(&seed.0);
+        data[32..].copy_from_slice(&value.to_le_bytes());
+        ByteDigest(blake2::Blake2s256::digest(data).into())
+    }
+}
+
+impl<B: StarkField> ElementHasher for Blake2s_256<B> {
+    type BaseField = B;
+
+    fn hash_elements<E: FieldElement<BaseField = Self::BaseField>>(elements: &[E]) -> Self::Digest {
+        accounting::record_hash();
+        if B::IS_CANONICAL {
+            // when element's internal and canonical representations are the same, we can hash
+            // element bytes directly
+            let bytes = E::elements_as_bytes(elements);
+            ByteDigest(blake2::Blake2s256::digest(bytes).into())
+        } else {
+            // when elements' internal and canonical representations differ, we need to serialize
+            // them before hashing
+            let mut hasher = Blake2sHasher::new();
+            hasher.write_many(elements);
+            ByteDigest(hasher.finalize())
+        }
+    }
+}
+
+// BLAKE2s HASHER
+// ================================================================================================
+
+/// Wrapper around BLAKE2s hasher to implement [ByteWriter] trait for it.
+struct Blake2sHasher(blake2::Blake2s256);
+
+impl Blake2sHasher {
+    pub fn new() -> Self {
+        Self(blake2::Blake2s256::new())
+    }
+
+    pub fn finalize(self) -> [u8; 32] {
+        self.0.finalize().into()
+    }
+}
+
+impl ByteWriter for Blake2sHasher {
+    fn write_u8(&mut self, value: u8) {
+        self.0.update([value]);
+    }
+
+    fn write_bytes(&mut self, values: &[u8]) {
+        self.0.update(values);
+    }
+}
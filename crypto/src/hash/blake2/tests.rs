@@ -0,0 +1,95 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use math::{
+    fields::{f128, f62::BaseElement},
+    FieldElement,
+};
+use rand_utils::rand_array;
+use utils::{Deserializable, Serializable};
+
+use super::{Blake2s_256, ElementHasher, Hasher};
+use crate::hash::{ByteDigest, Digest};
+
+#[test]
+fn hash_test_vectors() {
+    // test vectors from the BLAKE2 reference implementation (RFC 7693)
+    let expected: [u8; 32] = [
+        0x69, 0x21, 0x7a, 0x30, 0x79, 0x90, 0x80, 0x94, 0xe1, 0x11, 0x21, 0xd0, 0x42, 0x35, 0x4a,
+        0x7c, 0x1f, 0x55, 0xb6, 0x48, 0x2c, 0xa1, 0xa5, 0x1e, 0x1b, 0x25, 0x0d, 0xfd, 0x1e, 0xd0,
+        0xee, 0xf9,
+    ];
+    assert_eq!(expected, Blake2s_256::<BaseElement>::hash(b"").as_bytes());
+
+    let expected: [u8; 32] = [
+        0x50, 0x8c, 0x5e, 0x8c, 0x32, 0x7c, 0x14, 0xe2, 0xe1, 0xa7, 0x2b, 0xa3, 0x4e, 0xeb, 0x45,
+        0x2f, 0x37, 0x45, 0x8b, 0x20, 0x9e, 0xd6, 0x3a, 0x29, 0x4d, 0x99, 0x9b, 0x4c, 0x86, 0x67,
+        0x59, 0x82,
+    ];
+    assert_eq!(expected, Blake2s_256::<BaseElement>::hash(b"abc").as_bytes());
+}
+
+#[test]
+fn merge_test_vector() {
+    let digest_0 = ByteDigest::read_from_bytes(&[1_u8; 32]).unwrap();
+    let digest_1 = ByteDigest::read_from_bytes(&[2_u8; 32]).unwrap();
+
+    // merging two digests should be the same as hashing their concatenation
+    let expected: [u8; 32] = [
+        0x28, 0x05, 0x69, 0x93, 0x23, 0x78, 0xc9, 0x9f, 0x44, 0x8d, 0xf3, 0x7e, 0x89, 0x3f, 0x06,
+        0x2f, 0xab, 0x95, 0x1b, 0xea, 0x53, 0x51, 0x56, 0x34, 0xb7, 0x87, 0x5a, 0xe5, 0x1e, 0x19,
+        0x54, 0xe7,
+    ];
+    let result = Blake2s_256::<BaseElement>::merge(&[digest_0, digest_1]);
+    assert_eq!(expected, result.as_bytes());
+}
+
+#[test]
+fn hash_padding() {
+    let b1 = [1_u8, 2, 3];
+    let b2 = [1_u8, 2, 3, 0];
+
+    // adding a zero bytes at the end of a byte string should result in a different hash
+    let r1 = Blake2s_256::<BaseElement>::hash(&b1);
+    let r2 = Blake2s_256::<BaseElement>::hash(&b2);
+    assert_ne!(r1, r2);
+}
+
+#[test]
+fn hash_elements_padding() {
+    let e1: [BaseElement; 2] = rand_array();
+    let e2 = [e1[0], e1[1], BaseElement::ZERO];
+
+    // adding a zero element at the end of a list of elements should result in a different hash
+    let r1 = Blake2s_256::hash_elements(&e1);
+    let r2 = Blake2s_256::hash_elements(&e2);
+    assert_ne!(r1, r2);
+}
+
+#[test]
+fn hash_elements_vs_hash() {
+    // for canonical fields, hashing elements should be the same as hashing their serialization
+    let e1: [f128::BaseElement; 4] = rand_array();
+    let r1 = Blake2s_256::hash_elements(&e1);
+    let r2 = Blake2s_256::<f128::BaseElement>::hash(&e1.to_bytes());
+    assert_eq!(r1, r2);
+
+    // for non-canonical fields, elements should be serialized before hashing
+    let e1: [BaseElement; 4] = rand_array();
+    let r1 = Blake2s_256::hash_elements(&e1);
+    let r2 = Blake2s_256::<BaseElement>::hash(&e1.to_bytes());
+    assert_eq!(r1, r2);
+}
+
+#[test]
+fn merge_vs_merge_many() {
+    let digest_0 = ByteDigest::read_from_bytes(&[1_u8; 32]).unwrap();
+    let digest_1 = ByteDigest::read_from_bytes(&[2_u8; 32]).unwrap();
+
+    let r1 = Blake2s_256::<BaseElement>::merge(&[digest_0, digest_1]);
+    let r2 = Blake2s_256::<BaseElement>::merge_many(&[digest_0, digest_1]);
+
+    assert_eq!(r1, r2)
+}
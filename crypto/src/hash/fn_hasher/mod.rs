@@ -0,0 +1,117 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use core::{fmt::Debug, marker::PhantomData};
+
+use math::{FieldElement, StarkField};
+use utils::accounting;
+
+use super::{ByteDigest, ElementHasher, Hasher};
+
+#[cfg(test)]
+mod tests;
+
+// HASH FUNCTIONS
+// ================================================================================================
+
+/// Defines functions of a custom hash function with 256-bit output wrapped by [FnHasher].
+///
+/// Each of the functions can be specified as a non-capturing closure. For example, a hasher which
+/// delegates to BLAKE3 could be defined as:
+///
+/// ```
+/// # use winter_crypto::{hashers::{FnHasher, HashFunctions}, Hasher};
+/// # use math::{fields::f128::BaseElement, FieldElement};
+/// struct MyHash;
+///
+/// impl HashFunctions for MyHash {
+///     type BaseField = BaseElement;
+///     const COLLISION_RESISTANCE: u32 = 128;
+///     const HASH: fn(&[u8]) -> [u8; 32] = |bytes| blake3::hash(bytes).into();
+///     const MERGE: fn(&[u8; 32], &[u8; 32]) -> [u8; 32] =
+///         |a, b| blake3::hash(&[*a, *b].concat()).into();
+///     const HASH_ELEMENTS: fn(&[BaseElement]) -> [u8; 32] =
+///         |elements| blake3::hash(BaseElement::elements_as_bytes(elements)).into();
+/// }
+///
+/// type MyHasher = FnHasher<MyHash>;
+/// let digest = MyHasher::hash(b"hello");
+/// ```
+pub trait HashFunctions {
+    /// Base field of the elements hashed by [HASH_ELEMENTS](Self::HASH_ELEMENTS).
+    type BaseField: StarkField;
+
+    /// Collision resistance of the hash function measured in bits.
+    const COLLISION_RESISTANCE: u32;
+
+    /// Returns a hash of the provided sequence of bytes.
+    ///
+    /// This function is also used to hash many digests and to hash a digest together with an
+    /// integer value (see [FnHasher] for details).
+    const HASH: fn(&[u8]) -> [u8; 32];
+
+    /// Returns a hash of two digests.
+    const MERGE: fn(&[u8; 32], &[u8; 32]) -> [u8; 32];
+
+    /// Returns a hash of the provided base field elements.
+    ///
+    /// Elements in extensions of the base field are passed to this function as the base field
+    /// elements they consist of.
+    const HASH_ELEMENTS: fn(&[Self::BaseField]) -> [u8; 32];
+}
+
+// FN HASHER
+// ================================================================================================
+
+/// Implementation of the [Hasher](super::Hasher) and [ElementHasher](super::ElementHasher) traits
+/// which delegates to functions defined by `F`.
+///
+/// This makes it possible to use a custom hash function (e.g., a hardware-accelerated one) in the
+/// prover and the verifier by specifying only its core functions via [HashFunctions]. Digests are
+/// represented by [ByteDigest] of 32 bytes. The remaining methods of the [Hasher] trait are
+/// derived from [HASH](HashFunctions::HASH) as follows:
+/// * `merge_many` hashes the concatenation of the digests' bytes.
+/// * `merge_with_int` hashes the bytes of the seed followed by the little-endian bytes of the
+///   value.
+#[derive(Debug, PartialEq, Eq)]
+pub struct FnHasher<F: HashFunctions>(PhantomData<F>);
+
+impl<F: HashFunctions> Hasher for FnHasher<F> {
+    type Digest = ByteDigest<32>;
+
+    const COLLISION_RESISTANCE: u32 = F::COLLISION_RESISTANCE;
+
+    fn hash(bytes: &[u8]) -> Self::Digest {
+        accounting::record_hash();
+        ByteDigest((F::HASH)(bytes))
+    }
+
+    fn merge(values: &[Self::Digest; 2]) -> Self::Digest {
+        accounting::record_hash();
+        ByteDigest((F::MERGE)(&values[0].0, &values[1].0))
+    }
+
+    fn merge_many(values: &[Self::Digest]) -> Self::Digest {
+        accounting::record_hash();
+        ByteDigest((F::HASH)(ByteDigest::digests_as_bytes(values)))
+    }
+
+    fn merge_with_int(seed: Self::Digest, value: u64) -> Self::Digest {
+        accounting::record_hash();
+        let mut data = [0; 40];
+        data[..32].copy_from_slice(&seed.0);
+        data[32..].copy_from_slice(&value.to_le_bytes());
+        ByteDigest((F::HASH)(&data))
+    }
+}
+
+impl<F: HashFunctions> ElementHasher for FnHasher<F> {
+    type BaseField = F::BaseField;
+
+    fn hash_elements<E: FieldElement<BaseField = Self::BaseField>>(elements: &[E]) -> Self::Digest {
+        accounting::record_hash();
+        ByteDigest((F::HASH_ELEMENTS)(E::slice_as_base_elements(elements)))
+    }
+}
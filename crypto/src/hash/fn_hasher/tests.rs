@@ -0,0 +1,60 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use math::{
+    fields::{f128::BaseElement, QuadExtension},
+    FieldElement,
+};
+use rand_utils::{rand_array, rand_value};
+
+use super::{ElementHasher, FnHasher, HashFunctions, Hasher};
+use crate::hash::{Blake3_256, ByteDigest};
+
+struct Blake3Functions;
+
+impl HashFunctions for Blake3Functions {
+    type BaseField = BaseElement;
+    const COLLISION_RESISTANCE: u32 = 128;
+    const HASH: fn(&[u8]) -> [u8; 32] = |bytes| blake3::hash(bytes).into();
+    const MERGE: fn(&[u8; 32], &[u8; 32]) -> [u8; 32] =
+        |a, b| blake3::hash(&[*a, *b].concat()).into();
+    const HASH_ELEMENTS: fn(&[BaseElement]) -> [u8; 32] =
+        |elements| blake3::hash(BaseElement::elements_as_bytes(elements)).into();
+}
+
+type Blake3FnHasher = FnHasher<Blake3Functions>;
+
+#[test]
+fn fn_hasher_matches_wrapped_hash() {
+    let bytes: [u8; 48] = rand_array();
+    assert_eq!(Blake3_256::<BaseElement>::hash(&bytes), Blake3FnHasher::hash(&bytes));
+
+    let digests = [ByteDigest::new(rand_array()), ByteDigest::new(rand_array())];
+    assert_eq!(Blake3_256::<BaseElement>::merge(&digests), Blake3FnHasher::merge(&digests));
+    assert_eq!(
+        Blake3_256::<BaseElement>::merge_many(&digests),
+        Blake3FnHasher::merge_many(&digests)
+    );
+
+    let value: u64 = rand_value();
+    assert_eq!(
+        Blake3_256::<BaseElement>::merge_with_int(digests[0], value),
+        Blake3FnHasher::merge_with_int(digests[0], value)
+    );
+
+    // f128 elements are canonical, and thus, are hashed by BLAKE3 hasher as raw bytes as well
+    let elements: [BaseElement; 4] = rand_array();
+    assert_eq!(Blake3_256::hash_elements(&elements), Blake3FnHasher::hash_elements(&elements));
+}
+
+#[test]
+fn fn_hasher_extension_elements() {
+    let elements: [QuadExtension<BaseElement>; 3] = rand_array();
+    let base_elements = QuadExtension::<BaseElement>::slice_as_base_elements(&elements);
+    assert_eq!(
+        Blake3FnHasher::hash_elements(&elements),
+        Blake3FnHasher::hash_elements(base_elements)
+    );
+}
@@ -3,17 +3,24 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use core::{fmt::Debug, slice};
+use alloc::{string::String, vec::Vec};
+use core::{fmt::Debug, ops::Range, slice};
 
 use math::{FieldElement, StarkField};
-use utils::{ByteReader, Deserializable, DeserializationError, Serializable};
+use utils::{ByteReader, Deserializable, DeserializationError, Serializable, SliceReader};
 
 mod blake;
 pub use blake::{Blake3_192, Blake3_256};
 
+mod blake2;
+pub use self::blake2::Blake2s_256;
+
 mod sha;
 pub use sha::Sha3_256;
 
+mod fn_hasher;
+pub use fn_hasher::{FnHasher, HashFunctions};
+
 mod mds;
 
 mod rescue;
@@ -63,6 +70,38 @@ pub trait ElementHasher: Hasher {
         E: FieldElement<BaseField = Self::BaseField>;
 }
 
+/// Defines an algebraic hash function built using a sponge construction over a permutation of
+/// field elements.
+///
+/// This trait exposes the underlying permutation of the hash function so that field elements can
+/// be absorbed into (and squeezed out of) the sponge state directly, without converting them into
+/// bytes. This is used by [ElementRandomCoin](crate::ElementRandomCoin) to make the entire
+/// Fiat-Shamir transcript algebraic.
+pub trait AlgebraicSponge: ElementHasher {
+    /// Number of field elements in the sponge state.
+    const STATE_WIDTH: usize;
+
+    /// Range of state elements which form the rate portion of the sponge state. The remaining
+    /// elements of the state form the capacity portion.
+    const RATE_RANGE: Range<usize>;
+
+    /// Number of field elements in a digest of this hash function.
+    const DIGEST_SIZE: usize;
+
+    /// Applies the permutation of this hash function to the provided `state`.
+    ///
+    /// # Panics
+    /// Panics if the length of `state` is not equal to [STATE_WIDTH](Self::STATE_WIDTH).
+    fn apply_permutation(state: &mut [Self::BaseField]);
+
+    /// Returns the field elements of which the specified `digest` consists.
+    fn digest_as_elements(digest: &Self::Digest) -> &[Self::BaseField];
+
+    /// Returns a digest consisting of the specified field `elements`, or None if the number of
+    /// elements is not equal to [DIGEST_SIZE](Self::DIGEST_SIZE).
+    fn digest_from_elements(elements: &[Self::BaseField]) -> Option<Self::Digest>;
+}
+
 // DIGEST TRAIT
 // ================================================================================================
 
@@ -77,6 +116,67 @@ pub trait Digest:
     /// upper limit on the possible digest size. For digests which are smaller than 32 bytes, the
     /// unused bytes should be set to 0.
     fn as_bytes(&self) -> [u8; 32];
+
+    /// Returns a hex string encoding of this digest.
+    ///
+    /// The string encodes the bytes of the serialized digest (i.e., the bytes returned from
+    /// [Serializable::to_bytes()]) using lowercase characters, and thus, the digest can be
+    /// recovered from the string via [Digest::from_hex()]. This is a uniform way to log and
+    /// transmit digests regardless of the underlying hash function.
+    fn to_hex(&self) -> String {
+        const HEX_CHARS: &[u8; 16] = b"0123456789abcdef";
+        let bytes = self.to_bytes();
+        let mut result = String::with_capacity(bytes.len() * 2);
+        for byte in bytes {
+            result.push(HEX_CHARS[(byte >> 4) as usize] as char);
+            result.push(HEX_CHARS[(byte & 0xf) as usize] as char);
+        }
+        result
+    }
+
+    /// Returns a digest parsed from the specified hex string.
+    ///
+    /// The string is expected to be in the format returned from [Digest::to_hex()]; both lower
+    /// and upper case characters are accepted.
+    ///
+    /// # Errors
+    /// Returns an error if the string is not a valid hex string, or if the decoded bytes do not
+    /// represent a valid serialized digest.
+    fn from_hex(hex: &str) -> Result<Self, DeserializationError> {
+        let bytes = hex_to_bytes(hex)?;
+        let mut reader = SliceReader::new(&bytes);
+        let digest = Self::read_from(&mut reader)?;
+        if reader.has_more_bytes() {
+            return Err(DeserializationError::UnconsumedBytes);
+        }
+        Ok(digest)
+    }
+}
+
+/// Decodes the specified hex string into a vector of bytes.
+fn hex_to_bytes(hex: &str) -> Result<Vec<u8>, DeserializationError> {
+    fn hex_value(c: u8) -> Result<u8, DeserializationError> {
+        match c {
+            b'0'..=b'9' => Ok(c - b'0'),
+            b'a'..=b'f' => Ok(c - b'a' + 10),
+            b'A'..=b'F' => Ok(c - b'A' + 10),
+            _ => Err(DeserializationError::InvalidValue(format!(
+                "invalid hex character '{}'",
+                c as char
+            ))),
+        }
+    }
+
+    let hex = hex.as_bytes();
+    if hex.len() % 2 != 0 {
+        return Err(DeserializationError::InvalidValue(format!(
+            "hex string must have an even number of characters, but had {}",
+            hex.len()
+        )));
+    }
+    hex.chunks(2)
+        .map(|pair| Ok((hex_value(pair[0])? << 4) | hex_value(pair[1])?))
+        .collect()
 }
 
 // BYTE DIGEST
@@ -133,6 +233,8 @@ impl<const N: usize> Deserializable for ByteDigest<N> {
 
 #[cfg(test)]
 mod tests {
+    use utils::DeserializationError;
+
     use super::{ByteDigest, Digest};
 
     #[test]
@@ -145,4 +247,27 @@ mod tests {
         expected[31] = 0;
         assert_eq!(expected, d.as_bytes());
     }
+
+    #[test]
+    fn byte_digest_hex() {
+        let d = ByteDigest::new([0x0f_u8, 0xa0, 0x00, 0xff]);
+        assert_eq!("0fa000ff", d.to_hex());
+        assert_eq!(d, ByteDigest::from_hex("0fa000ff").unwrap());
+        assert_eq!(d, ByteDigest::from_hex("0FA000FF").unwrap());
+
+        // malformed strings and strings of a wrong length should be rejected
+        assert!(matches!(
+            ByteDigest::<4>::from_hex("0fa000f"),
+            Err(DeserializationError::InvalidValue(_))
+        ));
+        assert!(matches!(
+            ByteDigest::<4>::from_hex("0fa000fg"),
+            Err(DeserializationError::InvalidValue(_))
+        ));
+        assert_eq!(Err(DeserializationError::UnexpectedEOF), ByteDigest::<4>::from_hex("0fa000"));
+        assert_eq!(
+            Err(DeserializationError::UnconsumedBytes),
+            ByteDigest::<4>::from_hex("0fa000ff00")
+        );
+    }
 }
@@ -89,7 +89,7 @@ mod tests {
     use rand_utils::rand_array;
     use utils::{Deserializable, Serializable, SliceReader};
 
-    use super::ElementDigest;
+    use super::{Digest, ElementDigest};
 
     #[test]
     fn digest_serialization() {
@@ -104,4 +104,14 @@ mod tests {
 
         assert_eq!(d1, d2);
     }
+
+    #[test]
+    fn digest_hex() {
+        let d1 = ElementDigest(rand_array());
+
+        // the hex string encodes the 31 bytes of the serialized digest
+        let hex = d1.to_hex();
+        assert_eq!(62, hex.len());
+        assert_eq!(d1, ElementDigest::from_hex(&hex).unwrap());
+    }
 }
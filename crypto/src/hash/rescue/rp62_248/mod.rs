@@ -3,9 +3,12 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
+use core::ops::Range;
+
 use math::{fields::f62::BaseElement, FieldElement, StarkField};
+use utils::accounting;
 
-use super::{exp_acc, Digest, ElementHasher, Hasher};
+use super::{exp_acc, AlgebraicSponge, Digest, ElementHasher, Hasher};
 
 mod digest;
 pub use digest::ElementDigest;
@@ -95,6 +98,7 @@ impl Hasher for Rp62_248 {
     const COLLISION_RESISTANCE: u32 = 124;
 
     fn hash(bytes: &[u8]) -> Self::Digest {
+        accounting::record_hash();
         // compute the number of elements required to represent the string; we will be processing
         // the string in 7-byte chunks, thus the number of elements will be equal to the number
         // of such chunks (including a potential partial chunk at the end).
@@ -153,6 +157,7 @@ impl Hasher for Rp62_248 {
     }
 
     fn merge(values: &[Self::Digest; 2]) -> Self::Digest {
+        accounting::record_hash();
         // initialize the state by copying the digest elements into the rate portion of the state
         // (8 total elements), and set the last capacity element to 8 (the number of elements to
         // be hashed).
@@ -170,6 +175,7 @@ impl Hasher for Rp62_248 {
     }
 
     fn merge_with_int(seed: Self::Digest, value: u64) -> Self::Digest {
+        accounting::record_hash();
         // initialize the state as follows:
         // - seed is copied into the first 4 elements of the state.
         // - if the value fits into a single field element, copy it into the fifth state element
@@ -197,6 +203,7 @@ impl ElementHasher for Rp62_248 {
     type BaseField = BaseElement;
 
     fn hash_elements<E: FieldElement<BaseField = Self::BaseField>>(elements: &[E]) -> Self::Digest {
+        accounting::record_hash();
         // convert the elements into a list of base field elements
         let elements = E::slice_as_base_elements(elements);
 
@@ -232,6 +239,26 @@ impl ElementHasher for Rp62_248 {
     }
 }
 
+impl AlgebraicSponge for Rp62_248 {
+    const STATE_WIDTH: usize = STATE_WIDTH;
+    const RATE_RANGE: Range<usize> = 0..RATE_WIDTH;
+    const DIGEST_SIZE: usize = DIGEST_SIZE;
+
+    fn apply_permutation(state: &mut [BaseElement]) {
+        let state: &mut [BaseElement; STATE_WIDTH] =
+            state.try_into().expect("invalid sponge state width");
+        apply_permutation(state);
+    }
+
+    fn digest_as_elements(digest: &ElementDigest) -> &[BaseElement] {
+        digest.as_elements()
+    }
+
+    fn digest_from_elements(elements: &[BaseElement]) -> Option<ElementDigest> {
+        elements.try_into().ok().map(ElementDigest::new)
+    }
+}
+
 // RESCUE PERMUTATION
 // ================================================================================================
 
@@ -9,6 +9,7 @@ use math::fields::f64::BaseElement;
 use utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 
 use super::{Digest, DIGEST_SIZE};
+use crate::hash::ByteDigest;
 
 // DIGEST TRAIT IMPLEMENTATIONS
 // ================================================================================================
@@ -87,6 +88,12 @@ impl From<ElementDigest> for [u8; 32] {
     }
 }
 
+impl From<ElementDigest> for ByteDigest<32> {
+    fn from(value: ElementDigest) -> Self {
+        ByteDigest::new(value.as_bytes())
+    }
+}
+
 // TESTS
 // ================================================================================================
 
@@ -96,7 +103,7 @@ mod tests {
     use rand_utils::rand_array;
     use utils::{Deserializable, Serializable, SliceReader};
 
-    use super::ElementDigest;
+    use super::{ByteDigest, Digest, ElementDigest};
 
     #[test]
     fn digest_serialization() {
@@ -111,4 +118,11 @@ mod tests {
 
         assert_eq!(d1, d2);
     }
+
+    #[test]
+    fn digest_into_byte_digest() {
+        let d1 = ElementDigest(rand_array());
+        let d2: ByteDigest<32> = d1.into();
+        assert_eq!(d1.as_bytes(), d2.as_bytes());
+    }
 }
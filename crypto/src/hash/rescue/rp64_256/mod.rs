@@ -6,8 +6,12 @@
 use core::ops::Range;
 
 use math::{fields::f64::BaseElement, FieldElement, StarkField};
+use utils::accounting;
 
-use super::{super::mds::mds_f64_12x12::mds_multiply, exp_acc, Digest, ElementHasher, Hasher};
+use super::{
+    super::mds::mds_f64_12x12::mds_multiply, exp_acc, AlgebraicSponge, Digest, ElementHasher,
+    Hasher,
+};
 
 mod digest;
 pub use digest::ElementDigest;
@@ -121,6 +125,7 @@ impl Hasher for Rp64_256 {
     const COLLISION_RESISTANCE: u32 = 128;
 
     fn hash(bytes: &[u8]) -> Self::Digest {
+        accounting::record_hash();
         // compute the number of elements required to represent the string; we will be processing
         // the string in 7-byte chunks, thus the number of elements will be equal to the number
         // of such chunks (including a potential partial chunk at the end).
@@ -179,6 +184,7 @@ impl Hasher for Rp64_256 {
     }
 
     fn merge(values: &[Self::Digest; 2]) -> Self::Digest {
+        accounting::record_hash();
         // initialize the state by copying the digest elements into the rate portion of the state
         // (8 total elements), and set the first capacity element to 8 (the number of elements to
         // be hashed).
@@ -196,6 +202,7 @@ impl Hasher for Rp64_256 {
     }
 
     fn merge_with_int(seed: Self::Digest, value: u64) -> Self::Digest {
+        accounting::record_hash();
         // initialize the state as follows:
         // - seed is copied into the first 4 elements of the rate portion of the state.
         // - if the value fits into a single field element, copy it into the fifth rate element
@@ -223,6 +230,7 @@ impl ElementHasher for Rp64_256 {
     type BaseField = BaseElement;
 
     fn hash_elements<E: FieldElement<BaseField = Self::BaseField>>(elements: &[E]) -> Self::Digest {
+        accounting::record_hash();
         // convert the elements into a list of base field elements
         let elements = E::slice_as_base_elements(elements);
 
@@ -258,6 +266,26 @@ impl ElementHasher for Rp64_256 {
     }
 }
 
+impl AlgebraicSponge for Rp64_256 {
+    const STATE_WIDTH: usize = STATE_WIDTH;
+    const RATE_RANGE: Range<usize> = RATE_RANGE;
+    const DIGEST_SIZE: usize = DIGEST_SIZE;
+
+    fn apply_permutation(state: &mut [BaseElement]) {
+        let state: &mut [BaseElement; STATE_WIDTH] =
+            state.try_into().expect("invalid sponge state width");
+        Self::apply_permutation(state);
+    }
+
+    fn digest_as_elements(digest: &ElementDigest) -> &[BaseElement] {
+        digest.as_elements()
+    }
+
+    fn digest_from_elements(elements: &[BaseElement]) -> Option<ElementDigest> {
+        elements.try_into().ok().map(ElementDigest::new)
+    }
+}
+
 // HASH FUNCTION IMPLEMENTATION
 // ================================================================================================
 
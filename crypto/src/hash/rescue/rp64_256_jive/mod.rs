@@ -6,8 +6,11 @@
 use core::ops::Range;
 
 use math::{fields::f64::BaseElement, FieldElement, StarkField};
+use utils::accounting;
 
-use super::{super::mds::mds_f64_8x8::mds_multiply, exp_acc, Digest, ElementHasher, Hasher};
+use super::{
+    super::mds::mds_f64_8x8::mds_multiply, exp_acc, AlgebraicSponge, Digest, ElementHasher, Hasher,
+};
 
 mod digest;
 pub use digest::ElementDigest;
@@ -117,6 +120,7 @@ impl Hasher for RpJive64_256 {
     const COLLISION_RESISTANCE: u32 = 128;
 
     fn hash(bytes: &[u8]) -> Self::Digest {
+        accounting::record_hash();
         // compute the number of elements required to represent the string; we will be processing
         // the string in 7-byte chunks, thus the number of elements will be equal to the number
         // of such chunks (including a potential partial chunk at the end).
@@ -184,6 +188,7 @@ impl Hasher for RpJive64_256 {
     // We do not rely on the sponge construction to build our compression function. Instead, we use
     // the Jive compression mode designed in https://eprint.iacr.org/2022/840.pdf.
     fn merge(values: &[Self::Digest; 2]) -> Self::Digest {
+        accounting::record_hash();
         // initialize the state by copying the digest elements into the state
         let initial_state: [BaseElement; STATE_WIDTH] =
             Self::Digest::digests_as_elements(values).try_into().unwrap();
@@ -202,6 +207,7 @@ impl Hasher for RpJive64_256 {
     // We do not rely on the sponge construction to build our compression function. Instead, we use
     // the Jive compression mode designed in https://eprint.iacr.org/2022/840.pdf.
     fn merge_with_int(seed: Self::Digest, value: u64) -> Self::Digest {
+        accounting::record_hash();
         // initialize the state as follows:
         // - seed is copied into the first 4 elements of the state.
         // - if the value fits into a single field element, copy it into the fifth rate element
@@ -231,6 +237,7 @@ impl ElementHasher for RpJive64_256 {
     type BaseField = BaseElement;
 
     fn hash_elements<E: FieldElement<BaseField = Self::BaseField>>(elements: &[E]) -> Self::Digest {
+        accounting::record_hash();
         // convert the elements into a list of base field elements
         let elements = E::slice_as_base_elements(elements);
 
@@ -273,6 +280,26 @@ impl ElementHasher for RpJive64_256 {
     }
 }
 
+impl AlgebraicSponge for RpJive64_256 {
+    const STATE_WIDTH: usize = STATE_WIDTH;
+    const RATE_RANGE: Range<usize> = RATE_RANGE;
+    const DIGEST_SIZE: usize = DIGEST_SIZE;
+
+    fn apply_permutation(state: &mut [BaseElement]) {
+        let state: &mut [BaseElement; STATE_WIDTH] =
+            state.try_into().expect("invalid sponge state width");
+        Self::apply_permutation(state);
+    }
+
+    fn digest_as_elements(digest: &ElementDigest) -> &[BaseElement] {
+        digest.as_elements()
+    }
+
+    fn digest_from_elements(elements: &[BaseElement]) -> Option<ElementDigest> {
+        elements.try_into().ok().map(ElementDigest::new)
+    }
+}
+
 // HASH FUNCTION IMPLEMENTATION
 // ================================================================================================
 
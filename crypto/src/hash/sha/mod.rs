@@ -7,7 +7,7 @@ use core::marker::PhantomData;
 
 use math::{FieldElement, StarkField};
 use sha3::Digest;
-use utils::ByteWriter;
+use utils::{accounting, ByteWriter};
 
 use super::{ByteDigest, ElementHasher, Hasher};
 
@@ -24,18 +24,22 @@ impl<B: StarkField> Hasher for Sha3_256<B> {
     const COLLISION_RESISTANCE: u32 = 128;
 
     fn hash(bytes: &[u8]) -> Self::Digest {
+        accounting::record_hash();
         ByteDigest(sha3::Sha3_256::digest(bytes).into())
     }
 
     fn merge(values: &[Self::Digest; 2]) -> Self::Digest {
+        accounting::record_hash();
         ByteDigest(sha3::Sha3_256::digest(ByteDigest::digests_as_bytes(values)).into())
     }
 
     fn merge_many(values: &[Self::Digest]) -> Self::Digest {
+        accounting::record_hash();
         ByteDigest(sha3::Sha3_256::digest(ByteDigest::digests_as_bytes(values)).into())
     }
 
     fn merge_with_int(seed: Self::Digest, value: u64) -> Self::Digest {
+        accounting::record_hash();
         let mut data = [0; 40];
         data[..32].copy_from_slice(&seed.0);
         data[32..].copy_from_slice(&value.to_le_bytes());
@@ -47,6 +51,7 @@ impl<B: StarkField> ElementHasher for Sha3_256<B> {
     type BaseField = B;
 
     fn hash_elements<E: FieldElement<BaseField = Self::BaseField>>(elements: &[E]) -> Self::Digest {
+        accounting::record_hash();
         if B::IS_CANONICAL {
             // when element's internal and canonical representations are the same, we can hash
             // element bytes directly
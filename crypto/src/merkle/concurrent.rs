@@ -8,13 +8,9 @@ use core::slice;
 
 use utils::{iterators::*, rayon};
 
+pub use super::MIN_CONCURRENT_LEAVES;
 use crate::Hasher;
 
-// CONSTANTS
-// ================================================================================================
-
-pub const MIN_CONCURRENT_LEAVES: usize = 1024;
-
 // PUBLIC FUNCTIONS
 // ================================================================================================
 
@@ -23,6 +19,9 @@ pub const MIN_CONCURRENT_LEAVES: usize = 1024;
 /// Builds all internal nodes of the Merkle using all available threads and stores the
 /// results in a single vector such that root of the tree is at position 1, nodes immediately
 /// under the root is at positions 2 and 3 etc.
+///
+/// The lower levels of the tree are split into independent sub-trees, one per thread, and the
+/// top few levels of the tree (above the roots of these sub-trees) are built sequentially.
 pub fn build_merkle_nodes<H: Hasher>(leaves: &[H::Digest]) -> Vec<H::Digest> {
     let n = leaves.len() / 2;
 
@@ -41,8 +40,9 @@ pub fn build_merkle_nodes<H: Hasher>(leaves: &[H::Digest]) -> Vec<H::Digest> {
     // calculate all other tree nodes, we can't use regular iterators  here because
     // access patterns are rather complicated - so, we use regular threads instead
 
-    // number of sub-trees must always be a power of 2
-    let num_subtrees = rayon::current_num_threads().next_power_of_two();
+    // number of sub-trees must always be a power of 2; we also make sure that there are no more
+    // sub-trees than there are parents of leaves so that small trees can be built correctly
+    let num_subtrees = rayon::current_num_threads().next_power_of_two().min(n);
     let batch_size = n / num_subtrees;
 
     // re-interpret nodes as an array of two nodes fused together
@@ -79,6 +79,8 @@ pub fn build_merkle_nodes<H: Hasher>(leaves: &[H::Digest]) -> Vec<H::Digest> {
 
 #[cfg(test)]
 mod tests {
+    use alloc::vec::Vec;
+
     use math::fields::f128::BaseElement;
     use proptest::{collection::vec, prelude::*};
 
@@ -92,5 +94,15 @@ mod tests {
             let concurrent = super::build_merkle_nodes::<Sha3_256<BaseElement>>(&leaves);
             assert_eq!(concurrent, sequential);
         }
+
+        #[test]
+        fn build_small_merkle_nodes_concurrent(log_n in 1_usize..8) {
+            let leaves = (0..(1_u8 << log_n))
+                .map(|i| ByteDigest::new([i; 32]))
+                .collect::<Vec<_>>();
+            let sequential = super::super::build_merkle_nodes::<Sha3_256<BaseElement>>(&leaves);
+            let concurrent = super::build_merkle_nodes::<Sha3_256<BaseElement>>(&leaves);
+            assert_eq!(concurrent, sequential);
+        }
     }
 }
@@ -9,6 +9,8 @@ use alloc::{
 };
 use core::slice;
 
+use utils::accounting;
+
 mod proofs;
 pub use proofs::BatchMerkleProof;
 
@@ -20,6 +22,13 @@ pub mod concurrent;
 #[cfg(test)]
 mod tests;
 
+// CONSTANTS
+// ================================================================================================
+
+/// Default number of leaves at or below which a Merkle tree is built in a single thread even when
+/// `concurrent` feature is enabled.
+pub const MIN_CONCURRENT_LEAVES: usize = 1024;
+
 // TYPES AND INTERFACES
 // ================================================================================================
 
@@ -51,6 +60,9 @@ mod tests;
 /// When the crate is compiled with `concurrent` feature enabled, tree construction will be
 /// performed in multiple threads (usually, as many threads as there are logical cores on the
 /// machine). The number of threads can be configured via `RAYON_NUM_THREADS` environment variable.
+/// Trees with fewer leaves than a given threshold are still built in a single thread as for small
+/// trees the overhead of spawning threads outweighs the benefits; the threshold can be set via
+/// [MerkleTree::with_concurrency_threshold()].
 ///
 /// To generate an inclusion proof for a given leaf, [MerkleTree::prove()] method can be used.
 /// You can also use [MerkleTree::prove_batch()] method to generate inclusion proofs for multiple
@@ -97,6 +109,15 @@ pub struct MerkleTree<H: Hasher> {
 /// up to the root (excluding the root itself).
 pub type MerkleTreeOpening<H> = (<H as Hasher>::Digest, Vec<<H as Hasher>::Digest>);
 
+impl<H: Hasher> Clone for MerkleTree<H> {
+    fn clone(&self) -> Self {
+        Self {
+            nodes: self.nodes.clone(),
+            leaves: self.leaves.clone(),
+        }
+    }
+}
+
 // MERKLE TREE IMPLEMENTATION
 // ================================================================================================
 
@@ -114,6 +135,26 @@ impl<H: Hasher> MerkleTree<H> {
     /// * Fewer than two leaves were provided.
     /// * Number of leaves is not a power of two.
     pub fn new(leaves: Vec<H::Digest>) -> Result<Self, MerkleTreeError> {
+        Self::with_concurrency_threshold(leaves, MIN_CONCURRENT_LEAVES)
+    }
+
+    /// Returns new Merkle tree built from the provide leaves using hash function specified by the
+    /// `H` generic parameter.
+    ///
+    /// When `concurrent` feature is enabled, the tree is built using multiple threads if the
+    /// number of leaves is greater than `min_concurrent_leaves`; otherwise, the tree is built in
+    /// a single thread. When `concurrent` feature is disabled, `min_concurrent_leaves` parameter
+    /// is ignored.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * Fewer than two leaves were provided.
+    /// * Number of leaves is not a power of two.
+    #[cfg_attr(not(feature = "concurrent"), allow(unused_variables))]
+    pub fn with_concurrency_threshold(
+        leaves: Vec<H::Digest>,
+        min_concurrent_leaves: usize,
+    ) -> Result<Self, MerkleTreeError> {
         if leaves.len() < 2 {
             return Err(MerkleTreeError::TooFewLeaves(2, leaves.len()));
         }
@@ -125,7 +166,7 @@ impl<H: Hasher> MerkleTree<H> {
         let nodes = build_merkle_nodes::<H>(&leaves);
 
         #[cfg(feature = "concurrent")]
-        let nodes = if leaves.len() <= concurrent::MIN_CONCURRENT_LEAVES {
+        let nodes = if leaves.len() <= min_concurrent_leaves {
             build_merkle_nodes::<H>(&leaves)
         } else {
             concurrent::build_merkle_nodes::<H>(&leaves)
@@ -159,6 +200,57 @@ impl<H: Hasher> MerkleTree<H> {
         Ok(MerkleTree { nodes, leaves })
     }
 
+    // ROOT COMPUTATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the root of a Merkle tree built from the provided leaves without building the tree.
+    ///
+    /// The leaves are consumed one by one, and only the roots of the complete subtrees built so
+    /// far are retained. Thus, the root is computed using memory proportional to the depth of the
+    /// tree, rather than to the number of leaves (as is the case with [MerkleTree::new()]). The
+    /// returned root is the same as the root of the tree built via [MerkleTree::new()] from the same
+    /// leaves.
+    ///
+    /// This is useful when only a commitment to the leaves is needed (e.g., for checking that an
+    /// externally built tree has the expected root). To open any of the leaves, the full tree
+    /// must be built via [MerkleTree::new()].
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * Fewer than two leaves were provided.
+    /// * Number of leaves is not a power of two.
+    pub fn root_from_leaves<I>(leaves: I) -> Result<H::Digest, MerkleTreeError>
+    where
+        I: IntoIterator<Item = H::Digest>,
+    {
+        // each entry in the stack is the root of a complete subtree together with its height;
+        // heights of the subtrees are strictly decreasing from the bottom to the top of the stack
+        let mut stack: Vec<(u32, H::Digest)> = Vec::new();
+        let mut num_leaves = 0;
+        for leaf in leaves {
+            num_leaves += 1;
+            let mut node = (0, leaf);
+            while let Some(&(height, sibling)) = stack.last() {
+                if height != node.0 {
+                    break;
+                }
+                stack.pop();
+                node = (height + 1, H::merge(&[sibling, node.1]));
+            }
+            stack.push(node);
+        }
+
+        if num_leaves < 2 {
+            return Err(MerkleTreeError::TooFewLeaves(2, num_leaves));
+        }
+        if !usize::is_power_of_two(num_leaves) {
+            return Err(MerkleTreeError::NumberOfLeavesNotPowerOfTwo(num_leaves));
+        }
+        debug_assert_eq!(stack.len(), 1);
+
+        Ok(stack[0].1)
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -286,6 +378,7 @@ impl<H: Hasher> MerkleTree<H> {
         leaf: H::Digest,
         proof: &[H::Digest],
     ) -> Result<(), MerkleTreeError> {
+        accounting::record_merkle_paths(1);
         let r = index & 1;
         let mut v = if r == 0 {
             H::merge(&[leaf, proof[0]])
@@ -325,6 +418,7 @@ impl<H: Hasher> MerkleTree<H> {
         leaves: &[H::Digest],
         proof: &BatchMerkleProof<H>,
     ) -> Result<(), MerkleTreeError> {
+        accounting::record_merkle_paths(indexes.len());
         if *root != proof.get_root(indexes, leaves)? {
             return Err(MerkleTreeError::InvalidProof);
         }
@@ -404,17 +404,32 @@ impl<H: Hasher> Deserializable for BatchMerkleProof<H> {
     /// Parses internal nodes from the provided `source`, and constructs a batch Merkle proof
     /// from these nodes.
     ///
+    /// All lengths read from `source` are checked via [ByteReader::check_eor()] before any memory
+    /// is allocated for them, and thus, a malformed length cannot cause allocations larger than
+    /// `source` itself.
+    ///
     /// # Errors
     /// Returns an error if:
     /// * `source` could not be deserialized into a valid set of internal nodes.
+    /// * The number of node vectors or digests specified in `source` exceeds the number of
+    ///   bytes remaining in `source`.
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
         let depth = source.read_u8()?;
         let num_node_vectors = source.read_usize()?;
 
+        // each node vector is prefixed with its length, which takes up at least one byte
+        source.check_eor(num_node_vectors)?;
+        let digest_size = H::Digest::default().to_bytes().len();
+
         let mut nodes = Vec::with_capacity(num_node_vectors);
         for _ in 0..num_node_vectors {
             // read the digests and add them to the node vector
-            let digests = Vec::<_>::read_from(source)?;
+            let num_digests = source.read_usize()?;
+            let num_bytes = num_digests
+                .checked_mul(digest_size)
+                .ok_or(DeserializationError::UnexpectedEOF)?;
+            source.check_eor(num_bytes)?;
+            let digests = source.read_many(num_digests)?;
             nodes.push(digests);
         }
 
@@ -5,6 +5,7 @@
 
 use math::fields::f128::BaseElement;
 use proptest::prelude::*;
+use utils::{ByteWriter, Deserializable, DeserializationError, Serializable};
 
 use super::*;
 
@@ -83,6 +84,47 @@ fn new_tree() {
     assert_eq!(&root, tree.root());
 }
 
+#[test]
+fn new_tree_with_concurrency_threshold() {
+    let leaves = Digest256::bytes_as_digests(&LEAVES8).to_vec();
+    let tree = MerkleTree::<Blake3_256>::new(leaves.clone()).unwrap();
+
+    // trees built with any threshold should be the same as trees built with the default one
+    for threshold in [0, 2, 4, 8, 16] {
+        let tree2 = MerkleTree::<Blake3_256>::with_concurrency_threshold(leaves.clone(), threshold)
+            .unwrap();
+        assert_eq!(tree.nodes, tree2.nodes);
+        assert_eq!(tree.leaves, tree2.leaves);
+    }
+}
+
+#[test]
+fn root_from_leaves() {
+    let leaves = Digest256::bytes_as_digests(&LEAVES4).to_vec();
+    let tree = MerkleTree::<Blake3_256>::new(leaves.clone()).unwrap();
+    assert_eq!(tree.root(), &MerkleTree::<Blake3_256>::root_from_leaves(leaves).unwrap());
+
+    let leaves = Digest256::bytes_as_digests(&LEAVES8).to_vec();
+    let tree = MerkleTree::<Blake3_256>::new(leaves.clone()).unwrap();
+    assert_eq!(tree.root(), &MerkleTree::<Blake3_256>::root_from_leaves(leaves).unwrap());
+
+    let leaves = Digest256::bytes_as_digests(&LEAVES8)[..2].to_vec();
+    let root = hash_2x1(leaves[0], leaves[1]);
+    assert_eq!(root, MerkleTree::<Blake3_256>::root_from_leaves(leaves).unwrap());
+
+    // the number of leaves must be a power of two greater than one
+    let leaves = Digest256::bytes_as_digests(&LEAVES8)[..1].to_vec();
+    assert_eq!(
+        Err(MerkleTreeError::TooFewLeaves(2, 1)),
+        MerkleTree::<Blake3_256>::root_from_leaves(leaves)
+    );
+    let leaves = Digest256::bytes_as_digests(&LEAVES8)[..6].to_vec();
+    assert_eq!(
+        Err(MerkleTreeError::NumberOfLeavesNotPowerOfTwo(6)),
+        MerkleTree::<Blake3_256>::root_from_leaves(leaves)
+    );
+}
+
 #[test]
 fn prove() {
     // depth 4
@@ -254,7 +296,52 @@ fn from_proofs() {
     assert_eq!(proof1.depth, proof2.depth);
 }
 
+#[test]
+fn batch_proof_serialization() {
+    let leaves = Digest256::bytes_as_digests(&LEAVES8).to_vec();
+    let tree = MerkleTree::<Blake3_256>::new(leaves).unwrap();
+    let (_, proof) = tree.prove_batch(&[1, 2, 6]).unwrap();
+
+    let bytes = proof.to_bytes();
+    let result = BatchMerkleProof::<Blake3_256>::read_from_bytes(&bytes).unwrap();
+    assert_eq!(proof.nodes, result.nodes);
+    assert_eq!(proof.depth, result.depth);
+
+    // a node vector count which exceeds the remaining bytes should be rejected
+    let mut bytes = vec![3];
+    bytes.write_usize(usize::MAX);
+    assert!(matches!(
+        BatchMerkleProof::<Blake3_256>::read_from_bytes(&bytes),
+        Err(DeserializationError::UnexpectedEOF)
+    ));
+
+    // a digest count which exceeds the remaining bytes should be rejected
+    let mut bytes = vec![3];
+    bytes.write_usize(1);
+    bytes.write_usize(usize::MAX / 32);
+    bytes.extend_from_slice(&[0; 32]);
+    assert!(matches!(
+        BatchMerkleProof::<Blake3_256>::read_from_bytes(&bytes),
+        Err(DeserializationError::UnexpectedEOF)
+    ));
+
+    // a digest count whose byte length overflows should be rejected
+    let mut bytes = vec![3];
+    bytes.write_usize(1);
+    bytes.write_usize(usize::MAX);
+    assert!(matches!(
+        BatchMerkleProof::<Blake3_256>::read_from_bytes(&bytes),
+        Err(DeserializationError::UnexpectedEOF)
+    ));
+}
+
 proptest! {
+    #[test]
+    fn root_from_leaves_proptest(tree in random_blake3_merkle_tree(128)) {
+        let root = MerkleTree::<Blake3_256>::root_from_leaves(tree.leaves().iter().copied());
+        prop_assert_eq!(tree.root(), &root.unwrap());
+    }
+
     #[test]
     fn prove_n_verify(tree in random_blake3_merkle_tree(128),
                       proof_indices in prop::collection::vec(any::<prop::sample::Index>(), 10..20)
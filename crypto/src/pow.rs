@@ -0,0 +1,105 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+#[cfg(feature = "concurrent")]
+use utils::iterators::*;
+
+use crate::RandomCoin;
+
+// PROOF OF WORK TRAIT
+// ================================================================================================
+
+/// Proof-of-work algorithm used for grinding the query seed.
+///
+/// Before query positions are drawn, the prover must find a nonce which, together with the
+/// current state of the public coin (the seed), satisfies the proof-of-work of the specified
+/// difficulty (the grinding factor of the proof options). The verifier then checks that the nonce
+/// supplied in the proof satisfies the same proof-of-work, and uses the nonce to draw the query
+/// positions.
+///
+/// The seed is provided as the public coin itself. An implementation which needs to derive seed
+/// material from it (e.g., for a memory-hard function) can do so by drawing values from a clone
+/// of the coin; this does not affect the state of the coin used by the protocol.
+pub trait ProofOfWork<R: RandomCoin>: Sync {
+    /// Returns an identifier of this algorithm to be recorded in the context of the generated
+    /// proofs, or None for [DefaultProofOfWork].
+    ///
+    /// The identifier is chosen by the application, and allows the verifier to make sure that a
+    /// proof is checked using the same algorithm as the one used to generate it.
+    fn id(&self) -> Option<u8>;
+
+    /// Returns a nonce which satisfies the proof-of-work of the specified `difficulty` for the
+    /// specified `seed`.
+    fn solve(&self, seed: &R, difficulty: u32) -> u64;
+
+    /// Returns true if the specified `nonce` satisfies the proof-of-work of the specified
+    /// `difficulty` for the specified `seed`.
+    fn verify(&self, seed: &R, difficulty: u32, nonce: u64) -> bool;
+}
+
+// DEFAULT PROOF OF WORK
+// ================================================================================================
+
+/// Hash-based proof-of-work which requires hash(`seed` || `nonce`) to have at least `difficulty`
+/// leading zeros, as computed by [RandomCoin::check_leading_zeros()].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DefaultProofOfWork;
+
+impl<R: RandomCoin> ProofOfWork<R> for DefaultProofOfWork {
+    fn id(&self) -> Option<u8> {
+        None
+    }
+
+    /// Returns the first nonce (or, when the `concurrent` feature is enabled, any nonce) greater
+    /// than zero which satisfies the proof-of-work.
+    ///
+    /// # Panics
+    /// Panics if no such nonce exists.
+    fn solve(&self, seed: &R, difficulty: u32) -> u64 {
+        #[cfg(not(feature = "concurrent"))]
+        let nonce = (1..u64::MAX)
+            .find(|&nonce| seed.check_leading_zeros(nonce) >= difficulty)
+            .expect("nonce not found");
+
+        #[cfg(feature = "concurrent")]
+        let nonce = (1..u64::MAX)
+            .into_par_iter()
+            .find_any(|&nonce| seed.check_leading_zeros(nonce) >= difficulty)
+            .expect("nonce not found");
+
+        nonce
+    }
+
+    fn verify(&self, seed: &R, difficulty: u32, nonce: u64) -> bool {
+        seed.check_leading_zeros(nonce) >= difficulty
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use math::fields::f128::BaseElement;
+
+    use super::{DefaultProofOfWork, ProofOfWork};
+    use crate::{hashers::Blake3_256, DefaultRandomCoin, RandomCoin};
+
+    type Coin = DefaultRandomCoin<Blake3_256<BaseElement>>;
+
+    #[test]
+    fn default_pow_solve_and_verify() {
+        let coin = Coin::new(&[BaseElement::new(42)]);
+        let nonce = DefaultProofOfWork.solve(&coin, 8);
+
+        assert!(DefaultProofOfWork.verify(&coin, 8, nonce));
+        assert!(coin.check_leading_zeros(nonce) >= 8);
+        assert_eq!(None, ProofOfWork::<Coin>::id(&DefaultProofOfWork));
+
+        // a nonce which does not satisfy the difficulty is rejected
+        let nonce = (1..u64::MAX).find(|&nonce| coin.check_leading_zeros(nonce) < 8).unwrap();
+        assert!(!DefaultProofOfWork.verify(&coin, 8, nonce));
+    }
+}
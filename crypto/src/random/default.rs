@@ -4,10 +4,13 @@
 // LICENSE file in the root directory of this source tree.
 
 use alloc::vec::Vec;
+use core::cmp;
 
 use math::{FieldElement, StarkField};
 
-use crate::{errors::RandomCoinError, Digest, ElementHasher, RandomCoin};
+use crate::{
+    errors::RandomCoinError, Digest, ElementHasher, RandomCoin, DEFAULT_MAX_DRAW_ATTEMPTS,
+};
 
 // DEFAULT RANDOM COIN IMPLEMENTATION
 // ================================================================================================
@@ -135,23 +138,61 @@ impl<B: StarkField, H: ElementHasher<BaseField = B>> RandomCoin for DefaultRando
 
     /// Returns the next pseudo-random field element.
     ///
+    /// This is equivalent to [RandomCoin::draw_with_max_attempts()] with the maximum number of
+    /// attempts set to [DEFAULT_MAX_DRAW_ATTEMPTS].
+    ///
     /// # Errors
-    /// Returns an error if a valid field element could not be generated after 1000 calls to the
-    /// PRNG.
-    fn draw<E: FieldElement>(&mut self) -> Result<E, RandomCoinError> {
-        for _ in 0..1000 {
-            // get the next pseudo-random value and take the first ELEMENT_BYTES from it
+    /// Returns an error if a valid field element could not be generated after
+    /// [DEFAULT_MAX_DRAW_ATTEMPTS] calls to the PRNG.
+    fn draw<E: FieldElement<BaseField = Self::BaseField>>(&mut self) -> Result<E, RandomCoinError> {
+        self.draw_with_max_attempts(DEFAULT_MAX_DRAW_ATTEMPTS)
+    }
+
+    /// Returns the next pseudo-random field element, making at most `max_attempts` attempts to
+    /// draw a valid element.
+    ///
+    /// If a field element requires more bytes than a single PRNG output provides (e.g., for
+    /// elements in high-degree extension fields), the bytes are taken from several consecutive
+    /// PRNG outputs.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - `max_attempts` is zero.
+    /// - A valid field element could not be generated after `max_attempts` calls to the PRNG.
+    fn draw_with_max_attempts<E: FieldElement>(
+        &mut self,
+        max_attempts: usize,
+    ) -> Result<E, RandomCoinError> {
+        if max_attempts == 0 {
+            return Err(RandomCoinError::InvalidMaxAttempts);
+        }
+
+        for _ in 0..max_attempts {
+            // get the next pseudo-random value and take the first ELEMENT_BYTES from it (or from
+            // it and the subsequent values, if it is not long enough)
             let value = self.next();
-            let bytes = &value.as_bytes()[..E::ELEMENT_BYTES];
+            let element = if E::ELEMENT_BYTES <= value.as_bytes().len() {
+                E::from_random_bytes(&value.as_bytes()[..E::ELEMENT_BYTES])
+            } else {
+                let mut bytes = Vec::with_capacity(E::ELEMENT_BYTES);
+                bytes.extend_from_slice(&value.as_bytes());
+                while bytes.len() < E::ELEMENT_BYTES {
+                    let value = self.next();
+                    let num_bytes =
+                        cmp::min(value.as_bytes().len(), E::ELEMENT_BYTES - bytes.len());
+                    bytes.extend_from_slice(&value.as_bytes()[..num_bytes]);
+                }
+                E::from_random_bytes(&bytes)
+            };
 
             // check if the bytes can be converted into a valid field element; if they can,
             // return; otherwise try again
-            if let Some(element) = E::from_random_bytes(bytes) {
+            if let Some(element) = element {
                 return Ok(element);
             }
         }
 
-        Err(RandomCoinError::FailedToDrawFieldElement(1000))
+        Err(RandomCoinError::FailedToDrawFieldElement(max_attempts))
     }
 
     /// Returns a vector of integers selected from the range [0, domain_size) after reseeding
@@ -226,3 +267,57 @@ impl<B: StarkField, H: ElementHasher<BaseField = B>> RandomCoin for DefaultRando
         Ok(values)
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use math::{fields::f64::BaseElement, FieldElement};
+
+    use super::DefaultRandomCoin;
+    use crate::{
+        hashers::{Blake3_256, FnHasher, HashFunctions},
+        RandomCoin, RandomCoinError, DEFAULT_MAX_DRAW_ATTEMPTS,
+    };
+
+    /// Hash functions which always output bytes that do not encode a valid field element.
+    struct RejectingFunctions;
+
+    impl HashFunctions for RejectingFunctions {
+        type BaseField = BaseElement;
+        const COLLISION_RESISTANCE: u32 = 128;
+        const HASH: fn(&[u8]) -> [u8; 32] = |_| [u8::MAX; 32];
+        const MERGE: fn(&[u8; 32], &[u8; 32]) -> [u8; 32] = |_, _| [u8::MAX; 32];
+        const HASH_ELEMENTS: fn(&[BaseElement]) -> [u8; 32] = |_| [u8::MAX; 32];
+    }
+
+    #[test]
+    fn default_coin_draw_with_max_attempts() {
+        let seed = [BaseElement::new(1), BaseElement::new(2)];
+        let mut coin = DefaultRandomCoin::<Blake3_256<BaseElement>>::new(&seed);
+        let mut other = DefaultRandomCoin::<Blake3_256<BaseElement>>::new(&seed);
+
+        // a bounded draw produces the same element as a regular draw
+        let a: BaseElement = coin.draw_with_max_attempts(1).unwrap();
+        assert_eq!(a, other.draw().unwrap());
+
+        assert_eq!(
+            Err(RandomCoinError::InvalidMaxAttempts),
+            coin.draw_with_max_attempts::<BaseElement>(0)
+        );
+    }
+
+    #[test]
+    fn default_coin_draw_exhausts_attempts() {
+        let mut coin = DefaultRandomCoin::<FnHasher<RejectingFunctions>>::new(&[BaseElement::ONE]);
+        assert_eq!(
+            Err(RandomCoinError::FailedToDrawFieldElement(5)),
+            coin.draw_with_max_attempts::<BaseElement>(5)
+        );
+        assert_eq!(
+            Err(RandomCoinError::FailedToDrawFieldElement(DEFAULT_MAX_DRAW_ATTEMPTS)),
+            coin.draw::<BaseElement>()
+        );
+    }
+}
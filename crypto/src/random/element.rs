@@ -0,0 +1,300 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+
+use math::{FieldElement, StarkField};
+use utils::Serializable;
+
+use crate::{errors::RandomCoinError, AlgebraicSponge, RandomCoin};
+
+// ELEMENT RANDOM COIN IMPLEMENTATION
+// ================================================================================================
+
+/// Pseudo-random element generator for finite fields which operates directly on field elements
+/// via the permutation of an algebraic hash function.
+///
+/// In contrast to [DefaultRandomCoin](crate::DefaultRandomCoin), which draws elements by hashing
+/// and re-interpreting digest bytes, this coin keeps a sponge state of the hash function specified
+/// by the `H` type parameter and absorbs and squeezes field elements directly. Thus, when `H` is
+/// also used for commitments, the entire Fiat-Shamir transcript is expressed in terms of a single
+/// algebraic permutation, which makes it cheap to verify inside an AIR.
+///
+/// The coin works roughly as follows:
+/// - The state of the coin is a sponge state of `H::STATE_WIDTH` field elements. At instantiation
+///   time, the state is set to all zeros except for the first element of the capacity portion,
+///   which is set to the number of seed elements.
+/// - Elements are absorbed by adding them into the rate portion of the state, `H::RATE_RANGE.len()`
+///   elements at a time, and applying the permutation after each full (or last partial) block.
+///   Reseeding the coin with a digest absorbs all elements of the digest.
+/// - Elements are squeezed by reading the rate portion of the state one element at a time; once
+///   all rate elements have been read, the permutation is applied again. Thus, up to
+///   `H::RATE_RANGE.len()` base field elements are produced per permutation. Drawing an element
+///   from an extension field of degree `d` consumes `d` base field elements.
+///
+/// Since every squeezed element is a valid field element, drawing elements never fails. Integers
+/// for query positions are derived from the low bits of squeezed elements.
+///
+/// # Examples
+/// ```
+/// # use winter_crypto::{RandomCoin, ElementRandomCoin, hashers::Rp64_256};
+/// # use math::fields::f64::BaseElement;
+/// let seed = &[BaseElement::new(1), BaseElement::new(2), BaseElement::new(3), BaseElement::new(4)];
+///
+/// let mut coin1 = ElementRandomCoin::<Rp64_256>::new(seed);
+/// let mut coin2 = ElementRandomCoin::<Rp64_256>::new(seed);
+///
+/// // should draw the same elements for the same seed
+/// let e1 = coin1.draw::<BaseElement>().unwrap();
+/// let e2 = coin2.draw::<BaseElement>().unwrap();
+/// assert_eq!(e1, e2);
+///
+/// // but different elements on subsequent draws
+/// let e3 = coin1.draw::<BaseElement>().unwrap();
+/// assert_ne!(e1, e3);
+/// ```
+pub struct ElementRandomCoin<H: AlgebraicSponge> {
+    state: Vec<H::BaseField>,
+    output_idx: usize,
+}
+
+impl<H: AlgebraicSponge> ElementRandomCoin<H> {
+    /// Absorbs the specified elements into the rate portion of the state, and marks the entire
+    /// rate portion of the resulting state as available for squeezing.
+    fn absorb(state: &mut [H::BaseField], elements: &[H::BaseField]) {
+        let rate = H::RATE_RANGE;
+        let mut i = rate.start;
+        for &element in elements {
+            state[i] += element;
+            i += 1;
+            if i == rate.end {
+                H::apply_permutation(state);
+                i = rate.start;
+            }
+        }
+
+        // apply the permutation to the last partial block; if no elements were provided, the
+        // permutation is applied as well so that the output always changes
+        if i != rate.start || elements.is_empty() {
+            H::apply_permutation(state);
+        }
+    }
+
+    /// Returns the next base field element from the rate portion of the state, applying the
+    /// permutation if all elements of the rate portion have been consumed.
+    fn squeeze(&mut self) -> H::BaseField {
+        if self.output_idx == H::RATE_RANGE.end {
+            H::apply_permutation(&mut self.state);
+            self.output_idx = H::RATE_RANGE.start;
+        }
+        let result = self.state[self.output_idx];
+        self.output_idx += 1;
+        result
+    }
+
+    /// Splits the specified value into two 32-bit limbs so that it can be absorbed as field
+    /// elements regardless of the size of the field.
+    fn int_to_elements(value: u64) -> [H::BaseField; 2] {
+        [H::BaseField::from(value as u32), H::BaseField::from((value >> 32) as u32)]
+    }
+
+    /// Returns the lower 64 bits of the canonical representation of the specified element.
+    fn element_to_int(element: H::BaseField) -> u64 {
+        let bytes = element.to_bytes();
+        let mut result = [0u8; 8];
+        let len = bytes.len().min(8);
+        result[..len].copy_from_slice(&bytes[..len]);
+        u64::from_le_bytes(result)
+    }
+}
+
+impl<H: AlgebraicSponge> Clone for ElementRandomCoin<H> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+            output_idx: self.output_idx,
+        }
+    }
+}
+
+impl<B: StarkField, H: AlgebraicSponge<BaseField = B>> RandomCoin for ElementRandomCoin<H> {
+    type BaseField = B;
+    type Hasher = H;
+
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new random coin instantiated with the provided `seed`.
+    fn new(seed: &[Self::BaseField]) -> Self {
+        let mut state = vec![B::ZERO; H::STATE_WIDTH];
+        let capacity_idx = if H::RATE_RANGE.start == 0 { H::RATE_RANGE.end } else { 0 };
+        state[capacity_idx] = B::from(seed.len() as u32);
+        Self::absorb(&mut state, seed);
+        Self { state, output_idx: H::RATE_RANGE.start }
+    }
+
+    // RESEEDING
+    // --------------------------------------------------------------------------------------------
+
+    /// Reseeds the coin by absorbing the elements of the specified digest into the state.
+    fn reseed(&mut self, data: H::Digest) {
+        Self::absorb(&mut self.state, H::digest_as_elements(&data));
+        self.output_idx = H::RATE_RANGE.start;
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Absorbs `value` into a copy of the state and returns the number of trailing zeros in the
+    /// canonical representation of the first rate element of the resulting state.
+    fn check_leading_zeros(&self, value: u64) -> u32 {
+        let mut state = self.state.clone();
+        Self::absorb(&mut state, &Self::int_to_elements(value));
+        Self::element_to_int(state[H::RATE_RANGE.start]).trailing_zeros()
+    }
+
+    // DRAW METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the next pseudo-random field element.
+    ///
+    /// Drawing an element from an extension field of degree `d` consumes `d` base field elements
+    /// squeezed from the state. Since squeezed elements are always valid, a single attempt is
+    /// always sufficient, and thus, this method never returns an error.
+    fn draw<E: FieldElement<BaseField = Self::BaseField>>(&mut self) -> Result<E, RandomCoinError> {
+        let elements = (0..E::EXTENSION_DEGREE).map(|_| self.squeeze()).collect::<Vec<_>>();
+        Ok(E::slice_from_base_elements(&elements)[0])
+    }
+
+    /// Returns a vector of integers selected from the range [0, domain_size) after absorbing the
+    /// specified `nonce` into the state.
+    ///
+    /// Each integer is derived from the low bits of a single squeezed element. This method never
+    /// returns an error.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// - `domain_size` is not a power of two.
+    /// - `num_values` is greater than or equal to `domain_size`.
+    fn draw_integers(
+        &mut self,
+        num_values: usize,
+        domain_size: usize,
+        nonce: u64,
+    ) -> Result<Vec<usize>, RandomCoinError> {
+        assert!(domain_size.is_power_of_two(), "domain size must be a power of two");
+        assert!(num_values < domain_size, "number of values must be smaller than domain size");
+
+        // reseed with nonce
+        Self::absorb(&mut self.state, &Self::int_to_elements(nonce));
+        self.output_idx = H::RATE_RANGE.start;
+
+        // limit the values to the number of bits which can fit into the specified domain
+        let v_mask = (domain_size - 1) as u64;
+        let values = (0..num_values)
+            .map(|_| (Self::element_to_int(self.squeeze()) & v_mask) as usize)
+            .collect();
+
+        Ok(values)
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use math::{
+        fields::{f62, f64::BaseElement, CubeExtension, QuadExtension},
+        FieldElement,
+    };
+
+    use super::ElementRandomCoin;
+    use crate::{
+        hashers::{Rp62_248, Rp64_256, RpJive64_256},
+        ElementHasher, RandomCoin, RandomCoinError,
+    };
+
+    #[test]
+    fn element_coin_draw() {
+        let seed = [BaseElement::new(1), BaseElement::new(2)];
+        let mut coin1 = ElementRandomCoin::<Rp64_256>::new(&seed);
+        let mut coin2 = ElementRandomCoin::<Rp64_256>::new(&seed);
+
+        // draw more elements than the rate to make sure the state is permuted between draws
+        let a = (0..20).map(|_| coin1.draw::<BaseElement>().unwrap()).collect::<Vec<_>>();
+        let b = (0..20).map(|_| coin2.draw::<BaseElement>().unwrap()).collect::<Vec<_>>();
+        assert_eq!(a, b);
+        for i in 1..a.len() {
+            assert_ne!(a[i - 1], a[i]);
+        }
+
+        // different seeds should produce different elements
+        let mut coin3 = ElementRandomCoin::<Rp64_256>::new(&seed[..1]);
+        assert_ne!(a[0], coin3.draw::<BaseElement>().unwrap());
+
+        // extension field elements should be drawn as well
+        let c: QuadExtension<BaseElement> = coin1.draw().unwrap();
+        let d: CubeExtension<BaseElement> = coin2.draw().unwrap();
+        assert_eq!(c.base_element(0), d.base_element(0));
+        assert_eq!(c.base_element(1), d.base_element(1));
+    }
+
+    #[test]
+    fn element_coin_draw_with_max_attempts() {
+        let seed = [BaseElement::new(1), BaseElement::new(2)];
+        let mut coin1 = ElementRandomCoin::<Rp64_256>::new(&seed);
+        let mut coin2 = ElementRandomCoin::<Rp64_256>::new(&seed);
+
+        // the provided method delegates to draw() for a non-zero bound
+        let a: BaseElement = coin1.draw_with_max_attempts(1).unwrap();
+        assert_eq!(coin2.draw::<BaseElement>().unwrap(), a);
+
+        // a zero bound is rejected without drawing anything
+        assert_eq!(
+            Err(RandomCoinError::InvalidMaxAttempts),
+            coin1.draw_with_max_attempts::<BaseElement>(0)
+        );
+        assert_eq!(coin2.draw::<BaseElement>().unwrap(), coin1.draw::<BaseElement>().unwrap());
+    }
+
+    #[test]
+    fn element_coin_reseed() {
+        let seed = [BaseElement::new(1), BaseElement::new(2)];
+        let mut coin1 = ElementRandomCoin::<RpJive64_256>::new(&seed);
+        let mut coin2 = ElementRandomCoin::<RpJive64_256>::new(&seed);
+
+        coin1.reseed(RpJive64_256::hash_elements(&seed));
+        coin2.reseed(RpJive64_256::hash_elements(&seed[..1]));
+        assert_ne!(coin1.draw::<BaseElement>().unwrap(), coin2.draw::<BaseElement>().unwrap());
+    }
+
+    #[test]
+    fn element_coin_draw_integers() {
+        let seed = [f62::BaseElement::new(42)];
+        let mut coin1 = ElementRandomCoin::<Rp62_248>::new(&seed);
+        let mut coin2 = coin1.clone();
+
+        let values = coin1.draw_integers(20, 64, 0).unwrap();
+        assert_eq!(20, values.len());
+        assert!(values.iter().all(|&v| v < 64));
+
+        // the result should depend on the nonce
+        assert_eq!(values, coin2.clone().draw_integers(20, 64, 0).unwrap());
+        assert_ne!(values, coin2.draw_integers(20, 64, 1).unwrap());
+    }
+
+    #[test]
+    fn element_coin_check_leading_zeros() {
+        let seed = [BaseElement::new(7)];
+        let coin = ElementRandomCoin::<Rp64_256>::new(&seed);
+
+        // find a nonce which produces at least 4 zeros and make sure the result is deterministic
+        let nonce = (0..1000).find(|&nonce| coin.check_leading_zeros(nonce) >= 4).unwrap();
+        assert!(coin.clone().check_leading_zeros(nonce) >= 4);
+    }
+}
@@ -12,6 +12,16 @@ use crate::{errors::RandomCoinError, ElementHasher, Hasher};
 mod default;
 pub use default::DefaultRandomCoin;
 
+mod element;
+pub use element::ElementRandomCoin;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Default maximum number of attempts which [RandomCoin::draw()] is expected to make to draw a
+/// valid field element before giving up.
+pub const DEFAULT_MAX_DRAW_ATTEMPTS: usize = 1000;
+
 // RANDOM COIN TRAIT
 // ================================================================================================
 
@@ -45,8 +55,8 @@ pub trait RandomCoin: Sync {
     /// Returns the next pseudo-random field element.
     ///
     /// # Errors
-    /// Returns an error if a valid field element could not be generated after 1000 calls to the
-    /// PRNG.
+    /// Returns an error if a valid field element could not be generated after
+    /// [DEFAULT_MAX_DRAW_ATTEMPTS] calls to the PRNG.
     fn draw<E: FieldElement<BaseField = Self::BaseField>>(&mut self) -> Result<E, RandomCoinError>;
 
     /// Returns a vector of integers selected from the range [0, domain_size) after it reseeds
@@ -66,4 +76,29 @@ pub trait RandomCoin: Sync {
         domain_size: usize,
         nonce: u64,
     ) -> Result<Vec<usize>, RandomCoinError>;
+
+    // PROVIDED METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the next pseudo-random field element, making at most `max_attempts` attempts to
+    /// draw a valid element.
+    ///
+    /// The default implementation rejects a zero bound and otherwise delegates to
+    /// [RandomCoin::draw()], and thus, is bounded by the number of attempts made by that method.
+    /// Coins which draw elements via rejection sampling should override this method to respect
+    /// the specified bound.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - `max_attempts` is zero.
+    /// - A valid field element could not be generated after `max_attempts` calls to the PRNG.
+    fn draw_with_max_attempts<E: FieldElement<BaseField = Self::BaseField>>(
+        &mut self,
+        max_attempts: usize,
+    ) -> Result<E, RandomCoinError> {
+        if max_attempts == 0 {
+            return Err(RandomCoinError::InvalidMaxAttempts);
+        }
+        self.draw()
+    }
 }
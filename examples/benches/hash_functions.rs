@@ -0,0 +1,100 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use std::time::Duration;
+
+use criterion::{criterion_group, criterion_main, BenchmarkGroup, BenchmarkId, Criterion};
+use examples::{fibonacci::fib_small::FibExample, Example};
+use winterfell::{
+    crypto::{
+        hashers::{Blake2s_256, Blake3_256, Rp64_256, Sha3_256},
+        ElementHasher, MerkleTree,
+    },
+    math::{fft, fields::f64::BaseElement, FieldElement, StarkField},
+    DefaultTraceLde, FieldExtension, PartitionOptions, ProofOptions, StarkDomain, Trace,
+    TraceTable,
+};
+
+/// Length of the Fibonacci sequence proven in the benchmark; since every row of the trace
+/// advances the sequence by two terms, the trace has half as many rows.
+const SEQUENCE_LENGTH: usize = 131_072;
+const TRACE_WIDTH: usize = 2;
+const BLOWUP_FACTOR: usize = 8;
+
+fn trace_commitment(c: &mut Criterion) {
+    let mut group = c.benchmark_group("trace_commitment");
+    group.sample_size(10);
+    group.measurement_time(Duration::from_secs(10));
+
+    let options = ProofOptions::new(28, BLOWUP_FACTOR, 0, FieldExtension::Quadratic, 4, 255);
+    let trace = build_trace(SEQUENCE_LENGTH);
+
+    bench_hash_fn::<Blake3_256<BaseElement>>(&mut group, "blake3_256", &trace, &options);
+    bench_hash_fn::<Blake2s_256<BaseElement>>(&mut group, "blake2s_256", &trace, &options);
+    bench_hash_fn::<Sha3_256<BaseElement>>(&mut group, "sha3_256", &trace, &options);
+    bench_hash_fn::<Rp64_256>(&mut group, "rp64_256", &trace, &options);
+
+    group.finish();
+}
+
+criterion_group!(hash_functions_group, trace_commitment);
+criterion_main!(hash_functions_group);
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Benchmarks extending and committing to the specified trace using hash function `H`.
+///
+/// The size of a complete proof generated with the same hash function for the same trace is
+/// reported as the parameter of the benchmark ID, e.g., `commit/blake3_256/proof_bytes=65151`.
+fn bench_hash_fn<H>(
+    group: &mut BenchmarkGroup<'_, criterion::measurement::WallTime>,
+    name: &str,
+    trace: &TraceTable<BaseElement>,
+    options: &ProofOptions,
+) where
+    H: ElementHasher<BaseField = BaseElement> + Sync,
+{
+    // the domain is built from the trace twiddles directly since the AIR of the example is not
+    // needed to commit to the trace
+    let domain = StarkDomain::from_twiddles(
+        fft::get_twiddles(trace.length()),
+        BLOWUP_FACTOR,
+        BaseElement::GENERATOR,
+    );
+    assert_eq!(trace.length() * BLOWUP_FACTOR, domain.lde_domain_size());
+
+    let proof = FibExample::<H>::new(SEQUENCE_LENGTH, options.clone()).prove();
+    let proof_size = proof.to_bytes().len();
+
+    let id = BenchmarkId::new(format!("commit/{name}"), format!("proof_bytes={proof_size}"));
+    group.bench_function(id, |bench| {
+        bench.iter(|| {
+            DefaultTraceLde::<BaseElement, H, MerkleTree<H>>::new(
+                trace.info(),
+                trace.main_segment(),
+                &domain,
+                PartitionOptions::default(),
+            )
+        });
+    });
+}
+
+/// Builds the same execution trace as the one generated by the prover of the small-field
+/// Fibonacci example for the specified sequence length.
+fn build_trace(sequence_length: usize) -> TraceTable<BaseElement> {
+    let mut trace = TraceTable::new(TRACE_WIDTH, sequence_length / 2);
+    trace.fill(
+        |state| {
+            state[0] = BaseElement::ONE;
+            state[1] = BaseElement::ONE;
+        },
+        |_, state| {
+            state[0] += state[1];
+            state[1] += state[0];
+        },
+    );
+    trace
+}
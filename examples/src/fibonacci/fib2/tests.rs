@@ -3,7 +3,9 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use super::{super::utils::build_proof_options, Blake3_256};
+use winterfell::{math::fields::CubeExtension, FieldExtension, ProofOptions, Prover, ProverError};
+
+use super::{super::utils::build_proof_options, BaseElement, Blake3_256};
 
 #[test]
 fn fib2_test_basic_proof_verification() {
@@ -22,3 +24,19 @@ fn fib2_test_basic_proof_verification_fail() {
     let fib = Box::new(super::FibExample::<Blake3_256>::new(16, build_proof_options(false)));
     crate::tests::test_basic_proof_verification_fail(fib);
 }
+
+#[test]
+fn fib2_test_proof_file_round_trip() {
+    let fib = Box::new(super::FibExample::<Blake3_256>::new(16, build_proof_options(false)));
+    crate::tests::test_proof_file_round_trip(fib, "winterfell_fib2_test.proof");
+}
+
+#[test]
+fn fib2_test_unsupported_field_extension() {
+    // f128 field does not support cubic extensions
+    let options = ProofOptions::new(28, 8, 0, FieldExtension::Cubic, 4, 31);
+    let prover = super::FibProver::<Blake3_256>::new(options);
+    let trace = prover.build_trace(16);
+    let err = prover.prove_with_transcript::<CubeExtension<BaseElement>>(trace).unwrap_err();
+    assert_eq!(ProverError::UnsupportedFieldExtension(3), err);
+}
@@ -6,6 +6,7 @@
 use core::marker::PhantomData;
 use std::time::Instant;
 
+use core_utils::Serializable;
 use tracing::{field, info_span};
 use winterfell::{
     crypto::{DefaultRandomCoin, ElementHasher, MerkleTree},
@@ -14,7 +15,7 @@ use winterfell::{
 };
 
 use super::utils::compute_fib_term;
-use crate::{Example, ExampleOptions, HashFunction};
+use crate::{read_pub_inputs, Example, ExampleOptions, HashFunction};
 
 mod air;
 use air::FibSmall;
@@ -32,6 +33,8 @@ const TRACE_WIDTH: usize = 2;
 
 type Blake3_192 = winterfell::crypto::hashers::Blake3_192<BaseElement>;
 type Blake3_256 = winterfell::crypto::hashers::Blake3_256<BaseElement>;
+#[allow(non_camel_case_types)]
+type Blake2s_256 = winterfell::crypto::hashers::Blake2s_256<BaseElement>;
 type Sha3_256 = winterfell::crypto::hashers::Sha3_256<BaseElement>;
 type Rp64_256 = winterfell::crypto::hashers::Rp64_256;
 type RpJive64_256 = winterfell::crypto::hashers::RpJive64_256;
@@ -52,6 +55,9 @@ pub fn get_example(
         HashFunction::Blake3_256 => {
             Ok(Box::new(FibExample::<Blake3_256>::new(sequence_length, options)))
         },
+        HashFunction::Blake2s_256 => {
+            Ok(Box::new(FibExample::<Blake2s_256>::new(sequence_length, options)))
+        },
         HashFunction::Sha3_256 => {
             Ok(Box::new(FibExample::<Sha3_256>::new(sequence_length, options)))
         },
@@ -122,13 +128,17 @@ where
         prover.prove(trace).unwrap()
     }
 
-    fn verify(&self, proof: Proof) -> Result<(), VerifierError> {
+    fn pub_inputs_to_bytes(&self) -> Vec<u8> {
+        self.result.to_bytes()
+    }
+
+    fn verify_with_pub_inputs(&self, proof: Proof, pub_inputs: &[u8]) -> Result<(), VerifierError> {
+        let result = read_pub_inputs(pub_inputs)?;
         let acceptable_options =
             winterfell::AcceptableOptions::OptionSet(vec![proof.options().clone()]);
-
         winterfell::verify::<FibSmall, H, DefaultRandomCoin<H>, MerkleTree<H>>(
             proof,
-            self.result,
+            result,
             &acceptable_options,
         )
     }
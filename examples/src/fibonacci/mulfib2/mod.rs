@@ -6,6 +6,7 @@
 use core::marker::PhantomData;
 use std::time::Instant;
 
+use core_utils::Serializable;
 use tracing::{field, info_span};
 use winterfell::{
     crypto::{DefaultRandomCoin, ElementHasher, MerkleTree},
@@ -14,7 +15,10 @@ use winterfell::{
 };
 
 use super::utils::compute_mulfib_term;
-use crate::{Blake3_192, Blake3_256, Example, ExampleOptions, HashFunction, Sha3_256};
+use crate::{
+    read_pub_inputs, Blake2s_256, Blake3_192, Blake3_256, Example, ExampleOptions, HashFunction,
+    Sha3_256,
+};
 
 mod air;
 use air::MulFib2Air;
@@ -46,6 +50,9 @@ pub fn get_example(
         HashFunction::Blake3_256 => {
             Ok(Box::new(MulFib2Example::<Blake3_256>::new(sequence_length, options)))
         },
+        HashFunction::Blake2s_256 => {
+            Ok(Box::new(MulFib2Example::<Blake2s_256>::new(sequence_length, options)))
+        },
         HashFunction::Sha3_256 => {
             Ok(Box::new(MulFib2Example::<Sha3_256>::new(sequence_length, options)))
         },
@@ -111,12 +118,17 @@ where
         prover.prove(trace).unwrap()
     }
 
-    fn verify(&self, proof: Proof) -> Result<(), VerifierError> {
+    fn pub_inputs_to_bytes(&self) -> Vec<u8> {
+        self.result.to_bytes()
+    }
+
+    fn verify_with_pub_inputs(&self, proof: Proof, pub_inputs: &[u8]) -> Result<(), VerifierError> {
+        let result = read_pub_inputs(pub_inputs)?;
         let acceptable_options =
             winterfell::AcceptableOptions::OptionSet(vec![proof.options().clone()]);
         winterfell::verify::<MulFib2Air, H, DefaultRandomCoin<H>, MerkleTree<H>>(
             proof,
-            self.result,
+            result,
             &acceptable_options,
         )
     }
@@ -3,7 +3,10 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use core_utils::flatten_slice_elements;
+use core_utils::{
+    flatten_slice_elements, ByteReader, ByteWriter, Deserializable, DeserializationError,
+    Serializable,
+};
 use winterfell::{
     math::{fields::f128::BaseElement, FieldElement, ToElements},
     Air, AirContext, Assertion, EvaluationFrame, ProofOptions, TraceInfo,
@@ -37,6 +40,21 @@ impl ToElements<BaseElement> for PublicInputs {
     }
 }
 
+impl Serializable for PublicInputs {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write(&self.pub_keys);
+        target.write(&self.messages);
+    }
+}
+
+impl Deserializable for PublicInputs {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let pub_keys = source.read()?;
+        let messages = source.read()?;
+        Ok(Self { pub_keys, messages })
+    }
+}
+
 pub struct LamportAggregateAir {
     context: AirContext<BaseElement>,
     pub_keys: Vec<[BaseElement; 2]>,
@@ -6,6 +6,7 @@
 use core::marker::PhantomData;
 use std::time::Instant;
 
+use core_utils::Serializable;
 use tracing::{field, info_span};
 use winterfell::{
     crypto::{DefaultRandomCoin, ElementHasher, MerkleTree},
@@ -16,7 +17,9 @@ use winterfell::{
 use super::{
     message_to_elements, rescue, Example, PrivateKey, Signature, CYCLE_LENGTH, NUM_HASH_ROUNDS,
 };
-use crate::{Blake3_192, Blake3_256, ExampleOptions, HashFunction, Sha3_256};
+use crate::{
+    read_pub_inputs, Blake2s_256, Blake3_192, Blake3_256, ExampleOptions, HashFunction, Sha3_256,
+};
 
 mod air;
 use air::{LamportAggregateAir, PublicInputs};
@@ -45,6 +48,9 @@ pub fn get_example(
         HashFunction::Blake3_256 => {
             Ok(Box::new(LamportAggregateExample::<Blake3_256>::new(num_signatures, options)))
         },
+        HashFunction::Blake2s_256 => {
+            Ok(Box::new(LamportAggregateExample::<Blake2s_256>::new(num_signatures, options)))
+        },
         HashFunction::Sha3_256 => {
             Ok(Box::new(LamportAggregateExample::<Sha3_256>::new(num_signatures, options)))
         },
@@ -137,11 +143,16 @@ where
         prover.prove(trace).unwrap()
     }
 
-    fn verify(&self, proof: Proof) -> Result<(), VerifierError> {
+    fn pub_inputs_to_bytes(&self) -> Vec<u8> {
         let pub_inputs = PublicInputs {
             pub_keys: self.pub_keys.clone(),
             messages: self.messages.clone(),
         };
+        pub_inputs.to_bytes()
+    }
+
+    fn verify_with_pub_inputs(&self, proof: Proof, pub_inputs: &[u8]) -> Result<(), VerifierError> {
+        let pub_inputs: PublicInputs = read_pub_inputs(pub_inputs)?;
         let acceptable_options =
             winterfell::AcceptableOptions::OptionSet(vec![proof.options().clone()]);
         winterfell::verify::<LamportAggregateAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
@@ -3,6 +3,7 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
+use core_utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 use winterfell::{
     math::{fields::f128::BaseElement, FieldElement, StarkField, ToElements},
     Air, AirContext, Assertion, EvaluationFrame, ProofOptions, TraceInfo,
@@ -40,6 +41,30 @@ impl ToElements<BaseElement> for PublicInputs {
     }
 }
 
+impl Serializable for PublicInputs {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write(self.pub_key_root);
+        target.write(self.num_pub_keys);
+        target.write(self.num_signatures);
+        target.write(self.message);
+    }
+}
+
+impl Deserializable for PublicInputs {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let pub_key_root = source.read()?;
+        let num_pub_keys = source.read()?;
+        let num_signatures = source.read()?;
+        let message = source.read()?;
+        Ok(Self {
+            pub_key_root,
+            num_pub_keys,
+            num_signatures,
+            message,
+        })
+    }
+}
+
 pub struct LamportThresholdAir {
     context: AirContext<BaseElement>,
     pub_key_root: [BaseElement; 2],
@@ -6,6 +6,7 @@
 use core::marker::PhantomData;
 use std::time::Instant;
 
+use core_utils::Serializable;
 use tracing::{field, info_span};
 use winterfell::{
     crypto::{DefaultRandomCoin, ElementHasher, MerkleTree},
@@ -17,7 +18,9 @@ use super::{
     message_to_elements, rescue, Example, PrivateKey, Signature, CYCLE_LENGTH as HASH_CYCLE_LENGTH,
     NUM_HASH_ROUNDS,
 };
-use crate::{Blake3_192, Blake3_256, ExampleOptions, HashFunction, Sha3_256};
+use crate::{
+    read_pub_inputs, Blake2s_256, Blake3_192, Blake3_256, ExampleOptions, HashFunction, Sha3_256,
+};
 
 mod signature;
 use signature::AggPublicKey;
@@ -50,6 +53,9 @@ pub fn get_example(
         HashFunction::Blake3_256 => {
             Ok(Box::new(LamportThresholdExample::<Blake3_256>::new(num_signers, options)))
         },
+        HashFunction::Blake2s_256 => {
+            Ok(Box::new(LamportThresholdExample::<Blake2s_256>::new(num_signers, options)))
+        },
         HashFunction::Sha3_256 => {
             Ok(Box::new(LamportThresholdExample::<Sha3_256>::new(num_signers, options)))
         },
@@ -143,13 +149,18 @@ where
         prover.prove(trace).unwrap()
     }
 
-    fn verify(&self, proof: Proof) -> Result<(), VerifierError> {
+    fn pub_inputs_to_bytes(&self) -> Vec<u8> {
         let pub_inputs = PublicInputs {
             pub_key_root: self.pub_key.root().to_elements(),
             num_pub_keys: self.pub_key.num_keys(),
             num_signatures: self.signatures.len(),
             message: self.message,
         };
+        pub_inputs.to_bytes()
+    }
+
+    fn verify_with_pub_inputs(&self, proof: Proof, pub_inputs: &[u8]) -> Result<(), VerifierError> {
+        let pub_inputs: PublicInputs = read_pub_inputs(pub_inputs)?;
         let acceptable_options =
             winterfell::AcceptableOptions::OptionSet(vec![proof.options().clone()]);
         winterfell::verify::<LamportThresholdAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
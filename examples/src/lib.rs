@@ -3,6 +3,13 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use core_utils::{ByteReader, ByteWriter, Deserializable, Serializable, SliceReader};
+use serde::Deserialize;
 use structopt::StructOpt;
 use winterfell::{
     crypto::hashers::{Rp64_256, RpJive64_256},
@@ -15,6 +22,12 @@ pub mod fibonacci;
 pub mod lamport;
 #[cfg(feature = "std")]
 pub mod merkle;
+#[cfg(feature = "std")]
+pub mod permutation;
+#[cfg(feature = "std")]
+pub mod preimage;
+#[cfg(feature = "std")]
+pub mod range_check;
 pub mod rescue;
 #[cfg(feature = "std")]
 pub mod rescue_raps;
@@ -29,49 +42,158 @@ mod tests;
 
 pub type Blake3_192 = winterfell::crypto::hashers::Blake3_192<BaseElement>;
 pub type Blake3_256 = winterfell::crypto::hashers::Blake3_256<BaseElement>;
+#[allow(non_camel_case_types)]
+pub type Blake2s_256 = winterfell::crypto::hashers::Blake2s_256<BaseElement>;
 pub type Sha3_256 = winterfell::crypto::hashers::Sha3_256<BaseElement>;
 
 pub trait Example {
     fn prove(&self) -> Proof;
-    fn verify(&self, proof: Proof) -> Result<(), VerifierError>;
     fn verify_with_wrong_inputs(&self, proof: Proof) -> Result<(), VerifierError>;
+
+    /// Returns public inputs of the computation serialized into a vector of bytes.
+    fn pub_inputs_to_bytes(&self) -> Vec<u8>;
+
+    /// Verifies the proof against the public inputs deserialized from the provided bytes.
+    fn verify_with_pub_inputs(&self, proof: Proof, pub_inputs: &[u8]) -> Result<(), VerifierError>;
+
+    /// Verifies the proof against public inputs of this example.
+    fn verify(&self, proof: Proof) -> Result<(), VerifierError> {
+        self.verify_with_pub_inputs(proof, &self.pub_inputs_to_bytes())
+    }
+}
+
+// PROOF FILES
+// ================================================================================================
+
+/// Writes the proof together with the serialized public inputs into the file at `path`.
+///
+/// The public inputs are written first (prefixed with their length), followed by the proof.
+pub fn write_proof_file(path: &Path, proof: &Proof, pub_inputs: &[u8]) -> io::Result<()> {
+    let mut bytes = Vec::new();
+    bytes.write_usize(pub_inputs.len());
+    bytes.write_bytes(pub_inputs);
+    proof.write_into(&mut bytes);
+    fs::write(path, bytes)
+}
+
+/// Reads a proof and serialized public inputs from the file at `path`; the file is expected to
+/// have been written by [write_proof_file()].
+pub fn read_proof_file(path: &Path) -> io::Result<(Proof, Vec<u8>)> {
+    let bytes = fs::read(path)?;
+    let mut reader = SliceReader::new(&bytes);
+    let parsed = reader.read_usize().and_then(|num_bytes| {
+        let pub_inputs = reader.read_vec(num_bytes)?;
+        let proof = Proof::read_from(&mut reader)?;
+        Ok((proof, pub_inputs))
+    });
+
+    match parsed {
+        Ok(_) if reader.has_more_bytes() => {
+            Err(io::Error::new(io::ErrorKind::InvalidData, "unconsumed bytes in proof file"))
+        },
+        Ok(result) => Ok(result),
+        Err(err) => Err(io::Error::new(io::ErrorKind::InvalidData, err.to_string())),
+    }
+}
+
+/// Deserializes public inputs of an example from the provided bytes.
+pub fn read_pub_inputs<P: Deserializable>(bytes: &[u8]) -> Result<P, VerifierError> {
+    P::read_from_bytes(bytes).map_err(|err| {
+        VerifierError::ProofDeserializationError(format!(
+            "public inputs deserialization failed: {err}"
+        ))
+    })
 }
 
 // EXAMPLE OPTIONS
 // ================================================================================================
 
-#[derive(StructOpt, Debug)]
+/// Options of an example run.
+///
+/// The options are usually read from command-line arguments, but a set of options can also be
+/// read from a JSON config file via [ExampleOptions::from_file()]. In a config file, options use
+/// the same names as the long versions of command-line arguments, and the example is specified as
+/// an object keyed by the name of the example subcommand, e.g.:
+///
+/// ```json
+/// { "example": { "fib": { "sequence_length": 1024 } }, "queries": 28, "blowup": 8 }
+/// ```
+#[derive(StructOpt, Debug, Deserialize)]
 #[structopt(name = "winterfell", about = "Winterfell examples")]
+#[serde(deny_unknown_fields)]
 pub struct ExampleOptions {
     #[structopt(subcommand)]
     pub example: ExampleType,
 
     /// Hash function used in the protocol
     #[structopt(short = "h", long = "hash_fn", default_value = "blake3_256")]
+    #[serde(default = "default_hash_fn")]
     hash_fn: String,
 
     /// Number of queries to include in a proof
     #[structopt(short = "q", long = "queries")]
+    #[serde(default, rename = "queries")]
     num_queries: Option<usize>,
 
     /// Blowup factor for low degree extension
     #[structopt(short = "b", long = "blowup")]
+    #[serde(default, rename = "blowup")]
     blowup_factor: Option<usize>,
 
     /// Grinding factor for query seed
     #[structopt(short = "g", long = "grinding", default_value = "16")]
+    #[serde(default = "default_grinding_factor", rename = "grinding")]
     grinding_factor: u32,
 
     /// Field extension degree for composition polynomial
     #[structopt(short = "e", long = "field_extension", default_value = "1")]
+    #[serde(default = "default_field_extension")]
     field_extension: u32,
 
     /// Folding factor for FRI protocol
     #[structopt(short = "f", long = "folding", default_value = "8")]
+    #[serde(default = "default_folding_factor", rename = "folding")]
     folding_factor: usize,
+
+    /// Write the proof and the public inputs into the specified file
+    #[structopt(long = "out", parse(from_os_str))]
+    #[serde(default)]
+    pub out: Option<PathBuf>,
+
+    /// Verify the proof and the public inputs read from the specified file instead of generating
+    /// a new proof
+    #[structopt(long = "verify-file", parse(from_os_str))]
+    #[serde(default, rename = "verify-file")]
+    pub verify_file: Option<PathBuf>,
 }
 
 impl ExampleOptions {
+    /// Reads one or more sets of example options from the JSON config file at `path`.
+    ///
+    /// The file must contain either a single set of options, or an array of option sets; options
+    /// which are not specified default to the same values as the corresponding command-line
+    /// arguments. Batch runs (i.e., the `batch` subcommand) cannot be specified in a config file.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be read, or if its contents are not a valid config.
+    pub fn from_file(path: &Path) -> io::Result<Vec<Self>> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Config {
+            Single(ExampleOptions),
+            Batch(Vec<ExampleOptions>),
+        }
+
+        let bytes = fs::read(path)?;
+        let config = serde_json::from_slice(&bytes)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string()))?;
+
+        Ok(match config {
+            Config::Single(options) => vec![options],
+            Config::Batch(options) => options,
+        })
+    }
+
     pub fn to_proof_options(&self, q: usize, b: usize) -> (ProofOptions, HashFunction) {
         let num_queries = self.num_queries.unwrap_or(q);
         let blowup_factor = self.blowup_factor.unwrap_or(b);
@@ -79,12 +201,14 @@ impl ExampleOptions {
             1 => FieldExtension::None,
             2 => FieldExtension::Quadratic,
             3 => FieldExtension::Cubic,
+            6 => FieldExtension::Sextic,
             val => panic!("'{val}' is not a valid field extension option"),
         };
 
         let hash_fn = match self.hash_fn.as_str() {
             "blake3_192" => HashFunction::Blake3_192,
             "blake3_256" => HashFunction::Blake3_256,
+            "blake2s_256" => HashFunction::Blake2s_256,
             "sha3_256" => HashFunction::Sha3_256,
             "rp64_256" => HashFunction::Rp64_256,
             "rp_jive64_256" => HashFunction::RpJive64_256,
@@ -109,6 +233,7 @@ impl ExampleOptions {
         let security_level = match self.hash_fn.as_str() {
             "blake3_192" => proof.security_level::<Blake3_192>(conjectured),
             "blake3_256" => proof.security_level::<Blake3_256>(conjectured),
+            "blake2s_256" => proof.security_level::<Blake2s_256>(conjectured),
             "sha3_256" => proof.security_level::<Sha3_256>(conjectured),
             "rp64_256" => proof.security_level::<Rp64_256>(conjectured),
             "rp_jive64_256" => proof.security_level::<RpJive64_256>(conjectured),
@@ -119,8 +244,9 @@ impl ExampleOptions {
     }
 }
 
-#[derive(StructOpt, Debug)]
+#[derive(StructOpt, Debug, Deserialize)]
 //#[structopt(about = "available examples")]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
 pub enum ExampleType {
     /// Compute a Fibonacci sequence using trace table with 2 registers
     Fib {
@@ -177,6 +303,27 @@ pub enum ExampleType {
         #[structopt(short = "n", default_value = "1024")]
         chain_length: usize,
     },
+    /// Prove that a column of 64-bit field elements is a permutation of a public sequence
+    #[cfg(feature = "std")]
+    Permutation {
+        /// Number of values in the sequence; must be a power of two and at least 8
+        #[structopt(short = "n", default_value = "1024")]
+        num_values: usize,
+    },
+    /// Prove that a set of secret values lies in [0, 2^16) using a lookup argument
+    #[cfg(feature = "std")]
+    RangeCheck {
+        /// Number of values to range-check
+        #[structopt(short = "n", default_value = "1024")]
+        num_values: usize,
+    },
+    /// Prove knowledge of a preimage of a Rescue hash digest
+    #[cfg(feature = "std")]
+    Preimage {
+        /// Number of elements in the preimage; must be a multiple of 4
+        #[structopt(short = "n", default_value = "16")]
+        num_elements: usize,
+    },
     /// Compute a root of a Merkle path using Rescue hash function
     #[cfg(feature = "std")]
     Merkle {
@@ -198,6 +345,79 @@ pub enum ExampleType {
         #[structopt(short = "n", default_value = "3")]
         num_signers: usize,
     },
+    /// Prove and verify all examples listed in a JSON config file
+    #[serde(skip)]
+    Batch {
+        /// Path to the config file; see `ExampleOptions::from_file()` for the file format
+        #[structopt(parse(from_os_str))]
+        config: PathBuf,
+    },
+}
+
+fn default_hash_fn() -> String {
+    "blake3_256".into()
+}
+
+fn default_grinding_factor() -> u32 {
+    16
+}
+
+fn default_field_extension() -> u32 {
+    1
+}
+
+fn default_folding_factor() -> usize {
+    8
+}
+
+// EXAMPLE INSTANTIATION
+// ================================================================================================
+
+/// Instantiates the example specified by the provided `options`.
+///
+/// # Errors
+/// Returns an error if the example could not be instantiated with the specified options, or if
+/// the options specify a batch run rather than a single example.
+pub fn get_example(options: &ExampleOptions) -> Result<Box<dyn Example>, String> {
+    match options.example {
+        ExampleType::Fib { sequence_length } => {
+            fibonacci::fib2::get_example(options, sequence_length)
+        },
+        ExampleType::Fib8 { sequence_length } => {
+            fibonacci::fib8::get_example(options, sequence_length)
+        },
+        ExampleType::Mulfib { sequence_length } => {
+            fibonacci::mulfib2::get_example(options, sequence_length)
+        },
+        ExampleType::Mulfib8 { sequence_length } => {
+            fibonacci::mulfib8::get_example(options, sequence_length)
+        },
+        ExampleType::FibSmall { sequence_length } => {
+            fibonacci::fib_small::get_example(options, sequence_length)
+        },
+        ExampleType::Vdf { num_steps } => vdf::regular::get_example(options, num_steps),
+        ExampleType::VdfExempt { num_steps } => vdf::exempt::get_example(options, num_steps),
+        ExampleType::Rescue { chain_length } => rescue::get_example(options, chain_length),
+        #[cfg(feature = "std")]
+        ExampleType::RescueRaps { chain_length } => rescue_raps::get_example(options, chain_length),
+        #[cfg(feature = "std")]
+        ExampleType::Permutation { num_values } => permutation::get_example(options, num_values),
+        #[cfg(feature = "std")]
+        ExampleType::RangeCheck { num_values } => range_check::get_example(options, num_values),
+        #[cfg(feature = "std")]
+        ExampleType::Preimage { num_elements } => preimage::get_example(options, num_elements),
+        #[cfg(feature = "std")]
+        ExampleType::Merkle { tree_depth } => merkle::get_example(options, tree_depth),
+        #[cfg(feature = "std")]
+        ExampleType::LamportA { num_signatures } => {
+            lamport::aggregate::get_example(options, num_signatures)
+        },
+        #[cfg(feature = "std")]
+        ExampleType::LamportT { num_signers } => {
+            lamport::threshold::get_example(options, num_signers)
+        },
+        ExampleType::Batch { .. } => Err("a batch run is not a single example".into()),
+    }
 }
 
 /// Defines a set of hash functions available for the provided examples. Some examples may not
@@ -219,6 +439,12 @@ pub enum HashFunction {
     /// When this function is used in the STARK protocol, proof security cannot exceed 128 bits.
     Blake3_256,
 
+    /// BLAKE2s hash function with 256 bit output.
+    ///
+    /// When this function is used in the STARK protocol, proof security cannot exceed 128 bits.
+    #[allow(non_camel_case_types)]
+    Blake2s_256,
+
     /// SHA3 hash function with 256 bit output.
     ///
     /// When this function is used in the STARK protocol, proof security cannot exceed 128 bits.
@@ -5,9 +5,7 @@
 
 use std::time::Instant;
 
-use examples::{fibonacci, rescue, vdf, ExampleOptions, ExampleType};
-#[cfg(feature = "std")]
-use examples::{lamport, merkle, rescue_raps};
+use examples::{get_example, read_proof_file, write_proof_file, ExampleOptions, ExampleType};
 use structopt::StructOpt;
 use tracing::info_span;
 #[cfg(feature = "tracing-forest")]
@@ -48,42 +46,40 @@ fn main() {
     // read command-line args
     let options = ExampleOptions::from_args();
 
+    // run all examples listed in the config file, if requested
+    if let ExampleType::Batch { config } = &options.example {
+        let batch = ExampleOptions::from_file(config).expect("failed to read the config file");
+        for (i, options) in batch.iter().enumerate() {
+            println!("=====================\nExample {} of {}: {:?}", i + 1, batch.len(), options);
+            run_example(options);
+        }
+        return;
+    }
+
+    run_example(&options);
+}
+
+/// Instantiates the example specified by the `options`, and then proves and verifies it (or
+/// verifies the proof read from a file, if requested).
+fn run_example(options: &ExampleOptions) {
     // instantiate and prepare the example
-    let example = match options.example {
-        ExampleType::Fib { sequence_length } => {
-            fibonacci::fib2::get_example(&options, sequence_length)
-        },
-        ExampleType::Fib8 { sequence_length } => {
-            fibonacci::fib8::get_example(&options, sequence_length)
-        },
-        ExampleType::Mulfib { sequence_length } => {
-            fibonacci::mulfib2::get_example(&options, sequence_length)
-        },
-        ExampleType::Mulfib8 { sequence_length } => {
-            fibonacci::mulfib8::get_example(&options, sequence_length)
-        },
-        ExampleType::FibSmall { sequence_length } => {
-            fibonacci::fib_small::get_example(&options, sequence_length)
-        },
-        ExampleType::Vdf { num_steps } => vdf::regular::get_example(&options, num_steps),
-        ExampleType::VdfExempt { num_steps } => vdf::exempt::get_example(&options, num_steps),
-        ExampleType::Rescue { chain_length } => rescue::get_example(&options, chain_length),
-        #[cfg(feature = "std")]
-        ExampleType::RescueRaps { chain_length } => {
-            rescue_raps::get_example(&options, chain_length)
-        },
-        #[cfg(feature = "std")]
-        ExampleType::Merkle { tree_depth } => merkle::get_example(&options, tree_depth),
-        #[cfg(feature = "std")]
-        ExampleType::LamportA { num_signatures } => {
-            lamport::aggregate::get_example(&options, num_signatures)
-        },
-        #[cfg(feature = "std")]
-        ExampleType::LamportT { num_signers } => {
-            lamport::threshold::get_example(&options, num_signers)
-        },
+    let example = get_example(options).expect("The example failed to initialize.");
+
+    // verify the proof read from a file instead of generating a new one, if requested
+    if let Some(path) = &options.verify_file {
+        let (proof, pub_inputs) = read_proof_file(path).expect("failed to read the proof file");
+        println!("---------------------");
+        let now = Instant::now();
+        match example.verify_with_pub_inputs(proof, &pub_inputs) {
+            Ok(_) => println!(
+                "Proof from {} verified in {:.1} ms",
+                path.display(),
+                now.elapsed().as_micros() as f64 / 1000f64
+            ),
+            Err(msg) => println!("Failed to verify proof from {}: {}", path.display(), msg),
+        }
+        return;
     }
-    .expect("The example failed to initialize.");
 
     // generate proof
     let now = Instant::now();
@@ -109,6 +105,13 @@ fn main() {
     #[cfg(feature = "std")]
     println!("Proof hash: {}", hex::encode(blake3::hash(&proof_bytes).as_bytes()));
 
+    // write the proof and the public inputs into a file, if requested
+    if let Some(path) = &options.out {
+        write_proof_file(path, &proof, &example.pub_inputs_to_bytes())
+            .expect("failed to write the proof file");
+        println!("Proof written to {}", path.display());
+    }
+
     // verify the proof
     println!("---------------------");
     let parsed_proof = Proof::from_bytes(&proof_bytes).unwrap();
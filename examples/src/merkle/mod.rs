@@ -6,6 +6,7 @@
 use core::marker::PhantomData;
 use std::time::Instant;
 
+use core_utils::Serializable;
 use rand_utils::{rand_value, rand_vector};
 use tracing::{field, info_span};
 use winterfell::{
@@ -15,11 +16,12 @@ use winterfell::{
 };
 
 use crate::{
+    read_pub_inputs,
     utils::rescue::{
         self, Hash, Rescue128, CYCLE_LENGTH as HASH_CYCLE_LEN, NUM_ROUNDS as NUM_HASH_ROUNDS,
         STATE_WIDTH as HASH_STATE_WIDTH,
     },
-    Blake3_192, Blake3_256, Example, ExampleOptions, HashFunction, Sha3_256,
+    Blake2s_256, Blake3_192, Blake3_256, Example, ExampleOptions, HashFunction, Sha3_256,
 };
 
 mod air;
@@ -51,6 +53,9 @@ pub fn get_example(
         HashFunction::Blake3_256 => {
             Ok(Box::new(MerkleExample::<Blake3_256>::new(tree_depth, options)))
         },
+        HashFunction::Blake2s_256 => {
+            Ok(Box::new(MerkleExample::<Blake2s_256>::new(tree_depth, options)))
+        },
         HashFunction::Sha3_256 => Ok(Box::new(MerkleExample::<Sha3_256>::new(tree_depth, options))),
         _ => Err("The specified hash function cannot be used with this example.".to_string()),
     }
@@ -89,7 +94,7 @@ impl<H: ElementHasher> MerkleExample<H> {
         println!(
             "Computed Merkle path from leaf {} to root {} in {} ms",
             index,
-            hex::encode(tree.root().as_bytes()),
+            tree.root().to_hex(),
             now.elapsed().as_millis(),
         );
 
@@ -133,8 +138,13 @@ where
         prover.prove(trace).unwrap()
     }
 
-    fn verify(&self, proof: Proof) -> Result<(), VerifierError> {
+    fn pub_inputs_to_bytes(&self) -> Vec<u8> {
         let pub_inputs = PublicInputs { tree_root: self.tree_root.to_elements() };
+        pub_inputs.to_bytes()
+    }
+
+    fn verify_with_pub_inputs(&self, proof: Proof, pub_inputs: &[u8]) -> Result<(), VerifierError> {
+        let pub_inputs: PublicInputs = read_pub_inputs(pub_inputs)?;
         let acceptable_options =
             winterfell::AcceptableOptions::OptionSet(vec![proof.options().clone()]);
         winterfell::verify::<MerkleAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
@@ -24,6 +24,11 @@ fn merkle_test_basic_proof_verification_fail() {
     let merkle = Box::new(super::MerkleExample::<Blake3_256>::new(7, build_options(false)));
     crate::tests::test_basic_proof_verification_fail(merkle);
 }
+#[test]
+fn merkle_test_proof_file_round_trip() {
+    let merkle = Box::new(super::MerkleExample::<Blake3_256>::new(7, build_options(false)));
+    crate::tests::test_proof_file_round_trip(merkle, "winterfell_merkle_test.proof");
+}
 
 fn build_options(use_extension_field: bool) -> ProofOptions {
     let extension = if use_extension_field {
@@ -0,0 +1,149 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use core_utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
+use winterfell::{
+    math::ToElements, Air, AirContext, Assertion, AuxRandElements, EvaluationFrame, TraceInfo,
+    TransitionConstraintDegree,
+};
+
+use super::{BaseElement, ExtensionOf, FieldElement, ProofOptions, AUX_TRACE_WIDTH, TRACE_WIDTH};
+use crate::utils::are_equal;
+
+// PERMUTATION AIR
+// ================================================================================================
+
+pub struct PublicInputs {
+    pub seed: BaseElement,
+    pub result: BaseElement,
+}
+
+impl ToElements<BaseElement> for PublicInputs {
+    fn to_elements(&self) -> Vec<BaseElement> {
+        vec![self.seed, self.result]
+    }
+}
+
+impl Serializable for PublicInputs {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write(self.seed);
+        target.write(self.result);
+    }
+}
+
+impl Deserializable for PublicInputs {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let seed = source.read()?;
+        let result = source.read()?;
+        Ok(Self { seed, result })
+    }
+}
+
+/// AIR for the permutation example.
+///
+/// The main trace segment consists of two columns: column `a` holds the sequence a(0) = seed,
+/// a(i + 1) = a(i)^2 + 1, and column `b` holds a permutation of the values in column `a`.
+///
+/// The auxiliary segment consists of a single running product column `z` which is built using a
+/// random element α as z(0) = 1, z(i + 1) = z(i) * (α - a(i)) / (α - b(i)). Since the transition
+/// constraint for `z` relates each row to the next one, the running product does not cover the
+/// last row of the trace. Thus, the permutation is applied to all rows but the last one, and the
+/// last value of column `b` is asserted to be equal to the last value of column `a`. Asserting that
+/// z(n - 1) = 1 then implies that column `b` is a permutation of column `a`.
+pub struct PermutationAir {
+    context: AirContext<BaseElement>,
+    seed: BaseElement,
+    result: BaseElement,
+}
+
+impl Air for PermutationAir {
+    type BaseField = BaseElement;
+    type PublicInputs = PublicInputs;
+    type GkrProof = ();
+    type GkrVerifier = ();
+
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    fn new(trace_info: TraceInfo, pub_inputs: PublicInputs, options: ProofOptions) -> Self {
+        assert_eq!(TRACE_WIDTH, trace_info.main_trace_width());
+        assert_eq!(AUX_TRACE_WIDTH, trace_info.aux_segment_width());
+
+        let main_degrees = vec![TransitionConstraintDegree::new(2)];
+        let aux_degrees = vec![TransitionConstraintDegree::new(2)];
+        PermutationAir {
+            context: AirContext::new_multi_segment(
+                trace_info,
+                main_degrees,
+                aux_degrees,
+                3,
+                2,
+                None,
+                options,
+            ),
+            seed: pub_inputs.seed,
+            result: pub_inputs.result,
+        }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+
+    fn evaluate_transition<E: FieldElement + From<Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        debug_assert_eq!(TRACE_WIDTH, current.len());
+        debug_assert_eq!(TRACE_WIDTH, next.len());
+
+        // a(i + 1) = a(i)^2 + 1; column b is constrained only via the auxiliary segment
+        result[0] = are_equal(next[0], current[0].square() + E::ONE);
+    }
+
+    fn evaluate_aux_transition<F, E>(
+        &self,
+        main_frame: &EvaluationFrame<F>,
+        aux_frame: &EvaluationFrame<E>,
+        _periodic_values: &[F],
+        aux_rand_elements: &AuxRandElements<E>,
+        result: &mut [E],
+    ) where
+        F: FieldElement<BaseField = Self::BaseField>,
+        E: FieldElement<BaseField = Self::BaseField> + ExtensionOf<F>,
+    {
+        let main_current = main_frame.current();
+        let aux_current = aux_frame.current();
+        let aux_next = aux_frame.next();
+
+        let alpha = aux_rand_elements.rand_elements()[0];
+
+        // z(i + 1) * (α - b(i)) = z(i) * (α - a(i))
+        result[0] = are_equal(
+            aux_next[0] * (alpha - main_current[1].into()),
+            aux_current[0] * (alpha - main_current[0].into()),
+        );
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        let last_step = self.trace_length() - 1;
+        vec![
+            Assertion::single(0, 0, self.seed),
+            Assertion::single(0, last_step, self.result),
+            Assertion::single(1, last_step, self.result),
+        ]
+    }
+
+    fn get_aux_assertions<E>(&self, _aux_rand_elements: &AuxRandElements<E>) -> Vec<Assertion<E>>
+    where
+        E: FieldElement<BaseField = Self::BaseField>,
+    {
+        let last_step = self.trace_length() - 1;
+        vec![Assertion::single(0, 0, E::ONE), Assertion::single(0, last_step, E::ONE)]
+    }
+}
@@ -0,0 +1,182 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use core::marker::PhantomData;
+use std::time::Instant;
+
+use core_utils::Serializable;
+use tracing::{field, info_span};
+use winterfell::{
+    crypto::{DefaultRandomCoin, ElementHasher, MerkleTree},
+    math::{fields::f64::BaseElement, ExtensionOf, FieldElement},
+    Proof, ProofOptions, Prover, Trace, VerifierError,
+};
+
+use crate::{read_pub_inputs, Example, ExampleOptions, HashFunction};
+
+mod air;
+use air::{PermutationAir, PublicInputs};
+
+mod prover;
+use prover::PermutationProver;
+
+#[cfg(test)]
+mod tests;
+
+// CONSTANTS AND TYPES
+// ================================================================================================
+
+const TRACE_WIDTH: usize = 2;
+const AUX_TRACE_WIDTH: usize = 1;
+
+type Blake3_192 = winterfell::crypto::hashers::Blake3_192<BaseElement>;
+type Blake3_256 = winterfell::crypto::hashers::Blake3_256<BaseElement>;
+#[allow(non_camel_case_types)]
+type Blake2s_256 = winterfell::crypto::hashers::Blake2s_256<BaseElement>;
+type Sha3_256 = winterfell::crypto::hashers::Sha3_256<BaseElement>;
+type Rp64_256 = winterfell::crypto::hashers::Rp64_256;
+type RpJive64_256 = winterfell::crypto::hashers::RpJive64_256;
+
+// PERMUTATION EXAMPLE
+// ================================================================================================
+
+pub fn get_example(
+    options: &ExampleOptions,
+    num_values: usize,
+) -> Result<Box<dyn Example>, String> {
+    let (options, hash_fn) = options.to_proof_options(28, 8);
+
+    match hash_fn {
+        HashFunction::Blake3_192 => {
+            Ok(Box::new(PermutationExample::<Blake3_192>::new(num_values, options)))
+        },
+        HashFunction::Blake3_256 => {
+            Ok(Box::new(PermutationExample::<Blake3_256>::new(num_values, options)))
+        },
+        HashFunction::Blake2s_256 => {
+            Ok(Box::new(PermutationExample::<Blake2s_256>::new(num_values, options)))
+        },
+        HashFunction::Sha3_256 => {
+            Ok(Box::new(PermutationExample::<Sha3_256>::new(num_values, options)))
+        },
+        HashFunction::Rp64_256 => {
+            Ok(Box::new(PermutationExample::<Rp64_256>::new(num_values, options)))
+        },
+        HashFunction::RpJive64_256 => {
+            Ok(Box::new(PermutationExample::<RpJive64_256>::new(num_values, options)))
+        },
+    }
+}
+
+/// Proves that a secret column of values is a permutation of a public sequence computed as
+/// a(i + 1) = a(i)^2 + 1 from a public seed.
+///
+/// The main trace segment lives in the 64-bit base field, while the running product column of the
+/// permutation argument lives in the auxiliary segment. Since a random element drawn from a 64-bit
+/// field does not provide enough soundness for the permutation argument, this example is meant to
+/// be run with a quadratic (or higher) field extension.
+pub struct PermutationExample<H: ElementHasher> {
+    options: ProofOptions,
+    num_values: usize,
+    seed: BaseElement,
+    result: BaseElement,
+    _hasher: PhantomData<H>,
+}
+
+impl<H: ElementHasher> PermutationExample<H> {
+    pub fn new(num_values: usize, options: ProofOptions) -> Self {
+        assert!(num_values.is_power_of_two(), "number of values must be a power of 2");
+        assert!(num_values >= 8, "number of values must be at least 8");
+
+        let seed = BaseElement::new(42);
+
+        // compute the last value of the sequence
+        let now = Instant::now();
+        let result = compute_sequence(seed, num_values)[num_values - 1];
+        println!(
+            "Computed a sequence of {} values in {} ms",
+            num_values,
+            now.elapsed().as_millis()
+        );
+
+        PermutationExample {
+            options,
+            num_values,
+            seed,
+            result,
+            _hasher: PhantomData,
+        }
+    }
+}
+
+// EXAMPLE IMPLEMENTATION
+// ================================================================================================
+
+impl<H: ElementHasher> Example for PermutationExample<H>
+where
+    H: ElementHasher<BaseField = BaseElement> + Sync,
+{
+    fn prove(&self) -> Proof {
+        println!("Generating proof for a permutation of {} values", self.num_values);
+
+        // create a prover
+        let prover = PermutationProver::<H>::new(self.options.clone());
+
+        // generate execution trace
+        let trace =
+            info_span!("generate_execution_trace", num_cols = TRACE_WIDTH, steps = field::Empty)
+                .in_scope(|| {
+                    let trace = prover.build_trace(self.seed, self.num_values);
+                    tracing::Span::current().record("steps", trace.length());
+                    trace
+                });
+
+        // generate the proof
+        prover.prove(trace).unwrap()
+    }
+
+    fn pub_inputs_to_bytes(&self) -> Vec<u8> {
+        let pub_inputs = PublicInputs { seed: self.seed, result: self.result };
+        pub_inputs.to_bytes()
+    }
+
+    fn verify_with_pub_inputs(&self, proof: Proof, pub_inputs: &[u8]) -> Result<(), VerifierError> {
+        let pub_inputs: PublicInputs = read_pub_inputs(pub_inputs)?;
+        let acceptable_options =
+            winterfell::AcceptableOptions::OptionSet(vec![proof.options().clone()]);
+        winterfell::verify::<PermutationAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+            proof,
+            pub_inputs,
+            &acceptable_options,
+        )
+    }
+
+    fn verify_with_wrong_inputs(&self, proof: Proof) -> Result<(), VerifierError> {
+        let pub_inputs = PublicInputs {
+            seed: self.seed,
+            result: self.result + BaseElement::ONE,
+        };
+        let acceptable_options =
+            winterfell::AcceptableOptions::OptionSet(vec![proof.options().clone()]);
+        winterfell::verify::<PermutationAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+            proof,
+            pub_inputs,
+            &acceptable_options,
+        )
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a sequence of `n` values where a(0) = seed and a(i + 1) = a(i)^2 + 1.
+fn compute_sequence(seed: BaseElement, n: usize) -> Vec<BaseElement> {
+    let mut result = Vec::with_capacity(n);
+    result.push(seed);
+    for i in 1..n {
+        result.push(result[i - 1].square() + BaseElement::ONE);
+    }
+    result
+}
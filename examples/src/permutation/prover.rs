@@ -0,0 +1,136 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use rand_utils::shuffle;
+use winterfell::{
+    crypto::MerkleTree, math::batch_inversion, matrix::ColMatrix, AuxRandElements, CompositionPoly,
+    CompositionPolyTrace, ConstraintCompositionCoefficients, DefaultConstraintCommitment,
+    DefaultConstraintEvaluator, DefaultTraceLde, PartitionOptions, StarkDomain, Trace, TraceInfo,
+    TracePolyTable, TraceTable,
+};
+
+use super::{
+    compute_sequence, BaseElement, DefaultRandomCoin, ElementHasher, FieldElement, PermutationAir,
+    PhantomData, ProofOptions, Prover, PublicInputs, AUX_TRACE_WIDTH,
+};
+
+// PERMUTATION PROVER
+// ================================================================================================
+
+pub struct PermutationProver<H: ElementHasher> {
+    options: ProofOptions,
+    _hasher: PhantomData<H>,
+}
+
+impl<H: ElementHasher> PermutationProver<H> {
+    pub fn new(options: ProofOptions) -> Self {
+        Self { options, _hasher: PhantomData }
+    }
+
+    /// Builds the main segment of an execution trace for a sequence of `num_values` values
+    /// starting with the specified `seed`, together with a random permutation of this sequence.
+    ///
+    /// The last value of the sequence is not permuted (see [PermutationAir] for details). The
+    /// returned trace declares an auxiliary segment which is built by
+    /// [Prover::build_aux_trace()] over the extension field specified by proof options.
+    pub fn build_trace(&self, seed: BaseElement, num_values: usize) -> TraceTable<BaseElement> {
+        let values = compute_sequence(seed, num_values);
+        let mut permuted_values = values.clone();
+        shuffle(&mut permuted_values[..num_values - 1]);
+
+        TraceTable::init(vec![values, permuted_values]).with_aux_segment(AUX_TRACE_WIDTH, 1)
+    }
+}
+
+impl<H: ElementHasher> Prover for PermutationProver<H>
+where
+    H: ElementHasher<BaseField = BaseElement> + Sync,
+{
+    type BaseField = BaseElement;
+    type Air = PermutationAir;
+    type Trace = TraceTable<BaseElement>;
+    type HashFn = H;
+    type VC = MerkleTree<H>;
+    type RandomCoin = DefaultRandomCoin<Self::HashFn>;
+    type TraceLde<E: FieldElement<BaseField = Self::BaseField>> =
+        DefaultTraceLde<E, Self::HashFn, Self::VC>;
+    type ConstraintCommitment<E: FieldElement<BaseField = Self::BaseField>> =
+        DefaultConstraintCommitment<E, H, Self::VC>;
+    type ConstraintEvaluator<'a, E: FieldElement<BaseField = Self::BaseField>> =
+        DefaultConstraintEvaluator<'a, Self::Air, E>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> PublicInputs {
+        let last_step = trace.length() - 1;
+        PublicInputs {
+            seed: trace.get(0, 0),
+            result: trace.get(0, last_step),
+        }
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+
+    fn new_trace_lde<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        trace_info: &TraceInfo,
+        main_trace: &ColMatrix<Self::BaseField>,
+        domain: &StarkDomain<Self::BaseField>,
+        partition_option: PartitionOptions,
+    ) -> (Self::TraceLde<E>, TracePolyTable<E>) {
+        DefaultTraceLde::new(trace_info, main_trace, domain, partition_option)
+    }
+
+    fn new_evaluator<'a, E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        air: &'a Self::Air,
+        aux_rand_elements: Option<AuxRandElements<E>>,
+        composition_coefficients: ConstraintCompositionCoefficients<E>,
+    ) -> Self::ConstraintEvaluator<'a, E> {
+        DefaultConstraintEvaluator::new(air, aux_rand_elements, composition_coefficients)
+    }
+
+    fn build_constraint_commitment<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        composition_poly_trace: CompositionPolyTrace<E>,
+        num_constraint_composition_columns: usize,
+        domain: &StarkDomain<Self::BaseField>,
+        partition_options: PartitionOptions,
+    ) -> (Self::ConstraintCommitment<E>, CompositionPoly<E>) {
+        DefaultConstraintCommitment::new(
+            composition_poly_trace,
+            num_constraint_composition_columns,
+            domain,
+            partition_options,
+        )
+    }
+
+    fn build_aux_trace<E>(
+        &self,
+        trace: &Self::Trace,
+        aux_rand_elements: &AuxRandElements<E>,
+    ) -> ColMatrix<E>
+    where
+        E: FieldElement<BaseField = Self::BaseField>,
+    {
+        let main_trace = trace.main_segment();
+        let alpha = aux_rand_elements.rand_elements()[0];
+
+        let values = main_trace.get_column(0);
+        let permuted_values = main_trace.get_column(1);
+
+        // the denominators (α - b(i)) are inverted in a single batch
+        let denominators = permuted_values.iter().map(|&b| alpha - b.into()).collect::<Vec<_>>();
+        let inv_denominators = batch_inversion(&denominators);
+
+        let mut running_product = vec![E::ONE; main_trace.num_rows()];
+        for i in 0..running_product.len() - 1 {
+            running_product[i + 1] =
+                running_product[i] * (alpha - values[i].into()) * inv_denominators[i];
+        }
+
+        ColMatrix::new(vec![running_product])
+    }
+}
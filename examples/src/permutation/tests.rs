@@ -0,0 +1,24 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use winterfell::{FieldExtension, ProofOptions};
+
+use super::Rp64_256;
+
+#[test]
+fn permutation_test_basic_proof_verification() {
+    let permutation = Box::new(super::PermutationExample::<Rp64_256>::new(128, build_options()));
+    crate::tests::test_basic_proof_verification(permutation);
+}
+
+#[test]
+fn permutation_test_basic_proof_verification_fail() {
+    let permutation = Box::new(super::PermutationExample::<Rp64_256>::new(128, build_options()));
+    crate::tests::test_basic_proof_verification_fail(permutation);
+}
+
+fn build_options() -> ProofOptions {
+    ProofOptions::new(28, 8, 0, FieldExtension::Quadratic, 4, 7)
+}
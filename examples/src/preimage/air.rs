@@ -0,0 +1,109 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use core_utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
+use winterfell::{
+    math::ToElements, Air, AirContext, Assertion, EvaluationFrame, ProofOptions, TraceInfo,
+};
+
+use super::{get_trace_length, BaseElement, FieldElement, RATE_WIDTH, TRACE_WIDTH};
+use crate::utils::{gadgets, rescue::Hash};
+
+// HASH PREIMAGE AIR
+// ================================================================================================
+
+pub struct PublicInputs {
+    pub num_elements: usize,
+    pub digest: [BaseElement; 2],
+}
+
+impl ToElements<BaseElement> for PublicInputs {
+    fn to_elements(&self) -> Vec<BaseElement> {
+        vec![BaseElement::new(self.num_elements as u128), self.digest[0], self.digest[1]]
+    }
+}
+
+impl Serializable for PublicInputs {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u32(self.num_elements as u32);
+        target.write(self.digest);
+    }
+}
+
+impl Deserializable for PublicInputs {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let num_elements = source.read_u32()? as usize;
+        if num_elements == 0 || num_elements % RATE_WIDTH != 0 {
+            return Err(DeserializationError::InvalidValue(format!(
+                "number of preimage elements must be a non-zero multiple of {RATE_WIDTH}, \
+                but was {num_elements}"
+            )));
+        }
+        let digest = source.read()?;
+        Ok(Self { num_elements, digest })
+    }
+}
+
+/// AIR for the hash preimage example.
+///
+/// The trace consists of the columns of a Rescue sponge (see [gadgets::evaluate_rescue_sponge()]
+/// for details) which absorbs the secret preimage, one block of 4 elements per 8-step cycle. The
+/// capacity of the sponge is asserted to be zero at the first step, and the first two elements
+/// of its state are asserted to be equal to the public digest at the last step of the cycle of
+/// the last preimage block. The trace is padded to a power of two by absorbing zero blocks,
+/// which are not constrained.
+pub struct PreimageAir {
+    context: AirContext<BaseElement>,
+    num_blocks: usize,
+    digest: [BaseElement; 2],
+}
+
+impl Air for PreimageAir {
+    type BaseField = BaseElement;
+    type PublicInputs = PublicInputs;
+    type GkrProof = ();
+    type GkrVerifier = ();
+
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    fn new(trace_info: TraceInfo, pub_inputs: PublicInputs, options: ProofOptions) -> Self {
+        assert_eq!(TRACE_WIDTH, trace_info.width());
+        assert_eq!(get_trace_length(pub_inputs.num_elements), trace_info.length());
+
+        let degrees = gadgets::rescue_sponge_degrees();
+        PreimageAir {
+            context: AirContext::new(trace_info, degrees, 4, options),
+            num_blocks: pub_inputs.num_elements / RATE_WIDTH,
+            digest: pub_inputs.digest,
+        }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+
+    fn evaluate_transition<E: FieldElement + From<Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        debug_assert_eq!(TRACE_WIDTH, current.len());
+        debug_assert_eq!(TRACE_WIDTH, next.len());
+
+        gadgets::evaluate_rescue_sponge(current, next, periodic_values, result);
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        let digest = Hash::new(self.digest[0], self.digest[1]);
+        gadgets::rescue_sponge_assertions(0, self.num_blocks, digest)
+    }
+
+    fn get_periodic_column_values(&self) -> Vec<Vec<Self::BaseField>> {
+        gadgets::rescue_sponge_periodic_columns()
+    }
+}
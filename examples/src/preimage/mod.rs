@@ -0,0 +1,172 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use core::marker::PhantomData;
+use std::time::Instant;
+
+use core_utils::Serializable;
+use rand_utils::rand_vector;
+use tracing::{field, info_span};
+use winterfell::{
+    crypto::{DefaultRandomCoin, ElementHasher, MerkleTree},
+    math::{fields::f128::BaseElement, FieldElement},
+    Proof, ProofOptions, Prover, Trace, VerifierError,
+};
+
+use crate::{
+    read_pub_inputs,
+    utils::rescue::{Rescue128, CYCLE_LENGTH, RATE_WIDTH, STATE_WIDTH},
+    Blake2s_256, Blake3_192, Blake3_256, Example, ExampleOptions, HashFunction, Sha3_256,
+};
+
+mod air;
+use air::{PreimageAir, PublicInputs};
+
+mod prover;
+use prover::PreimageProver;
+
+#[cfg(test)]
+mod tests;
+
+// CONSTANTS
+// ================================================================================================
+
+const TRACE_WIDTH: usize = STATE_WIDTH;
+
+// HASH PREIMAGE EXAMPLE
+// ================================================================================================
+
+pub fn get_example(
+    options: &ExampleOptions,
+    num_elements: usize,
+) -> Result<Box<dyn Example>, String> {
+    let (options, hash_fn) = options.to_proof_options(28, 8);
+
+    match hash_fn {
+        HashFunction::Blake3_192 => {
+            Ok(Box::new(PreimageExample::<Blake3_192>::new(num_elements, options)))
+        },
+        HashFunction::Blake3_256 => {
+            Ok(Box::new(PreimageExample::<Blake3_256>::new(num_elements, options)))
+        },
+        HashFunction::Blake2s_256 => {
+            Ok(Box::new(PreimageExample::<Blake2s_256>::new(num_elements, options)))
+        },
+        HashFunction::Sha3_256 => {
+            Ok(Box::new(PreimageExample::<Sha3_256>::new(num_elements, options)))
+        },
+        _ => Err("The specified hash function cannot be used with this example.".to_string()),
+    }
+}
+
+/// Proves knowledge of a secret preimage of a public Rescue hash digest.
+///
+/// The preimage is absorbed into a Rescue sponge built from the gadget in
+/// [crate::utils::gadgets], and the number of preimage elements is public, since it determines
+/// the step at which the sponge holds the digest (see [PreimageAir] for details).
+pub struct PreimageExample<H: ElementHasher> {
+    options: ProofOptions,
+    preimage: Vec<BaseElement>,
+    digest: [BaseElement; 2],
+    _hasher: PhantomData<H>,
+}
+
+impl<H: ElementHasher> PreimageExample<H> {
+    pub fn new(num_elements: usize, options: ProofOptions) -> Self {
+        assert!(
+            num_elements > 0 && num_elements % RATE_WIDTH == 0,
+            "number of preimage elements must be a non-zero multiple of {RATE_WIDTH}"
+        );
+
+        // generate a random preimage and compute its hash using external implementation of Rescue
+        let now = Instant::now();
+        let preimage = rand_vector::<BaseElement>(num_elements);
+        let digest = Rescue128::digest(&preimage).to_elements();
+        println!(
+            "Hashed a preimage of {} elements with Rescue in {} ms",
+            num_elements,
+            now.elapsed().as_millis()
+        );
+
+        PreimageExample {
+            options,
+            preimage,
+            digest,
+            _hasher: PhantomData,
+        }
+    }
+}
+
+// EXAMPLE IMPLEMENTATION
+// ================================================================================================
+
+impl<H: ElementHasher> Example for PreimageExample<H>
+where
+    H: ElementHasher<BaseField = BaseElement> + Sync,
+{
+    fn prove(&self) -> Proof {
+        println!(
+            "Generating proof for knowing a preimage of {} elements of a Rescue hash",
+            self.preimage.len()
+        );
+
+        // create a prover
+        let prover = PreimageProver::<H>::new(self.options.clone(), self.preimage.len());
+
+        // generate execution trace
+        let trace =
+            info_span!("generate_execution_trace", num_cols = TRACE_WIDTH, steps = field::Empty)
+                .in_scope(|| {
+                    let trace = prover.build_trace(&self.preimage);
+                    tracing::Span::current().record("steps", trace.length());
+                    trace
+                });
+
+        // generate the proof
+        prover.prove(trace).unwrap()
+    }
+
+    fn pub_inputs_to_bytes(&self) -> Vec<u8> {
+        let pub_inputs = PublicInputs {
+            num_elements: self.preimage.len(),
+            digest: self.digest,
+        };
+        pub_inputs.to_bytes()
+    }
+
+    fn verify_with_pub_inputs(&self, proof: Proof, pub_inputs: &[u8]) -> Result<(), VerifierError> {
+        let pub_inputs: PublicInputs = read_pub_inputs(pub_inputs)?;
+        let acceptable_options =
+            winterfell::AcceptableOptions::OptionSet(vec![proof.options().clone()]);
+        winterfell::verify::<PreimageAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+            proof,
+            pub_inputs,
+            &acceptable_options,
+        )
+    }
+
+    fn verify_with_wrong_inputs(&self, proof: Proof) -> Result<(), VerifierError> {
+        let pub_inputs = PublicInputs {
+            num_elements: self.preimage.len(),
+            digest: [self.digest[0], self.digest[1] + BaseElement::ONE],
+        };
+        let acceptable_options =
+            winterfell::AcceptableOptions::OptionSet(vec![proof.options().clone()]);
+        winterfell::verify::<PreimageAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+            proof,
+            pub_inputs,
+            &acceptable_options,
+        )
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the length of the execution trace required to absorb a preimage of `num_elements`
+/// elements; this is the smallest power of two which fits a full cycle for each preimage block.
+fn get_trace_length(num_elements: usize) -> usize {
+    (num_elements / RATE_WIDTH * CYCLE_LENGTH).next_power_of_two()
+}
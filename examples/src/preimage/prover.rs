@@ -0,0 +1,118 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use winterfell::{
+    crypto::MerkleTree, matrix::ColMatrix, AuxRandElements, CompositionPoly, CompositionPolyTrace,
+    ConstraintCompositionCoefficients, DefaultConstraintCommitment, DefaultConstraintEvaluator,
+    DefaultTraceLde, PartitionOptions, StarkDomain, TraceInfo, TracePolyTable, TraceTable,
+};
+
+use super::{
+    get_trace_length, BaseElement, DefaultRandomCoin, ElementHasher, FieldElement, PhantomData,
+    PreimageAir, ProofOptions, Prover, PublicInputs, CYCLE_LENGTH, RATE_WIDTH,
+};
+use crate::utils::gadgets;
+
+// HASH PREIMAGE PROVER
+// ================================================================================================
+
+pub struct PreimageProver<H: ElementHasher> {
+    options: ProofOptions,
+    num_elements: usize,
+    _hasher: PhantomData<H>,
+}
+
+impl<H: ElementHasher> PreimageProver<H> {
+    /// Returns a new prover for preimages of `num_elements` elements.
+    pub fn new(options: ProofOptions, num_elements: usize) -> Self {
+        Self {
+            options,
+            num_elements,
+            _hasher: PhantomData,
+        }
+    }
+
+    /// Builds an execution trace which absorbs the specified `preimage` into a Rescue sponge (see
+    /// [PreimageAir] for the layout of the trace).
+    ///
+    /// # Panics
+    /// Panics if the length of the preimage is not equal to the number of elements this prover
+    /// was created for.
+    pub fn build_trace(&self, preimage: &[BaseElement]) -> TraceTable<BaseElement> {
+        assert_eq!(
+            self.num_elements,
+            preimage.len(),
+            "expected a preimage of {} elements, but was {}",
+            self.num_elements,
+            preimage.len()
+        );
+        let trace_length = get_trace_length(preimage.len());
+        TraceTable::init(gadgets::build_rescue_sponge_columns(preimage, trace_length))
+    }
+}
+
+impl<H: ElementHasher> Prover for PreimageProver<H>
+where
+    H: ElementHasher<BaseField = BaseElement> + Sync,
+{
+    type BaseField = BaseElement;
+    type Air = PreimageAir;
+    type Trace = TraceTable<BaseElement>;
+    type HashFn = H;
+    type VC = MerkleTree<H>;
+    type RandomCoin = DefaultRandomCoin<Self::HashFn>;
+    type TraceLde<E: FieldElement<BaseField = Self::BaseField>> =
+        DefaultTraceLde<E, Self::HashFn, Self::VC>;
+    type ConstraintCommitment<E: FieldElement<BaseField = Self::BaseField>> =
+        DefaultConstraintCommitment<E, H, Self::VC>;
+    type ConstraintEvaluator<'a, E: FieldElement<BaseField = Self::BaseField>> =
+        DefaultConstraintEvaluator<'a, Self::Air, E>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> PublicInputs {
+        let digest_step = self.num_elements / RATE_WIDTH * CYCLE_LENGTH - 1;
+        PublicInputs {
+            num_elements: self.num_elements,
+            digest: [trace.get(0, digest_step), trace.get(1, digest_step)],
+        }
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+
+    fn new_trace_lde<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        trace_info: &TraceInfo,
+        main_trace: &ColMatrix<Self::BaseField>,
+        domain: &StarkDomain<Self::BaseField>,
+        partition_option: PartitionOptions,
+    ) -> (Self::TraceLde<E>, TracePolyTable<E>) {
+        DefaultTraceLde::new(trace_info, main_trace, domain, partition_option)
+    }
+
+    fn new_evaluator<'a, E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        air: &'a Self::Air,
+        aux_rand_elements: Option<AuxRandElements<E>>,
+        composition_coefficients: ConstraintCompositionCoefficients<E>,
+    ) -> Self::ConstraintEvaluator<'a, E> {
+        DefaultConstraintEvaluator::new(air, aux_rand_elements, composition_coefficients)
+    }
+
+    fn build_constraint_commitment<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        composition_poly_trace: CompositionPolyTrace<E>,
+        num_constraint_composition_columns: usize,
+        domain: &StarkDomain<Self::BaseField>,
+        partition_options: PartitionOptions,
+    ) -> (Self::ConstraintCommitment<E>, CompositionPoly<E>) {
+        DefaultConstraintCommitment::new(
+            composition_poly_trace,
+            num_constraint_composition_columns,
+            domain,
+            partition_options,
+        )
+    }
+}
@@ -0,0 +1,59 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use winterfell::{Air, FieldExtension, ProofOptions, Prover, ProverError, Trace};
+
+use super::{BaseElement, Blake3_256, FieldElement, PreimageAir, PreimageProver};
+
+#[test]
+fn preimage_test_basic_proof_verification() {
+    let preimage = Box::new(super::PreimageExample::<Blake3_256>::new(4, build_options(false)));
+    crate::tests::test_basic_proof_verification(preimage);
+}
+
+#[test]
+fn preimage_test_basic_proof_verification_padded() {
+    // the trace is padded with zero blocks after the digest is computed
+    let preimage = Box::new(super::PreimageExample::<Blake3_256>::new(12, build_options(false)));
+    crate::tests::test_basic_proof_verification(preimage);
+}
+
+#[test]
+fn preimage_test_basic_proof_verification_extension() {
+    let preimage = Box::new(super::PreimageExample::<Blake3_256>::new(16, build_options(true)));
+    crate::tests::test_basic_proof_verification(preimage);
+}
+
+#[test]
+fn preimage_test_basic_proof_verification_fail() {
+    let preimage = Box::new(super::PreimageExample::<Blake3_256>::new(12, build_options(false)));
+    crate::tests::test_basic_proof_verification_fail(preimage);
+}
+
+#[test]
+fn preimage_test_tampered_capacity() {
+    let prover = PreimageProver::<Blake3_256>::new(build_options(false), 8);
+    let preimage = (1..=8_u32).map(BaseElement::from).collect::<Vec<_>>();
+    let mut trace = prover.build_trace(&preimage);
+
+    // changing the capacity at the first step violates an assertion; the check is performed
+    // directly against the trace so that it does not depend on the build profile
+    trace.set(4, 0, BaseElement::ONE);
+    let air =
+        PreimageAir::new(trace.info().clone(), prover.get_pub_inputs(&trace), build_options(false));
+    assert_eq!(
+        Err(ProverError::UnsatisfiedAssertion { column: 4, step: 0 }),
+        trace.check_assertions(&air)
+    );
+}
+
+fn build_options(use_extension_field: bool) -> ProofOptions {
+    let extension = if use_extension_field {
+        FieldExtension::Quadratic
+    } else {
+        FieldExtension::None
+    };
+    ProofOptions::new(28, 8, 0, extension, 4, 31)
+}
@@ -0,0 +1,172 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use core_utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
+use winterfell::{
+    math::ToElements, Air, AirContext, Assertion, AuxRandElements, EvaluationFrame, TraceInfo,
+    TransitionConstraintDegree,
+};
+
+use super::{
+    BaseElement, ExtensionOf, FieldElement, ProofOptions, AUX_TRACE_WIDTH, LIMB_BITS, TABLE_SIZE,
+    TRACE_WIDTH,
+};
+use crate::utils::{are_equal, is_binary};
+
+// RANGE CHECK AIR
+// ================================================================================================
+
+pub struct PublicInputs {
+    pub sum: BaseElement,
+}
+
+impl ToElements<BaseElement> for PublicInputs {
+    fn to_elements(&self) -> Vec<BaseElement> {
+        vec![self.sum]
+    }
+}
+
+impl Serializable for PublicInputs {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write(self.sum);
+    }
+}
+
+impl Deserializable for PublicInputs {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let sum = source.read()?;
+        Ok(Self { sum })
+    }
+}
+
+/// AIR for the range check example.
+///
+/// The main trace segment consists of the following columns:
+/// - `v` holds the values being range-checked, and `lo` and `hi` hold their 8-bit limbs, such
+///   that v = lo + 2^8 * hi.
+/// - `acc` holds the running sum of the values, such that acc(0) = 0 and acc(i + 1) = acc(i) +
+///   v(i). The last value of this column is asserted to be equal to the public sum.
+/// - `t` holds the lookup table. The table starts at 0, increases by either 0 or 1 at every step,
+///   and ends at 255; thus, it contains every value in [0, 2^8) and nothing else.
+/// - `m` holds the multiplicities of the table entries, i.e., the number of times the value in
+///   column `t` is looked up by the limbs.
+///
+/// The auxiliary segment consists of a single running sum column `s` which is built using a random
+/// element α (a log-derivative lookup argument) as s(0) = 0 and:
+///
+///   s(i + 1) = s(i) + 1 / (α - lo(i)) + 1 / (α - hi(i)) - m(i) / (α - t(i))
+///
+/// Since the transition constraint for `s` relates each row to the next one, the running sum does
+/// not cover the last row of the trace. Thus, the last row is used only as padding (it holds a
+/// zero value and does not contribute to `acc`). Asserting that s(n - 1) = 0 then implies that all
+/// limbs are contained in the table, and thus, every value is in [0, 2^16).
+pub struct RangeCheckAir {
+    context: AirContext<BaseElement>,
+    sum: BaseElement,
+}
+
+impl Air for RangeCheckAir {
+    type BaseField = BaseElement;
+    type PublicInputs = PublicInputs;
+    type GkrProof = ();
+    type GkrVerifier = ();
+
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    fn new(trace_info: TraceInfo, pub_inputs: PublicInputs, options: ProofOptions) -> Self {
+        assert_eq!(TRACE_WIDTH, trace_info.main_trace_width());
+        assert_eq!(AUX_TRACE_WIDTH, trace_info.aux_segment_width());
+
+        let main_degrees = vec![
+            TransitionConstraintDegree::new(1),
+            TransitionConstraintDegree::new(1),
+            TransitionConstraintDegree::new(2),
+        ];
+        let aux_degrees = vec![TransitionConstraintDegree::new(4)];
+        RangeCheckAir {
+            context: AirContext::new_multi_segment(
+                trace_info,
+                main_degrees,
+                aux_degrees,
+                4,
+                2,
+                None,
+                options,
+            ),
+            sum: pub_inputs.sum,
+        }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+
+    fn evaluate_transition<E: FieldElement + From<Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current();
+        let next = frame.next();
+        debug_assert_eq!(TRACE_WIDTH, current.len());
+        debug_assert_eq!(TRACE_WIDTH, next.len());
+
+        // v(i) = lo(i) + 2^8 * hi(i)
+        let limb_base = E::from(BaseElement::new(1 << LIMB_BITS));
+        result[0] = are_equal(current[0], current[1] + limb_base * current[2]);
+
+        // acc(i + 1) = acc(i) + v(i)
+        result[1] = are_equal(next[3], current[3] + current[0]);
+
+        // t(i + 1) - t(i) must be either 0 or 1
+        result[2] = is_binary(next[4] - current[4]);
+    }
+
+    fn evaluate_aux_transition<F, E>(
+        &self,
+        main_frame: &EvaluationFrame<F>,
+        aux_frame: &EvaluationFrame<E>,
+        _periodic_values: &[F],
+        aux_rand_elements: &AuxRandElements<E>,
+        result: &mut [E],
+    ) where
+        F: FieldElement<BaseField = Self::BaseField>,
+        E: FieldElement<BaseField = Self::BaseField> + ExtensionOf<F>,
+    {
+        let main_current = main_frame.current();
+        let aux_current = aux_frame.current();
+        let aux_next = aux_frame.next();
+
+        let alpha = aux_rand_elements.rand_elements()[0];
+        let lo = alpha - main_current[1].into();
+        let hi = alpha - main_current[2].into();
+        let table = alpha - main_current[4].into();
+        let multiplicity: E = main_current[5].into();
+
+        // (s(i + 1) - s(i)) * (α - lo) * (α - hi) * (α - t) =
+        //     (α - hi) * (α - t) + (α - lo) * (α - t) - m * (α - lo) * (α - hi)
+        result[0] = are_equal(
+            (aux_next[0] - aux_current[0]) * lo * hi * table,
+            hi * table + lo * table - multiplicity * lo * hi,
+        );
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        vec![
+            Assertion::single(3, 0, BaseElement::ZERO),
+            Assertion::last_step(3, self.sum),
+            Assertion::single(4, 0, BaseElement::ZERO),
+            Assertion::last_step(4, BaseElement::new(TABLE_SIZE as u64 - 1)),
+        ]
+    }
+
+    fn get_aux_assertions<E>(&self, _aux_rand_elements: &AuxRandElements<E>) -> Vec<Assertion<E>>
+    where
+        E: FieldElement<BaseField = Self::BaseField>,
+    {
+        vec![Assertion::single(0, 0, E::ZERO), Assertion::last_step(0, E::ZERO)]
+    }
+}
@@ -0,0 +1,178 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use core::marker::PhantomData;
+use std::time::Instant;
+
+use core_utils::Serializable;
+use rand_utils::rand_vector;
+use tracing::{field, info_span};
+use winterfell::{
+    crypto::{DefaultRandomCoin, ElementHasher, MerkleTree},
+    math::{fields::f64::BaseElement, ExtensionOf, FieldElement},
+    Proof, ProofOptions, Prover, Trace, VerifierError,
+};
+
+use crate::{read_pub_inputs, Example, ExampleOptions, HashFunction};
+
+mod air;
+use air::{PublicInputs, RangeCheckAir};
+
+mod prover;
+use prover::RangeCheckProver;
+
+#[cfg(test)]
+mod tests;
+
+// CONSTANTS AND TYPES
+// ================================================================================================
+
+const TRACE_WIDTH: usize = 6;
+const AUX_TRACE_WIDTH: usize = 1;
+
+/// Number of bits in a limb; each value is decomposed into two limbs.
+const LIMB_BITS: u32 = 8;
+
+/// Number of entries in the lookup table; the table holds values 0, 1, ..., TABLE_SIZE - 1.
+const TABLE_SIZE: usize = 1 << LIMB_BITS;
+
+/// Minimum length of the execution trace; the table (and the row holding the multiplicity of its
+/// last entry) must fit into all rows but the last one.
+const MIN_TRACE_LENGTH: usize = 2 * TABLE_SIZE;
+
+type Blake3_192 = winterfell::crypto::hashers::Blake3_192<BaseElement>;
+type Blake3_256 = winterfell::crypto::hashers::Blake3_256<BaseElement>;
+#[allow(non_camel_case_types)]
+type Blake2s_256 = winterfell::crypto::hashers::Blake2s_256<BaseElement>;
+type Sha3_256 = winterfell::crypto::hashers::Sha3_256<BaseElement>;
+type Rp64_256 = winterfell::crypto::hashers::Rp64_256;
+type RpJive64_256 = winterfell::crypto::hashers::RpJive64_256;
+
+// RANGE CHECK EXAMPLE
+// ================================================================================================
+
+pub fn get_example(
+    options: &ExampleOptions,
+    num_values: usize,
+) -> Result<Box<dyn Example>, String> {
+    let (options, hash_fn) = options.to_proof_options(28, 8);
+
+    match hash_fn {
+        HashFunction::Blake3_192 => {
+            Ok(Box::new(RangeCheckExample::<Blake3_192>::new(num_values, options)))
+        },
+        HashFunction::Blake3_256 => {
+            Ok(Box::new(RangeCheckExample::<Blake3_256>::new(num_values, options)))
+        },
+        HashFunction::Blake2s_256 => {
+            Ok(Box::new(RangeCheckExample::<Blake2s_256>::new(num_values, options)))
+        },
+        HashFunction::Sha3_256 => {
+            Ok(Box::new(RangeCheckExample::<Sha3_256>::new(num_values, options)))
+        },
+        HashFunction::Rp64_256 => {
+            Ok(Box::new(RangeCheckExample::<Rp64_256>::new(num_values, options)))
+        },
+        HashFunction::RpJive64_256 => {
+            Ok(Box::new(RangeCheckExample::<RpJive64_256>::new(num_values, options)))
+        },
+    }
+}
+
+/// Proves knowledge of a set of secret values, each in the range [0, 2^16), which add up to a
+/// public sum.
+///
+/// The range check is performed using a lookup argument: every value is decomposed into two 8-bit
+/// limbs, and each limb is looked up in a table column holding values 0, 1, ..., 255. The lookup
+/// argument relies on a running sum column built in the auxiliary trace segment from a random
+/// element drawn after the main segment has been committed to (see [RangeCheckAir] for details).
+/// Since a random element drawn from a 64-bit field does not provide enough soundness for the
+/// lookup argument, this example is meant to be run with a quadratic (or higher) field extension.
+pub struct RangeCheckExample<H: ElementHasher> {
+    options: ProofOptions,
+    values: Vec<u16>,
+    sum: BaseElement,
+    _hasher: PhantomData<H>,
+}
+
+impl<H: ElementHasher> RangeCheckExample<H> {
+    pub fn new(num_values: usize, options: ProofOptions) -> Self {
+        assert!(num_values > 0, "number of values must be greater than zero");
+
+        // generate the values to be range-checked and compute their sum
+        let now = Instant::now();
+        let values = rand_vector::<u16>(num_values);
+        let sum = values
+            .iter()
+            .map(|&v| BaseElement::from(v))
+            .fold(BaseElement::ZERO, |a, b| a + b);
+        println!(
+            "Generated {} random 16-bit values in {} ms",
+            num_values,
+            now.elapsed().as_millis()
+        );
+
+        RangeCheckExample {
+            options,
+            values,
+            sum,
+            _hasher: PhantomData,
+        }
+    }
+}
+
+// EXAMPLE IMPLEMENTATION
+// ================================================================================================
+
+impl<H: ElementHasher> Example for RangeCheckExample<H>
+where
+    H: ElementHasher<BaseField = BaseElement> + Sync,
+{
+    fn prove(&self) -> Proof {
+        println!("Generating proof for range-checking {} values", self.values.len());
+
+        // create a prover
+        let prover = RangeCheckProver::<H>::new(self.options.clone());
+
+        // generate execution trace
+        let trace =
+            info_span!("generate_execution_trace", num_cols = TRACE_WIDTH, steps = field::Empty)
+                .in_scope(|| {
+                    let trace = prover.build_trace(&self.values);
+                    tracing::Span::current().record("steps", trace.length());
+                    trace
+                });
+
+        // generate the proof
+        prover.prove(trace).unwrap()
+    }
+
+    fn pub_inputs_to_bytes(&self) -> Vec<u8> {
+        let pub_inputs = PublicInputs { sum: self.sum };
+        pub_inputs.to_bytes()
+    }
+
+    fn verify_with_pub_inputs(&self, proof: Proof, pub_inputs: &[u8]) -> Result<(), VerifierError> {
+        let pub_inputs: PublicInputs = read_pub_inputs(pub_inputs)?;
+        let acceptable_options =
+            winterfell::AcceptableOptions::OptionSet(vec![proof.options().clone()]);
+        winterfell::verify::<RangeCheckAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+            proof,
+            pub_inputs,
+            &acceptable_options,
+        )
+    }
+
+    fn verify_with_wrong_inputs(&self, proof: Proof) -> Result<(), VerifierError> {
+        let pub_inputs = PublicInputs { sum: self.sum + BaseElement::ONE };
+        let acceptable_options =
+            winterfell::AcceptableOptions::OptionSet(vec![proof.options().clone()]);
+        winterfell::verify::<RangeCheckAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+            proof,
+            pub_inputs,
+            &acceptable_options,
+        )
+    }
+}
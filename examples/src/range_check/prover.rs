@@ -0,0 +1,178 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use winterfell::{
+    crypto::MerkleTree, math::batch_inversion, matrix::ColMatrix, AuxRandElements, CompositionPoly,
+    CompositionPolyTrace, ConstraintCompositionCoefficients, DefaultConstraintCommitment,
+    DefaultConstraintEvaluator, DefaultTraceLde, PartitionOptions, StarkDomain, Trace, TraceInfo,
+    TracePolyTable, TraceTable,
+};
+
+use super::{
+    BaseElement, DefaultRandomCoin, ElementHasher, FieldElement, PhantomData, ProofOptions, Prover,
+    PublicInputs, RangeCheckAir, AUX_TRACE_WIDTH, LIMB_BITS, MIN_TRACE_LENGTH, TABLE_SIZE,
+};
+
+// RANGE CHECK PROVER
+// ================================================================================================
+
+pub struct RangeCheckProver<H: ElementHasher> {
+    options: ProofOptions,
+    _hasher: PhantomData<H>,
+}
+
+impl<H: ElementHasher> RangeCheckProver<H> {
+    pub fn new(options: ProofOptions) -> Self {
+        Self { options, _hasher: PhantomData }
+    }
+
+    /// Builds the main segment of an execution trace for range-checking the specified values.
+    ///
+    /// The length of the trace is the smallest power of two which fits all values and the lookup
+    /// table into all rows but the last one (see [RangeCheckAir] for the layout of the trace). The
+    /// remaining rows are padded with zero values. The returned trace declares an auxiliary
+    /// segment which is built by [Prover::build_aux_trace()] over the extension field specified
+    /// by proof options.
+    pub fn build_trace(&self, values: &[u16]) -> TraceTable<BaseElement> {
+        let trace_length = (values.len() + 1).next_power_of_two().max(MIN_TRACE_LENGTH);
+        let limb_mask = (1 << LIMB_BITS) - 1;
+
+        let mut value_column = vec![BaseElement::ZERO; trace_length];
+        let mut lo_column = vec![BaseElement::ZERO; trace_length];
+        let mut hi_column = vec![BaseElement::ZERO; trace_length];
+        let mut multiplicities = vec![0_u64; TABLE_SIZE];
+
+        // padding rows (except for the last one) look up limb value 0 twice
+        multiplicities[0] = 2 * (trace_length - 1 - values.len()) as u64;
+        for (i, &value) in values.iter().enumerate() {
+            let (lo, hi) = (value & limb_mask, value >> LIMB_BITS);
+            value_column[i] = BaseElement::from(value);
+            lo_column[i] = BaseElement::from(lo);
+            hi_column[i] = BaseElement::from(hi);
+            multiplicities[lo as usize] += 1;
+            multiplicities[hi as usize] += 1;
+        }
+
+        let mut acc_column = vec![BaseElement::ZERO; trace_length];
+        for i in 0..trace_length - 1 {
+            acc_column[i + 1] = acc_column[i] + value_column[i];
+        }
+
+        // the table holds 0, 1, ..., 255 followed by repetitions of 255; the multiplicity of each
+        // entry is recorded in the row where the entry first appears
+        let mut table_column = vec![BaseElement::new(TABLE_SIZE as u64 - 1); trace_length];
+        let mut multiplicity_column = vec![BaseElement::ZERO; trace_length];
+        for (i, &multiplicity) in multiplicities.iter().enumerate() {
+            table_column[i] = BaseElement::new(i as u64);
+            multiplicity_column[i] = BaseElement::new(multiplicity);
+        }
+
+        TraceTable::init(vec![
+            value_column,
+            lo_column,
+            hi_column,
+            acc_column,
+            table_column,
+            multiplicity_column,
+        ])
+        .with_aux_segment(AUX_TRACE_WIDTH, 1)
+    }
+}
+
+impl<H: ElementHasher> Prover for RangeCheckProver<H>
+where
+    H: ElementHasher<BaseField = BaseElement> + Sync,
+{
+    type BaseField = BaseElement;
+    type Air = RangeCheckAir;
+    type Trace = TraceTable<BaseElement>;
+    type HashFn = H;
+    type VC = MerkleTree<H>;
+    type RandomCoin = DefaultRandomCoin<Self::HashFn>;
+    type TraceLde<E: FieldElement<BaseField = Self::BaseField>> =
+        DefaultTraceLde<E, Self::HashFn, Self::VC>;
+    type ConstraintCommitment<E: FieldElement<BaseField = Self::BaseField>> =
+        DefaultConstraintCommitment<E, H, Self::VC>;
+    type ConstraintEvaluator<'a, E: FieldElement<BaseField = Self::BaseField>> =
+        DefaultConstraintEvaluator<'a, Self::Air, E>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> PublicInputs {
+        let last_step = trace.length() - 1;
+        PublicInputs { sum: trace.get(3, last_step) }
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+
+    fn new_trace_lde<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        trace_info: &TraceInfo,
+        main_trace: &ColMatrix<Self::BaseField>,
+        domain: &StarkDomain<Self::BaseField>,
+        partition_option: PartitionOptions,
+    ) -> (Self::TraceLde<E>, TracePolyTable<E>) {
+        DefaultTraceLde::new(trace_info, main_trace, domain, partition_option)
+    }
+
+    fn new_evaluator<'a, E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        air: &'a Self::Air,
+        aux_rand_elements: Option<AuxRandElements<E>>,
+        composition_coefficients: ConstraintCompositionCoefficients<E>,
+    ) -> Self::ConstraintEvaluator<'a, E> {
+        DefaultConstraintEvaluator::new(air, aux_rand_elements, composition_coefficients)
+    }
+
+    fn build_constraint_commitment<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        composition_poly_trace: CompositionPolyTrace<E>,
+        num_constraint_composition_columns: usize,
+        domain: &StarkDomain<Self::BaseField>,
+        partition_options: PartitionOptions,
+    ) -> (Self::ConstraintCommitment<E>, CompositionPoly<E>) {
+        DefaultConstraintCommitment::new(
+            composition_poly_trace,
+            num_constraint_composition_columns,
+            domain,
+            partition_options,
+        )
+    }
+
+    fn build_aux_trace<E>(
+        &self,
+        trace: &Self::Trace,
+        aux_rand_elements: &AuxRandElements<E>,
+    ) -> ColMatrix<E>
+    where
+        E: FieldElement<BaseField = Self::BaseField>,
+    {
+        let main_trace = trace.main_segment();
+        let alpha = aux_rand_elements.rand_elements()[0];
+        let num_rows = main_trace.num_rows();
+
+        let lo_column = main_trace.get_column(1);
+        let hi_column = main_trace.get_column(2);
+        let table_column = main_trace.get_column(4);
+        let multiplicities = main_trace.get_column(5);
+
+        // the denominators (α - lo(i)), (α - hi(i)) and (α - t(i)) are inverted in a single batch
+        let denominators = [lo_column, hi_column, table_column]
+            .iter()
+            .flat_map(|column| column.iter().map(|&value| alpha - value.into()))
+            .collect::<Vec<_>>();
+        let inv_denominators = batch_inversion(&denominators);
+        let (inv_lo, rest) = inv_denominators.split_at(num_rows);
+        let (inv_hi, inv_table) = rest.split_at(num_rows);
+
+        let mut running_sum = vec![E::ZERO; num_rows];
+        for i in 0..num_rows - 1 {
+            running_sum[i + 1] =
+                running_sum[i] + inv_lo[i] + inv_hi[i] - inv_table[i].mul_base(multiplicities[i]);
+        }
+
+        ColMatrix::new(vec![running_sum])
+    }
+}
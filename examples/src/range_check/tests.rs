@@ -0,0 +1,64 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use winterfell::{AcceptableOptions, FieldExtension, ProofOptions, ProverError, Trace};
+
+use super::{
+    BaseElement, Blake3_256, DefaultRandomCoin, FieldElement, MerkleTree, Prover, RangeCheckAir,
+    RangeCheckProver, TRACE_WIDTH,
+};
+
+#[test]
+fn range_check_test_basic_proof_verification() {
+    let range_check = Box::new(super::RangeCheckExample::<Blake3_256>::new(100, build_options()));
+    crate::tests::test_basic_proof_verification(range_check);
+}
+
+#[test]
+fn range_check_test_basic_proof_verification_fail() {
+    let range_check = Box::new(super::RangeCheckExample::<Blake3_256>::new(100, build_options()));
+    crate::tests::test_basic_proof_verification_fail(range_check);
+}
+
+#[test]
+fn range_check_test_out_of_range_value() {
+    let prover = RangeCheckProver::<Blake3_256>::new(build_options());
+    let mut trace = prover.build_trace(&[1, 2, 3]);
+    let last_step = trace.length() - 1;
+
+    // replace the first value 1 = 1 + 2^8 * 0 with 2^16 + 1 = 1 + 2^8 * 2^8 and update the running
+    // sum of values accordingly; all main trace constraints are still satisfied, but the high
+    // limb is not in the table, and so, the lookup argument cannot be satisfied
+    let delta = BaseElement::new(1 << 16);
+    trace.set(0, 0, BaseElement::ONE + delta);
+    trace.set(2, 0, BaseElement::new(1 << 8));
+    for step in 1..=last_step {
+        trace.set(3, step, trace.get(3, step) + delta);
+    }
+
+    let pub_inputs = prover.get_pub_inputs(&trace);
+    match prover.prove(trace) {
+        Err(err) => {
+            assert_eq!(
+                ProverError::UnsatisfiedAssertion { column: TRACE_WIDTH, step: last_step },
+                err
+            )
+        },
+        Ok(proof) => {
+            let acceptable_options = AcceptableOptions::OptionSet(vec![proof.options().clone()]);
+            let result = winterfell::verify::<
+                RangeCheckAir,
+                Blake3_256,
+                DefaultRandomCoin<Blake3_256>,
+                MerkleTree<Blake3_256>,
+            >(proof, pub_inputs, &acceptable_options);
+            assert!(result.is_err());
+        },
+    }
+}
+
+fn build_options() -> ProofOptions {
+    ProofOptions::new(28, 8, 0, FieldExtension::Quadratic, 4, 7)
+}
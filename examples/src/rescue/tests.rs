@@ -3,9 +3,9 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use winterfell::{FieldExtension, ProofOptions};
+use winterfell::{Air, FieldExtension, ProofOptions, Prover, ProverError, ProvingKey, Trace};
 
-use super::Blake3_256;
+use super::{BaseElement, Blake3_256, RescueAir, RescueProver};
 
 #[test]
 fn rescue_test_basic_proof_verification() {
@@ -25,6 +25,28 @@ fn rescue_test_basic_proof_verification_fail() {
     crate::tests::test_basic_proof_verification_fail(rescue_eg);
 }
 
+#[test]
+fn rescue_test_prove_with_key() {
+    let prover = RescueProver::<Blake3_256>::new(build_options(false));
+    let seed = [BaseElement::new(42), BaseElement::new(43)];
+    let trace = prover.build_trace(seed, 16);
+    let pub_inputs = prover.get_pub_inputs(&trace);
+    let air = RescueAir::new(trace.info().clone(), pub_inputs, prover.options().clone());
+    let key = ProvingKey::new(&air);
+
+    // proofs generated with a key are the same as the ones generated without it, and the same
+    // key can be reused for traces against different public inputs
+    for seed in [seed, [BaseElement::new(1), BaseElement::new(2)]] {
+        let trace = prover.build_trace(seed, 16);
+        let expected = prover.prove(trace.clone()).unwrap();
+        assert_eq!(expected, prover.prove_with_key(trace, &key).unwrap());
+    }
+
+    // a key cannot be used for a trace of different length
+    let trace = prover.build_trace(seed, 32);
+    assert_eq!(Err(ProverError::MismatchedProvingKey), prover.prove_with_key(trace, &key));
+}
+
 fn build_options(use_extension_field: bool) -> ProofOptions {
     let extension = if use_extension_field {
         FieldExtension::Quadratic
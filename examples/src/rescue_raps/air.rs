@@ -3,7 +3,10 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use core_utils::flatten_slice_elements;
+use core_utils::{
+    flatten_slice_elements, ByteReader, ByteWriter, Deserializable, DeserializationError,
+    Serializable,
+};
 use winterfell::{
     math::ToElements, Air, AirContext, Assertion, AuxRandElements, EvaluationFrame, TraceInfo,
     TransitionConstraintDegree,
@@ -51,6 +54,19 @@ impl ToElements<BaseElement> for PublicInputs {
     }
 }
 
+impl Serializable for PublicInputs {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write(self.result);
+    }
+}
+
+impl Deserializable for PublicInputs {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let result = source.read()?;
+        Ok(Self { result })
+    }
+}
+
 pub struct RescueRapsAir {
     context: AirContext<BaseElement>,
     result: [[BaseElement; 2]; 2],
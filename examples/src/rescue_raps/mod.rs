@@ -6,6 +6,7 @@
 use core::marker::PhantomData;
 use std::time::Instant;
 
+use core_utils::Serializable;
 use rand_utils::rand_array;
 use tracing::{field, info_span};
 use winterfell::{
@@ -14,7 +15,10 @@ use winterfell::{
     Proof, ProofOptions, Prover, Trace, VerifierError,
 };
 
-use crate::{Blake3_192, Blake3_256, Example, ExampleOptions, HashFunction, Sha3_256};
+use crate::{
+    read_pub_inputs, Blake2s_256, Blake3_192, Blake3_256, Example, ExampleOptions, HashFunction,
+    Sha3_256,
+};
 
 mod custom_trace_table;
 pub use custom_trace_table::RapTraceTable;
@@ -53,6 +57,9 @@ pub fn get_example(
         HashFunction::Blake3_256 => {
             Ok(Box::new(RescueRapsExample::<Blake3_256>::new(chain_length, options)))
         },
+        HashFunction::Blake2s_256 => {
+            Ok(Box::new(RescueRapsExample::<Blake2s_256>::new(chain_length, options)))
+        },
         HashFunction::Sha3_256 => {
             Ok(Box::new(RescueRapsExample::<Sha3_256>::new(chain_length, options)))
         },
@@ -129,11 +136,15 @@ where
         prover.prove(trace).unwrap()
     }
 
-    fn verify(&self, proof: Proof) -> Result<(), VerifierError> {
+    fn pub_inputs_to_bytes(&self) -> Vec<u8> {
         let pub_inputs = PublicInputs { result: self.result };
+        pub_inputs.to_bytes()
+    }
+
+    fn verify_with_pub_inputs(&self, proof: Proof, pub_inputs: &[u8]) -> Result<(), VerifierError> {
+        let pub_inputs: PublicInputs = read_pub_inputs(pub_inputs)?;
         let acceptable_options =
             winterfell::AcceptableOptions::OptionSet(vec![proof.options().clone()]);
-
         winterfell::verify::<RescueRapsAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
             proof,
             pub_inputs,
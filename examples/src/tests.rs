@@ -3,7 +3,9 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use crate::Example;
+use std::path::PathBuf;
+
+use crate::{read_proof_file, write_proof_file, Example, ExampleOptions, ExampleType};
 
 pub fn test_basic_proof_verification(e: Box<dyn Example>) {
     let proof = e.prove();
@@ -15,3 +17,56 @@ pub fn test_basic_proof_verification_fail(e: Box<dyn Example>) {
     let verified = e.verify_with_wrong_inputs(proof);
     assert!(verified.is_err());
 }
+
+pub fn test_proof_file_round_trip(e: Box<dyn Example>, file_name: &str) {
+    let proof = e.prove();
+    let path = std::env::temp_dir().join(file_name);
+    write_proof_file(&path, &proof, &e.pub_inputs_to_bytes()).unwrap();
+
+    let (parsed_proof, pub_inputs) = read_proof_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(proof, parsed_proof);
+    assert!(e.verify_with_pub_inputs(parsed_proof, &pub_inputs).is_ok());
+}
+
+#[test]
+fn example_options_from_file() {
+    let path = std::env::temp_dir().join("winterfell_example_options.json");
+
+    // a config file can list many option sets; unspecified options take default values
+    let config = r#"[
+        { "example": { "fib": { "sequence_length": 1024 } }, "queries": 28, "blowup": 8 },
+        { "example": { "rescue": { "chain_length": 16 } }, "hash_fn": "blake3_192",
+          "field_extension": 2, "out": "rescue.proof" }
+    ]"#;
+    std::fs::write(&path, config).unwrap();
+    let options = ExampleOptions::from_file(&path).unwrap();
+    assert_eq!(2, options.len());
+
+    assert!(matches!(options[0].example, ExampleType::Fib { sequence_length: 1024 }));
+    let (proof_options, _) = options[0].to_proof_options(42, 4);
+    assert_eq!(28, proof_options.num_queries());
+    assert_eq!(8, proof_options.blowup_factor());
+    assert_eq!(16, proof_options.grinding_factor());
+    assert_eq!(8, proof_options.to_fri_options().folding_factor());
+
+    assert!(matches!(options[1].example, ExampleType::Rescue { chain_length: 16 }));
+    let (proof_options, _) = options[1].to_proof_options(42, 4);
+    assert_eq!(42, proof_options.num_queries());
+    assert_eq!(4, proof_options.blowup_factor());
+    assert_eq!(Some(PathBuf::from("rescue.proof")), options[1].out);
+
+    // a config file can also contain a single option set
+    std::fs::write(&path, r#"{ "example": { "vdf": { "num_steps": 64 } } }"#).unwrap();
+    let options = ExampleOptions::from_file(&path).unwrap();
+    assert_eq!(1, options.len());
+    assert!(matches!(options[0].example, ExampleType::Vdf { num_steps: 64 }));
+
+    // unknown options and batch runs are rejected
+    std::fs::write(&path, r#"{ "example": { "vdf": { "num_steps": 64 } }, "query": 2 }"#).unwrap();
+    assert!(ExampleOptions::from_file(&path).is_err());
+    std::fs::write(&path, r#"{ "example": { "batch": { "config": "other.json" } } }"#).unwrap();
+    assert!(ExampleOptions::from_file(&path).is_err());
+
+    std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,511 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! Reusable building blocks for execution traces and AIRs.
+//!
+//! Each gadget consists of a function which builds trace columns for the gadget from a column of
+//! values, functions which describe the constraints and assertions the gadget requires, and
+//! functions which evaluate these constraints. The gadgets do not assume any particular layout
+//! of the execution trace: column indexes and values are supplied by the caller, and thus,
+//! gadgets can be combined with each other and with computation-specific constraints in a single
+//! AIR.
+
+use winterfell::{
+    math::{batch_inversion, fields::f128::BaseElement, ExtensionOf, FieldElement, StarkField},
+    Assertion, TransitionConstraintDegree,
+};
+
+use super::{
+    are_equal, is_binary, not,
+    rescue::{self, Hash, CYCLE_LENGTH, NUM_ROUNDS, RATE_WIDTH, STATE_WIDTH},
+    EvaluationResult,
+};
+
+// BIT DECOMPOSITION
+// ================================================================================================
+// A bit decomposition of a value column consists of `num_bits` binary columns such that in every
+// row, the value is equal to sum(bits[i] * 2^i). Since all bits are binary, this also implies
+// that all values in the value column are smaller than 2^num_bits.
+
+/// Returns `num_bits` columns containing the bit decomposition of the provided values; the i-th
+/// column contains the i-th least significant bit of every value.
+///
+/// # Panics
+/// Panics if `num_bits` is zero or is not smaller than the number of bits in the field modulus,
+/// or if any of the values does not fit into `num_bits` bits.
+pub fn build_bit_decomposition_columns<B: StarkField>(
+    values: &[B],
+    num_bits: usize,
+) -> Vec<Vec<B>> {
+    assert!(num_bits > 0, "number of bits must be greater than zero");
+    assert!(
+        num_bits < B::MODULUS_BITS as usize,
+        "number of bits must be smaller than {}, but was {num_bits}",
+        B::MODULUS_BITS
+    );
+
+    let one = B::PositiveInteger::from(1_u32);
+    let mut columns = vec![Vec::with_capacity(values.len()); num_bits];
+    for (step, value) in values.iter().enumerate() {
+        let value = value.as_int();
+        assert!(
+            value >> num_bits as u32 == B::PositiveInteger::from(0_u32),
+            "value at step {step} does not fit into {num_bits} bits"
+        );
+        for (i, column) in columns.iter_mut().enumerate() {
+            let bit = if (value >> i as u32) & one == one {
+                B::ONE
+            } else {
+                B::ZERO
+            };
+            column.push(bit);
+        }
+    }
+
+    columns
+}
+
+/// Returns degrees of the transition constraints required for a bit decomposition into
+/// `num_bits` bits.
+///
+/// The constraints are listed in the order in which they are evaluated by
+/// [evaluate_bit_decomposition()]: a binary constraint for each bit, followed by a single
+/// constraint for the recomposition of the value.
+pub fn bit_decomposition_degrees(num_bits: usize) -> Vec<TransitionConstraintDegree> {
+    let mut degrees = vec![TransitionConstraintDegree::new(2); num_bits];
+    degrees.push(TransitionConstraintDegree::new(1));
+    degrees
+}
+
+/// Evaluates constraints of a bit decomposition of `value` into `bits` (starting with the least
+/// significant bit) and writes the results into the first `bits.len() + 1` slots of `result`.
+///
+/// To enforce the decomposition in every row of the trace, this function should be applied to
+/// the current row of an evaluation frame.
+pub fn evaluate_bit_decomposition<E: FieldElement>(value: E, bits: &[E], result: &mut [E]) {
+    let mut power_of_two = E::ONE;
+    let mut recomposed = E::ZERO;
+    for (&bit, result) in bits.iter().zip(result.iter_mut()) {
+        *result = is_binary(bit);
+        recomposed += bit * power_of_two;
+        power_of_two = power_of_two.double();
+    }
+    result[bits.len()] = are_equal(value, recomposed);
+}
+
+// RANGE CHECK
+// ================================================================================================
+// A range check enforces that all values in a column are in the range [0, range) via a
+// logarithmic derivative lookup into a table column. The gadget requires two main trace columns
+// and a single auxiliary trace column:
+// - a table column, which starts at 0, ends at range - 1, and in every step either stays the same
+//   or increases by 1; thus, the table column contains all values in the range;
+// - a multiplicity column, which specifies how many times the value in the table column was
+//   looked up;
+// - an auxiliary running sum column s, which starts and ends at 0 and for every step is updated
+//   as s' = s + 1 / (α - value) - multiplicity / (α - table), where α is a random element drawn
+//   after the main trace segment is committed to.
+//
+// Since the running sum is updated on all steps but the last one, the value in the last row of
+// the value column is not range checked.
+
+/// Returns the table and the multiplicity columns for a range check of `values` against the
+/// range [0, `range`).
+///
+/// # Panics
+/// Panics if `range` is zero, if `range` is greater than or equal to the number of values, or if
+/// any of the values (except for the last one) is outside of the range.
+pub fn build_range_check_columns<B: StarkField>(values: &[B], range: usize) -> [Vec<B>; 2] {
+    assert!(range > 0, "range must be greater than zero");
+    assert!(
+        range < values.len(),
+        "range must be smaller than the number of values ({}), but was {range}",
+        values.len()
+    );
+
+    let num_rows = values.len();
+    let table = (0..num_rows).map(|i| B::from(i.min(range - 1) as u32)).collect::<Vec<_>>();
+
+    let mut multiplicities = vec![0_u32; num_rows];
+    for (step, value) in values[..num_rows - 1].iter().enumerate() {
+        let idx = table[..range].iter().position(|t| t == value);
+        let idx = idx.unwrap_or_else(|| panic!("value at step {step} is not in the range"));
+        multiplicities[idx] += 1;
+    }
+
+    [table, multiplicities.into_iter().map(B::from).collect()]
+}
+
+/// Returns the auxiliary running sum column for a range check of `values`, given the table and
+/// multiplicity columns built via [build_range_check_columns()] and the random element `alpha`.
+///
+/// # Panics
+/// Panics if the columns are not of the same length.
+pub fn build_range_check_aux_column<B, E>(
+    values: &[B],
+    table: &[B],
+    multiplicities: &[B],
+    alpha: E,
+) -> Vec<E>
+where
+    B: StarkField,
+    E: FieldElement<BaseField = B>,
+{
+    assert_eq!(values.len(), table.len(), "all columns must have the same length");
+    assert_eq!(values.len(), multiplicities.len(), "all columns must have the same length");
+
+    let value_inverses =
+        batch_inversion(&values.iter().map(|&v| alpha - E::from(v)).collect::<Vec<_>>());
+    let table_inverses =
+        batch_inversion(&table.iter().map(|&t| alpha - E::from(t)).collect::<Vec<_>>());
+
+    let mut result = Vec::with_capacity(values.len());
+    result.push(E::ZERO);
+    for i in 0..values.len() - 1 {
+        let delta = value_inverses[i] - table_inverses[i].mul_base(multiplicities[i]);
+        result.push(result[i] + delta);
+    }
+
+    result
+}
+
+/// Returns degrees of the main and auxiliary transition constraints required for a range check.
+pub fn range_check_degrees() -> (Vec<TransitionConstraintDegree>, Vec<TransitionConstraintDegree>) {
+    (
+        vec![TransitionConstraintDegree::new(2)],
+        vec![TransitionConstraintDegree::new(3)],
+    )
+}
+
+/// Returns assertions against the table column at index `table_column` for a range check against
+/// the range [0, `range`) in a trace of the specified length.
+pub fn range_check_assertions<B: StarkField>(
+    table_column: usize,
+    trace_length: usize,
+    range: usize,
+) -> Vec<Assertion<B>> {
+    vec![
+        Assertion::single(table_column, 0, B::ZERO),
+        Assertion::single(table_column, trace_length - 1, B::from((range - 1) as u32)),
+    ]
+}
+
+/// Returns assertions against the auxiliary running sum column at index `sum_column` for a range
+/// check in a trace of the specified length.
+pub fn range_check_aux_assertions<E: FieldElement>(
+    sum_column: usize,
+    trace_length: usize,
+) -> Vec<Assertion<E>> {
+    vec![
+        Assertion::single(sum_column, 0, E::ZERO),
+        Assertion::single(sum_column, trace_length - 1, E::ZERO),
+    ]
+}
+
+/// Evaluates the main transition constraint of a range check given the values of the table
+/// column in the current and the next rows.
+pub fn evaluate_range_check<E: FieldElement>(table: E, table_next: E) -> E {
+    is_binary(table_next - table)
+}
+
+/// Evaluates the auxiliary transition constraint of a range check given the values of the value,
+/// table, and multiplicity columns in the current row, the values of the running sum column in
+/// the current and the next rows, and the random element `alpha`.
+pub fn evaluate_range_check_aux<F, E>(
+    value: F,
+    table: F,
+    multiplicity: F,
+    sum: E,
+    sum_next: E,
+    alpha: E,
+) -> E
+where
+    F: FieldElement,
+    E: FieldElement + ExtensionOf<F>,
+{
+    // (s' - s) = 1 / (α - value) - multiplicity / (α - table), multiplied through by both
+    // denominators
+    let value_denom = alpha - E::from(value);
+    let table_denom = alpha - E::from(table);
+    (sum_next - sum) * value_denom * table_denom - table_denom + value_denom.mul_base(multiplicity)
+}
+
+// RESCUE SPONGE
+// ================================================================================================
+// A Rescue sponge absorbs a secret preimage into `STATE_WIDTH` columns holding the state of the
+// Rescue hash function (see [Rescue128](super::rescue::Rescue128)), and computes the hash of the
+// preimage. The first `RATE_WIDTH` columns of the state are the rate, and the remaining columns
+// are the capacity. The preimage is absorbed one block of `RATE_WIDTH` elements at a time, and
+// each block is processed in a cycle of `CYCLE_LENGTH` steps:
+// - the block is added to the rate at the first step of the cycle (for the first block, the
+//   state is initialized to the block followed by zeros in the capacity);
+// - a round of the Rescue permutation is applied on each of the next `NUM_ROUNDS` steps.
+//
+// Since the rate is not constrained when a block is absorbed, the absorbed block is a secret
+// witness defined implicitly as the difference between the rate in the first step of a cycle and
+// the rate in the last step of the previous cycle. The capacity must be carried over from one
+// cycle to the next, and thus, the state in the last step of the cycle of the last block contains
+// the hash of the preimage. Once all blocks have been absorbed, the sponge keeps absorbing zero
+// blocks until the end of the trace.
+
+/// Returns `STATE_WIDTH` columns of a trace of the specified length in which a Rescue sponge
+/// absorbs the specified `preimage`.
+///
+/// # Panics
+/// Panics if the preimage is empty or its length is not a multiple of `RATE_WIDTH`, or if
+/// `trace_length` is not a multiple of `CYCLE_LENGTH` large enough to absorb the preimage.
+pub fn build_rescue_sponge_columns(
+    preimage: &[BaseElement],
+    trace_length: usize,
+) -> Vec<Vec<BaseElement>> {
+    assert!(
+        !preimage.is_empty() && preimage.len() % RATE_WIDTH == 0,
+        "preimage length must be a non-zero multiple of {RATE_WIDTH}, but was {}",
+        preimage.len()
+    );
+    let num_blocks = preimage.len() / RATE_WIDTH;
+    assert!(
+        trace_length % CYCLE_LENGTH == 0 && trace_length >= num_blocks * CYCLE_LENGTH,
+        "trace length must be a multiple of {CYCLE_LENGTH} and at least {}, but was {trace_length}",
+        num_blocks * CYCLE_LENGTH
+    );
+
+    let mut columns =
+        (0..STATE_WIDTH).map(|_| Vec::with_capacity(trace_length)).collect::<Vec<_>>();
+    let mut state = [BaseElement::ZERO; STATE_WIDTH];
+    let mut blocks = preimage.chunks(RATE_WIDTH);
+    for step in 0..trace_length {
+        let cycle_pos = step % CYCLE_LENGTH;
+        if cycle_pos == 0 {
+            // absorb the next block; zero blocks are absorbed after the end of the preimage
+            if let Some(block) = blocks.next() {
+                for (s, &element) in state.iter_mut().zip(block) {
+                    *s += element;
+                }
+            }
+        } else {
+            rescue::apply_round(&mut state, step - 1);
+        }
+        for (column, &value) in columns.iter_mut().zip(state.iter()) {
+            column.push(value);
+        }
+    }
+
+    columns
+}
+
+/// Returns degrees of the transition constraints required for a Rescue sponge; there is one
+/// constraint for each column of the sponge state.
+pub fn rescue_sponge_degrees() -> Vec<TransitionConstraintDegree> {
+    vec![TransitionConstraintDegree::with_cycles(5, vec![CYCLE_LENGTH]); STATE_WIDTH]
+}
+
+/// Returns the periodic columns required for a Rescue sponge: a column which is ONE on the steps
+/// at which a round of the permutation is applied, followed by the Rescue round constants.
+pub fn rescue_sponge_periodic_columns() -> Vec<Vec<BaseElement>> {
+    let mut round_mask = vec![BaseElement::ONE; CYCLE_LENGTH];
+    round_mask[NUM_ROUNDS..].fill(BaseElement::ZERO);
+
+    let mut result = vec![round_mask];
+    result.append(&mut rescue::get_round_constants());
+    result
+}
+
+/// Returns assertions for a Rescue sponge whose state starts at column `first_column`, and which
+/// absorbs a preimage of `num_blocks` blocks hashing to the specified `digest`.
+///
+/// The assertions require the capacity to be zero at the first step, and the first two elements
+/// of the state to be equal to the digest at the last step of the cycle of the last block.
+pub fn rescue_sponge_assertions(
+    first_column: usize,
+    num_blocks: usize,
+    digest: Hash,
+) -> Vec<Assertion<BaseElement>> {
+    let digest_step = num_blocks * CYCLE_LENGTH - 1;
+    let mut result = (RATE_WIDTH..STATE_WIDTH)
+        .map(|i| Assertion::single(first_column + i, 0, BaseElement::ZERO))
+        .collect::<Vec<_>>();
+    result.extend(Assertion::vector(first_column, digest_step, digest.to_elements().to_vec()));
+    result
+}
+
+/// Evaluates transition constraints of a Rescue sponge given its state in the current and the
+/// next rows, and the periodic values of the columns returned by
+/// [rescue_sponge_periodic_columns()]; the results are written into the first `STATE_WIDTH`
+/// slots of `result`.
+pub fn evaluate_rescue_sponge<E: FieldElement + From<BaseElement>>(
+    current: &[E],
+    next: &[E],
+    periodic_values: &[E],
+    result: &mut [E],
+) {
+    let round_flag = periodic_values[0];
+    let ark = &periodic_values[1..];
+
+    // when round_flag = 1, the next state is the result of applying a Rescue round to the
+    // current state
+    result[..STATE_WIDTH].fill(E::ZERO);
+    rescue::enforce_round(result, &current[..STATE_WIDTH], &next[..STATE_WIDTH], ark, round_flag);
+
+    // when round_flag = 0, a block is absorbed into the rate, and the capacity is carried over
+    let absorb_flag = not(round_flag);
+    for i in RATE_WIDTH..STATE_WIDTH {
+        result.agg_constraint(i, absorb_flag, are_equal(next[i], current[i]));
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use winterfell::math::{fields::f128::BaseElement, FieldElement, StarkField};
+
+    use super::*;
+
+    #[test]
+    fn bit_decomposition() {
+        let values = [0_u32, 1, 5, 200, 255].map(BaseElement::from);
+        let columns = build_bit_decomposition_columns(&values, 8);
+        assert_eq!(8, columns.len());
+        assert_eq!(vec![0, 1, 1, 0, 1], columns[0].iter().map(|v| v.as_int()).collect::<Vec<_>>());
+        assert_eq!(vec![0, 0, 1, 0, 1], columns[2].iter().map(|v| v.as_int()).collect::<Vec<_>>());
+        assert_eq!(vec![0, 0, 0, 1, 1], columns[7].iter().map(|v| v.as_int()).collect::<Vec<_>>());
+
+        // constraints are satisfied on all rows
+        let mut result = vec![BaseElement::ZERO; bit_decomposition_degrees(8).len()];
+        for (step, &value) in values.iter().enumerate() {
+            let bits = columns.iter().map(|column| column[step]).collect::<Vec<_>>();
+            evaluate_bit_decomposition(value, &bits, &mut result);
+            assert!(result.iter().all(|&r| r == BaseElement::ZERO));
+        }
+
+        // constraints are not satisfied when a bit is not binary or the value does not match
+        let mut bits = columns.iter().map(|column| column[2]).collect::<Vec<_>>();
+        evaluate_bit_decomposition(BaseElement::new(6), &bits, &mut result);
+        assert_ne!(BaseElement::ZERO, result[8]);
+
+        bits[0] = -BaseElement::ONE;
+        bits[1] = BaseElement::ONE;
+        evaluate_bit_decomposition(BaseElement::new(5), &bits, &mut result);
+        assert_eq!(BaseElement::ZERO, result[8]);
+        assert_ne!(BaseElement::ZERO, result[0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "value at step 1 does not fit into 4 bits")]
+    fn bit_decomposition_out_of_range() {
+        build_bit_decomposition_columns(&[BaseElement::new(15), BaseElement::new(16)], 4);
+    }
+
+    #[test]
+    fn range_check() {
+        let range = 5;
+        let values = [3_u32, 0, 4, 4, 1, 3, 4, 100].map(BaseElement::from);
+        let [table, multiplicities] = build_range_check_columns(&values, range);
+        assert_eq!(
+            vec![0, 1, 2, 3, 4, 4, 4, 4],
+            table.iter().map(|v| v.as_int()).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![1, 1, 0, 2, 3, 0, 0, 0],
+            multiplicities.iter().map(|v| v.as_int()).collect::<Vec<_>>()
+        );
+
+        let alpha = BaseElement::new(123456789);
+        let sum = build_range_check_aux_column(&values, &table, &multiplicities, alpha);
+        let trace_length = values.len();
+
+        // assertions are satisfied
+        let assertions = range_check_assertions::<BaseElement>(0, trace_length, range);
+        assertions[0].apply(trace_length, |step, value| assert_eq!(value, table[step]));
+        assertions[1].apply(trace_length, |step, value| assert_eq!(value, table[step]));
+        for assertion in range_check_aux_assertions::<BaseElement>(0, trace_length) {
+            assertion.apply(trace_length, |step, value| assert_eq!(value, sum[step]));
+        }
+
+        // transition constraints are satisfied on all steps but the last one
+        for step in 0..trace_length - 1 {
+            assert_eq!(BaseElement::ZERO, evaluate_range_check(table[step], table[step + 1]));
+            let result = evaluate_range_check_aux(
+                values[step],
+                table[step],
+                multiplicities[step],
+                sum[step],
+                sum[step + 1],
+                alpha,
+            );
+            assert_eq!(BaseElement::ZERO, result);
+        }
+    }
+
+    #[test]
+    fn range_check_out_of_range() {
+        // build a trace where one of the values is out of range, and the running sum is
+        // consistent with the transition constraints; the final sum must then be non-zero
+        let values = [3_u32, 0, 7, 4, 1].map(BaseElement::from);
+        let table = [0_u32, 1, 2, 3, 3].map(BaseElement::from);
+        let multiplicities = [1_u32, 0, 0, 2, 0].map(BaseElement::from);
+
+        let alpha = BaseElement::new(123456789);
+        let sum = build_range_check_aux_column(&values, &table, &multiplicities, alpha);
+        assert_ne!(BaseElement::ZERO, sum[values.len() - 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "value at step 2 is not in the range")]
+    fn range_check_columns_out_of_range() {
+        build_range_check_columns(&[3_u32, 0, 7, 4, 1].map(BaseElement::from), 4);
+    }
+
+    #[test]
+    fn rescue_sponge() {
+        let preimage = (1..=8_u32).map(BaseElement::from).collect::<Vec<_>>();
+        let trace_length = 32;
+        let mut columns = build_rescue_sponge_columns(&preimage, trace_length);
+        assert_eq!(STATE_WIDTH, columns.len());
+
+        // the state holds the hash of the preimage at the last step of the second cycle
+        let digest = rescue::Rescue128::digest(&preimage);
+        assert_eq!(digest.to_elements(), [columns[0][15], columns[1][15]]);
+
+        // assertions are satisfied
+        for assertion in rescue_sponge_assertions(0, 2, digest) {
+            assertion.apply(trace_length, |step, value| {
+                assert_eq!(value, columns[assertion.column()][step])
+            });
+        }
+
+        // transition constraints are satisfied on all steps but the last one
+        let periodic_columns = rescue_sponge_periodic_columns();
+        let mut result = vec![BaseElement::ZERO; rescue_sponge_degrees().len()];
+        let evaluate = |columns: &[Vec<BaseElement>], step: usize, result: &mut [BaseElement]| {
+            let current = columns.iter().map(|column| column[step]).collect::<Vec<_>>();
+            let next = columns.iter().map(|column| column[step + 1]).collect::<Vec<_>>();
+            let periodic_values = periodic_columns
+                .iter()
+                .map(|column| column[step % CYCLE_LENGTH])
+                .collect::<Vec<_>>();
+            evaluate_rescue_sponge(&current, &next, &periodic_values, result);
+        };
+        for step in 0..trace_length - 1 {
+            evaluate(&columns, step, &mut result);
+            assert!(result.iter().all(|&r| r == BaseElement::ZERO));
+        }
+
+        // constraints are not satisfied when the capacity is changed while absorbing a block
+        columns[4][8] += BaseElement::ONE;
+        evaluate(&columns, 7, &mut result);
+        assert_ne!(BaseElement::ZERO, result[4]);
+        evaluate(&columns, 8, &mut result);
+        assert!(result.iter().any(|&r| r != BaseElement::ZERO));
+    }
+
+    #[test]
+    #[should_panic(expected = "preimage length must be a non-zero multiple of 4, but was 6")]
+    fn rescue_sponge_invalid_preimage() {
+        build_rescue_sponge_columns(&[BaseElement::ONE; 6], 16);
+    }
+}
@@ -10,6 +10,7 @@ use winterfell::{
     Trace, TraceTable,
 };
 
+pub mod gadgets;
 pub mod rescue;
 
 // CONSTRAINT EVALUATION HELPERS
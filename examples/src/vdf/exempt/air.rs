@@ -3,6 +3,7 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
+use core_utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 use winterfell::{
     math::ToElements, Air, AirContext, Assertion, EvaluationFrame, TraceInfo,
     TransitionConstraintDegree,
@@ -25,6 +26,21 @@ impl ToElements<BaseElement> for VdfInputs {
     }
 }
 
+impl Serializable for VdfInputs {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write(self.seed);
+        target.write(self.result);
+    }
+}
+
+impl Deserializable for VdfInputs {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let seed = source.read()?;
+        let result = source.read()?;
+        Ok(Self { seed, result })
+    }
+}
+
 // VDF AIR
 // ================================================================================================
 
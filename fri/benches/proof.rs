@@ -0,0 +1,129 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use std::time::Duration;
+
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use crypto::{hashers::Blake3_256, DefaultRandomCoin, MerkleTree, RandomCoin};
+use math::{fft, fields::f128::BaseElement, FieldElement};
+use rand_utils::rand_vector;
+use winter_fri::{
+    DefaultProverChannel, DefaultVerifierChannel, FriOptions, FriProof, FriProver, FriVerifier,
+};
+
+type Blake3 = Blake3_256<BaseElement>;
+type ProverChannel = DefaultProverChannel<BaseElement, Blake3, DefaultRandomCoin<Blake3>>;
+type VerifierChannel = DefaultVerifierChannel<BaseElement, Blake3, MerkleTree<Blake3>>;
+
+static DOMAIN_SIZES: [usize; 3] = [65536, 131072, 262144];
+static FOLDING_FACTORS: [usize; 4] = [2, 4, 8, 16];
+static BLOWUP_FACTOR: usize = 8;
+static MAX_REMAINDER_DEGREE: usize = 255;
+static NUM_QUERIES: usize = 32;
+
+pub fn build_proof(c: &mut Criterion) {
+    let mut fri_group = c.benchmark_group("FRI build_proof");
+    fri_group.sample_size(10);
+    fri_group.measurement_time(Duration::from_secs(10));
+
+    for &folding_factor in &FOLDING_FACTORS {
+        let options = FriOptions::new(BLOWUP_FACTOR, folding_factor, MAX_REMAINDER_DEGREE);
+        for &domain_size in &DOMAIN_SIZES {
+            let evaluations = build_evaluations(domain_size);
+            let (_, _, positions) = prove(&options, &evaluations);
+
+            fri_group.bench_with_input(
+                BenchmarkId::new(format!("folding factor {folding_factor}"), domain_size),
+                &evaluations,
+                |b, e| {
+                    b.iter_batched(
+                        || {
+                            let mut channel = ProverChannel::new(domain_size, NUM_QUERIES);
+                            let mut prover =
+                                FriProver::<_, _, _, MerkleTree<Blake3>>::new(options.clone());
+                            prover.build_layers(&mut channel, e.clone());
+                            prover
+                        },
+                        |mut prover| prover.build_proof(&positions),
+                        BatchSize::LargeInput,
+                    );
+                },
+            );
+        }
+    }
+}
+
+pub fn verify(c: &mut Criterion) {
+    let mut fri_group = c.benchmark_group("FRI verify");
+
+    for &folding_factor in &FOLDING_FACTORS {
+        let options = FriOptions::new(BLOWUP_FACTOR, folding_factor, MAX_REMAINDER_DEGREE);
+        for &domain_size in &DOMAIN_SIZES {
+            let evaluations = build_evaluations(domain_size);
+            let (proof, commitments, positions) = prove(&options, &evaluations);
+            let queried_evaluations = positions.iter().map(|&p| evaluations[p]).collect::<Vec<_>>();
+            let max_degree = domain_size / BLOWUP_FACTOR - 1;
+
+            fri_group.bench_with_input(
+                BenchmarkId::new(format!("folding factor {folding_factor}"), domain_size),
+                &proof,
+                |b, proof| {
+                    b.iter_batched(
+                        || proof.clone(),
+                        |proof| {
+                            let mut channel = VerifierChannel::new(
+                                proof,
+                                commitments.clone(),
+                                domain_size,
+                                folding_factor,
+                            )
+                            .unwrap();
+                            let mut coin = DefaultRandomCoin::<Blake3>::new(&[]);
+                            let verifier = FriVerifier::new(
+                                &mut channel,
+                                &mut coin,
+                                options.clone(),
+                                max_degree,
+                            )
+                            .unwrap();
+                            verifier.verify(&mut channel, &queried_evaluations, &positions).unwrap()
+                        },
+                        BatchSize::SmallInput,
+                    );
+                },
+            );
+        }
+    }
+}
+
+criterion_group!(fri_proof_group, build_proof, verify);
+criterion_main!(fri_proof_group);
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns evaluations of a random polynomial of degree `domain_size / BLOWUP_FACTOR - 1` over a
+/// domain of the specified size.
+fn build_evaluations(domain_size: usize) -> Vec<BaseElement> {
+    let mut p: Vec<BaseElement> = rand_vector(domain_size / BLOWUP_FACTOR);
+    p.resize(domain_size, BaseElement::ZERO);
+    let twiddles = fft::get_twiddles::<BaseElement>(domain_size);
+    fft::evaluate_poly(&mut p, &twiddles);
+    p
+}
+
+/// Builds a FRI proof for the specified evaluations, and returns the proof together with the
+/// layer commitments and the queried positions.
+fn prove(
+    options: &FriOptions,
+    evaluations: &[BaseElement],
+) -> (FriProof, Vec<<Blake3 as crypto::Hasher>::Digest>, Vec<usize>) {
+    let mut channel = ProverChannel::new(evaluations.len(), NUM_QUERIES);
+    let mut prover = FriProver::<_, _, _, MerkleTree<Blake3>>::new(options.clone());
+    prover.build_layers(&mut channel, evaluations.to_vec());
+    let positions = channel.draw_query_positions(0);
+    let proof = prover.build_proof(&positions);
+    (proof, channel.layer_commitments().to_vec(), positions)
+}
@@ -3,6 +3,7 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
+use alloc::string::String;
 use core::fmt;
 
 use crypto::RandomCoinError;
@@ -34,6 +35,8 @@ pub enum VerifierError {
     RemainderDegreeMismatch(usize),
     /// Polynomial degree at one of the FRI layers could not be divided evenly by the folding factor.
     DegreeTruncation(usize, usize, usize),
+    /// FRI proof data read from the channel could not be deserialized.
+    ProofDeserializationError(String),
 }
 
 impl fmt::Display for VerifierError {
@@ -70,6 +73,9 @@ impl fmt::Display for VerifierError {
             Self::DegreeTruncation(degree, folding, layer) => {
                 write!(f, "degree reduction from {degree} by {folding} at layer {layer} results in degree truncation")
             }
+            Self::ProofDeserializationError(msg) => {
+                write!(f, "FRI proof deserialization failed: {msg}")
+            }
         }
     }
 }
@@ -55,6 +55,21 @@
 //! * Folding factor (used for degree reduction for each FRI layer),
 //! * Maximum size of the last FRI layer.
 //!
+//! # Remainder polynomial
+//! Once the domain falls under the `max_remainder_size`, the prover sends the remainder
+//! polynomial to the verifier in coefficient form (rather than committing to it). This is what
+//! terminates the protocol: the verifier checks that the number of coefficients does not exceed
+//! the remainder degree bound, and that the polynomial evaluates to the values of the last folded
+//! layer at all query positions.
+//!
+//! Committing to the evaluations of the remainder and opening them only at the query positions
+//! would not be sound: the openings would establish consistency with the last folded layer, but
+//! would say nothing about the degree of the committed function. Checking the degree would
+//! require either all coefficients of the polynomial (which is what the current scheme sends) or
+//! another round of folding (which is what an additional FRI layer does). Thus, to reduce the
+//! size of the remainder in a proof, `remainder_max_degree` should be lowered instead; this adds
+//! FRI layers and trades a smaller remainder for additional layer openings.
+//!
 //! # References
 //! * StarkWare's blog post on [Low Degree Testing](https://medium.com/starkware/low-degree-testing-f7614f5172db)
 //! * [Fast Reed-Solomon Interactive Oracle Proofs of Proximity](https://eccc.weizmann.ac.il/report/2017/134/)
@@ -78,7 +93,7 @@ mod options;
 pub use options::FriOptions;
 
 mod proof;
-pub use proof::FriProof;
+pub use proof::{FriProof, FriProofLayer};
 
 mod errors;
 pub use errors::VerifierError;
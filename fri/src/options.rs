@@ -91,4 +91,59 @@ impl FriOptions {
         }
         result
     }
+
+    /// Computes and returns the size of the domain of the last FRI layer (i.e., the remainder
+    /// layer) for a domain of the specified initial size.
+    ///
+    /// This is equal to `domain_size / folding_factor^num_fri_layers(domain_size)`.
+    pub fn fri_remainder_domain_size(&self, domain_size: usize) -> usize {
+        let num_layers = self.num_fri_layers(domain_size) as u32;
+        domain_size / self.folding_factor.pow(num_layers)
+    }
+
+    /// Computes and returns the number of coefficients of the remainder polynomial for a domain of
+    /// the specified initial size.
+    ///
+    /// The remainder polynomial is sent to the verifier in coefficient form, and thus, this is
+    /// also the number of field elements in the remainder part of a FRI proof. The degree of the
+    /// remainder polynomial is guaranteed to be at most `remainder_max_degree` as long as the
+    /// initial domain is at least as large as `(remainder_max_degree + 1) * blowup_factor`.
+    pub fn fri_remainder_size(&self, domain_size: usize) -> usize {
+        self.fri_remainder_domain_size(domain_size) / self.blowup_factor
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::FriOptions;
+
+    #[test]
+    fn num_fri_layers() {
+        // domain of 2^10 with blowup 8 and remainder of degree 7: 1024 -> 512 -> 256 -> 128 -> 64
+        let options = FriOptions::new(8, 2, 7);
+        assert_eq!(4, options.num_fri_layers(1024));
+        assert_eq!(64, options.fri_remainder_domain_size(1024));
+        assert_eq!(8, options.fri_remainder_size(1024));
+
+        // with folding factor 4: 1024 -> 256 -> 64
+        let options = FriOptions::new(8, 4, 7);
+        assert_eq!(2, options.num_fri_layers(1024));
+        assert_eq!(64, options.fri_remainder_domain_size(1024));
+        assert_eq!(8, options.fri_remainder_size(1024));
+
+        // with folding factor 8: 1024 -> 128 -> 16
+        let options = FriOptions::new(8, 8, 7);
+        assert_eq!(2, options.num_fri_layers(1024));
+        assert_eq!(16, options.fri_remainder_domain_size(1024));
+        assert_eq!(2, options.fri_remainder_size(1024));
+
+        // domain which is already small enough does not require any layers
+        let options = FriOptions::new(4, 4, 31);
+        assert_eq!(0, options.num_fri_layers(128));
+        assert_eq!(128, options.fri_remainder_domain_size(128));
+        assert_eq!(32, options.fri_remainder_size(128));
+    }
 }
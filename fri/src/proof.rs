@@ -175,29 +175,20 @@ impl FriProof {
     /// * Any of the remainder values could not be parsed correctly.
     /// * Not all bytes have been consumed while parsing remainder values.
     pub fn parse_remainder<E: FieldElement>(&self) -> Result<Vec<E>, DeserializationError> {
-        let num_elements = self.num_remainder_elements::<E>();
-        if !num_elements.is_power_of_two() {
-            return Err(DeserializationError::InvalidValue(format!(
-                "number of remainder values must be a power of two, but {num_elements} was implied"
-            )));
-        }
-        let mut reader = SliceReader::new(&self.remainder);
-        let remainder = reader.read_many(num_elements).map_err(|err| {
-            DeserializationError::InvalidValue(format!("failed to parse FRI remainder: {err}"))
-        })?;
-        if reader.has_more_bytes() {
-            return Err(DeserializationError::UnconsumedBytes);
-        }
-        Ok(remainder)
+        parse_remainder_bytes(&self.remainder)
     }
-}
 
-// SERIALIZATION / DESERIALIZATION
-// ------------------------------------------------------------------------------------------------
+    // STREAMING
+    // --------------------------------------------------------------------------------------------
 
-impl Serializable for FriProof {
-    /// Serializes `self` and writes the resulting bytes into the `target` writer.
-    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+    /// Serializes layers and the remainder of this proof, and writes the resulting bytes into the
+    /// `target` writer.
+    ///
+    /// This writes the same bytes as [Serializable::write_into()], except for the number of
+    /// partitions, which is expected to be sent separately (and before the layers). Individual
+    /// layers can then be read from the source one at a time via [FriProofLayer::read_from()],
+    /// followed by the remainder via [FriProof::read_remainder()].
+    pub fn write_layers_into<W: ByteWriter>(&self, target: &mut W) {
         // write layers
         target.write_u8(self.layers.len() as u8);
         for layer in self.layers.iter() {
@@ -207,6 +198,31 @@ impl Serializable for FriProof {
         // write remainder
         target.write_u16(self.remainder.len() as u16);
         target.write_bytes(&self.remainder);
+    }
+
+    /// Reads the remainder written by [FriProof::write_layers_into()] from the specified `source`
+    /// and parses it into a vector of remainder values.
+    ///
+    /// # Errors
+    /// Returns an error if the remainder could not be read from the `source`, or if it could not
+    /// be parsed as described in [FriProof::parse_remainder()].
+    pub fn read_remainder<E: FieldElement, R: ByteReader>(
+        source: &mut R,
+    ) -> Result<Vec<E>, DeserializationError> {
+        let num_remainder_bytes = source.read_u16()? as usize;
+        let remainder = source.read_vec(num_remainder_bytes)?;
+        parse_remainder_bytes(&remainder)
+    }
+}
+
+// SERIALIZATION / DESERIALIZATION
+// ------------------------------------------------------------------------------------------------
+
+impl Serializable for FriProof {
+    /// Serializes `self` and writes the resulting bytes into the `target` writer.
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        // write layers and remainder
+        self.write_layers_into(target);
 
         // write number of partitions
         target.write_u8(self.num_partitions);
@@ -237,6 +253,12 @@ impl Deserializable for FriProof {
 // FRI PROOF LAYER
 // ================================================================================================
 
+/// A single layer of a [FriProof].
+///
+/// A layer contains polynomial evaluations at positions queried by the verifier together with
+/// opening proofs for these evaluations against the layer commitment. Similarly to [FriProof],
+/// all values are stored as vectors of bytes and must be parsed via
+/// [parse()](FriProofLayer::parse()) before they can be used.
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct FriProofLayer {
     values: Vec<u8>,
@@ -375,3 +397,24 @@ impl Deserializable for FriProofLayer {
         Ok(FriProofLayer { values, paths })
     }
 }
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Parses the specified remainder bytes into a vector of remainder values.
+fn parse_remainder_bytes<E: FieldElement>(bytes: &[u8]) -> Result<Vec<E>, DeserializationError> {
+    let num_elements = bytes.len() / E::ELEMENT_BYTES;
+    if !num_elements.is_power_of_two() {
+        return Err(DeserializationError::InvalidValue(format!(
+            "number of remainder values must be a power of two, but {num_elements} was implied"
+        )));
+    }
+    let mut reader = SliceReader::new(bytes);
+    let remainder = reader.read_many(num_elements).map_err(|err| {
+        DeserializationError::InvalidValue(format!("failed to parse FRI remainder: {err}"))
+    })?;
+    if reader.has_more_bytes() {
+        return Err(DeserializationError::UnconsumedBytes);
+    }
+    Ok(remainder)
+}
@@ -38,6 +38,59 @@ fn fri_folding_4() {
     fri_prove_verify(trace_length_e, lde_blowup_e, folding_factor_e, max_remainder_degree)
 }
 
+#[test]
+fn fri_spot_check() {
+    let trace_length = 1 << 10;
+    let lde_blowup = 1 << 3;
+    let domain_size = trace_length * lde_blowup;
+
+    for folding_factor in [2, 4, 8, 16] {
+        let options = FriOptions::new(lde_blowup, folding_factor, 31);
+        let mut channel = build_prover_channel(trace_length, &options);
+        let evaluations = build_evaluations(trace_length, lde_blowup);
+
+        let mut prover = FriProver::<_, _, _, MerkleTree<Blake3>>::new(options.clone());
+        prover.build_layers(&mut channel, evaluations.clone());
+        let positions = channel.draw_query_positions(0);
+        let proof = prover.build_proof(&positions);
+        let commitments = channel.layer_commitments().to_vec();
+
+        let spot_check = |sample: &[usize], evaluations: &[BaseElement]| {
+            let mut channel =
+                DefaultVerifierChannel::<BaseElement, Blake3, MerkleTree<Blake3>>::new(
+                    proof.clone(),
+                    commitments.clone(),
+                    domain_size,
+                    options.folding_factor(),
+                )
+                .unwrap();
+            let mut coin = DefaultRandomCoin::<Blake3>::new(&[]);
+            let verifier =
+                FriVerifier::new(&mut channel, &mut coin, options.clone(), trace_length - 1)?;
+            let sample_evaluations = sample.iter().map(|&p| evaluations[p]).collect::<Vec<_>>();
+            verifier.spot_check(&mut channel, &sample_evaluations, &positions, sample)
+        };
+
+        // the spot check passes for any subset of the queried positions
+        spot_check(&positions[..1], &evaluations).unwrap();
+        spot_check(&positions[3..7], &evaluations).unwrap();
+
+        // the spot check detects an invalid evaluation at a sampled position
+        let mut invalid_evaluations = evaluations.clone();
+        invalid_evaluations[positions[0]] += BaseElement::ONE;
+        let result = spot_check(&positions[..1], &invalid_evaluations);
+        assert_eq!(Err(VerifierError::InvalidLayerFolding(0)), result);
+
+        // a position which does not fold into any of the queried rows cannot be checked
+        let row_length = domain_size / folding_factor;
+        let unqueried = (0..domain_size)
+            .find(|p| positions.iter().all(|q| q % row_length != p % row_length))
+            .unwrap();
+        let result = spot_check(&[unqueried], &evaluations);
+        assert_eq!(Err(VerifierError::LayerCommitmentMismatch), result);
+    }
+}
+
 // TEST UTILS
 // ================================================================================================
 
@@ -109,6 +162,14 @@ fn fri_prove_verify(
     let positions = channel.draw_query_positions(0);
     let proof = prover.build_proof(&positions);
 
+    // make sure the shape of the proof matches the one predicted by the options
+    let domain_size = trace_length * lde_blowup;
+    assert_eq!(options.num_fri_layers(domain_size), proof.num_layers());
+    assert_eq!(
+        options.fri_remainder_size(domain_size),
+        proof.parse_remainder::<BaseElement>().unwrap().len()
+    );
+
     // make sure the proof can be verified
     let commitments = channel.layer_commitments().to_vec();
     let max_degree = trace_length - 1;
@@ -73,6 +73,32 @@ pub trait VerifierChannel<E: FieldElement> {
     // PROVIDED METHODS
     // --------------------------------------------------------------------------------------------
 
+    /// Loads query values and the opening proof for the next FRI layer into the channel.
+    ///
+    /// This is invoked by [read_layer_queries()](VerifierChannel::read_layer_queries) before the
+    /// layer data is taken from the channel. The default implementation does nothing; it can be
+    /// overridden by channels which read the proof incrementally (e.g., from a stream), so that
+    /// data for only a single layer needs to be held in memory at a time.
+    ///
+    /// # Errors
+    /// Returns an error if the data for the next layer could not be read.
+    fn load_next_fri_layer(&mut self) -> Result<(), VerifierError> {
+        Ok(())
+    }
+
+    /// Loads the remainder polynomial into the channel.
+    ///
+    /// This is invoked by [read_remainder()](VerifierChannel::read_remainder) before the
+    /// remainder is taken from the channel. Similarly to
+    /// [load_next_fri_layer()](VerifierChannel::load_next_fri_layer), the default implementation
+    /// does nothing.
+    ///
+    /// # Errors
+    /// Returns an error if the remainder could not be read.
+    fn load_fri_remainder(&mut self) -> Result<(), VerifierError> {
+        Ok(())
+    }
+
     /// Returns FRI query values at the specified positions from the current FRI layer and advances
     /// layer pointer by one.
     ///
@@ -85,6 +111,7 @@ pub trait VerifierChannel<E: FieldElement> {
         positions: &[usize],
         commitment: &<Self::Hasher as Hasher>::Digest,
     ) -> Result<Vec<[E; N]>, VerifierError> {
+        self.load_next_fri_layer()?;
         let layer_proof = self.take_next_fri_layer_proof();
         let layer_queries = self.take_next_fri_layer_queries();
         // build the values (i.e., polynomial evaluations over a coset of a multiplicative subgroup
@@ -108,8 +135,25 @@ pub trait VerifierChannel<E: FieldElement> {
         Ok(leaf_values.to_vec())
     }
 
+    /// Returns FRI query values from the current FRI layer and advances layer pointer by one.
+    ///
+    /// Unlike [read_layer_queries()](VerifierChannel::read_layer_queries), this does not check
+    /// the values against the layer commitment, and thus, the returned values cannot be trusted.
+    ///
+    /// # Errors
+    /// Returns an error if the data for the layer could not be read.
+    fn read_unchecked_layer_queries<const N: usize>(
+        &mut self,
+    ) -> Result<Vec<[E; N]>, VerifierError> {
+        self.load_next_fri_layer()?;
+        let _layer_proof = self.take_next_fri_layer_proof();
+        let layer_queries = self.take_next_fri_layer_queries();
+        Ok(group_slice_elements(&layer_queries).to_vec())
+    }
+
     /// Returns FRI remainder polynomial read from this channel.
     fn read_remainder(&mut self) -> Result<Vec<E>, VerifierError> {
+        self.load_fri_remainder()?;
         let remainder = self.take_fri_remainder();
 
         Ok(remainder)
@@ -9,7 +9,7 @@ use alloc::vec::Vec;
 use core::{marker::PhantomData, mem};
 
 use crypto::{ElementHasher, RandomCoin, VectorCommitment};
-use math::{polynom, FieldElement, StarkField};
+use math::{polynom, Choice, FieldElement, StarkField};
 
 use crate::{folding::fold_positions, utils::map_positions_to_indexes, FriOptions, VerifierError};
 
@@ -231,6 +231,50 @@ where
         }
     }
 
+    /// Executes a partial query phase of the FRI protocol for a sample of the queried positions.
+    ///
+    /// **This is not a sound verification procedure.** Query values read from the `channel` are
+    /// not checked against the layer commitments, and only the sampled positions are checked for
+    /// consistency across FRI layers. Thus, this can be used only as a cheap filter for rejecting
+    /// obviously invalid proofs.
+    ///
+    /// The `positions` parameter must contain all positions at which the prover was queried (in
+    /// the same order in which they were provided to the prover), while `sample` contains the
+    /// positions to check, and `evaluations` the evaluations of the polynomial at the first FRI
+    /// layer for the positions in the `sample`; the `sample` must be a subset of `positions`.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * The length of `evaluations` is not equal to the length of `sample`.
+    /// * An unsupported folding factor was specified by the `options` for this verifier.
+    /// * The query values read from the channel do not cover the sampled positions.
+    /// * The verifier detects an error in how the degree-respecting projection was applied at
+    ///   any of the FRI layers, or in the degree of the remainder, for the sampled positions.
+    pub fn spot_check(
+        &self,
+        channel: &mut C,
+        evaluations: &[E],
+        positions: &[usize],
+        sample: &[usize],
+    ) -> Result<(), VerifierError> {
+        if evaluations.len() != sample.len() {
+            return Err(VerifierError::NumPositionEvaluationMismatch(
+                sample.len(),
+                evaluations.len(),
+            ));
+        }
+
+        // static dispatch for folding factor parameter
+        let folding_factor = self.options.folding_factor();
+        match folding_factor {
+            2 => self.spot_check_generic::<2>(channel, evaluations, positions, sample),
+            4 => self.spot_check_generic::<4>(channel, evaluations, positions, sample),
+            8 => self.spot_check_generic::<8>(channel, evaluations, positions, sample),
+            16 => self.spot_check_generic::<16>(channel, evaluations, positions, sample),
+            _ => Err(VerifierError::UnsupportedFoldingFactor(folding_factor)),
+        }
+    }
+
     /// This is the actual implementation of the verification procedure described above, but it
     /// also takes folding factor as a generic parameter N.
     fn verify_generic<const N: usize>(
@@ -268,7 +312,7 @@ where
             let layer_values = channel.read_layer_queries(&position_indexes, &layer_commitment)?;
             let query_values =
                 get_query_values::<E, N>(&layer_values, &positions, &folded_positions, domain_size);
-            if evaluations != query_values {
+            if !ct_eq_all(&evaluations, &query_values) {
                 return Err(VerifierError::InvalidLayerFolding(depth));
             }
 
@@ -319,7 +363,100 @@ where
                 &remainder_poly,
                 offset * domain_generator.exp_vartime((position as u64).into()),
             );
-            if comp_eval != evaluation {
+            if !bool::from(comp_eval.ct_eq(&evaluation)) {
+                return Err(VerifierError::InvalidRemainderFolding);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// This is the actual implementation of the spot check procedure described above, but it
+    /// also takes folding factor as a generic parameter N.
+    fn spot_check_generic<const N: usize>(
+        &self,
+        channel: &mut C,
+        evaluations: &[E],
+        positions: &[usize],
+        sample: &[usize],
+    ) -> Result<(), VerifierError> {
+        // pre-compute roots of unity used in computing x coordinates in the folded domain
+        let folding_roots = (0..N)
+            .map(|i| self.domain_generator.exp_vartime(((self.domain_size / N * i) as u64).into()))
+            .collect::<Vec<_>>();
+
+        // 1 ----- check the recursive components of the FRI proof for the sampled positions ------
+        let mut domain_generator = self.domain_generator;
+        let mut domain_size = self.domain_size;
+        let mut max_degree_plus_1 = self.max_poly_degree + 1;
+        let mut positions = positions.to_vec();
+        let mut sample = sample.to_vec();
+        let mut evaluations = evaluations.to_vec();
+
+        for depth in 0..self.options.num_fri_layers(self.domain_size) {
+            // query values in the layer are arranged by folded positions of all queries, and thus,
+            // we need all folded positions to locate the values for the sampled positions
+            let mut folded_positions = fold_positions(&positions, domain_size, N);
+            let layer_values = channel.read_unchecked_layer_queries::<N>()?;
+            if layer_values.len() != folded_positions.len() {
+                return Err(VerifierError::LayerCommitmentMismatch);
+            }
+
+            let row_length = domain_size / N;
+            let alpha = self.layer_alphas[depth];
+            let mut folded_sample = Vec::with_capacity(sample.len());
+            for (&position, evaluation) in sample.iter().zip(evaluations.iter_mut()) {
+                let folded_position = position % row_length;
+                let idx = folded_positions
+                    .iter()
+                    .position(|&v| v == folded_position)
+                    .ok_or(VerifierError::LayerCommitmentMismatch)?;
+                let row = &layer_values[idx];
+                if !bool::from(row[position / row_length].ct_eq(evaluation)) {
+                    return Err(VerifierError::InvalidLayerFolding(depth));
+                }
+
+                // interpolate the row into a polynomial and evaluate it at alpha to get the
+                // value of the folded evaluation
+                let xe = domain_generator.exp_vartime((folded_position as u64).into())
+                    * self.options.domain_offset();
+                let xs: [E; N] = folding_roots
+                    .iter()
+                    .map(|&r| E::from(xe * r))
+                    .collect::<Vec<_>>()
+                    .try_into()
+                    .unwrap();
+                let row_poly = polynom::interpolate(&xs, row, true);
+                *evaluation = polynom::eval(&row_poly, alpha);
+                folded_sample.push(folded_position);
+            }
+
+            // make sure next degree reduction does not result in degree truncation
+            if max_degree_plus_1 % N != 0 {
+                return Err(VerifierError::DegreeTruncation(max_degree_plus_1 - 1, N, depth));
+            }
+
+            // update variables for the next iteration of the loop
+            domain_generator = domain_generator.exp_vartime((N as u32).into());
+            max_degree_plus_1 /= N;
+            domain_size /= N;
+            mem::swap(&mut positions, &mut folded_positions);
+            sample = folded_sample;
+        }
+
+        // 2 ----- check the remainder polynomial of the FRI proof for the sampled positions -----
+        let remainder_poly = channel.read_remainder()?;
+        if remainder_poly.len() > max_degree_plus_1 {
+            return Err(VerifierError::RemainderDegreeMismatch(max_degree_plus_1 - 1));
+        }
+        let offset: E::BaseField = self.options().domain_offset();
+
+        for (&position, evaluation) in sample.iter().zip(evaluations) {
+            let comp_eval = eval_horner::<E>(
+                &remainder_poly,
+                offset * domain_generator.exp_vartime((position as u64).into()),
+            );
+            if !bool::from(comp_eval.ct_eq(&evaluation)) {
                 return Err(VerifierError::InvalidRemainderFolding);
             }
         }
@@ -348,6 +485,13 @@ fn get_query_values<E: FieldElement, const N: usize>(
     result
 }
 
+/// Returns true if the provided slices contain the same elements; the elements are compared in
+/// constant time, and only the lengths of the slices may affect the running time.
+fn ct_eq_all<E: FieldElement>(lhs: &[E], rhs: &[E]) -> bool {
+    let eq = lhs.iter().zip(rhs).fold(Choice::from(1), |acc, (a, b)| acc & a.ct_eq(b));
+    lhs.len() == rhs.len() && bool::from(eq)
+}
+
 // Evaluates a polynomial with coefficients in an extension field at a point in the base field.
 pub fn eval_horner<E>(p: &[E], x: E::BaseField) -> E
 where
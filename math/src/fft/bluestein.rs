@@ -0,0 +1,141 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+
+use crate::{
+    fft::{evaluate_poly, get_inv_twiddles, get_twiddles, interpolate_poly},
+    field::{FieldElement, StarkField},
+    utils::get_power_series,
+};
+
+// BLUESTEIN'S ALGORITHM
+// ================================================================================================
+
+/// Computes the DFT of the specified values using Bluestein's algorithm.
+///
+/// Let `n` be the length of `values` and `psi` be a primitive root of unity of order 2`n`. Then,
+/// since `j * k = (j^2 + k^2 - (k - j)^2) / 2`, the DFT can be written as:
+///
+/// `X[k] = psi^(k^2) * sum_j (values[j] * psi^(j^2)) * psi^(-(k - j)^2)`
+///
+/// The inner sum is a convolution which is computed via power-of-two FFTs of size at least
+/// 2`n` - 1.
+///
+/// The caller is expected to have checked that `values` contains at least two elements.
+pub(super) fn dft<B, E>(values: &[E], psi: B) -> Vec<E>
+where
+    B: StarkField,
+    E: FieldElement<BaseField = B>,
+{
+    let n = values.len();
+    let conv_size = (2 * n - 1).next_power_of_two();
+    assert!(
+        conv_size.ilog2() <= B::TWO_ADICITY,
+        "multiplicative subgroup of size {conv_size} does not exist in the specified base field"
+    );
+
+    // compute chirp values psi^(k^2) for all k in [0, n); since psi has order 2n, the exponents
+    // can be reduced modulo 2n
+    let psi_powers = get_power_series(psi, 2 * n);
+    let chirp = (0..n)
+        .map(|k| psi_powers[((k as u128 * k as u128) % (2 * n) as u128) as usize])
+        .collect::<Vec<_>>();
+
+    // build the first operand of the convolution: values[j] * psi^(j^2) padded with zeros
+    let mut a = values
+        .iter()
+        .zip(chirp.iter())
+        .map(|(&v, &c)| v.mul_base(c))
+        .collect::<Vec<_>>();
+    a.resize(conv_size, E::ZERO);
+
+    // build the second operand of the convolution: psi^(-m^2) at positions m and -m (modulo the
+    // size of the convolution) for all m in [0, n)
+    let inv_chirp = chirp.iter().map(|c| c.inv()).collect::<Vec<_>>();
+    let mut b = Vec::with_capacity(conv_size);
+    b.extend_from_slice(&inv_chirp);
+    b.resize(conv_size - n + 1, B::ZERO);
+    b.extend(inv_chirp[1..].iter().rev());
+
+    // compute the cyclic convolution via FFT
+    let twiddles = get_twiddles::<B>(conv_size);
+    evaluate_poly(&mut a, &twiddles);
+    evaluate_poly(&mut b, &twiddles);
+    for (a, &b) in a.iter_mut().zip(b.iter()) {
+        *a = a.mul_base(b);
+    }
+    let inv_twiddles = get_inv_twiddles::<B>(conv_size);
+    interpolate_poly(&mut a, &inv_twiddles);
+
+    // multiply the result of the convolution by the chirp values
+    a.truncate(n);
+    for (a, &c) in a.iter_mut().zip(chirp.iter()) {
+        *a = a.mul_base(c);
+    }
+    a
+}
+
+// ROOTS OF UNITY
+// ================================================================================================
+
+/// Returns a primitive root of unity `psi` of order 2`n` such that `psi^2` is the root of unity
+/// of order `n` described in [dft_arbitrary()](super::dft_arbitrary), or None if such a root does
+/// not exist in the field specified by `B`.
+///
+/// Let `n` = 2^`a` * `m` where `m` is odd. Then, `psi` is computed as the product of the root of
+/// unity of order 2^(`a` + 1) and the square root of `B::GENERATOR^((B::MODULUS - 1) / m)`
+/// which is also a root of unity of order `m`.
+pub(super) fn get_chirp_root<B: StarkField>(n: usize) -> Option<B> {
+    let a = n.trailing_zeros();
+    let m = n >> a;
+    if a + 1 > B::TWO_ADICITY {
+        return None;
+    }
+
+    // since m is odd, (g^((m + 1) / 2))^2 = g for any root of unity g of order m
+    let odd_root = get_generator_root::<B>(m)?;
+    let odd_root_sqrt = odd_root.exp((m as u64).div_ceil(2).into());
+
+    Some(B::get_root_of_unity(a + 1) * odd_root_sqrt)
+}
+
+/// Returns `B::GENERATOR^((B::MODULUS - 1) / order)`, or None if `order` does not divide
+/// `B::MODULUS - 1`.
+///
+/// Since `B::GENERATOR` generates the multiplicative group of the field, the returned value is a
+/// primitive root of unity of the specified order.
+fn get_generator_root<B: StarkField>(order: usize) -> Option<B> {
+    // compute (MODULUS - 1) / order via long division over the bytes of the modulus; since the
+    // modulus is an odd prime, subtracting one affects only the least significant byte
+    let mut modulus = B::get_modulus_le_bytes();
+    modulus[0] -= 1;
+
+    let divisor = order as u128;
+    let mut remainder = 0u128;
+    let mut quotient = vec![0u8; modulus.len()];
+    for (q, &byte) in quotient.iter_mut().zip(modulus.iter()).rev() {
+        remainder = (remainder << 8) | byte as u128;
+        *q = (remainder / divisor) as u8;
+        remainder %= divisor;
+    }
+
+    if remainder != 0 {
+        return None;
+    }
+
+    // raise the generator to the power of the quotient via square-and-multiply
+    let mut result = B::ONE;
+    for &byte in quotient.iter().rev() {
+        for i in (0..8).rev() {
+            result = result.square();
+            if (byte >> i) & 1 == 1 {
+                result *= B::GENERATOR;
+            }
+        }
+    }
+
+    Some(result)
+}
@@ -29,23 +29,54 @@ pub fn evaluate_poly_with_offset<B: StarkField, E: FieldElement<BaseField = B>>(
     domain_offset: B,
     blowup_factor: usize,
 ) -> Vec<E> {
+    let mut result = unsafe { uninit_vector(p.len() * blowup_factor) };
+    evaluate_poly_with_offset_into(p, twiddles, domain_offset, blowup_factor, &mut result);
+    result
+}
+
+/// Evaluates polynomial `p` using FFT algorithm and writes the result into `result`. The
+/// polynomial is evaluated over domain specified by `twiddles`, expanded by the `blowup_factor`,
+/// and shifted by the `domain_offset`.
+pub fn evaluate_poly_with_offset_into<B: StarkField, E: FieldElement<BaseField = B>>(
+    p: &[E],
+    twiddles: &[B],
+    domain_offset: B,
+    blowup_factor: usize,
+    result: &mut [E],
+) {
     let domain_size = p.len() * blowup_factor;
     let g = B::get_root_of_unity(domain_size.ilog2());
-    let mut result = unsafe { uninit_vector(domain_size) };
 
-    result
-        .as_mut_slice()
-        .par_chunks_mut(p.len())
-        .enumerate()
-        .for_each(|(i, chunk)| {
-            let idx = super::permute_index(blowup_factor, i) as u64;
-            let offset = g.exp(idx.into()) * domain_offset;
-            clone_and_shift(p, chunk, offset);
-            split_radix_fft(chunk, twiddles);
-        });
+    result.par_chunks_mut(p.len()).enumerate().for_each(|(i, chunk)| {
+        let idx = super::permute_index(blowup_factor, i) as u64;
+        let offset = g.exp(idx.into()) * domain_offset;
+        clone_and_shift(p, chunk, offset);
+        split_radix_fft(chunk, twiddles);
+    });
 
-    permute(&mut result);
-    result
+    permute(result);
+}
+
+/// Evaluates polynomial `p` using FFT algorithm over the domain specified by `twiddles` and
+/// shifted by the `domain_offset`; the evaluation is done in-place, meaning `p` is updated with
+/// results of the evaluation.
+pub fn evaluate_poly_with_offset_in_place<B: StarkField, E: FieldElement<BaseField = B>>(
+    p: &mut [E],
+    twiddles: &[B],
+    domain_offset: B,
+) {
+    let batch_size = p.len() / rayon::current_num_threads().next_power_of_two();
+
+    p.par_chunks_mut(batch_size).enumerate().for_each(|(i, batch)| {
+        let mut offset = domain_offset.exp(((i * batch_size) as u64).into());
+        for coeff in batch.iter_mut() {
+            *coeff = coeff.mul_base(offset);
+            offset *= domain_offset;
+        }
+    });
+
+    split_radix_fft(p, twiddles);
+    permute(p);
 }
 
 // POLYNOMIAL INTERPOLATION
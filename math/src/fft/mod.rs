@@ -10,6 +10,9 @@
 //! As compared to evaluation and interpolation functions available in the `polynom` module,
 //! these functions are much more efficient: their runtime complexity is O(`n` log `n`), where
 //! `n` is the domain size.
+//!
+//! The only exception is [dft_arbitrary()] function which computes a DFT over a domain of any
+//! size for which a suitable multiplicative subgroup exists in the field.
 
 use alloc::vec::Vec;
 
@@ -19,6 +22,7 @@ use crate::{
     utils::get_power_series,
 };
 
+mod bluestein;
 pub mod fft_inputs;
 pub mod real_u64;
 mod serial;
@@ -210,6 +214,172 @@ where
     result
 }
 
+/// Evaluates a polynomial on all points of the specified (shifted) domain using the FFT algorithm,
+/// and writes the results into the provided `result` slice.
+///
+/// This function is the same as [evaluate_poly_with_offset()], but instead of allocating a new
+/// vector for the evaluations, it writes the evaluations into `result`, which must be of length
+/// `p.len()` * `blowup_factor`. Thus, the same buffer can be reused to evaluate many polynomials.
+/// The initial contents of `result` are ignored.
+///
+/// When `concurrent` feature is enabled, the evaluation is done in multiple threads.
+///
+/// # Panics
+/// Panics if:
+/// * Length of `p` is not a power of two.
+/// * `blowup_factor` is not a power of two.
+/// * Length of `twiddles` is not `p.len()` / 2.
+/// * Length of `result` is not `p.len()` * `blowup_factor`.
+/// * Field specified by `B` does not contain a multiplicative subgroup of size `p.len()`.
+/// * `domain_offset` is ZERO.
+///
+/// # Examples
+/// ```
+/// # use winter_math::{polynom, fft::*};
+/// # use winter_math::{fields::{f128::BaseElement}, FieldElement, StarkField};
+/// # use rand_utils::rand_vector;
+/// let n = 2048;
+/// let offset = BaseElement::GENERATOR;
+/// let blowup_factor = 2;
+///
+/// // build a random polynomial
+/// let p: Vec<BaseElement> = rand_vector(n / blowup_factor);
+/// let twiddles = get_twiddles::<BaseElement>(p.len());
+/// let expected = evaluate_poly_with_offset(&p, &twiddles, offset, blowup_factor);
+///
+/// // evaluate the polynomial into a pre-allocated buffer
+/// let mut result = vec![BaseElement::ZERO; n];
+/// evaluate_poly_with_offset_into(&p, &twiddles, offset, blowup_factor, &mut result);
+///
+/// assert_eq!(expected, result);
+/// ```
+pub fn evaluate_poly_with_offset_into<B, E>(
+    p: &[E],
+    twiddles: &[B],
+    domain_offset: B,
+    blowup_factor: usize,
+    result: &mut [E],
+) where
+    B: StarkField,
+    E: FieldElement<BaseField = B>,
+{
+    assert!(p.len().is_power_of_two(), "number of coefficients must be a power of 2");
+    assert!(blowup_factor.is_power_of_two(), "blowup factor must be a power of 2");
+    assert_eq!(
+        p.len(),
+        twiddles.len() * 2,
+        "invalid number of twiddles: expected {} but received {}",
+        p.len() / 2,
+        twiddles.len()
+    );
+    assert_eq!(
+        p.len() * blowup_factor,
+        result.len(),
+        "invalid result length: expected {} but received {}",
+        p.len() * blowup_factor,
+        result.len()
+    );
+    assert!(
+        (p.len() * blowup_factor).ilog2() <= B::TWO_ADICITY,
+        "multiplicative subgroup of size {} does not exist in the specified base field",
+        p.len() * blowup_factor
+    );
+    assert_ne!(domain_offset, B::ZERO, "domain offset cannot be zero");
+
+    // when `concurrent` feature is enabled, run the concurrent version of the function; unless
+    // the polynomial is small, then don't bother with the concurrent version
+    if cfg!(feature = "concurrent") && p.len() >= MIN_CONCURRENT_SIZE {
+        #[cfg(feature = "concurrent")]
+        concurrent::evaluate_poly_with_offset_into(
+            p,
+            twiddles,
+            domain_offset,
+            blowup_factor,
+            result,
+        );
+    } else {
+        serial::evaluate_poly_with_offset_into(p, twiddles, domain_offset, blowup_factor, result);
+    }
+}
+
+/// Evaluates a polynomial on all points of the specified (shifted) domain using the FFT algorithm.
+///
+/// Uses the [FFT](https://en.wikipedia.org/wiki/Discrete_Fourier_transform_(general)) algorithm
+/// to evaluate polynomial `p` on all points of a domain defined by the length of `p` and shifted
+/// by the `domain_offset` in the field specified by the `B` type parameter. The evaluation is done
+/// in-place, meaning no additional memory is allocated and `p` is updated with results of the
+/// evaluation. The polynomial `p` is expected to be in coefficient form.
+///
+/// This is the inverse of [interpolate_poly_with_offset()], and is the same as
+/// [evaluate_poly_with_offset()] with `blowup_factor` set to 1, except that no memory is
+/// allocated.
+///
+/// The `twiddles` needed for evaluation can be obtained via `fft::get_twiddles()` function using
+/// `p.len()` as the domain size parameter. This implies that `twiddles.len()` must be equal to
+/// `p.len()` / 2.
+///
+/// When `concurrent` feature is enabled, the evaluation is done in multiple threads.
+///
+/// # Panics
+/// Panics if:
+/// * Length of `p` is not a power of two.
+/// * Length of `twiddles` is not `p.len()` / 2.
+/// * Field specified by `B` does not contain a multiplicative subgroup of size `p.len()`.
+/// * `domain_offset` is ZERO.
+///
+/// # Examples
+/// ```
+/// # use winter_math::{polynom, fft::*, get_power_series};
+/// # use winter_math::{fields::{f128::BaseElement}, FieldElement, StarkField};
+/// # use rand_utils::rand_vector;
+/// let n = 2048;
+/// let offset = BaseElement::GENERATOR;
+///
+/// // build a random polynomial
+/// let mut p: Vec<BaseElement> = rand_vector(n);
+///
+/// // evaluate the polynomial over the domain using regular polynomial evaluation
+/// let g = BaseElement::get_root_of_unity(n.ilog2());
+/// let domain = get_power_series(g, n);
+/// let shifted_domain = domain.iter().map(|&x| x * offset).collect::<Vec<_>>();
+/// let expected = polynom::eval_many(&p, &shifted_domain);
+///
+/// // evaluate the polynomial over the domain using FFT-based evaluation
+/// let twiddles = get_twiddles::<BaseElement>(p.len());
+/// evaluate_poly_with_offset_in_place(&mut p, &twiddles, offset);
+///
+/// assert_eq!(expected, p);
+/// ```
+pub fn evaluate_poly_with_offset_in_place<B, E>(p: &mut [E], twiddles: &[B], domain_offset: B)
+where
+    B: StarkField,
+    E: FieldElement<BaseField = B>,
+{
+    assert!(p.len().is_power_of_two(), "number of coefficients must be a power of 2");
+    assert_eq!(
+        p.len(),
+        twiddles.len() * 2,
+        "invalid number of twiddles: expected {} but received {}",
+        p.len() / 2,
+        twiddles.len()
+    );
+    assert!(
+        p.len().ilog2() <= B::TWO_ADICITY,
+        "multiplicative subgroup of size {} does not exist in the specified base field",
+        p.len()
+    );
+    assert_ne!(domain_offset, B::ZERO, "domain offset cannot be zero");
+
+    // when `concurrent` feature is enabled, run the concurrent version of the function; unless
+    // the polynomial is small, then don't bother with the concurrent version
+    if cfg!(feature = "concurrent") && p.len() >= MIN_CONCURRENT_SIZE {
+        #[cfg(feature = "concurrent")]
+        concurrent::evaluate_poly_with_offset_in_place(p, twiddles, domain_offset);
+    } else {
+        serial::evaluate_poly_with_offset_in_place(p, twiddles, domain_offset);
+    }
+}
+
 // POLYNOMIAL INTERPOLATION
 // ================================================================================================
 
@@ -430,6 +600,84 @@ where
     values.permute();
 }
 
+// ARBITRARY-LENGTH DFT
+// ================================================================================================
+
+/// Computes the discrete Fourier transform of the provided values for domains of arbitrary size.
+///
+/// The result is a vector `X` of the same length `n` as `values` such that:
+///
+/// `X[k] = sum_j values[j] * g^(j * k)`
+///
+/// where `g` is a primitive root of unity of order `n` in the field specified by the `B` type
+/// parameter. Thus, if `values` are coefficients of a polynomial, `X[k]` is the evaluation of this
+/// polynomial at `g^k`.
+///
+/// Let `n` = 2^`a` * `m` where `m` is odd. Then, `g` is computed as the product of
+/// `B::get_root_of_unity(a)` and `B::GENERATOR^((B::MODULUS - 1) / m)`. This way, for all sizes
+/// which are powers of two, `g` is the same root of unity as the one used by the radix-2 FFT.
+///
+/// When `n` is a power of two, the transform is delegated to [evaluate_poly()] and the result is
+/// the same as the one produced by that function. Otherwise, the transform is computed using
+/// [Bluestein's algorithm](https://en.wikipedia.org/wiki/Chirp_Z-transform#Bluestein's_algorithm)
+/// which reduces it to a convolution over a power-of-two domain of size at least 2`n` - 1. Thus,
+/// the complexity of the transform is O(`n` log(`n`)) for all values of `n`, though for sizes
+/// which are not powers of two it is several times slower than [evaluate_poly()] for a domain
+/// of comparable size.
+///
+/// This function allocates memory for the result and does not modify `values`.
+///
+/// # Panics
+/// Panics if:
+/// * `n` is not a power of two and the field specified by `B` does not contain a multiplicative
+///   subgroup of size 2`n`.
+/// * `n` is not a power of two and the field specified by `B` does not contain a multiplicative
+///   subgroup of size equal to the smallest power of two greater than or equal to 2`n` - 1.
+/// * `n` is a power of two and the field specified by `B` does not contain a multiplicative
+///   subgroup of size `n`.
+///
+/// # Examples
+/// ```
+/// # use winter_math::{polynom, fft::*, get_power_series};
+/// # use winter_math::{fields::{f64::BaseElement}, FieldElement, StarkField};
+/// # use rand_utils::rand_vector;
+/// let n = 15;
+///
+/// // build a random polynomial
+/// let p: Vec<BaseElement> = rand_vector(n);
+///
+/// // evaluate the polynomial over a multiplicative subgroup of size 15 directly
+/// let g = BaseElement::GENERATOR.exp((BaseElement::MODULUS - 1) / n as u64);
+/// let domain = get_power_series(g, n);
+/// let expected = polynom::eval_many(&p, &domain);
+///
+/// assert_eq!(expected, dft_arbitrary(&p));
+/// ```
+pub fn dft_arbitrary<B, E>(values: &[E]) -> Vec<E>
+where
+    B: StarkField,
+    E: FieldElement<BaseField = B>,
+{
+    let n = values.len();
+    if n <= 1 {
+        return values.to_vec();
+    }
+
+    if n.is_power_of_two() {
+        let mut result = values.to_vec();
+        evaluate_poly(&mut result, &get_twiddles::<B>(n));
+        return result;
+    }
+
+    let psi = bluestein::get_chirp_root::<B>(n).unwrap_or_else(|| {
+        panic!(
+            "multiplicative subgroup of size {} does not exist in the specified base field",
+            2 * n
+        )
+    });
+    bluestein::dft(values, psi)
+}
+
 // TWIDDLES
 // ================================================================================================
 
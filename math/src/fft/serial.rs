@@ -35,12 +35,29 @@ pub fn evaluate_poly_with_offset<B, E>(
 where
     B: StarkField,
     E: FieldElement<BaseField = B>,
+{
+    let mut result = unsafe { uninit_vector(p.len() * blowup_factor) };
+    evaluate_poly_with_offset_into(p, twiddles, domain_offset, blowup_factor, &mut result);
+    result
+}
+
+/// Evaluates polynomial `p` over the domain of length `p.len()` * `blowup_factor` shifted by
+/// `domain_offset` in the field specified `B` using the FFT algorithm and writes the result into
+/// `result`.
+pub fn evaluate_poly_with_offset_into<B, E>(
+    p: &[E],
+    twiddles: &[B],
+    domain_offset: B,
+    blowup_factor: usize,
+    result: &mut [E],
+) where
+    B: StarkField,
+    E: FieldElement<BaseField = B>,
 {
     let domain_size = p.len() * blowup_factor;
     let g = B::get_root_of_unity(domain_size.ilog2());
-    let mut result = unsafe { uninit_vector(domain_size) };
 
-    result.as_mut_slice().chunks_mut(p.len()).enumerate().for_each(|(i, chunk)| {
+    result.chunks_mut(p.len()).enumerate().for_each(|(i, chunk)| {
         let idx = super::permute_index(blowup_factor, i) as u64;
         let offset = g.exp(idx.into()) * domain_offset;
         let mut factor = E::BaseField::ONE;
@@ -52,7 +69,18 @@ where
     });
 
     result.permute();
-    result
+}
+
+/// Evaluates polynomial `p` in-place over the domain of length `p.len()` shifted by
+/// `domain_offset` in the field specified by `B` using the FFT algorithm.
+pub fn evaluate_poly_with_offset_in_place<B, E>(p: &mut [E], twiddles: &[B], domain_offset: B)
+where
+    B: StarkField,
+    E: FieldElement<BaseField = B>,
+{
+    p.shift_by_series(B::ONE, domain_offset);
+    p.fft_in_place(twiddles);
+    p.permute();
 }
 
 // POLYNOMIAL INTERPOLATION
@@ -9,7 +9,7 @@ use rand_utils::rand_vector;
 
 use crate::{
     fft::fft_inputs::FftInputs,
-    field::{f128::BaseElement, StarkField},
+    field::{f128::BaseElement, f64, FieldElement, QuadExtension, StarkField},
     polynom,
     utils::get_power_series,
 };
@@ -60,6 +60,33 @@ fn fft_in_place() {
     assert_eq!(expected, p);
 }
 
+#[test]
+fn fft_evaluate_poly_with_offset_without_allocation() {
+    let offset = BaseElement::GENERATOR;
+    let blowup_factor = 4;
+
+    // cover both the serial and (when enabled) the concurrent versions
+    for n in [16, super::MIN_CONCURRENT_SIZE * 2] {
+        let p: Vec<BaseElement> = rand_vector(n);
+        let twiddles = super::get_twiddles::<BaseElement>(n);
+
+        let expected = super::evaluate_poly_with_offset(&p, &twiddles, offset, blowup_factor);
+        let mut result = vec![BaseElement::ZERO; n * blowup_factor];
+        super::evaluate_poly_with_offset_into(&p, &twiddles, offset, blowup_factor, &mut result);
+        assert_eq!(expected, result);
+
+        let expected = super::evaluate_poly_with_offset(&p, &twiddles, offset, 1);
+        let mut result = p.clone();
+        super::evaluate_poly_with_offset_in_place(&mut result, &twiddles, offset);
+        assert_eq!(expected, result);
+
+        // interpolation with the same offset recovers the original polynomial
+        let inv_twiddles = super::get_inv_twiddles::<BaseElement>(n);
+        super::interpolate_poly_with_offset(&mut result, &inv_twiddles, offset);
+        assert_eq!(p, result);
+    }
+}
+
 #[test]
 fn fft_get_twiddles() {
     let n = super::MIN_CONCURRENT_SIZE * 2;
@@ -72,9 +99,48 @@ fn fft_get_twiddles() {
     assert_eq!(expected, twiddles);
 }
 
+// ARBITRARY-LENGTH DFT
+// ================================================================================================
+
+#[test]
+fn fft_dft_arbitrary() {
+    // p - 1 = 2^32 * 3 * 5 * 17 * 257 * 65537 for the 64-bit field
+    assert!(super::dft_arbitrary::<f64::BaseElement, f64::BaseElement>(&[]).is_empty());
+    for n in [1, 2, 3, 5, 6, 12, 15, 16, 17, 24, 51, 255, 257, 1020] {
+        let p: Vec<f64::BaseElement> = rand_vector(n);
+        let expected = naive_dft(&p);
+        assert_eq!(expected, super::dft_arbitrary(&p), "DFT mismatch for size {n}");
+    }
+
+    // values in an extension field
+    let n = 85;
+    let p: Vec<QuadExtension<f64::BaseElement>> = rand_vector(n);
+    assert_eq!(naive_dft(&p), super::dft_arbitrary(&p));
+}
+
+#[test]
+#[should_panic(expected = "multiplicative subgroup of size 14 does not exist")]
+fn fft_dft_arbitrary_no_root() {
+    let p: Vec<f64::BaseElement> = rand_vector(7);
+    super::dft_arbitrary(&p);
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
+fn naive_dft<E: FieldElement<BaseField = f64::BaseElement>>(values: &[E]) -> Vec<E> {
+    let a = values.len().trailing_zeros();
+    let m = (values.len() >> a) as u64;
+    let g = f64::BaseElement::GENERATOR.exp((f64::BaseElement::MODULUS - 1) / m);
+    let g = if a == 0 {
+        g
+    } else {
+        g * f64::BaseElement::get_root_of_unity(a)
+    };
+    let domain = get_power_series(E::from(g), values.len());
+    polynom::eval_many(values, &domain)
+}
+
 fn build_domain(size: usize) -> Vec<BaseElement> {
     let g = BaseElement::get_root_of_unity(size.ilog2());
     get_power_series(g, size)
@@ -12,6 +12,7 @@ use core::{
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use subtle::{Choice, ConstantTimeEq};
 use utils::{
     AsBytes, ByteReader, ByteWriter, Deserializable, DeserializationError, Randomizable,
     Serializable, SliceReader,
@@ -96,6 +97,20 @@ impl<B: ExtensibleField<3>> FieldElement for CubeExtension<B> {
         Self(numerator[0] * denom_inv, numerator[1] * denom_inv, numerator[2] * denom_inv)
     }
 
+    #[inline]
+    fn inv_or_zero(self) -> Self {
+        // same as inv(), but the norm of ZERO (which is ZERO) is inverted to ZERO, and thus, the
+        // check for ZERO is not needed
+        let x = [self.0, self.1, self.2];
+        let c1 = <B as ExtensibleField<3>>::frobenius(x);
+        let c2 = <B as ExtensibleField<3>>::frobenius(c1);
+        let numerator = <B as ExtensibleField<3>>::mul(c1, c2);
+        let norm = <B as ExtensibleField<3>>::mul(x, numerator);
+        let denom_inv = norm[0].inv_or_zero();
+
+        Self(numerator[0] * denom_inv, numerator[1] * denom_inv, numerator[2] * denom_inv)
+    }
+
     #[inline]
     fn conjugate(&self) -> Self {
         let result = <B as ExtensibleField<3>>::frobenius([self.0, self.1, self.2]);
@@ -188,6 +203,16 @@ impl<B: ExtensibleField<3>> fmt::Display for CubeExtension<B> {
     }
 }
 
+// EQUALITY CHECKS
+// ================================================================================================
+
+impl<B: ExtensibleField<3>> ConstantTimeEq for CubeExtension<B> {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0) & self.1.ct_eq(&other.1) & self.2.ct_eq(&other.2)
+    }
+}
+
 // OVERLOADED OPERATORS
 // ------------------------------------------------------------------------------------------------
 
@@ -362,6 +387,10 @@ impl<B: ExtensibleField<3>> Serializable for CubeExtension<B> {
         self.1.write_into(target);
         self.2.write_into(target);
     }
+
+    fn get_size_hint(&self) -> usize {
+        Self::ELEMENT_BYTES
+    }
 }
 
 impl<B: ExtensibleField<3>> Deserializable for CubeExtension<B> {
@@ -380,7 +409,7 @@ impl<B: ExtensibleField<3>> Deserializable for CubeExtension<B> {
 mod tests {
     use rand_utils::rand_value;
 
-    use super::{CubeExtension, DeserializationError, FieldElement};
+    use super::{CubeExtension, DeserializationError, FieldElement, Serializable};
     use crate::field::f64::BaseElement;
 
     // BASIC ALGEBRA
@@ -417,6 +446,15 @@ mod tests {
     // SERIALIZATION / DESERIALIZATION
     // --------------------------------------------------------------------------------------------
 
+    #[test]
+    fn element_bytes() {
+        assert_eq!(24, CubeExtension::<BaseElement>::ELEMENT_BYTES);
+
+        let r: CubeExtension<BaseElement> = rand_value();
+        assert_eq!(CubeExtension::<BaseElement>::ELEMENT_BYTES, r.to_bytes().len());
+        assert_eq!(CubeExtension::<BaseElement>::ELEMENT_BYTES, r.get_size_hint());
+    }
+
     #[test]
     fn elements_as_bytes() {
         let source = vec![
@@ -12,6 +12,7 @@ use core::{
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use subtle::{Choice, ConstantTimeEq};
 use utils::{
     AsBytes, ByteReader, ByteWriter, Deserializable, DeserializationError, Randomizable,
     Serializable, SliceReader,
@@ -93,6 +94,18 @@ impl<B: ExtensibleField<2>> FieldElement for QuadExtension<B> {
         Self(numerator[0] * denom_inv, numerator[1] * denom_inv)
     }
 
+    #[inline]
+    fn inv_or_zero(self) -> Self {
+        // same as inv(), but the norm of ZERO (which is ZERO) is inverted to ZERO, and thus, the
+        // check for ZERO is not needed
+        let x = [self.0, self.1];
+        let numerator = <B as ExtensibleField<2>>::frobenius(x);
+        let norm = <B as ExtensibleField<2>>::mul(x, numerator);
+        let denom_inv = norm[0].inv_or_zero();
+
+        Self(numerator[0] * denom_inv, numerator[1] * denom_inv)
+    }
+
     #[inline]
     fn conjugate(&self) -> Self {
         let result = <B as ExtensibleField<2>>::frobenius([self.0, self.1]);
@@ -184,6 +197,16 @@ impl<B: ExtensibleField<2>> fmt::Display for QuadExtension<B> {
     }
 }
 
+// EQUALITY CHECKS
+// ================================================================================================
+
+impl<B: ExtensibleField<2>> ConstantTimeEq for QuadExtension<B> {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0) & self.1.ct_eq(&other.1)
+    }
+}
+
 // OVERLOADED OPERATORS
 // ------------------------------------------------------------------------------------------------
 
@@ -356,6 +379,10 @@ impl<B: ExtensibleField<2>> Serializable for QuadExtension<B> {
         self.0.write_into(target);
         self.1.write_into(target);
     }
+
+    fn get_size_hint(&self) -> usize {
+        Self::ELEMENT_BYTES
+    }
 }
 
 impl<B: ExtensibleField<2>> Deserializable for QuadExtension<B> {
@@ -373,7 +400,7 @@ impl<B: ExtensibleField<2>> Deserializable for QuadExtension<B> {
 mod tests {
     use rand_utils::rand_value;
 
-    use super::{DeserializationError, FieldElement, QuadExtension};
+    use super::{DeserializationError, FieldElement, QuadExtension, Serializable};
     use crate::field::f64::BaseElement;
 
     // BASIC ALGEBRA
@@ -410,6 +437,15 @@ mod tests {
     // SERIALIZATION / DESERIALIZATION
     // --------------------------------------------------------------------------------------------
 
+    #[test]
+    fn element_bytes() {
+        assert_eq!(16, QuadExtension::<BaseElement>::ELEMENT_BYTES);
+
+        let r: QuadExtension<BaseElement> = rand_value();
+        assert_eq!(QuadExtension::<BaseElement>::ELEMENT_BYTES, r.to_bytes().len());
+        assert_eq!(QuadExtension::<BaseElement>::ELEMENT_BYTES, r.get_size_hint());
+    }
+
     #[test]
     fn elements_as_bytes() {
         let source = vec![
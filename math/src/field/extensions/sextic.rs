@@ -0,0 +1,517 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::string::{String, ToString};
+use core::{
+    fmt,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    slice,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use subtle::{Choice, ConstantTimeEq};
+use utils::{
+    AsBytes, ByteReader, ByteWriter, Deserializable, DeserializationError, Randomizable,
+    Serializable, SliceReader,
+};
+
+use super::{ExtensibleField, ExtensionOf, FieldElement};
+
+// SEXTIC EXTENSION FIELD
+// ================================================================================================
+
+/// Represents an element in a degree 6 extension of a [StarkField](crate::StarkField).
+///
+/// The extension element is defined by 6 base field elements (c<sub>0</sub>, ..., c<sub>5</sub>).
+/// The way these coefficients are interpreted (and thus, the irreducible polynomial or tower of
+/// extensions over which the field is defined) is determined by the implementation of the
+/// [ExtensibleField] trait for the base field.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct SexticExtension<B: ExtensibleField<6>>([B; 6]);
+
+impl<B: ExtensibleField<6>> SexticExtension<B> {
+    /// Returns a new extension element instantiated from the provided base elements.
+    pub const fn new(elements: [B; 6]) -> Self {
+        Self(elements)
+    }
+
+    /// Returns true if the base field specified by B type parameter supports sextic extensions.
+    pub fn is_supported() -> bool {
+        <B as ExtensibleField<6>>::is_supported()
+    }
+
+    /// Returns an array of base field elements comprising this extension field element.
+    ///
+    /// The order of base elements in the returned array is the same as the order in which
+    /// the elements are provided to the [SexticExtension::new()] constructor.
+    pub const fn to_base_elements(self) -> [B; 6] {
+        self.0
+    }
+}
+
+impl<B: ExtensibleField<6>> FieldElement for SexticExtension<B> {
+    type PositiveInteger = B::PositiveInteger;
+    type BaseField = B;
+
+    const EXTENSION_DEGREE: usize = 6;
+
+    const ELEMENT_BYTES: usize = B::ELEMENT_BYTES * Self::EXTENSION_DEGREE;
+    const IS_CANONICAL: bool = B::IS_CANONICAL;
+    const ZERO: Self = Self([B::ZERO; 6]);
+    const ONE: Self = Self([B::ONE, B::ZERO, B::ZERO, B::ZERO, B::ZERO, B::ZERO]);
+
+    // ALGEBRA
+    // --------------------------------------------------------------------------------------------
+
+    #[inline]
+    fn double(self) -> Self {
+        Self(self.0.map(|a| a.double()))
+    }
+
+    #[inline]
+    fn square(self) -> Self {
+        Self(<B as ExtensibleField<6>>::square(self.0))
+    }
+
+    #[inline]
+    fn inv(self) -> Self {
+        if self == Self::ZERO {
+            return self;
+        }
+
+        // the inverse is computed as x^(r - 1) / x^r, where r = 1 + p + ... + p^5; x^r is the
+        // norm of x and is in the base field, while x^(r - 1) is the product of all conjugates
+        // of x distinct from x itself.
+        let x = self.0;
+        let mut conjugate = <B as ExtensibleField<6>>::frobenius(x);
+        let mut numerator = conjugate;
+        for _ in 0..4 {
+            conjugate = <B as ExtensibleField<6>>::frobenius(conjugate);
+            numerator = <B as ExtensibleField<6>>::mul(numerator, conjugate);
+        }
+
+        let norm = <B as ExtensibleField<6>>::mul(x, numerator);
+        debug_assert!(norm[1..].iter().all(|&c| c == B::ZERO), "norm must be in the base field");
+        let denom_inv = norm[0].inv();
+
+        Self(<B as ExtensibleField<6>>::mul_base(numerator, denom_inv))
+    }
+
+    #[inline]
+    fn inv_or_zero(self) -> Self {
+        // same as inv(), but the norm of ZERO (which is ZERO) is inverted to ZERO, and thus, the
+        // check for ZERO is not needed
+        let x = self.0;
+        let mut conjugate = <B as ExtensibleField<6>>::frobenius(x);
+        let mut numerator = conjugate;
+        for _ in 0..4 {
+            conjugate = <B as ExtensibleField<6>>::frobenius(conjugate);
+            numerator = <B as ExtensibleField<6>>::mul(numerator, conjugate);
+        }
+        let norm = <B as ExtensibleField<6>>::mul(x, numerator);
+        let denom_inv = norm[0].inv_or_zero();
+
+        Self(<B as ExtensibleField<6>>::mul_base(numerator, denom_inv))
+    }
+
+    #[inline]
+    fn conjugate(&self) -> Self {
+        Self(<B as ExtensibleField<6>>::frobenius(self.0))
+    }
+
+    // BASE ELEMENT CONVERSIONS
+    // --------------------------------------------------------------------------------------------
+
+    fn base_element(&self, i: usize) -> Self::BaseField {
+        match i {
+            0..=5 => self.0[i],
+            _ => panic!("element index must be smaller than 6, but was {i}"),
+        }
+    }
+
+    fn slice_as_base_elements(elements: &[Self]) -> &[Self::BaseField] {
+        let ptr = elements.as_ptr();
+        let len = elements.len() * Self::EXTENSION_DEGREE;
+        unsafe { slice::from_raw_parts(ptr as *const Self::BaseField, len) }
+    }
+
+    fn slice_from_base_elements(elements: &[Self::BaseField]) -> &[Self] {
+        assert!(
+            elements.len() % Self::EXTENSION_DEGREE == 0,
+            "number of base elements must be divisible by 6, but was {}",
+            elements.len()
+        );
+
+        let ptr = elements.as_ptr();
+        let len = elements.len() / Self::EXTENSION_DEGREE;
+        unsafe { slice::from_raw_parts(ptr as *const Self, len) }
+    }
+
+    // SERIALIZATION / DESERIALIZATION
+    // --------------------------------------------------------------------------------------------
+
+    fn elements_as_bytes(elements: &[Self]) -> &[u8] {
+        unsafe {
+            slice::from_raw_parts(
+                elements.as_ptr() as *const u8,
+                elements.len() * Self::ELEMENT_BYTES,
+            )
+        }
+    }
+
+    unsafe fn bytes_as_elements(bytes: &[u8]) -> Result<&[Self], DeserializationError> {
+        if bytes.len() % Self::ELEMENT_BYTES != 0 {
+            return Err(DeserializationError::InvalidValue(format!(
+                "number of bytes ({}) does not divide into whole number of field elements",
+                bytes.len(),
+            )));
+        }
+
+        let p = bytes.as_ptr();
+        let len = bytes.len() / Self::ELEMENT_BYTES;
+
+        // make sure the bytes are aligned on the boundary consistent with base element alignment
+        if (p as usize) % Self::BaseField::ELEMENT_BYTES != 0 {
+            return Err(DeserializationError::InvalidValue(
+                "slice memory alignment is not valid for this field element type".to_string(),
+            ));
+        }
+
+        Ok(slice::from_raw_parts(p as *const Self, len))
+    }
+}
+
+impl<B: ExtensibleField<6>> ExtensionOf<B> for SexticExtension<B> {
+    #[inline(always)]
+    fn mul_base(self, other: B) -> Self {
+        Self(<B as ExtensibleField<6>>::mul_base(self.0, other))
+    }
+}
+
+impl<B: ExtensibleField<6>> Randomizable for SexticExtension<B> {
+    const VALUE_SIZE: usize = Self::ELEMENT_BYTES;
+
+    fn from_random_bytes(bytes: &[u8]) -> Option<Self> {
+        Self::try_from(bytes).ok()
+    }
+}
+
+impl<B: ExtensibleField<6>> fmt::Display for SexticExtension<B> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let [c0, c1, c2, c3, c4, c5] = self.0;
+        write!(f, "({c0}, {c1}, {c2}, {c3}, {c4}, {c5})")
+    }
+}
+
+// EQUALITY CHECKS
+// ================================================================================================
+
+impl<B: ExtensibleField<6>> ConstantTimeEq for SexticExtension<B> {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0
+            .iter()
+            .zip(other.0.iter())
+            .fold(Choice::from(1), |acc, (a, b)| acc & a.ct_eq(b))
+    }
+}
+
+// OVERLOADED OPERATORS
+// ------------------------------------------------------------------------------------------------
+
+impl<B: ExtensibleField<6>> Add for SexticExtension<B> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self(core::array::from_fn(|i| self.0[i] + rhs.0[i]))
+    }
+}
+
+impl<B: ExtensibleField<6>> AddAssign for SexticExtension<B> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+impl<B: ExtensibleField<6>> Sub for SexticExtension<B> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self(core::array::from_fn(|i| self.0[i] - rhs.0[i]))
+    }
+}
+
+impl<B: ExtensibleField<6>> SubAssign for SexticExtension<B> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<B: ExtensibleField<6>> Mul for SexticExtension<B> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self(<B as ExtensibleField<6>>::mul(self.0, rhs.0))
+    }
+}
+
+impl<B: ExtensibleField<6>> MulAssign for SexticExtension<B> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs
+    }
+}
+
+impl<B: ExtensibleField<6>> Div for SexticExtension<B> {
+    type Output = Self;
+
+    #[inline]
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        self * rhs.inv()
+    }
+}
+
+impl<B: ExtensibleField<6>> DivAssign for SexticExtension<B> {
+    #[inline]
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs
+    }
+}
+
+impl<B: ExtensibleField<6>> Neg for SexticExtension<B> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self(self.0.map(|a| -a))
+    }
+}
+
+// TYPE CONVERSIONS
+// ------------------------------------------------------------------------------------------------
+
+impl<B: ExtensibleField<6>> From<B> for SexticExtension<B> {
+    fn from(value: B) -> Self {
+        Self([value, B::ZERO, B::ZERO, B::ZERO, B::ZERO, B::ZERO])
+    }
+}
+
+impl<B: ExtensibleField<6>> From<u32> for SexticExtension<B> {
+    fn from(value: u32) -> Self {
+        Self::from(B::from(value))
+    }
+}
+
+impl<B: ExtensibleField<6>> From<u16> for SexticExtension<B> {
+    fn from(value: u16) -> Self {
+        Self::from(B::from(value))
+    }
+}
+
+impl<B: ExtensibleField<6>> From<u8> for SexticExtension<B> {
+    fn from(value: u8) -> Self {
+        Self::from(B::from(value))
+    }
+}
+
+impl<B: ExtensibleField<6>> TryFrom<u64> for SexticExtension<B> {
+    type Error = String;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        match B::try_from(value) {
+            Ok(elem) => Ok(Self::from(elem)),
+            Err(_) => Err(format!(
+                "invalid field element: value {value} is greater than or equal to the field modulus"
+            )),
+        }
+    }
+}
+
+impl<B: ExtensibleField<6>> TryFrom<u128> for SexticExtension<B> {
+    type Error = String;
+
+    fn try_from(value: u128) -> Result<Self, Self::Error> {
+        match B::try_from(value) {
+            Ok(elem) => Ok(Self::from(elem)),
+            Err(_) => Err(format!(
+                "invalid field element: value {value} is greater than or equal to the field modulus"
+            )),
+        }
+    }
+}
+
+impl<B: ExtensibleField<6>> TryFrom<&'_ [u8]> for SexticExtension<B> {
+    type Error = DeserializationError;
+
+    /// Converts a slice of bytes into a field element; returns error if the value encoded in bytes
+    /// is not a valid field element. The bytes are assumed to be in little-endian byte order.
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        if bytes.len() < Self::ELEMENT_BYTES {
+            return Err(DeserializationError::InvalidValue(format!(
+                "not enough bytes for a full field element; expected {} bytes, but was {} bytes",
+                Self::ELEMENT_BYTES,
+                bytes.len(),
+            )));
+        }
+        if bytes.len() > Self::ELEMENT_BYTES {
+            return Err(DeserializationError::InvalidValue(format!(
+                "too many bytes for a field element; expected {} bytes, but was {} bytes",
+                Self::ELEMENT_BYTES,
+                bytes.len(),
+            )));
+        }
+        let mut reader = SliceReader::new(bytes);
+        Self::read_from(&mut reader)
+    }
+}
+
+impl<B: ExtensibleField<6>> AsBytes for SexticExtension<B> {
+    fn as_bytes(&self) -> &[u8] {
+        // TODO: take endianness into account
+        let self_ptr: *const Self = self;
+        unsafe { slice::from_raw_parts(self_ptr as *const u8, Self::ELEMENT_BYTES) }
+    }
+}
+
+// SERIALIZATION / DESERIALIZATION
+// ------------------------------------------------------------------------------------------------
+
+impl<B: ExtensibleField<6>> Serializable for SexticExtension<B> {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.0.write_into(target);
+    }
+
+    fn get_size_hint(&self) -> usize {
+        Self::ELEMENT_BYTES
+    }
+}
+
+impl<B: ExtensibleField<6>> Deserializable for SexticExtension<B> {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self(<[B; 6]>::read_from(source)?))
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use rand_utils::rand_value;
+
+    use super::{DeserializationError, FieldElement, Serializable, SexticExtension};
+    use crate::field::f64::BaseElement;
+
+    // BASIC ALGEBRA
+    // --------------------------------------------------------------------------------------------
+
+    #[test]
+    fn add() {
+        // identity
+        let r: SexticExtension<BaseElement> = rand_value();
+        assert_eq!(r, r + SexticExtension::<BaseElement>::ZERO);
+
+        // test random values
+        let r1: SexticExtension<BaseElement> = rand_value();
+        let r2: SexticExtension<BaseElement> = rand_value();
+
+        let expected = SexticExtension(core::array::from_fn(|i| r1.0[i] + r2.0[i]));
+        assert_eq!(expected, r1 + r2);
+    }
+
+    #[test]
+    fn sub() {
+        // identity
+        let r: SexticExtension<BaseElement> = rand_value();
+        assert_eq!(r, r - SexticExtension::<BaseElement>::ZERO);
+
+        // test random values
+        let r1: SexticExtension<BaseElement> = rand_value();
+        let r2: SexticExtension<BaseElement> = rand_value();
+
+        let expected = SexticExtension(core::array::from_fn(|i| r1.0[i] - r2.0[i]));
+        assert_eq!(expected, r1 - r2);
+    }
+
+    // SERIALIZATION / DESERIALIZATION
+    // --------------------------------------------------------------------------------------------
+
+    #[test]
+    fn element_bytes() {
+        assert_eq!(48, SexticExtension::<BaseElement>::ELEMENT_BYTES);
+
+        let r: SexticExtension<BaseElement> = rand_value();
+        assert_eq!(SexticExtension::<BaseElement>::ELEMENT_BYTES, r.to_bytes().len());
+        assert_eq!(SexticExtension::<BaseElement>::ELEMENT_BYTES, r.get_size_hint());
+    }
+
+    #[test]
+    fn elements_as_bytes() {
+        let source = vec![
+            SexticExtension(core::array::from_fn(|i| BaseElement::new(i as u64 + 1))),
+            SexticExtension(core::array::from_fn(|i| BaseElement::new(i as u64 + 7))),
+        ];
+
+        let mut expected = vec![];
+        for element in source.iter() {
+            for c in element.0 {
+                expected.extend_from_slice(&c.inner().to_le_bytes());
+            }
+        }
+
+        assert_eq!(expected, SexticExtension::<BaseElement>::elements_as_bytes(&source));
+    }
+
+    #[test]
+    fn bytes_as_elements() {
+        let elements = vec![
+            SexticExtension(core::array::from_fn(|i| BaseElement::new(i as u64 + 1))),
+            SexticExtension(core::array::from_fn(|i| BaseElement::new(i as u64 + 7))),
+        ];
+
+        let mut bytes = vec![];
+        for element in elements.iter() {
+            for c in element.0 {
+                bytes.extend_from_slice(&c.inner().to_le_bytes());
+            }
+        }
+        bytes.extend_from_slice(&BaseElement::new(5).inner().to_le_bytes());
+
+        let result = unsafe { SexticExtension::<BaseElement>::bytes_as_elements(&bytes[..96]) };
+        assert!(result.is_ok());
+        assert_eq!(elements, result.unwrap());
+
+        let result = unsafe { SexticExtension::<BaseElement>::bytes_as_elements(&bytes) };
+        assert!(matches!(result, Err(DeserializationError::InvalidValue(_))));
+
+        let result = unsafe { SexticExtension::<BaseElement>::bytes_as_elements(&bytes[1..]) };
+        assert!(matches!(result, Err(DeserializationError::InvalidValue(_))));
+    }
+
+    // UTILITIES
+    // --------------------------------------------------------------------------------------------
+
+    #[test]
+    fn as_base_elements() {
+        let elements = vec![
+            SexticExtension(core::array::from_fn(|i| BaseElement::new(i as u64 + 1))),
+            SexticExtension(core::array::from_fn(|i| BaseElement::new(i as u64 + 7))),
+        ];
+
+        let expected = (1..13).map(BaseElement::new).collect::<Vec<_>>();
+        assert_eq!(expected, SexticExtension::<BaseElement>::slice_as_base_elements(&elements));
+    }
+}
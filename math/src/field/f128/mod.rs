@@ -23,9 +23,10 @@ use core::{
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use subtle::{Choice, ConstantTimeEq};
 use utils::{
-    AsBytes, ByteReader, ByteWriter, Deserializable, DeserializationError, Randomizable,
-    Serializable,
+    accounting, AsBytes, ByteReader, ByteWriter, Deserializable, DeserializationError,
+    Randomizable, Serializable,
 };
 
 use super::{ExtensibleField, FieldElement, StarkField};
@@ -86,6 +87,20 @@ impl FieldElement for BaseElement {
         BaseElement(inv(self.0))
     }
 
+    fn inv_or_zero(self) -> Self {
+        // compute base^(M - 2) via square-and-multiply; the branches depend only on the bits of
+        // the (public) exponent, and ZERO is mapped to ZERO
+        const POWER: u128 = M - 2;
+        let mut result = Self::ONE;
+        for i in (0..128 - POWER.leading_zeros()).rev() {
+            result = result.square();
+            if (POWER >> i) & 1 == 1 {
+                result *= self;
+            }
+        }
+        result
+    }
+
     fn conjugate(&self) -> Self {
         BaseElement(self.0)
     }
@@ -191,6 +206,19 @@ impl Display for BaseElement {
     }
 }
 
+// EQUALITY CHECKS
+// ================================================================================================
+
+impl ConstantTimeEq for BaseElement {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        // internal values are always in canonical form, and thus, can be compared directly
+        let (lhs_lo, lhs_hi) = (self.0 as u64, (self.0 >> 64) as u64);
+        let (rhs_lo, rhs_hi) = (other.0 as u64, (other.0 >> 64) as u64);
+        lhs_lo.ct_eq(&rhs_lo) & lhs_hi.ct_eq(&rhs_hi)
+    }
+}
+
 // OVERLOADED OPERATORS
 // ================================================================================================
 
@@ -226,6 +254,7 @@ impl Mul for BaseElement {
     type Output = Self;
 
     fn mul(self, rhs: Self) -> Self {
+        accounting::record_field_mul();
         Self(mul(self.0, rhs.0))
     }
 }
@@ -307,6 +336,30 @@ impl ExtensibleField<3> for BaseElement {
     }
 }
 
+// SEXTIC EXTENSION
+// ================================================================================================
+
+/// Sextic extension for this field is not implemented.
+impl ExtensibleField<6> for BaseElement {
+    fn mul(_a: [Self; 6], _b: [Self; 6]) -> [Self; 6] {
+        unimplemented!()
+    }
+
+    #[inline(always)]
+    fn mul_base(_a: [Self; 6], _b: Self) -> [Self; 6] {
+        unimplemented!()
+    }
+
+    #[inline(always)]
+    fn frobenius(_x: [Self; 6]) -> [Self; 6] {
+        unimplemented!()
+    }
+
+    fn is_supported() -> bool {
+        false
+    }
+}
+
 // TYPE CONVERSIONS
 // ================================================================================================
 
@@ -10,7 +10,10 @@ use rand_utils::{rand_value, rand_vector};
 use utils::SliceReader;
 
 use super::{AsBytes, BaseElement, ByteReader, DeserializationError, FieldElement, StarkField, M};
-use crate::field::{ExtensionOf, QuadExtension};
+use crate::{
+    field::{ExtensionOf, QuadExtension},
+    ConstantTimeEq,
+};
 
 // BASIC ALGEBRA
 // ================================================================================================
@@ -53,6 +56,7 @@ fn sub() {
 }
 
 #[test]
+#[allow(clippy::manual_div_ceil)]
 fn mul() {
     // identity
     let r: BaseElement = rand_value();
@@ -102,6 +106,17 @@ fn inv() {
     }
 }
 
+#[test]
+fn inv_or_zero() {
+    // zero is mapped to zero, and non-zero values are mapped to their inverses
+    assert_eq!(BaseElement::ZERO, BaseElement::ZERO.inv_or_zero());
+    assert_eq!(BaseElement::ONE, BaseElement::ONE.inv_or_zero());
+    for _ in 0..100 {
+        let x: BaseElement = rand_value();
+        assert_eq!(x.inv(), x.inv_or_zero());
+    }
+}
+
 #[test]
 fn conjugate() {
     let a: BaseElement = rand_value();
@@ -109,6 +124,18 @@ fn conjugate() {
     assert_eq!(a, b);
 }
 
+#[test]
+fn equals() {
+    let a: BaseElement = rand_value();
+    assert!(bool::from(a.ct_eq(&a)));
+    assert!(!bool::from(a.ct_eq(&(a + BaseElement::ONE))));
+
+    // elements which differ only in the upper or only in the lower 64 bits are not equal
+    let b = BaseElement::new(1 << 64);
+    assert!(!bool::from(b.ct_eq(&BaseElement::ZERO)));
+    assert!(!bool::from(b.ct_eq(&BaseElement::new((1 << 64) + 1))));
+}
+
 // ROOTS OF UNITY
 // ================================================================================================
 
@@ -124,6 +151,19 @@ fn get_root_of_unity() {
     assert_eq!(BaseElement::ONE, root_39.exp(u128::pow(2, 39)));
 }
 
+#[test]
+fn try_get_root_of_unity() {
+    assert_eq!(None, BaseElement::try_get_root_of_unity(0));
+    assert_eq!(None, BaseElement::try_get_root_of_unity(BaseElement::TWO_ADICITY + 1));
+
+    // roots of unity are primitive, i.e., they generate subgroups of exactly the specified size
+    for n in 1..=BaseElement::TWO_ADICITY {
+        let root = BaseElement::try_get_root_of_unity(n).unwrap();
+        assert_eq!(BaseElement::get_root_of_unity(n), root);
+        assert_eq!(-BaseElement::ONE, (0..n - 1).fold(root, |r, _| r.square()));
+    }
+}
+
 #[test]
 fn test_g_is_2_exp_40_root() {
     let g = BaseElement::TWO_ADIC_ROOT_OF_UNITY;
@@ -146,6 +186,27 @@ fn quad_mul_base() {
 // SERIALIZATION / DESERIALIZATION
 // ================================================================================================
 
+#[test]
+fn endian_bytes() {
+    let element: BaseElement = rand_value();
+    let le_bytes = element.to_le_bytes();
+    let be_bytes = element.to_be_bytes();
+    assert_eq!(16, le_bytes.len());
+    assert_eq!(element.as_int().to_le_bytes().to_vec(), le_bytes);
+    assert_eq!(element.as_int().to_be_bytes().to_vec(), be_bytes);
+    assert_eq!(element, BaseElement::from_le_bytes(&le_bytes).unwrap());
+    assert_eq!(element, BaseElement::from_be_bytes(&be_bytes).unwrap());
+
+    // the largest canonical value is accepted, but the modulus and values of a wrong length are
+    // rejected
+    let max = BaseElement::from_be_bytes(&(M - 1).to_be_bytes()).unwrap();
+    assert_eq!(-BaseElement::ONE, max);
+    assert!(BaseElement::from_le_bytes(&M.to_le_bytes()).is_err());
+    assert!(BaseElement::from_be_bytes(&M.to_be_bytes()).is_err());
+    assert!(BaseElement::from_le_bytes(&le_bytes[..15]).is_err());
+    assert!(BaseElement::from_le_bytes(&[le_bytes.as_slice(), &[0]].concat()).is_err());
+}
+
 #[test]
 fn elements_as_bytes() {
     let source = vec![
@@ -22,9 +22,10 @@ use core::{
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use subtle::{Choice, ConstantTimeEq};
 use utils::{
-    AsBytes, ByteReader, ByteWriter, Deserializable, DeserializationError, Randomizable,
-    Serializable,
+    accounting, AsBytes, ByteReader, ByteWriter, Deserializable, DeserializationError,
+    Randomizable, Serializable,
 };
 
 use super::{ExtensibleField, FieldElement, StarkField};
@@ -123,6 +124,20 @@ impl FieldElement for BaseElement {
         BaseElement(inv(self.0))
     }
 
+    fn inv_or_zero(self) -> Self {
+        // compute base^(M - 2) via square-and-multiply; the branches depend only on the bits of
+        // the (public) exponent, and ZERO is mapped to ZERO
+        const POWER: u64 = M - 2;
+        let mut result = Self::ONE;
+        for i in (0..64 - POWER.leading_zeros()).rev() {
+            result = result.square();
+            if (POWER >> i) & 1 == 1 {
+                result *= self;
+            }
+        }
+        result
+    }
+
     fn conjugate(&self) -> Self {
         BaseElement(self.0)
     }
@@ -245,6 +260,13 @@ impl PartialEq for BaseElement {
 
 impl Eq for BaseElement {}
 
+impl ConstantTimeEq for BaseElement {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        normalize_ct(self.0).ct_eq(&normalize_ct(other.0))
+    }
+}
+
 // OVERLOADED OPERATORS
 // ================================================================================================
 
@@ -280,6 +302,7 @@ impl Mul for BaseElement {
     type Output = Self;
 
     fn mul(self, rhs: Self) -> Self {
+        accounting::record_field_mul();
         Self(mul(self.0, rhs.0))
     }
 }
@@ -389,6 +412,30 @@ impl ExtensibleField<3> for BaseElement {
     }
 }
 
+// SEXTIC EXTENSION
+// ================================================================================================
+
+/// Sextic extension for this field is not implemented.
+impl ExtensibleField<6> for BaseElement {
+    fn mul(_a: [Self; 6], _b: [Self; 6]) -> [Self; 6] {
+        unimplemented!()
+    }
+
+    #[inline(always)]
+    fn mul_base(_a: [Self; 6], _b: Self) -> [Self; 6] {
+        unimplemented!()
+    }
+
+    #[inline(always)]
+    fn frobenius(_x: [Self; 6]) -> [Self; 6] {
+        unimplemented!()
+    }
+
+    fn is_supported() -> bool {
+        false
+    }
+}
+
 // TYPE CONVERSIONS
 // ================================================================================================
 
@@ -625,3 +672,12 @@ fn normalize(value: u64) -> u64 {
         value
     }
 }
+
+/// Reduces any value in [0, 2M) range to [0, M) range without branching on the value.
+#[inline(always)]
+fn normalize_ct(value: u64) -> u64 {
+    // since M < 2^63, the top bit of value - M is set if and only if value < M
+    let reduced = value.wrapping_sub(M);
+    let mask = ((reduced as i64) >> 63) as u64;
+    reduced.wrapping_add(M & mask)
+}
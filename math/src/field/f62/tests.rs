@@ -3,12 +3,19 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
+use alloc::vec::Vec;
+
 use num_bigint::BigUint;
 use proptest::prelude::*;
 use rand_utils::rand_value;
+use utils::{Deserializable, SliceReader};
 
 use super::{AsBytes, BaseElement, DeserializationError, FieldElement, Serializable, StarkField};
-use crate::field::{CubeExtension, ExtensionOf, QuadExtension};
+use crate::{
+    fft,
+    field::{CubeExtension, ExtensionOf, QuadExtension},
+    get_power_series, polynom, ConstantTimeEq,
+};
 
 // MANUAL TESTS
 // ================================================================================================
@@ -43,6 +50,7 @@ fn sub() {
 }
 
 #[test]
+#[allow(clippy::manual_div_ceil)]
 fn mul() {
     // identity
     let r: BaseElement = rand_value();
@@ -85,6 +93,17 @@ fn inv() {
     assert_eq!(BaseElement::ZERO, BaseElement::inv(BaseElement::ZERO));
 }
 
+#[test]
+fn inv_or_zero() {
+    // zero is mapped to zero, and non-zero values are mapped to their inverses
+    assert_eq!(BaseElement::ZERO, BaseElement::ZERO.inv_or_zero());
+    assert_eq!(BaseElement::ONE, BaseElement::ONE.inv_or_zero());
+    for _ in 0..100 {
+        let x: BaseElement = rand_value();
+        assert_eq!(x.inv(), x.inv_or_zero());
+    }
+}
+
 #[test]
 fn element_as_int() {
     let v = u64::MAX;
@@ -105,6 +124,19 @@ fn equals() {
     // but their internal representation is not
     assert_ne!(a.0, b.0);
     assert_ne!(a.as_bytes(), b.as_bytes());
+
+    // constant-time comparison normalizes internal representation as well
+    assert!(bool::from(a.ct_eq(&b)));
+    assert!(!bool::from(a.ct_eq(&BaseElement::ZERO)));
+}
+
+#[test]
+fn normalize_ct() {
+    // branch-free normalization agrees with the regular one across the entire [0, 2M) range
+    let m = super::M;
+    for value in [0, 1, m - 1, m, m + 1, 2 * m - 1] {
+        assert_eq!(super::normalize(value), super::normalize_ct(value));
+    }
 }
 
 // QUADRATIC EXTENSION
@@ -194,6 +226,63 @@ fn cube_mul_base() {
     assert_eq!(expected, a.mul_base(b0));
 }
 
+#[test]
+fn cube_frobenius() {
+    // conjugate of an element must be equal to the element raised to the power of the modulus
+    let a: CubeExtension<BaseElement> = rand_value();
+    assert_eq!(a.exp(super::M), a.conjugate());
+
+    // applying Frobenius map three times must yield the original element
+    assert_eq!(a, a.conjugate().conjugate().conjugate());
+
+    // elements of the base field are fixed by the Frobenius map
+    let b = <CubeExtension<BaseElement>>::from(BaseElement::new(42));
+    assert_eq!(b, b.conjugate());
+}
+
+#[test]
+fn cube_serialization() {
+    let a = <CubeExtension<BaseElement>>::new(
+        BaseElement::new(1),
+        BaseElement::new(2),
+        BaseElement::new(3),
+    );
+    let bytes = a.to_bytes();
+    assert_eq!([1, 0, 0, 0, 0, 0, 0, 0], bytes[..8]);
+    assert_eq!(24, bytes.len());
+
+    let mut reader = SliceReader::new(&bytes);
+    assert_eq!(a, CubeExtension::<BaseElement>::read_from(&mut reader).unwrap());
+
+    // element with a non-canonical coefficient should fail to deserialize
+    let mut bytes = a.to_bytes();
+    bytes[16..].copy_from_slice(&super::M.to_le_bytes());
+    let mut reader = SliceReader::new(&bytes);
+    assert!(CubeExtension::<BaseElement>::read_from(&mut reader).is_err());
+}
+
+#[test]
+fn cube_fft() {
+    let n = 16;
+    let poly: Vec<CubeExtension<BaseElement>> = (0..n).map(|_| rand_value()).collect();
+
+    // evaluate the polynomial over the domain using FFT and compare with direct evaluation
+    let mut evaluations = poly.clone();
+    let twiddles = fft::get_twiddles::<BaseElement>(n);
+    fft::evaluate_poly(&mut evaluations, &twiddles);
+
+    let g = BaseElement::get_root_of_unity(n.ilog2());
+    let domain = get_power_series(g, n);
+    for (&x, &y) in domain.iter().zip(evaluations.iter()) {
+        assert_eq!(polynom::eval(&poly, CubeExtension::from(x)), y);
+    }
+
+    // interpolating evaluations should yield the original polynomial
+    let inv_twiddles = fft::get_inv_twiddles::<BaseElement>(n);
+    fft::interpolate_poly(&mut evaluations, &inv_twiddles);
+    assert_eq!(poly, evaluations);
+}
+
 // ROOTS OF UNITY
 // ------------------------------------------------------------------------------------------------
 
@@ -209,6 +298,19 @@ fn get_root_of_unity() {
     assert_eq!(BaseElement::ONE, root_38.exp(1u64 << 38));
 }
 
+#[test]
+fn try_get_root_of_unity() {
+    assert_eq!(None, BaseElement::try_get_root_of_unity(0));
+    assert_eq!(None, BaseElement::try_get_root_of_unity(BaseElement::TWO_ADICITY + 1));
+
+    // roots of unity are primitive, i.e., they generate subgroups of exactly the specified size
+    for n in 1..=BaseElement::TWO_ADICITY {
+        let root = BaseElement::try_get_root_of_unity(n).unwrap();
+        assert_eq!(BaseElement::get_root_of_unity(n), root);
+        assert_eq!(-BaseElement::ONE, (0..n - 1).fold(root, |r, _| r.square()));
+    }
+}
+
 // SERIALIZATION AND DESERIALIZATION
 // ------------------------------------------------------------------------------------------------
 
@@ -232,6 +334,27 @@ fn try_from_slice() {
     assert!(result.is_err());
 }
 
+#[test]
+fn endian_bytes() {
+    let element: BaseElement = rand_value();
+    let le_bytes = element.to_le_bytes();
+    let be_bytes = element.to_be_bytes();
+    assert_eq!(8, le_bytes.len());
+    assert_eq!(element.as_int().to_le_bytes().to_vec(), le_bytes);
+    assert_eq!(element.as_int().to_be_bytes().to_vec(), be_bytes);
+    assert_eq!(element, BaseElement::from_le_bytes(&le_bytes).unwrap());
+    assert_eq!(element, BaseElement::from_be_bytes(&be_bytes).unwrap());
+
+    // the largest canonical value is accepted, but the modulus and values of a wrong length are
+    // rejected
+    let max = BaseElement::from_be_bytes(&(super::M - 1).to_be_bytes()).unwrap();
+    assert_eq!(-BaseElement::ONE, max);
+    assert!(BaseElement::from_le_bytes(&super::M.to_le_bytes()).is_err());
+    assert!(BaseElement::from_be_bytes(&super::M.to_be_bytes()).is_err());
+    assert!(BaseElement::from_le_bytes(&le_bytes[..7]).is_err());
+    assert!(BaseElement::from_le_bytes(&[le_bytes.as_slice(), &[0]].concat()).is_err());
+}
+
 #[test]
 fn elements_as_bytes() {
     let source = vec![
@@ -282,6 +405,15 @@ fn bytes_as_elements() {
 
 proptest! {
 
+    #[test]
+    fn ct_eq_proptest(a in 0..2 * super::M, b in 0..2 * super::M) {
+        // internal values can be anywhere in [0, 2M) range
+        let v1 = BaseElement(a);
+        let v2 = BaseElement(b);
+        prop_assert_eq!(v1 == v2, bool::from(v1.ct_eq(&v2)));
+        prop_assert!(bool::from(v1.ct_eq(&BaseElement(a % super::M))));
+    }
+
     #[test]
     fn add_proptest(a in any::<u64>(), b in any::<u64>()) {
         let v1 = BaseElement::new(a);
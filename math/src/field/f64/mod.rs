@@ -29,9 +29,10 @@ use core::{
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use subtle::{Choice, ConstantTimeEq};
 use utils::{
-    AsBytes, ByteReader, ByteWriter, Deserializable, DeserializationError, Randomizable,
-    Serializable,
+    accounting, AsBytes, ByteReader, ByteWriter, Deserializable, DeserializationError,
+    Randomizable, Serializable,
 };
 
 use super::{ExtensibleField, FieldElement, StarkField};
@@ -48,6 +49,9 @@ const M: u64 = 0xFFFFFFFF00000001;
 /// 2^128 mod M; this is used for conversion of elements into Montgomery representation.
 const R2: u64 = 0xFFFFFFFE00000001;
 
+/// 2^64 mod M = 2^32 - 1; this is used for fast reduction of 128-bit integers.
+const EPSILON: u64 = 0xFFFFFFFF;
+
 /// Number of bytes needed to represent field element
 const ELEMENT_BYTES: usize = core::mem::size_of::<u64>();
 
@@ -73,6 +77,26 @@ impl BaseElement {
         Self(mont_red_cst((value as u128) * (R2 as u128)))
     }
 
+    /// Returns a field element equal to `value` reduced modulo the field modulus.
+    ///
+    /// This is equivalent to [BaseElement::new()], and is provided for symmetry with
+    /// [BaseElement::from_u128_reduce()].
+    #[inline(always)]
+    pub const fn reduce(value: u64) -> BaseElement {
+        Self::new(value)
+    }
+
+    /// Returns a field element equal to `value` reduced modulo the field modulus.
+    ///
+    /// The reduction relies on the special form of the modulus (i.e., 2^64 = 2^32 - 1 mod M and
+    /// 2^96 = -1 mod M). Field arithmetic keeps elements in Montgomery form and reduces products
+    /// via Montgomery reduction instead, and thus, this is not the same reduction as the one used
+    /// for field arithmetic; the resulting element is, however, the canonical `value mod M`.
+    #[inline(always)]
+    pub const fn from_u128_reduce(value: u128) -> BaseElement {
+        Self::new(reduce_u128(value))
+    }
+
     /// Returns a new field element from the provided 'value'. Assumes that 'value' is already
     /// in canonical Montgomery form.
     pub const fn from_mont(value: u64) -> BaseElement {
@@ -184,6 +208,12 @@ impl FieldElement for BaseElement {
         t63.square() * self
     }
 
+    #[inline]
+    fn inv_or_zero(self) -> Self {
+        // the inverse is computed via a fixed addition chain which maps ZERO to ZERO
+        self.inv()
+    }
+
     fn conjugate(&self) -> Self {
         Self(self.0)
     }
@@ -308,6 +338,13 @@ impl PartialEq for BaseElement {
 
 impl Eq for BaseElement {}
 
+impl ConstantTimeEq for BaseElement {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
 // OVERLOADED OPERATORS
 // ================================================================================================
 
@@ -355,6 +392,7 @@ impl Mul for BaseElement {
 
     #[inline]
     fn mul(self, rhs: Self) -> Self {
+        accounting::record_field_mul();
         Self(mont_red_cst((self.0 as u128) * (rhs.0 as u128)))
     }
 }
@@ -498,6 +536,100 @@ impl ExtensibleField<3> for BaseElement {
     }
 }
 
+// SEXTIC EXTENSION
+// ================================================================================================
+
+/// Defines a degree 6 extension of the base field as a quadratic extension of the cubic extension
+/// of the base field.
+///
+/// Specifically, let F<sub>p<sup>3</sup></sub> be the cubic extension defined over x<sup>3</sup> -
+/// x - 1 with a root φ. The sextic extension is then defined over an irreducible polynomial
+/// y<sup>2</sup> - 7 over F<sub>p<sup>3</sup></sub> with a root ψ (7 is a quadratic non-residue in
+/// the base field, and thus, also in its cubic extension). An extension element is defined as
+/// (c<sub>0</sub> + c<sub>1</sub> * φ + c<sub>2</sub> * φ^2) + (c<sub>3</sub> + c<sub>4</sub> * φ
+/// + c<sub>5</sub> * φ^2) * ψ, where c<sub>0</sub>, ..., c<sub>5</sub> are base field elements.
+///
+/// Using the tower, a multiplication in the sextic extension requires 3 multiplications in the
+/// cubic extension.
+impl ExtensibleField<6> for BaseElement {
+    #[inline(always)]
+    fn mul(a: [Self; 6], b: [Self; 6]) -> [Self; 6] {
+        let (a0, a1) = split_sextic(a);
+        let (b0, b1) = split_sextic(b);
+
+        // (a0 + a1 * ψ) * (b0 + b1 * ψ) = (a0 * b0 + 7 * a1 * b1) + (a0 * b1 + a1 * b0) * ψ,
+        // where the second term is computed using Karatsuba's method
+        let a0b0 = <Self as ExtensibleField<3>>::mul(a0, b0);
+        let a1b1 = <Self as ExtensibleField<3>>::mul(a1, b1);
+        let a0b0_a0b1_a1b0_a1b1 =
+            <Self as ExtensibleField<3>>::mul(add_cubic(a0, a1), add_cubic(b0, b1));
+
+        let out0 = add_cubic(a0b0, mul_cubic_by_nonresidue(a1b1));
+        let out1 = sub_cubic(sub_cubic(a0b0_a0b1_a1b0_a1b1, a0b0), a1b1);
+
+        join_sextic(out0, out1)
+    }
+
+    #[inline(always)]
+    fn square(a: [Self; 6]) -> [Self; 6] {
+        let (a0, a1) = split_sextic(a);
+
+        let a0_sq = <Self as ExtensibleField<3>>::square(a0);
+        let a1_sq = <Self as ExtensibleField<3>>::square(a1);
+        let a0a1 = <Self as ExtensibleField<3>>::mul(a0, a1);
+
+        let out0 = add_cubic(a0_sq, mul_cubic_by_nonresidue(a1_sq));
+        let out1 = add_cubic(a0a1, a0a1);
+
+        join_sextic(out0, out1)
+    }
+
+    #[inline(always)]
+    fn mul_base(a: [Self; 6], b: Self) -> [Self; 6] {
+        // multiplying an extension field element by a base field element requires just 6
+        // multiplications in the base field.
+        a.map(|a| a * b)
+    }
+
+    #[inline(always)]
+    fn frobenius(x: [Self; 6]) -> [Self; 6] {
+        // since 7 is a quadratic non-residue, ψ^p = ψ * 7^((p - 1) / 2) = -ψ; thus, Frobenius
+        // automorphism can be applied to each cubic coefficient separately
+        let (x0, x1) = split_sextic(x);
+        let out0 = <Self as ExtensibleField<3>>::frobenius(x0);
+        let out1 = <Self as ExtensibleField<3>>::frobenius(x1).map(|c| -c);
+        join_sextic(out0, out1)
+    }
+}
+
+/// Splits an element of the sextic extension into two elements of the cubic extension.
+#[inline(always)]
+fn split_sextic(a: [BaseElement; 6]) -> ([BaseElement; 3], [BaseElement; 3]) {
+    ([a[0], a[1], a[2]], [a[3], a[4], a[5]])
+}
+
+/// Joins two elements of the cubic extension into an element of the sextic extension.
+#[inline(always)]
+fn join_sextic(a0: [BaseElement; 3], a1: [BaseElement; 3]) -> [BaseElement; 6] {
+    [a0[0], a0[1], a0[2], a1[0], a1[1], a1[2]]
+}
+
+#[inline(always)]
+fn add_cubic(a: [BaseElement; 3], b: [BaseElement; 3]) -> [BaseElement; 3] {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+#[inline(always)]
+fn sub_cubic(a: [BaseElement; 3], b: [BaseElement; 3]) -> [BaseElement; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+/// Multiplies an element of the cubic extension by 7 (i.e., ψ^2).
+#[inline(always)]
+fn mul_cubic_by_nonresidue(a: [BaseElement; 3]) -> [BaseElement; 3] {
+    a.map(|a| a.double().double().double() - a)
+}
+
 // TYPE CONVERSIONS
 // ================================================================================================
 
@@ -723,6 +855,29 @@ const fn mont_red_cst(x: u128) -> u64 {
     r.wrapping_sub(0u32.wrapping_sub(c as u32) as u64)
 }
 
+/// Reduces a 128-bit integer modulo M; the result is in the range [0, 2^64) and is not
+/// necessarily canonical.
+///
+/// For x = x_hi_hi * 2^96 + x_hi_lo * 2^64 + x_lo, this computes
+/// x_lo - x_hi_hi + x_hi_lo * (2^32 - 1), correcting for the borrow and the carry by adding or
+/// subtracting M.
+#[inline(always)]
+const fn reduce_u128(x: u128) -> u64 {
+    let x_lo = x as u64;
+    let x_hi = (x >> 64) as u64;
+    let x_hi_hi = x_hi >> 32;
+    let x_hi_lo = x_hi & EPSILON;
+
+    // on borrow, adding M is the same as subtracting EPSILON modulo 2^64
+    let (t0, borrow) = x_lo.overflowing_sub(x_hi_hi);
+    let t0 = t0.wrapping_sub(EPSILON * (borrow as u64));
+
+    // x_hi_lo * EPSILON < 2^64; on carry, subtracting M is the same as adding EPSILON
+    let t1 = x_hi_lo * EPSILON;
+    let (t2, carry) = t0.overflowing_add(t1);
+    t2.wrapping_add(EPSILON * (carry as u64))
+}
+
 // Converts a field element in Montgomery form to canonical form. That is, given x, it computes
 // x/2^64 modulo M. This is exactly what mont_red_cst does only that it does it more efficiently
 // using the fact that a field element in Montgomery form is stored as a u64 and thus one can
@@ -8,7 +8,10 @@ use proptest::prelude::*;
 use rand_utils::rand_value;
 
 use super::{BaseElement, DeserializationError, FieldElement, Serializable, StarkField, M};
-use crate::field::{CubeExtension, ExtensionOf, QuadExtension};
+use crate::{
+    field::{CubeExtension, ExtensionOf, QuadExtension, SexticExtension},
+    ConstantTimeEq,
+};
 
 // MANUAL TESTS
 // ================================================================================================
@@ -52,6 +55,7 @@ fn neg() {
 }
 
 #[test]
+#[allow(clippy::manual_div_ceil)]
 fn mul() {
     // identity
     let r: BaseElement = rand_value();
@@ -83,6 +87,26 @@ fn mul_small() {
     assert_eq!(expected, t.mul_small(a));
 }
 
+#[test]
+fn reduce() {
+    assert_eq!(BaseElement::ZERO, BaseElement::reduce(M));
+    assert_eq!(BaseElement::new(M - 1), BaseElement::reduce(u64::MAX - (u32::MAX as u64)));
+    assert_eq!(BaseElement::new(u32::MAX as u64 - 1), BaseElement::reduce(u64::MAX));
+
+    assert_eq!(BaseElement::ZERO, BaseElement::from_u128_reduce(0));
+    assert_eq!(BaseElement::ZERO, BaseElement::from_u128_reduce(M as u128));
+    assert_eq!(BaseElement::ONE, BaseElement::from_u128_reduce(M as u128 + 1));
+    assert_eq!(BaseElement::new(u32::MAX as u64), BaseElement::from_u128_reduce(1 << 64));
+    assert_eq!(-BaseElement::ONE, BaseElement::from_u128_reduce(1 << 96));
+
+    // (M - 1)^2 = 1 mod M
+    let t = (M - 1) as u128;
+    assert_eq!(BaseElement::ONE, BaseElement::from_u128_reduce(t * t));
+
+    let expected = (u128::MAX % (M as u128)) as u64;
+    assert_eq!(expected, BaseElement::from_u128_reduce(u128::MAX).as_int());
+}
+
 #[test]
 fn exp() {
     let a = BaseElement::ZERO;
@@ -101,6 +125,27 @@ fn exp() {
     assert_eq!(a.exp(7), a.exp7());
 }
 
+#[test]
+fn pow_ct() {
+    let a = BaseElement::ZERO;
+    assert_eq!(a.pow_ct(0), BaseElement::ONE);
+    assert_eq!(a.pow_ct(1), BaseElement::ZERO);
+
+    let a = BaseElement::ONE;
+    assert_eq!(a.pow_ct(0), BaseElement::ONE);
+    assert_eq!(a.pow_ct(u64::MAX), BaseElement::ONE);
+
+    let a: BaseElement = rand_value();
+    assert_eq!(a.pow_ct(3), a * a * a);
+    assert_eq!(a.pow_ct(u64::MAX), a.exp(u64::MAX));
+
+    // extension field elements are exponentiated by the default implementation
+    let b: QuadExtension<BaseElement> = rand_value();
+    assert_eq!(b.pow_ct(12345), b.exp(12345));
+    let c: CubeExtension<BaseElement> = rand_value();
+    assert_eq!(c.pow_ct(u64::MAX - 1), c.exp(u64::MAX - 1));
+}
+
 #[test]
 fn inv() {
     // identity
@@ -108,6 +153,30 @@ fn inv() {
     assert_eq!(BaseElement::ZERO, BaseElement::inv(BaseElement::ZERO));
 }
 
+#[test]
+fn inv_or_zero() {
+    // zero is mapped to zero, and non-zero values are mapped to their inverses
+    assert_eq!(BaseElement::ZERO, BaseElement::ZERO.inv_or_zero());
+    assert_eq!(BaseElement::ONE, BaseElement::ONE.inv_or_zero());
+    for _ in 0..100 {
+        let x: BaseElement = rand_value();
+        assert_eq!(x.inv(), x.inv_or_zero());
+    }
+
+    // the same holds in extensions of the field
+    assert_eq!(QuadExtension::<BaseElement>::ZERO, QuadExtension::ZERO.inv_or_zero());
+    assert_eq!(CubeExtension::<BaseElement>::ZERO, CubeExtension::ZERO.inv_or_zero());
+    assert_eq!(SexticExtension::<BaseElement>::ZERO, SexticExtension::ZERO.inv_or_zero());
+    for _ in 0..100 {
+        let x: QuadExtension<BaseElement> = rand_value();
+        assert_eq!(x.inv(), x.inv_or_zero());
+        let x: CubeExtension<BaseElement> = rand_value();
+        assert_eq!(x.inv(), x.inv_or_zero());
+        let x: SexticExtension<BaseElement> = rand_value();
+        assert_eq!(x.inv(), x.inv_or_zero());
+    }
+}
+
 #[test]
 fn element_as_int() {
     let v = u64::MAX;
@@ -129,6 +198,25 @@ fn equals() {
     assert_eq!(a, b);
     assert_eq!(a.as_int(), b.as_int());
     assert_eq!(a.to_bytes(), b.to_bytes());
+    assert!(bool::from(a.ct_eq(&b)));
+    assert!(!bool::from(a.ct_eq(&BaseElement::ZERO)));
+
+    // elements of extension fields are equal only if all their coefficients are equal
+    let c = QuadExtension::new(a, BaseElement::ZERO);
+    assert!(bool::from(c.ct_eq(&QuadExtension::new(b, BaseElement::ZERO))));
+    assert!(!bool::from(c.ct_eq(&QuadExtension::new(b, BaseElement::ONE))));
+    let c = CubeExtension::new(a, BaseElement::ZERO, a);
+    assert!(bool::from(c.ct_eq(&CubeExtension::new(b, BaseElement::ZERO, b))));
+    assert!(!bool::from(c.ct_eq(&CubeExtension::new(
+        b,
+        BaseElement::ZERO,
+        BaseElement::ZERO
+    ))));
+    let c = SexticExtension::new([a; 6]);
+    assert!(bool::from(c.ct_eq(&SexticExtension::new([b; 6]))));
+    let mut coefficients = [b; 6];
+    coefficients[5] = BaseElement::ZERO;
+    assert!(!bool::from(c.ct_eq(&SexticExtension::new(coefficients))));
 }
 
 // ROOTS OF UNITY
@@ -146,6 +234,25 @@ fn get_root_of_unity() {
     assert_eq!(BaseElement::ONE, root_31.exp(1u64 << 31));
 }
 
+#[test]
+#[should_panic(expected = "cannot get root of unity for n = 0")]
+fn get_root_of_unity_zero() {
+    BaseElement::get_root_of_unity(0);
+}
+
+#[test]
+fn try_get_root_of_unity() {
+    assert_eq!(None, BaseElement::try_get_root_of_unity(0));
+    assert_eq!(None, BaseElement::try_get_root_of_unity(BaseElement::TWO_ADICITY + 1));
+
+    // roots of unity are primitive, i.e., they generate subgroups of exactly the specified size
+    for n in 1..=BaseElement::TWO_ADICITY {
+        let root = BaseElement::try_get_root_of_unity(n).unwrap();
+        assert_eq!(BaseElement::get_root_of_unity(n), root);
+        assert_eq!(-BaseElement::ONE, (0..n - 1).fold(root, |r, _| r.square()));
+    }
+}
+
 // SERIALIZATION AND DESERIALIZATION
 // ------------------------------------------------------------------------------------------------
 
@@ -169,6 +276,27 @@ fn try_from_slice() {
     assert!(result.is_err());
 }
 
+#[test]
+fn endian_bytes() {
+    let element: BaseElement = rand_value();
+    let le_bytes = element.to_le_bytes();
+    let be_bytes = element.to_be_bytes();
+    assert_eq!(8, le_bytes.len());
+    assert_eq!(element.as_int().to_le_bytes().to_vec(), le_bytes);
+    assert_eq!(element.as_int().to_be_bytes().to_vec(), be_bytes);
+    assert_eq!(element, BaseElement::from_le_bytes(&le_bytes).unwrap());
+    assert_eq!(element, BaseElement::from_be_bytes(&be_bytes).unwrap());
+
+    // the largest canonical value is accepted, but the modulus and values of a wrong length are
+    // rejected
+    let max = BaseElement::from_be_bytes(&(M - 1).to_be_bytes()).unwrap();
+    assert_eq!(-BaseElement::ONE, max);
+    assert!(BaseElement::from_le_bytes(&M.to_le_bytes()).is_err());
+    assert!(BaseElement::from_be_bytes(&M.to_be_bytes()).is_err());
+    assert!(BaseElement::from_le_bytes(&le_bytes[..7]).is_err());
+    assert!(BaseElement::from_le_bytes(&[le_bytes.as_slice(), &[0]].concat()).is_err());
+}
+
 #[test]
 fn elements_as_bytes() {
     let source = vec![
@@ -354,11 +482,124 @@ fn cube_mul_base() {
     assert_eq!(expected, a.mul_base(b0));
 }
 
+// SEXTIC EXTENSION
+// ------------------------------------------------------------------------------------------------
+
+/// Builds a sextic extension element from the provided coefficients.
+fn sextic(coefficients: [u64; 6]) -> SexticExtension<BaseElement> {
+    SexticExtension::new(coefficients.map(BaseElement::new))
+}
+
+#[test]
+fn sextic_mul() {
+    // identity
+    let r: SexticExtension<BaseElement> = rand_value();
+    assert_eq!(<SexticExtension<BaseElement>>::ZERO, r * <SexticExtension<BaseElement>>::ZERO);
+    assert_eq!(r, r * <SexticExtension<BaseElement>>::ONE);
+
+    // ψ^2 = 7
+    let psi = sextic([0, 0, 0, 1, 0, 0]);
+    assert_eq!(sextic([7, 0, 0, 0, 0, 0]), psi * psi);
+
+    // test multiplication within bounds
+    let a = sextic([1, 2, 3, 4, 5, 6]);
+    let b = sextic([M - 1, 2, M - 3, 4, M - 5, 6]);
+    let expected = sextic([111, 243, 402, 18446744069414584315, 0, 0]);
+    assert_eq!(expected, a * b);
+    assert_eq!(expected, b * a);
+
+    // test multiplication with overflow; expected values were computed using a schoolbook
+    // implementation of the tower arithmetic
+    let a = sextic([M - 7, 11, 1 << 40, M - (1 << 33), 12345678901234567, 3]);
+    let b = sextic([9, M - 2, 5, 1 << 63, 77, M - 1]);
+    let expected = sextic([
+        18360322118082688416,
+        14250656765346572980,
+        15877708460862211301,
+        9285184999267006870,
+        9396295143010475840,
+        18422051569150815219,
+    ]);
+    assert_eq!(expected, a * b);
+    assert_eq!(expected, b * a);
+    assert_eq!(a * a, a.square());
+}
+
+#[test]
+fn sextic_mul_base() {
+    let a: SexticExtension<BaseElement> = rand_value();
+    let b0: BaseElement = rand_value();
+    let b = SexticExtension::from(b0);
+
+    let expected = a * b;
+    assert_eq!(expected, a.mul_base(b0));
+}
+
+#[test]
+fn sextic_inv() {
+    // identity
+    assert_eq!(<SexticExtension<BaseElement>>::ONE, <SexticExtension<BaseElement>>::ONE.inv());
+    assert_eq!(<SexticExtension<BaseElement>>::ZERO, <SexticExtension<BaseElement>>::ZERO.inv());
+
+    let a = sextic([M - 7, 11, 1 << 40, M - (1 << 33), 12345678901234567, 3]);
+    let expected = sextic([
+        14644026082770231344,
+        15210059889633883538,
+        3994879293816596097,
+        6055553885454293978,
+        10661322558951494213,
+        14575010859992173068,
+    ]);
+    assert_eq!(expected, a.inv());
+
+    // test random values
+    let r: SexticExtension<BaseElement> = rand_value();
+    assert_eq!(<SexticExtension<BaseElement>>::ONE, r * r.inv());
+}
+
+#[test]
+fn sextic_conjugate() {
+    // conjugate is the Frobenius automorphism x -> x^p
+    let a = sextic([M - 7, 11, 1 << 40, M - (1 << 33), 12345678901234567, 3]);
+    let expected = sextic([
+        8769623879790321350,
+        765006137973200590,
+        14515681383948022222,
+        7665510504274046236,
+        9662763924994688946,
+        6948478325888416852,
+    ]);
+    assert_eq!(expected, a.conjugate());
+    assert_eq!(a.exp(M), a.conjugate());
+
+    // applying the automorphism 6 times yields the original element
+    let r: SexticExtension<BaseElement> = rand_value();
+    let mut c = r;
+    for _ in 0..6 {
+        c = c.conjugate();
+    }
+    assert_eq!(r, c);
+}
+
 // RANDOMIZED TESTS
 // ================================================================================================
 
 proptest! {
 
+    #[test]
+    fn ct_eq_proptest(a in any::<u64>(), b in any::<u64>()) {
+        let v1 = BaseElement::new(a);
+        let v2 = BaseElement::new(b);
+        prop_assert_eq!(v1 == v2, bool::from(v1.ct_eq(&v2)));
+        prop_assert!(bool::from(v1.ct_eq(&BaseElement::new(a % super::M))));
+    }
+
+    #[test]
+    fn from_u128_reduce_proptest(a in any::<u128>()) {
+        let expected = (a % super::M as u128) as u64;
+        prop_assert_eq!(expected, BaseElement::from_u128_reduce(a).as_int());
+    }
+
     #[test]
     fn add_proptest(a in any::<u64>(), b in any::<u64>()) {
         let v1 = BaseElement::new(a);
@@ -429,6 +670,12 @@ proptest! {
         prop_assert_eq!(expected, result.as_int());
     }
 
+    #[test]
+    fn pow_ct_proptest(a in any::<u64>(), b in any::<u64>()) {
+        let a = BaseElement::new(a);
+        prop_assert_eq!(a.exp_vartime(b), a.pow_ct(b));
+    }
+
     #[test]
     fn inv_proptest(a in any::<u64>()) {
         let a = BaseElement::new(a);
@@ -3,7 +3,7 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use alloc::vec::Vec;
+use alloc::{format, vec::Vec};
 use core::{
     fmt::{Debug, Display},
     ops::{
@@ -12,6 +12,7 @@ use core::{
     },
 };
 
+use subtle::ConstantTimeEq;
 use utils::{AsBytes, Deserializable, DeserializationError, Randomizable, Serializable};
 
 // FIELD ELEMENT
@@ -25,6 +26,13 @@ use utils::{AsBytes, Deserializable, DeserializationError, Randomizable, Seriali
 ///
 /// The elements could be in a prime field or an extension of a prime field. Currently, only
 /// quadratic and cubic field extensions are supported.
+///
+/// In addition to the regular equality comparison, field elements can be compared in constant
+/// time via [ConstantTimeEq::ct_eq()]; this should be used whenever the compared values may
+/// depend on secret data. [ConstantTimeEq] is a supertrait of this trait, and thus, field types
+/// defined outside of this crate must implement it as well; the trait is re-exported from this
+/// crate (together with [Choice](crate::Choice)) so that such implementations do not need to
+/// depend on the `subtle` crate directly.
 pub trait FieldElement:
     Copy
     + Clone
@@ -35,6 +43,7 @@ pub trait FieldElement:
     + Sync
     + Eq
     + PartialEq
+    + ConstantTimeEq
     + Sized
     + Add<Self, Output = Self>
     + Sub<Self, Output = Self>
@@ -78,7 +87,13 @@ pub trait FieldElement:
     /// extension degree should be set to 1.
     const EXTENSION_DEGREE: usize;
 
-    /// Number of bytes needed to encode an element
+    /// Number of bytes needed to encode an element.
+    ///
+    /// This is exactly the number of bytes written by the [Serializable] implementation of the
+    /// field, and the value returned from [Serializable::get_size_hint()] for any element. For
+    /// extension fields, this is the number of bytes of the base field element multiplied by the
+    /// extension degree (e.g., 8 bytes for an element of the 64-bit field, and 16 bytes for an
+    /// element of its quadratic extension).
     const ELEMENT_BYTES: usize;
 
     /// True if internal representation of the element is the same as its canonical representation.
@@ -115,6 +130,9 @@ pub trait FieldElement:
     }
 
     /// Exponentiates this field element by `power` parameter.
+    ///
+    /// Unless overridden by a specific field, this function is variable time, and thus, it should
+    /// be used only with public exponents. For secret exponents, use [FieldElement::pow_ct()].
     #[must_use]
     fn exp(self, power: Self::PositiveInteger) -> Self {
         self.exp_vartime(power)
@@ -122,6 +140,9 @@ pub trait FieldElement:
 
     /// Exponentiates this field element by `power` parameter.
     /// This function is expressly variable time, to speed-up verifier computations.
+    ///
+    /// The running time of this function depends on the value of `power`, and thus, it should not
+    /// be used with secret exponents. For secret exponents, use [FieldElement::pow_ct()].
     #[must_use]
     fn exp_vartime(self, power: Self::PositiveInteger) -> Self {
         let mut r = Self::ONE;
@@ -148,11 +169,66 @@ pub trait FieldElement:
         r
     }
 
+    /// Exponentiates this field element by `exp` parameter in constant time.
+    ///
+    /// The exponentiation is performed using a fixed-window method with 4-bit windows: the
+    /// sequence of field operations performed by this function is the same for all values of
+    /// `exp`, and entries of the window table are selected arithmetically rather than via
+    /// branching or indexing. Thus, the running time of this function does not depend on the
+    /// value of `exp` as long as field addition and multiplication are constant time.
+    ///
+    /// This function is several times slower than [FieldElement::exp_vartime()], and thus, it
+    /// should be used only when the exponent must be kept secret (e.g., when the exponent is
+    /// derived from a witness or from blinding factors). For public exponents, use
+    /// [FieldElement::exp()].
+    #[must_use]
+    fn pow_ct(self, exp: u64) -> Self {
+        const WINDOW_SIZE: u32 = 4;
+        const TABLE_SIZE: usize = 1 << WINDOW_SIZE;
+
+        // table[i] = self^i for i in [0, 16)
+        let mut table = [Self::ONE; TABLE_SIZE];
+        for i in 1..TABLE_SIZE {
+            table[i] = table[i - 1] * self;
+        }
+
+        let mut r = Self::ONE;
+        for i in (0..u64::BITS / WINDOW_SIZE).rev() {
+            for _ in 0..WINDOW_SIZE {
+                r = r.square();
+            }
+
+            // select table[window] by summing all table entries multiplied by zero or one; the
+            // indicator is computed without branching: (j ^ window) - 1 overflows only if j is
+            // equal to window
+            let window = (exp >> (i * WINDOW_SIZE)) & (TABLE_SIZE as u64 - 1);
+            let mut selected = Self::ZERO;
+            for (j, &value) in table.iter().enumerate() {
+                let is_selected = ((j as u64 ^ window).wrapping_sub(1) >> 63) as u8;
+                selected += value * Self::from(is_selected);
+            }
+            r *= selected;
+        }
+
+        r
+    }
+
     /// Returns a multiplicative inverse of this field element. If this element is ZERO, ZERO is
     /// returned.
     #[must_use]
     fn inv(self) -> Self;
 
+    /// Returns a multiplicative inverse of this field element, or ZERO if this element is ZERO.
+    ///
+    /// Unlike [FieldElement::inv()], this function does not branch on the value of this element,
+    /// and thus, it follows the `1/0 = 0` convention of constraint systems without requiring a
+    /// separate check for ZERO. The default implementation delegates to [FieldElement::inv()];
+    /// fields for which the inversion is not branchless should override it.
+    #[must_use]
+    fn inv_or_zero(self) -> Self {
+        self.inv()
+    }
+
     /// Returns a conjugate of this field element.
     #[must_use]
     fn conjugate(&self) -> Self;
@@ -253,13 +329,36 @@ pub trait StarkField: FieldElement<BaseField = Self> {
 
     /// Returns the root of unity of order 2^`n`.
     ///
+    /// The returned value is a primitive 2^`n`-th root of unity, i.e., a generator of the
+    /// multiplicative subgroup of size 2^`n`, computed as
+    /// [TWO_ADIC_ROOT_OF_UNITY](Self::TWO_ADIC_ROOT_OF_UNITY)^(2^(`TWO_ADICITY` - `n`)). This is
+    /// the same generator as the one used by the prover and the verifier to build the trace and
+    /// LDE domains of the corresponding size.
+    ///
     /// # Panics
-    /// Panics if the root of unity for the specified order does not exist in this field.
+    /// Panics if:
+    /// - `n` is zero.
+    /// - The root of unity for the specified order does not exist in this field (i.e., `n` is
+    ///   greater than [TWO_ADICITY](Self::TWO_ADICITY)).
+    ///
+    /// See [try_get_root_of_unity()](Self::try_get_root_of_unity) for a non-panicking version.
     fn get_root_of_unity(n: u32) -> Self {
         assert!(n != 0, "cannot get root of unity for n = 0");
-        assert!(n <= Self::TWO_ADICITY, "order cannot exceed 2^{}", Self::TWO_ADICITY);
+        Self::try_get_root_of_unity(n)
+            .unwrap_or_else(|| panic!("order cannot exceed 2^{}", Self::TWO_ADICITY))
+    }
+
+    /// Returns the root of unity of order 2^`n`, or None if `n` is zero or is greater than
+    /// [TWO_ADICITY](Self::TWO_ADICITY).
+    ///
+    /// See [get_root_of_unity()](Self::get_root_of_unity) for the description of the returned
+    /// value.
+    fn try_get_root_of_unity(n: u32) -> Option<Self> {
+        if n == 0 || n > Self::TWO_ADICITY {
+            return None;
+        }
         let power = Self::PositiveInteger::from(1u32) << (Self::TWO_ADICITY - n);
-        Self::TWO_ADIC_ROOT_OF_UNITY.exp(power)
+        Some(Self::TWO_ADIC_ROOT_OF_UNITY.exp(power))
     }
 
     /// Converts a slice of bytes into a field element. Pads the slice if it is smaller than the number
@@ -282,6 +381,60 @@ pub trait StarkField: FieldElement<BaseField = Self> {
 
         element
     }
+
+    /// Returns the canonical integer representation of this field element encoded in
+    /// little-endian byte order.
+    ///
+    /// The returned vector contains exactly [FieldElement::ELEMENT_BYTES] bytes: 8 bytes for
+    /// elements of [f62](crate::fields::f62) and [f64](crate::fields::f64) fields, and 16 bytes
+    /// for elements of [f128](crate::fields::f128) field. This matches the format used by the
+    /// [Serializable] implementation of the field.
+    fn to_le_bytes(&self) -> Vec<u8> {
+        let bytes = self.to_bytes();
+        debug_assert_eq!(bytes.len(), Self::ELEMENT_BYTES);
+        bytes
+    }
+
+    /// Returns the canonical integer representation of this field element encoded in big-endian
+    /// byte order.
+    ///
+    /// The returned vector contains exactly [FieldElement::ELEMENT_BYTES] bytes.
+    fn to_be_bytes(&self) -> Vec<u8> {
+        let mut bytes = self.to_le_bytes();
+        bytes.reverse();
+        bytes
+    }
+
+    /// Returns a field element decoded from its canonical integer representation encoded in
+    /// little-endian byte order.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The length of `bytes` is not equal to [FieldElement::ELEMENT_BYTES].
+    /// - The encoded integer is greater than or equal to the field modulus.
+    fn from_le_bytes(bytes: &[u8]) -> Result<Self, DeserializationError> {
+        if bytes.len() != Self::ELEMENT_BYTES {
+            return Err(DeserializationError::InvalidValue(format!(
+                "expected {} bytes for a field element, but was {} bytes",
+                Self::ELEMENT_BYTES,
+                bytes.len()
+            )));
+        }
+        Self::read_from_bytes(bytes)
+    }
+
+    /// Returns a field element decoded from its canonical integer representation encoded in
+    /// big-endian byte order.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The length of `bytes` is not equal to [FieldElement::ELEMENT_BYTES].
+    /// - The encoded integer is greater than or equal to the field modulus.
+    fn from_be_bytes(bytes: &[u8]) -> Result<Self, DeserializationError> {
+        let mut bytes = bytes.to_vec();
+        bytes.reverse();
+        Self::from_le_bytes(&bytes)
+    }
 }
 
 // EXTENSIBLE FIELD
@@ -30,14 +30,14 @@
 //! * A 64-bit field with modulus 2<sup>64</sup> - 2<sup>32</sup> + 1. This field is about 15%
 //!   slower than the 62-bit field described above, but it has a number of other attractive
 //!   properties. To achieve adequate security (i.e. ~100 bits), proofs must be generated in a
-//!   quadratic extension of this field. For higher levels of security, a cubic extension field
-//!   should be used.
+//!   quadratic extension of this field. For higher levels of security, a cubic or a sextic
+//!   extension field should be used.
 //!
 //! ## Extension fields
 //!
-//! Currently, the library provides a generic way to create quadratic and cubic extensions of
-//! supported STARK fields. This can be done by implementing [ExtensibleField] trait for
-//! degrees 2 and 3.
+//! Currently, the library provides a generic way to create quadratic, cubic, and sextic (degree 6)
+//! extensions of supported STARK fields. This can be done by implementing [ExtensibleField] trait
+//! for degrees 2, 3, and 6.
 //!
 //! Quadratic extension fields are defined using the following irreducible polynomials:
 //! * For [f62](crate::fields::f62) field, the polynomial is x<sup>2</sup> - x - 1.
@@ -49,6 +49,15 @@
 //! * For [f64](crate::fields::f64) field, the polynomial is x<sup>3</sup> - x - 1.
 //! * For [f128](crate::fields::f128) field, cubic extensions are not supported.
 //!
+//! Sextic extension fields are defined as towers of extensions:
+//! * For [f64](crate::fields::f64) field, the extension is a quadratic extension of the cubic
+//!   extension defined above, over the polynomial y<sup>2</sup> - 7. An element is represented by
+//!   6 base field elements (c<sub>0</sub>, ..., c<sub>5</sub>) such that the first 3 and the last
+//!   3 elements are the coefficients of the cubic extension elements a<sub>0</sub> and
+//!   a<sub>1</sub> respectively, where the sextic element is a<sub>0</sub> + a<sub>1</sub> * y.
+//! * For [f62](crate::fields::f62) and [f128](crate::fields::f128) fields, sextic extensions are
+//!   not supported.
+//!
 //! # Polynomials
 //! [Polynomials](polynom) module implements basic polynomial operations such as:
 //!
@@ -97,16 +106,18 @@ pub mod polynom;
 
 mod field;
 pub use field::{ExtensibleField, ExtensionOf, FieldElement, StarkField, ToElements};
+pub use subtle::{Choice, ConstantTimeEq};
 pub mod fields {
     //! Finite field implementations.
     //!
     //! This module contains concrete implementations of base STARK fields as well as extensions
     //! of these field.
 
-    pub use super::field::{f128, f62, f64, CubeExtension, QuadExtension};
+    pub use super::field::{f128, f62, f64, CubeExtension, QuadExtension, SexticExtension};
 }
 
 mod utils;
 pub use crate::utils::{
     add_in_place, batch_inversion, get_power_series, get_power_series_with_offset, mul_acc,
+    random_elements, random_iter, RandomElements,
 };
@@ -11,6 +11,8 @@
 //! - Polynomial addition, subtraction, multiplication, and division.
 //! - Synthetic polynomial division for efficient division by polynomials of the form
 //!   `x`^`a` - `b`.
+//! - Construction of vanishing polynomials of multiplicative subgroups and exact division by
+//!   such polynomials.
 //!
 //! In the context of this module any slice of field elements is considered to be a polynomial
 //! in reverse coefficient form. A few examples:
@@ -25,7 +27,7 @@
 //! ```
 
 use alloc::vec::Vec;
-use core::mem;
+use core::{fmt, mem};
 
 use utils::group_slice_elements;
 
@@ -366,6 +368,51 @@ where
     result
 }
 
+/// Returns a polynomial resulting from composing two polynomials, i.e., p(q(x)).
+///
+/// Polynomials `p` and `q` are expected to be in the coefficient form, and the returned
+/// polynomial will be in the coefficient form as well. The composition is computed using Horner's
+/// method, where each step multiplies the accumulated polynomial by `q` and adds the next
+/// coefficient of `p`.
+///
+/// Degree of the resulting polynomial is deg(p) * deg(q), and thus, composition increases degrees
+/// much faster than multiplication. Specifically, the length of the returned vector will be
+/// (p.len() - 1) * (q.len() - 1) + 1 (leading zeros are not removed). If `p` is empty, an empty
+/// vector is returned; if `q` is empty, it is treated as the zero polynomial.
+///
+/// # Examples
+/// ```
+/// # use winter_math::polynom::*;
+/// # use winter_math::{fields::{f128::BaseElement}, FieldElement};
+/// // p(x) = x^2 + 1
+/// let p = [BaseElement::ONE, BaseElement::ZERO, BaseElement::ONE];
+/// // q(x) = x + 2
+/// let q = [BaseElement::new(2), BaseElement::ONE];
+///
+/// // expected result = (x + 2)^2 + 1 = x^2 + 4 * x + 5
+/// let expected = vec![BaseElement::new(5), BaseElement::new(4), BaseElement::ONE];
+/// assert_eq!(expected, compose(&p, &q));
+/// ```
+pub fn compose<E>(p: &[E], q: &[E]) -> Vec<E>
+where
+    E: FieldElement,
+{
+    let (&leading_coeff, rest) = match p.split_last() {
+        Some(split) => split,
+        None => return Vec::new(),
+    };
+    if q.is_empty() {
+        return vec![p[0]];
+    }
+
+    let mut result = vec![leading_coeff];
+    for &coeff in rest.iter().rev() {
+        result = mul(&result, q);
+        result[0] += coeff;
+    }
+    result
+}
+
 /// Returns a polynomial resulting from dividing one polynomial by another.
 ///
 /// Specifically, divides polynomial `a` by polynomial `b` and returns the result. If the
@@ -610,6 +657,118 @@ where
     }
 }
 
+// VANISHING POLYNOMIAL
+// ================================================================================================
+
+/// Returns the vanishing polynomial of a multiplicative subgroup of the specified size.
+///
+/// Specifically, returns coefficients of polynomial `x`^`domain_size` - 1, which evaluates to
+/// zero at all elements of the multiplicative subgroup of size `domain_size`.
+///
+/// # Panics
+/// Panics if `domain_size` is zero.
+///
+/// # Examples
+/// ```
+/// # use winter_math::polynom::*;
+/// # use winter_math::{fields::{f128::BaseElement}, FieldElement};
+/// // x^4 - 1
+/// let expected = [
+///     -BaseElement::ONE,
+///     BaseElement::ZERO,
+///     BaseElement::ZERO,
+///     BaseElement::ZERO,
+///     BaseElement::ONE,
+/// ];
+///
+/// assert_eq!(expected.to_vec(), vanishing_poly::<BaseElement>(4));
+/// ```
+pub fn vanishing_poly<E>(domain_size: usize) -> Vec<E>
+where
+    E: FieldElement,
+{
+    assert!(domain_size != 0, "domain size cannot be zero");
+    let mut result = vec![E::ZERO; domain_size + 1];
+    result[0] = -E::ONE;
+    result[domain_size] = E::ONE;
+    result
+}
+
+/// Divides a polynomial by the vanishing polynomial of a multiplicative subgroup of the
+/// specified size.
+///
+/// Specifically, divides polynomial `p` by polynomial `x`^`domain_size` - 1 and returns the
+/// quotient in coefficient form. The quotient contains `p.len() - domain_size` coefficients (or
+/// no coefficients if `p.len()` is not greater than `domain_size`).
+///
+/// Due to the structure of the divisor, each coefficient of the quotient is computed with a
+/// single addition, and thus, this function is significantly faster than the generic
+/// `polynom::div()` function.
+///
+/// # Errors
+/// Returns an error if `p` is not divisible by `x`^`domain_size` - 1 (i.e., if `p` does not
+/// evaluate to zero over the entire subgroup).
+///
+/// # Panics
+/// Panics if `domain_size` is zero.
+///
+/// # Examples
+/// ```
+/// # use winter_math::polynom::*;
+/// # use winter_math::{fields::{f128::BaseElement}, FieldElement};
+/// // p(x) = x^5 + 2 * x^4 - x - 2 = (x + 2) * (x^4 - 1)
+/// let p = [
+///     -BaseElement::new(2),
+///     -BaseElement::ONE,
+///     BaseElement::ZERO,
+///     BaseElement::ZERO,
+///     BaseElement::new(2),
+///     BaseElement::ONE,
+/// ];
+///
+/// // expected result = x + 2
+/// let expected = vec![BaseElement::new(2), BaseElement::ONE];
+/// assert_eq!(Ok(expected), divide_by_vanishing(&p, 4));
+///
+/// // x^5 + 2 * x^4 is not divisible by x^4 - 1
+/// let p = [
+///     BaseElement::ZERO,
+///     BaseElement::ZERO,
+///     BaseElement::ZERO,
+///     BaseElement::ZERO,
+///     BaseElement::new(2),
+///     BaseElement::ONE,
+/// ];
+/// assert_eq!(Err(DivisionError::NonZeroRemainder), divide_by_vanishing(&p, 4));
+/// ```
+pub fn divide_by_vanishing<E>(p: &[E], domain_size: usize) -> Result<Vec<E>, DivisionError>
+where
+    E: FieldElement,
+{
+    assert!(domain_size != 0, "domain size cannot be zero");
+
+    // if p(x) = q(x) * (x^n - 1), then p_j = q_{j - n} - q_j, and thus, the coefficients of
+    // the quotient can be computed starting from the highest one as q_{j - n} = p_j + q_j
+    let quotient_len = p.len().saturating_sub(domain_size);
+    let mut quotient = vec![E::ZERO; quotient_len];
+    for i in (0..quotient_len).rev() {
+        let carry = quotient.get(i + domain_size).copied().unwrap_or(E::ZERO);
+        quotient[i] = p[i + domain_size] + carry;
+    }
+
+    // the remainder consists of the lowest n coefficients of p(x) + q(x), all of which must be
+    // zero for the division to be exact
+    let remainder_len = p.len().min(domain_size);
+    for (i, &coeff) in p[..remainder_len].iter().enumerate() {
+        let remainder = if i < quotient_len { coeff + quotient[i] } else { coeff };
+        if remainder != E::ZERO {
+            return Err(DivisionError::NonZeroRemainder);
+        }
+    }
+
+    Ok(quotient)
+}
+
 // DEGREE INFERENCE
 // ================================================================================================
 
@@ -708,6 +867,26 @@ pub fn poly_from_roots<E: FieldElement>(xs: &[E]) -> Vec<E> {
     result
 }
 
+// DIVISION ERROR
+// ================================================================================================
+
+/// Defines errors which can occur during exact polynomial division.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DivisionError {
+    /// The dividend was not divisible by the divisor without a remainder.
+    NonZeroRemainder,
+}
+
+impl fmt::Display for DivisionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NonZeroRemainder => write!(f, "division resulted in a non-zero remainder"),
+        }
+    }
+}
+
+impl core::error::Error for DivisionError {}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -132,6 +132,38 @@ fn mul() {
     assert_eq!(pr, super::mul(&poly1, &poly2[..2]));
 }
 
+#[test]
+fn compose() {
+    let p: [BaseElement; 4] = [
+        BaseElement::new(384863712573444386u128),
+        BaseElement::new(7682273369345308472u128),
+        BaseElement::new(13294661765012277990u128),
+        BaseElement::new(16234810094004944758u128),
+    ];
+    let q: [BaseElement; 3] = [
+        BaseElement::new(9918505539874556741u128),
+        BaseElement::new(16401861429499852246u128),
+        BaseElement::new(12181445947541805654u128),
+    ];
+
+    // the composed polynomial has degree deg(p) * deg(q) and evaluates to p(q(x)) at any x
+    let r = super::compose(&p, &q);
+    assert_eq!((p.len() - 1) * (q.len() - 1) + 1, r.len());
+    assert_eq!(6, super::degree_of(&r));
+    for x in [BaseElement::ZERO, BaseElement::ONE, BaseElement::new(11269864713250585702u128)] {
+        assert_eq!(super::eval(&p, super::eval(&q, x)), super::eval(&r, x));
+    }
+
+    // composing with q(x) = x leaves the polynomial unchanged
+    let identity = [BaseElement::ZERO, BaseElement::ONE];
+    assert_eq!(p.to_vec(), super::compose(&p, &identity));
+
+    // constant and empty polynomials are handled as the corresponding constants
+    assert_eq!(vec![p[0]], super::compose(&p[..1], &q));
+    assert_eq!(vec![p[0]], super::compose(&p, &[]));
+    assert!(super::compose(&[], &q).is_empty());
+}
+
 #[test]
 fn div() {
     let poly1 = vec![
@@ -236,3 +268,36 @@ fn syn_div() {
     let result = super::syn_div(&poly, 4, root.exp(4));
     assert_eq!(poly, remove_leading_zeros(&super::mul(&result, &z_poly)));
 }
+
+#[test]
+fn divide_by_vanishing() {
+    let domain_size = 8_usize;
+    let root = BaseElement::get_root_of_unity(domain_size.ilog2());
+    let domain = get_power_series(root, domain_size);
+
+    // a polynomial which evaluates to zero over the entire domain is divisible by x^8 - 1
+    let quotient: Vec<BaseElement> = (1u8..6).map(BaseElement::from).collect();
+    let poly = super::mul(&quotient, &super::vanishing_poly(domain_size));
+    assert!(domain.iter().all(|&x| super::eval(&poly, x) == BaseElement::ZERO));
+    assert_eq!(Ok(quotient.clone()), super::divide_by_vanishing(&poly, domain_size));
+
+    // quotients of high-degree polynomials are computed correctly as well
+    let quotient: Vec<BaseElement> = (1u8..20).map(BaseElement::from).collect();
+    let poly = super::mul(&quotient, &super::vanishing_poly(domain_size));
+    assert_eq!(Ok(quotient), super::divide_by_vanishing(&poly, domain_size));
+
+    // the zero polynomial is divisible by any vanishing polynomial
+    assert_eq!(Ok(vec![]), super::divide_by_vanishing(&[BaseElement::ZERO; 4], domain_size));
+
+    // a polynomial which does not evaluate to zero over the domain is not divisible
+    let mut poly = poly;
+    poly[3] += BaseElement::ONE;
+    assert_eq!(
+        Err(super::DivisionError::NonZeroRemainder),
+        super::divide_by_vanishing(&poly, domain_size)
+    );
+    assert_eq!(
+        Err(super::DivisionError::NonZeroRemainder),
+        super::divide_by_vanishing(&[BaseElement::ONE], domain_size)
+    );
+}
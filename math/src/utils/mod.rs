@@ -11,6 +11,9 @@ use utils::{batch_iter_mut, iter_mut, uninit_vector};
 
 use crate::{field::FieldElement, ExtensionOf};
 
+mod random;
+pub use random::{random_elements, random_iter, RandomElements};
+
 // MATH FUNCTIONS
 // ================================================================================================
 
@@ -0,0 +1,193 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use crate::field::FieldElement;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Maximum number of bytes needed to generate a single element; this is enough for all currently
+/// supported fields and their extensions.
+const MAX_ELEMENT_BYTES: usize = 64;
+
+// RANDOM ELEMENT GENERATION
+// ================================================================================================
+
+/// Returns a vector of `n` field elements generated pseudo-randomly from the specified `seed`.
+///
+/// This is equivalent to taking the first `n` elements from the iterator returned by
+/// [random_iter()] for the same seed. See [random_iter()] for more details.
+///
+/// # Examples
+/// ```
+/// # use winter_math::random_elements;
+/// # use winter_math::fields::{f64::BaseElement, QuadExtension};
+/// let a = random_elements::<BaseElement>([1; 32], 16);
+/// let b = random_elements::<BaseElement>([1; 32], 16);
+/// assert_eq!(a, b);
+///
+/// let c = random_elements::<QuadExtension<BaseElement>>([1; 32], 16);
+/// assert_eq!(16, c.len());
+/// ```
+pub fn random_elements<E: FieldElement>(seed: [u8; 32], n: usize) -> Vec<E> {
+    random_iter(seed).take(n).collect()
+}
+
+/// Returns an infinite iterator over field elements generated pseudo-randomly from the specified
+/// `seed`.
+///
+/// The sequence of elements is fully determined by the seed, and is the same on all platforms.
+/// Elements are sampled via rejection sampling: candidate byte strings are drawn from the
+/// underlying generator and are converted into field elements using
+/// [Randomizable::from_random_bytes()](utils::Randomizable::from_random_bytes); candidates which
+/// do not encode a valid element are discarded. Thus, the generated elements are uniformly
+/// distributed over the field (as opposed to, for example, reducing random integers modulo
+/// the field modulus, which biases the distribution).
+///
+/// The elements are generated using the xoshiro256** generator, which is NOT cryptographically
+/// secure. Thus, this iterator is intended for generating test vectors and benchmark inputs only.
+///
+/// # Panics
+/// Panics if a single element of type `E` requires more than 64 bytes to encode.
+pub fn random_iter<E: FieldElement>(seed: [u8; 32]) -> RandomElements<E> {
+    RandomElements::new(seed)
+}
+
+// RANDOM ELEMENTS ITERATOR
+// ================================================================================================
+
+/// An infinite iterator over pseudo-random field elements.
+///
+/// This iterator can be instantiated via [random_iter()] function.
+#[derive(Debug, Clone)]
+pub struct RandomElements<E: FieldElement> {
+    state: [u64; 4],
+    _element: PhantomData<E>,
+}
+
+impl<E: FieldElement> RandomElements<E> {
+    fn new(seed: [u8; 32]) -> Self {
+        assert!(
+            E::VALUE_SIZE <= MAX_ELEMENT_BYTES,
+            "element size cannot exceed {MAX_ELEMENT_BYTES} bytes, but was {} bytes",
+            E::VALUE_SIZE
+        );
+
+        let mut state = [0; 4];
+        for (s, chunk) in state.iter_mut().zip(seed.chunks(8)) {
+            *s = u64::from_le_bytes(chunk.try_into().unwrap());
+        }
+
+        // the generator must not be seeded with all zeros; so, in this case, we expand the
+        // seed using the SplitMix64 generator
+        if state == [0; 4] {
+            let mut x = 0_u64;
+            for s in state.iter_mut() {
+                x = x.wrapping_add(0x9e3779b97f4a7c15);
+                let mut z = x;
+                z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+                z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+                *s = z ^ (z >> 31);
+            }
+        }
+
+        Self { state, _element: PhantomData }
+    }
+
+    /// Returns the next 64 pseudo-random bits from the underlying xoshiro256** generator.
+    fn next_u64(&mut self) -> u64 {
+        let s = &mut self.state;
+        let result = s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = s[1] << 17;
+
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+
+        result
+    }
+}
+
+impl<E: FieldElement> Iterator for RandomElements<E> {
+    type Item = E;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut bytes = [0_u8; MAX_ELEMENT_BYTES];
+        let bytes = &mut bytes[..E::VALUE_SIZE];
+        loop {
+            for chunk in bytes.chunks_mut(8) {
+                let value = self.next_u64().to_le_bytes();
+                chunk.copy_from_slice(&value[..chunk.len()]);
+            }
+            if let Some(element) = E::from_random_bytes(bytes) {
+                return Some(element);
+            }
+        }
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::{random_elements, random_iter};
+    use crate::{
+        fields::{f128, f62, f64, CubeExtension, QuadExtension},
+        FieldElement, StarkField,
+    };
+
+    #[test]
+    fn random_elements_deterministic() {
+        let a = random_elements::<f64::BaseElement>([7; 32], 64);
+        let b = random_elements::<f64::BaseElement>([7; 32], 64);
+        assert_eq!(a, b);
+
+        let c = random_elements::<f64::BaseElement>([8; 32], 64);
+        assert_ne!(a, c);
+
+        // changing the number of elements should not affect the prefix of the sequence
+        let d = random_elements::<f64::BaseElement>([7; 32], 32);
+        assert_eq!(a[..32], d);
+    }
+
+    #[test]
+    fn random_elements_zero_seed() {
+        let a = random_elements::<f128::BaseElement>([0; 32], 16);
+        assert!(a.iter().any(|&e| e != f128::BaseElement::ZERO));
+        assert_ne!(a[0], a[1]);
+    }
+
+    #[test]
+    fn random_elements_extensions() {
+        let a = random_elements::<QuadExtension<f62::BaseElement>>([3; 32], 16);
+        let b = random_elements::<QuadExtension<f62::BaseElement>>([3; 32], 16);
+        assert_eq!(a, b);
+
+        let a = random_elements::<CubeExtension<f64::BaseElement>>([3; 32], 16);
+        let b = random_iter::<CubeExtension<f64::BaseElement>>([3; 32])
+            .take(16)
+            .collect::<Vec<_>>();
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn random_elements_distribution() {
+        // for f62 field, a large fraction of candidate values is rejected; make sure that the
+        // remaining values still cover both halves of the field
+        let half = f62::BaseElement::MODULUS / 2;
+        let elements = random_elements::<f62::BaseElement>([5; 32], 1000);
+        let num_low = elements.iter().filter(|e| e.as_int() < half).count();
+        assert!(num_low > 400 && num_low < 600);
+    }
+}
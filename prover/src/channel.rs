@@ -8,13 +8,13 @@ use core::marker::PhantomData;
 
 use air::{
     proof::{Commitments, Context, OodFrame, Proof, Queries, TraceOodFrame},
-    Air, ConstraintCompositionCoefficients, DeepCompositionCoefficients,
+    Air, AuxRandElements, ConstraintCompositionCoefficients, DeepCompositionCoefficients,
 };
-use crypto::{ElementHasher, RandomCoin, VectorCommitment};
+use crypto::{ElementHasher, Hasher, ProofOfWork, RandomCoin, VectorCommitment};
 use fri::FriProof;
-use math::{FieldElement, ToElements};
-#[cfg(feature = "concurrent")]
-use utils::iterators::*;
+use math::FieldElement;
+
+use crate::{build_context, ProverTranscript};
 
 // TYPES AND INTERFACES
 // ================================================================================================
@@ -28,12 +28,18 @@ where
     V: VectorCommitment<H>,
 {
     air: &'a A,
+    pow: &'a dyn ProofOfWork<R>,
     public_coin: R,
     context: Context,
     commitments: Commitments,
     ood_frame: OodFrame,
     pow_nonce: u64,
-    _field_element: PhantomData<E>,
+    aux_rand_elements: Option<AuxRandElements<E>>,
+    constraint_composition_coeffs: Option<ConstraintCompositionCoefficients<E>>,
+    ood_point: Option<E>,
+    deep_composition_coeffs: Option<DeepCompositionCoefficients<E>>,
+    fri_alphas: Vec<E>,
+    query_positions: Vec<usize>,
     _vector_commitment: PhantomData<V>,
 }
 
@@ -50,24 +56,41 @@ where
 {
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
-    /// Creates a new prover channel for the specified `air` and public inputs.
-    pub fn new(air: &'a A, mut pub_inputs_elements: Vec<A::BaseField>) -> Self {
-        let context = Context::new::<A::BaseField>(air.trace_info().clone(), air.options().clone());
-
-        // build a seed for the public coin; the initial seed is a hash of the proof context and
-        // the public inputs, but as the protocol progresses, the coin will be reseeded with the
-        // info sent to the verifier
-        let mut coin_seed_elements = context.to_elements();
-        coin_seed_elements.append(&mut pub_inputs_elements);
+    /// Creates a new prover channel for the specified `air`, hash function and auxiliary segment
+    /// hash function identifiers, proof-of-work algorithm, and public coin.
+    ///
+    /// The public coin is expected to be already seeded with the proof context and the public
+    /// inputs (or a commitment to them); as the protocol progresses, the coin will be reseeded
+    /// with the info sent to the verifier.
+    pub fn new(
+        air: &'a A,
+        hash_id: Option<u8>,
+        aux_hash_id: Option<u8>,
+        pow: &'a dyn ProofOfWork<R>,
+        public_coin: R,
+    ) -> Self {
+        let context = build_context::<A::BaseField>(
+            air.trace_info().clone(),
+            air.options().clone(),
+            hash_id,
+            aux_hash_id,
+            pow.id(),
+        );
 
         ProverChannel {
             air,
-            public_coin: RandomCoin::new(&coin_seed_elements),
+            pow,
+            public_coin,
             context,
             commitments: Commitments::default(),
             ood_frame: OodFrame::default(),
             pow_nonce: 0,
-            _field_element: PhantomData,
+            aux_rand_elements: None,
+            constraint_composition_coeffs: None,
+            ood_point: None,
+            deep_composition_coeffs: None,
+            fri_alphas: Vec::new(),
+            query_positions: Vec::new(),
             _vector_commitment: PhantomData,
         }
     }
@@ -81,6 +104,19 @@ where
         self.public_coin.reseed(trace_root);
     }
 
+    /// Commits the prover to the extended auxiliary trace segment.
+    ///
+    /// The auxiliary segment may be committed to using hash function `AH` different from the one
+    /// used by the channel; in such a case, the commitment is converted into a digest of the
+    /// channel's hash function before it is absorbed into the public coin.
+    pub fn commit_aux_trace<AH>(&mut self, trace_root: AH::Digest)
+    where
+        AH: Hasher<Digest: Into<H::Digest>>,
+    {
+        self.commitments.add::<AH>(&trace_root);
+        self.public_coin.reseed(trace_root.into());
+    }
+
     /// Commits the prover to the evaluations of the constraint composition polynomial.
     pub fn commit_constraints(&mut self, constraint_root: H::Digest) {
         self.commitments.add::<H>(&constraint_root);
@@ -109,27 +145,41 @@ where
         &mut self.public_coin
     }
 
+    /// Records random elements drawn from the inner public coin for building the auxiliary trace
+    /// segment.
+    pub fn record_aux_rand_elements(&mut self, aux_rand_elements: &AuxRandElements<E>) {
+        self.aux_rand_elements = Some(aux_rand_elements.clone());
+    }
+
     /// Returns a set of coefficients for constructing a constraint composition polynomial.
     ///
     /// The coefficients are drawn from the public coin uniformly at random.
     pub fn get_constraint_composition_coeffs(&mut self) -> ConstraintCompositionCoefficients<E> {
-        self.air
+        let coefficients = self
+            .air
             .get_constraint_composition_coefficients(&mut self.public_coin)
-            .expect("failed to draw composition coefficients")
+            .expect("failed to draw composition coefficients");
+        self.constraint_composition_coeffs = Some(coefficients.clone());
+        coefficients
     }
 
     /// Returns an out-of-domain point drawn uniformly at random from the public coin.
     pub fn get_ood_point(&mut self) -> E {
-        self.public_coin.draw().expect("failed to draw OOD point")
+        let z = self.public_coin.draw().expect("failed to draw OOD point");
+        self.ood_point = Some(z);
+        z
     }
 
     /// Returns a set of coefficients for constructing a DEEP composition polynomial.
     ///
     /// The coefficients are drawn from the public coin uniformly at random.
     pub fn get_deep_composition_coeffs(&mut self) -> DeepCompositionCoefficients<E> {
-        self.air
+        let coefficients = self
+            .air
             .get_deep_composition_coefficients(&mut self.public_coin)
-            .expect("failed to draw DEEP composition coefficients")
+            .expect("failed to draw DEEP composition coefficients");
+        self.deep_composition_coeffs = Some(coefficients.clone());
+        coefficients
     }
 
     /// Returns a set of positions in the LDE domain against which the evaluations of trace and
@@ -144,6 +194,7 @@ where
             .public_coin
             .draw_integers(num_queries, lde_domain_size, self.pow_nonce)
             .expect("failed to draw query position");
+        self.query_positions.clone_from(&positions);
 
         // remove any duplicate positions from the list
         positions.sort_unstable();
@@ -152,30 +203,48 @@ where
         positions
     }
 
-    /// Determines a nonce, which when hashed with the current seed of the public coin results
-    /// in a new seed with the number of leading zeros equal to the grinding_factor specified
-    /// in the proof options.
-    pub fn grind_query_seed(&mut self) {
-        let grinding_factor = self.context.options().grinding_factor();
+    /// Returns the specified positions in the LDE domain against which the evaluations of trace
+    /// and constraint composition polynomials should be queried.
+    ///
+    /// Duplicate positions are removed from the returned vector, and the positions are sorted in
+    /// the same way as the positions returned from [Self::get_query_positions()].
+    ///
+    /// # Panics
+    /// Panics if `positions` is empty or if any of the positions is outside of the LDE domain.
+    #[cfg(feature = "external-queries")]
+    pub fn normalize_query_positions(&self, mut positions: Vec<usize>) -> Vec<usize> {
+        let lde_domain_size = self.context.lde_domain_size();
+        assert!(!positions.is_empty(), "at least one query position must be specified");
+        assert!(
+            positions.iter().all(|&p| p < lde_domain_size),
+            "query positions must be smaller than the LDE domain size {lde_domain_size}"
+        );
 
-        #[cfg(not(feature = "concurrent"))]
-        let nonce = (1..u64::MAX)
-            .find(|&nonce| self.public_coin.check_leading_zeros(nonce) >= grinding_factor)
-            .expect("nonce not found");
+        positions.sort_unstable();
+        positions.dedup();
 
-        #[cfg(feature = "concurrent")]
-        let nonce = (1..u64::MAX)
-            .into_par_iter()
-            .find_any(|&nonce| self.public_coin.check_leading_zeros(nonce) >= grinding_factor)
-            .expect("nonce not found");
+        positions
+    }
 
-        self.pow_nonce = nonce;
+    /// Determines a nonce which, together with the current seed of the public coin, satisfies
+    /// the proof-of-work of the channel's algorithm with difficulty equal to the grinding factor
+    /// specified in the proof options.
+    pub fn grind_query_seed(&mut self) {
+        let grinding_factor = self.context.options().grinding_factor();
+        self.pow_nonce = self.pow.solve(&self.public_coin, grinding_factor);
     }
 
     // PROOF BUILDER
     // --------------------------------------------------------------------------------------------
     /// Builds a proof from the previously committed values as well as values passed into
     /// this method.
+    ///
+    /// The public coin in the state it is in at the end of the protocol, as well as the record of
+    /// all values drawn from the public coin, are returned together with the proof.
+    ///
+    /// # Panics
+    /// Panics if constraint composition coefficients, the out-of-domain point, or DEEP composition
+    /// coefficients have not been drawn yet.
     pub fn build_proof(
         self,
         trace_queries: Vec<Queries>,
@@ -183,10 +252,10 @@ where
         fri_proof: FriProof,
         num_query_positions: usize,
         gkr_proof: Option<Vec<u8>>,
-    ) -> Proof {
+    ) -> (Proof, R, ProverTranscript<E>) {
         assert!(num_query_positions <= u8::MAX as usize, "num_query_positions too big");
 
-        Proof {
+        let proof = Proof {
             context: self.context,
             commitments: self.commitments,
             ood_frame: self.ood_frame,
@@ -196,7 +265,23 @@ where
             pow_nonce: self.pow_nonce,
             num_unique_queries: num_query_positions as u8,
             gkr_proof,
-        }
+        };
+
+        let transcript = ProverTranscript {
+            aux_rand_elements: self.aux_rand_elements,
+            constraint_composition_coeffs: self
+                .constraint_composition_coeffs
+                .expect("constraint composition coefficients have not been drawn"),
+            ood_point: self.ood_point.expect("out-of-domain point has not been drawn"),
+            deep_composition_coeffs: self
+                .deep_composition_coeffs
+                .expect("DEEP composition coefficients have not been drawn"),
+            fri_alphas: self.fri_alphas,
+            pow_nonce: self.pow_nonce,
+            query_positions: self.query_positions,
+        };
+
+        (proof, self.public_coin, transcript)
     }
 }
 
@@ -221,6 +306,8 @@ where
 
     /// Returns a new alpha drawn from the public coin.
     fn draw_fri_alpha(&mut self) -> E {
-        self.public_coin.draw().expect("failed to draw FRI alpha")
+        let alpha = self.public_coin.draw().expect("failed to draw FRI alpha");
+        self.fri_alphas.push(alpha);
+        alpha
     }
 }
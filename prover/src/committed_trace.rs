@@ -0,0 +1,64 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use air::PartitionOptions;
+use math::FieldElement;
+
+use crate::{Prover, StarkDomain, TraceLde, TracePolyTable};
+
+// COMMITTED TRACE
+// ================================================================================================
+
+/// Low-degree extension of and commitment to the main segment of an execution trace which can be
+/// reused across multiple proof generation runs.
+///
+/// Extending the main trace segment and committing to the extension are among the most expensive
+/// steps of proof generation, and they depend only on the trace, the blowup factor, and the
+/// partition options. A committed trace is built via [Prover::commit_to_trace()], and can then be
+/// passed to [Prover::prove_with_committed_trace()] any number of times to generate proofs with
+/// different proof options (e.g., when tuning the number of queries or the grinding factor)
+/// without recomputing the low-degree extension, as long as the blowup factor and the partition
+/// options remain the same. To sweep over different blowup factors, a separate committed trace
+/// needs to be built for each of them.
+///
+/// The trace is extended in field `E`, and thus, proofs can be generated from a committed trace
+/// only with proof options which specify the field extension corresponding to `E`.
+pub struct CommittedTrace<'a, P, E>
+where
+    P: Prover + ?Sized,
+    E: FieldElement<BaseField = P::BaseField>,
+{
+    pub(crate) trace: &'a P::Trace,
+    pub(crate) trace_lde: P::TraceLde<E>,
+    pub(crate) trace_polys: TracePolyTable<E>,
+    pub(crate) domain: StarkDomain<P::BaseField>,
+    pub(crate) partition_options: PartitionOptions,
+}
+
+impl<P, E> CommittedTrace<'_, P, E>
+where
+    P: Prover + ?Sized,
+    E: FieldElement<BaseField = P::BaseField>,
+{
+    /// Returns the execution trace to which this committed trace commits.
+    pub fn trace(&self) -> &P::Trace {
+        self.trace
+    }
+
+    /// Returns the blowup factor with which the main trace segment was extended.
+    pub fn blowup_factor(&self) -> usize {
+        self.domain.trace_to_lde_blowup()
+    }
+
+    /// Returns the partition options with which the main trace segment was committed to.
+    pub fn partition_options(&self) -> PartitionOptions {
+        self.partition_options
+    }
+
+    /// Returns the commitment to the main trace segment.
+    pub fn main_trace_commitment(&self) -> <P::HashFn as crypto::Hasher>::Digest {
+        self.trace_lde.get_main_trace_commitment()
+    }
+}
@@ -18,6 +18,14 @@ use super::{constraints::CompositionPoly, StarkDomain, TracePolyTable};
 
 // DEEP COMPOSITION POLYNOMIAL
 // ================================================================================================
+/// A random linear combination of trace and constraint composition polynomials with out-of-domain
+/// points divided out.
+///
+/// Polynomials of all trace segments (main and auxiliary), as well as all constraint composition
+/// column polynomials, are interpolated over the trace domain, and thus, all of them have the
+/// same degree bound (trace length - 1). Combining them into a single polynomial does not impose
+/// a higher degree bound on any of them, and so, a single FRI instance is sufficient to prove
+/// low-degreeness of all segments.
 pub struct DeepCompositionPoly<E: FieldElement> {
     coefficients: Vec<E>,
     cc: DeepCompositionCoefficients<E>,
@@ -3,7 +3,7 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use alloc::vec::Vec;
+use alloc::{borrow::Cow, vec::Vec};
 
 #[cfg(debug_assertions)]
 use air::TransitionConstraints;
@@ -109,6 +109,7 @@ impl<'a, E: FieldElement> ConstraintEvaluationTable<'a, E> {
 
     /// Break the table into the number of specified fragments. All fragments can be updated
     /// independently - e.g. in different threads.
+    #[allow(mismatched_lifetime_syntaxes)]
     pub fn fragments(&mut self, num_fragments: usize) -> Vec<EvaluationTableFragment<E>> {
         let fragment_size = self.num_rows() / num_fragments;
         assert!(
@@ -156,11 +157,42 @@ impl<'a, E: FieldElement> ConstraintEvaluationTable<'a, E> {
         result
     }
 
+    // TRANSITION EVALUATIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Adds the provided merged transition constraint evaluations to the first column of this
+    /// table.
+    ///
+    /// # Panics
+    /// Panics if the number of provided evaluations is not equal to the number of rows in this
+    /// table.
+    pub fn add_transition_evaluations(&mut self, evaluations: &[E]) {
+        assert_eq!(evaluations.len(), self.num_rows(), "invalid number of transition evaluations");
+        iter_mut!(self.evaluations[0], 1024)
+            .zip(evaluations)
+            .for_each(|(acc_value, &value)| *acc_value += value);
+    }
+
+    /// Replaces evaluations of main transition constraint at the specified index with the
+    /// provided evaluations; available only in debug mode.
+    #[cfg(debug_assertions)]
+    pub fn set_main_transition_evaluations(
+        &mut self,
+        constraint_idx: usize,
+        evaluations: Vec<E::BaseField>,
+    ) {
+        assert_eq!(evaluations.len(), self.num_rows(), "invalid number of transition evaluations");
+        self.main_transition_evaluations[constraint_idx] = evaluations;
+    }
+
     // CONSTRAINT COMPOSITION
     // --------------------------------------------------------------------------------------------
     /// Divides constraint evaluation columns by their respective divisor (in evaluation form) and
     /// combines the results into a single column.
-    pub fn combine(self) -> Vec<E> {
+    ///
+    /// Inverse evaluations of divisor numerators are taken from the provided `divisor_table` for
+    /// divisors contained in it, and are computed for all other divisors.
+    pub fn combine(self, divisor_table: Option<&DivisorTable<E::BaseField>>) -> Vec<E> {
         // allocate memory for the combined polynomial
         let mut combined_poly = vec![E::ZERO; self.num_rows()];
 
@@ -168,8 +200,15 @@ impl<'a, E: FieldElement> ConstraintEvaluationTable<'a, E> {
         // by the evaluations of its corresponding divisor, and add all resulting evaluations
         // together into a single vector
         for (column, divisor) in self.evaluations.into_iter().zip(self.divisors.iter()) {
+            // compute inverse evaluations of the divisor's numerator, unless they have been
+            // precomputed
+            let z = match divisor_table.and_then(|table| table.get(divisor)) {
+                Some(z) => Cow::Borrowed(z),
+                None => Cow::Owned(get_inv_evaluation(divisor, self.domain)),
+            };
+
             // divide the column by the divisor and accumulate the result into combined_poly
-            acc_column(column, divisor, self.domain, &mut combined_poly);
+            acc_column(column, divisor, &z, self.domain, &mut combined_poly);
         }
 
         combined_poly
@@ -317,15 +356,10 @@ fn make_fragments<E: FieldElement>(
 fn acc_column<E: FieldElement>(
     column: Vec<E>,
     divisor: &ConstraintDivisor<E::BaseField>,
+    z: &[E::BaseField],
     domain: &StarkDomain<E::BaseField>,
     result: &mut [E],
 ) {
-    let numerator = divisor.numerator();
-    assert_eq!(numerator.len(), 1, "complex divisors are not yet supported");
-
-    // compute inverse evaluations of the divisor's numerator, which has the form (x^a - b)
-    let z = get_inv_evaluation(divisor, domain);
-
     // divide column values by the divisor; for boundary constraints this computed simply as
     // multiplication of column value by the inverse of divisor numerator; for transition
     // constraints, it is computed similarly, but the result is also multiplied by the divisor's
@@ -366,12 +400,14 @@ fn acc_column<E: FieldElement>(
     }
 }
 
-/// Computes evaluations of the divisor's numerator over the domain of the specified size and offset.
+/// Computes inverse evaluations of the divisor's numerator, which has the form (x^a - b), over
+/// the constraint evaluation domain.
 fn get_inv_evaluation<B: StarkField>(
     divisor: &ConstraintDivisor<B>,
     domain: &StarkDomain<B>,
 ) -> Vec<B> {
     let numerator = divisor.numerator();
+    assert_eq!(numerator.len(), 1, "complex divisors are not yet supported");
     let a = numerator[0].0 as u64; // numerator degree
     let b = numerator[0].1;
 
@@ -405,6 +441,43 @@ fn get_inv_evaluation<B: StarkField>(
     batch_inversion(&evaluations)
 }
 
+// DIVISOR TABLE
+// ================================================================================================
+
+/// Inverse evaluations of constraint divisor numerators over the constraint evaluation domain.
+///
+/// Since these evaluations depend only on the numerator of a divisor, a single set of evaluations
+/// is kept for all divisors with the same numerator.
+pub(crate) struct DivisorTable<B: StarkField> {
+    numerators: Vec<Vec<(usize, B)>>,
+    evaluations: Vec<Vec<B>>,
+}
+
+impl<B: StarkField> DivisorTable<B> {
+    /// Returns a table with inverse evaluations of numerators of the specified divisors over the
+    /// constraint evaluation domain of the specified `domain`.
+    pub fn new(divisors: &[ConstraintDivisor<B>], domain: &StarkDomain<B>) -> Self {
+        let mut numerators = Vec::new();
+        let mut evaluations = Vec::new();
+        for divisor in divisors {
+            if !numerators.iter().any(|numerator| numerator == divisor.numerator()) {
+                numerators.push(divisor.numerator().to_vec());
+                evaluations.push(get_inv_evaluation(divisor, domain));
+            }
+        }
+        Self { numerators, evaluations }
+    }
+
+    /// Returns inverse evaluations of the numerator of the specified divisor, if the table
+    /// contains them.
+    pub fn get(&self, divisor: &ConstraintDivisor<B>) -> Option<&[B]> {
+        self.numerators
+            .iter()
+            .position(|numerator| numerator == divisor.numerator())
+            .map(|idx| self.evaluations[idx].as_slice())
+    }
+}
+
 // DEBUG HELPERS
 // ================================================================================================
 
@@ -3,20 +3,25 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
+use alloc::{sync::Arc, vec::Vec};
+use core::cmp;
+
 use air::{
     Air, AuxRandElements, ConstraintCompositionCoefficients, EvaluationFrame, TransitionConstraints,
 };
-use math::FieldElement;
+use math::{fft, FieldElement, StarkField};
 use tracing::instrument;
-use utils::iter_mut;
+use utils::{batch_iter_mut, iter_mut, uninit_vector};
 #[cfg(feature = "concurrent")]
 use utils::{iterators::*, rayon};
 
 use super::{
-    super::EvaluationTableFragment, lagrange::LagrangeKernelConstraintsBatchEvaluator,
+    super::{DivisorTable, EvaluationTableFragment},
+    lagrange::LagrangeKernelConstraintsBatchEvaluator,
     BoundaryConstraints, CompositionPolyTrace, ConstraintEvaluationTable, ConstraintEvaluator,
     PeriodicValueTable, StarkDomain, TraceLde,
 };
+use crate::ProvingKey;
 
 // CONSTANTS
 // ================================================================================================
@@ -36,13 +41,21 @@ const MIN_CONCURRENT_DOMAIN_SIZE: usize = 8192;
 /// When `concurrent` feature is enabled, the extended execution trace is split into sets of
 /// sequential evaluation frames (called fragments), and frames in each fragment are evaluated
 /// in separate threads.
+///
+/// Constraints are grouped by the trace segment they are applied against. If the transition
+/// constraints against the main trace segment have lower degree than the ones against the
+/// auxiliary segment, the main transition constraints are evaluated over the smallest subdomain
+/// of the constraint evaluation domain which can accommodate their degree, and the resulting
+/// evaluations are then extended to the entire domain via polynomial interpolation. All other
+/// constraints are evaluated over the entire constraint evaluation domain.
 pub struct DefaultConstraintEvaluator<'a, A: Air, E: FieldElement<BaseField = A::BaseField>> {
     air: &'a A,
     boundary_constraints: BoundaryConstraints<E>,
     transition_constraints: TransitionConstraints<E>,
     lagrange_constraints_evaluator: Option<LagrangeKernelConstraintsBatchEvaluator<E>>,
     aux_rand_elements: Option<AuxRandElements<E>>,
-    periodic_values: PeriodicValueTable<E::BaseField>,
+    periodic_values: Option<Arc<PeriodicValueTable<E::BaseField>>>,
+    divisors: Option<Arc<DivisorTable<E::BaseField>>>,
 }
 
 impl<A, E> ConstraintEvaluator<E> for DefaultConstraintEvaluator<'_, A, E>
@@ -76,6 +89,12 @@ where
         let mut divisors = vec![self.transition_constraints.divisor().clone()];
         divisors.append(&mut self.boundary_constraints.get_divisors());
 
+        // build periodic value table, unless it has been taken from a proving key
+        let periodic_values = match &self.periodic_values {
+            Some(periodic_values) => periodic_values.clone(),
+            None => Arc::new(PeriodicValueTable::new(self.air)),
+        };
+
         // allocate space for constraint evaluations; when we are in debug mode, we also allocate
         // memory to hold all transition constraint evaluations (before they are merged into a
         // single value) so that we can check their degrees later
@@ -99,27 +118,55 @@ where
             1
         };
 
+        // if the trace consists of multiple segments, determine whether main transition
+        // constraints can be evaluated over a subdomain of the constraint evaluation domain
+        let main_subdomain_size = if self.air.trace_info().is_multi_segment() {
+            self.get_main_transition_subdomain_size(domain)
+        } else {
+            None
+        };
+
         // evaluate constraints for each fragment; if the trace consist of multiple segments
         // we evaluate constraints for all segments. otherwise, we evaluate constraints only
-        // for the main segment.
+        // for the main segment. main transition constraints are skipped here if they are
+        // evaluated over a subdomain.
         let mut fragments = evaluation_table.fragments(num_fragments);
         iter_mut!(fragments).for_each(|fragment| {
             if self.air.trace_info().is_multi_segment() {
-                self.evaluate_fragment_full(trace, domain, fragment);
+                self.evaluate_fragment_full(
+                    trace,
+                    domain,
+                    &periodic_values,
+                    main_subdomain_size.is_none(),
+                    fragment,
+                );
             } else {
-                self.evaluate_fragment_main(trace, domain, fragment);
+                self.evaluate_fragment_main(trace, domain, &periodic_values, fragment);
             }
         });
 
+        // evaluate main transition constraints over the subdomain (if needed), and add the
+        // evaluations extended to the entire constraint evaluation domain to the table
+        if let Some(subdomain_size) = main_subdomain_size {
+            self.evaluate_main_transition_over_subdomain(
+                trace,
+                domain,
+                &periodic_values,
+                subdomain_size,
+                &mut evaluation_table,
+            );
+        }
+
         // when in debug mode, make sure expected transition constraint degrees align with
         // actual degrees we got during constraint evaluation
         #[cfg(debug_assertions)]
         evaluation_table.validate_transition_degrees();
 
         // combine all constraint evaluations into a single column, including the evaluations of the
-        // Lagrange kernel constraints (if present)
+        // Lagrange kernel constraints (if present); divisors precomputed in a proving key (if any)
+        // are reused
         let combined_evaluations = {
-            let mut constraints_evaluations = evaluation_table.combine();
+            let mut constraints_evaluations = evaluation_table.combine(self.divisors.as_deref());
             self.evaluate_lagrange_kernel_constraints(trace, domain, &mut constraints_evaluations);
 
             constraints_evaluations
@@ -127,6 +174,17 @@ where
 
         CompositionPolyTrace::new(combined_evaluations)
     }
+
+    fn with_proving_key(mut self, key: &ProvingKey<E::BaseField>) -> Self {
+        debug_assert_eq!(
+            self.air.trace_info(),
+            key.trace_info(),
+            "key built for a different trace"
+        );
+        self.periodic_values = Some(key.periodic_values().clone());
+        self.divisors = Some(key.divisors().clone());
+        self
+    }
 }
 
 impl<'a, A, E> DefaultConstraintEvaluator<'a, A, E>
@@ -147,8 +205,6 @@ where
         // evaluations
         let transition_constraints =
             air.get_transition_constraints(&composition_coefficients.transition);
-        // build periodic value table
-        let periodic_values = PeriodicValueTable::new(air);
 
         // build boundary constraint groups; these will be used to evaluate and compose boundary
         // constraint evaluations.
@@ -181,10 +237,26 @@ where
             transition_constraints,
             lagrange_constraints_evaluator,
             aux_rand_elements,
-            periodic_values,
+            periodic_values: None,
+            divisors: None,
         }
     }
 
+    /// Returns a new evaluator which can be used to evaluate transition and boundary constraints
+    /// over extended execution trace, reusing values precomputed in the specified proving key.
+    ///
+    /// This is equivalent to [DefaultConstraintEvaluator::new()] followed by
+    /// [ConstraintEvaluator::with_proving_key()]. The `key` must have been built for the same
+    /// computation as `air`.
+    pub fn with_key(
+        air: &'a A,
+        key: &ProvingKey<A::BaseField>,
+        aux_rand_elements: Option<AuxRandElements<E>>,
+        composition_coefficients: ConstraintCompositionCoefficients<E>,
+    ) -> Self {
+        Self::new(air, aux_rand_elements, composition_coefficients).with_proving_key(key)
+    }
+
     // EVALUATION HELPERS
     // --------------------------------------------------------------------------------------------
 
@@ -195,6 +267,7 @@ where
         &self,
         trace: &T,
         domain: &StarkDomain<A::BaseField>,
+        periodic_values: &PeriodicValueTable<A::BaseField>,
         fragment: &mut EvaluationTableFragment<E>,
     ) {
         // initialize buffers to hold trace values and evaluation results at each step;
@@ -214,10 +287,13 @@ where
             // table is extended over the LDE domain, so, we need to convert step in constraint
             // evaluation domain, into a step in LDE domain, in case these domains are different
             trace.read_main_trace_frame_into(step << lde_shift, &mut main_frame);
+            main_frame.evaluate_virtual_columns(self.air);
 
             // evaluate transition constraints and save the merged result the first slot of the
             // evaluations buffer
-            evaluations[0] = self.evaluate_main_transition(&main_frame, step, &mut t_evaluations);
+            let periodic_values = periodic_values.get_row(step);
+            evaluations[0] =
+                self.evaluate_main_transition(&main_frame, periodic_values, &mut t_evaluations);
 
             // when in debug mode, save transition constraint evaluations
             #[cfg(debug_assertions)]
@@ -241,11 +317,14 @@ where
     /// Evaluates constraints for a single fragment of the evaluation table.
     ///
     /// This evaluates constraints only over all segments of the execution trace (i.e. main segment
-    /// and all auxiliary segments).
+    /// and all auxiliary segments). If `evaluate_main_transition` is false, transition constraints
+    /// against the main segment are not evaluated.
     fn evaluate_fragment_full<T: TraceLde<E>>(
         &self,
         trace: &T,
         domain: &StarkDomain<A::BaseField>,
+        periodic_values: &PeriodicValueTable<A::BaseField>,
+        evaluate_main_transition: bool,
         fragment: &mut EvaluationTableFragment<E>,
     ) {
         // initialize buffers to hold trace values and evaluation results at each step
@@ -264,19 +343,33 @@ where
 
             // read both the main and the auxiliary evaluation frames from the trace
             trace.read_main_trace_frame_into(step << lde_shift, &mut main_frame);
+            main_frame.evaluate_virtual_columns(self.air);
             trace.read_aux_trace_frame_into(step << lde_shift, &mut aux_frame);
 
             // evaluate transition constraints and save the merged result the first slot of the
             // evaluations buffer; we evaluate and compose constraints in the same function, we
             // can just add up the results of evaluating main and auxiliary constraints.
-            evaluations[0] = self.evaluate_main_transition(&main_frame, step, &mut tm_evaluations);
-
-            evaluations[0] +=
-                self.evaluate_aux_transition(&main_frame, &aux_frame, step, &mut ta_evaluations);
+            let periodic_values = periodic_values.get_row(step);
+            evaluations[0] = if evaluate_main_transition {
+                self.evaluate_main_transition(&main_frame, periodic_values, &mut tm_evaluations)
+            } else {
+                E::ZERO
+            };
+
+            evaluations[0] += self.evaluate_aux_transition(
+                &main_frame,
+                &aux_frame,
+                periodic_values,
+                &mut ta_evaluations,
+            );
 
             // when in debug mode, save transition constraint evaluations
             #[cfg(debug_assertions)]
-            fragment.update_transition_evaluations(i, &tm_evaluations, &ta_evaluations);
+            fragment.update_transition_evaluations(
+                i,
+                if evaluate_main_transition { &tm_evaluations } else { &[] },
+                &ta_evaluations,
+            );
 
             // evaluate boundary constraints; the results go into remaining slots of the
             // evaluations buffer
@@ -295,6 +388,75 @@ where
         }
     }
 
+    /// Evaluates transition constraints of the main execution trace segment over a subdomain of the
+    /// constraint evaluation domain of the specified size, and adds the merged evaluations
+    /// extended to the entire constraint evaluation domain to the `evaluation_table`.
+    ///
+    /// The subdomain is the subgroup of the constraint evaluation domain of size `subdomain_size`
+    /// shifted by the domain offset. Thus, the i-th point of the subdomain is the same as the
+    /// point at step `i * (ce_domain_size / subdomain_size)` of the constraint evaluation domain.
+    fn evaluate_main_transition_over_subdomain<T: TraceLde<E>>(
+        &self,
+        trace: &T,
+        domain: &StarkDomain<A::BaseField>,
+        periodic_values: &PeriodicValueTable<A::BaseField>,
+        subdomain_size: usize,
+        evaluation_table: &mut ConstraintEvaluationTable<E>,
+    ) {
+        let step_size = domain.ce_domain_size() / subdomain_size;
+
+        // evaluate and merge main transition constraints at all points of the subdomain
+        let mut evaluations = unsafe { uninit_vector::<E>(subdomain_size) };
+        batch_iter_mut!(
+            &mut evaluations,
+            128, // min batch size
+            |batch: &mut [E], batch_offset: usize| {
+                let mut main_frame = EvaluationFrame::new(trace.trace_info().main_trace_width());
+                let mut t_evaluations =
+                    vec![E::BaseField::ZERO; self.num_main_transition_constraints()];
+                for (i, evaluation) in batch.iter_mut().enumerate() {
+                    let step = (batch_offset + i) * step_size;
+                    self.read_main_frame_into(trace, domain, step, &mut main_frame);
+                    *evaluation = self.evaluate_main_transition(
+                        &main_frame,
+                        periodic_values.get_row(step),
+                        &mut t_evaluations,
+                    );
+                }
+            }
+        );
+
+        // extend the merged evaluations to the entire constraint evaluation domain
+        let evaluations = extend_evaluations(evaluations, domain, step_size);
+        evaluation_table.add_transition_evaluations(&evaluations);
+
+        // when in debug mode, save individual transition constraint evaluations extended to the
+        // entire constraint evaluation domain so that their degrees could be checked later
+        #[cfg(debug_assertions)]
+        {
+            let num_constraints = self.num_main_transition_constraints();
+            let mut main_frame = EvaluationFrame::new(trace.trace_info().main_trace_width());
+            let mut t_evaluations = vec![E::BaseField::ZERO; num_constraints];
+            let mut columns = vec![Vec::with_capacity(subdomain_size); num_constraints];
+            for i in 0..subdomain_size {
+                let step = i * step_size;
+                self.read_main_frame_into(trace, domain, step, &mut main_frame);
+                self.evaluate_main_transition(
+                    &main_frame,
+                    periodic_values.get_row(step),
+                    &mut t_evaluations,
+                );
+                for (column, &value) in columns.iter_mut().zip(t_evaluations.iter()) {
+                    column.push(value);
+                }
+            }
+            for (constraint_idx, column) in columns.into_iter().enumerate() {
+                let column = extend_evaluations(column, domain, step_size);
+                evaluation_table.set_main_transition_evaluations(constraint_idx, column);
+            }
+        }
+    }
+
     /// If present, evaluates the Lagrange kernel constraints over the constraint evaluation domain.
     /// The evaluation of each constraint (both boundary and transition) is divided by its divisor,
     /// multiplied by its composition coefficient, the result of which is added to
@@ -329,15 +491,12 @@ where
     fn evaluate_main_transition(
         &self,
         main_frame: &EvaluationFrame<E::BaseField>,
-        step: usize,
+        periodic_values: &[E::BaseField],
         evaluations: &mut [E::BaseField],
     ) -> E {
         // TODO: use a more efficient way to zero out memory
         evaluations.fill(E::BaseField::ZERO);
 
-        // get periodic values at the evaluation step
-        let periodic_values = self.periodic_values.get_row(step);
-
         // evaluate transition constraints over the main segment of the execution trace and save
         // the results into evaluations buffer
         self.air.evaluate_transition(main_frame, periodic_values, evaluations);
@@ -359,15 +518,12 @@ where
         &self,
         main_frame: &EvaluationFrame<E::BaseField>,
         aux_frame: &EvaluationFrame<E>,
-        step: usize,
+        periodic_values: &[E::BaseField],
         evaluations: &mut [E],
     ) -> E {
         // TODO: use a more efficient way to zero out memory
         evaluations.fill(E::ZERO);
 
-        // get periodic values at the evaluation step
-        let periodic_values = self.periodic_values.get_row(step);
-
         // evaluate transition constraints over the auxiliary trace segment and save the results into
         // evaluations buffer
         self.air.evaluate_aux_transition(
@@ -388,9 +544,45 @@ where
             .fold(E::ZERO, |acc, (&const_eval, &coef)| acc + coef * const_eval)
     }
 
+    /// Reads the main trace evaluation frame at the specified step of the constraint evaluation
+    /// domain into the `frame`, and computes the values of virtual columns for the frame.
+    fn read_main_frame_into<T: TraceLde<E>>(
+        &self,
+        trace: &T,
+        domain: &StarkDomain<A::BaseField>,
+        step: usize,
+        frame: &mut EvaluationFrame<E::BaseField>,
+    ) {
+        let lde_shift = domain.ce_to_lde_blowup().trailing_zeros();
+        trace.read_main_trace_frame_into(step << lde_shift, frame);
+        frame.evaluate_virtual_columns(self.air);
+    }
+
     // ACCESSORS
     // --------------------------------------------------------------------------------------------
 
+    /// Returns the size of the subdomain of the constraint evaluation domain over which
+    /// transition constraints against the main trace segment should be evaluated, or None if
+    /// they should be evaluated over the entire domain.
+    ///
+    /// The subdomain size is the smallest power of two greater than the degree of any main
+    /// transition constraint polynomial (before it is divided by the divisor); None is returned
+    /// if this size is not smaller than the size of the constraint evaluation domain.
+    fn get_main_transition_subdomain_size(
+        &self,
+        domain: &StarkDomain<A::BaseField>,
+    ) -> Option<usize> {
+        let trace_length = domain.trace_length();
+        let max_degree = self
+            .transition_constraints
+            .main_constraint_degrees()
+            .iter()
+            .map(|degree| degree.get_evaluation_degree(trace_length))
+            .max()?;
+        let subdomain_size = cmp::max(max_degree + 1, trace_length).next_power_of_two();
+        (subdomain_size < domain.ce_domain_size()).then_some(subdomain_size)
+    }
+
     /// Returns the number of transition constraints applied against the main segment of the
     /// execution trace.
     fn num_main_transition_constraints(&self) -> usize {
@@ -402,3 +594,23 @@ where
         self.transition_constraints.num_aux_constraints()
     }
 }
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Extends evaluations of a polynomial over a subdomain of the constraint evaluation domain to
+/// the entire constraint evaluation domain.
+///
+/// The subdomain is expected to consist of every `step_size`-th point of the constraint
+/// evaluation domain, and the degree of the polynomial is expected to be smaller than the size of
+/// the subdomain.
+fn extend_evaluations<B: StarkField, F: FieldElement<BaseField = B>>(
+    mut evaluations: Vec<F>,
+    domain: &StarkDomain<B>,
+    step_size: usize,
+) -> Vec<F> {
+    let inv_twiddles = fft::get_inv_twiddles::<B>(evaluations.len());
+    fft::interpolate_poly_with_offset(&mut evaluations, &inv_twiddles, domain.offset());
+    let twiddles = fft::get_twiddles::<B>(evaluations.len());
+    fft::evaluate_poly_with_offset(&evaluations, &twiddles, domain.offset(), step_size)
+}
@@ -6,7 +6,10 @@
 use air::Air;
 use math::FieldElement;
 
-use super::{super::TraceLde, CompositionPolyTrace, ConstraintEvaluationTable, StarkDomain};
+use super::{
+    super::{ProvingKey, TraceLde},
+    CompositionPolyTrace, ConstraintEvaluationTable, StarkDomain,
+};
 
 mod default;
 pub use default::DefaultConstraintEvaluator;
@@ -17,7 +20,7 @@ use boundary::BoundaryConstraints;
 mod lagrange;
 
 mod periodic_table;
-use periodic_table::PeriodicValueTable;
+pub(crate) use periodic_table::PeriodicValueTable;
 
 // CONSTRAINT EVALUATOR TRAIT
 // ================================================================================================
@@ -41,4 +44,17 @@ pub trait ConstraintEvaluator<E: FieldElement> {
         trace: &T,
         domain: &StarkDomain<E::BaseField>,
     ) -> CompositionPolyTrace<E>;
+
+    /// Returns this evaluator updated to reuse values precomputed in the specified proving key
+    /// (see [ProvingKey] for the list of such values).
+    ///
+    /// The `key` must have been built for the same computation as this evaluator. The default
+    /// implementation ignores the key.
+    #[allow(unused_variables)]
+    fn with_proving_key(self, key: &ProvingKey<E::BaseField>) -> Self
+    where
+        Self: Sized,
+    {
+        self
+    }
 }
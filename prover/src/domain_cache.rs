@@ -0,0 +1,77 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use air::Air;
+use math::StarkField;
+
+use crate::StarkDomain;
+
+// DOMAIN CACHE
+// ================================================================================================
+
+/// A cache for the STARK domain which can be reused across multiple proof generation runs.
+///
+/// Building a proof requires a [StarkDomain], which depends only on the dimensions of the
+/// computation (i.e., trace length, blowup factor, and domain offset), but not on the trace
+/// itself. The most expensive part of the domain are the twiddles used to evaluate trace
+/// polynomials over the LDE domain and the constraint evaluation domain. When many proofs are
+/// generated for traces of the same dimensions, passing the same cache into
+/// [Prover::prove_with_domain_cache()](crate::Prover::prove_with_domain_cache) allows the domain
+/// to be built only once.
+///
+/// If a proof is requested for a computation with dimensions different from the ones the cache
+/// was last used with, the cached domain is discarded and rebuilt for the new dimensions. No other
+/// state is carried over between proof generation runs, and thus, proofs generated with and
+/// without a cache are identical.
+///
+/// Only the domain is cached. Buffers which hold trace LDEs, constraint evaluations, and the
+/// composition polynomial are allocated anew for every proof by the [TraceLde](crate::TraceLde),
+/// [ConstraintEvaluator](crate::ConstraintEvaluator), and
+/// [ConstraintCommitment](crate::ConstraintCommitment) implementations selected by the prover.
+pub struct DomainCache<B: StarkField> {
+    domain: Option<StarkDomain<B>>,
+}
+
+impl<B: StarkField> DomainCache<B> {
+    /// Returns a new empty cache.
+    pub fn new() -> Self {
+        Self { domain: None }
+    }
+
+    /// Returns true if this cache does not currently hold a domain.
+    pub fn is_empty(&self) -> bool {
+        self.domain.is_none()
+    }
+
+    /// Releases the domain held in this cache.
+    pub fn clear(&mut self) {
+        self.domain = None;
+    }
+
+    /// Returns a STARK domain for the specified AIR.
+    ///
+    /// If the domain held in this cache matches the dimensions of the specified AIR, the
+    /// cached domain is returned; otherwise, a new domain is built and cached in its place.
+    pub(crate) fn get_domain<A: Air<BaseField = B>>(&mut self, air: &A) -> &StarkDomain<B> {
+        let is_match = self.domain.as_ref().is_some_and(|domain| {
+            domain.trace_length() == air.trace_length()
+                && domain.ce_domain_size() == air.ce_domain_size()
+                && domain.lde_domain_size() == air.lde_domain_size()
+                && domain.offset() == air.domain_offset()
+        });
+
+        if !is_match {
+            self.domain = Some(StarkDomain::new(air));
+        }
+
+        self.domain.as_ref().expect("domain not initialized")
+    }
+}
+
+impl<B: StarkField> Default for DomainCache<B> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
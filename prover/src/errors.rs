@@ -5,6 +5,7 @@
 
 //! Contains common error types for prover and verifier.
 
+use alloc::string::String;
 use core::fmt;
 
 // PROVER ERROR
@@ -15,12 +16,50 @@ pub enum ProverError {
     /// This error occurs when a transition constraint evaluated over a specific execution trace
     /// does not evaluate to zero at any of the steps.
     UnsatisfiedTransitionConstraintError(usize),
+    /// This error occurs when a boundary assertion is not satisfied by the execution trace at the
+    /// specified step. Columns are indexed across all trace segments, with columns of the main
+    /// segment coming first.
+    UnsatisfiedAssertion { column: usize, step: usize },
+    /// This error occurs when the public inputs derived from an execution trace imply an assertion
+    /// which is not satisfied by the trace. The error specifies the column and the step of the
+    /// assertion, as well as the value implied by the public inputs (`expected`) and the value
+    /// contained in the trace (`actual`).
+    MismatchedPublicInputs {
+        column: usize,
+        step: usize,
+        expected: String,
+        actual: String,
+    },
     /// This error occurs when polynomials built from the columns of a constraint evaluation
     /// table do not all have the same degree.
     MismatchedConstraintPolynomialDegree(usize, usize),
     /// This error occurs when the base field specified by the AIR does not support field extension
     /// of degree specified by proof options.
     UnsupportedFieldExtension(usize),
+    /// This error occurs when the number of columns in the auxiliary trace segment built by the
+    /// prover is different from the auxiliary segment width declared in the trace info.
+    MismatchedAuxTraceWidth(usize, usize),
+    /// This error occurs when the number of rows in the auxiliary trace segment built by the
+    /// prover is different from the length of the execution trace.
+    MismatchedAuxTraceLength(usize, usize),
+    /// This error occurs when the degree of the field extension in which proof generation was
+    /// requested is different from the degree of the field extension specified by proof options.
+    MismatchedFieldExtension(usize, usize),
+    /// This error occurs when a proof is generated from a committed trace which was extended with
+    /// a blowup factor, or committed to with partition options, different from the ones specified
+    /// by proof options.
+    MismatchedCommittedTrace,
+    /// This error occurs when a proof is generated using a proving key which was built for a trace
+    /// info or proof options different from the ones of the trace and the prover.
+    MismatchedProvingKey,
+    /// This error occurs when DEEP composition coefficients supplied by the prover do not have the
+    /// same shape as the coefficients drawn from the public coin.
+    MismatchedDeepCompositionCoefficients,
+    /// This error occurs when the LDE domain of the specified size is larger than the largest
+    /// multiplicative subgroup of size 2^n (specified as n) of the base field. Since the domain
+    /// must be a subgroup of the base field, using a field extension does not increase the
+    /// largest supported domain size.
+    DomainTooLarge(usize, u32),
 }
 
 impl fmt::Display for ProverError {
@@ -30,12 +69,39 @@ impl fmt::Display for ProverError {
             Self::UnsatisfiedTransitionConstraintError(step) => {
                 write!(f, "a transition constraint was not satisfied at step {step}")
             }
+            Self::UnsatisfiedAssertion { column, step } => {
+                write!(f, "an assertion against column {column} was not satisfied at step {step}")
+            }
+            Self::MismatchedPublicInputs { column, step, expected, actual } => {
+                write!(f, "public inputs imply value {expected} in column {column} at step {step}, but the trace contains {actual}")
+            }
             Self::MismatchedConstraintPolynomialDegree(expected, actual) => {
                 write!(f, "the constraint polynomial's components do not all have the same degree; expected {expected}, but was {actual}")
             }
             Self::UnsupportedFieldExtension(degree) => {
                 write!(f, "field extension of degree {degree} is not supported for the specified base field")
             }
+            Self::MismatchedAuxTraceWidth(expected, actual) => {
+                write!(f, "the auxiliary trace segment must consist of {expected} columns as declared in the trace info, but {actual} columns were built")
+            }
+            Self::MismatchedAuxTraceLength(expected, actual) => {
+                write!(f, "the auxiliary trace segment must consist of {expected} rows, but {actual} rows were built")
+            }
+            Self::MismatchedFieldExtension(expected, actual) => {
+                write!(f, "proof options specify a field extension of degree {expected}, but proof generation was requested in a field extension of degree {actual}")
+            }
+            Self::MismatchedCommittedTrace => {
+                write!(f, "the committed trace was extended or committed to with different blowup factor or partition options than specified by proof options")
+            }
+            Self::MismatchedProvingKey => {
+                write!(f, "the proving key was built for a different trace info or different proof options")
+            }
+            Self::MismatchedDeepCompositionCoefficients => {
+                write!(f, "the supplied DEEP composition coefficients do not match the shape of the coefficients drawn from the public coin")
+            }
+            Self::DomainTooLarge(size, two_adicity) => {
+                write!(f, "LDE domain of size {size} is larger than the largest power-of-two subgroup of the base field of size 2^{two_adicity}")
+            }
         }
     }
 }
@@ -0,0 +1,156 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::sync::Arc;
+
+use air::{Air, ConstraintDivisor, ProofOptions, TraceInfo};
+use math::StarkField;
+
+use crate::{
+    constraints::{DivisorTable, PeriodicValueTable},
+    StarkDomain,
+};
+
+// PROVING KEY
+// ================================================================================================
+
+/// Precomputed values which depend only on the computation being proven, but not on a specific
+/// execution trace.
+///
+/// A proving key is built once for an AIR instantiated with a given trace info and proof options,
+/// and can then be reused across all proofs of traces with the same trace info via
+/// [Prover::prove_with_key()](crate::Prover::prove_with_key). The key holds:
+/// - The STARK domain, including the twiddles used to evaluate trace polynomials over the LDE
+///   domain and constraint evaluation domain (the same values as cached by
+///   [DomainCache](crate::DomainCache)).
+/// - Evaluations of periodic columns of the AIR over the constraint evaluation domain.
+/// - Inverse evaluations of the transition constraint divisor and of the divisors of the main
+///   trace assertions over the constraint evaluation domain; divisors of auxiliary trace
+///   assertions depend on the auxiliary random elements, and are evaluated for every proof.
+///
+/// The precomputed constraint values are attached to the constraint evaluator via
+/// [ConstraintEvaluator::with_proving_key()](crate::ConstraintEvaluator::with_proving_key), and
+/// thus, are reused by [DefaultConstraintEvaluator](crate::DefaultConstraintEvaluator) without any
+/// changes to the prover; custom constraint evaluators may ignore them.
+///
+/// Since the key is built from a single AIR instance, periodic columns of the AIR must not depend
+/// on the values of public inputs for the key to be reused across proofs with different public
+/// inputs; divisors of assertions placed against steps other than the ones of the AIR instance
+/// are evaluated for every proof. Proofs generated with and without a key are identical.
+pub struct ProvingKey<B: StarkField> {
+    trace_info: TraceInfo,
+    options: ProofOptions,
+    domain: StarkDomain<B>,
+    periodic_values: Arc<PeriodicValueTable<B>>,
+    divisors: Arc<DivisorTable<B>>,
+}
+
+impl<B: StarkField> ProvingKey<B> {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new proving key for the specified AIR.
+    pub fn new<A: Air<BaseField = B>>(air: &A) -> Self {
+        let domain = StarkDomain::new(air);
+
+        // the transition constraint divisor goes first, followed by the divisors of main trace
+        // assertions
+        let trace_length = air.trace_length();
+        let mut divisors = vec![ConstraintDivisor::from_transition(
+            trace_length,
+            air.context().num_transition_exemptions(),
+        )];
+        divisors.extend(air.get_assertions().into_iter().map(|assertion| {
+            ConstraintDivisor::from_assertion(&assertion.resolve(trace_length), trace_length)
+        }));
+        let divisors = DivisorTable::new(&divisors, &domain);
+
+        Self {
+            trace_info: air.trace_info().clone(),
+            options: air.options().clone(),
+            domain,
+            periodic_values: Arc::new(PeriodicValueTable::new(air)),
+            divisors: Arc::new(divisors),
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the trace info of the computation for which this key was built.
+    pub fn trace_info(&self) -> &TraceInfo {
+        &self.trace_info
+    }
+
+    /// Returns the proof options for which this key was built.
+    pub fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+
+    /// Returns the STARK domain of the computation for which this key was built.
+    pub fn domain(&self) -> &StarkDomain<B> {
+        &self.domain
+    }
+
+    /// Returns evaluations of periodic columns of the AIR over the constraint evaluation domain.
+    pub(crate) fn periodic_values(&self) -> &Arc<PeriodicValueTable<B>> {
+        &self.periodic_values
+    }
+
+    /// Returns inverse evaluations of constraint divisors over the constraint evaluation domain.
+    pub(crate) fn divisors(&self) -> &Arc<DivisorTable<B>> {
+        &self.divisors
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use air::{Air, Assertion, ConstraintDivisor};
+    use math::{fields::f128::BaseElement, FieldElement, StarkField};
+
+    use super::ProvingKey;
+    use crate::tests::MockAir;
+
+    #[test]
+    fn proving_key_divisors() {
+        let trace_length = 16;
+        let assertions = vec![
+            Assertion::single(0, 0, BaseElement::ONE),
+            Assertion::single(1, 0, BaseElement::ONE),
+            Assertion::single(0, 7, BaseElement::ONE),
+        ];
+        let air = MockAir::with_assertions(assertions, trace_length);
+        let key = ProvingKey::new(&air);
+        let domain = key.domain();
+
+        // inverse evaluations of the transition divisor numerator (x^n - 1) are cached
+        let divisor = ConstraintDivisor::<BaseElement>::from_transition(trace_length, 1);
+        let z = key.divisors().get(&divisor).unwrap();
+        assert_eq!(air.ce_domain_size() / trace_length, z.len());
+        for (i, &z) in z.iter().enumerate() {
+            let x = domain.get_ce_x_at(i).exp((trace_length as u32).into());
+            assert_eq!(BaseElement::ONE, z * (x - BaseElement::ONE));
+        }
+
+        // divisors of the assertions are cached once per asserted step, regardless of the column
+        // and the asserted value
+        let assertion = Assertion::single(2, 7, BaseElement::new(42));
+        let divisor = ConstraintDivisor::from_assertion(&assertion, trace_length);
+        let z = key.divisors().get(&divisor).unwrap();
+        assert_eq!(air.ce_domain_size(), z.len());
+        let g = BaseElement::get_root_of_unity(trace_length.ilog2());
+        for (i, &z) in z.iter().enumerate() {
+            assert_eq!(BaseElement::ONE, z * (domain.get_ce_x_at(i) - g.exp(7)));
+        }
+
+        // divisors of assertions against other steps are not cached
+        let assertion = Assertion::single(0, 3, BaseElement::ONE);
+        let divisor = ConstraintDivisor::from_assertion(&assertion, trace_length);
+        assert!(key.divisors().get(&divisor).is_none());
+    }
+}
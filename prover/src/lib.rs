@@ -14,6 +14,11 @@
 //! machine). The number of threads can be configured via `RAYON_NUM_THREADS` environment
 //! variable.
 //!
+//! When the crate is compiled with `external-queries` feature enabled, query positions can be
+//! supplied by the prover via `Prover::external_query_positions()` method rather than drawn from
+//! the public coin. This breaks soundness of the non-interactive protocol, and is intended only
+//! for testing and for building interactive variants of the protocol.
+//!
 //! # Usage
 //! To generate a proof that a computation was executed correctly, you'll need to do the
 //! following:
@@ -42,20 +47,29 @@
 #[macro_use]
 extern crate alloc;
 
+#[cfg(feature = "std")]
+extern crate std;
+
+use alloc::{string::ToString, vec::Vec};
+use core::borrow::Borrow;
+
 pub use air::{
-    proof, proof::Proof, Air, AirContext, Assertion, BoundaryConstraint, BoundaryConstraintGroup,
-    ConstraintCompositionCoefficients, ConstraintDivisor, DeepCompositionCoefficients,
-    EvaluationFrame, FieldExtension, LagrangeKernelRandElements, ProofOptions, TraceInfo,
-    TransitionConstraintDegree,
+    proof, proof::Proof, Air, AirContext, AirWithAssertions, Assertion, AuxRandElements,
+    BoundaryConstraint, BoundaryConstraintGroup, ConstraintCompositionCoefficients,
+    ConstraintDivisor, DeepCompositionCoefficients, EvaluationFrame, FieldExtension,
+    InputsWithAssertions, LagrangeKernelEvaluationFrame, LagrangeKernelRandElements,
+    PartitionOptions, ProofOptions, SecurityLevel, Selector, TraceInfo, TransitionConstraintDegree,
 };
-use air::{AuxRandElements, GkrRandElements, PartitionOptions};
+use air::{proof::Context, GkrRandElements};
 pub use crypto;
-use crypto::{ElementHasher, RandomCoin, VectorCommitment};
+use crypto::{
+    DefaultProofOfWork, Digest, ElementHasher, ProofOfWork, RandomCoin, VectorCommitment,
+};
 use fri::FriProver;
 pub use math;
 use math::{
     fft::infer_degree,
-    fields::{CubeExtension, QuadExtension},
+    fields::{CubeExtension, QuadExtension, SexticExtension},
     ExtensibleField, FieldElement, StarkField, ToElements,
 };
 use tracing::{event, info_span, instrument, Level};
@@ -82,8 +96,8 @@ use composer::DeepCompositionPoly;
 mod trace;
 use maybe_async::{maybe_async, maybe_await};
 pub use trace::{
-    AuxTraceWithMetadata, DefaultTraceLde, Trace, TraceLde, TracePolyTable, TraceTable,
-    TraceTableFragment,
+    AuxTraceWithMetadata, ConstraintResidual, DefaultTraceLde, PaddedTrace, Trace, TraceLde,
+    TracePolyTable, TraceTable, TraceTableFragment, TransitionResiduals,
 };
 
 mod channel;
@@ -92,6 +106,21 @@ use channel::ProverChannel;
 mod errors;
 pub use errors::ProverError;
 
+mod domain_cache;
+pub use domain_cache::DomainCache;
+
+mod key;
+pub use key::ProvingKey;
+
+mod committed_trace;
+pub use committed_trace::CommittedTrace;
+
+mod memory;
+pub use memory::MemoryEstimate;
+
+mod transcript;
+pub use transcript::ProverTranscript;
+
 #[cfg(test)]
 pub mod tests;
 
@@ -128,7 +157,7 @@ pub type ProverGkrProof<P> = <<P as Prover>::Air as Air>::GkrProof;
 /// generation can be delegated to non-CPU hardware (e.g., GPUs).
 pub trait Prover {
     /// Base field for the computation described by this prover.
-    type BaseField: StarkField + ExtensibleField<2> + ExtensibleField<3>;
+    type BaseField: StarkField + ExtensibleField<2> + ExtensibleField<3> + ExtensibleField<6>;
 
     /// Algebraic intermediate representation (AIR) for the computation described by this prover.
     type Air: Air<BaseField = Self::BaseField>;
@@ -146,6 +175,10 @@ pub trait Prover {
     type RandomCoin: RandomCoin<BaseField = Self::BaseField, Hasher = Self::HashFn>;
 
     /// Trace low-degree extension for building the LDEs of trace segments and their commitments.
+    ///
+    /// In most cases, this should be set to [DefaultTraceLde]. See [TraceLde] for the
+    /// requirements a custom implementation must satisfy and an example of such an
+    /// implementation.
     type TraceLde<E>: TraceLde<E, HashFn = Self::HashFn, VC = Self::VC>
     where
         E: FieldElement<BaseField = Self::BaseField>;
@@ -182,6 +215,11 @@ pub trait Prover {
     ///
     /// Returns a tuple containing a [TracePolyTable] with the trace polynomials for the main trace
     /// and a new [TraceLde] instance from which the LDE and trace commitments can be obtained.
+    ///
+    /// The `domain` describes the LDE domain over which the trace must be extended. For provers
+    /// using [DefaultTraceLde], this method should simply delegate to [DefaultTraceLde::new()];
+    /// providers of custom [TraceLde] implementations can build the LDE in any way as long as
+    /// the result is consistent with the `domain`.
     #[maybe_async]
     fn new_trace_lde<E>(
         &self,
@@ -205,6 +243,31 @@ pub trait Prover {
     where
         E: FieldElement<BaseField = Self::BaseField>;
 
+    /// Returns a new constraint evaluator which can be used to evaluate transition and boundary
+    /// constraints over the extended execution trace, reusing values precomputed in the
+    /// specified proving key.
+    ///
+    /// This is used instead of [Self::new_evaluator()](Prover::new_evaluator) when a proof is
+    /// generated via [Self::prove_with_key()](Prover::prove_with_key). The default implementation
+    /// builds the evaluator via [Self::new_evaluator()](Prover::new_evaluator) and then attaches
+    /// the key to it via [ConstraintEvaluator::with_proving_key()]; thus, provers which use
+    /// [DefaultConstraintEvaluator] reuse the values precomputed in the key without overriding
+    /// this method.
+    #[maybe_async]
+    fn new_evaluator_with_key<'a, E>(
+        &self,
+        air: &'a Self::Air,
+        key: &'a ProvingKey<Self::BaseField>,
+        aux_rand_elements: Option<AuxRandElements<E>>,
+        composition_coefficients: ConstraintCompositionCoefficients<E>,
+    ) -> Self::ConstraintEvaluator<'a, E>
+    where
+        E: FieldElement<BaseField = Self::BaseField>,
+    {
+        maybe_await!(self.new_evaluator(air, aux_rand_elements, composition_coefficients))
+            .with_proving_key(key)
+    }
+
     /// Extends constraint composition polynomial over the LDE domain and builds a commitment to
     /// its evaluations.
     ///
@@ -229,6 +292,33 @@ pub trait Prover {
     // PROVIDED METHODS
     // --------------------------------------------------------------------------------------------
 
+    /// Returns an approximate breakdown of memory required to generate a proof for an execution
+    /// trace described by `trace_info` using the options of this prover.
+    ///
+    /// The estimate is derived from the trace dimensions and proof options only, and thus, can be
+    /// computed before the trace is built. See [MemoryEstimate] for details on what is included
+    /// in the estimate.
+    fn estimate_memory(&self, trace_info: &TraceInfo) -> MemoryEstimate {
+        MemoryEstimate::new::<Self::BaseField, Self::HashFn>(trace_info, self.options())
+    }
+
+    /// Returns query positions which should be used in place of the ones drawn from the public
+    /// coin, or `None` if the query positions should be drawn from the public coin.
+    ///
+    /// This is intended for testing specific positions in the LDE domain, and for building
+    /// interactive variants of the protocol in which the verifier chooses the queries. Proofs
+    /// generated with externally supplied query positions must be verified against the same
+    /// positions (e.g., via `verify_with_query_positions()` function of the verifier crate).
+    ///
+    /// **This breaks soundness of the non-interactive protocol**: a prover who knows the query
+    /// positions in advance can forge a proof for an invalid execution.
+    ///
+    /// The default implementation returns `None`.
+    #[cfg(feature = "external-queries")]
+    fn external_query_positions(&self) -> Option<Vec<usize>> {
+        None
+    }
+
     /// Builds the GKR proof. If the [`Air`] doesn't use a GKR proof, leave unimplemented.
     #[allow(unused_variables)]
     #[maybe_async]
@@ -244,6 +334,11 @@ pub trait Prover {
     }
 
     /// Builds and returns the auxiliary trace.
+    ///
+    /// The returned matrix must have as many columns as the auxiliary segment width declared in
+    /// the trace info and as many rows as the main trace segment; otherwise, proof generation
+    /// fails with [ProverError::MismatchedAuxTraceWidth] or
+    /// [ProverError::MismatchedAuxTraceLength] respectively.
     #[allow(unused_variables)]
     #[maybe_async]
     #[instrument(skip_all)]
@@ -258,6 +353,110 @@ pub trait Prover {
         unimplemented!("`Prover::build_aux_trace` needs to be implemented when the trace has an auxiliary segment.")
     }
 
+    /// Returns an identifier of [Self::HashFn](Prover::HashFn) to be recorded in the context of
+    /// generated proofs, or None if no identifier should be recorded.
+    ///
+    /// The identifier is chosen by the application, and allows a verifier which accepts proofs
+    /// generated using more than one hash function (e.g., during a migration from one hash
+    /// function to another) to determine the hash function with which a proof should be
+    /// verified; see `HashRegistry` of the verifier crate. The identifier is bound to the proof
+    /// via the public coin. By default, no identifier is recorded, and the serialization of the
+    /// generated proofs is not affected.
+    fn hash_id(&self) -> Option<u8> {
+        None
+    }
+
+    /// Returns an identifier of the hash function used to commit to the auxiliary trace segment
+    /// (i.e., `AuxHashFn` of [Self::TraceLde](Prover::TraceLde)) to be recorded in the context of
+    /// generated proofs, or None if no identifier should be recorded.
+    ///
+    /// The identifier is chosen by the application, and should be specified whenever the
+    /// auxiliary segment is committed to using a different hash function than
+    /// [Self::HashFn](Prover::HashFn). Such proofs must be verified via `verify_with_aux_hash()`
+    /// function of the verifier crate with the same identifier, which makes sure that the
+    /// auxiliary segment is checked using the matching hash function. By default, no identifier
+    /// is recorded, and the serialization of the generated proofs is not affected.
+    fn aux_hash_id(&self) -> Option<u8> {
+        None
+    }
+
+    /// Returns true if the execution trace should be checked against boundary assertions of the
+    /// AIR before proof generation.
+    ///
+    /// Checking assertions is cheap compared to proof generation, and allows a trace which does
+    /// not satisfy an assertion to be rejected with [ProverError::UnsatisfiedAssertion] instead
+    /// of producing a proof which fails verification. Assertions against the main trace segment
+    /// are checked before the main segment is committed to, and assertions against the auxiliary
+    /// segment are checked as soon as this segment is built. By default, the check is performed
+    /// in debug builds only; this method can be overridden to perform it in release builds too.
+    fn check_assertions(&self) -> bool {
+        cfg!(debug_assertions)
+    }
+
+    /// Checks that the public inputs derived from the specified trace via
+    /// [Self::get_pub_inputs()](Prover::get_pub_inputs) are consistent with the trace.
+    ///
+    /// Public inputs are usually read from specific cells of the trace, and the AIR then uses them
+    /// to build boundary assertions. This method instantiates the AIR from the derived public
+    /// inputs and checks its assertions against the main segment of the trace, catching the cases
+    /// where `get_pub_inputs()` reads a cell different from the one the AIR asserts against.
+    ///
+    /// Unlike the check controlled by [Self::check_assertions()](Prover::check_assertions),
+    /// this method reports the value implied by the public inputs together with the value
+    /// contained in the trace. It is intended to be used in tests of a prover implementation.
+    ///
+    /// # Errors
+    /// Returns [ProverError::MismatchedPublicInputs] for the first assertion which is not
+    /// satisfied by the main segment of the trace.
+    fn validate_pub_inputs(&self, trace: &Self::Trace) -> Result<(), ProverError> {
+        let pub_inputs = self.get_pub_inputs(trace);
+        let air = Self::Air::new(trace.info().clone(), pub_inputs, self.options().clone());
+        let main_segment = trace.main_segment();
+        match trace::find_unsatisfied_assertion(&air.get_assertions(), main_segment) {
+            Some((column, step, expected)) => Err(ProverError::MismatchedPublicInputs {
+                column,
+                step,
+                expected: expected.to_string(),
+                actual: main_segment.get(column, step).to_string(),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns the proof-of-work algorithm used for grinding the query seed.
+    ///
+    /// The identifier of the algorithm (see [ProofOfWork::id()]) is recorded in the context of
+    /// generated proofs, and thus, a proof generated using an algorithm other than the default
+    /// one must be verified via `verify_with_proof_of_work()` function of the verifier crate
+    /// using the same algorithm. By default, [DefaultProofOfWork] is used, and the serialization
+    /// of the generated proofs is not affected.
+    fn proof_of_work(&self) -> &dyn ProofOfWork<Self::RandomCoin> {
+        &DefaultProofOfWork
+    }
+
+    /// Returns coefficients to be used for building the DEEP composition polynomial.
+    ///
+    /// The provided `coefficients` are the ones drawn from the public coin, and the default
+    /// implementation returns them unchanged. This method can be overridden to inspect the
+    /// coefficients, or to replace them with externally supplied ones (e.g., to reconcile
+    /// intermediate states of this prover with a different implementation of the protocol).
+    ///
+    /// The verifier always derives the coefficients from the public coin, and thus, a proof
+    /// generated using coefficients other than the drawn ones will not verify. The returned
+    /// coefficients must have the same shape as the drawn ones (i.e., the same number of trace
+    /// and constraint coefficients, and a Lagrange kernel coefficient only if the drawn
+    /// coefficients have one); otherwise, proof generation fails with
+    /// [ProverError::MismatchedDeepCompositionCoefficients].
+    fn deep_composition_coefficients<E>(
+        &self,
+        coefficients: DeepCompositionCoefficients<E>,
+    ) -> DeepCompositionCoefficients<E>
+    where
+        E: FieldElement<BaseField = Self::BaseField>,
+    {
+        coefficients
+    }
+
     /// Returns a STARK proof attesting to a correct execution of a computation defined by the
     /// provided trace.
     ///
@@ -271,51 +470,593 @@ pub trait Prover {
     where
         <Self::Air as Air>::PublicInputs: Send,
         <Self::Air as Air>::GkrProof: Send,
+    {
+        maybe_await!(self.prove_with_domain_cache(trace, &mut DomainCache::new()))
+    }
+
+    /// Returns a STARK proof attesting to a correct execution of a computation defined by the
+    /// provided trace without taking ownership of the trace.
+    ///
+    /// This method is equivalent to [Prover::prove()], but the caller retains the trace after the
+    /// proof is generated (e.g., to generate another proof with different options). Note that
+    /// [Prover::prove()] releases the memory occupied by the trace as soon as the trace is no
+    /// longer needed; this method cannot do so, and thus, peak memory usage may be higher.
+    #[maybe_async]
+    fn prove_ref(&self, trace: &Self::Trace) -> Result<Proof, ProverError>
+    where
+        <Self::Air as Air>::PublicInputs: Send,
+        <Self::Air as Air>::GkrProof: Send,
+    {
+        let pub_inputs = self.get_pub_inputs(trace);
+        let public_coin = self.new_public_coin(trace.info(), &pub_inputs);
+        let mut domain_cache = DomainCache::new();
+        maybe_await!(self.generate_proof_for_options(
+            trace,
+            pub_inputs,
+            public_coin,
+            &mut domain_cache,
+            None
+        ))
+        .map(|(proof, _)| proof)
+    }
+
+    /// Returns a STARK proof attesting to a correct execution of a computation defined by the
+    /// provided trace, reusing the STARK domain cached in the provided `domain_cache`.
+    ///
+    /// This method is equivalent to [Prover::prove()], but the STARK domain (including twiddles
+    /// for the trace and constraint evaluation domains) is taken from the `domain_cache` if it has
+    /// been built for the same dimensions during a previous call; otherwise, the domain is built
+    /// and stored in the `domain_cache` for subsequent calls. Buffers for trace LDEs and
+    /// constraint evaluations are not cached, and are allocated anew for every proof. See
+    /// [DomainCache] for more details.
+    #[maybe_async]
+    fn prove_with_domain_cache(
+        &self,
+        trace: Self::Trace,
+        domain_cache: &mut DomainCache<Self::BaseField>,
+    ) -> Result<Proof, ProverError>
+    where
+        <Self::Air as Air>::PublicInputs: Send,
+        <Self::Air as Air>::GkrProof: Send,
+    {
+        let pub_inputs = self.get_pub_inputs(&trace);
+        let public_coin = self.new_public_coin(trace.info(), &pub_inputs);
+        maybe_await!(self.generate_proof_for_options(
+            trace,
+            pub_inputs,
+            public_coin,
+            domain_cache,
+            None
+        ))
+        .map(|(proof, _)| proof)
+    }
+
+    /// Returns a STARK proof attesting to a correct execution of a computation defined by the
+    /// provided trace, reusing values precomputed in the provided proving `key`.
+    ///
+    /// This method is equivalent to [Prover::prove()], but the STARK domain is taken from the
+    /// `key`, and the constraint evaluator is built via
+    /// [Self::new_evaluator_with_key()](Prover::new_evaluator_with_key), which allows it to reuse
+    /// other values precomputed in the key. See [ProvingKey] for more details.
+    ///
+    /// # Errors
+    /// Returns [ProverError::MismatchedProvingKey] if the key was built for a trace info different
+    /// from the one of the provided trace, or for proof options different from the ones of this
+    /// prover.
+    #[maybe_async]
+    fn prove_with_key(
+        &self,
+        trace: Self::Trace,
+        key: &ProvingKey<Self::BaseField>,
+    ) -> Result<Proof, ProverError>
+    where
+        <Self::Air as Air>::PublicInputs: Send,
+        <Self::Air as Air>::GkrProof: Send,
+    {
+        if key.trace_info() != trace.info() || key.options() != self.options() {
+            return Err(ProverError::MismatchedProvingKey);
+        }
+
+        let pub_inputs = self.get_pub_inputs(&trace);
+        let public_coin = self.new_public_coin(trace.info(), &pub_inputs);
+        maybe_await!(self.generate_proof_for_options(
+            trace,
+            pub_inputs,
+            public_coin,
+            &mut DomainCache::new(),
+            Some(key)
+        ))
+        .map(|(proof, _)| proof)
+    }
+
+    /// Returns STARK proofs attesting to correct executions of computations defined by the
+    /// provided traces.
+    ///
+    /// This is equivalent to calling [Prover::prove()] for each of the traces, and the proofs are
+    /// returned in the same order as the traces. When `concurrent` feature is enabled, the traces
+    /// are proven in parallel by as many workers as there are threads in the rayon thread pool;
+    /// see [Prover::prove_many_with_concurrency()] for more details.
+    #[maybe_async]
+    fn prove_many(&self, traces: Vec<Self::Trace>) -> Vec<Result<Proof, ProverError>>
+    where
+        Self: Sync,
+        <Self::Air as Air>::PublicInputs: Send,
+        <Self::Air as Air>::GkrProof: Send,
+    {
+        #[cfg(feature = "concurrent")]
+        let concurrency = iterators::rayon_num_threads();
+        #[cfg(not(feature = "concurrent"))]
+        let concurrency = 1;
+
+        maybe_await!(self.prove_many_with_concurrency(traces, concurrency))
+    }
+
+    /// Returns STARK proofs attesting to correct executions of computations defined by the
+    /// provided traces, proving at most `concurrency` traces at the same time.
+    ///
+    /// The traces are split into at most `concurrency` contiguous batches, and each batch is
+    /// proven sequentially by a single worker using its own [DomainCache]. Thus, the STARK domain
+    /// (including twiddles) is built once per worker rather than once per trace. The workers run
+    /// in the rayon thread pool used by the rest of the prover, and so, no additional thread pools
+    /// are created. The proofs are returned in the same order as the traces, and are identical to
+    /// the proofs which would be generated by [Prover::prove()].
+    ///
+    /// When `concurrent` feature is disabled, or when `async` feature is enabled, the traces are
+    /// always proven sequentially using a single domain cache.
+    #[maybe_async]
+    #[cfg_attr(
+        not(all(feature = "concurrent", not(feature = "async"))),
+        allow(unused_variables)
+    )]
+    fn prove_many_with_concurrency(
+        &self,
+        traces: Vec<Self::Trace>,
+        concurrency: usize,
+    ) -> Vec<Result<Proof, ProverError>>
+    where
+        Self: Sync,
+        <Self::Air as Air>::PublicInputs: Send,
+        <Self::Air as Air>::GkrProof: Send,
+    {
+        #[cfg(all(feature = "concurrent", not(feature = "async")))]
+        if concurrency > 1 && traces.len() > 1 {
+            use utils::iterators::*;
+
+            let batch_size = traces.len().div_ceil(concurrency);
+            let mut batches = Vec::with_capacity(concurrency);
+            let mut traces = traces.into_iter();
+            loop {
+                let batch: Vec<_> = traces.by_ref().take(batch_size).collect();
+                if batch.is_empty() {
+                    break;
+                }
+                batches.push(batch);
+            }
+
+            let results: Vec<Vec<_>> = batches
+                .into_par_iter()
+                .map(|batch| {
+                    let mut domain_cache = DomainCache::new();
+                    batch
+                        .into_iter()
+                        .map(|trace| self.prove_with_domain_cache(trace, &mut domain_cache))
+                        .collect()
+                })
+                .collect();
+            return results.into_iter().flatten().collect();
+        }
+
+        let mut domain_cache = DomainCache::new();
+        let mut results = Vec::with_capacity(traces.len());
+        for trace in traces {
+            results.push(maybe_await!(self.prove_with_domain_cache(trace, &mut domain_cache)));
+        }
+        results
+    }
+
+    /// Returns a STARK proof attesting to a correct execution of a computation defined by the
+    /// provided trace; the proof is bound to a commitment to the public inputs rather than to the
+    /// public inputs themselves.
+    ///
+    /// The commitment is computed by hashing the public inputs returned from
+    /// [Self::get_pub_inputs()](Prover::get_pub_inputs) with [Self::HashFn](Prover::HashFn), and
+    /// is absorbed into the public coin in place of the public inputs. An instance of
+    /// [Self::Air](Prover::Air) is then constructed from the bytes of the commitment alone, and
+    /// thus, the AIR must not depend on any information about the public inputs not captured by
+    /// the commitment.
+    ///
+    /// Proofs generated by this method can be verified by supplying only the commitment to the
+    /// public inputs to the verifier (rather than the public inputs themselves).
+    ///
+    /// The commitment only hides the public inputs from the verifier; it is not a binding
+    /// commitment to the inputs the trace was generated from. The proof is bound to the value of
+    /// the commitment (since it is absorbed into the public coin), but nothing in the proof opens
+    /// the commitment or constrains the trace against the committed inputs. Thus, a proof
+    /// generated with this method attests only to the statement the AIR can express in terms of
+    /// the commitment bytes, and the prover is free to commit to any inputs which are consistent
+    /// with this statement.
+    #[maybe_async]
+    fn prove_with_committed_inputs(&self, trace: Self::Trace) -> Result<Proof, ProverError>
+    where
+        <Self::Air as Air>::PublicInputs: Send + From<[u8; 32]>,
+        <Self::Air as Air>::GkrProof: Send,
+    {
+        let pub_inputs_commitment =
+            Self::HashFn::hash_elements(&self.get_pub_inputs(&trace).to_elements());
+        let pub_inputs = pub_inputs_commitment.as_bytes().into();
+
+        // the public coin is seeded with the proof context only, and is then reseeded with the
+        // commitment to the public inputs
+        let mut public_coin = Self::RandomCoin::new(&context_elements(
+            trace.info(),
+            self.options(),
+            self.hash_id(),
+            self.aux_hash_id(),
+            self.proof_of_work().id(),
+        ));
+        public_coin.reseed(pub_inputs_commitment);
+
+        let mut domain_cache = DomainCache::new();
+        maybe_await!(self.generate_proof_for_options(
+            trace,
+            pub_inputs,
+            public_coin,
+            &mut domain_cache,
+            None
+        ))
+        .map(|(proof, _)| proof)
+    }
+
+    /// Returns a STARK proof attesting to a correct execution of a computation defined by the
+    /// provided trace, using the provided `public_coin` as the Fiat-Shamir transcript.
+    ///
+    /// This allows a STARK proof to be generated as a part of a larger Fiat-Shamir transcript
+    /// (e.g., when the proof is one component of a multi-component protocol). Instead of
+    /// instantiating a new public coin, the provided coin is reseeded with a hash of the proof
+    /// context and the public inputs, and is then used to draw all randomness needed to generate
+    /// the proof. The coin in the state it is in at the end of proof generation is returned
+    /// together with the proof, and can be used to continue the transcript.
+    ///
+    /// Proofs generated by this method can be verified via `verify_with_coin()` function of the
+    /// verifier crate, provided the verifier's coin is in the same state as `public_coin`.
+    #[maybe_async]
+    fn prove_with_coin(
+        &self,
+        trace: Self::Trace,
+        mut public_coin: Self::RandomCoin,
+    ) -> Result<(Proof, Self::RandomCoin), ProverError>
+    where
+        <Self::Air as Air>::PublicInputs: Send,
+        <Self::Air as Air>::GkrProof: Send,
+    {
+        let pub_inputs = self.get_pub_inputs(&trace);
+        let mut seed_elements = context_elements(
+            trace.info(),
+            self.options(),
+            self.hash_id(),
+            self.aux_hash_id(),
+            self.proof_of_work().id(),
+        );
+        seed_elements.append(&mut pub_inputs.to_elements());
+        public_coin.reseed(Self::HashFn::hash_elements(&seed_elements));
+
+        let mut domain_cache = DomainCache::new();
+        maybe_await!(self.generate_proof_for_options(
+            trace,
+            pub_inputs,
+            public_coin,
+            &mut domain_cache,
+            None
+        ))
+    }
+
+    /// Returns a STARK proof attesting to a correct execution of a computation defined by the
+    /// provided trace, together with the record of all values drawn from the public coin during
+    /// proof generation.
+    ///
+    /// This method is equivalent to [Prover::prove()], but it also returns a [ProverTranscript]
+    /// which lists all Fiat-Shamir draws performed by the prover in the order in which they were
+    /// made (e.g., so that the transcript could be replayed by a recursive verifier).
+    ///
+    /// Proof generation is performed in field `E` which must be the field specified by this
+    /// prover's options (i.e., the base field for [FieldExtension::None], or its quadratic,
+    /// cubic, or sextic extension otherwise).
+    ///
+    /// # Errors
+    /// Returns an error if the degree of `E` over the base field is different from the degree of
+    /// the field extension specified by this prover's options, if the base field does not support
+    /// this field extension, or if proof generation fails.
+    #[maybe_async]
+    fn prove_with_transcript<E>(
+        &self,
+        trace: Self::Trace,
+    ) -> Result<(Proof, ProverTranscript<E>), ProverError>
+    where
+        E: FieldElement<BaseField = Self::BaseField>,
+        <Self::Air as Air>::PublicInputs: Send,
+        <Self::Air as Air>::GkrProof: Send,
+    {
+        let extension_degree = self.options().field_extension().degree() as usize;
+        if E::EXTENSION_DEGREE != extension_degree {
+            return Err(ProverError::MismatchedFieldExtension(
+                extension_degree,
+                E::EXTENSION_DEGREE,
+            ));
+        }
+        check_field_extension::<Self::BaseField>(self.options().field_extension())?;
+
+        let pub_inputs = self.get_pub_inputs(&trace);
+        let public_coin = self.new_public_coin(trace.info(), &pub_inputs);
+        let mut domain_cache = DomainCache::new();
+        maybe_await!(self.generate_proof::<E, _>(
+            trace,
+            pub_inputs,
+            public_coin,
+            &mut domain_cache,
+            None
+        ))
+        .map(|(proof, _, transcript)| (proof, transcript))
+    }
+
+    /// Extends the main segment of the provided `trace` and commits to the extension, returning
+    /// a [CommittedTrace] which can be used to generate multiple proofs for the same trace.
+    ///
+    /// The trace is extended over the LDE domain defined by the blowup factor of this prover's
+    /// options, and is committed to using this prover's partition options. The trace is extended
+    /// in field `E`, which must correspond to the field extension specified by the options of
+    /// the provers with which proofs are generated from the committed trace.
+    ///
+    /// See [CommittedTrace] for more details.
+    #[instrument(skip_all)]
+    #[maybe_async]
+    fn commit_to_trace<'a, E>(&self, trace: &'a Self::Trace) -> CommittedTrace<'a, Self, E>
+    where
+        E: FieldElement<BaseField = Self::BaseField>,
+    {
+        let air = Self::Air::new(
+            trace.info().clone(),
+            self.get_pub_inputs(trace),
+            self.options().clone(),
+        );
+        let domain = StarkDomain::new(&air);
+        let partition_options = self.options().partition_options();
+        let (trace_lde, trace_polys) = maybe_await!(self.new_trace_lde(
+            trace.info(),
+            trace.main_segment(),
+            &domain,
+            partition_options,
+        ));
+
+        CommittedTrace {
+            trace,
+            trace_lde,
+            trace_polys,
+            domain,
+            partition_options,
+        }
+    }
+
+    /// Returns a STARK proof attesting to a correct execution of a computation defined by the
+    /// trace committed to in the provided `committed_trace`.
+    ///
+    /// This method is equivalent to [Prover::prove_ref()] invoked with the committed trace, but
+    /// instead of extending and committing to the main trace segment, it reuses the low-degree
+    /// extension and the commitment from `committed_trace`. Thus, the committed trace can be
+    /// built once (via [Prover::commit_to_trace()]) and then used to generate proofs with
+    /// different proof options, and the generated proofs are identical to the ones generated via
+    /// [Prover::prove_ref()].
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// * The degree of `E` over the base field is different from the degree of the field
+    ///   extension specified by this prover's options.
+    /// * The base field does not support the field extension specified by this prover's options.
+    /// * The main trace segment in `committed_trace` was extended with a blowup factor, or was
+    ///   committed to with partition options, different from the ones specified by this prover's
+    ///   options.
+    /// * Proof generation fails.
+    #[maybe_async]
+    fn prove_with_committed_trace<E>(
+        &self,
+        committed_trace: &CommittedTrace<'_, Self, E>,
+    ) -> Result<Proof, ProverError>
+    where
+        E: FieldElement<BaseField = Self::BaseField>,
+        Self::TraceLde<E>: Clone,
+        <Self::Air as Air>::PublicInputs: Send,
+        <Self::Air as Air>::GkrProof: Send,
+    {
+        let extension_degree = self.options().field_extension().degree() as usize;
+        if E::EXTENSION_DEGREE != extension_degree {
+            return Err(ProverError::MismatchedFieldExtension(
+                extension_degree,
+                E::EXTENSION_DEGREE,
+            ));
+        }
+        check_field_extension::<Self::BaseField>(self.options().field_extension())?;
+
+        let trace = committed_trace.trace;
+        let pub_inputs = self.get_pub_inputs(trace);
+        let public_coin = self.new_public_coin(trace.info(), &pub_inputs);
+        check_domain_size::<Self::BaseField>(trace.info(), self.options())?;
+        let air = Self::Air::new(trace.info().clone(), pub_inputs, self.options().clone());
+        if self.check_assertions() {
+            trace.check_assertions(&air)?;
+        }
+
+        // make sure the committed trace was extended over the domain required by the options
+        let domain = &committed_trace.domain;
+        if domain.lde_domain_size() != air.lde_domain_size()
+            || domain.ce_domain_size() != air.ce_domain_size()
+            || domain.offset() != air.domain_offset()
+            || committed_trace.partition_options != self.options().partition_options()
+        {
+            return Err(ProverError::MismatchedCommittedTrace);
+        }
+
+        // commit to the main trace segment by writing the commitment computed previously into
+        // the channel, and complete the proof using a copy of the committed trace's LDE
+        let mut channel =
+            ProverChannel::<Self::Air, E, Self::HashFn, Self::RandomCoin, Self::VC>::new(
+                &air,
+                self.hash_id(),
+                self.aux_hash_id(),
+                self.proof_of_work(),
+                public_coin,
+            );
+        channel.commit_trace(committed_trace.trace_lde.get_main_trace_commitment());
+
+        maybe_await!(self.complete_proof(
+            &air,
+            channel,
+            trace,
+            committed_trace.trace_lde.clone(),
+            committed_trace.trace_polys.clone(),
+            domain,
+            None
+        ))
+        .map(|(proof, ..)| proof)
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new public coin seeded with the proof context and the specified public inputs.
+    #[doc(hidden)]
+    fn new_public_coin(
+        &self,
+        trace_info: &TraceInfo,
+        pub_inputs: &<Self::Air as Air>::PublicInputs,
+    ) -> Self::RandomCoin {
+        let mut seed_elements = context_elements(
+            trace_info,
+            self.options(),
+            self.hash_id(),
+            self.aux_hash_id(),
+            self.proof_of_work().id(),
+        );
+        seed_elements.append(&mut pub_inputs.to_elements());
+        Self::RandomCoin::new(&seed_elements)
+    }
+
+    /// Selects the field in which the protocol is executed based on this prover's options, and
+    /// generates the proof via [Prover::generate_proof()] instantiated for this field.
+    ///
+    /// The `trace` can be provided either by value or by reference; in the former case, the trace
+    /// is dropped as soon as it is no longer needed. The `public_coin` is expected to be already
+    /// seeded with the proof context and public inputs (or a commitment to them).
+    #[doc(hidden)]
+    #[maybe_async]
+    fn generate_proof_for_options<T>(
+        &self,
+        trace: T,
+        pub_inputs: <Self::Air as Air>::PublicInputs,
+        public_coin: Self::RandomCoin,
+        domain_cache: &mut DomainCache<Self::BaseField>,
+        key: Option<&ProvingKey<Self::BaseField>>,
+    ) -> Result<(Proof, Self::RandomCoin), ProverError>
+    where
+        T: Borrow<Self::Trace> + Send,
+        <Self::Air as Air>::PublicInputs: Send,
+        <Self::Air as Air>::GkrProof: Send,
     {
         // figure out which version of the generic proof generation procedure to run. this is a sort
         // of static dispatch for selecting two generic parameter: extension field and hash
         // function.
         match self.options().field_extension() {
-            FieldExtension::None => maybe_await!(self.generate_proof::<Self::BaseField>(trace)),
+            FieldExtension::None => maybe_await!(self.generate_proof::<Self::BaseField, T>(
+                trace,
+                pub_inputs,
+                public_coin,
+                domain_cache,
+                key
+            ))
+            .map(|(proof, public_coin, _)| (proof, public_coin)),
             FieldExtension::Quadratic => {
                 if !<QuadExtension<Self::BaseField>>::is_supported() {
                     return Err(ProverError::UnsupportedFieldExtension(2));
                 }
-                maybe_await!(self.generate_proof::<QuadExtension<Self::BaseField>>(trace))
+                maybe_await!(self.generate_proof::<QuadExtension<Self::BaseField>, T>(
+                    trace,
+                    pub_inputs,
+                    public_coin,
+                    domain_cache,
+                    key
+                ))
+                .map(|(proof, public_coin, _)| (proof, public_coin))
             },
             FieldExtension::Cubic => {
                 if !<CubeExtension<Self::BaseField>>::is_supported() {
                     return Err(ProverError::UnsupportedFieldExtension(3));
                 }
-                maybe_await!(self.generate_proof::<CubeExtension<Self::BaseField>>(trace))
+                maybe_await!(self.generate_proof::<CubeExtension<Self::BaseField>, T>(
+                    trace,
+                    pub_inputs,
+                    public_coin,
+                    domain_cache,
+                    key
+                ))
+                .map(|(proof, public_coin, _)| (proof, public_coin))
+            },
+            FieldExtension::Sextic => {
+                if !<SexticExtension<Self::BaseField>>::is_supported() {
+                    return Err(ProverError::UnsupportedFieldExtension(6));
+                }
+                maybe_await!(self.generate_proof::<SexticExtension<Self::BaseField>, T>(
+                    trace,
+                    pub_inputs,
+                    public_coin,
+                    domain_cache,
+                    key
+                ))
+                .map(|(proof, public_coin, _)| (proof, public_coin))
             },
         }
     }
 
-    // HELPER METHODS
-    // --------------------------------------------------------------------------------------------
-
     /// Performs the actual proof generation procedure, generating the proof that the provided
     /// execution `trace` is valid against this prover's AIR.
+    ///
+    /// The `public_coin` is expected to be already seeded with the proof context and public inputs
+    /// (or a commitment to them); the coin in the state it is in at the end of the protocol, as
+    /// well as the record of all values drawn from the coin, are returned together with the proof.
+    /// Values which depend only on the dimensions of the computation are taken from the proving
+    /// `key` if one is provided, and otherwise, from (and cached in) the provided `domain_cache`.
     /// TODO: make this function un-callable externally?
     #[doc(hidden)]
     #[maybe_async]
-    fn generate_proof<E>(&self, trace: Self::Trace) -> Result<Proof, ProverError>
+    fn generate_proof<E, T>(
+        &self,
+        trace: T,
+        pub_inputs: <Self::Air as Air>::PublicInputs,
+        public_coin: Self::RandomCoin,
+        domain_cache: &mut DomainCache<Self::BaseField>,
+        key: Option<&ProvingKey<Self::BaseField>>,
+    ) -> Result<(Proof, Self::RandomCoin, ProverTranscript<E>), ProverError>
     where
         E: FieldElement<BaseField = Self::BaseField>,
+        T: Borrow<Self::Trace> + Send,
         <Self::Air as Air>::PublicInputs: Send,
         <Self::Air as Air>::GkrProof: Send,
     {
         // 0 ----- instantiate AIR and prover channel ---------------------------------------------
 
-        // serialize public inputs; these will be included in the seed for the public coin
-        let pub_inputs = self.get_pub_inputs(&trace);
-        let pub_inputs_elements = pub_inputs.to_elements();
+        let main_trace: &Self::Trace = trace.borrow();
+
+        // make sure the LDE domain fits into the base field; otherwise, the AIR cannot be
+        // instantiated
+        check_domain_size::<Self::BaseField>(main_trace.info(), self.options())?;
 
         // create an instance of AIR for the provided parameters. This takes a generic description
         // of the computation (provided via AIR type), and creates a description of a specific
         // execution of the computation for the provided public inputs.
-        let air = Self::Air::new(trace.info().clone(), pub_inputs, self.options().clone());
+        let air = Self::Air::new(main_trace.info().clone(), pub_inputs, self.options().clone());
+
+        // make sure the trace satisfies assertions against the main segment before doing any of
+        // the expensive work
+        if self.check_assertions() {
+            main_trace.check_assertions(&air)?;
+        }
 
         // create a channel which is used to simulate interaction between the prover and the
         // verifier; the channel will be used to commit to values and to draw randomness that
@@ -323,29 +1064,68 @@ pub trait Prover {
         let mut channel =
             ProverChannel::<Self::Air, E, Self::HashFn, Self::RandomCoin, Self::VC>::new(
                 &air,
-                pub_inputs_elements,
+                self.hash_id(),
+                self.aux_hash_id(),
+                self.proof_of_work(),
+                public_coin,
             );
 
         // 1 ----- Commit to the execution trace --------------------------------------------------
 
-        // build computation domain; this is used later for polynomial evaluations
+        // build computation domain (or re-use the one held in the domain cache); this is used
+        // later for polynomial evaluations
         let lde_domain_size = air.lde_domain_size();
         let trace_length = air.trace_length();
-        let domain = info_span!("build_domain", trace_length, lde_domain_size)
-            .in_scope(|| StarkDomain::new(&air));
+        let domain =
+            info_span!("build_domain", trace_length, lde_domain_size).in_scope(|| match key {
+                Some(key) => key.domain(),
+                None => domain_cache.get_domain(&air),
+            });
         assert_eq!(domain.lde_domain_size(), lde_domain_size);
         assert_eq!(domain.trace_length(), trace_length);
 
         // commit to the main trace segment
-        let (mut trace_lde, mut trace_polys) =
-            maybe_await!(self.commit_to_main_trace_segment(&trace, &domain, &mut channel));
+        let (trace_lde, trace_polys) =
+            maybe_await!(self.commit_to_main_trace_segment(main_trace, domain, &mut channel));
+
+        maybe_await!(self.complete_proof(&air, channel, trace, trace_lde, trace_polys, domain, key))
+    }
+
+    /// Completes the proof generation procedure started in [Prover::generate_proof()] after the
+    /// prover has committed to the main segment of the execution `trace`.
+    ///
+    /// The `channel` is expected to already contain the commitment to the main trace segment, and
+    /// `trace_lde` and `trace_polys` are expected to be the low-degree extension and polynomials
+    /// of the main trace segment over the specified `domain`. If a proving `key` is provided, the
+    /// constraint evaluator is built via [Prover::new_evaluator_with_key()].
+    #[doc(hidden)]
+    #[allow(clippy::too_many_arguments)]
+    #[maybe_async]
+    fn complete_proof<E, T>(
+        &self,
+        air: &Self::Air,
+        mut channel: ProverChannel<'_, Self::Air, E, Self::HashFn, Self::RandomCoin, Self::VC>,
+        trace: T,
+        mut trace_lde: Self::TraceLde<E>,
+        mut trace_polys: TracePolyTable<E>,
+        domain: &StarkDomain<Self::BaseField>,
+        key: Option<&ProvingKey<Self::BaseField>>,
+    ) -> Result<(Proof, Self::RandomCoin, ProverTranscript<E>), ProverError>
+    where
+        E: FieldElement<BaseField = Self::BaseField>,
+        T: Borrow<Self::Trace> + Send,
+        <Self::Air as Air>::GkrProof: Send,
+    {
+        let main_trace: &Self::Trace = trace.borrow();
+        let lde_domain_size = air.lde_domain_size();
+        let trace_length = air.trace_length();
 
         // build the auxiliary trace segment, and append the resulting segments to trace commitment
         // and trace polynomial table structs
         let aux_trace_with_metadata = if air.trace_info().is_multi_segment() {
             let (gkr_proof, aux_rand_elements) = if air.context().has_lagrange_kernel_aux_column() {
                 let (gkr_proof, gkr_rand_elements) =
-                    maybe_await!(self.generate_gkr_proof(&trace, channel.public_coin()));
+                    maybe_await!(self.generate_gkr_proof(main_trace, channel.public_coin()));
 
                 let rand_elements = air
                     .get_aux_rand_elements(channel.public_coin())
@@ -362,19 +1142,45 @@ pub trait Prover {
 
                 (None, AuxRandElements::new(rand_elements))
             };
-
-            let aux_trace = maybe_await!(self.build_aux_trace(&trace, &aux_rand_elements));
+            channel.record_aux_rand_elements(&aux_rand_elements);
+
+            let aux_trace = maybe_await!(self.build_aux_trace(main_trace, &aux_rand_elements));
+
+            // make sure the auxiliary trace segment has the shape declared in the trace info
+            // before it is extended and committed to
+            let aux_segment_width = air.trace_info().aux_segment_width();
+            if aux_trace.num_cols() != aux_segment_width {
+                return Err(ProverError::MismatchedAuxTraceWidth(
+                    aux_segment_width,
+                    aux_trace.num_cols(),
+                ));
+            }
+            if aux_trace.num_rows() != trace_length {
+                return Err(ProverError::MismatchedAuxTraceLength(
+                    trace_length,
+                    aux_trace.num_rows(),
+                ));
+            }
+            if self.check_assertions() {
+                trace::check_assertions(
+                    &air.get_aux_assertions(&aux_rand_elements),
+                    &aux_trace,
+                    air.trace_info().main_trace_width(),
+                )?;
+            }
 
             // commit to the auxiliary trace segment
             let aux_segment_polys = {
                 // extend the auxiliary trace segment and commit to the extended trace
                 let span = info_span!("commit_to_aux_trace_segment").entered();
                 let (aux_segment_polys, aux_segment_commitment) =
-                    trace_lde.set_aux_trace(&aux_trace, &domain);
+                    trace_lde.set_aux_trace(&aux_trace, domain);
 
                 // commit to the LDE of the extended auxiliary trace segment by writing its
                 // commitment into the channel
-                channel.commit_trace(aux_segment_commitment);
+                channel.commit_aux_trace::<<Self::TraceLde<E> as TraceLde<E>>::AuxHashFn>(
+                    aux_segment_commitment,
+                );
 
                 drop(span);
                 aux_segment_polys
@@ -389,10 +1195,18 @@ pub trait Prover {
         };
 
         // make sure the specified trace (including auxiliary segment) is valid against the AIR.
-        // This checks validity of both, assertions and state transitions. We do this in debug
+        // This checks validity of both, assertions and state transitions. Before doing so, we
+        // report residuals of all transition constraints which do not vanish over the trace
+        // domain, so that all broken constraints can be identified at once. We do this in debug
         // mode only because this is a very expensive operation.
         #[cfg(debug_assertions)]
-        trace.validate(&air, aux_trace_with_metadata.as_ref());
+        {
+            let residuals = main_trace.transition_residuals(air, aux_trace_with_metadata.as_ref());
+            if !residuals.is_zero() {
+                event!(Level::DEBUG, "transition constraint residuals:\n{residuals}");
+            }
+            main_trace.validate(air, aux_trace_with_metadata.as_ref());
+        }
 
         // Destructure `aux_trace_with_metadata`.
         let (aux_trace, aux_rand_elements, gkr_proof) = match aux_trace_with_metadata {
@@ -409,17 +1223,25 @@ pub trait Prover {
         // compute random linear combinations of these evaluations using coefficients drawn from
         // the channel
         let ce_domain_size = air.ce_domain_size();
-        let composition_poly_trace = maybe_await!(self.new_evaluator(
-            &air,
-            aux_rand_elements,
-            channel.get_constraint_composition_coeffs()
-        ))
-        .evaluate(&trace_lde, &domain);
+        let composition_coefficients = channel.get_constraint_composition_coeffs();
+        let composition_poly_trace = match key {
+            Some(key) => maybe_await!(self.new_evaluator_with_key(
+                air,
+                key,
+                aux_rand_elements,
+                composition_coefficients
+            ))
+            .evaluate(&trace_lde, domain),
+            None => {
+                maybe_await!(self.new_evaluator(air, aux_rand_elements, composition_coefficients))
+                    .evaluate(&trace_lde, domain)
+            },
+        };
         assert_eq!(composition_poly_trace.num_rows(), ce_domain_size);
 
         // 3 ----- commit to constraint evaluations -----------------------------------------------
         let (constraint_commitment, composition_poly) = maybe_await!(self
-            .commit_to_constraint_evaluations(&air, composition_poly_trace, &domain, &mut channel));
+            .commit_to_constraint_evaluations(air, composition_poly_trace, domain, &mut channel));
 
         // 4 ----- build DEEP composition polynomial ----------------------------------------------
         let deep_composition_poly = {
@@ -444,9 +1266,16 @@ pub trait Prover {
             let ood_evaluations = composition_poly.evaluate_at(z);
             channel.send_ood_constraint_evaluations(&ood_evaluations);
 
-            // draw random coefficients to use during DEEP polynomial composition, and use them to
-            // initialize the DEEP composition polynomial
-            let deep_coefficients = channel.get_deep_composition_coeffs();
+            // draw random coefficients to use during DEEP polynomial composition (possibly replaced
+            // by this prover), and use them to initialize the DEEP composition polynomial
+            let drawn_coefficients = channel.get_deep_composition_coeffs();
+            let deep_coefficients = self.deep_composition_coefficients(drawn_coefficients.clone());
+            if deep_coefficients.trace.len() != drawn_coefficients.trace.len()
+                || deep_coefficients.constraints.len() != drawn_coefficients.constraints.len()
+                || deep_coefficients.lagrange.is_some() != drawn_coefficients.lagrange.is_some()
+            {
+                return Err(ProverError::MismatchedDeepCompositionCoefficients);
+            }
             let mut deep_composition_poly = DeepCompositionPoly::new(z, deep_coefficients);
 
             // combine all trace polynomials together and merge them into the DEEP composition
@@ -470,7 +1299,7 @@ pub trait Prover {
         // 5 ----- evaluate DEEP composition polynomial over LDE domain ---------------------------
         let deep_evaluations = {
             let span = info_span!("evaluate_deep_composition_poly").entered();
-            let deep_evaluations = deep_composition_poly.evaluate(&domain);
+            let deep_evaluations = deep_composition_poly.evaluate(domain);
             // we check the following condition in debug mode only because infer_degree is an
             // expensive operation
             debug_assert_eq!(trace_length - 2, infer_degree(&deep_evaluations, domain.offset()));
@@ -496,8 +1325,14 @@ pub trait Prover {
             // apply proof-of-work to the query seed
             channel.grind_query_seed();
 
-            // generate pseudo-random query positions
+            // generate pseudo-random query positions (unless they are supplied externally)
+            #[cfg(not(feature = "external-queries"))]
             let query_positions = channel.get_query_positions();
+            #[cfg(feature = "external-queries")]
+            let query_positions = match self.external_query_positions() {
+                Some(positions) => channel.normalize_query_positions(positions),
+                None => channel.get_query_positions(),
+            };
             event!(Level::DEBUG, "query_positions_len: {}", query_positions.len());
 
             drop(span);
@@ -505,7 +1340,7 @@ pub trait Prover {
         };
 
         // 8 ----- build proof object -------------------------------------------------------------
-        let proof = {
+        let (proof, public_coin, transcript) = {
             let span = info_span!("build_proof_object").entered();
             // generate FRI proof
             let fri_proof = fri_prover.build_proof(&query_positions);
@@ -520,7 +1355,7 @@ pub trait Prover {
             let constraint_queries = constraint_commitment.query(&query_positions);
 
             // build the proof object
-            let proof = channel.build_proof(
+            let result = channel.build_proof(
                 trace_queries,
                 constraint_queries,
                 fri_proof,
@@ -529,10 +1364,10 @@ pub trait Prover {
             );
 
             drop(span);
-            proof
+            result
         };
 
-        Ok(proof)
+        Ok((proof, public_coin, transcript))
     }
 
     #[doc(hidden)]
@@ -595,3 +1430,75 @@ pub trait Prover {
         (constraint_commitment, composition_poly)
     }
 }
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the proof context for the specified trace info, proof options, and hash function,
+/// auxiliary segment hash function, and proof-of-work identifiers serialized into field elements;
+/// these are used to seed the public coin.
+fn context_elements<B: StarkField>(
+    trace_info: &TraceInfo,
+    options: &ProofOptions,
+    hash_id: Option<u8>,
+    aux_hash_id: Option<u8>,
+    pow_id: Option<u8>,
+) -> Vec<B> {
+    build_context::<B>(trace_info.clone(), options.clone(), hash_id, aux_hash_id, pow_id)
+        .to_elements()
+}
+
+/// Checks that the LDE domain defined by the specified trace info and proof options is a subgroup
+/// of the base field.
+///
+/// The constraint evaluation domain is never larger than the LDE domain, and thus, does not need
+/// to be checked separately.
+pub(crate) fn check_domain_size<B: StarkField>(
+    trace_info: &TraceInfo,
+    options: &ProofOptions,
+) -> Result<(), ProverError> {
+    let lde_domain_size = trace_info.length() * options.blowup_factor();
+    if lde_domain_size.ilog2() > B::TWO_ADICITY {
+        return Err(ProverError::DomainTooLarge(lde_domain_size, B::TWO_ADICITY));
+    }
+    Ok(())
+}
+
+/// Checks that the base field supports the specified field extension.
+pub(crate) fn check_field_extension<B>(field_extension: FieldExtension) -> Result<(), ProverError>
+where
+    B: StarkField + ExtensibleField<2> + ExtensibleField<3> + ExtensibleField<6>,
+{
+    let is_supported = match field_extension {
+        FieldExtension::None => true,
+        FieldExtension::Quadratic => <QuadExtension<B>>::is_supported(),
+        FieldExtension::Cubic => <CubeExtension<B>>::is_supported(),
+        FieldExtension::Sextic => <SexticExtension<B>>::is_supported(),
+    };
+    if !is_supported {
+        return Err(ProverError::UnsupportedFieldExtension(field_extension.degree() as usize));
+    }
+    Ok(())
+}
+
+/// Returns the proof context for the specified trace info, proof options, and hash function,
+/// auxiliary segment hash function, and proof-of-work identifiers.
+pub(crate) fn build_context<B: StarkField>(
+    trace_info: TraceInfo,
+    options: ProofOptions,
+    hash_id: Option<u8>,
+    aux_hash_id: Option<u8>,
+    pow_id: Option<u8>,
+) -> Context {
+    let mut context = Context::new::<B>(trace_info, options);
+    if let Some(hash_id) = hash_id {
+        context = context.with_hash_id(hash_id);
+    }
+    if let Some(aux_hash_id) = aux_hash_id {
+        context = context.with_aux_hash_id(aux_hash_id);
+    }
+    if let Some(pow_id) = pow_id {
+        context = context.with_pow_id(pow_id);
+    }
+    context
+}
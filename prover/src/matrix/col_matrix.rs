@@ -6,7 +6,7 @@
 use alloc::vec::Vec;
 use core::{iter::FusedIterator, slice};
 
-use crypto::{ElementHasher, VectorCommitment};
+use crypto::{ElementHasher, RandomCoin, RandomCoinError, VectorCommitment};
 use math::{fft, polynom, FieldElement};
 #[cfg(feature = "concurrent")]
 use utils::iterators::*;
@@ -163,15 +163,66 @@ impl<E: FieldElement> ColMatrix<E> {
         self.columns.remove(index)
     }
 
+    /// Appends at least `num_rows` rows of random elements drawn from the specified `coin` to this
+    /// matrix, and returns the number of rows appended.
+    ///
+    /// To keep the number of rows a power of two, the matrix is extended to the next power of two
+    /// greater than or equal to `self.num_rows() + num_rows`, and all of the appended rows are
+    /// filled with random elements. The elements are drawn from the coin row by row (i.e., all
+    /// elements of the first appended row are drawn before any element of the second appended
+    /// row), and thus, the same coin state always results in the same masking rows. If
+    /// `num_rows` is zero, the matrix is left unchanged.
+    ///
+    /// When this matrix is used as an execution trace, the transition constraints will generally
+    /// not hold for any transition which involves an appended row, including the transition from
+    /// the last original row into the first appended row. The AIR for such a trace must therefore
+    /// exempt the appended rows from transition constraints (e.g., via
+    /// [AirContext::set_num_transition_exemptions()](air::AirContext::set_num_transition_exemptions)
+    /// with the number of returned rows plus one). Similarly, assertions against the last step of
+    /// the trace refer to the last appended row; assertions against the original trace should be
+    /// made against the original last step instead.
+    ///
+    /// # Errors
+    /// Returns an error if the coin fails to draw a random element.
+    pub fn randomize_padding<R>(
+        &mut self,
+        num_rows: usize,
+        coin: &mut R,
+    ) -> Result<usize, RandomCoinError>
+    where
+        R: RandomCoin<BaseField = E::BaseField>,
+    {
+        if num_rows == 0 {
+            return Ok(0);
+        }
+
+        let num_original_rows = self.num_rows();
+        let num_padded_rows = (num_original_rows + num_rows).next_power_of_two();
+        let num_appended_rows = num_padded_rows - num_original_rows;
+
+        for column in self.columns.iter_mut() {
+            column.reserve_exact(num_appended_rows);
+        }
+        for _ in 0..num_appended_rows {
+            for column in self.columns.iter_mut() {
+                column.push(coin.draw()?);
+            }
+        }
+
+        Ok(num_appended_rows)
+    }
+
     // ITERATION
     // --------------------------------------------------------------------------------------------
 
     /// Returns an iterator over the columns of this matrix.
+    #[allow(mismatched_lifetime_syntaxes)]
     pub fn columns(&self) -> ColumnIter<E> {
         ColumnIter::new(self)
     }
 
     /// Returns a mutable iterator over the columns of this matrix.
+    #[allow(mismatched_lifetime_syntaxes)]
     pub fn columns_mut(&mut self) -> ColumnIterMut<E> {
         ColumnIterMut::new(self)
     }
@@ -227,6 +278,11 @@ impl<E: FieldElement> ColMatrix<E> {
     ///   [StarkDomain] using FFT algorithm. The domain specification includes the size of the
     ///   subgroup as well as the domain offset (to define a coset).
     /// * The resulting evaluations are returned in a new Matrix.
+    ///
+    /// This is the same low-degree extension which the prover applies to the trace polynomials,
+    /// and thus, it can be used to compute the LDE of a trace independently of the prover.
+    ///
+    /// When `concurrent` feature is enabled, the columns are evaluated in multiple threads.
     pub fn evaluate_columns_over(&self, domain: &StarkDomain<E::BaseField>) -> Self {
         let columns = iter!(self.columns)
             .map(|poly| {
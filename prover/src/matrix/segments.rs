@@ -174,6 +174,7 @@ impl<B: StarkField, const N: usize> Segment<B, N> {
 
     /// Copies N polynomials starting at the specified base column offset (`poly_offset`) into the
     /// specified destination. Each polynomial coefficient is offset by the specified offset.
+    #[allow(clippy::needless_range_loop)]
     fn copy_polys<E: FieldElement<BaseField = B>>(
         dest: &mut [[B; N]],
         polys: &ColMatrix<E>,
@@ -192,6 +193,7 @@ impl<B: StarkField, const N: usize> Segment<B, N> {
     /// `N` polynomials.
     ///
     /// Assumes that `num_polys` is smaller than `N`.
+    #[allow(clippy::needless_range_loop)]
     fn copy_polys_partial<E: FieldElement<BaseField = B>>(
         dest: &mut [[B; N]],
         polys: &ColMatrix<E>,
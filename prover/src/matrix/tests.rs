@@ -8,8 +8,9 @@ use alloc::vec::Vec;
 use rand_utils::rand_vector;
 
 use crate::{
-    math::{fields::f64::BaseElement, get_power_series, polynom, StarkField},
-    ColMatrix, RowMatrix,
+    crypto::{hashers::Blake3_256, DefaultRandomCoin, RandomCoin},
+    math::{fft, fields::f64::BaseElement, get_power_series, polynom, FieldElement, StarkField},
+    ColMatrix, RowMatrix, StarkDomain,
 };
 
 #[test]
@@ -41,6 +42,73 @@ fn test_eval_poly_with_offset_matrix() {
     }
 }
 
+#[test]
+fn test_col_matrix_evaluate_columns_over() {
+    let n = 128;
+    let num_polys = 8;
+    let blowup_factor = 4;
+
+    // generate random columns. Each column is a polynomial of degree n - 1.
+    let columns: Vec<Vec<BaseElement>> = (0..num_polys).map(|_| rand_vector(n)).collect();
+    let matrix = ColMatrix::new(columns.clone());
+
+    // evaluate columns over the LDE domain using the column matrix implementation.
+    let offset = BaseElement::GENERATOR;
+    let stark_domain = StarkDomain::from_twiddles(fft::get_twiddles(n), blowup_factor, offset);
+    let result = matrix.evaluate_columns_over(&stark_domain);
+    assert_eq!(num_polys, result.num_cols());
+    assert_eq!(n * blowup_factor, result.num_rows());
+
+    // evaluate columns using the polynomial evaluation implementation.
+    let domain = build_domain(n * blowup_factor);
+    let shifted_domain = domain.iter().map(|&x| x * offset).collect::<Vec<_>>();
+    for (i, p) in columns.iter().enumerate() {
+        assert_eq!(polynom::eval_many(p, &shifted_domain), result.get_column(i));
+    }
+
+    // the results should be the same as the ones computed using the row matrix implementation.
+    let row_matrix = RowMatrix::evaluate_polys_over::<8>(&matrix, &stark_domain);
+    let result = result.into_columns();
+    for row in 0..n * blowup_factor {
+        assert_eq!(row_matrix.row(row), get_row(&result, row));
+    }
+}
+
+#[test]
+fn test_col_matrix_randomize_padding() {
+    type Coin = DefaultRandomCoin<Blake3_256<BaseElement>>;
+
+    let columns: Vec<Vec<BaseElement>> = (0..3).map(|_| rand_vector(8)).collect();
+    let mut matrix = ColMatrix::new(columns.clone());
+    let mut coin = Coin::new(&[BaseElement::ONE]);
+
+    // zero rows leave the matrix unchanged
+    assert_eq!(0, matrix.randomize_padding(0, &mut coin).unwrap());
+    assert_eq!(8, matrix.num_rows());
+
+    // appending 3 rows to 8 rows extends the matrix to 16 rows
+    assert_eq!(8, matrix.randomize_padding(3, &mut coin).unwrap());
+    assert_eq!(16, matrix.num_rows());
+    for (i, column) in columns.iter().enumerate() {
+        assert_eq!(column, &matrix.get_column(i)[..8]);
+    }
+
+    // the appended rows are drawn row by row from the coin
+    let mut expected_coin = Coin::new(&[BaseElement::ONE]);
+    for row in 8..16 {
+        for col in 0..3 {
+            assert_eq!(expected_coin.draw::<BaseElement>().unwrap(), matrix.get(col, row));
+        }
+    }
+
+    // the same coin state results in the same padding
+    let mut other = ColMatrix::new(columns);
+    other.randomize_padding(3, &mut Coin::new(&[BaseElement::ONE])).unwrap();
+    for col in 0..3 {
+        assert_eq!(matrix.get_column(col), other.get_column(col));
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -0,0 +1,149 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use core::mem::size_of;
+
+use air::{ProofOptions, TraceInfo};
+use crypto::Hasher;
+use math::StarkField;
+
+// MEMORY ESTIMATE
+// ================================================================================================
+
+/// An approximate breakdown of memory required to generate a STARK proof.
+///
+/// All values are expressed in bytes and are derived solely from the dimensions of the execution
+/// trace and the proof options (i.e., they do not depend on the values in the trace). The main
+/// trace segment itself is not included since it is allocated by the caller before proof
+/// generation starts.
+///
+/// Sizes of constraint evaluation buffers depend on the degree of the constraint composition
+/// polynomial which cannot be derived from the trace dimensions alone. These buffers are
+/// estimated assuming the largest degree permitted by the blowup factor, and thus, the estimate
+/// is an upper bound for computations with lower constraint degrees.
+///
+/// An estimate can be built via [Prover::estimate_memory()](crate::Prover::estimate_memory).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryEstimate {
+    /// Trace polynomials for the main and auxiliary trace segments.
+    pub trace_polys: usize,
+    /// Evaluations of trace polynomials over the LDE domain.
+    pub trace_lde: usize,
+    /// Merkle trees built over the main and auxiliary trace LDEs.
+    pub trace_commitments: usize,
+    /// Constraint evaluations over the constraint evaluation domain and the constraint
+    /// composition polynomial.
+    pub constraint_evaluations: usize,
+    /// Evaluations of the constraint composition polynomial columns over the LDE domain and the
+    /// Merkle tree built over them.
+    pub constraint_commitment: usize,
+    /// Evaluations of the DEEP composition polynomial and all FRI layers together with their
+    /// Merkle trees.
+    pub fri: usize,
+}
+
+impl MemoryEstimate {
+    /// Returns an estimate of memory required to prove a computation described by `trace_info`
+    /// with the specified `options`, using field `B` and hash function `H`.
+    pub fn new<B: StarkField, H: Hasher>(trace_info: &TraceInfo, options: &ProofOptions) -> Self {
+        let base_bytes = B::ELEMENT_BYTES;
+        let ext_bytes = base_bytes * options.field_extension().degree() as usize;
+        let digest_bytes = size_of::<H::Digest>();
+
+        let trace_length = trace_info.length();
+        let lde_domain_size = trace_length * options.blowup_factor();
+
+        // a Merkle tree over n leaves stores n leaf digests and n internal node digests
+        let tree_bytes = |num_leaves: usize| 2 * num_leaves * digest_bytes;
+
+        // trace polynomials and their evaluations; constant columns are not committed to
+        let main_width = trace_info.main_trace_width();
+        let main_lde_width = trace_info.main_trace_commitment_width();
+        let aux_width = trace_info.aux_segment_width();
+        let trace_polys = trace_length * (main_width * base_bytes + aux_width * ext_bytes);
+        let trace_lde = lde_domain_size * (main_lde_width * base_bytes + aux_width * ext_bytes);
+        let num_trace_commitments = if trace_info.is_multi_segment() { 2 } else { 1 };
+        let trace_commitments = num_trace_commitments * tree_bytes(lde_domain_size);
+
+        // constraint evaluation domain size and number of composition columns grow with the
+        // constraint degree; both are bounded by the blowup factor
+        let ce_domain_size = lde_domain_size;
+        let num_composition_columns = options.blowup_factor();
+        let constraint_evaluations = 2 * ce_domain_size * ext_bytes;
+        let constraint_commitment =
+            lde_domain_size * num_composition_columns * ext_bytes + tree_bytes(lde_domain_size);
+
+        // DEEP composition polynomial evaluations followed by FRI layers
+        let fri_options = options.to_fri_options();
+        let folding_factor = fri_options.folding_factor();
+        let mut fri = lde_domain_size * ext_bytes;
+        let mut domain_size = lde_domain_size;
+        for _ in 0..fri_options.num_fri_layers(lde_domain_size) {
+            fri += domain_size * ext_bytes + tree_bytes(domain_size / folding_factor);
+            domain_size /= folding_factor;
+        }
+
+        Self {
+            trace_polys,
+            trace_lde,
+            trace_commitments,
+            constraint_evaluations,
+            constraint_commitment,
+            fri,
+        }
+    }
+
+    /// Returns the total number of bytes in this estimate.
+    ///
+    /// Not all of the estimated buffers are alive at the same time during proof generation, and
+    /// thus, this value is a conservative estimate of peak memory usage.
+    pub fn total(&self) -> usize {
+        self.trace_polys
+            + self.trace_lde
+            + self.trace_commitments
+            + self.constraint_evaluations
+            + self.constraint_commitment
+            + self.fri
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use air::{FieldExtension, ProofOptions, TraceInfo};
+    use crypto::hashers::Blake3_256;
+    use math::fields::f64::BaseElement;
+
+    use super::MemoryEstimate;
+
+    #[test]
+    fn memory_estimate() {
+        let trace_info = TraceInfo::new(2, 64);
+        let options = ProofOptions::new(32, 4, 0, FieldExtension::Quadratic, 4, 7);
+        let estimate =
+            MemoryEstimate::new::<BaseElement, Blake3_256<BaseElement>>(&trace_info, &options);
+
+        // LDE domain size is 256, extension elements are 16 bytes, and digests are 32 bytes
+        assert_eq!(64 * 2 * 8, estimate.trace_polys);
+        assert_eq!(256 * 2 * 8, estimate.trace_lde);
+        assert_eq!(2 * 256 * 32, estimate.trace_commitments);
+        assert_eq!(2 * 256 * 16, estimate.constraint_evaluations);
+        assert_eq!(256 * 4 * 16 + 2 * 256 * 32, estimate.constraint_commitment);
+
+        // two FRI layers over domains of size 256 and 64 are needed to reach the remainder
+        let fri = 256 * 16 + (256 * 16 + 2 * 64 * 32) + (64 * 16 + 2 * 16 * 32);
+        assert_eq!(fri, estimate.fri);
+        assert_eq!(76800, estimate.total());
+
+        // an auxiliary trace segment requires an additional commitment
+        let trace_info = TraceInfo::new_multi_segment(2, 1, 0, 64, vec![]);
+        let aux_estimate =
+            MemoryEstimate::new::<BaseElement, Blake3_256<BaseElement>>(&trace_info, &options);
+        assert_eq!(64 * (2 * 8 + 16), aux_estimate.trace_polys);
+        assert_eq!(2 * estimate.trace_commitments, aux_estimate.trace_commitments);
+    }
+}
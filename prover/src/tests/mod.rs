@@ -108,6 +108,31 @@ impl Air for MockAir {
     }
 }
 
+// DOMAIN SIZE
+// ================================================================================================
+
+#[test]
+fn check_domain_size() {
+    use math::fields::f64::BaseElement as Felt;
+
+    use crate::ProverError;
+
+    // the largest subgroup of the 64-bit field has size 2^32
+    let options = ProofOptions::new(32, 8, 0, FieldExtension::None, 4, 31);
+    let trace_info = TraceInfo::new(1, 2_usize.pow(29));
+    assert_eq!(Ok(()), crate::check_domain_size::<Felt>(&trace_info, &options));
+
+    let trace_info = TraceInfo::new(1, 2_usize.pow(30));
+    assert_eq!(
+        Err(ProverError::DomainTooLarge(2_usize.pow(33), 32)),
+        crate::check_domain_size::<Felt>(&trace_info, &options)
+    );
+
+    // a field extension does not increase the largest supported domain size
+    let options = ProofOptions::new(32, 8, 0, FieldExtension::Quadratic, 4, 31);
+    assert!(crate::check_domain_size::<Felt>(&trace_info, &options).is_err());
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
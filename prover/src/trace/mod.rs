@@ -3,10 +3,12 @@
 // This source code is licensed under the MIT license found in the
 // LICENSE file in the root directory of this source tree.
 
-use air::{Air, AuxRandElements, EvaluationFrame, LagrangeKernelBoundaryConstraint, TraceInfo};
+use air::{
+    Air, Assertion, AuxRandElements, EvaluationFrame, LagrangeKernelBoundaryConstraint, TraceInfo,
+};
 use math::{polynom, FieldElement, StarkField};
 
-use super::ColMatrix;
+use super::{ColMatrix, ProverError};
 
 mod trace_lde;
 pub use trace_lde::{DefaultTraceLde, TraceLde};
@@ -17,6 +19,13 @@ pub use poly_table::TracePolyTable;
 mod trace_table;
 pub use trace_table::{TraceTable, TraceTableFragment};
 
+mod padded_trace;
+pub use padded_trace::PaddedTrace;
+
+mod residuals;
+use residuals::describe_constraint;
+pub use residuals::{ConstraintResidual, TransitionResiduals};
+
 #[cfg(test)]
 mod tests;
 
@@ -48,10 +57,11 @@ pub struct AuxTraceWithMetadata<E: FieldElement, GkrProof> {
 /// instance of a computation must be supplied to [Prover::prove()](super::Prover::prove) method
 /// to generate a STARK proof.
 ///
-/// This crate exposes one concrete implementation of the [Trace] trait: [TraceTable]. This
-/// implementation supports concurrent trace generation and should be sufficient in most
-/// situations. However, if functionality provided by [TraceTable] is not sufficient, uses can
-/// provide custom implementations of the [Trace] trait which better suit their needs.
+/// This crate exposes two concrete implementations of the [Trace] trait: [TraceTable] and
+/// [PaddedTrace]. The former supports concurrent trace generation and should be sufficient in most
+/// situations; the latter wraps another trace and pads it past the last computed row. However, if
+/// functionality provided by these implementations is not sufficient, uses can provide custom
+/// implementations of the [Trace] trait which better suit their needs.
 pub trait Trace: Sized {
     /// Base field for this execution trace.
     ///
@@ -87,6 +97,34 @@ pub trait Trace: Sized {
         self.info().aux_segment_width()
     }
 
+    /// Returns the entire column of the main trace segment at the specified index.
+    ///
+    /// # Panics
+    /// Panics if `col_idx` is greater than or equal to the width of the main trace segment.
+    fn get_column(&self, col_idx: usize) -> &[Self::BaseField] {
+        assert!(
+            col_idx < self.main_trace_width(),
+            "column index must be smaller than {}, but was {}",
+            self.main_trace_width(),
+            col_idx
+        );
+        self.main_segment().get_column(col_idx)
+    }
+
+    /// Checks that the main segment of this trace satisfies all assertions of the specified AIR.
+    ///
+    /// Unlike [Trace::validate()], this checks only boundary assertions (and not transition
+    /// constraints), and thus, is cheap enough to be performed before proof generation.
+    ///
+    /// # Errors
+    /// Returns [ProverError::UnsatisfiedAssertion] for the first assertion which is not satisfied.
+    fn check_assertions<A>(&self, air: &A) -> Result<(), ProverError>
+    where
+        A: Air<BaseField = Self::BaseField>,
+    {
+        check_assertions(&air.get_assertions(), self.main_segment(), 0)
+    }
+
     /// Checks if this trace is valid against the specified AIR, and panics if not.
     ///
     /// NOTE: this is a very expensive operation and is intended for use only in debug mode.
@@ -107,6 +145,20 @@ pub trait Trace: Sized {
             air.trace_info().main_trace_width(),
         );
 
+        // make sure the columns declared as constant are actually constant
+        let constant_columns = self
+            .info()
+            .constant_columns::<Self::BaseField>()
+            .expect("failed to read constant columns from trace info");
+        for (column, value) in constant_columns {
+            for step in 0..self.length() {
+                assert!(
+                    value == self.main_segment().get(column, step),
+                    "trace does not match constant column main_trace({column}, {step}) == {value}"
+                );
+            }
+        }
+
         // --- 1. make sure the assertions are valid ----------------------------------------------
 
         // first, check assertions against the main segment of the execution trace
@@ -171,9 +223,10 @@ pub trait Trace: Sized {
         } else {
             None
         };
-        let mut main_evaluations =
-            vec![Self::BaseField::ZERO; air.context().num_main_transition_constraints()];
+        let num_main_constraints = air.context().num_main_transition_constraints();
+        let mut main_evaluations = vec![Self::BaseField::ZERO; num_main_constraints];
         let mut aux_evaluations = vec![E::ZERO; air.context().num_aux_transition_constraints()];
+        let constraint_names = air.constraint_names();
 
         // we check transition constraints on all steps except the last k steps, where k is the
         // number of steps exempt from transition constraints (guaranteed to be at least 1)
@@ -188,11 +241,13 @@ pub trait Trace: Sized {
             // evaluate transition constraints for the main trace segment and make sure they all
             // evaluate to zeros
             self.read_main_frame(step, &mut main_frame);
+            main_frame.evaluate_virtual_columns(air);
             air.evaluate_transition(&main_frame, &periodic_values, &mut main_evaluations);
             for (i, &evaluation) in main_evaluations.iter().enumerate() {
                 assert!(
                     evaluation == Self::BaseField::ZERO,
-                    "main transition constraint {i} did not evaluate to ZERO at step {step}"
+                    "main transition constraint {} did not evaluate to ZERO at step {step}",
+                    describe_constraint(i, constraint_names.get(i).copied())
                 );
             }
 
@@ -213,9 +268,11 @@ pub trait Trace: Sized {
                     &mut aux_evaluations,
                 );
                 for (i, &evaluation) in aux_evaluations.iter().enumerate() {
+                    let name = constraint_names.get(num_main_constraints + i).copied();
                     assert!(
                         evaluation == E::ZERO,
-                        "auxiliary transition constraint {i} did not evaluate to ZERO at step {step}"
+                        "auxiliary transition constraint {} did not evaluate to ZERO at step {step}",
+                        describe_constraint(i, name)
                     );
                 }
             }
@@ -258,11 +315,132 @@ pub trait Trace: Sized {
             }
         }
     }
+
+    /// Evaluates transition constraints of the specified AIR over the trace domain of this trace
+    /// and returns their residuals.
+    ///
+    /// Unlike [Trace::validate()], this does not stop at the first constraint which does not
+    /// evaluate to zero, and thus, can be used to identify all constraints which are not satisfied
+    /// by this trace. Transition constraints of the Lagrange kernel column are not included.
+    ///
+    /// NOTE: this is a very expensive operation and is intended for use only in debug mode.
+    fn transition_residuals<A, E>(
+        &self,
+        air: &A,
+        aux_trace_with_metadata: Option<&AirAuxTraceWithMetadata<A, E>>,
+    ) -> TransitionResiduals<E>
+    where
+        A: Air<BaseField = Self::BaseField>,
+        E: FieldElement<BaseField = Self::BaseField>,
+    {
+        let num_main_constraints = air.context().num_main_transition_constraints();
+        let num_aux_constraints = air.context().num_aux_transition_constraints();
+        let mut residuals = TransitionResiduals::new(
+            num_main_constraints,
+            num_aux_constraints,
+            &air.constraint_names(),
+        );
+
+        // collect the info needed to build periodic values for a specific step
+        let g = air.trace_domain_generator();
+        let periodic_values_polys = air.get_periodic_column_polys();
+        let mut periodic_values = vec![Self::BaseField::ZERO; periodic_values_polys.len()];
+
+        // initialize buffers to hold evaluation frames and results of constraint evaluations
+        let mut x = Self::BaseField::ONE;
+        let mut main_frame = EvaluationFrame::new(self.main_trace_width());
+        let mut aux_frame = aux_trace_with_metadata.map(|aux_trace_with_metadata| {
+            (aux_trace_with_metadata, EvaluationFrame::<E>::new(self.aux_trace_width()))
+        });
+        let mut main_evaluations = vec![Self::BaseField::ZERO; num_main_constraints];
+        let mut aux_evaluations = vec![E::ZERO; num_aux_constraints];
+
+        for step in 0..self.length() - air.context().num_transition_exemptions() {
+            // build periodic values
+            for (p, v) in periodic_values_polys.iter().zip(periodic_values.iter_mut()) {
+                let num_cycles = air.trace_length() / p.len();
+                let x = x.exp((num_cycles as u32).into());
+                *v = polynom::eval(p, x);
+            }
+
+            // evaluate transition constraints for the main trace segment
+            self.read_main_frame(step, &mut main_frame);
+            main_frame.evaluate_virtual_columns(air);
+            air.evaluate_transition(&main_frame, &periodic_values, &mut main_evaluations);
+            for (residual, &evaluation) in residuals.main.iter_mut().zip(main_evaluations.iter()) {
+                residual.update(step, E::from(evaluation));
+            }
+
+            // evaluate transition constraints for the auxiliary trace segment (if any)
+            if let Some((aux_trace_with_metadata, aux_frame)) = aux_frame.as_mut() {
+                read_aux_frame(&aux_trace_with_metadata.aux_trace, step, aux_frame);
+                air.evaluate_aux_transition(
+                    &main_frame,
+                    aux_frame,
+                    &periodic_values,
+                    &aux_trace_with_metadata.aux_rand_elements,
+                    &mut aux_evaluations,
+                );
+                for (residual, &evaluation) in residuals.aux.iter_mut().zip(aux_evaluations.iter())
+                {
+                    residual.update(step, evaluation);
+                }
+            }
+
+            // update x coordinate of the domain
+            x *= g;
+        }
+
+        residuals
+    }
 }
 
 // HELPER FUNCTIONS
 // ================================================================================================
 
+/// Checks that the provided trace segment satisfies all of the specified assertions.
+///
+/// `column_offset` is the index of the first column of the segment across all trace segments,
+/// and is used only to report the column of an unsatisfied assertion.
+pub(crate) fn check_assertions<E>(
+    assertions: &[Assertion<E>],
+    segment: &ColMatrix<E>,
+    column_offset: usize,
+) -> Result<(), ProverError>
+where
+    E: FieldElement,
+{
+    match find_unsatisfied_assertion(assertions, segment) {
+        Some((column, step, _)) => {
+            Err(ProverError::UnsatisfiedAssertion { column: column_offset + column, step })
+        },
+        None => Ok(()),
+    }
+}
+
+/// Returns the column, the step and the asserted value of the first assertion which is not
+/// satisfied by the provided trace segment, or None if all assertions are satisfied.
+pub(crate) fn find_unsatisfied_assertion<E>(
+    assertions: &[Assertion<E>],
+    segment: &ColMatrix<E>,
+) -> Option<(usize, usize, E)>
+where
+    E: FieldElement,
+{
+    for assertion in assertions {
+        let mut unsatisfied = None;
+        assertion.apply(segment.num_rows(), |step, value| {
+            if unsatisfied.is_none() && segment.get(assertion.column(), step) != value {
+                unsatisfied = Some((step, value));
+            }
+        });
+        if let Some((step, value)) = unsatisfied {
+            return Some((assertion.column(), step, value));
+        }
+    }
+    None
+}
+
 /// Reads an evaluation frame from the provided auxiliary segment.
 ///
 /// This is probably not the most efficient implementation, but since we call this function only
@@ -0,0 +1,173 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+
+use air::{EvaluationFrame, TraceInfo};
+
+use super::{ColMatrix, Trace};
+
+// PADDED TRACE
+// ================================================================================================
+/// An execution trace adapter which pads the rows of a trace past its real length.
+///
+/// STARK proofs require the length of an execution trace to be a power of two (and at least
+/// [TraceInfo::MIN_TRACE_LENGTH]). A [PaddedTrace] wraps a trace allocated to such a length of
+/// which only the first `real_length` rows were computed, and treats every row past the real
+/// length as a copy of the last real row. Thus, the trace generator needs to fill in only the
+/// rows it computes, while the AIR sees a complete trace of a valid length.
+///
+/// Padding rows are not stored: [PaddedTrace::get()] and [Trace::read_main_frame()] read them
+/// from the last real row of the inner trace. The padded main trace segment is built only when
+/// it is first requested via [Trace::main_segment()] (e.g., when the prover extends the trace).
+/// Without the `std` feature, the padded segment is built when the [PaddedTrace] is created.
+///
+/// The length of the trace before padding can be retrieved via [PaddedTrace::real_length()] and
+/// [PaddedTrace::last_real_step()]. These can be used to place assertions against the last
+/// computed row of the trace (rather than the last row of the padded trace). Note that transition
+/// constraints of the AIR must hold for the padding rows as well; since padding rows repeat the
+/// last row, this is the case for constraints which are satisfied when the state of the
+/// computation does not change.
+#[derive(Debug, Clone)]
+pub struct PaddedTrace<T: Trace> {
+    inner: T,
+    real_length: usize,
+    #[cfg(feature = "std")]
+    main_segment: std::sync::OnceLock<ColMatrix<T::BaseField>>,
+    #[cfg(not(feature = "std"))]
+    main_segment: ColMatrix<T::BaseField>,
+}
+
+impl<T: Trace> PaddedTrace<T> {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new padded execution trace which treats all rows of the `inner` trace at or
+    /// past `real_length` as copies of the row at `real_length - 1`.
+    ///
+    /// The length and the metadata of the padded trace are the same as those of the `inner`
+    /// trace. Values of the padding rows in the `inner` trace are ignored.
+    ///
+    /// # Panics
+    /// Panics if `real_length` is zero or greater than the length of the `inner` trace.
+    pub fn new(inner: T, real_length: usize) -> Self {
+        assert!(real_length > 0, "execution trace must consist of at least one real row");
+        assert!(
+            real_length <= inner.length(),
+            "real length of the trace cannot exceed {}, but was {}",
+            inner.length(),
+            real_length
+        );
+
+        #[cfg(feature = "std")]
+        let main_segment = std::sync::OnceLock::new();
+        #[cfg(not(feature = "std"))]
+        let main_segment = build_main_segment(&inner, real_length);
+
+        Self { inner, real_length, main_segment }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a reference to the trace wrapped by this padded trace.
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Returns the number of rows in this trace before padding.
+    pub fn real_length(&self) -> usize {
+        self.real_length
+    }
+
+    /// Returns the index of the last row of this trace before padding.
+    pub fn last_real_step(&self) -> usize {
+        self.real_length - 1
+    }
+
+    /// Returns the number of padding rows in this trace.
+    pub fn num_padding_rows(&self) -> usize {
+        self.length() - self.real_length
+    }
+
+    /// Returns true if the row at the specified index is a padding row.
+    pub fn is_padding_row(&self, row_idx: usize) -> bool {
+        row_idx >= self.real_length
+    }
+
+    /// Returns the number of columns in this execution trace.
+    pub fn width(&self) -> usize {
+        self.main_trace_width()
+    }
+
+    /// Returns value of the cell in the specified column at the specified row of this trace.
+    ///
+    /// This does not require the padded main trace segment to be built.
+    pub fn get(&self, column: usize, step: usize) -> T::BaseField {
+        assert!(
+            step < self.length(),
+            "step must be smaller than {}, but was {}",
+            self.length(),
+            step
+        );
+        self.inner.main_segment().get(column, self.source_row(step))
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the index of the row of the inner trace which holds the values of the specified
+    /// row of this trace.
+    fn source_row(&self, row_idx: usize) -> usize {
+        row_idx.min(self.last_real_step())
+    }
+}
+
+// TRACE TRAIT IMPLEMENTATION
+// ================================================================================================
+
+impl<T: Trace> Trace for PaddedTrace<T> {
+    type BaseField = T::BaseField;
+
+    fn info(&self) -> &TraceInfo {
+        self.inner.info()
+    }
+
+    fn read_main_frame(&self, row_idx: usize, frame: &mut EvaluationFrame<Self::BaseField>) {
+        let next_row_idx = (row_idx + 1) % self.length();
+        let inner = self.inner.main_segment();
+        inner.read_row_into(self.source_row(row_idx), frame.current_mut());
+        inner.read_row_into(self.source_row(next_row_idx), frame.next_mut());
+    }
+
+    #[cfg(feature = "std")]
+    fn main_segment(&self) -> &ColMatrix<Self::BaseField> {
+        self.main_segment
+            .get_or_init(|| build_main_segment(&self.inner, self.real_length))
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn main_segment(&self) -> &ColMatrix<Self::BaseField> {
+        &self.main_segment
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Copies the first `real_length` rows of the main segment of the `inner` trace and fills the
+/// remaining rows with copies of the last real row.
+fn build_main_segment<T: Trace>(inner: &T, real_length: usize) -> ColMatrix<T::BaseField> {
+    let columns = inner
+        .main_segment()
+        .columns()
+        .map(|column| {
+            let mut column = column[..real_length].to_vec();
+            column.resize(inner.length(), column[real_length - 1]);
+            column
+        })
+        .collect::<Vec<_>>();
+    ColMatrix::new(columns)
+}
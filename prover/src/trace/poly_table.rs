@@ -19,6 +19,7 @@ use crate::{matrix::ColumnIter, ColMatrix};
 /// However, coefficients of the polynomials for the auxiliary trace segment (including
 /// the Lagrange kernel polynomial when present) may be either in the base field, or in
 /// the extension field, depending on whether extension field is being used.
+#[derive(Clone)]
 pub struct TracePolyTable<E: FieldElement> {
     main_trace_polys: ColMatrix<E::BaseField>,
     aux_trace_polys: Option<ColMatrix<E>>,
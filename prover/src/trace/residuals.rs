@@ -0,0 +1,134 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+
+use math::FieldElement;
+
+// TRANSITION RESIDUALS
+// ================================================================================================
+
+/// Residuals of transition constraints evaluated over the trace domain.
+///
+/// For a valid execution trace, all transition constraints evaluate to zero at all steps of the
+/// trace domain (except for the exempt steps at the end of the trace). A non-zero residual points
+/// to the constraint which is not satisfied by the trace, and to the steps at which this happens.
+///
+/// Elements of a finite field are not ordered, and thus, instead of the magnitude of a residual,
+/// each constraint records the number of steps at which its residual is non-zero together with
+/// the first such step.
+///
+/// Residuals can be computed via [Trace::transition_residuals()](crate::Trace::transition_residuals).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransitionResiduals<E: FieldElement> {
+    /// Residuals of transition constraints against the main trace segment.
+    pub main: Vec<ConstraintResidual<E>>,
+    /// Residuals of transition constraints against the auxiliary trace segment.
+    pub aux: Vec<ConstraintResidual<E>>,
+}
+
+impl<E: FieldElement> TransitionResiduals<E> {
+    /// Returns new residuals for the specified number of main and auxiliary transition
+    /// constraints, all of which are initially zero.
+    ///
+    /// Constraint names are expected to be in the order returned from
+    /// [Air::constraint_names()](air::Air::constraint_names).
+    pub(super) fn new(
+        num_main_constraints: usize,
+        num_aux_constraints: usize,
+        names: &[&'static str],
+    ) -> Self {
+        let mut names = names.iter().copied();
+        let mut build_residuals = |num_constraints| {
+            (0..num_constraints)
+                .map(|_| ConstraintResidual { name: names.next(), ..Default::default() })
+                .collect()
+        };
+
+        Self {
+            main: build_residuals(num_main_constraints),
+            aux: build_residuals(num_aux_constraints),
+        }
+    }
+
+    /// Returns true if all transition constraints evaluated to zero at all steps.
+    pub fn is_zero(&self) -> bool {
+        self.main.iter().chain(self.aux.iter()).all(ConstraintResidual::is_zero)
+    }
+}
+
+impl<E: FieldElement> fmt::Display for TransitionResiduals<E> {
+    /// Lists all transition constraints with non-zero residuals.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let segments = [("main", &self.main), ("auxiliary", &self.aux)];
+        for (segment, residuals) in segments {
+            for (i, residual) in residuals.iter().enumerate() {
+                if let Some((step, value)) = residual.first_nonzero {
+                    writeln!(
+                        f,
+                        "{segment} transition constraint {} did not evaluate to ZERO at {} \
+                        steps; first residual was {value} at step {step}",
+                        describe_constraint(i, residual.name),
+                        residual.num_nonzero_steps
+                    )?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+// CONSTRAINT RESIDUAL
+// ================================================================================================
+
+/// Residual of a single transition constraint over the trace domain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConstraintResidual<E: FieldElement> {
+    /// Name of the constraint, if provided by the AIR.
+    pub name: Option<&'static str>,
+    /// Number of steps at which the constraint did not evaluate to zero.
+    pub num_nonzero_steps: usize,
+    /// The first step at which the constraint did not evaluate to zero, together with the value
+    /// of the constraint at this step.
+    pub first_nonzero: Option<(usize, E)>,
+}
+
+impl<E: FieldElement> ConstraintResidual<E> {
+    /// Returns true if the constraint evaluated to zero at all steps.
+    pub fn is_zero(&self) -> bool {
+        self.num_nonzero_steps == 0
+    }
+
+    /// Records the value of the constraint at the specified step.
+    pub(super) fn update(&mut self, step: usize, value: E) {
+        if value != E::ZERO {
+            self.first_nonzero.get_or_insert((step, value));
+            self.num_nonzero_steps += 1;
+        }
+    }
+}
+
+impl<E: FieldElement> Default for ConstraintResidual<E> {
+    fn default() -> Self {
+        Self {
+            name: None,
+            num_nonzero_steps: 0,
+            first_nonzero: None,
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a description of the constraint at the specified index for use in diagnostic messages;
+/// if the constraint has a name, the name is included in the description.
+pub(super) fn describe_constraint(index: usize, name: Option<&'static str>) -> String {
+    match name {
+        Some(name) => format!("{index} ({name})"),
+        None => format!("{index}"),
+    }
+}
@@ -5,9 +5,10 @@
 
 use alloc::vec::Vec;
 
-use math::fields::f128::BaseElement;
+use air::EvaluationFrame;
+use math::{fields::f128::BaseElement, FieldElement};
 
-use crate::{tests::build_fib_trace, Trace};
+use crate::{tests::build_fib_trace, PaddedTrace, Trace, TraceTable};
 
 #[test]
 fn new_trace_table() {
@@ -29,3 +30,184 @@ fn new_trace_table() {
         .collect();
     assert_eq!(expected, trace.get_column(1));
 }
+
+#[test]
+fn trace_get_column() {
+    fn read_column<T: Trace>(trace: &T, col_idx: usize) -> &[T::BaseField] {
+        trace.get_column(col_idx)
+    }
+
+    let trace = build_fib_trace(16);
+    for col_idx in 0..trace.main_trace_width() {
+        let expected = (0..trace.length()).map(|i| trace.get(col_idx, i)).collect::<Vec<_>>();
+        assert_eq!(expected, read_column(&trace, col_idx));
+    }
+}
+
+#[test]
+#[should_panic(expected = "column index must be smaller than 2, but was 2")]
+fn trace_get_column_out_of_bounds() {
+    let trace = build_fib_trace(16);
+    Trace::get_column(&trace, 2);
+}
+
+#[test]
+#[cfg(feature = "concurrent")]
+fn trace_table_rows_par_iter() {
+    use utils::iterators::*;
+
+    let trace = build_fib_trace(16);
+    let rows = trace.rows_par_iter().collect::<Vec<_>>();
+    assert_eq!(trace.length(), rows.len());
+    for (i, (step, row)) in rows.into_iter().enumerate() {
+        assert_eq!(i, step);
+        assert_eq!(vec![trace.get(0, step), trace.get(1, step)], row);
+    }
+}
+
+#[test]
+fn trace_table_interleave() {
+    let trace1 = build_fib_trace(16);
+    let trace2 = build_fib_trace(16);
+    let trace3 = TraceTable::init(vec![(0..8u32).map(BaseElement::from).collect()]);
+
+    let trace = TraceTable::interleave(&[&trace1, &trace3, &trace2]);
+    assert_eq!(5, trace.width());
+    assert_eq!(8, trace.length());
+    assert_eq!(trace1.get_column(0), trace.get_column(0));
+    assert_eq!(trace1.get_column(1), trace.get_column(1));
+    assert_eq!(trace3.get_column(0), trace.get_column(2));
+    assert_eq!(trace2.get_column(0), trace.get_column(3));
+    assert_eq!(trace2.get_column(1), trace.get_column(4));
+}
+
+#[test]
+#[should_panic(expected = "all traces must have the same length, but trace 1 has length 16")]
+fn trace_table_interleave_length_mismatch() {
+    let trace1 = build_fib_trace(16);
+    let trace2 = build_fib_trace(32);
+    TraceTable::interleave(&[&trace1, &trace2]);
+}
+
+#[test]
+fn trace_table_clone_with_extra_columns() {
+    let trace = build_fib_trace(16);
+    let extended = trace.clone_with_extra_columns(2, |step, row| {
+        vec![row[0] + row[1], BaseElement::from(step as u32)]
+    });
+
+    assert_eq!(4, extended.width());
+    assert_eq!(trace.length(), extended.length());
+    assert_eq!(trace.info().meta(), extended.info().meta());
+    assert_eq!(trace.get_column(0), extended.get_column(0));
+    assert_eq!(trace.get_column(1), extended.get_column(1));
+    for step in 0..trace.length() {
+        assert_eq!(trace.get(0, step) + trace.get(1, step), extended.get(2, step));
+        assert_eq!(BaseElement::from(step as u32), extended.get(3, step));
+    }
+
+    // constant columns are carried over
+    let trace = TraceTable::init(vec![
+        vec![BaseElement::ONE; 8],
+        (0..8u32).map(BaseElement::from).collect(),
+    ])
+    .with_constant_columns(&[0]);
+    let extended = trace.clone_with_extra_columns(1, |_, row| vec![row[1].double()]);
+    assert_eq!(1, extended.info().num_constant_columns());
+    assert_eq!(
+        vec![(0, BaseElement::ONE)],
+        extended.info().constant_columns::<BaseElement>().unwrap()
+    );
+}
+
+#[test]
+#[should_panic(expected = "expected 2 values for the extra columns at step 0, but received 1")]
+fn trace_table_clone_with_extra_columns_wrong_width() {
+    let trace = build_fib_trace(16);
+    trace.clone_with_extra_columns(2, |_, row| vec![row[0]]);
+}
+
+#[test]
+fn trace_table_from_rows() {
+    let rows = (0..5u32).map(|i| vec![BaseElement::from(i), BaseElement::from(i * i)]);
+    let trace: TraceTable<BaseElement> = rows.collect();
+
+    // the trace is padded to the minimum trace length by repeating the last row
+    assert_eq!(2, trace.width());
+    assert_eq!(8, trace.length());
+    let expected = [0u32, 1, 2, 3, 4, 4, 4, 4].map(BaseElement::from);
+    assert_eq!(expected, trace.get_column(0));
+    let expected = [0u32, 1, 4, 9, 16, 16, 16, 16].map(BaseElement::from);
+    assert_eq!(expected, trace.get_column(1));
+
+    // traces with a power of two length are not padded
+    let trace = build_fib_trace(32);
+    let rows = (0..trace.length()).map(|step| vec![trace.get(0, step), trace.get(1, step)]);
+    let collected: TraceTable<BaseElement> = rows.collect();
+    assert_eq!(trace.length(), collected.length());
+    assert_eq!(trace.get_column(0), collected.get_column(0));
+    assert_eq!(trace.get_column(1), collected.get_column(1));
+}
+
+#[test]
+#[should_panic(expected = "expected 2 values in the row at step 1, but received 1")]
+fn trace_table_from_rows_inconsistent_width() {
+    let rows = vec![vec![BaseElement::ONE; 2], vec![BaseElement::ONE]];
+    let _: TraceTable<BaseElement> = rows.into_iter().collect();
+}
+
+#[test]
+fn trace_table_with_aux_segment() {
+    use math::fields::f64::BaseElement as Felt;
+
+    let trace = TraceTable::new(2, 8).with_aux_segment(1, 2);
+    assert_eq!(2, trace.main_trace_width());
+    assert_eq!(1, trace.aux_trace_width());
+    assert_eq!(2, trace.info().get_num_aux_segment_rand_elements());
+    assert_eq!(8, trace.length());
+
+    // the main segment remains in the base field
+    let column: &[Felt] = trace.get_column(0);
+    assert_eq!(8, column.len());
+}
+
+#[test]
+fn padded_trace() {
+    // only the first 5 rows of the inner trace are computed
+    let mut column = (1..=5u32).map(BaseElement::from).collect::<Vec<_>>();
+    column.resize(8, BaseElement::ZERO);
+    let trace = PaddedTrace::new(TraceTable::init(vec![column.clone(), column]), 5);
+
+    // the rows past the real length repeat the last real row
+    assert_eq!(8, trace.length());
+    assert_eq!(2, trace.width());
+    assert_eq!(5, trace.real_length());
+    assert_eq!(4, trace.last_real_step());
+    assert_eq!(3, trace.num_padding_rows());
+    assert!(!trace.is_padding_row(4));
+    assert!(trace.is_padding_row(5));
+    assert_eq!(BaseElement::from(5u32), trace.get(1, 7));
+
+    // padding rows are read without building the padded main segment
+    let mut frame = EvaluationFrame::new(2);
+    trace.read_main_frame(4, &mut frame);
+    assert_eq!(frame.current(), frame.next());
+    trace.read_main_frame(7, &mut frame);
+    assert_eq!(&[BaseElement::from(5u32); 2], frame.current());
+    assert_eq!(&[BaseElement::ONE; 2], frame.next());
+
+    let expected = [1u32, 2, 3, 4, 5, 5, 5, 5].map(BaseElement::from).to_vec();
+    assert_eq!(expected, trace.get_column(0));
+    assert_eq!(expected, trace.get_column(1));
+
+    // traces without padding rows are unchanged
+    let trace = PaddedTrace::new(build_fib_trace(32), 16);
+    assert_eq!(0, trace.num_padding_rows());
+    assert_eq!(trace.inner().get_column(0), trace.get_column(0));
+}
+
+#[test]
+#[should_panic(expected = "real length of the trace cannot exceed 8, but was 9")]
+fn padded_trace_real_length_too_long() {
+    PaddedTrace::new(TraceTable::<BaseElement>::new(1, 8), 9);
+}
@@ -8,6 +8,7 @@ use core::marker::PhantomData;
 
 use air::{proof::Queries, LagrangeKernelEvaluationFrame, PartitionOptions, TraceInfo};
 use crypto::VectorCommitment;
+use math::StarkField;
 use tracing::info_span;
 
 use super::{
@@ -28,52 +29,98 @@ mod tests;
 ///   will always be elements in the base field (even when an extension field is used).
 /// - Auxiliary segments: a list of 0 or more segments for traces generated after the prover
 ///   commits to the first trace segment. Currently, at most 1 auxiliary segment is possible.
+///
+/// Constant columns of the main segment (as declared in the [TraceInfo]) are not extended and are
+/// not committed to. Instead, their values are inserted into the main trace frames whenever
+/// these frames are read from the trace LDE.
+///
+/// The main segment is committed to using hash function `H` and vector commitment scheme `V`.
+/// By default, the auxiliary segment is committed to in the same way, but a different hash
+/// function and vector commitment scheme can be specified for it via `AH` and `AV` type
+/// parameters (e.g., to use a fast hash function for one segment and an algebraic hash function
+/// for the other).
 pub struct DefaultTraceLde<
     E: FieldElement,
     H: ElementHasher<BaseField = E::BaseField>,
     V: VectorCommitment<H>,
+    AH: ElementHasher<BaseField = E::BaseField> = H,
+    AV: VectorCommitment<AH> = V,
 > {
     // low-degree extension of the main segment of the trace
     main_segment_lde: RowMatrix<E::BaseField>,
     // commitment to the main segment of the trace
     main_segment_oracles: V,
+    // indexes and values of constant columns of the main segment of the trace
+    constant_columns: Vec<(usize, E::BaseField)>,
     // low-degree extensions of the auxiliary segment of the trace
     aux_segment_lde: Option<RowMatrix<E>>,
     // commitment to the auxiliary segment of the trace
-    aux_segment_oracles: Option<V>,
+    aux_segment_oracles: Option<AV>,
     blowup: usize,
     trace_info: TraceInfo,
     partition_options: PartitionOptions,
-    _h: PhantomData<H>,
+    _h: PhantomData<(H, AH)>,
 }
 
-impl<E, H, V> DefaultTraceLde<E, H, V>
+impl<E, H, V, AH, AV> DefaultTraceLde<E, H, V, AH, AV>
 where
     E: FieldElement,
     H: ElementHasher<BaseField = E::BaseField>,
     V: VectorCommitment<H>,
+    AH: ElementHasher<BaseField = E::BaseField>,
+    AV: VectorCommitment<AH>,
 {
     /// Takes the main trace segment columns as input, interpolates them into polynomials in
     /// coefficient form, evaluates the polynomials over the LDE domain, commits to the
     /// polynomial evaluations, and creates a new [DefaultTraceLde] with the LDE of the main trace
     /// segment and the commitment.
     ///
+    /// Constant columns of the main trace segment are excluded from the LDE and from the
+    /// commitment; polynomials of these columns are still included in the returned
+    /// [TracePolyTable].
+    ///
     /// Returns a tuple containing a [TracePolyTable] with the trace polynomials for the main trace
     /// segment and the new [DefaultTraceLde].
+    ///
+    /// # Panics
+    /// Panics if values of constant columns in the `trace_info` are not valid field elements.
     pub fn new(
         trace_info: &TraceInfo,
         main_trace: &ColMatrix<E::BaseField>,
         domain: &StarkDomain<E::BaseField>,
         partition_options: PartitionOptions,
     ) -> (Self, TracePolyTable<E>) {
+        let constant_columns = trace_info
+            .constant_columns::<E::BaseField>()
+            .expect("failed to read constant columns from trace info");
+
         // extend the main execution trace and build a commitment to the extended trace
-        let (main_segment_lde, main_segment_vector_com, main_segment_polys) =
-            build_trace_commitment::<E, E::BaseField, H, V>(main_trace, domain, partition_options);
+        let (main_segment_lde, main_segment_vector_com, main_segment_polys) = if constant_columns
+            .is_empty()
+        {
+            build_trace_commitment::<E, E::BaseField, H, V>(main_trace, domain, partition_options)
+        } else {
+            let committed_trace = ColMatrix::new(
+                main_trace
+                    .columns()
+                    .enumerate()
+                    .filter(|(i, _)| constant_columns.iter().all(|(column, _)| column != i))
+                    .map(|(_, column)| column.to_vec())
+                    .collect(),
+            );
+            let (lde, vector_com, polys) = build_trace_commitment::<E, E::BaseField, H, V>(
+                &committed_trace,
+                domain,
+                partition_options,
+            );
+            (lde, vector_com, insert_constant_polys(polys, &constant_columns))
+        };
 
         let trace_poly_table = TracePolyTable::new(main_segment_polys);
         let trace_lde = DefaultTraceLde {
             main_segment_lde,
             main_segment_oracles: main_segment_vector_com,
+            constant_columns,
             aux_segment_lde: None,
             aux_segment_oracles: None,
             blowup: domain.trace_to_lde_blowup(),
@@ -109,14 +156,41 @@ where
     }
 }
 
-impl<E, H, V> TraceLde<E> for DefaultTraceLde<E, H, V>
+impl<E, H, V, AH, AV> Clone for DefaultTraceLde<E, H, V, AH, AV>
+where
+    E: FieldElement,
+    H: ElementHasher<BaseField = E::BaseField>,
+    V: VectorCommitment<H> + Clone,
+    AH: ElementHasher<BaseField = E::BaseField>,
+    AV: VectorCommitment<AH> + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            main_segment_lde: self.main_segment_lde.clone(),
+            main_segment_oracles: self.main_segment_oracles.clone(),
+            constant_columns: self.constant_columns.clone(),
+            aux_segment_lde: self.aux_segment_lde.clone(),
+            aux_segment_oracles: self.aux_segment_oracles.clone(),
+            blowup: self.blowup,
+            trace_info: self.trace_info.clone(),
+            partition_options: self.partition_options,
+            _h: PhantomData,
+        }
+    }
+}
+
+impl<E, H, V, AH, AV> TraceLde<E> for DefaultTraceLde<E, H, V, AH, AV>
 where
     E: FieldElement,
     H: ElementHasher<BaseField = E::BaseField> + core::marker::Sync,
     V: VectorCommitment<H> + core::marker::Sync,
+    AH: ElementHasher<BaseField = E::BaseField, Digest: Into<H::Digest>> + core::marker::Sync,
+    AV: VectorCommitment<AH> + core::marker::Sync,
 {
     type HashFn = H;
     type VC = V;
+    type AuxHashFn = AH;
+    type AuxVC = AV;
 
     /// Returns the commitment to the low-degree extension of the main trace segment.
     fn get_main_trace_commitment(&self) -> H::Digest {
@@ -141,10 +215,10 @@ where
         &mut self,
         aux_trace: &ColMatrix<E>,
         domain: &StarkDomain<E::BaseField>,
-    ) -> (ColMatrix<E>, H::Digest) {
+    ) -> (ColMatrix<E>, AH::Digest) {
         // extend the auxiliary trace segment and build a commitment to the extended trace
         let (aux_segment_lde, aux_segment_oracles, aux_segment_polys) =
-            build_trace_commitment::<E, E, H, Self::VC>(aux_trace, domain, self.partition_options);
+            build_trace_commitment::<E, E, AH, AV>(aux_trace, domain, self.partition_options);
 
         // check errors
         assert!(
@@ -175,8 +249,15 @@ where
         let next_lde_step = (lde_step + self.blowup()) % self.trace_len();
 
         // copy main trace segment values into the frame
-        frame.current_mut().copy_from_slice(self.main_segment_lde.row(lde_step));
-        frame.next_mut().copy_from_slice(self.main_segment_lde.row(next_lde_step));
+        if self.constant_columns.is_empty() {
+            frame.current_mut().copy_from_slice(self.main_segment_lde.row(lde_step));
+            frame.next_mut().copy_from_slice(self.main_segment_lde.row(next_lde_step));
+        } else {
+            let current = self.main_segment_lde.row(lde_step);
+            let next = self.main_segment_lde.row(next_lde_step);
+            read_row_with_constants(current, &self.constant_columns, frame.current_mut());
+            read_row_with_constants(next, &self.constant_columns, frame.next_mut());
+        }
     }
 
     /// Reads current and next rows from the auxiliary trace segment into the specified frame.
@@ -219,6 +300,8 @@ where
 
     /// Returns trace table rows at the specified positions along with an opening proof to these
     /// rows againt the already computed commitment.
+    ///
+    /// Values of constant columns of the main trace segment are not included in the returned rows.
     fn query(&self, positions: &[usize]) -> Vec<Queries> {
         // build queries for the main trace segment
         let mut result = vec![build_segment_queries::<E::BaseField, H, V>(
@@ -231,7 +314,11 @@ where
         if let Some(ref segment_oracles) = self.aux_segment_oracles {
             let segment_lde =
                 self.aux_segment_lde.as_ref().expect("expected aux segment to be present");
-            result.push(build_segment_queries::<E, H, V>(segment_lde, segment_oracles, positions));
+            result.push(build_segment_queries::<E, AH, AV>(
+                segment_lde,
+                segment_oracles,
+                positions,
+            ));
         }
 
         result
@@ -304,6 +391,39 @@ where
     (trace_lde, trace_vector_com, trace_polys)
 }
 
+/// Returns a table of column polynomials in which polynomials of the specified constant columns
+/// are inserted at their respective indexes into the provided polynomials of non-constant columns.
+fn insert_constant_polys<B: StarkField>(
+    polys: ColMatrix<B>,
+    constant_columns: &[(usize, B)],
+) -> ColMatrix<B> {
+    let poly_size = polys.num_rows();
+    let mut columns = polys.into_columns();
+    for &(column, value) in constant_columns {
+        let mut poly = vec![B::ZERO; poly_size];
+        poly[0] = value;
+        columns.insert(column, poly);
+    }
+    ColMatrix::new(columns)
+}
+
+/// Copies the provided row of the committed main trace segment into `target` inserting values of
+/// the specified constant columns at their respective indexes.
+fn read_row_with_constants<B: StarkField>(
+    row: &[B],
+    constant_columns: &[(usize, B)],
+    target: &mut [B],
+) {
+    let mut values = row.iter();
+    let mut constants = constant_columns.iter().peekable();
+    for (i, target) in target.iter_mut().enumerate() {
+        *target = match constants.next_if(|(column, _)| *column == i) {
+            Some((_, value)) => *value,
+            None => *values.next().expect("not enough values in trace row"),
+        };
+    }
+}
+
 fn build_segment_queries<E, H, V>(
     segment_lde: &RowMatrix<E>,
     segment_vector_com: &V,
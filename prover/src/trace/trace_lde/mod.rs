@@ -23,13 +23,266 @@ pub use default::DefaultTraceLde;
 ///   will always be elements in the base field (even when an extension field is used).
 /// - Auxiliary segments: a list of 0 or more segments for traces generated after the prover
 ///   commits to the first trace segment. Currently, at most 1 auxiliary segment is possible.
+///
+/// # Custom implementations
+/// [DefaultTraceLde] is the implementation of this trait provided with the prover, and it should
+/// be used unless there is a specific need to change how the trace is extended or committed to
+/// (e.g., to delegate FFTs or hashing to a different backend, or to reuse precomputed data). An
+/// instance of the trace LDE is created by [Prover::new_trace_lde()](crate::Prover::new_trace_lde)
+/// method, and thus, using a custom implementation requires setting
+/// [Prover::TraceLde](crate::Prover::TraceLde) associated type and implementing this method
+/// accordingly.
+///
+/// A custom implementation can use any algorithms internally, but the results it produces must
+/// be the same as the ones produced by [DefaultTraceLde]; otherwise, the verifier will reject
+/// the proof. Specifically:
+/// - The LDE of every trace segment must consist of evaluations of the trace polynomials over
+///   the LDE domain described by the [StarkDomain] passed to the constructor. That is, over
+///   the coset of size `domain.lde_domain_size()` shifted by `domain.offset()`, in the natural
+///   order of the domain elements.
+/// - Commitments must be built by hashing the rows of the extended segments and committing to the
+///   resulting digests, taking into account the specified
+///   [PartitionOptions](air::PartitionOptions). The main segment is committed to using
+///   [TraceLde::HashFn] and [TraceLde::VC], and the auxiliary segment is committed to using
+///   [TraceLde::AuxHashFn] and [TraceLde::AuxVC].
+/// - The trace polynomials returned alongside the trace LDE must be in coefficient form.
+///
+/// # Examples
+/// The following example defines a trace LDE which wraps [DefaultTraceLde] and can be used as a
+/// starting point for a custom implementation. The prover built for it differs from a prover
+/// using [DefaultTraceLde] directly only in the definitions of the `TraceLde` associated type
+/// and of the `new_trace_lde()` method.
+/// ```
+/// # use winter_prover::{
+/// #     crypto::{hashers::Blake3_256, DefaultRandomCoin, ElementHasher, Hasher, MerkleTree,
+/// #         VectorCommitment},
+/// #     math::{fields::f64::BaseElement, FieldElement},
+/// #     matrix::ColMatrix,
+/// #     proof::Queries,
+/// #     Air, AirContext, Assertion, AuxRandElements, CompositionPoly, CompositionPolyTrace,
+/// #     ConstraintCompositionCoefficients, DefaultConstraintCommitment,
+/// #     DefaultConstraintEvaluator, DefaultTraceLde, EvaluationFrame, FieldExtension,
+/// #     LagrangeKernelEvaluationFrame, PartitionOptions, ProofOptions, Prover, StarkDomain,
+/// #     Trace, TraceInfo, TraceLde, TracePolyTable, TraceTable, TransitionConstraintDegree,
+/// # };
+/// #
+/// /// A trace LDE which delegates all work to the default implementation.
+/// struct MyTraceLde<E, H, V>
+/// where
+///     E: FieldElement,
+///     H: ElementHasher<BaseField = E::BaseField>,
+///     V: VectorCommitment<H>,
+/// {
+///     inner: DefaultTraceLde<E, H, V>,
+/// }
+///
+/// impl<E, H, V> MyTraceLde<E, H, V>
+/// where
+///     E: FieldElement,
+///     H: ElementHasher<BaseField = E::BaseField> + Sync,
+///     V: VectorCommitment<H> + Sync,
+/// {
+///     /// Extends the main trace segment over the LDE domain and commits to it; this is where
+///     /// a custom implementation would use its own domain data or computation backend.
+///     fn new(
+///         trace_info: &TraceInfo,
+///         main_trace: &ColMatrix<E::BaseField>,
+///         domain: &StarkDomain<E::BaseField>,
+///         partition_options: PartitionOptions,
+///     ) -> (Self, TracePolyTable<E>) {
+///         let (inner, trace_polys) =
+///             DefaultTraceLde::new(trace_info, main_trace, domain, partition_options);
+///         (Self { inner }, trace_polys)
+///     }
+/// }
+///
+/// impl<E, H, V> TraceLde<E> for MyTraceLde<E, H, V>
+/// where
+///     E: FieldElement,
+///     H: ElementHasher<BaseField = E::BaseField> + Sync,
+///     V: VectorCommitment<H> + Sync,
+/// {
+///     type HashFn = H;
+///     type VC = V;
+///     type AuxHashFn = H;
+///     type AuxVC = V;
+///
+///     fn get_main_trace_commitment(&self) -> H::Digest {
+///         self.inner.get_main_trace_commitment()
+///     }
+///
+///     fn set_aux_trace(
+///         &mut self,
+///         aux_trace: &ColMatrix<E>,
+///         domain: &StarkDomain<E::BaseField>,
+///     ) -> (ColMatrix<E>, H::Digest) {
+///         self.inner.set_aux_trace(aux_trace, domain)
+///     }
+///
+///     fn read_main_trace_frame_into(
+///         &self,
+///         lde_step: usize,
+///         frame: &mut EvaluationFrame<E::BaseField>,
+///     ) {
+///         self.inner.read_main_trace_frame_into(lde_step, frame)
+///     }
+///
+///     fn read_aux_trace_frame_into(&self, lde_step: usize, frame: &mut EvaluationFrame<E>) {
+///         self.inner.read_aux_trace_frame_into(lde_step, frame)
+///     }
+///
+///     fn read_lagrange_kernel_frame_into(
+///         &self,
+///         lde_step: usize,
+///         col_idx: usize,
+///         frame: &mut LagrangeKernelEvaluationFrame<E>,
+///     ) {
+///         self.inner.read_lagrange_kernel_frame_into(lde_step, col_idx, frame)
+///     }
+///
+///     fn query(&self, positions: &[usize]) -> Vec<Queries> {
+///         self.inner.query(positions)
+///     }
+///
+///     fn trace_len(&self) -> usize {
+///         self.inner.trace_len()
+///     }
+///
+///     fn blowup(&self) -> usize {
+///         self.inner.blowup()
+///     }
+///
+///     fn trace_info(&self) -> &TraceInfo {
+///         self.inner.trace_info()
+///     }
+/// }
+/// #
+/// # struct CounterAir {
+/// #     context: AirContext<BaseElement>,
+/// # }
+/// #
+/// # impl Air for CounterAir {
+/// #     type BaseField = BaseElement;
+/// #     type GkrProof = ();
+/// #     type GkrVerifier = ();
+/// #     type PublicInputs = ();
+/// #
+/// #     fn new(trace_info: TraceInfo, _pub_inputs: (), options: ProofOptions) -> Self {
+/// #         let degrees = vec![TransitionConstraintDegree::new(1)];
+/// #         Self { context: AirContext::new(trace_info, degrees, 1, options) }
+/// #     }
+/// #
+/// #     fn context(&self) -> &AirContext<Self::BaseField> {
+/// #         &self.context
+/// #     }
+/// #
+/// #     fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+/// #         &self,
+/// #         frame: &EvaluationFrame<E>,
+/// #         _periodic_values: &[E],
+/// #         result: &mut [E],
+/// #     ) {
+/// #         result[0] = frame.next()[0] - frame.current()[0] - E::ONE;
+/// #     }
+/// #
+/// #     fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+/// #         vec![Assertion::single(0, 0, BaseElement::ZERO)]
+/// #     }
+/// # }
+/// #
+/// # struct CounterProver {
+/// #     options: ProofOptions,
+/// # }
+///
+/// impl Prover for CounterProver {
+///     type TraceLde<E: FieldElement<BaseField = BaseElement>> =
+///         MyTraceLde<E, Self::HashFn, Self::VC>;
+/// #   type BaseField = BaseElement;
+/// #   type Air = CounterAir;
+/// #   type Trace = TraceTable<BaseElement>;
+/// #   type HashFn = Blake3_256<BaseElement>;
+/// #   type VC = MerkleTree<Self::HashFn>;
+/// #   type RandomCoin = DefaultRandomCoin<Self::HashFn>;
+/// #   type ConstraintCommitment<E: FieldElement<BaseField = BaseElement>> =
+/// #       DefaultConstraintCommitment<E, Self::HashFn, Self::VC>;
+/// #   type ConstraintEvaluator<'a, E: FieldElement<BaseField = BaseElement>> =
+/// #       DefaultConstraintEvaluator<'a, CounterAir, E>;
+///
+///     fn new_trace_lde<E: FieldElement<BaseField = BaseElement>>(
+///         &self,
+///         trace_info: &TraceInfo,
+///         main_trace: &ColMatrix<BaseElement>,
+///         domain: &StarkDomain<BaseElement>,
+///         partition_options: PartitionOptions,
+///     ) -> (Self::TraceLde<E>, TracePolyTable<E>) {
+///         MyTraceLde::new(trace_info, main_trace, domain, partition_options)
+///     }
+///
+///     // other methods are the same as for a prover using the default trace LDE
+/// #   fn get_pub_inputs(&self, _trace: &Self::Trace) {}
+/// #
+/// #   fn options(&self) -> &ProofOptions {
+/// #       &self.options
+/// #   }
+/// #
+/// #   fn build_constraint_commitment<E: FieldElement<BaseField = BaseElement>>(
+/// #       &self,
+/// #       composition_poly_trace: CompositionPolyTrace<E>,
+/// #       num_constraint_composition_columns: usize,
+/// #       domain: &StarkDomain<BaseElement>,
+/// #       partition_options: PartitionOptions,
+/// #   ) -> (Self::ConstraintCommitment<E>, CompositionPoly<E>) {
+/// #       DefaultConstraintCommitment::new(
+/// #           composition_poly_trace,
+/// #           num_constraint_composition_columns,
+/// #           domain,
+/// #           partition_options,
+/// #       )
+/// #   }
+/// #
+/// #   fn new_evaluator<'a, E: FieldElement<BaseField = BaseElement>>(
+/// #       &self,
+/// #       air: &'a CounterAir,
+/// #       aux_rand_elements: Option<AuxRandElements<E>>,
+/// #       composition_coefficients: ConstraintCompositionCoefficients<E>,
+/// #   ) -> Self::ConstraintEvaluator<'a, E> {
+/// #       DefaultConstraintEvaluator::new(air, aux_rand_elements, composition_coefficients)
+/// #   }
+/// }
+///
+/// # let mut trace = TraceTable::new(1, 16);
+/// # trace.fill(|state| state[0] = BaseElement::ZERO, |_, state| state[0] += BaseElement::ONE);
+/// # let options = ProofOptions::new(28, 8, 0, FieldExtension::Quadratic, 4, 7);
+/// // proofs generated with the custom trace LDE are the same as with the default one
+/// let prover = CounterProver { options };
+/// let proof = prover.prove(trace).unwrap();
+/// # let _ = proof;
+/// ```
 pub trait TraceLde<E: FieldElement>: Sync {
     /// The hash function used for hashing the rows of trace segment LDEs.
     type HashFn: ElementHasher<BaseField = E::BaseField>;
 
-    /// The vector commitment scheme used for commiting to the trace.
+    /// The vector commitment scheme used for commiting to the main trace segment.
     type VC: VectorCommitment<Self::HashFn>;
 
+    /// The hash function used for hashing the rows of the auxiliary trace segment LDE.
+    ///
+    /// This can be different from [TraceLde::HashFn]; the commitment to the auxiliary segment is
+    /// still absorbed into the public coin (which is defined over [TraceLde::HashFn]), and thus,
+    /// the digests of this hash function must be convertible into the digests of the main one.
+    ///
+    /// This type (together with [TraceLde::AuxVC]) has no default, and thus, custom
+    /// implementations of this trait must specify it; implementations which do not need a
+    /// separate auxiliary segment hash function should set it to [TraceLde::HashFn] and set
+    /// [TraceLde::AuxVC] to [TraceLde::VC].
+    type AuxHashFn: ElementHasher<
+        BaseField = E::BaseField,
+        Digest: Into<<Self::HashFn as Hasher>::Digest>,
+    >;
+
+    /// The vector commitment scheme used for commiting to the auxiliary trace segment.
+    type AuxVC: VectorCommitment<Self::AuxHashFn>;
+
     /// Returns the commitment to the low-degree extension of the main trace segment.
     fn get_main_trace_commitment(&self) -> <Self::HashFn as Hasher>::Digest;
 
@@ -49,7 +302,7 @@ pub trait TraceLde<E: FieldElement>: Sync {
         &mut self,
         aux_trace: &ColMatrix<E>,
         domain: &StarkDomain<E::BaseField>,
-    ) -> (ColMatrix<E>, <Self::HashFn as Hasher>::Digest);
+    ) -> (ColMatrix<E>, <Self::AuxHashFn as Hasher>::Digest);
 
     /// Reads current and next rows from the main trace segment into the specified frame.
     fn read_main_trace_frame_into(
@@ -43,6 +43,10 @@ const MIN_FRAGMENT_LENGTH: usize = 2;
 /// 2. The second closure receives the previous state of the execution trace as input, and must
 ///    update it to the next state of the computation.
 ///
+/// A trace table can also be collected from an iterator over the rows of the execution trace;
+/// in this case, the trace is padded to a power of two length by repeating its last row (see the
+/// [FromIterator] implementation for details).
+///
 /// You can also use [TraceTable::with_meta()] function to create a blank execution trace.
 /// This function work just like [TraceTable::new()] function, but also takes a metadata
 /// parameter which can be an arbitrary sequence of bytes up to 64KB in size.
@@ -58,6 +62,15 @@ const MIN_FRAGMENT_LENGTH: usize = 2;
 /// [fill()](TraceTableFragment::fill) method to fill all fragments with data in parallel.
 /// The semantics of the fragment's [TraceTableFragment::fill()] method are identical to the
 /// semantics of the [TraceTable::fill()] method.
+///
+/// # Auxiliary trace segment
+/// A trace table holds values of the main trace segment only, and these values are always in the
+/// base field `B`. An auxiliary segment (e.g., for a permutation or a lookup argument) can be
+/// declared via [TraceTable::with_aux_segment()]; its values are then built by
+/// [Prover::build_aux_trace()](crate::Prover::build_aux_trace) in the field in which the proof is
+/// generated. Thus, the common setup of a main segment in a small field (e.g., the 64-bit field)
+/// with an auxiliary segment in its quadratic extension requires only setting field extension in
+/// proof options to [FieldExtension::Quadratic](air::FieldExtension::Quadratic).
 #[derive(Debug, Clone)]
 pub struct TraceTable<B: StarkField> {
     info: TraceInfo,
@@ -75,7 +88,7 @@ impl<B: StarkField> TraceTable<B> {
     ///
     /// # Panics
     /// Panics if:
-    /// * `width` is zero or greater than 255.
+    /// * `width` is zero or greater than 65536.
     /// * `length` is smaller than 8, greater than biggest multiplicative subgroup in the field
     ///   `B`, or is not a power of two.
     pub fn new(width: usize, length: usize) -> Self {
@@ -90,7 +103,7 @@ impl<B: StarkField> TraceTable<B> {
     ///
     /// # Panics
     /// Panics if:
-    /// * `width` is zero or greater than 255.
+    /// * `width` is zero or greater than 65536.
     /// * `length` is smaller than 8, greater than the biggest multiplicative subgroup in the
     ///   field `B`, or is not a power of two.
     /// * Length of `meta` is greater than 65535;
@@ -112,7 +125,7 @@ impl<B: StarkField> TraceTable<B> {
     ///
     /// # Panics
     /// Panics if:
-    /// * The `columns` vector is empty or has over 255 columns.
+    /// * The `columns` vector is empty or has over 65536 columns.
     /// * Number of elements in any of the columns is smaller than 8, greater than the biggest
     ///   multiplicative subgroup in the field `B`, or is not a power of two.
     /// * Number of elements is not identical for all columns.
@@ -136,6 +149,137 @@ impl<B: StarkField> TraceTable<B> {
         Self { info, trace: ColMatrix::new(columns) }
     }
 
+    /// Creates a new execution trace by combining columns of the provided execution traces.
+    ///
+    /// The columns of the resulting trace are ordered as follows: first come all columns of
+    /// `traces[0]` (in their original order), followed by all columns of `traces[1]`, and so on.
+    /// Thus, column `j` of `traces[i]` ends up at index `j + sum(traces[k].width() for k < i)`
+    /// of the resulting trace. This allows committing to several logically distinct traces under
+    /// a single commitment.
+    ///
+    /// Metadata of the provided traces is not carried over into the resulting trace.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * The `traces` slice is empty.
+    /// * Lengths of the provided traces are not identical.
+    /// * The combined width of the provided traces is greater than 65536.
+    pub fn interleave(traces: &[&TraceTable<B>]) -> Self {
+        assert!(!traces.is_empty(), "at least one trace must be provided");
+
+        let trace_length = traces[0].length();
+        let mut columns = Vec::with_capacity(traces.iter().map(|trace| trace.width()).sum());
+        for (i, trace) in traces.iter().enumerate() {
+            assert_eq!(
+                trace.length(),
+                trace_length,
+                "all traces must have the same length, but trace {i} has length {}",
+                trace.length()
+            );
+            columns.extend((0..trace.width()).map(|col_idx| trace.get_column(col_idx).to_vec()));
+        }
+
+        Self::init(columns)
+    }
+
+    /// Returns a copy of this execution trace extended with `extra` columns.
+    ///
+    /// The new columns are appended after the existing columns of the trace, and their values are
+    /// computed by the `fill` closure. The closure is invoked once for every row of the trace; it
+    /// receives the index of the row and the values of the existing columns in this row, and must
+    /// return the values of the new columns for this row. This is convenient for extending a
+    /// trace with columns derived from the existing ones without rebuilding the trace.
+    ///
+    /// Metadata and constant columns of this trace are carried over into the resulting trace.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * The `fill` closure returns a number of values different from `extra` for some row.
+    /// * The width of the resulting trace is greater than 65536.
+    pub fn clone_with_extra_columns<F>(&self, extra: usize, fill: F) -> Self
+    where
+        F: Fn(usize, &[B]) -> Vec<B>,
+    {
+        let width = self.width();
+        let length = self.length();
+
+        let mut columns = Vec::with_capacity(width + extra);
+        columns.extend((0..width).map(|col_idx| self.get_column(col_idx).to_vec()));
+        columns.resize_with(width + extra, || Vec::with_capacity(length));
+
+        let mut row = vec![B::ZERO; width];
+        for step in 0..length {
+            self.read_row_into(step, &mut row);
+            let values = fill(step, &row);
+            assert_eq!(
+                values.len(),
+                extra,
+                "expected {extra} values for the extra columns at step {step}, but received {}",
+                values.len()
+            );
+            for (column, value) in columns[width..].iter_mut().zip(values) {
+                column.push(value);
+            }
+        }
+
+        let mut info = TraceInfo::with_meta(width + extra, length, self.info.meta().to_vec());
+        if self.info.num_constant_columns() > 0 {
+            let constants = self
+                .info
+                .constant_columns::<B>()
+                .expect("constant columns of the trace must be valid");
+            info = info.with_constant_columns(&constants);
+        }
+
+        Self { info, trace: ColMatrix::new(columns) }
+    }
+
+    /// Returns this execution trace with the specified columns declared as constant.
+    ///
+    /// The value of each constant column is read from the first row of the trace and is recorded
+    /// in the [TraceInfo] of the trace. Constant columns are not committed to by the prover, and
+    /// thus, this should be called only after the trace has been filled. See
+    /// [TraceInfo::with_constant_columns()] for details.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * Column indexes are not sorted in strictly increasing order or are out of bounds for this
+    ///   execution trace.
+    /// * Any of the specified columns contains different values in different rows.
+    /// * All columns of this execution trace are declared as constant.
+    pub fn with_constant_columns(mut self, columns: &[usize]) -> Self {
+        let constants = columns
+            .iter()
+            .map(|&col_idx| {
+                let column = self.get_column(col_idx);
+                assert!(
+                    column.iter().all(|&value| value == column[0]),
+                    "column {col_idx} is declared as constant but contains different values"
+                );
+                (col_idx, column[0])
+            })
+            .collect::<Vec<_>>();
+
+        self.info = self.info.with_constant_columns(&constants);
+        self
+    }
+
+    /// Returns this execution trace with an auxiliary segment of the specified width which
+    /// requires the specified number of random elements.
+    ///
+    /// The trace table holds only the main trace segment; values of the auxiliary segment are
+    /// built via [Prover::build_aux_trace()](crate::Prover::build_aux_trace) after the main
+    /// segment has been committed to, and the random elements have been drawn. See
+    /// [TraceInfo::with_aux_segment()] for details.
+    ///
+    /// # Panics
+    /// Panics if this execution trace already has an auxiliary segment, or if the auxiliary
+    /// segment is not valid for the trace as described in [TraceInfo::new_multi_segment()].
+    pub fn with_aux_segment(mut self, aux_width: usize, num_aux_rands: usize) -> Self {
+        self.info = self.info.with_aux_segment(aux_width, num_aux_rands);
+        self
+    }
+
     // DATA MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -193,6 +337,7 @@ impl<B: StarkField> TraceTable<B> {
     /// Panics if `fragment_length` is smaller than 2, greater than the length of the trace,
     /// or is not a power of two.
     #[cfg(not(feature = "concurrent"))]
+    #[allow(mismatched_lifetime_syntaxes)]
     pub fn fragments(
         &mut self,
         fragment_length: usize,
@@ -209,6 +354,7 @@ impl<B: StarkField> TraceTable<B> {
     /// Panics if `fragment_length` is smaller than 2, greater than the length of the trace,
     /// or is not a power of two.
     #[cfg(feature = "concurrent")]
+    #[allow(mismatched_lifetime_syntaxes)]
     pub fn fragments(
         &mut self,
         fragment_length: usize,
@@ -218,6 +364,7 @@ impl<B: StarkField> TraceTable<B> {
 
     /// Returns a vector of trace fragments each covering the number of steps specified by the
     /// `fragment_length` parameter.
+    #[allow(mismatched_lifetime_syntaxes)]
     fn build_fragments(&mut self, fragment_length: usize) -> Vec<TraceTableFragment<B>> {
         assert!(
             fragment_length >= MIN_FRAGMENT_LENGTH,
@@ -259,6 +406,9 @@ impl<B: StarkField> TraceTable<B> {
     }
 
     /// Returns the entire trace column at the specified index.
+    ///
+    /// # Panics
+    /// Panics if `col_idx` is greater than or equal to the width of this execution trace.
     pub fn get_column(&self, col_idx: usize) -> &[B] {
         self.trace.get_column(col_idx)
     }
@@ -272,6 +422,72 @@ impl<B: StarkField> TraceTable<B> {
     pub fn read_row_into(&self, step: usize, target: &mut [B]) {
         self.trace.read_row_into(step, target);
     }
+
+    /// Returns a parallel iterator over all rows of this execution trace.
+    ///
+    /// Each item of the iterator is a tuple consisting of a step and the row of the trace at this
+    /// step. Since the trace is stored in column-major order, rows are not contiguous in memory:
+    /// every row is gathered by reading a single value from each column into a newly allocated
+    /// vector. Thus, iterating over all rows requires `width * length` non-sequential reads and
+    /// one allocation per row; computations which can be expressed in terms of columns should
+    /// use [TraceTable::get_column()] instead.
+    #[cfg(feature = "concurrent")]
+    pub fn rows_par_iter(&self) -> impl IndexedParallelIterator<Item = (usize, Vec<B>)> + '_ {
+        (0..self.info.length()).into_par_iter().map(|step| {
+            let mut row = vec![B::ZERO; self.width()];
+            self.read_row_into(step, &mut row);
+            (step, row)
+        })
+    }
+}
+
+// COLLECTING FROM ROWS
+// ================================================================================================
+
+/// Collects an execution trace from an iterator over its rows.
+///
+/// Width of the trace is inferred from the first row. If the number of rows is not a power of
+/// two (or is smaller than 8), the trace is padded to the next power of two (but to at least 8
+/// rows) by repeating the last row. Thus, the AIR of the computation must be satisfied by the
+/// repeated rows.
+///
+/// # Panics
+/// Panics if:
+/// * The iterator is empty, or the first row is empty or has over 65536 values.
+/// * Not all rows have the same number of values.
+/// * The padded length of the trace is greater than the biggest multiplicative subgroup in the
+///   field `B`.
+impl<B: StarkField> FromIterator<Vec<B>> for TraceTable<B> {
+    fn from_iter<I: IntoIterator<Item = Vec<B>>>(iter: I) -> Self {
+        let mut rows = iter.into_iter();
+        let first_row = rows.next().expect("execution trace must consist of at least one row");
+        assert!(!first_row.is_empty(), "execution trace must consist of at least one column");
+
+        let mut columns = first_row.into_iter().map(|value| vec![value]).collect::<Vec<_>>();
+        for (step, row) in rows.enumerate() {
+            assert_eq!(
+                row.len(),
+                columns.len(),
+                "expected {} values in the row at step {}, but received {}",
+                columns.len(),
+                step + 1,
+                row.len()
+            );
+            for (column, value) in columns.iter_mut().zip(row) {
+                column.push(value);
+            }
+        }
+
+        // pad each column by repeating its last value
+        let real_length = columns[0].len();
+        let trace_length = real_length.next_power_of_two().max(TraceInfo::MIN_TRACE_LENGTH);
+        for column in columns.iter_mut() {
+            let last_value = column[real_length - 1];
+            column.resize(trace_length, last_value);
+        }
+
+        Self::init(columns)
+    }
 }
 
 // TRACE TRAIT IMPLEMENTATION
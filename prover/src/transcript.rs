@@ -0,0 +1,60 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+
+use air::{AuxRandElements, ConstraintCompositionCoefficients, DeepCompositionCoefficients};
+use math::FieldElement;
+
+// PROVER TRANSCRIPT
+// ================================================================================================
+
+/// A record of all values drawn from the public coin during generation of a STARK proof.
+///
+/// The fields of this struct are listed in the order in which the values are drawn by the prover
+/// (and thus, by the verifier). Between these draws, the public coin is reseeded with the values
+/// sent to the verifier as follows:
+///
+/// 1. After the commitment to the main trace segment, random elements for building the auxiliary
+///    trace segment are drawn (if the trace has an auxiliary segment). If the AIR relies on a GKR
+///    proof, randomness drawn while generating the GKR proof is included in these elements as
+///    well.
+/// 2. After the commitment to the auxiliary trace segment (if any), constraint composition
+///    coefficients are drawn.
+/// 3. After the commitment to the constraint composition polynomial, the out-of-domain point is
+///    drawn.
+/// 4. After the out-of-domain evaluations of trace and constraint composition polynomials are
+///    sent, DEEP composition coefficients are drawn.
+/// 5. After the commitment to each FRI layer, a folding challenge for the layer is drawn.
+/// 6. After the last FRI layer (remainder) is sent, the proof-of-work nonce is determined and
+///    query positions are drawn using this nonce.
+///
+/// All field elements are elements of the field in which the protocol was executed (i.e., the
+/// extension field specified in the proof options).
+///
+/// A transcript can be obtained via [Prover::prove_with_transcript()](crate::Prover::prove_with_transcript).
+#[derive(Debug, Clone)]
+pub struct ProverTranscript<E: FieldElement> {
+    /// Random elements used to build the auxiliary trace segment, or None if the trace does not
+    /// have an auxiliary segment.
+    pub aux_rand_elements: Option<AuxRandElements<E>>,
+    /// Coefficients used to compute a random linear combination of all constraints.
+    pub constraint_composition_coeffs: ConstraintCompositionCoefficients<E>,
+    /// The out-of-domain point at which trace and constraint composition polynomials are
+    /// evaluated.
+    pub ood_point: E,
+    /// Coefficients used to build the DEEP composition polynomial.
+    pub deep_composition_coeffs: DeepCompositionCoefficients<E>,
+    /// Folding challenges for all FRI layers, starting with the first layer.
+    pub fri_alphas: Vec<E>,
+    /// The proof-of-work nonce used to draw query positions.
+    pub pow_nonce: u64,
+    /// Query positions drawn from the public coin, in the order in which they were drawn (i.e.,
+    /// with possible duplicates).
+    ///
+    /// When query positions are supplied externally (via `external-queries` feature), no positions
+    /// are drawn and this vector is empty.
+    pub query_positions: Vec<usize>,
+}
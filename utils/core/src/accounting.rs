@@ -0,0 +1,87 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! Counters of operations performed by the current thread.
+//!
+//! The counters are incremented by hash functions, field arithmetic, and vector commitment
+//! verification in other Winterfell crates, and can be used to meter the amount of work
+//! performed by a given computation (e.g., verification of a STARK proof).
+//!
+//! The counters are maintained only when the crate is compiled with `accounting` feature enabled;
+//! otherwise, all `record_*()` functions compile to no-ops.
+
+#[cfg(feature = "accounting")]
+use core::cell::Cell;
+
+// OPERATION COUNTS
+// ================================================================================================
+
+/// Number of operations of each type performed by the current thread.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OperationCounts {
+    /// Number of invocations of hash functions (i.e., of `hash`, `merge`, `merge_many`,
+    /// `merge_with_int`, and `hash_elements` methods of the provided hashers).
+    pub num_hashes: u64,
+    /// Number of multiplications in the base field. Multiplications in extension fields, as well
+    /// as multiplications performed by algebraic hash functions, are counted as the base field
+    /// multiplications they are composed of.
+    pub num_field_muls: u64,
+    /// Number of verified Merkle paths; a batch opening proof is counted as one path per opened
+    /// leaf.
+    pub num_merkle_paths: u64,
+}
+
+impl OperationCounts {
+    /// Returns the number of operations performed since the `earlier` counts were taken.
+    pub fn since(&self, earlier: &Self) -> Self {
+        Self {
+            num_hashes: self.num_hashes - earlier.num_hashes,
+            num_field_muls: self.num_field_muls - earlier.num_field_muls,
+            num_merkle_paths: self.num_merkle_paths - earlier.num_merkle_paths,
+        }
+    }
+}
+
+/// Returns the number of operations performed by the current thread so far.
+#[cfg(feature = "accounting")]
+pub fn operation_counts() -> OperationCounts {
+    OperationCounts {
+        num_hashes: NUM_HASHES.with(Cell::get),
+        num_field_muls: NUM_FIELD_MULS.with(Cell::get),
+        num_merkle_paths: NUM_MERKLE_PATHS.with(Cell::get),
+    }
+}
+
+// RECORDING FUNCTIONS
+// ================================================================================================
+
+#[cfg(feature = "accounting")]
+std::thread_local! {
+    static NUM_HASHES: Cell<u64> = const { Cell::new(0) };
+    static NUM_FIELD_MULS: Cell<u64> = const { Cell::new(0) };
+    static NUM_MERKLE_PATHS: Cell<u64> = const { Cell::new(0) };
+}
+
+/// Records a single invocation of a hash function.
+#[inline(always)]
+pub fn record_hash() {
+    #[cfg(feature = "accounting")]
+    NUM_HASHES.with(|c| c.set(c.get() + 1));
+}
+
+/// Records a single multiplication in the base field.
+#[inline(always)]
+pub fn record_field_mul() {
+    #[cfg(feature = "accounting")]
+    NUM_FIELD_MULS.with(|c| c.set(c.get() + 1));
+}
+
+/// Records verification of the specified number of Merkle paths.
+#[inline(always)]
+#[allow(unused_variables)]
+pub fn record_merkle_paths(num_paths: usize) {
+    #[cfg(feature = "accounting")]
+    NUM_MERKLE_PATHS.with(|c| c.set(c.get() + num_paths as u64));
+}
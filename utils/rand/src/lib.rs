@@ -24,11 +24,11 @@ mod internal {
     ///
     /// # Panics
     /// Panics if:
-    /// * A valid value requires over 32 bytes.
+    /// * A valid value requires over 64 bytes.
     /// * A valid value could not be generated after 1000 tries.
     pub fn rand_value<R: Randomizable>() -> R {
         for _ in 0..1000 {
-            let bytes = rand::thread_rng().gen::<[u8; 32]>();
+            let bytes = next_bytes::<R>(&mut rand::thread_rng());
             if let Some(value) = R::from_random_bytes(&bytes[..R::VALUE_SIZE]) {
                 return value;
             }
@@ -41,14 +41,14 @@ mod internal {
     ///
     /// # Panics
     /// Panics if:
-    /// * A valid value requires at over 32 bytes.
+    /// * A valid value requires over 64 bytes.
     /// * A valid value could not be generated after 1000 tries.
     pub fn rand_vector<R: Randomizable>(n: usize) -> Vec<R> {
         let mut result = Vec::with_capacity(n);
         let seed = rand::thread_rng().gen::<[u8; 32]>();
         let mut g = StdRng::from_seed(seed);
         for _ in 0..1000 * n {
-            let bytes = g.gen::<[u8; 32]>();
+            let bytes = next_bytes::<R>(&mut g);
             if let Some(element) = R::from_random_bytes(&bytes[..R::VALUE_SIZE]) {
                 result.push(element);
                 if result.len() == n {
@@ -64,7 +64,7 @@ mod internal {
     ///
     /// # Panics
     /// Panics if:
-    /// * A valid value requires at over 32 bytes.
+    /// * A valid value requires over 64 bytes.
     /// * A valid value could not be generated after 1000 tries.
     pub fn rand_array<R: Randomizable + Debug, const N: usize>() -> [R; N] {
         let elements = rand_vector(N);
@@ -76,13 +76,13 @@ mod internal {
     ///
     /// # Panics
     /// Panics if:
-    /// * A valid value requires at over 32 bytes.
+    /// * A valid value requires over 64 bytes.
     /// * A valid value could not be generated after 1000 tries.
     pub fn prng_vector<R: Randomizable>(seed: [u8; 32], n: usize) -> Vec<R> {
         let mut result = Vec::with_capacity(n);
         let mut g = StdRng::from_seed(seed);
         for _ in 0..1000 * n {
-            let bytes = g.gen::<[u8; 32]>();
+            let bytes = next_bytes::<R>(&mut g);
             if let Some(element) = R::from_random_bytes(&bytes[..R::VALUE_SIZE]) {
                 result.push(element);
                 if result.len() == n {
@@ -99,13 +99,39 @@ mod internal {
     ///
     /// # Panics
     /// Panics if:
-    /// * A valid value requires at over 32 bytes.
+    /// * A valid value requires over 64 bytes.
     /// * A valid value could not be generated after 1000 tries.
     pub fn prng_array<R: Randomizable + Debug, const N: usize>(seed: [u8; 32]) -> [R; N] {
         let elements = prng_vector(seed, N);
         elements.try_into().expect("failed to convert vector to array")
     }
 
+    // HELPER FUNCTIONS
+    // ============================================================================================
+
+    /// Maximum number of bytes which can be used to generate a single value.
+    const MAX_VALUE_SIZE: usize = 64;
+
+    /// Returns a buffer with enough random bytes to generate a value of the specified type.
+    ///
+    /// Values of up to 32 bytes always consume exactly 32 bytes of the generator output so that
+    /// sequences of such values generated from a given seed remain stable.
+    fn next_bytes<R: Randomizable>(g: &mut impl RngCore) -> [u8; MAX_VALUE_SIZE] {
+        assert!(
+            R::VALUE_SIZE <= MAX_VALUE_SIZE,
+            "value size cannot exceed {MAX_VALUE_SIZE} bytes, but was {} bytes",
+            R::VALUE_SIZE
+        );
+
+        let mut bytes = [0_u8; MAX_VALUE_SIZE];
+        if R::VALUE_SIZE <= 32 {
+            bytes[..32].copy_from_slice(&g.gen::<[u8; 32]>());
+        } else {
+            g.fill_bytes(&mut bytes[..R::VALUE_SIZE]);
+        }
+        bytes
+    }
+
     // SHUFFLING
     // ============================================================================================
 
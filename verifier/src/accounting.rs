@@ -0,0 +1,70 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use air::{proof::Proof, Air};
+use crypto::{ElementHasher, RandomCoin, VectorCommitment};
+use utils::accounting::{self, OperationCounts};
+
+use super::{verify, AcceptableOptions, VerifierError};
+
+// VERIFICATION COST
+// ================================================================================================
+
+/// Amount of work performed during verification of a STARK proof.
+///
+/// Unlike verification time, the cost depends only on the proof (and on the computation it
+/// attests to), and thus, can be used as a reproducible metric for metering of proof
+/// verification.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct VerificationCost {
+    /// Number of hash function invocations, including hashing performed by the public coin and
+    /// during verification of Merkle paths.
+    pub num_hashes: u64,
+    /// Number of multiplications in the base field. Multiplications in extension fields are
+    /// counted as the base field multiplications they are composed of.
+    pub num_field_muls: u64,
+    /// Number of verified Merkle paths, counted as one path per opened leaf.
+    pub num_merkle_paths: u64,
+}
+
+impl From<OperationCounts> for VerificationCost {
+    fn from(counts: OperationCounts) -> Self {
+        Self {
+            num_hashes: counts.num_hashes,
+            num_field_muls: counts.num_field_muls,
+            num_merkle_paths: counts.num_merkle_paths,
+        }
+    }
+}
+
+// VERIFIER
+// ================================================================================================
+
+/// Verifies that the specified computation was executed correctly against the specified inputs,
+/// and returns the amount of work performed during verification.
+///
+/// This is the same as [verify()], but it also counts hash function invocations, field
+/// multiplications, and Merkle path verifications performed by the current thread while the
+/// proof is being verified. Operations performed by custom hash functions or vector commitment
+/// schemes defined outside of Winterfell crates are counted only to the extent that they rely on
+/// the provided hash functions and field arithmetic.
+///
+/// # Errors
+/// Returns an error under the same conditions as [verify()].
+pub fn verify_with_accounting<AIR, HashFn, RandCoin, VC>(
+    proof: Proof,
+    pub_inputs: AIR::PublicInputs,
+    acceptable_options: &AcceptableOptions,
+) -> Result<VerificationCost, VerifierError>
+where
+    AIR: Air,
+    HashFn: ElementHasher<BaseField = AIR::BaseField>,
+    RandCoin: RandomCoin<BaseField = AIR::BaseField, Hasher = HashFn>,
+    VC: VectorCommitment<HashFn>,
+{
+    let start = accounting::operation_counts();
+    verify::<AIR, HashFn, RandCoin, VC>(proof, pub_inputs, acceptable_options)?;
+    Ok(accounting::operation_counts().since(&start).into())
+}
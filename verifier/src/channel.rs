@@ -7,15 +7,89 @@ use alloc::{string::ToString, vec::Vec};
 use core::marker::PhantomData;
 
 use air::{
-    proof::{Proof, Queries, Table, TraceOodFrame},
+    proof::{Proof, ProofHeader, Queries, Table, TraceOodFrame},
     Air,
 };
-use crypto::{ElementHasher, VectorCommitment};
+use crypto::{ElementHasher, Hasher, VectorCommitment};
 use fri::VerifierChannel as FriVerifierChannel;
 use math::{FieldElement, StarkField};
 
 use crate::VerifierError;
 
+// PROOF CHANNEL TRAIT
+// ================================================================================================
+
+/// Defines an interface for reading the components of a STARK proof in the order in which they
+/// are consumed by the verifier.
+///
+/// FRI layers and the FRI remainder are read via the [FriVerifierChannel] super-trait.
+pub(crate) trait ProofChannel<E: FieldElement>: FriVerifierChannel<E> {
+    /// Returns the main trace segment commitment sent by the prover.
+    fn read_main_trace_commitment(&self) -> <Self::Hasher as Hasher>::Digest;
+
+    /// Returns the auxiliary trace segment commitment sent by the prover, or None if the trace
+    /// does not have an auxiliary segment.
+    ///
+    /// If the auxiliary trace segment is committed to using a different hash function, the
+    /// commitment is converted into a digest of the channel's hash function.
+    fn read_aux_trace_commitment(&self) -> Option<<Self::Hasher as Hasher>::Digest>;
+
+    /// Returns constraint evaluation commitment sent by the prover.
+    fn read_constraint_commitment(&self) -> <Self::Hasher as Hasher>::Digest;
+
+    /// Returns trace polynomial evaluations at out-of-domain points z and z * g, where g is the
+    /// generator of the LDE domain.
+    ///
+    /// For computations requiring multiple trace segments, evaluations of auxiliary trace
+    /// polynomials are also included.
+    fn read_ood_trace_frame(&mut self) -> TraceOodFrame<E>;
+
+    /// Returns evaluations of composition polynomial columns at z^m, where z is the out-of-domain
+    /// point, and m is the number of composition polynomial columns.
+    fn read_ood_constraint_evaluations(&mut self) -> Vec<E>;
+
+    /// Returns query proof-of-work nonce sent by the prover.
+    fn read_pow_nonce(&self) -> u64;
+
+    /// Returns the serialized GKR proof, if any.
+    fn read_gkr_proof(&self) -> Option<&Vec<u8>>;
+
+    /// Returns trace states at the specified positions of the LDE domain. This also checks if
+    /// the trace states are valid against the trace commitment sent by the prover.
+    ///
+    /// For computations requiring multiple trace segments, trace states for auxiliary segments
+    /// are also included as the second value of the returned tuple. Otherwise, the second value
+    /// is None.
+    #[allow(clippy::type_complexity)]
+    fn read_queried_trace_states(
+        &mut self,
+        positions: &[usize],
+    ) -> Result<(Table<E::BaseField>, Option<Table<E>>), VerifierError>;
+
+    /// Returns constraint evaluations at the specified positions of the LDE domain. This also
+    /// checks if the constraint evaluations are valid against the constraint commitment sent by
+    /// the prover.
+    fn read_constraint_evaluations(
+        &mut self,
+        positions: &[usize],
+    ) -> Result<Table<E>, VerifierError>;
+
+    /// Returns trace states at the queried positions of the LDE domain without checking them
+    /// against the trace commitment sent by the prover.
+    ///
+    /// The returned states are in the same form as the ones returned from
+    /// [read_queried_trace_states()](ProofChannel::read_queried_trace_states), but they cannot be
+    /// trusted.
+    #[allow(clippy::type_complexity)]
+    fn read_unchecked_trace_states(
+        &mut self,
+    ) -> Result<(Table<E::BaseField>, Option<Table<E>>), VerifierError>;
+
+    /// Returns constraint evaluations at the queried positions of the LDE domain without checking
+    /// them against the constraint commitment sent by the prover.
+    fn read_unchecked_constraint_evaluations(&mut self) -> Result<Table<E>, VerifierError>;
+}
+
 // VERIFIER CHANNEL
 // ================================================================================================
 
@@ -24,17 +98,26 @@ use crate::VerifierError;
 /// A channel is instantiated for a specific proof, which is parsed into structs over the
 /// appropriate field (specified by type parameter `E`). This also validates that the proof is
 /// well-formed in the context of the computation for the specified [Air].
+///
+/// The auxiliary trace segment is expected to be committed to using hash function `AH` and vector
+/// commitment scheme `AV`; all other commitments are expected to be made using `H` and `V`.
 pub struct VerifierChannel<
     E: FieldElement,
     H: ElementHasher<BaseField = E::BaseField>,
     V: VectorCommitment<H>,
+    AH: ElementHasher<BaseField = E::BaseField>,
+    AV: VectorCommitment<AH>,
 > {
     // trace queries
-    trace_commitments: Vec<H::Digest>,
-    trace_queries: Option<TraceQueries<E, H, V>>,
+    main_trace_commitment: H::Digest,
+    aux_trace_commitment: Option<AH::Digest>,
+    trace_queries: Option<TraceQueries<E, H, V, AH, AV>>,
     // constraint queries
     constraint_commitment: H::Digest,
     constraint_queries: Option<ConstraintQueries<E, H, V>>,
+    num_unique_queries: usize,
+    // indexes and values of constant columns of the main trace segment
+    constant_columns: Vec<(usize, E::BaseField)>,
     // partition sizes for the rows of main, auxiliary and constraint traces rows
     partition_size_main: usize,
     partition_size_aux: usize,
@@ -53,11 +136,13 @@ pub struct VerifierChannel<
     gkr_proof: Option<Vec<u8>>,
 }
 
-impl<E, H, V> VerifierChannel<E, H, V>
+impl<E, H, V, AH, AV> VerifierChannel<E, H, V, AH, AV>
 where
     E: FieldElement,
     H: ElementHasher<BaseField = E::BaseField>,
     V: VectorCommitment<H>,
+    AH: ElementHasher<BaseField = E::BaseField>,
+    AV: VectorCommitment<AH>,
 {
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
@@ -78,6 +163,55 @@ where
             gkr_proof,
         } = proof;
 
+        let header = ProofHeader {
+            context,
+            num_unique_queries,
+            commitments,
+            gkr_proof,
+            ood_frame,
+            fri_num_partitions: fri_proof.num_partitions(),
+            pow_nonce,
+        };
+        let mut channel = Self::from_header(air, header)?;
+
+        // --- parse trace and constraint queries -------------------------------------------------
+        channel.set_trace_queries(trace_queries, air)?;
+        channel.set_constraint_queries(constraint_queries, air)?;
+
+        // --- parse FRI proofs -------------------------------------------------------------------
+        let lde_domain_size = air.lde_domain_size();
+        let fri_options = air.options().to_fri_options();
+        let fri_remainder = fri_proof
+            .parse_remainder()
+            .map_err(|err| VerifierError::ProofDeserializationError(err.to_string()))?;
+        let (fri_layer_queries, fri_layer_proofs) = fri_proof
+            .parse_layers::<E, H, V>(lde_domain_size, fri_options.folding_factor())
+            .map_err(|err| VerifierError::ProofDeserializationError(err.to_string()))?;
+        channel.fri_layer_queries = fri_layer_queries;
+        channel.fri_layer_proofs = fri_layer_proofs;
+        channel.fri_remainder = Some(fri_remainder);
+
+        Ok(channel)
+    }
+
+    /// Creates and returns a new [VerifierChannel] initialized from the specified proof `header`.
+    ///
+    /// Trace and constraint queries, as well as FRI layers and the FRI remainder, are not a part
+    /// of the header; these must be added to the channel before they can be read from it.
+    pub fn from_header<A: Air<BaseField = E::BaseField>>(
+        air: &A,
+        header: ProofHeader,
+    ) -> Result<Self, VerifierError> {
+        let ProofHeader {
+            context,
+            num_unique_queries,
+            commitments,
+            gkr_proof,
+            ood_frame,
+            fri_num_partitions,
+            pow_nonce,
+        } = header;
+
         // make sure AIR and proof base fields are the same
         if E::BaseField::get_modulus_le_bytes() != context.field_modulus_bytes() {
             return Err(VerifierError::InconsistentBaseField);
@@ -92,36 +226,29 @@ where
         let partition_options = air.options().partition_options();
 
         // --- parse commitments ------------------------------------------------------------------
-        let (trace_commitments, constraint_commitment, fri_commitments) = commitments
-            .parse::<H>(num_trace_segments, fri_options.num_fri_layers(lde_domain_size))
-            .map_err(|err| VerifierError::ProofDeserializationError(err.to_string()))?;
-
-        // --- parse trace and constraint queries -------------------------------------------------
-        let trace_queries =
-            TraceQueries::<E, H, V>::new(trace_queries, air, num_unique_queries as usize)?;
-        let constraint_queries = ConstraintQueries::<E, H, V>::new(
-            constraint_queries,
-            air,
-            num_unique_queries as usize,
-        )?;
-
-        // --- parse FRI proofs -------------------------------------------------------------------
-        let fri_num_partitions = fri_proof.num_partitions();
-        let fri_remainder = fri_proof
-            .parse_remainder()
-            .map_err(|err| VerifierError::ProofDeserializationError(err.to_string()))?;
-        let (fri_layer_queries, fri_layer_proofs) = fri_proof
-            .parse_layers::<E, H, V>(lde_domain_size, fri_options.folding_factor())
-            .map_err(|err| VerifierError::ProofDeserializationError(err.to_string()))?;
+        let (main_trace_commitment, aux_trace_commitment, constraint_commitment, fri_commitments) =
+            commitments
+                .parse_with_aux_hash::<H, AH>(
+                    num_trace_segments,
+                    fri_options.num_fri_layers(lde_domain_size),
+                )
+                .map_err(|err| VerifierError::ProofDeserializationError(err.to_string()))?;
 
         // --- parse out-of-domain evaluation frame -----------------------------------------------
         let (ood_trace_frame, ood_constraint_evaluations) = ood_frame
             .parse(main_trace_width, aux_trace_width, constraint_frame_width)
             .map_err(|err| VerifierError::ProofDeserializationError(err.to_string()))?;
 
+        // --- read constant columns of the main trace segment -----------------------------------
+        let constant_columns = air
+            .trace_info()
+            .constant_columns::<E::BaseField>()
+            .map_err(|err| VerifierError::ProofDeserializationError(err.to_string()))?;
+
         // --- compute the partition size for each trace ------------------------------------------
-        let partition_size_main = partition_options
-            .partition_size::<E::BaseField>(air.context().trace_info().main_trace_width());
+        let partition_size_main = partition_options.partition_size::<E::BaseField>(
+            air.context().trace_info().main_trace_commitment_width(),
+        );
         let partition_size_aux =
             partition_options.partition_size::<E>(air.context().trace_info().aux_segment_width());
         let partition_size_constraint = partition_options
@@ -129,20 +256,23 @@ where
 
         Ok(VerifierChannel {
             // trace queries
-            trace_commitments,
-            trace_queries: Some(trace_queries),
+            main_trace_commitment,
+            aux_trace_commitment,
+            trace_queries: None,
             // constraint queries
             constraint_commitment,
-            constraint_queries: Some(constraint_queries),
+            constraint_queries: None,
+            num_unique_queries: num_unique_queries as usize,
+            constant_columns,
             // num partitions used in commitment
             partition_size_main,
             partition_size_aux,
             partition_size_constraint,
             // FRI proof
             fri_commitments: Some(fri_commitments),
-            fri_layer_proofs,
-            fri_layer_queries,
-            fri_remainder: Some(fri_remainder),
+            fri_layer_proofs: Vec::new(),
+            fri_layer_queries: Vec::new(),
+            fri_remainder: None,
             fri_num_partitions,
             // out-of-domain evaluation
             ood_trace_frame: Some(ood_trace_frame),
@@ -153,55 +283,84 @@ where
         })
     }
 
-    // DATA READERS
+    // DATA WRITERS
     // --------------------------------------------------------------------------------------------
 
-    /// Returns execution trace commitments sent by the prover.
-    ///
-    /// For computations requiring multiple trace segment, the returned slice will contain a
-    /// commitment for each trace segment.
-    pub fn read_trace_commitments(&self) -> &[H::Digest] {
-        &self.trace_commitments
+    /// Parses the specified trace queries and adds them to this channel.
+    pub fn set_trace_queries<A: Air<BaseField = E::BaseField>>(
+        &mut self,
+        queries: Vec<Queries>,
+        air: &A,
+    ) -> Result<(), VerifierError> {
+        let queries = TraceQueries::new(queries, air, self.num_unique_queries)?;
+        self.trace_queries = Some(queries);
+        Ok(())
     }
 
-    /// Returns constraint evaluation commitment sent by the prover.
-    pub fn read_constraint_commitment(&self) -> H::Digest {
+    /// Parses the specified constraint queries and adds them to this channel.
+    pub fn set_constraint_queries<A: Air<BaseField = E::BaseField>>(
+        &mut self,
+        queries: Queries,
+        air: &A,
+    ) -> Result<(), VerifierError> {
+        let queries = ConstraintQueries::new(queries, air, self.num_unique_queries)?;
+        self.constraint_queries = Some(queries);
+        Ok(())
+    }
+
+    /// Adds parsed query values and the corresponding opening proof for the next FRI layer to this
+    /// channel.
+    pub fn push_fri_layer(&mut self, layer_queries: Vec<E>, layer_proof: V::MultiProof) {
+        self.fri_layer_queries.push(layer_queries);
+        self.fri_layer_proofs.push(layer_proof);
+    }
+
+    /// Adds the parsed FRI remainder to this channel.
+    pub fn set_fri_remainder(&mut self, remainder: Vec<E>) {
+        self.fri_remainder = Some(remainder);
+    }
+}
+
+// PROOF CHANNEL IMPLEMENTATION
+// ================================================================================================
+
+impl<E, H, V, AH, AV> ProofChannel<E> for VerifierChannel<E, H, V, AH, AV>
+where
+    E: FieldElement,
+    H: ElementHasher<BaseField = E::BaseField>,
+    V: VectorCommitment<H>,
+    AH: ElementHasher<BaseField = E::BaseField, Digest: Into<H::Digest>>,
+    AV: VectorCommitment<AH>,
+{
+    fn read_main_trace_commitment(&self) -> H::Digest {
+        self.main_trace_commitment
+    }
+
+    fn read_aux_trace_commitment(&self) -> Option<H::Digest> {
+        self.aux_trace_commitment.map(Into::into)
+    }
+
+    fn read_constraint_commitment(&self) -> H::Digest {
         self.constraint_commitment
     }
 
-    /// Returns trace polynomial evaluations at out-of-domain points z and z * g, where g is the
-    /// generator of the LDE domain.
-    ///
-    /// For computations requiring multiple trace segments, evaluations of auxiliary trace
-    /// polynomials are also included.
-    pub fn read_ood_trace_frame(&mut self) -> TraceOodFrame<E> {
+    fn read_ood_trace_frame(&mut self) -> TraceOodFrame<E> {
         self.ood_trace_frame.take().expect("already read")
     }
 
-    /// Returns evaluations of composition polynomial columns at z^m, where z is the out-of-domain
-    /// point, and m is the number of composition polynomial columns.
-    pub fn read_ood_constraint_evaluations(&mut self) -> Vec<E> {
+    fn read_ood_constraint_evaluations(&mut self) -> Vec<E> {
         self.ood_constraint_evaluations.take().expect("already read")
     }
 
-    /// Returns query proof-of-work nonce sent by the prover.
-    pub fn read_pow_nonce(&self) -> u64 {
+    fn read_pow_nonce(&self) -> u64 {
         self.pow_nonce
     }
 
-    /// Returns the serialized GKR proof, if any.
-    pub fn read_gkr_proof(&self) -> Option<&Vec<u8>> {
+    fn read_gkr_proof(&self) -> Option<&Vec<u8>> {
         self.gkr_proof.as_ref()
     }
 
-    /// Returns trace states at the specified positions of the LDE domain. This also checks if
-    /// the trace states are valid against the trace commitment sent by the prover.
-    ///
-    /// For computations requiring multiple trace segments, trace states for auxiliary segments
-    /// are also included as the second value of the returned tuple (trace states for all auxiliary
-    /// segments are merged into a single table). Otherwise, the second value is None.
-    #[allow(clippy::type_complexity)]
-    pub fn read_queried_trace_states(
+    fn read_queried_trace_states(
         &mut self,
         positions: &[usize],
     ) -> Result<(Table<E::BaseField>, Option<Table<E>>), VerifierError> {
@@ -215,35 +374,39 @@ where
             .collect();
 
         <V as VectorCommitment<H>>::verify_many(
-            self.trace_commitments[0],
+            self.main_trace_commitment,
             positions,
             &items,
-            &queries.query_proofs[0],
+            &queries.main_query_proof,
         )
         .map_err(|_| VerifierError::TraceQueryDoesNotMatchCommitment)?;
 
-        if let Some(ref aux_states) = queries.aux_states {
-            let items: Vec<H::Digest> = aux_states
+        if let Some((ref aux_states, ref aux_query_proof)) = queries.aux_states {
+            let items: Vec<AH::Digest> = aux_states
                 .rows()
-                .map(|row| hash_row::<H, E>(row, self.partition_size_aux))
+                .map(|row| hash_row::<AH, E>(row, self.partition_size_aux))
                 .collect();
 
-            <V as VectorCommitment<H>>::verify_many(
-                self.trace_commitments[1],
+            let aux_trace_commitment =
+                self.aux_trace_commitment.expect("expected aux trace commitment to be present");
+            <AV as VectorCommitment<AH>>::verify_many(
+                aux_trace_commitment,
                 positions,
                 &items,
-                &queries.query_proofs[1],
+                aux_query_proof,
             )
             .map_err(|_| VerifierError::TraceQueryDoesNotMatchCommitment)?;
         }
 
-        Ok((queries.main_states, queries.aux_states))
+        // values of constant columns of the main trace segment are not committed to by the
+        // prover; these values are inserted into the main trace states after the committed values
+        // are checked against the trace commitment
+        let main_states = queries.main_states.with_constant_columns(&self.constant_columns);
+
+        Ok((main_states, queries.aux_states.map(|(aux_states, _)| aux_states)))
     }
 
-    /// Returns constraint evaluations at the specified positions of the LDE domain. This also
-    /// checks if the constraint evaluations are valid against the constraint commitment sent by
-    /// the prover.
-    pub fn read_constraint_evaluations(
+    fn read_constraint_evaluations(
         &mut self,
         positions: &[usize],
     ) -> Result<Table<E>, VerifierError> {
@@ -265,16 +428,31 @@ where
 
         Ok(queries.evaluations)
     }
+
+    fn read_unchecked_trace_states(
+        &mut self,
+    ) -> Result<(Table<E::BaseField>, Option<Table<E>>), VerifierError> {
+        let queries = self.trace_queries.take().expect("already read");
+        let main_states = queries.main_states.with_constant_columns(&self.constant_columns);
+        Ok((main_states, queries.aux_states.map(|(aux_states, _)| aux_states)))
+    }
+
+    fn read_unchecked_constraint_evaluations(&mut self) -> Result<Table<E>, VerifierError> {
+        let queries = self.constraint_queries.take().expect("already read");
+        Ok(queries.evaluations)
+    }
 }
 
 // FRI VERIFIER CHANNEL IMPLEMENTATION
 // ================================================================================================
 
-impl<E, H, V> FriVerifierChannel<E> for VerifierChannel<E, H, V>
+impl<E, H, V, AH, AV> FriVerifierChannel<E> for VerifierChannel<E, H, V, AH, AV>
 where
     E: FieldElement,
     H: ElementHasher<BaseField = E::BaseField>,
     V: VectorCommitment<H>,
+    AH: ElementHasher<BaseField = E::BaseField>,
+    AV: VectorCommitment<AH>,
 {
     type Hasher = H;
     type VectorCommitment = V;
@@ -305,25 +483,29 @@ where
 
 /// Container of trace query data, including:
 /// * Queried states for all trace segments.
-/// * Batch opening proof for all queries.
+/// * Batch opening proof for the queries of each trace segment.
 ///
 /// Trace states for all auxiliary segments are stored in a single table.
 struct TraceQueries<
     E: FieldElement,
     H: ElementHasher<BaseField = E::BaseField>,
     V: VectorCommitment<H>,
+    AH: ElementHasher<BaseField = E::BaseField>,
+    AV: VectorCommitment<AH>,
 > {
-    query_proofs: Vec<V::MultiProof>,
+    main_query_proof: V::MultiProof,
     main_states: Table<E::BaseField>,
-    aux_states: Option<Table<E>>,
-    _h: PhantomData<H>,
+    aux_states: Option<(Table<E>, AV::MultiProof)>,
+    _h: PhantomData<(H, AH)>,
 }
 
-impl<E, H, V> TraceQueries<E, H, V>
+impl<E, H, V, AH, AV> TraceQueries<E, H, V, AH, AV>
 where
     E: FieldElement,
     H: ElementHasher<BaseField = E::BaseField>,
     V: VectorCommitment<H>,
+    AH: ElementHasher<BaseField = E::BaseField>,
+    AV: VectorCommitment<AH>,
 {
     /// Parses the provided trace queries into trace states in the specified field and
     /// corresponding batch opening proof.
@@ -340,10 +522,10 @@ where
             queries.len()
         );
 
-        // parse main trace segment queries
-        let main_segment_width = air.trace_info().main_trace_width();
+        // parse main trace segment queries; constant columns are not included in the queries
+        let main_segment_width = air.trace_info().main_trace_commitment_width();
         let main_segment_queries = queries.remove(0);
-        let (main_segment_query_proofs, main_segment_states) = main_segment_queries
+        let (main_query_proof, main_segment_states) = main_segment_queries
             .parse::<E::BaseField, H, V>(air.lde_domain_size(), num_queries, main_segment_width)
             .map_err(|err| {
                 VerifierError::ProofDeserializationError(format!(
@@ -351,9 +533,6 @@ where
                 ))
             })?;
 
-        // all query proofs will be aggregated into a single vector
-        let mut query_proofs = vec![main_segment_query_proofs];
-
         // parse auxiliary trace segment queries (if any), and merge resulting tables into a
         // single table
         let aux_trace_states = if air.trace_info().is_multi_segment() {
@@ -361,24 +540,23 @@ where
             let segment_queries = queries.remove(0);
             let segment_width = air.trace_info().get_aux_segment_width();
             let (segment_query_proof, segment_trace_states) = segment_queries
-                .parse::<E, H, V>(air.lde_domain_size(), num_queries, segment_width)
+                .parse::<E, AH, AV>(air.lde_domain_size(), num_queries, segment_width)
                 .map_err(|err| {
                     VerifierError::ProofDeserializationError(format!(
                         "auxiliary trace segment query deserialization failed: {err}"
                     ))
                 })?;
 
-            query_proofs.push(segment_query_proof);
             aux_trace_states.push(segment_trace_states);
 
             // merge tables for each auxiliary segment into a single table
-            Some(Table::merge(aux_trace_states))
+            Some((Table::merge(aux_trace_states), segment_query_proof))
         } else {
             None
         };
 
         Ok(Self {
-            query_proofs,
+            main_query_proof,
             main_states: main_segment_states,
             aux_states: aux_trace_states,
             _h: PhantomData,
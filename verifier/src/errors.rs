@@ -27,8 +27,11 @@ pub enum VerifierError {
     /// within a specified number of tries.
     RandomCoinError,
     /// This error occurs when constraints evaluated over out-of-domain trace rows do not match
-    /// evaluations of the constraint composition polynomial at the out-of-domain point.
-    InconsistentOodConstraintEvaluations,
+    /// evaluations of the constraint composition polynomial at the out-of-domain point. The error
+    /// specifies the value of the composition polynomial at the out-of-domain point derived from
+    /// the column evaluations sent by the prover (`expected`), and the value obtained by
+    /// evaluating constraints of the AIR over the out-of-domain frame (`actual`).
+    InconsistentOodConstraintEvaluation { expected: String, actual: String },
     /// This error occurs when the batch opening proof fails to verify for trace queries.
     TraceQueryDoesNotMatchCommitment,
     /// This error occurs when the batch opening proof fails to verify for constraint evaluation
@@ -53,6 +56,88 @@ pub enum VerifierError {
     /// This error occurs when the parameters, that were used to generate the proof, do not match
     /// any of the set of parameters expected by the verifier.
     UnacceptableProofOptions,
+    /// This error occurs when externally supplied query positions are empty or fall outside of
+    /// the LDE domain. Query positions can be supplied externally only when the
+    /// `external-queries` feature is enabled.
+    InvalidQueryPositions,
+    /// This error occurs when the precomputed AIR context supplied to the verifier was built for
+    /// a different trace or for different proof options than the ones used to generate the
+    /// proof.
+    MismatchedAirContext,
+    /// This error occurs when an assertion supplied to the verifier in addition to the assertions
+    /// of the AIR is not valid for the trace described by the proof.
+    InvalidAssertion(String),
+    /// This error occurs when the hash function identifier recorded in the proof context (or
+    /// the lack of one) does not correspond to any hash function accepted by the verifier.
+    UnsupportedHashFunction(Option<u8>),
+    /// This error occurs when the proof-of-work identifier recorded in the proof context (or the
+    /// lack of one) does not correspond to the proof-of-work algorithm used by the verifier.
+    UnsupportedProofOfWork(Option<u8>),
+    /// This error occurs when the context of a proof differs from the context pinned by the
+    /// verification key supplied to the verifier.
+    MismatchedVerificationKey,
+    /// This error occurs when the identifier of the auxiliary segment hash function recorded in the
+    /// proof context (or the lack of one) does not correspond to the one used by the verifier.
+    UnsupportedAuxHashFunction(Option<u8>),
+}
+
+impl VerifierError {
+    /// Returns a numeric code identifying the reason of this error.
+    ///
+    /// The codes are intended for consumers which cannot work with Rust enums directly (e.g.,
+    /// via FFI). The following codes are currently defined:
+    ///
+    /// | Code | Variant                                    |
+    /// | ---- | ------------------------------------------ |
+    /// | 1    | [GkrProofVerificationFailed](Self::GkrProofVerificationFailed) |
+    /// | 2    | [InconsistentBaseField](Self::InconsistentBaseField) |
+    /// | 3    | [UnsupportedFieldExtension](Self::UnsupportedFieldExtension) |
+    /// | 4    | [ProofDeserializationError](Self::ProofDeserializationError) |
+    /// | 5    | [RandomCoinError](Self::RandomCoinError) |
+    /// | 6    | [InconsistentOodConstraintEvaluation](Self::InconsistentOodConstraintEvaluation) |
+    /// | 7    | [TraceQueryDoesNotMatchCommitment](Self::TraceQueryDoesNotMatchCommitment) |
+    /// | 8    | [ConstraintQueryDoesNotMatchCommitment](Self::ConstraintQueryDoesNotMatchCommitment) |
+    /// | 9    | [QuerySeedProofOfWorkVerificationFailed](Self::QuerySeedProofOfWorkVerificationFailed) |
+    /// | 10   | [FriVerificationFailed](Self::FriVerificationFailed) |
+    /// | 11   | [InsufficientConjecturedSecurity](Self::InsufficientConjecturedSecurity) |
+    /// | 12   | [InsufficientProvenSecurity](Self::InsufficientProvenSecurity) |
+    /// | 13   | [UnacceptableProofOptions](Self::UnacceptableProofOptions) |
+    /// | 14   | [InvalidQueryPositions](Self::InvalidQueryPositions) |
+    /// | 15   | [MismatchedAirContext](Self::MismatchedAirContext) |
+    /// | 16   | [InvalidAssertion](Self::InvalidAssertion) |
+    /// | 17   | [UnsupportedHashFunction](Self::UnsupportedHashFunction) |
+    /// | 18   | [UnsupportedProofOfWork](Self::UnsupportedProofOfWork) |
+    /// | 19   | [MismatchedVerificationKey](Self::MismatchedVerificationKey) |
+    /// | 20   | [UnsupportedAuxHashFunction](Self::UnsupportedAuxHashFunction) |
+    ///
+    /// Code 0 is never returned, and thus, can be used by consumers to indicate successful
+    /// verification. The codes are stable: a code assigned to a variant will not change across
+    /// minor versions, and codes of removed variants will not be reused. New variants will be
+    /// assigned new codes following the largest code in use.
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::GkrProofVerificationFailed(_) => 1,
+            Self::InconsistentBaseField => 2,
+            Self::UnsupportedFieldExtension(_) => 3,
+            Self::ProofDeserializationError(_) => 4,
+            Self::RandomCoinError => 5,
+            Self::InconsistentOodConstraintEvaluation { .. } => 6,
+            Self::TraceQueryDoesNotMatchCommitment => 7,
+            Self::ConstraintQueryDoesNotMatchCommitment => 8,
+            Self::QuerySeedProofOfWorkVerificationFailed => 9,
+            Self::FriVerificationFailed(_) => 10,
+            Self::InsufficientConjecturedSecurity(..) => 11,
+            Self::InsufficientProvenSecurity(..) => 12,
+            Self::UnacceptableProofOptions => 13,
+            Self::InvalidQueryPositions => 14,
+            Self::MismatchedAirContext => 15,
+            Self::InvalidAssertion(_) => 16,
+            Self::UnsupportedHashFunction(_) => 17,
+            Self::UnsupportedProofOfWork(_) => 18,
+            Self::MismatchedVerificationKey => 19,
+            Self::UnsupportedAuxHashFunction(_) => 20,
+        }
+    }
 }
 
 impl fmt::Display for VerifierError {
@@ -74,8 +159,8 @@ impl fmt::Display for VerifierError {
             Self::RandomCoinError => {
                 write!(f, "failed to draw a random value from a random coin")
             }
-            Self::InconsistentOodConstraintEvaluations => {
-                write!(f, "constraint evaluations over the out-of-domain frame are inconsistent")
+            Self::InconsistentOodConstraintEvaluation { expected, actual } => {
+                write!(f, "constraint evaluations over the out-of-domain frame are inconsistent: composition polynomial evaluates to {expected}, but constraints evaluate to {actual}")
             }
             Self::TraceQueryDoesNotMatchCommitment => {
                 write!(f, "failed to open trace query against the given commitment")
@@ -96,6 +181,36 @@ impl fmt::Display for VerifierError {
                 write!(f, "insufficient proof security level: expected at least {minimal_security} bits of proven security, but was {proof_security} bits")
             }
             Self::UnacceptableProofOptions => {write!(f, "invalid proof options: security parameters do not match the acceptable parameter set")}
+            Self::InvalidQueryPositions => {
+                write!(f, "query positions must be non-empty and must fall within the LDE domain")
+            }
+            Self::MismatchedAirContext => {
+                write!(f, "AIR context does not match the trace info and proof options of the proof")
+            }
+            Self::InvalidAssertion(msg) => {
+                write!(f, "invalid additional assertion: {msg}")
+            }
+            Self::UnsupportedHashFunction(Some(hash_id)) => {
+                write!(f, "hash function with identifier {hash_id} is not supported")
+            }
+            Self::UnsupportedHashFunction(None) => {
+                write!(f, "proof does not specify a hash function identifier")
+            }
+            Self::UnsupportedProofOfWork(Some(pow_id)) => {
+                write!(f, "proof-of-work algorithm with identifier {pow_id} is not supported")
+            }
+            Self::UnsupportedProofOfWork(None) => {
+                write!(f, "proof does not specify a proof-of-work identifier")
+            }
+            Self::MismatchedVerificationKey => {
+                write!(f, "proof context does not match the verification key")
+            }
+            Self::UnsupportedAuxHashFunction(Some(id)) => {
+                write!(f, "auxiliary segment hash function with identifier {id} is not supported")
+            }
+            Self::UnsupportedAuxHashFunction(None) => {
+                write!(f, "proof does not specify an auxiliary segment hash function identifier")
+            }
         }
     }
 }
@@ -0,0 +1,163 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use air::{proof::Context, AirContext, ProofOptions, TraceInfo};
+use crypto::Hasher;
+use math::{StarkField, ToElements};
+use utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
+
+// VERIFICATION KEY
+// ================================================================================================
+
+/// Public-input-independent parameters of a statement against which proofs are verified.
+///
+/// A verification key pins the exact proof context expected by the verifier: the shape of the
+/// execution trace, the field in which the computation is defined, proof options, and the hash
+/// function and proof-of-work identifiers (if any). A key is built once per statement, and can
+/// then be shipped to verifiers in serialized form and reused across all proofs of the statement
+/// via `verify_with_key()` function.
+///
+/// Since the key fixes the proof options, the verifier does not need to check them against a set
+/// of [AcceptableOptions](crate::AcceptableOptions); instead, the security level of proofs
+/// accepted with a key is determined when the key is built, and can be queried via
+/// [VerificationKey::security_level()].
+///
+/// The key also caches the field elements derived from the proof context with which the public
+/// coin is seeded, and, optionally, an [AirContext] of the statement (see
+/// [VerificationKey::with_air_context()]). When an AIR context is cached, the AIR is instantiated
+/// for each proof via `Air::with_context()` instead of `Air::new()`, and thus, AIRs which override
+/// that method can skip rebuilding their context (e.g., transition constraint degrees and
+/// divisors) for every proof.
+///
+/// Nothing else is precomputed: constraint divisors and periodic columns are evaluated by the
+/// verifier only at the out-of-domain point, which is drawn anew for every proof, and thus, there
+/// are no public-input-independent values of them to cache. The cached AIR context is also not a
+/// part of the serialized key, and must be attached to a deserialized key again if needed.
+#[derive(Clone, PartialEq, Eq)]
+pub struct VerificationKey<B: StarkField> {
+    context: Context,
+    context_elements: Vec<B>,
+    air_context: Option<AirContext<B>>,
+}
+
+impl<B: StarkField> VerificationKey<B> {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new verification key for proofs of a computation with the specified trace info
+    /// generated using the specified proof options.
+    ///
+    /// The key accepts only proofs which do not record a hash function or a proof-of-work
+    /// identifier; use [VerificationKey::from_context()] to build a key for other proofs.
+    pub fn new(trace_info: TraceInfo, options: ProofOptions) -> Self {
+        Self::from_context(Context::new::<B>(trace_info, options))
+    }
+
+    /// Returns a new verification key which accepts proofs with exactly the specified context.
+    pub fn from_context(context: Context) -> Self {
+        let context_elements = context.to_elements();
+        Self {
+            context,
+            context_elements,
+            air_context: None,
+        }
+    }
+
+    /// Returns this key with the specified AIR context cached in it.
+    ///
+    /// The context must describe the statement for any public inputs against which proofs are
+    /// verified with this key (see `Air::with_context()` for when this is the case).
+    ///
+    /// # Panics
+    /// Panics if trace info or proof options of the `air_context` differ from the ones of the proof
+    /// context pinned by this key.
+    pub fn with_air_context(mut self, air_context: AirContext<B>) -> Self {
+        assert!(
+            air_context.trace_info() == self.trace_info()
+                && air_context.options() == self.options(),
+            "AIR context does not match the proof context of the verification key"
+        );
+        self.air_context = Some(air_context);
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the proof context accepted by this key.
+    pub fn context(&self) -> &Context {
+        &self.context
+    }
+
+    /// Returns the trace info of proofs accepted by this key.
+    pub fn trace_info(&self) -> &TraceInfo {
+        self.context.trace_info()
+    }
+
+    /// Returns the proof options of proofs accepted by this key.
+    pub fn options(&self) -> &ProofOptions {
+        self.context.options()
+    }
+
+    /// Returns the security level (in bits) of proofs accepted by this key when verified using
+    /// the hash function `H`.
+    ///
+    /// Conjectured security is returned when `conjectured` is true, and proven security is
+    /// returned otherwise.
+    pub fn security_level<H: Hasher>(&self, conjectured: bool) -> u32 {
+        self.context.security_level::<H>(conjectured)
+    }
+
+    /// Returns the AIR context cached in this key, if any.
+    pub fn air_context(&self) -> Option<&AirContext<B>> {
+        self.air_context.as_ref()
+    }
+
+    /// Returns the field elements derived from the proof context with which the public coin is
+    /// seeded.
+    pub(crate) fn context_elements(&self) -> &[B] {
+        &self.context_elements
+    }
+}
+
+impl<B: StarkField> fmt::Debug for VerificationKey<B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VerificationKey")
+            .field("context", &self.context)
+            .field("context_elements", &self.context_elements)
+            .field("has_air_context", &self.air_context.is_some())
+            .finish()
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl<B: StarkField> Serializable for VerificationKey<B> {
+    /// Serializes `self` and writes the resulting bytes into the `target`.
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.context.write_into(target);
+    }
+}
+
+impl<B: StarkField> Deserializable for VerificationKey<B> {
+    /// Reads a verification key from the specified `source` and returns the result.
+    ///
+    /// # Errors
+    /// Returns an error if a valid proof context could not be read from the specified `source`,
+    /// or if the context was built for a field other than `B`.
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let context = Context::read_from(source)?;
+        if context.field_modulus_bytes() != B::get_modulus_le_bytes() {
+            return Err(DeserializationError::InvalidValue(
+                "verification key was built for a different field".into(),
+            ));
+        }
+        Ok(Self::from_context(context))
+    }
+}
@@ -15,6 +15,41 @@
 //! 2. Execute [verify()] function and supply the AIR of your computation together with the
 //!    [Proof] and related public inputs as parameters.
 //!
+//! # Streaming verification
+//! For memory-constrained environments, [StreamingVerifier] can be used to verify a proof while
+//! reading it incrementally from a [ByteReader], without holding the entire [Proof] in memory.
+//! This requires the proof to be serialized using the streaming format via
+//! [Proof::write_streaming_into()] (or [Proof::to_streaming_bytes()]).
+//!
+//! # External query positions
+//! When `external-queries` feature is enabled, `verify_with_query_positions()` function can be
+//! used to verify a proof against query positions supplied by the caller rather than drawn from
+//! the public coin. This breaks soundness of the non-interactive protocol, and is intended only
+//! for testing and for building interactive variants of the protocol.
+//!
+//! # Verification cost
+//! When `accounting` feature is enabled, `verify_with_accounting()` function can be used to verify
+//! a proof and obtain the amount of work performed during verification (the number of hash
+//! function invocations, field multiplications, and Merkle path verifications). This can be used
+//! to meter verification by a reproducible metric rather than by wall-clock time. When the
+//! feature is disabled, the operations are not counted and no overhead is incurred.
+//!
+//! # Multiple hash functions
+//! [HashRegistry] can be used to verify proofs generated using any of a set of hash functions,
+//! selecting the hash function at runtime based on the hash function identifier recorded in the
+//! proof context. This allows a single verifier to accept proofs during a migration from one hash
+//! function to another.
+//!
+//! # Spot checks
+//! [verify_spot_check()] function can be used to cheaply check a proof against only a few of its
+//! queries. This is not a sound verification procedure, and is intended only for filtering out
+//! obviously invalid proofs before they are fully verified.
+//!
+//! # WebAssembly
+//! The verifier can be compiled for `wasm32-unknown-unknown` target. When `wasm` feature is
+//! enabled, [verify_bytes()] function can be used to verify serialized proofs from JavaScript via
+//! `wasm-bindgen`.
+//!
 //! # Performance
 //! Proof verification is extremely fast and is nearly independent of the complexity of the
 //! computation being verified. In vast majority of cases proofs can be verified in 3 - 5 ms
@@ -33,18 +68,21 @@ extern crate alloc;
 
 use alloc::{string::ToString, vec::Vec};
 
+use air::{proof::Context, AuxRandElements, GkrVerifier};
 pub use air::{
-    proof::Proof, Air, AirContext, Assertion, BoundaryConstraint, BoundaryConstraintGroup,
-    ConstraintCompositionCoefficients, ConstraintDivisor, DeepCompositionCoefficients,
-    EvaluationFrame, FieldExtension, ProofOptions, TraceInfo, TransitionConstraintDegree,
+    proof::Proof, Air, AirContext, AirWithAssertions, Assertion, BoundaryConstraint,
+    BoundaryConstraintGroup, ConstraintCompositionCoefficients, ConstraintDivisor,
+    DeepCompositionCoefficients, EvaluationFrame, FieldExtension, InputsWithAssertions,
+    ProofOptions, TraceInfo, TransitionConstraintDegree,
 };
-use air::{AuxRandElements, GkrVerifier};
 pub use crypto;
-use crypto::{ElementHasher, Hasher, RandomCoin, VectorCommitment};
-use fri::FriVerifier;
+use crypto::{
+    DefaultProofOfWork, Digest, ElementHasher, Hasher, ProofOfWork, RandomCoin, VectorCommitment,
+};
+use fri::{FriVerifier, VerifierChannel as FriVerifierChannel};
 pub use math;
 use math::{
-    fields::{CubeExtension, QuadExtension},
+    fields::{CubeExtension, QuadExtension, SexticExtension},
     FieldElement, ToElements,
 };
 pub use utils::{
@@ -52,7 +90,7 @@ pub use utils::{
 };
 
 mod channel;
-use channel::VerifierChannel;
+use channel::{ProofChannel, VerifierChannel};
 
 mod evaluator;
 use evaluator::evaluate_constraints;
@@ -63,6 +101,25 @@ use composer::DeepComposer;
 mod errors;
 pub use errors::VerifierError;
 
+mod streaming;
+pub use streaming::StreamingVerifier;
+
+mod registry;
+pub use registry::HashRegistry;
+
+mod key;
+pub use key::VerificationKey;
+
+#[cfg(feature = "accounting")]
+mod accounting;
+#[cfg(feature = "accounting")]
+pub use accounting::{verify_with_accounting, VerificationCost};
+
+#[cfg(feature = "wasm")]
+mod wasm;
+#[cfg(feature = "wasm")]
+pub use wasm::verify_bytes;
+
 // VERIFIER
 // ================================================================================================
 
@@ -72,6 +129,13 @@ pub use errors::VerifierError;
 /// the provided `proof` attests to the correct execution of the computation against public inputs
 /// specified by `pub_inputs`. If the verification is successful, `Ok(())` is returned.
 ///
+/// A proof does not contain any of the values drawn from the public coin (e.g., constraint
+/// composition coefficients, the out-of-domain point, FRI folding challenges, or query
+/// positions). Instead, all of these values are re-derived by the verifier from the proof context,
+/// the public inputs, and the commitments contained in the proof, and all data in the proof which
+/// depends on them is checked against the re-derived values. Thus, a proof assembled using a
+/// transcript inconsistent with its commitments fails verification.
+///
 /// # Errors
 /// Returns an error if combination of the provided proof and public inputs does not attest to
 /// a correct execution of the computation. This could happen for many various reasons, including:
@@ -93,69 +157,715 @@ where
     // of the verifier
     acceptable_options.validate::<HashFn>(&proof)?;
 
-    // build a seed for the public coin; the initial seed is a hash of the proof context and the
-    // public inputs, but as the protocol progresses, the coin will be reseeded with the info
-    // received from the prover
-    let mut public_coin_seed = proof.context.to_elements();
-    public_coin_seed.append(&mut pub_inputs.to_elements());
+    let context_elements = proof.context.to_elements();
+    verify_instance::<AIR, HashFn, RandCoin, VC, HashFn, VC>(
+        proof,
+        context_elements,
+        pub_inputs,
+        new_air,
+        &DefaultProofOfWork,
+        None,
+        None,
+        None,
+    )?;
+    Ok(())
+}
+
+/// Verifies that the specified computation was executed correctly against the specified inputs,
+/// and returns a digest of the public inputs.
+///
+/// This is the same as [verify()], but on success, the digest of the public inputs is returned.
+/// The digest is computed as `HashFn::hash_elements(pub_inputs.to_elements())`. Note that this is
+/// not the seed of the public coin: the seed is built from the serialized proof context followed
+/// by the raw public input elements, and the digest itself is not absorbed into the transcript of
+/// the proof being verified. The digest is, however, the same value as the commitment used by
+/// `Prover::prove_with_committed_inputs()` method, and thus, it can be used with
+/// [verify_with_committed_inputs()] for which it is bound into the transcript.
+///
+/// # Errors
+/// Returns an error under the same conditions as [verify()].
+pub fn verify_returning_digest<AIR, HashFn, RandCoin, VC>(
+    proof: Proof,
+    pub_inputs: AIR::PublicInputs,
+    acceptable_options: &AcceptableOptions,
+) -> Result<HashFn::Digest, VerifierError>
+where
+    AIR: Air,
+    HashFn: ElementHasher<BaseField = AIR::BaseField>,
+    RandCoin: RandomCoin<BaseField = AIR::BaseField, Hasher = HashFn>,
+    VC: VectorCommitment<HashFn>,
+{
+    let pub_inputs_digest = HashFn::hash_elements(&pub_inputs.to_elements());
+    verify::<AIR, HashFn, RandCoin, VC>(proof, pub_inputs, acceptable_options)?;
+    Ok(pub_inputs_digest)
+}
+
+/// Verifies that the specified computation was executed correctly against the specified inputs
+/// using a precomputed AIR context.
+///
+/// This is similar to [verify()], but instead of building a new context for the AIR, the AIR is
+/// instantiated via [Air::with_context()] from the provided `context`. This can be used to
+/// amortize the cost of building the context when many proofs of the same computation are
+/// verified; see [Air::with_context()] for the description of when a context can be reused
+/// across different public inputs.
+///
+/// # Errors
+/// Returns an error under the same conditions as [verify()]. Additionally, returns
+/// [VerifierError::MismatchedAirContext] if trace info or proof options of the `context` differ
+/// from the ones of the proof.
+pub fn verify_with_context<AIR, HashFn, RandCoin, VC>(
+    proof: Proof,
+    pub_inputs: AIR::PublicInputs,
+    context: &AirContext<AIR::BaseField>,
+    acceptable_options: &AcceptableOptions,
+) -> Result<(), VerifierError>
+where
+    AIR: Air,
+    HashFn: ElementHasher<BaseField = AIR::BaseField>,
+    RandCoin: RandomCoin<BaseField = AIR::BaseField, Hasher = HashFn>,
+    VC: VectorCommitment<HashFn>,
+{
+    // check that `proof` was generated with an acceptable set of parameters from the point of view
+    // of the verifier, and that the context was built for this proof
+    acceptable_options.validate::<HashFn>(&proof)?;
+    if context.trace_info() != proof.trace_info() || context.options() != proof.options() {
+        return Err(VerifierError::MismatchedAirContext);
+    }
+
+    // create AIR instance for the computation from the precomputed context
+    let context_elements = proof.context.to_elements();
+    verify_instance::<AIR, HashFn, RandCoin, VC, HashFn, VC>(
+        proof,
+        context_elements,
+        pub_inputs,
+        |_, pub_inputs| Ok(AIR::with_context(context, pub_inputs)),
+        &DefaultProofOfWork,
+        None,
+        None,
+        None,
+    )?;
+    Ok(())
+}
+
+/// Verifies that the specified computation was executed correctly against the specified inputs
+/// using a verification key of the statement.
+///
+/// This is similar to [verify()], but instead of checking the parameters of the proof against a
+/// set of acceptable options, the proof context must be exactly the same as the context pinned
+/// by the `key`; the public coin is then seeded with the context elements cached in the key, and
+/// the AIR is instantiated from the AIR context cached in the key (if any). The security level of
+/// accepted proofs is thus determined by the key (see [VerificationKey::security_level()]).
+///
+/// The proof-of-work algorithm is selected based on the identifier pinned by the key: keys
+/// without an identifier accept proofs checked using [DefaultProofOfWork]. Proofs generated using
+/// other algorithms must be verified via [verify_with_key_and_proof_of_work()].
+///
+/// # Errors
+/// Returns an error under the same conditions as [verify()] (except for the errors related to
+/// acceptable options). Additionally, returns:
+/// - [VerifierError::MismatchedVerificationKey] if the context of the proof differs from the
+///   context pinned by the `key`.
+/// - [VerifierError::UnsupportedProofOfWork] if the `key` pins a proof-of-work identifier.
+pub fn verify_with_key<AIR, HashFn, RandCoin, VC>(
+    proof: Proof,
+    pub_inputs: AIR::PublicInputs,
+    key: &VerificationKey<AIR::BaseField>,
+) -> Result<(), VerifierError>
+where
+    AIR: Air,
+    HashFn: ElementHasher<BaseField = AIR::BaseField>,
+    RandCoin: RandomCoin<BaseField = AIR::BaseField, Hasher = HashFn>,
+    VC: VectorCommitment<HashFn>,
+{
+    match key.context().pow_id() {
+        None => verify_with_key_and_proof_of_work::<AIR, HashFn, RandCoin, VC>(
+            proof,
+            pub_inputs,
+            key,
+            &DefaultProofOfWork,
+        ),
+        Some(pow_id) => Err(VerifierError::UnsupportedProofOfWork(Some(pow_id))),
+    }
+}
+
+/// Verifies that the specified computation was executed correctly against the specified inputs
+/// using a verification key of the statement for a proof generated using the specified
+/// proof-of-work algorithm.
+///
+/// This is the same as [verify_with_key()], but the proof-of-work nonce supplied in the proof is
+/// checked using `pow` (see [verify_with_proof_of_work()]).
+///
+/// # Errors
+/// Returns an error under the same conditions as [verify_with_key()], except that
+/// [VerifierError::UnsupportedProofOfWork] is returned if the proof-of-work identifier pinned by
+/// the `key` (or the lack of one) differs from the identifier of `pow`.
+pub fn verify_with_key_and_proof_of_work<AIR, HashFn, RandCoin, VC>(
+    proof: Proof,
+    pub_inputs: AIR::PublicInputs,
+    key: &VerificationKey<AIR::BaseField>,
+    pow: &dyn ProofOfWork<RandCoin>,
+) -> Result<(), VerifierError>
+where
+    AIR: Air,
+    HashFn: ElementHasher<BaseField = AIR::BaseField>,
+    RandCoin: RandomCoin<BaseField = AIR::BaseField, Hasher = HashFn>,
+    VC: VectorCommitment<HashFn>,
+{
+    if &proof.context != key.context() {
+        return Err(VerifierError::MismatchedVerificationKey);
+    }
+
+    verify_instance::<AIR, HashFn, RandCoin, VC, HashFn, VC>(
+        proof,
+        key.context_elements().to_vec(),
+        pub_inputs,
+        |proof, pub_inputs| match key.air_context() {
+            Some(context) => Ok(AIR::with_context(context, pub_inputs)),
+            None => new_air(proof, pub_inputs),
+        },
+        pow,
+        None,
+        None,
+        None,
+    )?;
+    Ok(())
+}
+
+/// Verifies that the specified computation was executed correctly against the specified inputs
+/// and satisfies the specified additional assertions.
+///
+/// This is similar to [verify()], but in addition to the assertions defined by `AIR`, the
+/// execution trace must also satisfy the provided `assertions` against its main segment. This
+/// allows the verifier to enforce boundary conditions which are not hardcoded into the AIR (e.g.,
+/// a deployment-specific public output).
+///
+/// The proof must have been generated for [AirWithAssertions] wrapping `AIR` with public inputs
+/// consisting of `pub_inputs` and the same set of `assertions`. The assertions are absorbed into
+/// the public coin together with the public inputs, and thus, a proof generated against a
+/// different set of assertions (or against `AIR` itself) will not verify.
+///
+/// # Errors
+/// Returns an error under the same conditions as [verify()]. Additionally, returns
+/// [VerifierError::InvalidAssertion] if any of the provided assertions is placed against a
+/// column or a step which does not exist in the trace, or if it overlaps with another assertion.
+pub fn verify_with_assertions<AIR, HashFn, RandCoin, VC>(
+    proof: Proof,
+    pub_inputs: AIR::PublicInputs,
+    assertions: Vec<Assertion<AIR::BaseField>>,
+    acceptable_options: &AcceptableOptions,
+) -> Result<(), VerifierError>
+where
+    AIR: Air,
+    HashFn: ElementHasher<BaseField = AIR::BaseField>,
+    RandCoin: RandomCoin<BaseField = AIR::BaseField, Hasher = HashFn>,
+    VC: VectorCommitment<HashFn>,
+{
+    // check that `proof` was generated with an acceptable set of parameters from the point of view
+    // of the verifier
+    acceptable_options.validate::<HashFn>(&proof)?;
+
+    // the additional assertions are a part of the public inputs, and thus, are absorbed into the
+    // public coin together with them; the assertions must also be valid for the AIR, otherwise,
+    // building boundary constraints would panic
+    let context_elements = proof.context.to_elements();
+    verify_instance::<AirWithAssertions<AIR>, HashFn, RandCoin, VC, HashFn, VC>(
+        proof,
+        context_elements,
+        InputsWithAssertions::new(pub_inputs, assertions),
+        |proof, pub_inputs| {
+            let air = new_air::<AirWithAssertions<AIR>>(proof, pub_inputs)?;
+            air.validate_assertions().map_err(VerifierError::InvalidAssertion)?;
+            Ok(air)
+        },
+        &DefaultProofOfWork,
+        None,
+        None,
+        None,
+    )?;
+    Ok(())
+}
+
+/// Verifies that the specified computation was executed correctly against public inputs
+/// committed to by the specified commitment.
+///
+/// This is similar to [verify()], but instead of the public inputs, the verifier receives only
+/// a commitment to them. This is intended to be used with proofs generated via
+/// `Prover::prove_with_committed_inputs()` method, where the commitment is computed by hashing
+/// the public inputs with `HashFn`, and is then absorbed into the public coin in place of the
+/// public inputs.
+///
+/// An instance of `AIR` is constructed from the bytes of the commitment alone, and thus, the AIR
+/// must not depend on any information about the public inputs not captured by the commitment.
+///
+/// Successful verification shows only that the proof was generated against the specified
+/// commitment. The commitment is not opened and the trace is not constrained against the
+/// committed inputs, and thus, this does not show that the proven computation consumed public
+/// inputs which hash to the commitment. Any such relationship must be enforced by `AIR` itself.
+///
+/// # Errors
+/// Returns an error if combination of the provided proof and public inputs commitment does not
+/// attest to a correct execution of the computation. In addition to the reasons listed for
+/// [verify()], this could happen if the specified proof was generated against public inputs
+/// which do not match the provided commitment.
+pub fn verify_with_committed_inputs<AIR, HashFn, RandCoin, VC>(
+    proof: Proof,
+    pub_inputs_commitment: HashFn::Digest,
+    acceptable_options: &AcceptableOptions,
+) -> Result<(), VerifierError>
+where
+    AIR: Air,
+    AIR::PublicInputs: From<[u8; 32]>,
+    HashFn: ElementHasher<BaseField = AIR::BaseField>,
+    RandCoin: RandomCoin<BaseField = AIR::BaseField, Hasher = HashFn>,
+    VC: VectorCommitment<HashFn>,
+{
+    // check that `proof` was generated with an acceptable set of parameters from the point of view
+    // of the verifier
+    acceptable_options.validate::<HashFn>(&proof)?;
+
+    // build the public coin; the initial seed is a hash of the proof context, and immediately
+    // after that the coin is reseeded with the commitment to the public inputs
+    let mut public_coin = RandCoin::new(&proof.context.to_elements());
+    public_coin.reseed(pub_inputs_commitment);
+
+    // create AIR instance for the computation specified in the proof
+    let air = AIR::new(
+        proof.trace_info().clone(),
+        pub_inputs_commitment.as_bytes().into(),
+        proof.options().clone(),
+    );
+
+    verify_with_air::<AIR, HashFn, RandCoin, VC, HashFn, VC>(
+        air,
+        proof,
+        public_coin,
+        &DefaultProofOfWork,
+        None,
+        None,
+        None,
+    )?;
+    Ok(())
+}
+
+/// Verifies that the specified computation was executed correctly against the specified inputs
+/// using the specified query positions.
+///
+/// This is the same as [verify()], but instead of drawing query positions from the public coin,
+/// the verifier uses `query_positions` (these must be the same positions as the ones used by the
+/// prover to generate the proof). This is intended for testing the verifier against specific
+/// positions in the LDE domain, and for building interactive variants of the protocol in which
+/// the verifier chooses the queries.
+///
+/// **This breaks soundness of the non-interactive protocol**: a prover who knows the query
+/// positions in advance can forge a proof for an invalid execution. Thus, this function must not
+/// be used to verify proofs generated in a non-interactive setting.
+///
+/// # Errors
+/// Returns an error under the same conditions as [verify()], as well as if `query_positions` is
+/// empty or contains positions outside of the LDE domain.
+#[cfg(feature = "external-queries")]
+pub fn verify_with_query_positions<AIR, HashFn, RandCoin, VC>(
+    proof: Proof,
+    pub_inputs: AIR::PublicInputs,
+    acceptable_options: &AcceptableOptions,
+    query_positions: &[usize],
+) -> Result<(), VerifierError>
+where
+    AIR: Air,
+    HashFn: ElementHasher<BaseField = AIR::BaseField>,
+    RandCoin: RandomCoin<BaseField = AIR::BaseField, Hasher = HashFn>,
+    VC: VectorCommitment<HashFn>,
+{
+    acceptable_options.validate::<HashFn>(&proof)?;
+
+    let lde_domain_size = proof.lde_domain_size();
+    if query_positions.is_empty() || query_positions.iter().any(|&p| p >= lde_domain_size) {
+        return Err(VerifierError::InvalidQueryPositions);
+    }
+
+    let context_elements = proof.context.to_elements();
+    verify_instance::<AIR, HashFn, RandCoin, VC, HashFn, VC>(
+        proof,
+        context_elements,
+        pub_inputs,
+        new_air,
+        &DefaultProofOfWork,
+        None,
+        Some(query_positions),
+        None,
+    )?;
+    Ok(())
+}
+
+/// Verifies that the specified computation was executed correctly against the specified inputs
+/// using the provided `public_coin` as the Fiat-Shamir transcript.
+///
+/// This is the same as [verify()], but instead of instantiating a new public coin, the provided
+/// coin is reseeded with a hash of the proof context and the public inputs, and is then used to
+/// draw all randomness needed to verify the proof. This allows a STARK proof to be verified as a
+/// part of a larger Fiat-Shamir transcript, and is intended to be used with proofs generated via
+/// `Prover::prove_with_coin()` method, where the prover's coin was in the same state as the
+/// provided `public_coin`.
+///
+/// On success, the public coin in the state it is in at the end of the verification is
+/// returned; this state is the same as the state of the prover's coin at the end of proof
+/// generation, and thus, can be used to continue the transcript.
+///
+/// # Errors
+/// Returns an error under the same conditions as [verify()].
+pub fn verify_with_coin<AIR, HashFn, RandCoin, VC>(
+    proof: Proof,
+    pub_inputs: AIR::PublicInputs,
+    acceptable_options: &AcceptableOptions,
+    mut public_coin: RandCoin,
+) -> Result<RandCoin, VerifierError>
+where
+    AIR: Air,
+    HashFn: ElementHasher<BaseField = AIR::BaseField>,
+    RandCoin: RandomCoin<BaseField = AIR::BaseField, Hasher = HashFn>,
+    VC: VectorCommitment<HashFn>,
+{
+    // check that `proof` was generated with an acceptable set of parameters from the point of view
+    // of the verifier
+    acceptable_options.validate::<HashFn>(&proof)?;
+
+    // reseed the coin with a hash of the proof context and the public inputs
+    let mut seed_elements = proof.context.to_elements();
+    seed_elements.append(&mut pub_inputs.to_elements());
+    public_coin.reseed(HashFn::hash_elements(&seed_elements));
 
     // create AIR instance for the computation specified in the proof
     let air = AIR::new(proof.trace_info().clone(), pub_inputs, proof.options().clone());
 
+    verify_with_air::<AIR, HashFn, RandCoin, VC, HashFn, VC>(
+        air,
+        proof,
+        public_coin,
+        &DefaultProofOfWork,
+        None,
+        None,
+        None,
+    )
+}
+
+/// Verifies that the specified computation was executed correctly against the specified inputs
+/// for a proof in which the auxiliary trace segment was committed to using a different hash
+/// function than the rest of the proof.
+///
+/// This is the same as [verify()], but the commitment to the auxiliary trace segment and the
+/// openings of the queried auxiliary trace rows are checked using `AuxHashFn` and `AuxVC`. These
+/// must be the same as the hash function and vector commitment scheme used by the prover for the
+/// auxiliary segment (i.e., `TraceLde::AuxHashFn` and `TraceLde::AuxVC` of the prover's trace
+/// LDE). The choice of the auxiliary segment hash function is recorded in the proof context via
+/// the identifier returned from `Prover::aux_hash_id()`, and `aux_hash_id` must be the identifier
+/// which the application assigned to `AuxHashFn`.
+///
+/// # Errors
+/// Returns an error under the same conditions as [verify()]. Security level of the proof is
+/// checked against collision resistance of both `HashFn` and `AuxHashFn`. Additionally, returns
+/// [VerifierError::UnsupportedAuxHashFunction] if the auxiliary segment hash function identifier
+/// recorded in the proof context (or the lack of one) differs from `aux_hash_id`.
+pub fn verify_with_aux_hash<AIR, HashFn, RandCoin, VC, AuxHashFn, AuxVC>(
+    proof: Proof,
+    pub_inputs: AIR::PublicInputs,
+    aux_hash_id: u8,
+    acceptable_options: &AcceptableOptions,
+) -> Result<(), VerifierError>
+where
+    AIR: Air,
+    HashFn: ElementHasher<BaseField = AIR::BaseField>,
+    RandCoin: RandomCoin<BaseField = AIR::BaseField, Hasher = HashFn>,
+    VC: VectorCommitment<HashFn>,
+    AuxHashFn: ElementHasher<BaseField = AIR::BaseField, Digest: Into<HashFn::Digest>>,
+    AuxVC: VectorCommitment<AuxHashFn>,
+{
+    // check that `proof` was generated with an acceptable set of parameters from the point of view
+    // of the verifier; the security of the proof is bounded by both hash functions
+    acceptable_options.validate::<HashFn>(&proof)?;
+    acceptable_options.validate::<AuxHashFn>(&proof)?;
+
+    let context_elements = proof.context.to_elements();
+    verify_instance::<AIR, HashFn, RandCoin, VC, AuxHashFn, AuxVC>(
+        proof,
+        context_elements,
+        pub_inputs,
+        new_air,
+        &DefaultProofOfWork,
+        Some(aux_hash_id),
+        None,
+        None,
+    )?;
+    Ok(())
+}
+
+/// Verifies that the specified computation was executed correctly against the specified inputs
+/// for a proof generated using the specified proof-of-work algorithm.
+///
+/// This is the same as [verify()], but the proof-of-work nonce supplied in the proof is checked
+/// using `pow` instead of [DefaultProofOfWork]. This is intended to be used with proofs generated
+/// by a prover which overrides `Prover::proof_of_work()` method; the identifier of the algorithm
+/// is recorded in the proof context, and must match the identifier of `pow`.
+///
+/// # Errors
+/// Returns an error under the same conditions as [verify()]. Additionally, returns
+/// [VerifierError::UnsupportedProofOfWork] if the proof-of-work identifier recorded in the proof
+/// context (or the lack of one) differs from the identifier of `pow`.
+pub fn verify_with_proof_of_work<AIR, HashFn, RandCoin, VC>(
+    proof: Proof,
+    pub_inputs: AIR::PublicInputs,
+    acceptable_options: &AcceptableOptions,
+    pow: &dyn ProofOfWork<RandCoin>,
+) -> Result<(), VerifierError>
+where
+    AIR: Air,
+    HashFn: ElementHasher<BaseField = AIR::BaseField>,
+    RandCoin: RandomCoin<BaseField = AIR::BaseField, Hasher = HashFn>,
+    VC: VectorCommitment<HashFn>,
+{
+    acceptable_options.validate::<HashFn>(&proof)?;
+
+    let context_elements = proof.context.to_elements();
+    verify_instance::<AIR, HashFn, RandCoin, VC, HashFn, VC>(
+        proof,
+        context_elements,
+        pub_inputs,
+        new_air,
+        pow,
+        None,
+        None,
+        None,
+    )?;
+    Ok(())
+}
+
+/// Performs a cheap spot check of the specified proof against the specified inputs.
+///
+/// **This is NOT a sound verification procedure**, and a proof which passes the spot check may
+/// still be invalid. This is intended only for quickly filtering out obviously invalid proofs
+/// (e.g., when pre-screening a large batch of proofs before fully verifying the remaining ones
+/// via [verify()]).
+///
+/// The spot check runs the same pipeline as [verify()], including the out-of-domain consistency
+/// check and the proof-of-work check, but:
+/// - Only the first `num_queries` distinct query positions drawn from the public coin are
+///   checked for consistency across the DEEP composition and all FRI layers.
+/// - Queried values are not checked against trace, constraint, and FRI layer commitments.
+/// - Security level of the proof is not checked against any acceptable options.
+///
+/// # Errors
+/// Returns an error if the spot check detects that the provided proof does not attest to a
+/// correct execution of the computation against the specified public inputs.
+pub fn verify_spot_check<AIR, HashFn, RandCoin, VC>(
+    proof: Proof,
+    pub_inputs: AIR::PublicInputs,
+    num_queries: usize,
+) -> Result<(), VerifierError>
+where
+    AIR: Air,
+    HashFn: ElementHasher<BaseField = AIR::BaseField>,
+    RandCoin: RandomCoin<BaseField = AIR::BaseField, Hasher = HashFn>,
+    VC: VectorCommitment<HashFn>,
+{
+    let context_elements = proof.context.to_elements();
+    verify_instance::<AIR, HashFn, RandCoin, VC, HashFn, VC>(
+        proof,
+        context_elements,
+        pub_inputs,
+        new_air,
+        &DefaultProofOfWork,
+        None,
+        None,
+        Some(num_queries),
+    )?;
+    Ok(())
+}
+
+// VERIFICATION PROCEDURE
+// ================================================================================================
+
+/// Verifies the proof against an instance of the computation built from the specified public
+/// inputs.
+///
+/// The public coin is seeded with `context_elements` (the elements of the proof context) followed
+/// by the elements of `pub_inputs`; as the protocol progresses, the coin is reseeded with the info
+/// received from the prover. The AIR for the computation is then built from the proof and the
+/// public inputs by `build_air`, and the proof is verified against it via [verify_with_air()].
+#[allow(clippy::too_many_arguments)]
+fn verify_instance<AIR, HashFn, RandCoin, VC, AuxHashFn, AuxVC>(
+    proof: Proof,
+    context_elements: Vec<AIR::BaseField>,
+    pub_inputs: AIR::PublicInputs,
+    build_air: impl FnOnce(&Proof, AIR::PublicInputs) -> Result<AIR, VerifierError>,
+    pow: &dyn ProofOfWork<RandCoin>,
+    aux_hash_id: Option<u8>,
+    query_positions: Option<&[usize]>,
+    spot_checks: Option<usize>,
+) -> Result<RandCoin, VerifierError>
+where
+    AIR: Air,
+    HashFn: ElementHasher<BaseField = AIR::BaseField>,
+    RandCoin: RandomCoin<BaseField = AIR::BaseField, Hasher = HashFn>,
+    VC: VectorCommitment<HashFn>,
+    AuxHashFn: ElementHasher<BaseField = AIR::BaseField, Digest: Into<HashFn::Digest>>,
+    AuxVC: VectorCommitment<AuxHashFn>,
+{
+    let mut public_coin_seed = context_elements;
+    public_coin_seed.append(&mut pub_inputs.to_elements());
+    let public_coin = RandCoin::new(&public_coin_seed);
+
+    let air = build_air(&proof, pub_inputs)?;
+    verify_with_air::<AIR, HashFn, RandCoin, VC, AuxHashFn, AuxVC>(
+        air,
+        proof,
+        public_coin,
+        pow,
+        aux_hash_id,
+        query_positions,
+        spot_checks,
+    )
+}
+
+/// Returns an instance of `AIR` for the computation specified in the proof.
+fn new_air<AIR: Air>(proof: &Proof, pub_inputs: AIR::PublicInputs) -> Result<AIR, VerifierError> {
+    Ok(AIR::new(proof.trace_info().clone(), pub_inputs, proof.options().clone()))
+}
+
+/// Verifies the proof against the provided `air` using a public coin already seeded with the
+/// proof context and public inputs.
+///
+/// The `aux_hash_id` is the identifier of `AuxHashFn` expected in the proof context, or None if
+/// the auxiliary trace segment is expected to be committed to using `HashFn`.
+///
+/// If `query_positions` are provided, they are used in place of the query positions drawn from
+/// the public coin. If `spot_checks` is provided, only a spot check of the specified number of
+/// queries is performed (see [verify_spot_check()]). On success, the public coin in the state it
+/// is in at the end of the protocol is returned.
+fn verify_with_air<AIR, HashFn, RandCoin, VC, AuxHashFn, AuxVC>(
+    air: AIR,
+    proof: Proof,
+    public_coin: RandCoin,
+    pow: &dyn ProofOfWork<RandCoin>,
+    aux_hash_id: Option<u8>,
+    query_positions: Option<&[usize]>,
+    spot_checks: Option<usize>,
+) -> Result<RandCoin, VerifierError>
+where
+    AIR: Air,
+    HashFn: ElementHasher<BaseField = AIR::BaseField>,
+    RandCoin: RandomCoin<BaseField = AIR::BaseField, Hasher = HashFn>,
+    VC: VectorCommitment<HashFn>,
+    AuxHashFn: ElementHasher<BaseField = AIR::BaseField, Digest: Into<HashFn::Digest>>,
+    AuxVC: VectorCommitment<AuxHashFn>,
+{
+    // make sure the proof-of-work algorithm and the auxiliary segment hash function recorded in
+    // the proof are the ones used to check it
+    if proof.context.pow_id() != pow.id() {
+        return Err(VerifierError::UnsupportedProofOfWork(proof.context.pow_id()));
+    }
+    if proof.context.aux_hash_id() != aux_hash_id {
+        return Err(VerifierError::UnsupportedAuxHashFunction(proof.context.aux_hash_id()));
+    }
+
     // figure out which version of the generic proof verification procedure to run. this is a sort
     // of static dispatch for selecting two generic parameter: extension field and hash function.
     match air.options().field_extension() {
         FieldExtension::None => {
-            let public_coin = RandCoin::new(&public_coin_seed);
-            let channel = VerifierChannel::new(&air, proof)?;
-            perform_verification::<AIR, AIR::BaseField, HashFn, RandCoin, VC>(
-                air,
+            let channel =
+                VerifierChannel::<AIR::BaseField, HashFn, VC, AuxHashFn, AuxVC>::new(&air, proof)?;
+            perform_verification::<AIR, AIR::BaseField, HashFn, RandCoin, VC, _>(
+                &air,
                 channel,
                 public_coin,
+                pow,
+                query_positions,
+                spot_checks,
             )
         },
         FieldExtension::Quadratic => {
             if !<QuadExtension<AIR::BaseField>>::is_supported() {
                 return Err(VerifierError::UnsupportedFieldExtension(2));
             }
-            let public_coin = RandCoin::new(&public_coin_seed);
-            let channel = VerifierChannel::new(&air, proof)?;
-            perform_verification::<AIR, QuadExtension<AIR::BaseField>, HashFn, RandCoin, VC>(
-                air,
+            let channel = VerifierChannel::<
+                QuadExtension<AIR::BaseField>,
+                HashFn,
+                VC,
+                AuxHashFn,
+                AuxVC,
+            >::new(&air, proof)?;
+            perform_verification::<AIR, QuadExtension<AIR::BaseField>, HashFn, RandCoin, VC, _>(
+                &air,
                 channel,
                 public_coin,
+                pow,
+                query_positions,
+                spot_checks,
             )
         },
         FieldExtension::Cubic => {
             if !<CubeExtension<AIR::BaseField>>::is_supported() {
                 return Err(VerifierError::UnsupportedFieldExtension(3));
             }
-            let public_coin = RandCoin::new(&public_coin_seed);
-            let channel = VerifierChannel::new(&air, proof)?;
-            perform_verification::<AIR, CubeExtension<AIR::BaseField>, HashFn, RandCoin, VC>(
-                air,
+            let channel = VerifierChannel::<
+                CubeExtension<AIR::BaseField>,
+                HashFn,
+                VC,
+                AuxHashFn,
+                AuxVC,
+            >::new(&air, proof)?;
+            perform_verification::<AIR, CubeExtension<AIR::BaseField>, HashFn, RandCoin, VC, _>(
+                &air,
                 channel,
                 public_coin,
+                pow,
+                query_positions,
+                spot_checks,
+            )
+        },
+        FieldExtension::Sextic => {
+            if !<SexticExtension<AIR::BaseField>>::is_supported() {
+                return Err(VerifierError::UnsupportedFieldExtension(6));
+            }
+            let channel = VerifierChannel::<
+                SexticExtension<AIR::BaseField>,
+                HashFn,
+                VC,
+                AuxHashFn,
+                AuxVC,
+            >::new(&air, proof)?;
+            perform_verification::<AIR, SexticExtension<AIR::BaseField>, HashFn, RandCoin, VC, _>(
+                &air,
+                channel,
+                public_coin,
+                pow,
+                query_positions,
+                spot_checks,
             )
         },
     }
 }
 
-// VERIFICATION PROCEDURE
-// ================================================================================================
 /// Performs the actual verification by reading the data from the `channel` and making sure it
 /// attests to a correct execution of the computation specified by the provided `air`.
-fn perform_verification<A, E, H, R, V>(
-    air: A,
-    mut channel: VerifierChannel<E, H, V>,
+///
+/// If `spot_checks` is provided, only the specified number of queries is checked, and the
+/// queried values are not checked against the commitments (see [verify_spot_check()]).
+///
+/// On success, the public coin in the state it is in at the end of the protocol is returned.
+fn perform_verification<A, E, H, R, V, C>(
+    air: &A,
+    mut channel: C,
     mut public_coin: R,
-) -> Result<(), VerifierError>
+    pow: &dyn ProofOfWork<R>,
+    query_positions: Option<&[usize]>,
+    spot_checks: Option<usize>,
+) -> Result<R, VerifierError>
 where
     E: FieldElement<BaseField = A::BaseField>,
     A: Air,
     H: ElementHasher<BaseField = A::BaseField>,
     R: RandomCoin<BaseField = A::BaseField, Hasher = H>,
     V: VectorCommitment<H>,
+    C: ProofChannel<E> + FriVerifierChannel<E, Hasher = H, VectorCommitment = V>,
 {
     // 1 ----- trace commitment -------------------------------------------------------------------
     // Read the commitments to evaluations of the trace polynomials over the LDE domain sent by the
@@ -166,12 +876,8 @@ where
     // used to draw random elements needed to construct the next trace segment. The last trace
     // commitment is used to draw a set of random coefficients which the prover uses to compute
     // constraint composition polynomial.
-    const MAIN_TRACE_IDX: usize = 0;
-    const AUX_TRACE_IDX: usize = 1;
-    let trace_commitments = channel.read_trace_commitments();
-
     // reseed the coin with the commitment to the main trace segment
-    public_coin.reseed(trace_commitments[MAIN_TRACE_IDX]);
+    public_coin.reseed(channel.read_main_trace_commitment());
 
     // process auxiliary trace segments (if any), to build a set of random elements for each segment
     let aux_trace_rand_elements = if air.trace_info().is_multi_segment() {
@@ -193,7 +899,10 @@ where
                 "failed to generate the random elements needed to build the auxiliary trace",
             );
 
-            public_coin.reseed(trace_commitments[AUX_TRACE_IDX]);
+            let aux_trace_commitment = channel
+                .read_aux_trace_commitment()
+                .expect("expected a commitment to the auxiliary trace segment");
+            public_coin.reseed(aux_trace_commitment);
 
             Some(AuxRandElements::new_with_gkr(rand_elements, gkr_rand_elements))
         } else {
@@ -201,7 +910,10 @@ where
                 "failed to generate the random elements needed to build the auxiliary trace",
             );
 
-            public_coin.reseed(trace_commitments[AUX_TRACE_IDX]);
+            let aux_trace_commitment = channel
+                .read_aux_trace_commitment()
+                .expect("expected a commitment to the auxiliary trace segment");
+            public_coin.reseed(aux_trace_commitment);
 
             Some(AuxRandElements::new(rand_elements))
         }
@@ -232,11 +944,12 @@ where
     // provided) sent by the prover and evaluate constraints over them; also, reseed the public
     // coin with the OOD frames received from the prover.
     let ood_trace_frame = channel.read_ood_trace_frame();
-    let ood_main_trace_frame = ood_trace_frame.main_frame();
+    let mut ood_main_trace_frame = ood_trace_frame.main_frame();
+    ood_main_trace_frame.evaluate_virtual_columns(air);
     let ood_aux_trace_frame = ood_trace_frame.aux_frame();
     let ood_lagrange_kernel_frame = ood_trace_frame.lagrange_kernel_frame();
     let ood_constraint_evaluation_1 = evaluate_constraints(
-        &air,
+        air,
         constraint_coeffs,
         &ood_main_trace_frame,
         &ood_aux_trace_frame,
@@ -263,9 +976,12 @@ where
             });
     public_coin.reseed(H::hash_elements(&ood_constraint_evaluations));
 
-    // finally, make sure the values are the same
-    if ood_constraint_evaluation_1 != ood_constraint_evaluation_2 {
-        return Err(VerifierError::InconsistentOodConstraintEvaluations);
+    // finally, make sure the values are the same; the values are compared in constant time
+    if !bool::from(ood_constraint_evaluation_1.ct_eq(&ood_constraint_evaluation_2)) {
+        return Err(VerifierError::InconsistentOodConstraintEvaluation {
+            expected: ood_constraint_evaluation_2.to_string(),
+            actual: ood_constraint_evaluation_1.to_string(),
+        });
     }
 
     // 4 ----- FRI commitments --------------------------------------------------------------------
@@ -296,17 +1012,34 @@ where
     let pow_nonce = channel.read_pow_nonce();
 
     // make sure the proof-of-work specified by the grinding factor is satisfied
-    if public_coin.check_leading_zeros(pow_nonce) < air.options().grinding_factor() {
+    if !pow.verify(&public_coin, air.options().grinding_factor(), pow_nonce) {
         return Err(VerifierError::QuerySeedProofOfWorkVerificationFailed);
     }
 
-    // draw pseudo-random query positions for the LDE domain from the public coin; in the
-    // interactive version of the protocol, the verifier sends these query positions to the prover,
-    // and the prover responds with decommitments against these positions for trace and constraint
-    // composition polynomial evaluations.
-    let mut query_positions = public_coin
-        .draw_integers(air.options().num_queries(), air.lde_domain_size(), pow_nonce)
-        .map_err(|_| VerifierError::RandomCoinError)?;
+    // draw pseudo-random query positions for the LDE domain from the public coin (unless they
+    // were supplied externally); in the interactive version of the protocol, the verifier sends
+    // these query positions to the prover, and the prover responds with decommitments against
+    // these positions for trace and constraint composition polynomial evaluations.
+    let mut query_positions = match query_positions {
+        Some(positions) => positions.to_vec(),
+        None => public_coin
+            .draw_integers(air.options().num_queries(), air.lde_domain_size(), pow_nonce)
+            .map_err(|_| VerifierError::RandomCoinError)?,
+    };
+
+    // in spot check mode, only the first few distinct queries drawn from the coin are checked
+    let spot_check_sample = spot_checks.map(|num_queries| {
+        let mut sample = Vec::with_capacity(num_queries);
+        for &position in query_positions.iter() {
+            if sample.len() == num_queries {
+                break;
+            }
+            if !sample.contains(&position) {
+                sample.push(position);
+            }
+        }
+        sample
+    });
 
     // remove any potential duplicates from the positions as the prover will send openings only
     // for unique queries
@@ -314,14 +1047,24 @@ where
     query_positions.dedup();
 
     // read evaluations of trace and constraint composition polynomials at the queried positions;
-    // this also checks that the read values are valid against trace and constraint commitments
-    let (queried_main_trace_states, queried_aux_trace_states) =
-        channel.read_queried_trace_states(&query_positions)?;
-    let queried_constraint_evaluations = channel.read_constraint_evaluations(&query_positions)?;
+    // this also checks that the read values are valid against trace and constraint commitments,
+    // unless only a spot check is performed
+    let ((queried_main_trace_states, queried_aux_trace_states), queried_constraint_evaluations) =
+        if spot_check_sample.is_some() {
+            (
+                channel.read_unchecked_trace_states()?,
+                channel.read_unchecked_constraint_evaluations()?,
+            )
+        } else {
+            (
+                channel.read_queried_trace_states(&query_positions)?,
+                channel.read_constraint_evaluations(&query_positions)?,
+            )
+        };
 
     // 6 ----- DEEP composition -------------------------------------------------------------------
     // compute evaluations of the DEEP composition polynomial at the queried positions
-    let composer = DeepComposer::new(&air, &query_positions, z, deep_coefficients);
+    let composer = DeepComposer::new(air, &query_positions, z, deep_coefficients);
     let t_composition = composer.compose_trace_columns(
         queried_main_trace_states,
         queried_aux_trace_states,
@@ -336,9 +1079,25 @@ where
     // 7 ----- Verify low-degree proof -------------------------------------------------------------
     // make sure that evaluations of the DEEP composition polynomial we computed in the previous
     // step are in fact evaluations of a polynomial of degree equal to trace polynomial degree
-    fri_verifier
-        .verify(&mut channel, &deep_evaluations, &query_positions)
-        .map_err(VerifierError::FriVerificationFailed)
+    if let Some(sample) = spot_check_sample {
+        // in spot check mode, only the evaluations at the sampled positions are checked
+        let sample_evaluations = sample
+            .iter()
+            .map(|position| {
+                let idx = query_positions.binary_search(position).expect("position not queried");
+                deep_evaluations[idx]
+            })
+            .collect::<Vec<_>>();
+        fri_verifier
+            .spot_check(&mut channel, &sample_evaluations, &query_positions, &sample)
+            .map_err(VerifierError::FriVerificationFailed)?;
+    } else {
+        fri_verifier
+            .verify(&mut channel, &deep_evaluations, &query_positions)
+            .map_err(VerifierError::FriVerificationFailed)?;
+    }
+
+    Ok(public_coin)
 }
 
 // ACCEPTABLE OPTIONS
@@ -357,9 +1116,15 @@ pub enum AcceptableOptions {
 impl AcceptableOptions {
     /// Checks that a proof was generated using an acceptable set of parameters.
     pub fn validate<H: Hasher>(&self, proof: &Proof) -> Result<(), VerifierError> {
+        self.validate_context::<H>(&proof.context)
+    }
+
+    /// Checks that a proof with the specified context was generated using an acceptable set of
+    /// parameters.
+    pub fn validate_context<H: Hasher>(&self, context: &Context) -> Result<(), VerifierError> {
         match self {
             AcceptableOptions::MinConjecturedSecurity(minimal_security) => {
-                let proof_security = proof.security_level::<H>(true);
+                let proof_security = context.security_level::<H>(true);
                 if proof_security < *minimal_security {
                     return Err(VerifierError::InsufficientConjecturedSecurity(
                         *minimal_security,
@@ -368,7 +1133,7 @@ impl AcceptableOptions {
                 }
             },
             AcceptableOptions::MinProvenSecurity(minimal_security) => {
-                let proof_security = proof.security_level::<H>(false);
+                let proof_security = context.security_level::<H>(false);
                 if proof_security < *minimal_security {
                     return Err(VerifierError::InsufficientProvenSecurity(
                         *minimal_security,
@@ -377,7 +1142,7 @@ impl AcceptableOptions {
                 }
             },
             AcceptableOptions::OptionSet(options) => {
-                if !options.iter().any(|opt| opt == proof.options()) {
+                if !options.iter().any(|opt| opt == context.options()) {
                     return Err(VerifierError::UnacceptableProofOptions);
                 }
             },
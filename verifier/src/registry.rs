@@ -0,0 +1,132 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::vec::Vec;
+
+use air::{proof::Proof, Air};
+use crypto::{ElementHasher, RandomCoin, VectorCommitment};
+
+use super::{verify, AcceptableOptions, VerifierError};
+
+// HASH REGISTRY
+// ================================================================================================
+
+/// A function which verifies a proof of a computation described by `AIR` using a specific hash
+/// function, random coin, and vector commitment scheme.
+type VerifyFn<AIR> =
+    fn(Proof, <AIR as Air>::PublicInputs, &AcceptableOptions) -> Result<(), VerifierError>;
+
+/// A set of hash functions with which proofs of a computation described by `AIR` can be verified.
+///
+/// Each hash function (together with the random coin and the vector commitment scheme based on
+/// it) is registered under an application-defined identifier. When a proof is verified via
+/// [HashRegistry::verify()], the hash function is selected based on the identifier recorded in
+/// the proof context (see the `hash_id()` method of the prover), and the proof is then verified
+/// in the same way as via [verify()] instantiated with the selected hash function. This allows
+/// a single verifier to accept proofs generated using different hash functions (e.g., during a
+/// migration from one hash function to another).
+///
+/// A hash function can also be registered as the default one via
+/// [HashRegistry::with_default_hash()]; such a hash function is used to verify proofs which do
+/// not record a hash function identifier (e.g., proofs generated before the identifiers were
+/// introduced).
+pub struct HashRegistry<AIR: Air> {
+    entries: Vec<(Option<u8>, VerifyFn<AIR>)>,
+}
+
+impl<AIR: Air> HashRegistry<AIR> {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new registry without any hash functions.
+    pub fn new() -> Self {
+        Self { entries: Vec::new() }
+    }
+
+    /// Returns this registry with an additional hash function registered under the specified
+    /// `hash_id`.
+    ///
+    /// # Panics
+    /// Panics if a hash function has already been registered under the same identifier.
+    pub fn with_hash<HashFn, RandCoin, VC>(self, hash_id: u8) -> Self
+    where
+        HashFn: ElementHasher<BaseField = AIR::BaseField>,
+        RandCoin: RandomCoin<BaseField = AIR::BaseField, Hasher = HashFn>,
+        VC: VectorCommitment<HashFn>,
+    {
+        self.register(Some(hash_id), verify::<AIR, HashFn, RandCoin, VC>)
+    }
+
+    /// Returns this registry with an additional hash function used to verify proofs which do not
+    /// record a hash function identifier.
+    ///
+    /// # Panics
+    /// Panics if a default hash function has already been registered.
+    pub fn with_default_hash<HashFn, RandCoin, VC>(self) -> Self
+    where
+        HashFn: ElementHasher<BaseField = AIR::BaseField>,
+        RandCoin: RandomCoin<BaseField = AIR::BaseField, Hasher = HashFn>,
+        VC: VectorCommitment<HashFn>,
+    {
+        self.register(None, verify::<AIR, HashFn, RandCoin, VC>)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns true if proofs with the specified hash function identifier can be verified using
+    /// this registry; None refers to proofs which do not record a hash function identifier.
+    pub fn contains(&self, hash_id: Option<u8>) -> bool {
+        self.get(hash_id).is_some()
+    }
+
+    // VERIFICATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Verifies that the specified computation was executed correctly against the specified
+    /// inputs using the hash function registered under the identifier recorded in the context of
+    /// the `proof`.
+    ///
+    /// # Errors
+    /// Returns [VerifierError::UnsupportedHashFunction] if no hash function has been registered
+    /// under the identifier recorded in the proof (or as the default one, if the proof does not
+    /// record an identifier); otherwise, returns an error under the same conditions as
+    /// [verify()].
+    pub fn verify(
+        &self,
+        proof: Proof,
+        pub_inputs: AIR::PublicInputs,
+        acceptable_options: &AcceptableOptions,
+    ) -> Result<(), VerifierError> {
+        let hash_id = proof.context.hash_id();
+        let verify_fn = self.get(hash_id).ok_or(VerifierError::UnsupportedHashFunction(hash_id))?;
+        verify_fn(proof, pub_inputs, acceptable_options)
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    fn register(mut self, hash_id: Option<u8>, verify_fn: VerifyFn<AIR>) -> Self {
+        match hash_id {
+            Some(id) => assert!(!self.contains(hash_id), "hash function {id} already registered"),
+            None => assert!(!self.contains(hash_id), "default hash function already registered"),
+        }
+        self.entries.push((hash_id, verify_fn));
+        self
+    }
+
+    fn get(&self, hash_id: Option<u8>) -> Option<VerifyFn<AIR>> {
+        self.entries
+            .iter()
+            .find(|(id, _)| *id == hash_id)
+            .map(|(_, verify_fn)| *verify_fn)
+    }
+}
+
+impl<AIR: Air> Default for HashRegistry<AIR> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,421 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use alloc::{string::ToString, vec::Vec};
+use core::marker::PhantomData;
+
+use air::{
+    proof::{ProofHeader, Queries, Table, TraceOodFrame},
+    Air, FieldExtension,
+};
+use crypto::{DefaultProofOfWork, ElementHasher, RandomCoin, VectorCommitment};
+use fri::{FriProof, FriProofLayer, VerifierChannel as FriVerifierChannel};
+use math::{
+    fields::{CubeExtension, QuadExtension, SexticExtension},
+    FieldElement, ToElements,
+};
+use utils::{ByteReader, Deserializable};
+
+use crate::{
+    channel::{ProofChannel, VerifierChannel},
+    perform_verification, AcceptableOptions, VerifierError,
+};
+
+// STREAMING VERIFIER
+// ================================================================================================
+
+/// A verifier which reads a STARK proof incrementally from a [ByteReader] while verifying it.
+///
+/// The proof is expected to be serialized using the streaming format (see
+/// [Proof::write_streaming_into()](air::proof::Proof::write_streaming_into)). The verifier
+/// proceeds phase by phase: first, the proof header is read and used to derive the
+/// out-of-domain point, FRI folding challenges, and query positions; then, trace and constraint
+/// queries are read and checked against the respective commitments; finally, FRI layers are read
+/// and verified one at a time, followed by the FRI remainder.
+///
+/// Beyond the proof header and the values queried from the trace and constraint composition
+/// polynomials, the verifier holds in memory only the data of the FRI layer which is currently
+/// being verified. Thus, the entire proof is never held in memory at the same time.
+///
+/// Verification performed by this verifier is equivalent to verification performed by
+/// [verify()](crate::verify).
+pub struct StreamingVerifier<AIR, HashFn, RandCoin, VC> {
+    acceptable_options: AcceptableOptions,
+    _phantom: PhantomData<(AIR, HashFn, RandCoin, VC)>,
+}
+
+impl<AIR, HashFn, RandCoin, VC> StreamingVerifier<AIR, HashFn, RandCoin, VC>
+where
+    AIR: Air,
+    HashFn: ElementHasher<BaseField = AIR::BaseField>,
+    RandCoin: RandomCoin<BaseField = AIR::BaseField, Hasher = HashFn>,
+    VC: VectorCommitment<HashFn>,
+{
+    /// Returns a new streaming verifier which accepts proofs generated with parameters allowed
+    /// by `acceptable_options`.
+    pub fn new(acceptable_options: AcceptableOptions) -> Self {
+        Self {
+            acceptable_options,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Verifies that the proof read from the specified `source` attests to a correct execution
+    /// of the computation specified by `AIR` against the public inputs specified by
+    /// `pub_inputs`.
+    ///
+    /// Exactly one proof is read from the `source`; any bytes following the proof are left in
+    /// the `source`.
+    ///
+    /// # Errors
+    /// Returns an error if the proof could not be read from the `source`, or if the proof does not
+    /// attest to a correct execution of the computation for the same reasons as
+    /// [verify()](crate::verify).
+    pub fn verify<R: ByteReader>(
+        &self,
+        source: &mut R,
+        pub_inputs: AIR::PublicInputs,
+    ) -> Result<(), VerifierError> {
+        // read the proof header and check that the proof was generated with an acceptable set of
+        // parameters from the point of view of the verifier
+        let header = ProofHeader::read_from(source)
+            .map_err(|err| VerifierError::ProofDeserializationError(err.to_string()))?;
+        self.acceptable_options.validate_context::<HashFn>(&header.context)?;
+        if let Some(pow_id) = header.context.pow_id() {
+            return Err(VerifierError::UnsupportedProofOfWork(Some(pow_id)));
+        }
+        if let Some(aux_hash_id) = header.context.aux_hash_id() {
+            return Err(VerifierError::UnsupportedAuxHashFunction(Some(aux_hash_id)));
+        }
+
+        // build a seed for the public coin; the initial seed is a hash of the proof context and
+        // the public inputs
+        let mut public_coin_seed = header.context.to_elements();
+        public_coin_seed.append(&mut pub_inputs.to_elements());
+
+        // create AIR instance for the computation specified in the proof
+        let air = AIR::new(
+            header.context.trace_info().clone(),
+            pub_inputs,
+            header.context.options().clone(),
+        );
+        let public_coin = RandCoin::new(&public_coin_seed);
+
+        // figure out which version of the generic proof verification procedure to run
+        match air.options().field_extension() {
+            FieldExtension::None => {
+                let channel = StreamingChannel::<_, AIR::BaseField, HashFn, VC, R>::new(
+                    &air, header, source,
+                )?;
+                perform_verification::<AIR, AIR::BaseField, HashFn, RandCoin, VC, _>(
+                    &air,
+                    channel,
+                    public_coin,
+                    &DefaultProofOfWork,
+                    None,
+                    None,
+                )?;
+            },
+            FieldExtension::Quadratic => {
+                if !<QuadExtension<AIR::BaseField>>::is_supported() {
+                    return Err(VerifierError::UnsupportedFieldExtension(2));
+                }
+                let channel =
+                    StreamingChannel::<_, QuadExtension<AIR::BaseField>, HashFn, VC, R>::new(
+                        &air, header, source,
+                    )?;
+                perform_verification::<AIR, QuadExtension<AIR::BaseField>, HashFn, RandCoin, VC, _>(
+                    &air,
+                    channel,
+                    public_coin,
+                    &DefaultProofOfWork,
+                    None,
+                    None,
+                )?;
+            },
+            FieldExtension::Cubic => {
+                if !<CubeExtension<AIR::BaseField>>::is_supported() {
+                    return Err(VerifierError::UnsupportedFieldExtension(3));
+                }
+                let channel =
+                    StreamingChannel::<_, CubeExtension<AIR::BaseField>, HashFn, VC, R>::new(
+                        &air, header, source,
+                    )?;
+                perform_verification::<AIR, CubeExtension<AIR::BaseField>, HashFn, RandCoin, VC, _>(
+                    &air,
+                    channel,
+                    public_coin,
+                    &DefaultProofOfWork,
+                    None,
+                    None,
+                )?;
+            },
+            FieldExtension::Sextic => {
+                if !<SexticExtension<AIR::BaseField>>::is_supported() {
+                    return Err(VerifierError::UnsupportedFieldExtension(6));
+                }
+                let channel =
+                    StreamingChannel::<_, SexticExtension<AIR::BaseField>, HashFn, VC, R>::new(
+                        &air, header, source,
+                    )?;
+                perform_verification::<
+                    AIR,
+                    SexticExtension<AIR::BaseField>,
+                    HashFn,
+                    RandCoin,
+                    VC,
+                    _,
+                >(&air, channel, public_coin, &DefaultProofOfWork, None, None)?;
+            },
+        }
+
+        Ok(())
+    }
+}
+
+// STREAMING CHANNEL
+// ================================================================================================
+
+/// A [ProofChannel] which reads trace and constraint queries and the FRI proof from a
+/// [ByteReader] at the time they are requested by the verifier.
+///
+/// Data read from the source is parsed and added to an inner [VerifierChannel] initialized from
+/// the proof header; the data is then read from the inner channel. Thus, at any given time, the
+/// inner channel holds data for at most one FRI layer.
+struct StreamingChannel<'a, A, E, H, V, R>
+where
+    A: Air<BaseField = E::BaseField>,
+    E: FieldElement,
+    H: ElementHasher<BaseField = E::BaseField>,
+    V: VectorCommitment<H>,
+    R: ByteReader,
+{
+    inner: VerifierChannel<E, H, V, H, V>,
+    air: &'a A,
+    source: &'a mut R,
+    // number of FRI layers which are yet to be read from the source, or None if the number of
+    // FRI layers has not been read from the source yet
+    num_remaining_fri_layers: Option<usize>,
+    // size of the domain of the FRI layer which was read last
+    fri_domain_size: usize,
+}
+
+impl<'a, A, E, H, V, R> StreamingChannel<'a, A, E, H, V, R>
+where
+    A: Air<BaseField = E::BaseField>,
+    E: FieldElement,
+    H: ElementHasher<BaseField = E::BaseField>,
+    V: VectorCommitment<H>,
+    R: ByteReader,
+{
+    /// Creates and returns a new [StreamingChannel] initialized from the specified proof `header`;
+    /// the remainder of the proof is read from the specified `source`.
+    pub fn new(air: &'a A, header: ProofHeader, source: &'a mut R) -> Result<Self, VerifierError> {
+        let inner = VerifierChannel::from_header(air, header)?;
+        Ok(Self {
+            inner,
+            air,
+            source,
+            num_remaining_fri_layers: None,
+            fri_domain_size: air.lde_domain_size(),
+        })
+    }
+
+    /// Reads the number of FRI layers from the source (unless it has already been read), and
+    /// returns the number of layers which are yet to be read.
+    fn read_num_fri_layers(&mut self) -> Result<usize, fri::VerifierError> {
+        if let Some(num_layers) = self.num_remaining_fri_layers {
+            return Ok(num_layers);
+        }
+
+        let num_layers = self.source.read_u8().map_err(deserialization_error)? as usize;
+        let expected_num_layers =
+            self.air.options().to_fri_options().num_fri_layers(self.air.lde_domain_size());
+        if num_layers != expected_num_layers {
+            return Err(fri::VerifierError::ProofDeserializationError(format!(
+                "expected {expected_num_layers} FRI layers, but {num_layers} were declared"
+            )));
+        }
+
+        self.num_remaining_fri_layers = Some(num_layers);
+        Ok(num_layers)
+    }
+
+    /// Reads trace queries for all trace segments from the source and adds them to the inner
+    /// channel.
+    fn load_trace_queries(&mut self) -> Result<(), VerifierError> {
+        let num_trace_segments = self.air.trace_info().num_segments();
+        let mut queries = Vec::with_capacity(num_trace_segments);
+        for _ in 0..num_trace_segments {
+            let segment_queries = Queries::read_from(self.source).map_err(|err| {
+                VerifierError::ProofDeserializationError(format!(
+                    "failed to read trace queries: {err}"
+                ))
+            })?;
+            queries.push(segment_queries);
+        }
+
+        self.inner.set_trace_queries(queries, self.air)
+    }
+
+    /// Reads constraint queries from the source and adds them to the inner channel.
+    fn load_constraint_queries(&mut self) -> Result<(), VerifierError> {
+        let queries = Queries::read_from(self.source).map_err(|err| {
+            VerifierError::ProofDeserializationError(format!(
+                "failed to read constraint queries: {err}"
+            ))
+        })?;
+
+        self.inner.set_constraint_queries(queries, self.air)
+    }
+}
+
+impl<A, E, H, V, R> ProofChannel<E> for StreamingChannel<'_, A, E, H, V, R>
+where
+    A: Air<BaseField = E::BaseField>,
+    E: FieldElement,
+    H: ElementHasher<BaseField = E::BaseField>,
+    V: VectorCommitment<H>,
+    R: ByteReader,
+{
+    fn read_main_trace_commitment(&self) -> H::Digest {
+        self.inner.read_main_trace_commitment()
+    }
+
+    fn read_aux_trace_commitment(&self) -> Option<H::Digest> {
+        self.inner.read_aux_trace_commitment()
+    }
+
+    fn read_constraint_commitment(&self) -> H::Digest {
+        self.inner.read_constraint_commitment()
+    }
+
+    fn read_ood_trace_frame(&mut self) -> TraceOodFrame<E> {
+        self.inner.read_ood_trace_frame()
+    }
+
+    fn read_ood_constraint_evaluations(&mut self) -> Vec<E> {
+        self.inner.read_ood_constraint_evaluations()
+    }
+
+    fn read_pow_nonce(&self) -> u64 {
+        self.inner.read_pow_nonce()
+    }
+
+    fn read_gkr_proof(&self) -> Option<&Vec<u8>> {
+        self.inner.read_gkr_proof()
+    }
+
+    fn read_queried_trace_states(
+        &mut self,
+        positions: &[usize],
+    ) -> Result<(Table<E::BaseField>, Option<Table<E>>), VerifierError> {
+        self.load_trace_queries()?;
+        self.inner.read_queried_trace_states(positions)
+    }
+
+    fn read_constraint_evaluations(
+        &mut self,
+        positions: &[usize],
+    ) -> Result<Table<E>, VerifierError> {
+        self.load_constraint_queries()?;
+        self.inner.read_constraint_evaluations(positions)
+    }
+
+    fn read_unchecked_trace_states(
+        &mut self,
+    ) -> Result<(Table<E::BaseField>, Option<Table<E>>), VerifierError> {
+        self.load_trace_queries()?;
+        self.inner.read_unchecked_trace_states()
+    }
+
+    fn read_unchecked_constraint_evaluations(&mut self) -> Result<Table<E>, VerifierError> {
+        self.load_constraint_queries()?;
+        self.inner.read_unchecked_constraint_evaluations()
+    }
+}
+
+impl<A, E, H, V, R> FriVerifierChannel<E> for StreamingChannel<'_, A, E, H, V, R>
+where
+    A: Air<BaseField = E::BaseField>,
+    E: FieldElement,
+    H: ElementHasher<BaseField = E::BaseField>,
+    V: VectorCommitment<H>,
+    R: ByteReader,
+{
+    type Hasher = H;
+    type VectorCommitment = V;
+
+    fn read_fri_num_partitions(&self) -> usize {
+        self.inner.read_fri_num_partitions()
+    }
+
+    fn read_fri_layer_commitments(&mut self) -> Vec<H::Digest> {
+        self.inner.read_fri_layer_commitments()
+    }
+
+    fn load_next_fri_layer(&mut self) -> Result<(), fri::VerifierError> {
+        let num_remaining_layers = self.read_num_fri_layers()?;
+        if num_remaining_layers == 0 {
+            return Err(fri::VerifierError::ProofDeserializationError(
+                "no more FRI layers to read".to_string(),
+            ));
+        }
+
+        let folding_factor = self.air.options().to_fri_options().folding_factor();
+        self.fri_domain_size /= folding_factor;
+
+        let layer = FriProofLayer::read_from(self.source).map_err(deserialization_error)?;
+        let (layer_queries, layer_proof) =
+            layer.parse::<E, H, V>(folding_factor).map_err(deserialization_error)?;
+
+        // check that the opening proof matches the domain length
+        let domain_len = <V as VectorCommitment<H>>::get_multiproof_domain_len(&layer_proof);
+        if domain_len != self.fri_domain_size {
+            return Err(fri::VerifierError::ProofDeserializationError(format!(
+                "expected a domain of size {} but was {domain_len}",
+                self.fri_domain_size
+            )));
+        }
+
+        self.inner.push_fri_layer(layer_queries, layer_proof);
+        self.num_remaining_fri_layers = Some(num_remaining_layers - 1);
+        Ok(())
+    }
+
+    fn load_fri_remainder(&mut self) -> Result<(), fri::VerifierError> {
+        let num_remaining_layers = self.read_num_fri_layers()?;
+        if num_remaining_layers != 0 {
+            return Err(fri::VerifierError::ProofDeserializationError(format!(
+                "expected the FRI remainder, but {num_remaining_layers} FRI layers were not read"
+            )));
+        }
+
+        let remainder = FriProof::read_remainder(self.source).map_err(deserialization_error)?;
+        self.inner.set_fri_remainder(remainder);
+        Ok(())
+    }
+
+    fn take_next_fri_layer_proof(&mut self) -> V::MultiProof {
+        self.inner.take_next_fri_layer_proof()
+    }
+
+    fn take_next_fri_layer_queries(&mut self) -> Vec<E> {
+        self.inner.take_next_fri_layer_queries()
+    }
+
+    fn take_fri_remainder(&mut self) -> Vec<E> {
+        self.inner.take_fri_remainder()
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Converts an error encountered while reading the FRI proof from the source into a FRI verifier
+/// error.
+fn deserialization_error(err: utils::DeserializationError) -> fri::VerifierError {
+    fri::VerifierError::ProofDeserializationError(err.to_string())
+}
@@ -0,0 +1,69 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! Helpers for exposing proof verification to JavaScript via `wasm-bindgen`.
+//!
+//! The verifier does not use threads, timers, or platform-specific intrinsics, and thus, it can
+//! be compiled for `wasm32-unknown-unknown` target as is (with default features disabled if
+//! `std` is not available). This module adds a verification entry point which accepts proofs and
+//! public inputs as raw bytes and reports errors as [JsError], so that it can be called from a
+//! `#[wasm_bindgen]` function with minimal glue code.
+
+use alloc::string::ToString;
+
+use air::Air;
+use crypto::{ElementHasher, RandomCoin, VectorCommitment};
+use utils::Deserializable;
+use wasm_bindgen::JsError;
+
+use crate::{verify, AcceptableOptions, Proof};
+
+// VERIFY BYTES
+// ================================================================================================
+
+/// Verifies a serialized proof against serialized public inputs.
+///
+/// This is the same as [verify()], but the proof and the public inputs are deserialized from the
+/// provided bytes, and errors are converted into [JsError] with the error message set to the
+/// description of the error.
+///
+/// Since `wasm-bindgen` cannot export generic functions, this function is expected to be called
+/// from a concrete wrapper which fixes the AIR, the hash function, and the acceptable options of
+/// the computation being verified. For example:
+///
+/// ```ignore
+/// #[wasm_bindgen]
+/// pub fn verify_my_proof(proof: &[u8], pub_inputs: &[u8]) -> Result<(), JsError> {
+///     let options = AcceptableOptions::MinConjecturedSecurity(96);
+///     winter_verifier::verify_bytes::<MyAir, Rp64_256, DefaultRandomCoin<Rp64_256>,
+///         MerkleTree<Rp64_256>>(proof, pub_inputs, &options)
+/// }
+/// ```
+///
+/// # Errors
+/// Returns an error if:
+/// - `proof` is not a valid serialization of a [Proof].
+/// - `pub_inputs` is not a valid serialization of the public inputs of `AIR`.
+/// - The proof does not verify for any of the reasons listed for [verify()].
+pub fn verify_bytes<AIR, HashFn, RandCoin, VC>(
+    proof: &[u8],
+    pub_inputs: &[u8],
+    acceptable_options: &AcceptableOptions,
+) -> Result<(), JsError>
+where
+    AIR: Air,
+    AIR::PublicInputs: Deserializable,
+    HashFn: ElementHasher<BaseField = AIR::BaseField>,
+    RandCoin: RandomCoin<BaseField = AIR::BaseField, Hasher = HashFn>,
+    VC: VectorCommitment<HashFn>,
+{
+    let proof = Proof::from_bytes(proof)
+        .map_err(|err| JsError::new(&format!("proof deserialization failed: {err}")))?;
+    let pub_inputs = AIR::PublicInputs::read_from_bytes(pub_inputs)
+        .map_err(|err| JsError::new(&format!("public inputs deserialization failed: {err}")))?;
+
+    verify::<AIR, HashFn, RandCoin, VC>(proof, pub_inputs, acceptable_options)
+        .map_err(|err| JsError::new(&err.to_string()))
+}
@@ -629,20 +629,39 @@
 
 #![no_std]
 
+extern crate alloc;
+
 #[cfg(test)]
 extern crate std;
 
-pub use air::{AuxRandElements, GkrVerifier, PartitionOptions};
+pub use air::{
+    AirWithAssertions, AuxRandElements, GkrVerifier, InputsWithAssertions, PartitionOptions,
+};
 pub use prover::{
     crypto, iterators, math, matrix, Air, AirContext, Assertion, AuxTraceWithMetadata,
-    BoundaryConstraint, BoundaryConstraintGroup, CompositionPoly, CompositionPolyTrace,
-    ConstraintCompositionCoefficients, ConstraintDivisor, ConstraintEvaluator,
-    DeepCompositionCoefficients, DefaultConstraintCommitment, DefaultConstraintEvaluator,
-    DefaultTraceLde, EvaluationFrame, FieldExtension, Proof, ProofOptions, Prover, ProverError,
-    ProverGkrProof, StarkDomain, Trace, TraceInfo, TraceLde, TracePolyTable, TraceTable,
-    TraceTableFragment, TransitionConstraintDegree,
+    BoundaryConstraint, BoundaryConstraintGroup, CommittedTrace, CompositionPoly,
+    CompositionPolyTrace, ConstraintCompositionCoefficients, ConstraintDivisor,
+    ConstraintEvaluator, ConstraintResidual, DeepCompositionCoefficients,
+    DefaultConstraintCommitment, DefaultConstraintEvaluator, DefaultTraceLde, DomainCache,
+    EvaluationFrame, FieldExtension, MemoryEstimate, PaddedTrace, Proof, ProofOptions, Prover,
+    ProverError, ProverGkrProof, ProverTranscript, ProvingKey, SecurityLevel, Selector,
+    StarkDomain, Trace, TraceInfo, TraceLde, TracePolyTable, TraceTable, TraceTableFragment,
+    TransitionConstraintDegree, TransitionResiduals,
 };
-pub use verifier::{verify, AcceptableOptions, ByteWriter, VerifierError};
+#[cfg(feature = "wasm")]
+pub use verifier::verify_bytes;
+#[cfg(feature = "external-queries")]
+pub use verifier::verify_with_query_positions;
+pub use verifier::{
+    verify, verify_returning_digest, verify_spot_check, verify_with_assertions,
+    verify_with_aux_hash, verify_with_coin, verify_with_committed_inputs, verify_with_context,
+    verify_with_key, verify_with_key_and_proof_of_work, verify_with_proof_of_work,
+    AcceptableOptions, ByteWriter, HashRegistry, StreamingVerifier, VerificationKey, VerifierError,
+};
+#[cfg(feature = "accounting")]
+pub use verifier::{verify_with_accounting, VerificationCost};
+
+pub mod testing;
 
 #[cfg(test)]
 mod tests;
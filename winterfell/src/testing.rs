@@ -0,0 +1,411 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! Utilities for testing provers built with this library.
+
+use alloc::{string::String, vec, vec::Vec};
+use core::marker::PhantomData;
+
+use maybe_async::{maybe_async, maybe_await};
+use prover::{
+    crypto::{hashers::Blake3_256, DefaultRandomCoin, Digest, ElementHasher, Hasher, MerkleTree},
+    math::{
+        fft, fields::f64::BaseElement, get_power_series_with_offset, polynom, FieldElement,
+        StarkField,
+    },
+    matrix::ColMatrix,
+    Air, AirContext, Assertion, AuxRandElements, CompositionPoly, CompositionPolyTrace,
+    ConstraintCompositionCoefficients, ConstraintDivisor, DefaultConstraintCommitment,
+    DefaultConstraintEvaluator, DefaultTraceLde, EvaluationFrame, PartitionOptions, Proof,
+    ProofOptions, Prover, StarkDomain, TraceInfo, TracePolyTable, TraceTable,
+    TransitionConstraintDegree,
+};
+use verifier::{AcceptableOptions, VerifierError};
+
+// PROOF STABILITY
+// ================================================================================================
+
+/// Asserts that the proof generated by the specified prover for the specified trace is
+/// byte-for-byte identical to a previously pinned proof.
+///
+/// The AIR and [ProofOptions](crate::ProofOptions) used to generate the proof are the ones defined
+/// by the prover. The proof is compared to the pinned one by its hash, which is computed as
+/// described in [proof_hash()] and is expected to be provided as a hex string via the
+/// `expected_hash` parameter.
+///
+/// This function can be used to guard against unintended changes to the proof format or to the
+/// proof generation procedure: any such change causes the hash of the proof to change. For
+/// a deliberate change, the pinned hash should be updated to the value reported in the panic
+/// message.
+///
+/// # Panics
+/// Panics if:
+/// * Proof generation fails.
+/// * Options of the generated proof are not equal to the options of the prover.
+/// * Proving the same trace twice results in different proofs (i.e., proof generation is not
+///   deterministic).
+/// * The hash of the proof is not equal to the `expected_hash`.
+#[maybe_async]
+pub fn assert_proof_stable<P>(prover: &P, trace: P::Trace, expected_hash: &str)
+where
+    P: Prover,
+    P::Trace: Clone,
+{
+    let proof = maybe_await!(prover.prove(trace.clone())).expect("failed to generate proof");
+    assert_eq!(
+        prover.options(),
+        proof.options(),
+        "proof options differ from the options of the prover"
+    );
+
+    let other_proof = maybe_await!(prover.prove(trace)).expect("failed to generate proof");
+    assert!(
+        proof == other_proof,
+        "proving the same trace twice resulted in different proofs; proof generation is not \
+        deterministic"
+    );
+
+    let actual_hash = proof_hash::<P::BaseField>(&proof);
+    assert_eq!(
+        expected_hash, actual_hash,
+        "proof hash does not match the pinned value; expected {expected_hash} but was {actual_hash}"
+    );
+}
+
+/// Returns a hex-encoded BLAKE3 hash of the serialized proof.
+///
+/// The type parameter `B` specifies the base field of the proof, and affects only the type of
+/// the hasher but not the resulting hash.
+pub fn proof_hash<B: StarkField>(proof: &Proof) -> String {
+    Blake3_256::<B>::hash(&proof.to_bytes()).to_hex()
+}
+
+// TRANSITION CONSTRAINT DEGREES
+// ================================================================================================
+
+/// Describes a transition constraint for which the declared degree does not match the degree
+/// inferred from an execution trace.
+///
+/// Degrees are expressed as degrees of the polynomials obtained by evaluating the constraints
+/// over trace polynomials (i.e., in the same units as the values returned from
+/// [TransitionConstraintDegree::get_evaluation_degree()](crate::TransitionConstraintDegree::get_evaluation_degree)).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DegreeMismatch {
+    /// Index of the constraint in the main transition constraint list of the AIR.
+    pub constraint_idx: usize,
+    /// Degree of the constraint as declared by the AIR.
+    pub declared: usize,
+    /// Degree of the constraint inferred from the execution trace.
+    pub actual: usize,
+}
+
+/// Returns degrees of the main transition constraints of the specified `air` inferred from the
+/// provided main trace segment.
+///
+/// The degrees are inferred by evaluating the constraints over the LDE domain of the AIR (using
+/// the low-degree extension of the `main_trace`), and interpolating the results. Thus, degrees
+/// greater than or equal to the size of the LDE domain cannot be inferred accurately; however,
+/// such degrees exceed the degrees supported by the blowup factor of the AIR anyway.
+///
+/// The inferred degrees depend on the values in the trace. For example, if a trace column is
+/// constant, constraints which depend only on this column will have a lower degree than
+/// declared. To get meaningful results, a trace with representative values should be used.
+///
+/// Virtual columns declared by the AIR are evaluated from the extended main trace segment at
+/// every point of the LDE domain, and thus, constraints over virtual columns are analyzed as well.
+/// Constraints over the auxiliary trace segment are not analyzed.
+///
+/// # Panics
+/// Panics if the number of columns or rows in `main_trace` does not match the main trace segment
+/// described by the AIR.
+pub fn infer_transition_degrees<A: Air>(
+    air: &A,
+    main_trace: &ColMatrix<A::BaseField>,
+) -> Vec<usize> {
+    let trace_length = air.trace_length();
+    let lde_domain_size = air.lde_domain_size();
+    let blowup_factor = air.options().blowup_factor();
+    let domain_offset = air.domain_offset();
+    assert_eq!(
+        air.trace_info().main_trace_width(),
+        main_trace.num_cols(),
+        "number of trace columns does not match the main trace segment width of the AIR"
+    );
+    assert_eq!(
+        trace_length,
+        main_trace.num_rows(),
+        "number of trace rows does not match the trace length of the AIR"
+    );
+
+    // extend the main trace segment over the LDE domain
+    let trace_inv_twiddles = fft::get_inv_twiddles::<A::BaseField>(trace_length);
+    let trace_twiddles = fft::get_twiddles::<A::BaseField>(trace_length);
+    let lde_columns = main_trace
+        .columns()
+        .map(|column| {
+            let mut poly = column.to_vec();
+            fft::interpolate_poly(&mut poly, &trace_inv_twiddles);
+            fft::evaluate_poly_with_offset(&poly, &trace_twiddles, domain_offset, blowup_factor)
+        })
+        .collect::<Vec<_>>();
+
+    // evaluate the constraints at every point of the LDE domain and divide the results by the
+    // transition constraint divisor; this reduces the degree of the resulting polynomials so
+    // that they can be interpolated over the LDE domain
+    let num_constraints = air.context().num_main_transition_constraints();
+    let g = A::BaseField::get_root_of_unity(lde_domain_size.ilog2());
+    let domain = get_power_series_with_offset(g, domain_offset, lde_domain_size);
+    let divisor = ConstraintDivisor::<A::BaseField>::from_transition(
+        trace_length,
+        air.context().num_transition_exemptions(),
+    );
+    let periodic_polys = air.get_periodic_column_polys();
+
+    let mut frame = EvaluationFrame::new(main_trace.num_cols());
+    let mut periodic_values = vec![A::BaseField::ZERO; periodic_polys.len()];
+    let mut evaluations = vec![A::BaseField::ZERO; num_constraints];
+    let mut quotients = (0..num_constraints)
+        .map(|_| Vec::with_capacity(lde_domain_size))
+        .collect::<Vec<_>>();
+    for (step, &x) in domain.iter().enumerate() {
+        let next_step = (step + blowup_factor) % lde_domain_size;
+        for (col_idx, column) in lde_columns.iter().enumerate() {
+            frame.current_mut()[col_idx] = column[step];
+            frame.next_mut()[col_idx] = column[next_step];
+        }
+        for (value, poly) in periodic_values.iter_mut().zip(periodic_polys.iter()) {
+            let num_cycles = (trace_length / poly.len()) as u64;
+            *value = polynom::eval(poly, x.exp(num_cycles.into()));
+        }
+
+        frame.evaluate_virtual_columns(air);
+        air.evaluate_transition(&frame, &periodic_values, &mut evaluations);
+        let z = divisor.evaluate_at(x).inv();
+        for (quotient, &evaluation) in quotients.iter_mut().zip(evaluations.iter()) {
+            quotient.push(evaluation * z);
+        }
+    }
+
+    // interpolate the quotients and add the degree of the divisor back to their degrees
+    let lde_inv_twiddles = fft::get_inv_twiddles::<A::BaseField>(lde_domain_size);
+    quotients
+        .into_iter()
+        .map(|mut quotient| {
+            fft::interpolate_poly_with_offset(&mut quotient, &lde_inv_twiddles, domain_offset);
+            match polynom::degree_of(&quotient) {
+                0 if quotient[0] == A::BaseField::ZERO => 0,
+                degree => degree + divisor.degree(),
+            }
+        })
+        .collect()
+}
+
+/// Checks that degrees of the main transition constraints declared by the specified `air` match
+/// the degrees inferred from the provided main trace segment.
+///
+/// Wrongly declared constraint degrees cause proof generation to fail with an opaque error in
+/// release mode (or a panic in debug mode), and this function can be used to detect them before
+/// a proof is generated. See [infer_transition_degrees()] for details on how the degrees are
+/// inferred.
+///
+/// # Errors
+/// Returns a list of all constraints for which the declared and the inferred degrees differ.
+///
+/// # Panics
+/// Panics under the same conditions as [infer_transition_degrees()].
+pub fn check_transition_degrees<A: Air>(
+    air: &A,
+    main_trace: &ColMatrix<A::BaseField>,
+) -> Result<(), Vec<DegreeMismatch>> {
+    let trace_length = air.trace_length();
+    let composition_coefficients =
+        vec![A::BaseField::ONE; air.context().num_transition_constraints()];
+    let mismatches = air
+        .get_transition_constraints(&composition_coefficients)
+        .main_constraint_degrees()
+        .iter()
+        .zip(infer_transition_degrees(air, main_trace))
+        .enumerate()
+        .filter_map(|(constraint_idx, (degree, actual))| {
+            let declared = degree.get_evaluation_degree(trace_length);
+            (declared != actual).then_some(DegreeMismatch { constraint_idx, declared, actual })
+        })
+        .collect::<Vec<_>>();
+
+    if mismatches.is_empty() {
+        Ok(())
+    } else {
+        Err(mismatches)
+    }
+}
+
+// TRIVIAL AIR
+// ================================================================================================
+
+/// A minimal AIR which can be used to test integration with this library end-to-end (e.g.,
+/// proof serialization, proof options, or choice of a hash function) without defining a
+/// meaningful computation.
+///
+/// The execution trace of this AIR consists of a single column which starts with the value
+/// provided as a public input and is incremented by one at every step; that is, the only
+/// transition constraint is `next = current + 1`, and the only assertion is against the first
+/// value of the column. A constraint `next = current` is not used because it can be satisfied
+/// only by a constant column, over which the constraint has no well-defined degree.
+///
+/// Traces for this AIR can be built via [TrivialProver::build_trace()], and proofs can be verified
+/// via [verify_trivial_proof()].
+pub struct TrivialAir {
+    context: AirContext<BaseElement>,
+    start: BaseElement,
+}
+
+impl Air for TrivialAir {
+    type BaseField = BaseElement;
+    type GkrProof = ();
+    type GkrVerifier = ();
+
+    type PublicInputs = BaseElement;
+
+    fn new(trace_info: TraceInfo, pub_inputs: Self::PublicInputs, options: ProofOptions) -> Self {
+        let degrees = vec![TransitionConstraintDegree::new(1)];
+        Self {
+            context: AirContext::new(trace_info, degrees, 1, options),
+            start: pub_inputs,
+        }
+    }
+
+    fn with_context(context: &AirContext<Self::BaseField>, pub_inputs: Self::PublicInputs) -> Self {
+        // the context does not depend on the starting value, and thus, can be reused as is
+        Self {
+            context: context.clone(),
+            start: pub_inputs,
+        }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+
+    fn evaluate_transition<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        result[0] = frame.next()[0] - frame.current()[0] - E::ONE;
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        vec![Assertion::single(0, 0, self.start)]
+    }
+}
+
+/// A prover for [TrivialAir] which uses hash function `H` for all commitments and for the public
+/// coin.
+pub struct TrivialProver<H = Blake3_256<BaseElement>> {
+    options: ProofOptions,
+    _hasher: PhantomData<H>,
+}
+
+impl<H> TrivialProver<H> {
+    /// Returns a new prover which generates proofs using the specified options.
+    pub fn new(options: ProofOptions) -> Self {
+        Self { options, _hasher: PhantomData }
+    }
+
+    /// Builds an execution trace of the specified length for [TrivialAir] with the column starting
+    /// at `start`.
+    ///
+    /// # Panics
+    /// Panics if `length` is smaller than 8 or is not a power of two.
+    pub fn build_trace(start: BaseElement, length: usize) -> TraceTable<BaseElement> {
+        let mut trace = TraceTable::new(1, length);
+        trace.fill(|state| state[0] = start, |_, state| state[0] += BaseElement::ONE);
+        trace
+    }
+}
+
+impl<H> Prover for TrivialProver<H>
+where
+    H: ElementHasher<BaseField = BaseElement> + Sync,
+{
+    type BaseField = BaseElement;
+    type Air = TrivialAir;
+    type Trace = TraceTable<BaseElement>;
+    type HashFn = H;
+    type VC = MerkleTree<H>;
+    type RandomCoin = DefaultRandomCoin<Self::HashFn>;
+    type TraceLde<E: FieldElement<BaseField = Self::BaseField>> =
+        DefaultTraceLde<E, Self::HashFn, Self::VC>;
+    type ConstraintCommitment<E: FieldElement<BaseField = Self::BaseField>> =
+        DefaultConstraintCommitment<E, H, Self::VC>;
+    type ConstraintEvaluator<'a, E: FieldElement<BaseField = Self::BaseField>> =
+        DefaultConstraintEvaluator<'a, Self::Air, E>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> BaseElement {
+        trace.get(0, 0)
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+
+    #[maybe_async]
+    fn new_trace_lde<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        trace_info: &TraceInfo,
+        main_trace: &ColMatrix<Self::BaseField>,
+        domain: &StarkDomain<Self::BaseField>,
+        partition_option: PartitionOptions,
+    ) -> (Self::TraceLde<E>, TracePolyTable<E>) {
+        DefaultTraceLde::new(trace_info, main_trace, domain, partition_option)
+    }
+
+    #[maybe_async]
+    fn new_evaluator<'a, E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        air: &'a Self::Air,
+        aux_rand_elements: Option<AuxRandElements<E>>,
+        composition_coefficients: ConstraintCompositionCoefficients<E>,
+    ) -> Self::ConstraintEvaluator<'a, E> {
+        DefaultConstraintEvaluator::new(air, aux_rand_elements, composition_coefficients)
+    }
+
+    #[maybe_async]
+    fn build_constraint_commitment<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        composition_poly_trace: CompositionPolyTrace<E>,
+        num_constraint_composition_columns: usize,
+        domain: &StarkDomain<Self::BaseField>,
+        partition_options: PartitionOptions,
+    ) -> (Self::ConstraintCommitment<E>, CompositionPoly<E>) {
+        DefaultConstraintCommitment::new(
+            composition_poly_trace,
+            num_constraint_composition_columns,
+            domain,
+            partition_options,
+        )
+    }
+}
+
+/// Verifies a proof generated by [TrivialProver] using hash function `H` for a trace starting
+/// at `start`.
+///
+/// # Errors
+/// Returns an error under the same conditions as [verify()](crate::verify).
+pub fn verify_trivial_proof<H>(
+    proof: Proof,
+    start: BaseElement,
+    acceptable_options: &AcceptableOptions,
+) -> Result<(), VerifierError>
+where
+    H: ElementHasher<BaseField = BaseElement>,
+{
+    verifier::verify::<TrivialAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+        proof,
+        start,
+        acceptable_options,
+    )
+}
@@ -0,0 +1,1195 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+//! Computations shared by the tests of this crate.
+
+use std::{marker::PhantomData, vec, vec::Vec};
+
+use air::{GkrRandElements, LagrangeKernelRandElements};
+use crypto::{ElementHasher, Hasher, MerkleTree};
+use prover::{
+    crypto::{hashers::Blake3_256, DefaultRandomCoin, ProofOfWork, RandomCoin},
+    math::{fields::f64::BaseElement, ExtensionOf, FieldElement, ToElements},
+    matrix::ColMatrix,
+    CompositionPoly, DefaultConstraintCommitment,
+};
+
+use crate::*;
+
+// LagrangeComplexTrace
+// =================================================================================================
+
+#[derive(Clone, Debug)]
+pub struct LagrangeComplexTrace {
+    // dummy main trace
+    main_trace: ColMatrix<BaseElement>,
+    info: TraceInfo,
+}
+
+impl LagrangeComplexTrace {
+    pub fn new(trace_len: usize, aux_segment_width: usize) -> Self {
+        assert!(trace_len < u32::MAX.try_into().unwrap());
+
+        let main_trace_col: Vec<BaseElement> =
+            (0..trace_len).map(|idx| BaseElement::from(idx as u32)).collect();
+
+        Self {
+            main_trace: ColMatrix::new(vec![main_trace_col]),
+            info: TraceInfo::new_multi_segment(1, aux_segment_width, 0, trace_len, vec![]),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.main_trace.num_rows()
+    }
+}
+
+impl Trace for LagrangeComplexTrace {
+    type BaseField = BaseElement;
+
+    fn info(&self) -> &TraceInfo {
+        &self.info
+    }
+
+    fn main_segment(&self) -> &ColMatrix<Self::BaseField> {
+        &self.main_trace
+    }
+
+    fn read_main_frame(&self, row_idx: usize, frame: &mut EvaluationFrame<Self::BaseField>) {
+        let next_row_idx = row_idx + 1;
+        assert_ne!(next_row_idx, self.len());
+
+        self.main_trace.read_row_into(row_idx, frame.current_mut());
+        self.main_trace.read_row_into(next_row_idx, frame.next_mut());
+    }
+}
+
+// AIR
+// =================================================================================================
+
+#[derive(Debug, Clone, Default)]
+pub struct DummyGkrVerifier;
+
+impl GkrVerifier for DummyGkrVerifier {
+    // `GkrProof` is log(trace_len) for this dummy example, so that the verifier knows how many aux
+    // random variables to generate
+    type GkrProof = usize;
+    type Error = VerifierError;
+
+    fn verify<E, Hasher>(
+        &self,
+        gkr_proof: usize,
+        public_coin: &mut impl RandomCoin<BaseField = E::BaseField, Hasher = Hasher>,
+    ) -> Result<GkrRandElements<E>, Self::Error>
+    where
+        E: FieldElement,
+        Hasher: crypto::ElementHasher<BaseField = E::BaseField>,
+    {
+        let log_trace_len = gkr_proof;
+        let lagrange_kernel_rand_elements: LagrangeKernelRandElements<E> = {
+            let mut rand_elements = Vec::with_capacity(log_trace_len);
+            for _ in 0..log_trace_len {
+                rand_elements.push(public_coin.draw().unwrap());
+            }
+
+            LagrangeKernelRandElements::new(rand_elements)
+        };
+
+        Ok(GkrRandElements::new(lagrange_kernel_rand_elements, Vec::new()))
+    }
+}
+
+pub struct LagrangeKernelComplexAir {
+    context: AirContext<BaseElement>,
+}
+
+impl Air for LagrangeKernelComplexAir {
+    type BaseField = BaseElement;
+    // `GkrProof` is log(trace_len) for this dummy example, so that the verifier knows how many aux
+    // random variables to generate
+    type GkrProof = usize;
+    type GkrVerifier = DummyGkrVerifier;
+
+    type PublicInputs = ();
+
+    fn new(trace_info: TraceInfo, _pub_inputs: Self::PublicInputs, options: ProofOptions) -> Self {
+        Self {
+            context: AirContext::new_multi_segment(
+                trace_info,
+                vec![TransitionConstraintDegree::new(1)],
+                vec![TransitionConstraintDegree::new(1)],
+                1,
+                1,
+                Some(1),
+                options,
+            ),
+        }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+
+    fn evaluate_transition<E: math::FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        let current = frame.current()[0];
+        let next = frame.next()[0];
+
+        // increments by 1
+        result[0] = next - current - E::ONE;
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        vec![Assertion::single(0, 0, BaseElement::ZERO)]
+    }
+
+    fn evaluate_aux_transition<F, E>(
+        &self,
+        _main_frame: &EvaluationFrame<F>,
+        _aux_frame: &EvaluationFrame<E>,
+        _periodic_values: &[F],
+        _aux_rand_elements: &AuxRandElements<E>,
+        _result: &mut [E],
+    ) where
+        F: FieldElement<BaseField = Self::BaseField>,
+        E: FieldElement<BaseField = Self::BaseField> + ExtensionOf<F>,
+    {
+        // do nothing
+    }
+
+    fn get_aux_assertions<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        _aux_rand_elements: &AuxRandElements<E>,
+    ) -> Vec<Assertion<E>> {
+        vec![Assertion::single(0, 0, E::ZERO)]
+    }
+
+    fn get_gkr_proof_verifier<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+    ) -> Self::GkrVerifier {
+        DummyGkrVerifier
+    }
+}
+
+// LagrangeComplexProver
+// ================================================================================================
+
+pub struct LagrangeComplexProver<H = Blake3_256<BaseElement>> {
+    aux_trace_width: usize,
+    options: ProofOptions,
+    _hasher: PhantomData<H>,
+}
+
+impl LagrangeComplexProver {
+    pub fn new(aux_trace_width: usize) -> Self {
+        Self {
+            aux_trace_width,
+            options: ProofOptions::new(1, 2, 0, FieldExtension::None, 2, 1),
+            _hasher: PhantomData,
+        }
+    }
+
+    pub fn with_hasher<H>(self) -> LagrangeComplexProver<H> {
+        LagrangeComplexProver {
+            aux_trace_width: self.aux_trace_width,
+            options: self.options,
+            _hasher: PhantomData,
+        }
+    }
+}
+
+impl<H: ElementHasher<BaseField = BaseElement> + Sync> Prover for LagrangeComplexProver<H> {
+    type BaseField = BaseElement;
+    type Air = LagrangeKernelComplexAir;
+    type Trace = LagrangeComplexTrace;
+    type HashFn = H;
+    type VC = MerkleTree<H>;
+    type RandomCoin = DefaultRandomCoin<Self::HashFn>;
+    type TraceLde<E: FieldElement<BaseField = BaseElement>> =
+        DefaultTraceLde<E, Self::HashFn, Self::VC>;
+    type ConstraintCommitment<E: FieldElement<BaseField = Self::BaseField>> =
+        DefaultConstraintCommitment<E, H, Self::VC>;
+    type ConstraintEvaluator<'a, E: FieldElement<BaseField = BaseElement>> =
+        DefaultConstraintEvaluator<'a, LagrangeKernelComplexAir, E>;
+
+    fn get_pub_inputs(&self, _trace: &Self::Trace) -> <<Self as Prover>::Air as Air>::PublicInputs {
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+
+    fn new_trace_lde<E>(
+        &self,
+        trace_info: &TraceInfo,
+        main_trace: &ColMatrix<Self::BaseField>,
+        domain: &StarkDomain<Self::BaseField>,
+        partition_option: PartitionOptions,
+    ) -> (Self::TraceLde<E>, TracePolyTable<E>)
+    where
+        E: math::FieldElement<BaseField = Self::BaseField>,
+    {
+        DefaultTraceLde::new(trace_info, main_trace, domain, partition_option)
+    }
+
+    fn build_constraint_commitment<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        composition_poly_trace: CompositionPolyTrace<E>,
+        num_constraint_composition_columns: usize,
+        domain: &StarkDomain<Self::BaseField>,
+        partition_options: PartitionOptions,
+    ) -> (Self::ConstraintCommitment<E>, CompositionPoly<E>) {
+        DefaultConstraintCommitment::new(
+            composition_poly_trace,
+            num_constraint_composition_columns,
+            domain,
+            partition_options,
+        )
+    }
+
+    fn new_evaluator<'a, E>(
+        &self,
+        air: &'a Self::Air,
+        aux_rand_elements: Option<AuxRandElements<E>>,
+        composition_coefficients: ConstraintCompositionCoefficients<E>,
+    ) -> Self::ConstraintEvaluator<'a, E>
+    where
+        E: math::FieldElement<BaseField = Self::BaseField>,
+    {
+        DefaultConstraintEvaluator::new(air, aux_rand_elements, composition_coefficients)
+    }
+
+    fn generate_gkr_proof<E>(
+        &self,
+        main_trace: &Self::Trace,
+        public_coin: &mut Self::RandomCoin,
+    ) -> (ProverGkrProof<Self>, GkrRandElements<E>)
+    where
+        E: FieldElement<BaseField = Self::BaseField>,
+    {
+        let main_trace = main_trace.main_segment();
+        let log_trace_len = main_trace.num_rows().ilog2() as usize;
+        let lagrange_kernel_rand_elements = {
+            let mut rand_elements = Vec::with_capacity(log_trace_len);
+            for _ in 0..log_trace_len {
+                rand_elements.push(public_coin.draw().unwrap());
+            }
+
+            LagrangeKernelRandElements::new(rand_elements)
+        };
+
+        (log_trace_len, GkrRandElements::new(lagrange_kernel_rand_elements, Vec::new()))
+    }
+
+    fn build_aux_trace<E>(
+        &self,
+        main_trace: &Self::Trace,
+        aux_rand_elements: &AuxRandElements<E>,
+    ) -> ColMatrix<E>
+    where
+        E: FieldElement<BaseField = Self::BaseField>,
+    {
+        let main_trace = main_trace.main_segment();
+        let lagrange_kernel_rand_elements = aux_rand_elements
+            .lagrange()
+            .expect("expected lagrange random elements to be present.");
+
+        let mut columns = Vec::new();
+
+        // First all other auxiliary columns
+        let rand_summed = lagrange_kernel_rand_elements.iter().fold(E::ZERO, |acc, &r| acc + r);
+        for _ in 1..self.aux_trace_width {
+            // building a dummy auxiliary column
+            let column = main_trace
+                .get_column(0)
+                .iter()
+                .map(|row_val| rand_summed.mul_base(*row_val))
+                .collect();
+
+            columns.push(column);
+        }
+
+        // then build the Lagrange kernel column
+        {
+            let r = &lagrange_kernel_rand_elements;
+
+            let mut lagrange_col = Vec::with_capacity(main_trace.num_rows());
+
+            for row_idx in 0..main_trace.num_rows() {
+                let mut row_value = E::ONE;
+                for (bit_idx, &r_i) in r.iter().enumerate() {
+                    if row_idx & (1 << bit_idx) == 0 {
+                        row_value *= E::ONE - r_i;
+                    } else {
+                        row_value *= r_i;
+                    }
+                }
+                lagrange_col.push(row_value);
+            }
+
+            columns.push(lagrange_col);
+        }
+
+        ColMatrix::new(columns)
+    }
+}
+
+// ExtensionWitnessAir
+// ================================================================================================
+
+/// Returns an element of field `E` which, when `E` is an extension field, is not in the base
+/// field.
+pub fn extension_witness_offset<E: FieldElement>() -> E {
+    let coefficients = (1..=E::EXTENSION_DEGREE as u32)
+        .map(|i| E::BaseField::from(i + 1))
+        .collect::<Vec<_>>();
+    E::slice_from_base_elements(&coefficients)[0]
+}
+
+/// AIR with a counter column in the main trace segment and a column containing inverses of
+/// `counter + t` in the auxiliary trace segment, where `t` is a constant from the field in which
+/// the protocol is executed. The auxiliary segment does not depend on random elements.
+pub struct ExtensionWitnessAir {
+    context: AirContext<BaseElement>,
+}
+
+impl Air for ExtensionWitnessAir {
+    type BaseField = BaseElement;
+    type GkrProof = ();
+    type GkrVerifier = ();
+
+    type PublicInputs = ();
+
+    fn new(trace_info: TraceInfo, _pub_inputs: Self::PublicInputs, options: ProofOptions) -> Self {
+        Self {
+            context: AirContext::new_multi_segment(
+                trace_info,
+                vec![TransitionConstraintDegree::new(1)],
+                vec![TransitionConstraintDegree::new(2)],
+                1,
+                1,
+                None,
+                options,
+            ),
+        }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+
+    fn evaluate_transition<E: math::FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        // increments by 1
+        result[0] = frame.next()[0] - frame.current()[0] - E::ONE;
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        vec![Assertion::single(0, 0, BaseElement::ZERO)]
+    }
+
+    fn evaluate_aux_transition<F, E>(
+        &self,
+        main_frame: &EvaluationFrame<F>,
+        aux_frame: &EvaluationFrame<E>,
+        _periodic_values: &[F],
+        _aux_rand_elements: &AuxRandElements<E>,
+        result: &mut [E],
+    ) where
+        F: FieldElement<BaseField = Self::BaseField>,
+        E: FieldElement<BaseField = Self::BaseField> + ExtensionOf<F>,
+    {
+        // aux * (main + t) = 1
+        let counter = E::from(main_frame.current()[0]);
+        result[0] = aux_frame.current()[0] * (counter + extension_witness_offset::<E>()) - E::ONE;
+    }
+
+    fn get_aux_assertions<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        _aux_rand_elements: &AuxRandElements<E>,
+    ) -> Vec<Assertion<E>> {
+        vec![Assertion::single(0, 0, extension_witness_offset::<E>().inv())]
+    }
+}
+
+// ExtensionWitnessProver
+// ================================================================================================
+
+/// Hash functions which can be used to commit to the auxiliary segment in tests.
+pub trait AuxHasher:
+    ElementHasher<
+        BaseField = BaseElement,
+        Digest: Into<<Blake3_256<BaseElement> as Hasher>::Digest>,
+    > + Sync
+{
+}
+
+impl<H> AuxHasher for H where
+    H: ElementHasher<
+            BaseField = BaseElement,
+            Digest: Into<<Blake3_256<BaseElement> as Hasher>::Digest>,
+        > + Sync
+{
+}
+
+/// Prover for [ExtensionWitnessAir] which commits to the auxiliary trace segment using hash
+/// function `AH` (and to everything else using BLAKE3). When `AH` differs from BLAKE3, the
+/// identifier assigned to it is recorded in the proof context.
+pub struct ExtensionWitnessProver<AH = Blake3_256<BaseElement>> {
+    options: ProofOptions,
+    aux_hash_id: Option<u8>,
+    _aux_hash: PhantomData<AH>,
+}
+
+impl ExtensionWitnessProver {
+    pub fn new(field_extension: FieldExtension) -> Self {
+        Self {
+            options: ProofOptions::new(1, 4, 0, field_extension, 2, 1),
+            aux_hash_id: None,
+            _aux_hash: PhantomData,
+        }
+    }
+
+    pub fn with_aux_hash<AH>(self, aux_hash_id: u8) -> ExtensionWitnessProver<AH> {
+        ExtensionWitnessProver {
+            options: self.options,
+            aux_hash_id: Some(aux_hash_id),
+            _aux_hash: PhantomData,
+        }
+    }
+}
+
+impl<AH: AuxHasher> Prover for ExtensionWitnessProver<AH> {
+    type BaseField = BaseElement;
+    type Air = ExtensionWitnessAir;
+    type Trace = LagrangeComplexTrace;
+    type HashFn = Blake3_256<BaseElement>;
+    type VC = MerkleTree<Blake3_256<BaseElement>>;
+    type RandomCoin = DefaultRandomCoin<Self::HashFn>;
+    type TraceLde<E: FieldElement<BaseField = BaseElement>> =
+        DefaultTraceLde<E, Self::HashFn, Self::VC, AH, MerkleTree<AH>>;
+    type ConstraintCommitment<E: FieldElement<BaseField = Self::BaseField>> =
+        DefaultConstraintCommitment<E, Blake3_256<BaseElement>, Self::VC>;
+    type ConstraintEvaluator<'a, E: FieldElement<BaseField = BaseElement>> =
+        DefaultConstraintEvaluator<'a, ExtensionWitnessAir, E>;
+
+    fn get_pub_inputs(&self, _trace: &Self::Trace) -> <<Self as Prover>::Air as Air>::PublicInputs {
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+
+    fn aux_hash_id(&self) -> Option<u8> {
+        self.aux_hash_id
+    }
+
+    fn new_trace_lde<E>(
+        &self,
+        trace_info: &TraceInfo,
+        main_trace: &ColMatrix<Self::BaseField>,
+        domain: &StarkDomain<Self::BaseField>,
+        partition_option: PartitionOptions,
+    ) -> (Self::TraceLde<E>, TracePolyTable<E>)
+    where
+        E: math::FieldElement<BaseField = Self::BaseField>,
+    {
+        DefaultTraceLde::new(trace_info, main_trace, domain, partition_option)
+    }
+
+    fn build_constraint_commitment<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        composition_poly_trace: CompositionPolyTrace<E>,
+        num_constraint_composition_columns: usize,
+        domain: &StarkDomain<Self::BaseField>,
+        partition_options: PartitionOptions,
+    ) -> (Self::ConstraintCommitment<E>, CompositionPoly<E>) {
+        DefaultConstraintCommitment::new(
+            composition_poly_trace,
+            num_constraint_composition_columns,
+            domain,
+            partition_options,
+        )
+    }
+
+    fn new_evaluator<'a, E>(
+        &self,
+        air: &'a Self::Air,
+        aux_rand_elements: Option<AuxRandElements<E>>,
+        composition_coefficients: ConstraintCompositionCoefficients<E>,
+    ) -> Self::ConstraintEvaluator<'a, E>
+    where
+        E: math::FieldElement<BaseField = Self::BaseField>,
+    {
+        DefaultConstraintEvaluator::new(air, aux_rand_elements, composition_coefficients)
+    }
+
+    fn build_aux_trace<E>(
+        &self,
+        main_trace: &Self::Trace,
+        _aux_rand_elements: &AuxRandElements<E>,
+    ) -> ColMatrix<E>
+    where
+        E: FieldElement<BaseField = Self::BaseField>,
+    {
+        let offset = extension_witness_offset::<E>();
+        let column = main_trace
+            .main_segment()
+            .get_column(0)
+            .iter()
+            .map(|&value| (E::from(value) + offset).inv())
+            .collect();
+
+        ColMatrix::new(vec![column])
+    }
+}
+
+// CounterAir
+// ================================================================================================
+
+/// Public inputs for [CounterAir]; the AIR does not depend on the values of public inputs, and
+/// thus, it can be instantiated from a commitment to them.
+#[derive(Clone, Debug)]
+pub struct CounterInputs(pub Vec<BaseElement>);
+
+impl ToElements<BaseElement> for CounterInputs {
+    fn to_elements(&self) -> Vec<BaseElement> {
+        self.0.clone()
+    }
+}
+
+impl From<[u8; 32]> for CounterInputs {
+    fn from(_commitment: [u8; 32]) -> Self {
+        Self(Vec::new())
+    }
+}
+
+pub struct CounterAir {
+    context: AirContext<BaseElement>,
+}
+
+impl Air for CounterAir {
+    type BaseField = BaseElement;
+    type GkrProof = ();
+    type GkrVerifier = ();
+
+    type PublicInputs = CounterInputs;
+
+    fn new(trace_info: TraceInfo, _pub_inputs: Self::PublicInputs, options: ProofOptions) -> Self {
+        Self {
+            context: AirContext::new(
+                trace_info,
+                vec![TransitionConstraintDegree::new(1)],
+                1,
+                options,
+            ),
+        }
+    }
+
+    fn with_context(
+        context: &AirContext<Self::BaseField>,
+        _pub_inputs: Self::PublicInputs,
+    ) -> Self {
+        Self { context: context.clone() }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+
+    fn evaluate_transition<E: math::FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        // increments by 1
+        result[0] = frame.next()[0] - frame.current()[0] - E::ONE;
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        vec![Assertion::single(0, 0, BaseElement::ZERO)]
+    }
+
+    fn constraint_names(&self) -> Vec<&'static str> {
+        vec!["increment"]
+    }
+}
+
+// WrongDegreeAir
+// ================================================================================================
+
+/// AIR with a counter column and a column which is squared at every step; the degree of the
+/// squaring constraint is declared incorrectly.
+pub struct WrongDegreeAir {
+    context: AirContext<BaseElement>,
+}
+
+impl Air for WrongDegreeAir {
+    type BaseField = BaseElement;
+    type GkrProof = ();
+    type GkrVerifier = ();
+
+    type PublicInputs = ();
+
+    fn new(trace_info: TraceInfo, _pub_inputs: Self::PublicInputs, options: ProofOptions) -> Self {
+        let degrees = vec![TransitionConstraintDegree::new(1), TransitionConstraintDegree::new(1)];
+        Self {
+            context: AirContext::new(trace_info, degrees, 1, options),
+        }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+
+    fn evaluate_transition<E: math::FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        result[0] = frame.next()[0] - frame.current()[0] - E::ONE;
+        result[1] = frame.next()[1] - frame.current()[1].square();
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        vec![Assertion::single(0, 0, BaseElement::ZERO)]
+    }
+}
+
+// CounterProver
+// ================================================================================================
+
+/// Proof-of-work which requires hash(seed || (nonce XOR 0xffff)) to have the specified number of
+/// leading zeros.
+pub struct XorProofOfWork;
+
+impl<R: RandomCoin> ProofOfWork<R> for XorProofOfWork {
+    fn id(&self) -> Option<u8> {
+        Some(1)
+    }
+
+    fn solve(&self, seed: &R, difficulty: u32) -> u64 {
+        (1..u64::MAX)
+            .find(|&nonce| self.verify(seed, difficulty, nonce))
+            .expect("nonce not found")
+    }
+
+    fn verify(&self, seed: &R, difficulty: u32, nonce: u64) -> bool {
+        seed.check_leading_zeros(nonce ^ 0xffff) >= difficulty
+    }
+}
+
+pub struct CounterProver {
+    inputs: Vec<BaseElement>,
+    pub options: ProofOptions,
+    pub deep_coefficients: Option<DeepCompositionCoefficients<BaseElement>>,
+    pub hash_id: Option<u8>,
+    pub xor_pow: bool,
+    #[cfg(feature = "external-queries")]
+    pub query_positions: Option<Vec<usize>>,
+}
+
+impl CounterProver {
+    pub fn new(inputs: Vec<BaseElement>) -> Self {
+        Self {
+            inputs,
+            options: ProofOptions::new(1, 2, 0, FieldExtension::None, 2, 1),
+            deep_coefficients: None,
+            hash_id: None,
+            xor_pow: false,
+            #[cfg(feature = "external-queries")]
+            query_positions: None,
+        }
+    }
+
+    pub fn build_trace(trace_len: usize) -> TraceTable<BaseElement> {
+        let mut trace = TraceTable::new(1, trace_len);
+        trace.fill(|state| state[0] = BaseElement::ZERO, |_, state| state[0] += BaseElement::ONE);
+        trace
+    }
+}
+
+impl Prover for CounterProver {
+    type BaseField = BaseElement;
+    type Air = CounterAir;
+    type Trace = TraceTable<BaseElement>;
+    type HashFn = Blake3_256<BaseElement>;
+    type VC = MerkleTree<Blake3_256<BaseElement>>;
+    type RandomCoin = DefaultRandomCoin<Self::HashFn>;
+    type TraceLde<E: FieldElement<BaseField = BaseElement>> =
+        DefaultTraceLde<E, Self::HashFn, Self::VC>;
+    type ConstraintCommitment<E: FieldElement<BaseField = Self::BaseField>> =
+        DefaultConstraintCommitment<E, Blake3_256<BaseElement>, Self::VC>;
+    type ConstraintEvaluator<'a, E: FieldElement<BaseField = BaseElement>> =
+        DefaultConstraintEvaluator<'a, CounterAir, E>;
+
+    fn get_pub_inputs(&self, _trace: &Self::Trace) -> <<Self as Prover>::Air as Air>::PublicInputs {
+        CounterInputs(self.inputs.clone())
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+
+    fn hash_id(&self) -> Option<u8> {
+        self.hash_id
+    }
+
+    fn proof_of_work(&self) -> &dyn ProofOfWork<Self::RandomCoin> {
+        if self.xor_pow {
+            &XorProofOfWork
+        } else {
+            &crypto::DefaultProofOfWork
+        }
+    }
+
+    #[cfg(feature = "external-queries")]
+    fn external_query_positions(&self) -> Option<Vec<usize>> {
+        self.query_positions.clone()
+    }
+
+    fn deep_composition_coefficients<E>(
+        &self,
+        coefficients: DeepCompositionCoefficients<E>,
+    ) -> DeepCompositionCoefficients<E>
+    where
+        E: FieldElement<BaseField = Self::BaseField>,
+    {
+        match &self.deep_coefficients {
+            Some(injected) => DeepCompositionCoefficients {
+                trace: injected.trace.iter().map(|&c| E::from(c)).collect(),
+                constraints: injected.constraints.iter().map(|&c| E::from(c)).collect(),
+                lagrange: injected.lagrange.map(E::from),
+            },
+            None => coefficients,
+        }
+    }
+
+    fn new_trace_lde<E>(
+        &self,
+        trace_info: &TraceInfo,
+        main_trace: &ColMatrix<Self::BaseField>,
+        domain: &StarkDomain<Self::BaseField>,
+        partition_option: PartitionOptions,
+    ) -> (Self::TraceLde<E>, TracePolyTable<E>)
+    where
+        E: math::FieldElement<BaseField = Self::BaseField>,
+    {
+        DefaultTraceLde::new(trace_info, main_trace, domain, partition_option)
+    }
+
+    fn build_constraint_commitment<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        composition_poly_trace: CompositionPolyTrace<E>,
+        num_constraint_composition_columns: usize,
+        domain: &StarkDomain<Self::BaseField>,
+        partition_options: PartitionOptions,
+    ) -> (Self::ConstraintCommitment<E>, CompositionPoly<E>) {
+        DefaultConstraintCommitment::new(
+            composition_poly_trace,
+            num_constraint_composition_columns,
+            domain,
+            partition_options,
+        )
+    }
+
+    fn new_evaluator<'a, E>(
+        &self,
+        air: &'a Self::Air,
+        aux_rand_elements: Option<AuxRandElements<E>>,
+        composition_coefficients: ConstraintCompositionCoefficients<E>,
+    ) -> Self::ConstraintEvaluator<'a, E>
+    where
+        E: math::FieldElement<BaseField = Self::BaseField>,
+    {
+        DefaultConstraintEvaluator::new(air, aux_rand_elements, composition_coefficients)
+    }
+}
+
+// CounterAssertionsProver
+// ================================================================================================
+
+/// Prover for [CounterAir] which enforces a set of additional assertions against the trace.
+pub struct CounterAssertionsProver {
+    inputs: Vec<BaseElement>,
+    assertions: Vec<Assertion<BaseElement>>,
+    pub options: ProofOptions,
+}
+
+impl CounterAssertionsProver {
+    pub fn new(inputs: Vec<BaseElement>, assertions: Vec<Assertion<BaseElement>>) -> Self {
+        Self {
+            inputs,
+            assertions,
+            options: ProofOptions::new(1, 2, 0, FieldExtension::None, 2, 1),
+        }
+    }
+}
+
+impl Prover for CounterAssertionsProver {
+    type BaseField = BaseElement;
+    type Air = AirWithAssertions<CounterAir>;
+    type Trace = TraceTable<BaseElement>;
+    type HashFn = Blake3_256<BaseElement>;
+    type VC = MerkleTree<Blake3_256<BaseElement>>;
+    type RandomCoin = DefaultRandomCoin<Self::HashFn>;
+    type TraceLde<E: FieldElement<BaseField = BaseElement>> =
+        DefaultTraceLde<E, Self::HashFn, Self::VC>;
+    type ConstraintCommitment<E: FieldElement<BaseField = Self::BaseField>> =
+        DefaultConstraintCommitment<E, Blake3_256<BaseElement>, Self::VC>;
+    type ConstraintEvaluator<'a, E: FieldElement<BaseField = BaseElement>> =
+        DefaultConstraintEvaluator<'a, Self::Air, E>;
+
+    fn get_pub_inputs(&self, _trace: &Self::Trace) -> <<Self as Prover>::Air as Air>::PublicInputs {
+        InputsWithAssertions::new(CounterInputs(self.inputs.clone()), self.assertions.clone())
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+
+    fn new_trace_lde<E>(
+        &self,
+        trace_info: &TraceInfo,
+        main_trace: &ColMatrix<Self::BaseField>,
+        domain: &StarkDomain<Self::BaseField>,
+        partition_option: PartitionOptions,
+    ) -> (Self::TraceLde<E>, TracePolyTable<E>)
+    where
+        E: math::FieldElement<BaseField = Self::BaseField>,
+    {
+        DefaultTraceLde::new(trace_info, main_trace, domain, partition_option)
+    }
+
+    fn build_constraint_commitment<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        composition_poly_trace: CompositionPolyTrace<E>,
+        num_constraint_composition_columns: usize,
+        domain: &StarkDomain<Self::BaseField>,
+        partition_options: PartitionOptions,
+    ) -> (Self::ConstraintCommitment<E>, CompositionPoly<E>) {
+        DefaultConstraintCommitment::new(
+            composition_poly_trace,
+            num_constraint_composition_columns,
+            domain,
+            partition_options,
+        )
+    }
+
+    fn new_evaluator<'a, E>(
+        &self,
+        air: &'a Self::Air,
+        aux_rand_elements: Option<AuxRandElements<E>>,
+        composition_coefficients: ConstraintCompositionCoefficients<E>,
+    ) -> Self::ConstraintEvaluator<'a, E>
+    where
+        E: math::FieldElement<BaseField = Self::BaseField>,
+    {
+        DefaultConstraintEvaluator::new(air, aux_rand_elements, composition_coefficients)
+    }
+}
+
+// VirtualFibAir
+// ================================================================================================
+
+/// AIR for a Fibonacci sequence stored in two columns, where the sum of the two columns is
+/// described as a virtual column rather than being computed inline.
+pub struct VirtualFibAir {
+    context: AirContext<BaseElement>,
+    result: BaseElement,
+}
+
+impl Air for VirtualFibAir {
+    type BaseField = BaseElement;
+    type GkrProof = ();
+    type GkrVerifier = ();
+
+    type PublicInputs = BaseElement;
+
+    fn new(trace_info: TraceInfo, pub_inputs: Self::PublicInputs, options: ProofOptions) -> Self {
+        let degrees = vec![TransitionConstraintDegree::new(1), TransitionConstraintDegree::new(1)];
+        Self {
+            context: AirContext::new(trace_info, degrees, 3, options).set_num_virtual_columns(1),
+            result: pub_inputs,
+        }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+
+    fn evaluate_virtual_columns<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        row: &[E],
+        result: &mut [E],
+    ) {
+        result[0] = row[0] + row[1];
+    }
+
+    fn evaluate_transition<E: math::FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        result[0] = frame.next()[0] - frame.current()[1];
+        result[1] = frame.next()[1] - frame.current_virtual()[0];
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        let last_step = self.trace_length() - 1;
+        vec![
+            Assertion::single(0, 0, BaseElement::ONE),
+            Assertion::single(1, 0, BaseElement::ONE),
+            Assertion::single(1, last_step, self.result),
+        ]
+    }
+}
+
+// VirtualFibProver
+// ================================================================================================
+
+pub struct VirtualFibProver {
+    pub options: ProofOptions,
+}
+
+impl VirtualFibProver {
+    pub fn new() -> Self {
+        Self {
+            options: ProofOptions::new(4, 4, 0, FieldExtension::Quadratic, 2, 1),
+        }
+    }
+
+    pub fn build_trace(trace_len: usize) -> TraceTable<BaseElement> {
+        let mut trace = TraceTable::new(2, trace_len);
+        trace.fill(
+            |state| {
+                state[0] = BaseElement::ONE;
+                state[1] = BaseElement::ONE;
+            },
+            |_, state| {
+                let sum = state[0] + state[1];
+                state[0] = state[1];
+                state[1] = sum;
+            },
+        );
+        trace
+    }
+}
+
+impl Prover for VirtualFibProver {
+    type BaseField = BaseElement;
+    type Air = VirtualFibAir;
+    type Trace = TraceTable<BaseElement>;
+    type HashFn = Blake3_256<BaseElement>;
+    type VC = MerkleTree<Blake3_256<BaseElement>>;
+    type RandomCoin = DefaultRandomCoin<Self::HashFn>;
+    type TraceLde<E: FieldElement<BaseField = BaseElement>> =
+        DefaultTraceLde<E, Self::HashFn, Self::VC>;
+    type ConstraintCommitment<E: FieldElement<BaseField = Self::BaseField>> =
+        DefaultConstraintCommitment<E, Blake3_256<BaseElement>, Self::VC>;
+    type ConstraintEvaluator<'a, E: FieldElement<BaseField = BaseElement>> =
+        DefaultConstraintEvaluator<'a, VirtualFibAir, E>;
+
+    fn get_pub_inputs(&self, trace: &Self::Trace) -> <<Self as Prover>::Air as Air>::PublicInputs {
+        trace.get(1, trace.length() - 1)
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+
+    fn new_trace_lde<E>(
+        &self,
+        trace_info: &TraceInfo,
+        main_trace: &ColMatrix<Self::BaseField>,
+        domain: &StarkDomain<Self::BaseField>,
+        partition_option: PartitionOptions,
+    ) -> (Self::TraceLde<E>, TracePolyTable<E>)
+    where
+        E: math::FieldElement<BaseField = Self::BaseField>,
+    {
+        DefaultTraceLde::new(trace_info, main_trace, domain, partition_option)
+    }
+
+    fn build_constraint_commitment<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        composition_poly_trace: CompositionPolyTrace<E>,
+        num_constraint_composition_columns: usize,
+        domain: &StarkDomain<Self::BaseField>,
+        partition_options: PartitionOptions,
+    ) -> (Self::ConstraintCommitment<E>, CompositionPoly<E>) {
+        DefaultConstraintCommitment::new(
+            composition_poly_trace,
+            num_constraint_composition_columns,
+            domain,
+            partition_options,
+        )
+    }
+
+    fn new_evaluator<'a, E>(
+        &self,
+        air: &'a Self::Air,
+        aux_rand_elements: Option<AuxRandElements<E>>,
+        composition_coefficients: ConstraintCompositionCoefficients<E>,
+    ) -> Self::ConstraintEvaluator<'a, E>
+    where
+        E: math::FieldElement<BaseField = Self::BaseField>,
+    {
+        DefaultConstraintEvaluator::new(air, aux_rand_elements, composition_coefficients)
+    }
+}
+
+// WideAir
+// ================================================================================================
+
+pub const WIDE_TRACE_WIDTH: usize = 300;
+
+/// AIR for a trace in which every column is incremented by one at every step, and column `i`
+/// starts with value `i`.
+pub struct WideAir {
+    context: AirContext<BaseElement>,
+}
+
+impl Air for WideAir {
+    type BaseField = BaseElement;
+    type GkrProof = ();
+    type GkrVerifier = ();
+
+    type PublicInputs = ();
+
+    fn new(trace_info: TraceInfo, _pub_inputs: Self::PublicInputs, options: ProofOptions) -> Self {
+        let width = trace_info.main_trace_width();
+        let degrees = vec![TransitionConstraintDegree::new(1); width];
+        Self {
+            context: AirContext::new(trace_info, degrees, width, options),
+        }
+    }
+
+    fn context(&self) -> &AirContext<Self::BaseField> {
+        &self.context
+    }
+
+    fn evaluate_transition<E: math::FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        frame: &EvaluationFrame<E>,
+        _periodic_values: &[E],
+        result: &mut [E],
+    ) {
+        for (i, result) in result.iter_mut().enumerate() {
+            *result = frame.next()[i] - frame.current()[i] - E::ONE;
+        }
+    }
+
+    fn get_assertions(&self) -> Vec<Assertion<Self::BaseField>> {
+        (0..self.trace_info().main_trace_width())
+            .map(|i| Assertion::single(i, 0, BaseElement::new(i as u64)))
+            .collect()
+    }
+}
+
+// WideProver
+// ================================================================================================
+
+pub struct WideProver {
+    options: ProofOptions,
+}
+
+impl WideProver {
+    pub fn new() -> Self {
+        Self {
+            options: ProofOptions::new(4, 4, 0, FieldExtension::None, 2, 1),
+        }
+    }
+
+    pub fn build_trace(width: usize, trace_len: usize) -> TraceTable<BaseElement> {
+        let mut trace = TraceTable::new(width, trace_len);
+        trace.fill(
+            |state| {
+                for (i, value) in state.iter_mut().enumerate() {
+                    *value = BaseElement::new(i as u64);
+                }
+            },
+            |_, state| {
+                for value in state.iter_mut() {
+                    *value += BaseElement::ONE;
+                }
+            },
+        );
+        trace
+    }
+}
+
+impl Prover for WideProver {
+    type BaseField = BaseElement;
+    type Air = WideAir;
+    type Trace = TraceTable<BaseElement>;
+    type HashFn = Blake3_256<BaseElement>;
+    type VC = MerkleTree<Blake3_256<BaseElement>>;
+    type RandomCoin = DefaultRandomCoin<Self::HashFn>;
+    type TraceLde<E: FieldElement<BaseField = BaseElement>> =
+        DefaultTraceLde<E, Self::HashFn, Self::VC>;
+    type ConstraintCommitment<E: FieldElement<BaseField = Self::BaseField>> =
+        DefaultConstraintCommitment<E, Blake3_256<BaseElement>, Self::VC>;
+    type ConstraintEvaluator<'a, E: FieldElement<BaseField = BaseElement>> =
+        DefaultConstraintEvaluator<'a, WideAir, E>;
+
+    fn get_pub_inputs(&self, _trace: &Self::Trace) -> <<Self as Prover>::Air as Air>::PublicInputs {
+    }
+
+    fn options(&self) -> &ProofOptions {
+        &self.options
+    }
+
+    fn new_trace_lde<E>(
+        &self,
+        trace_info: &TraceInfo,
+        main_trace: &ColMatrix<Self::BaseField>,
+        domain: &StarkDomain<Self::BaseField>,
+        partition_option: PartitionOptions,
+    ) -> (Self::TraceLde<E>, TracePolyTable<E>)
+    where
+        E: math::FieldElement<BaseField = Self::BaseField>,
+    {
+        DefaultTraceLde::new(trace_info, main_trace, domain, partition_option)
+    }
+
+    fn build_constraint_commitment<E: FieldElement<BaseField = Self::BaseField>>(
+        &self,
+        composition_poly_trace: CompositionPolyTrace<E>,
+        num_constraint_composition_columns: usize,
+        domain: &StarkDomain<Self::BaseField>,
+        partition_options: PartitionOptions,
+    ) -> (Self::ConstraintCommitment<E>, CompositionPoly<E>) {
+        DefaultConstraintCommitment::new(
+            composition_poly_trace,
+            num_constraint_composition_columns,
+            domain,
+            partition_options,
+        )
+    }
+
+    fn new_evaluator<'a, E>(
+        &self,
+        air: &'a Self::Air,
+        aux_rand_elements: Option<AuxRandElements<E>>,
+        composition_coefficients: ConstraintCompositionCoefficients<E>,
+    ) -> Self::ConstraintEvaluator<'a, E>
+    where
+        E: math::FieldElement<BaseField = Self::BaseField>,
+    {
+        DefaultConstraintEvaluator::new(air, aux_rand_elements, composition_coefficients)
+    }
+}
@@ -0,0 +1,1552 @@
+// Copyright (c) Facebook, Inc. and its affiliates.
+//
+// This source code is licensed under the MIT license found in the
+// LICENSE file in the root directory of this source tree.
+
+use std::{string::ToString, vec, vec::Vec};
+
+use crypto::{ElementHasher, Hasher, MerkleTree};
+use prover::{
+    crypto::{
+        hashers::{Blake3_256, FnHasher, HashFunctions, Rp64_256},
+        DefaultRandomCoin, Digest, RandomCoin,
+    },
+    math::{
+        fields::{f64::BaseElement, QuadExtension},
+        ExtensionOf, FieldElement, StarkField,
+    },
+    Deserializable, Serializable, SliceReader,
+};
+
+use super::*;
+
+mod fixtures;
+use fixtures::*;
+
+const AUX_TRACE_WIDTH: usize = 2;
+
+#[test]
+fn test_complex_lagrange_kernel_air() {
+    let trace = LagrangeComplexTrace::new(2_usize.pow(10), AUX_TRACE_WIDTH);
+
+    let prover = LagrangeComplexProver::new(AUX_TRACE_WIDTH);
+
+    let proof = prover.prove(trace).unwrap();
+
+    verify::<
+        LagrangeKernelComplexAir,
+        Blake3_256<BaseElement>,
+        DefaultRandomCoin<Blake3_256<BaseElement>>,
+        MerkleTree<Blake3_256<BaseElement>>,
+    >(proof, (), &AcceptableOptions::MinConjecturedSecurity(0))
+    .unwrap()
+}
+
+#[test]
+fn test_extension_witness_air() {
+    // the auxiliary segment does not depend on randomness and contains extension field elements
+    let trace = LagrangeComplexTrace::new(2_usize.pow(6), 1);
+    assert_eq!(0, trace.info().get_num_aux_segment_rand_elements());
+
+    for field_extension in [
+        FieldExtension::None,
+        FieldExtension::Quadratic,
+        FieldExtension::Cubic,
+        FieldExtension::Sextic,
+    ] {
+        let prover = ExtensionWitnessProver::new(field_extension);
+        let proof = prover.prove(trace.clone()).unwrap();
+
+        // the proof should survive serialization round trip
+        let proof = Proof::from_bytes(&proof.to_bytes()).unwrap();
+
+        verify::<
+            ExtensionWitnessAir,
+            Blake3_256<BaseElement>,
+            DefaultRandomCoin<Blake3_256<BaseElement>>,
+            MerkleTree<Blake3_256<BaseElement>>,
+        >(proof, (), &AcceptableOptions::MinConjecturedSecurity(0))
+        .unwrap()
+    }
+}
+
+#[cfg(feature = "wasm")]
+#[test]
+fn test_verify_bytes() {
+    let trace = LagrangeComplexTrace::new(2_usize.pow(6), 1);
+    let prover = ExtensionWitnessProver::new(FieldExtension::Quadratic);
+    let proof = prover.prove(trace).unwrap();
+
+    // only the success path is checked here since JsError can be constructed on wasm32 targets
+    // only
+    assert!(verify_bytes::<
+        ExtensionWitnessAir,
+        Blake3_256<BaseElement>,
+        DefaultRandomCoin<Blake3_256<BaseElement>>,
+        MerkleTree<Blake3_256<BaseElement>>,
+    >(&proof.to_bytes(), &().to_bytes(), &AcceptableOptions::MinConjecturedSecurity(0))
+    .is_ok());
+}
+
+#[test]
+fn test_aux_trace_hash() {
+    let trace = LagrangeComplexTrace::new(2_usize.pow(6), 1);
+
+    // commit to the auxiliary segment using RPO while using BLAKE3 for everything else
+    let prover =
+        ExtensionWitnessProver::new(FieldExtension::Quadratic).with_aux_hash::<Rp64_256>(1);
+    let proof = prover.prove(trace).unwrap();
+    assert_eq!(Some(1), proof.context.aux_hash_id());
+
+    verify_with_aux_hash::<
+        ExtensionWitnessAir,
+        Blake3_256<BaseElement>,
+        DefaultRandomCoin<Blake3_256<BaseElement>>,
+        MerkleTree<Blake3_256<BaseElement>>,
+        Rp64_256,
+        MerkleTree<Rp64_256>,
+    >(proof.clone(), (), 1, &AcceptableOptions::MinConjecturedSecurity(0))
+    .unwrap();
+
+    // the proof should be rejected if the recorded identifier differs from the expected one
+    let result = verify_with_aux_hash::<
+        ExtensionWitnessAir,
+        Blake3_256<BaseElement>,
+        DefaultRandomCoin<Blake3_256<BaseElement>>,
+        MerkleTree<Blake3_256<BaseElement>>,
+        Rp64_256,
+        MerkleTree<Rp64_256>,
+    >(proof.clone(), (), 2, &AcceptableOptions::MinConjecturedSecurity(0));
+    assert_eq!(Err(VerifierError::UnsupportedAuxHashFunction(Some(1))), result);
+
+    // the proof should not verify if the auxiliary segment is checked against the main hash
+    let result = verify::<
+        ExtensionWitnessAir,
+        Blake3_256<BaseElement>,
+        DefaultRandomCoin<Blake3_256<BaseElement>>,
+        MerkleTree<Blake3_256<BaseElement>>,
+    >(proof, (), &AcceptableOptions::MinConjecturedSecurity(0));
+    assert_eq!(Err(VerifierError::UnsupportedAuxHashFunction(Some(1))), result);
+}
+
+#[test]
+fn test_mismatched_aux_trace_width() {
+    // the trace declares two auxiliary columns, but the prover builds only one
+    let trace = LagrangeComplexTrace::new(2_usize.pow(6), 2);
+    let prover = ExtensionWitnessProver::new(FieldExtension::None);
+    assert_eq!(ProverError::MismatchedAuxTraceWidth(2, 1), prover.prove(trace).unwrap_err());
+}
+
+#[test]
+fn test_committed_inputs_air() {
+    let inputs = (0..64_u32).map(BaseElement::from).collect::<Vec<_>>();
+    let pub_inputs_commitment = Blake3_256::<BaseElement>::hash_elements(&inputs);
+
+    let prover = CounterProver::new(inputs.clone());
+    let proof = prover.prove_with_committed_inputs(CounterProver::build_trace(8)).unwrap();
+
+    // the proof should verify against the commitment to the public inputs
+    verify_with_committed_inputs::<
+        CounterAir,
+        Blake3_256<BaseElement>,
+        DefaultRandomCoin<Blake3_256<BaseElement>>,
+        MerkleTree<Blake3_256<BaseElement>>,
+    >(
+        proof.clone(),
+        pub_inputs_commitment,
+        &AcceptableOptions::MinConjecturedSecurity(0),
+    )
+    .unwrap();
+
+    // but not against a commitment to different public inputs
+    let wrong_commitment = Blake3_256::<BaseElement>::hash_elements(&inputs[1..]);
+    assert!(verify_with_committed_inputs::<
+        CounterAir,
+        Blake3_256<BaseElement>,
+        DefaultRandomCoin<Blake3_256<BaseElement>>,
+        MerkleTree<Blake3_256<BaseElement>>,
+    >(proof.clone(), wrong_commitment, &AcceptableOptions::MinConjecturedSecurity(0))
+    .is_err());
+
+    // and also not against the public inputs themselves
+    assert!(verify::<
+        CounterAir,
+        Blake3_256<BaseElement>,
+        DefaultRandomCoin<Blake3_256<BaseElement>>,
+        MerkleTree<Blake3_256<BaseElement>>,
+    >(proof, CounterInputs(inputs), &AcceptableOptions::MinConjecturedSecurity(0))
+    .is_err());
+}
+
+#[test]
+fn test_committed_inputs_mismatched_inputs() {
+    let inputs = (0..16_u32).map(BaseElement::from).collect::<Vec<_>>();
+    let other_inputs = (16..32_u32).map(BaseElement::from).collect::<Vec<_>>();
+    let commitment = Blake3_256::<BaseElement>::hash_elements(&inputs);
+    let other_commitment = Blake3_256::<BaseElement>::hash_elements(&other_inputs);
+
+    // a proof generated against one set of inputs should not verify against a commitment to a
+    // different set of inputs for the same trace
+    let proof = CounterProver::new(other_inputs)
+        .prove_with_committed_inputs(CounterProver::build_trace(8))
+        .unwrap();
+    let result = verify_with_committed_inputs::<
+        CounterAir,
+        Blake3_256<BaseElement>,
+        DefaultRandomCoin<Blake3_256<BaseElement>>,
+        MerkleTree<Blake3_256<BaseElement>>,
+    >(proof.clone(), commitment, &AcceptableOptions::MinConjecturedSecurity(0));
+    assert!(result.is_err());
+
+    // since the commitment is not opened, the proof verifies against the commitment to the inputs
+    // the prover chose, even though the trace does not depend on these inputs
+    verify_with_committed_inputs::<
+        CounterAir,
+        Blake3_256<BaseElement>,
+        DefaultRandomCoin<Blake3_256<BaseElement>>,
+        MerkleTree<Blake3_256<BaseElement>>,
+    >(proof, other_commitment, &AcceptableOptions::MinConjecturedSecurity(0))
+    .unwrap();
+}
+
+#[test]
+fn test_verify_returning_digest() {
+    let inputs = (0..16_u32).map(BaseElement::from).collect::<Vec<_>>();
+    let prover = CounterProver::new(inputs.clone());
+    let proof = prover.prove(CounterProver::build_trace(8)).unwrap();
+
+    let digest = verify_returning_digest::<
+        CounterAir,
+        Blake3_256<BaseElement>,
+        DefaultRandomCoin<Blake3_256<BaseElement>>,
+        MerkleTree<Blake3_256<BaseElement>>,
+    >(
+        proof,
+        CounterInputs(inputs.clone()),
+        &AcceptableOptions::MinConjecturedSecurity(0),
+    )
+    .unwrap();
+    assert_eq!(Blake3_256::<BaseElement>::hash_elements(&inputs), digest);
+
+    // the digest should be usable to verify proofs generated against committed inputs
+    let proof = prover.prove_with_committed_inputs(CounterProver::build_trace(8)).unwrap();
+    verify_with_committed_inputs::<
+        CounterAir,
+        Blake3_256<BaseElement>,
+        DefaultRandomCoin<Blake3_256<BaseElement>>,
+        MerkleTree<Blake3_256<BaseElement>>,
+    >(proof, digest, &AcceptableOptions::MinConjecturedSecurity(0))
+    .unwrap();
+}
+
+#[test]
+fn test_prove_with_coin() {
+    type Coin = DefaultRandomCoin<Blake3_256<BaseElement>>;
+    let inputs = (0..8_u32).map(BaseElement::from).collect::<Vec<_>>();
+    let prover = CounterProver::new(inputs.clone());
+
+    // the coin carries the state of a transcript into which some other values have already been
+    // absorbed
+    let transcript_seed = [BaseElement::new(42)];
+    let (proof, mut prover_coin) = prover
+        .prove_with_coin(CounterProver::build_trace(16), Coin::new(&transcript_seed))
+        .unwrap();
+
+    // the verifier should end up with the coin in the same state as the prover
+    let mut verifier_coin = verify_with_coin::<
+        CounterAir,
+        Blake3_256<BaseElement>,
+        Coin,
+        MerkleTree<Blake3_256<BaseElement>>,
+    >(
+        proof.clone(),
+        CounterInputs(inputs.clone()),
+        &AcceptableOptions::MinConjecturedSecurity(0),
+        Coin::new(&transcript_seed),
+    )
+    .unwrap();
+    assert_eq!(
+        prover_coin.draw::<BaseElement>().unwrap(),
+        verifier_coin.draw::<BaseElement>().unwrap()
+    );
+
+    // the proof should not verify against a coin in a different state
+    assert!(verify_with_coin::<
+        CounterAir,
+        Blake3_256<BaseElement>,
+        Coin,
+        MerkleTree<Blake3_256<BaseElement>>,
+    >(
+        proof.clone(),
+        CounterInputs(inputs.clone()),
+        &AcceptableOptions::MinConjecturedSecurity(0),
+        Coin::new(&[BaseElement::new(43)]),
+    )
+    .is_err());
+
+    // and also not as a standalone proof
+    assert!(
+        verify::<CounterAir, Blake3_256<BaseElement>, Coin, MerkleTree<Blake3_256<BaseElement>>>(
+            proof,
+            CounterInputs(inputs),
+            &AcceptableOptions::MinConjecturedSecurity(0)
+        )
+        .is_err()
+    );
+}
+
+#[test]
+fn test_ood_evaluations() {
+    let trace = LagrangeComplexTrace::new(2_usize.pow(6), 1);
+    let prover = ExtensionWitnessProver::new(FieldExtension::Quadratic);
+    let (proof, transcript) = prover
+        .prove_with_transcript::<QuadExtension<BaseElement>>(trace.clone())
+        .unwrap();
+    let air = ExtensionWitnessAir::new(trace.info().clone(), (), prover.options().clone());
+
+    let evaluations = proof.ood_evaluations::<QuadExtension<BaseElement>>().unwrap();
+    assert_eq!(trace.info().aux_segment_width(), evaluations.aux_current.len());
+    assert_eq!(trace.info().aux_segment_width(), evaluations.aux_next.len());
+    assert_eq!(None, evaluations.lagrange_kernel);
+    assert_eq!(
+        air.context().num_constraint_composition_columns(),
+        evaluations.constraint_evaluations.len()
+    );
+
+    // main trace evaluations are evaluations of main trace polynomials at z and z * g
+    let z = transcript.ood_point;
+    let g = BaseElement::get_root_of_unity(trace.length().ilog2());
+    let main_polys = trace.main_segment().interpolate_columns();
+    assert_eq!(main_polys.evaluate_columns_at(z), evaluations.main_current);
+    assert_eq!(main_polys.evaluate_columns_at(z.mul_base(g)), evaluations.main_next);
+
+    // a frame which does not match the trace info cannot be parsed
+    let wider_info = TraceInfo::new_multi_segment(
+        trace.info().main_trace_width() + 1,
+        trace.info().aux_segment_width(),
+        0,
+        trace.length(),
+        vec![],
+    );
+    assert!(proof.ood_frame.evaluations::<QuadExtension<BaseElement>>(&wider_info).is_err());
+}
+
+#[test]
+fn test_prove_with_transcript() {
+    let trace = LagrangeComplexTrace::new(2_usize.pow(6), 1);
+    let prover = ExtensionWitnessProver::new(FieldExtension::Quadratic);
+
+    // the proof is the same as the one generated without recording the transcript
+    let (proof, transcript) = prover
+        .prove_with_transcript::<QuadExtension<BaseElement>>(trace.clone())
+        .unwrap();
+    assert_eq!(prover.prove(trace.clone()).unwrap(), proof);
+
+    // the transcript contains a value for every draw made by the prover
+    let air = ExtensionWitnessAir::new(trace.info().clone(), (), prover.options().clone());
+    assert!(transcript.aux_rand_elements.is_some());
+    let coefficients = &transcript.constraint_composition_coeffs;
+    assert_eq!(air.context().num_transition_constraints(), coefficients.transition.len());
+    assert_eq!(air.context().num_assertions(), coefficients.boundary.len());
+    let coefficients = &transcript.deep_composition_coeffs;
+    assert_eq!(trace.info().width(), coefficients.trace.len());
+    assert_eq!(
+        air.context().num_constraint_composition_columns(),
+        coefficients.constraints.len()
+    );
+    assert_eq!(proof.fri_proof.num_layers(), transcript.fri_alphas.len());
+    assert_eq!(proof.pow_nonce, transcript.pow_nonce);
+    assert_eq!(prover.options().num_queries(), transcript.query_positions.len());
+
+    // the transcript must be recorded in the field specified by proof options
+    let err = prover.prove_with_transcript::<BaseElement>(trace).unwrap_err();
+    assert_eq!(ProverError::MismatchedFieldExtension(2, 1), err);
+}
+
+#[test]
+fn test_verifier_error_code() {
+    let inputs = (0..8_u32).map(BaseElement::from).collect::<Vec<_>>();
+    let prover = CounterProver::new(inputs.clone());
+    let proof = prover.prove(CounterProver::build_trace(16)).unwrap();
+
+    // the proof was generated with options different from the only acceptable ones
+    let options = ProofOptions::new(2, 4, 0, FieldExtension::None, 2, 1);
+    let err = verify::<
+        CounterAir,
+        Blake3_256<BaseElement>,
+        DefaultRandomCoin<Blake3_256<BaseElement>>,
+        MerkleTree<Blake3_256<BaseElement>>,
+    >(
+        proof.clone(),
+        CounterInputs(inputs.clone()),
+        &AcceptableOptions::OptionSet(vec![options]),
+    )
+    .unwrap_err();
+    assert_eq!(VerifierError::UnacceptableProofOptions, err);
+    assert_eq!(13, err.code());
+
+    // the proof does not match the public inputs
+    let err = verify::<
+        CounterAir,
+        Blake3_256<BaseElement>,
+        DefaultRandomCoin<Blake3_256<BaseElement>>,
+        MerkleTree<Blake3_256<BaseElement>>,
+    >(
+        proof,
+        CounterInputs(inputs[1..].to_vec()),
+        &AcceptableOptions::MinConjecturedSecurity(0),
+    )
+    .unwrap_err();
+    assert_ne!(0, err.code());
+    assert_ne!(13, err.code());
+}
+
+#[test]
+fn test_prove_with_domain_cache() {
+    let inputs = (0..8_u32).map(BaseElement::from).collect::<Vec<_>>();
+    let prover = CounterProver::new(inputs.clone());
+
+    let mut domain_cache = DomainCache::new();
+    assert!(domain_cache.is_empty());
+
+    // the domain cache should be re-used for traces of the same length, and rebuilt for traces of
+    // a different length; in all cases, the proofs should be the same as the ones generated
+    // without a domain cache
+    for trace_length in [8, 8, 16, 8] {
+        let proof = prover
+            .prove_with_domain_cache(CounterProver::build_trace(trace_length), &mut domain_cache)
+            .unwrap();
+        assert!(!domain_cache.is_empty());
+
+        let expected = prover.prove(CounterProver::build_trace(trace_length)).unwrap();
+        assert_eq!(expected, proof);
+
+        verify::<
+            CounterAir,
+            Blake3_256<BaseElement>,
+            DefaultRandomCoin<Blake3_256<BaseElement>>,
+            MerkleTree<Blake3_256<BaseElement>>,
+        >(
+            proof,
+            CounterInputs(inputs.clone()),
+            &AcceptableOptions::MinConjecturedSecurity(0),
+        )
+        .unwrap();
+    }
+
+    domain_cache.clear();
+    assert!(domain_cache.is_empty());
+}
+
+#[test]
+fn test_prove_many() {
+    let inputs = (0..8_u32).map(BaseElement::from).collect::<Vec<_>>();
+    let prover = CounterProver::new(inputs.clone());
+
+    let trace_lengths = [8, 16, 8, 32, 16];
+    let build_traces = || trace_lengths.iter().map(|&n| CounterProver::build_trace(n)).collect();
+
+    // proofs should be returned in the order of the traces and be the same as the ones generated
+    // one at a time, regardless of the number of workers
+    let expected = prover.prove_many_with_concurrency(build_traces(), 1);
+    assert_eq!(trace_lengths.len(), expected.len());
+    for (&trace_length, proof) in trace_lengths.iter().zip(expected.iter()) {
+        let proof = proof.as_ref().unwrap();
+        assert_eq!(&prover.prove(CounterProver::build_trace(trace_length)).unwrap(), proof);
+
+        verify::<
+            CounterAir,
+            Blake3_256<BaseElement>,
+            DefaultRandomCoin<Blake3_256<BaseElement>>,
+            MerkleTree<Blake3_256<BaseElement>>,
+        >(
+            proof.clone(),
+            CounterInputs(inputs.clone()),
+            &AcceptableOptions::MinConjecturedSecurity(0),
+        )
+        .unwrap();
+    }
+
+    for concurrency in [2, 3, 8] {
+        let proofs = prover.prove_many_with_concurrency(build_traces(), concurrency);
+        assert_eq!(expected, proofs);
+    }
+    assert_eq!(expected, prover.prove_many(build_traces()));
+    assert!(prover.prove_many(Vec::new()).is_empty());
+}
+
+#[test]
+fn test_prove_ref() {
+    let inputs = (0..8_u32).map(BaseElement::from).collect::<Vec<_>>();
+    let prover = CounterProver::new(inputs.clone());
+
+    // the trace is retained by the caller, and the proof is the same as the one generated by
+    // consuming the trace
+    let trace = CounterProver::build_trace(16);
+    let proof = prover.prove_ref(&trace).unwrap();
+    assert_eq!(16, trace.length());
+    assert_eq!(prover.prove(trace).unwrap(), proof);
+
+    verify::<
+        CounterAir,
+        Blake3_256<BaseElement>,
+        DefaultRandomCoin<Blake3_256<BaseElement>>,
+        MerkleTree<Blake3_256<BaseElement>>,
+    >(proof, CounterInputs(inputs), &AcceptableOptions::MinConjecturedSecurity(0))
+    .unwrap();
+}
+
+#[test]
+fn test_prove_with_committed_trace() {
+    let inputs = (0..8_u32).map(BaseElement::from).collect::<Vec<_>>();
+    let mut prover = CounterProver::new(inputs.clone());
+    let trace = CounterProver::build_trace(16);
+    let committed_trace = prover.commit_to_trace::<BaseElement>(&trace);
+    assert_eq!(2, committed_trace.blowup_factor());
+
+    // proofs generated from the committed trace with different options (but the same blowup
+    // factor) are the same as the proofs generated from the trace itself
+    for options in [
+        ProofOptions::new(1, 2, 0, FieldExtension::None, 2, 1),
+        ProofOptions::new(12, 2, 4, FieldExtension::None, 4, 3),
+    ] {
+        prover.options = options;
+        let proof = prover.prove_with_committed_trace(&committed_trace).unwrap();
+        assert_eq!(prover.prove_ref(&trace).unwrap(), proof);
+
+        verify::<
+            CounterAir,
+            Blake3_256<BaseElement>,
+            DefaultRandomCoin<Blake3_256<BaseElement>>,
+            MerkleTree<Blake3_256<BaseElement>>,
+        >(
+            proof,
+            CounterInputs(inputs.clone()),
+            &AcceptableOptions::MinConjecturedSecurity(0),
+        )
+        .unwrap();
+    }
+
+    // the committed trace cannot be reused with a different blowup factor or field extension
+    prover.options = ProofOptions::new(1, 4, 0, FieldExtension::None, 2, 1);
+    assert_eq!(
+        Err(ProverError::MismatchedCommittedTrace),
+        prover.prove_with_committed_trace(&committed_trace)
+    );
+    prover.options = ProofOptions::new(1, 2, 0, FieldExtension::Quadratic, 2, 1);
+    assert_eq!(
+        Err(ProverError::MismatchedFieldExtension(2, 1)),
+        prover.prove_with_committed_trace(&committed_trace)
+    );
+
+    // traces with auxiliary segments can be proven from a committed trace as well
+    let trace = LagrangeComplexTrace::new(2_usize.pow(6), AUX_TRACE_WIDTH);
+    let prover = LagrangeComplexProver::new(AUX_TRACE_WIDTH);
+    let committed_trace = prover.commit_to_trace::<BaseElement>(&trace);
+    let proof = prover.prove_with_committed_trace(&committed_trace).unwrap();
+    assert_eq!(prover.prove_ref(&trace).unwrap(), proof);
+    assert_eq!(proof, prover.prove_with_committed_trace(&committed_trace).unwrap());
+}
+
+#[test]
+fn test_trace_commitments() {
+    type Blake3 = Blake3_256<BaseElement>;
+
+    // a proof of a single-segment trace commits to the main trace segment only
+    let inputs = (0..8_u32).map(BaseElement::from).collect::<Vec<_>>();
+    let prover = CounterProver::new(inputs);
+    let trace = CounterProver::build_trace(16);
+    let main_commitment = prover.commit_to_trace::<BaseElement>(&trace).main_trace_commitment();
+    let proof = prover.prove(trace).unwrap();
+    assert_eq!(vec![main_commitment], proof.trace_commitments::<Blake3>().unwrap());
+
+    // the main trace segment commitment comes before the auxiliary trace segment commitment
+    let trace = LagrangeComplexTrace::new(2_usize.pow(6), AUX_TRACE_WIDTH);
+    let prover = LagrangeComplexProver::new(AUX_TRACE_WIDTH);
+    let main_commitment = prover.commit_to_trace::<BaseElement>(&trace).main_trace_commitment();
+    let proof = prover.prove(trace).unwrap();
+    let commitments = proof.trace_commitments::<Blake3>().unwrap();
+    assert_eq!(2, commitments.len());
+    assert_eq!(main_commitment, commitments[0]);
+    assert_ne!(commitments[0], commitments[1]);
+}
+
+#[test]
+fn test_estimate_proof_size() {
+    let inputs = (0..8_u32).map(BaseElement::from).collect::<Vec<_>>();
+    let mut prover = CounterProver::new(inputs);
+
+    for (trace_length, options) in [
+        (1024, ProofOptions::new(32, 8, 0, FieldExtension::None, 4, 31)),
+        (1024, ProofOptions::new(28, 16, 0, FieldExtension::Quadratic, 8, 7)),
+        (4096, ProofOptions::new(40, 4, 0, FieldExtension::Cubic, 2, 15)),
+    ] {
+        prover.options = options.clone();
+        let trace = CounterProver::build_trace(trace_length);
+        let trace_info = trace.info().clone();
+        let proof_size = prover.prove(trace).unwrap().to_bytes().len();
+
+        // the counter AIR has a single degree 1 constraint, and thus, requires a single column
+        let estimate =
+            options.estimate_proof_size::<BaseElement, Blake3_256<BaseElement>>(&trace_info, 1);
+        assert!(estimate.abs_diff(proof_size) * 20 <= proof_size, "{estimate} vs {proof_size}");
+    }
+}
+
+#[test]
+#[cfg(feature = "external-queries")]
+fn test_external_query_positions() {
+    let inputs = (0..8_u32).map(BaseElement::from).collect::<Vec<_>>();
+    let mut prover = CounterProver::new(inputs.clone());
+
+    // the LDE domain has 32 positions; duplicates are removed by both, prover and verifier
+    let positions = vec![31, 0, 7, 7];
+    prover.query_positions = Some(positions.clone());
+    let proof = prover.prove(CounterProver::build_trace(16)).unwrap();
+    assert_eq!(3, proof.num_unique_queries);
+
+    verify_with_query_positions::<
+        CounterAir,
+        Blake3_256<BaseElement>,
+        DefaultRandomCoin<Blake3_256<BaseElement>>,
+        MerkleTree<Blake3_256<BaseElement>>,
+    >(
+        proof.clone(),
+        CounterInputs(inputs.clone()),
+        &AcceptableOptions::MinConjecturedSecurity(0),
+        &positions,
+    )
+    .unwrap();
+
+    // the proof should not verify against other positions
+    assert!(verify_with_query_positions::<
+        CounterAir,
+        Blake3_256<BaseElement>,
+        DefaultRandomCoin<Blake3_256<BaseElement>>,
+        MerkleTree<Blake3_256<BaseElement>>,
+    >(
+        proof.clone(),
+        CounterInputs(inputs.clone()),
+        &AcceptableOptions::MinConjecturedSecurity(0),
+        &[1, 2, 3],
+    )
+    .is_err());
+
+    // positions outside of the LDE domain should be rejected
+    let err = verify_with_query_positions::<
+        CounterAir,
+        Blake3_256<BaseElement>,
+        DefaultRandomCoin<Blake3_256<BaseElement>>,
+        MerkleTree<Blake3_256<BaseElement>>,
+    >(
+        proof,
+        CounterInputs(inputs),
+        &AcceptableOptions::MinConjecturedSecurity(0),
+        &[0, 32],
+    )
+    .unwrap_err();
+    assert_eq!(VerifierError::InvalidQueryPositions, err);
+}
+
+#[test]
+fn test_check_transition_degrees() {
+    let trace = CounterProver::build_trace(16);
+    let options = ProofOptions::new(1, 8, 0, FieldExtension::None, 2, 1);
+
+    // degrees declared by the counter AIR are correct
+    let air = CounterAir::new(trace.info().clone(), CounterInputs(Vec::new()), options.clone());
+    assert_eq!(vec![15], testing::infer_transition_degrees(&air, trace.main_segment()));
+    assert!(testing::check_transition_degrees(&air, trace.main_segment()).is_ok());
+
+    // the second constraint of this AIR is quadratic, but is declared as linear
+    let mut trace = TraceTable::new(2, 16);
+    trace.fill(
+        |state| {
+            state[0] = BaseElement::ZERO;
+            state[1] = BaseElement::new(3);
+        },
+        |_, state| {
+            state[0] += BaseElement::ONE;
+            state[1] = state[1].square();
+        },
+    );
+    let air = WrongDegreeAir::new(trace.info().clone(), (), options);
+    assert_eq!(
+        Err(vec![testing::DegreeMismatch {
+            constraint_idx: 1,
+            declared: 15,
+            actual: 30
+        }]),
+        testing::check_transition_degrees(&air, trace.main_segment())
+    );
+}
+
+#[test]
+fn test_check_transition_degrees_virtual_columns() {
+    // the second constraint of this AIR is defined over a virtual column
+    let trace = VirtualFibProver::build_trace(16);
+    let options = ProofOptions::new(1, 8, 0, FieldExtension::None, 2, 1);
+    let air = VirtualFibAir::new(trace.info().clone(), trace.get(1, 15), options);
+    assert_eq!(vec![15, 15], testing::infer_transition_degrees(&air, trace.main_segment()));
+    assert!(testing::check_transition_degrees(&air, trace.main_segment()).is_ok());
+}
+
+#[test]
+fn test_transition_residuals() {
+    let options = ProofOptions::new(1, 8, 0, FieldExtension::None, 2, 1);
+    let mut trace = CounterProver::build_trace(16);
+    let air = CounterAir::new(trace.info().clone(), CounterInputs(Vec::new()), options);
+
+    // a valid trace has no residuals
+    let residuals: TransitionResiduals<BaseElement> = trace.transition_residuals(&air, None);
+    assert!(residuals.is_zero());
+    assert_eq!(
+        vec![ConstraintResidual {
+            name: Some("increment"),
+            ..Default::default()
+        }],
+        residuals.main
+    );
+
+    // modifying a single cell breaks the constraint at the transitions into and out of it
+    trace.set(0, 5, BaseElement::new(100));
+    let residuals: TransitionResiduals<BaseElement> = trace.transition_residuals(&air, None);
+    assert!(!residuals.is_zero());
+    assert_eq!(
+        vec![ConstraintResidual {
+            name: Some("increment"),
+            num_nonzero_steps: 2,
+            first_nonzero: Some((4, BaseElement::new(95))),
+        }],
+        residuals.main
+    );
+    assert!(residuals.aux.is_empty());
+
+    // constraint names are included in diagnostic messages
+    assert_eq!(
+        "main transition constraint 0 (increment) did not evaluate to ZERO at 2 steps; first \
+        residual was 95 at step 4\n",
+        residuals.to_string()
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "main transition constraint 0 (increment) did not evaluate to ZERO at step 4"
+)]
+fn test_validate_constraint_names() {
+    let options = ProofOptions::new(1, 8, 0, FieldExtension::None, 2, 1);
+    let mut trace = CounterProver::build_trace(16);
+    let air = CounterAir::new(trace.info().clone(), CounterInputs(Vec::new()), options);
+
+    trace.set(0, 5, BaseElement::new(100));
+    trace.validate::<_, BaseElement>(&air, None);
+}
+
+#[test]
+fn test_constant_columns() {
+    let inputs = (0..8_u32).map(BaseElement::from).collect::<Vec<_>>();
+    let prover = CounterProver::new(inputs.clone());
+
+    // build a trace in which the last two columns are constant
+    let build_trace = || {
+        let mut trace = TraceTable::new(3, 16);
+        trace.fill(
+            |state| {
+                state[0] = BaseElement::ZERO;
+                state[1] = BaseElement::ZERO;
+                state[2] = BaseElement::new(42);
+            },
+            |_, state| state[0] += BaseElement::ONE,
+        );
+        trace
+    };
+
+    let proof = prover.prove(build_trace()).unwrap();
+    let sparse_proof = prover.prove(build_trace().with_constant_columns(&[1, 2])).unwrap();
+    assert_eq!(2, sparse_proof.trace_info().num_constant_columns());
+
+    // queried values of the constant columns are not included in the proof
+    let trace_queries_size = |proof: &Proof| proof.trace_queries[0].to_bytes().len();
+    assert!(trace_queries_size(&sparse_proof) < trace_queries_size(&proof));
+
+    let verify_proof = |proof: Proof| {
+        verify::<
+            CounterAir,
+            Blake3_256<BaseElement>,
+            DefaultRandomCoin<Blake3_256<BaseElement>>,
+            MerkleTree<Blake3_256<BaseElement>>,
+        >(
+            proof,
+            CounterInputs(inputs.clone()),
+            &AcceptableOptions::MinConjecturedSecurity(0),
+        )
+    };
+    verify_proof(proof).unwrap();
+    verify_proof(sparse_proof.clone()).unwrap();
+
+    // changing the value of a constant column should make the proof invalid
+    let mut parts = sparse_proof.into_parts();
+    let trace_info = TraceInfo::new(3, 16)
+        .with_constant_columns(&[(1, BaseElement::ZERO), (2, BaseElement::new(43))]);
+    parts.context =
+        air::proof::Context::new::<BaseElement>(trace_info, parts.context.options().clone());
+    let proof = Proof::from_parts(parts);
+    assert!(verify_proof(proof).is_err());
+}
+
+#[test]
+fn test_virtual_columns() {
+    let trace = VirtualFibProver::build_trace(16);
+    let result = trace.get(1, 15);
+    let prover = VirtualFibProver::new();
+    let proof = prover.prove(trace.clone()).unwrap();
+
+    verify::<
+        VirtualFibAir,
+        Blake3_256<BaseElement>,
+        DefaultRandomCoin<Blake3_256<BaseElement>>,
+        MerkleTree<Blake3_256<BaseElement>>,
+    >(proof.clone(), result, &AcceptableOptions::MinConjecturedSecurity(0))
+    .unwrap();
+
+    // the virtual column does not increase the width of the committed trace
+    assert_eq!(2, proof.trace_info().main_trace_width());
+
+    // the proof does not verify against a wrong result
+    assert!(verify::<
+        VirtualFibAir,
+        Blake3_256<BaseElement>,
+        DefaultRandomCoin<Blake3_256<BaseElement>>,
+        MerkleTree<Blake3_256<BaseElement>>,
+    >(proof, result + BaseElement::ONE, &AcceptableOptions::MinConjecturedSecurity(0))
+    .is_err());
+
+    // virtual columns are also evaluated when computing transition residuals
+    let air = VirtualFibAir::new(trace.info().clone(), result, prover.options.clone());
+    let residuals: TransitionResiduals<BaseElement> = trace.transition_residuals(&air, None);
+    assert!(residuals.is_zero());
+}
+
+#[test]
+fn test_wide_trace() {
+    // traces with more than 255 columns use a wider encoding of the trace info
+    let trace = WideProver::build_trace(WIDE_TRACE_WIDTH, 16);
+    let prover = WideProver::new();
+    let proof = prover.prove(trace).unwrap();
+    assert_eq!(WIDE_TRACE_WIDTH, proof.trace_info().main_trace_width());
+
+    let proof = Proof::from_bytes(&proof.to_bytes()).unwrap();
+    verify::<
+        WideAir,
+        Blake3_256<BaseElement>,
+        DefaultRandomCoin<Blake3_256<BaseElement>>,
+        MerkleTree<Blake3_256<BaseElement>>,
+    >(proof, (), &AcceptableOptions::MinConjecturedSecurity(0))
+    .unwrap();
+}
+
+#[test]
+fn test_streaming_verifier() {
+    // proofs serialized using the streaming format can be verified while they are being read,
+    // including proofs with an auxiliary trace segment over an extension field
+    let trace = LagrangeComplexTrace::new(2_usize.pow(6), 1);
+    let verifier = StreamingVerifier::<
+        ExtensionWitnessAir,
+        Blake3_256<BaseElement>,
+        DefaultRandomCoin<Blake3_256<BaseElement>>,
+        MerkleTree<Blake3_256<BaseElement>>,
+    >::new(AcceptableOptions::MinConjecturedSecurity(0));
+    for field_extension in [
+        FieldExtension::None,
+        FieldExtension::Quadratic,
+        FieldExtension::Cubic,
+        FieldExtension::Sextic,
+    ] {
+        let prover = ExtensionWitnessProver::new(field_extension);
+        let proof = prover.prove(trace.clone()).unwrap();
+        let bytes = proof.to_streaming_bytes();
+        verifier.verify(&mut SliceReader::new(&bytes), ()).unwrap();
+    }
+
+    // and proofs which contain a GKR proof
+    let trace = LagrangeComplexTrace::new(2_usize.pow(10), AUX_TRACE_WIDTH);
+    let proof = LagrangeComplexProver::new(AUX_TRACE_WIDTH).prove(trace).unwrap();
+    let bytes = proof.to_streaming_bytes();
+    StreamingVerifier::<
+        LagrangeKernelComplexAir,
+        Blake3_256<BaseElement>,
+        DefaultRandomCoin<Blake3_256<BaseElement>>,
+        MerkleTree<Blake3_256<BaseElement>>,
+    >::new(AcceptableOptions::MinConjecturedSecurity(0))
+    .verify(&mut SliceReader::new(&bytes), ())
+    .unwrap();
+}
+
+#[cfg(feature = "accounting")]
+#[test]
+fn test_verify_with_accounting() {
+    let inputs = (0..4_u32).map(BaseElement::from).collect::<Vec<_>>();
+    let prover = CounterProver::new(inputs.clone());
+    let proof = prover.prove(CounterProver::build_trace(8)).unwrap();
+    let num_queries = proof.num_unique_queries as u64;
+
+    let verify_proof = |proof: Proof| {
+        verify_with_accounting::<
+            CounterAir,
+            Blake3_256<BaseElement>,
+            DefaultRandomCoin<Blake3_256<BaseElement>>,
+            MerkleTree<Blake3_256<BaseElement>>,
+        >(
+            proof,
+            CounterInputs(inputs.clone()),
+            &AcceptableOptions::MinConjecturedSecurity(0),
+        )
+    };
+
+    let cost = verify_proof(proof.clone()).unwrap();
+    assert!(cost.num_hashes > 0);
+    assert!(cost.num_field_muls > 0);
+
+    // at least one path is verified per query for the trace and the constraint commitments
+    assert!(cost.num_merkle_paths >= 2 * num_queries);
+
+    // the cost depends only on the proof
+    assert_eq!(cost, verify_proof(proof).unwrap());
+}
+
+#[test]
+fn test_streaming_verifier_invalid_proofs() {
+    let inputs = (0..4_u32).map(BaseElement::from).collect::<Vec<_>>();
+    let prover = CounterProver::new(inputs.clone());
+    let proof = prover.prove(CounterProver::build_trace(8)).unwrap();
+    let bytes = proof.to_streaming_bytes();
+
+    let verifier = StreamingVerifier::<
+        CounterAir,
+        Blake3_256<BaseElement>,
+        DefaultRandomCoin<Blake3_256<BaseElement>>,
+        MerkleTree<Blake3_256<BaseElement>>,
+    >::new(AcceptableOptions::MinConjecturedSecurity(0));
+    verifier
+        .verify(&mut SliceReader::new(&bytes), CounterInputs(inputs.clone()))
+        .unwrap();
+
+    // a proof does not verify against different public inputs
+    let result = verifier.verify(&mut SliceReader::new(&bytes), CounterInputs(Vec::new()));
+    assert!(result.is_err());
+
+    // a proof which ends at any point before its last byte is rejected
+    for len in 0..bytes.len() {
+        let mut source = SliceReader::new(&bytes[..len]);
+        assert!(verifier.verify(&mut source, CounterInputs(inputs.clone())).is_err());
+    }
+
+    // a proof serialized using the regular format is rejected
+    let bytes = proof.to_bytes();
+    let result = verifier.verify(&mut SliceReader::new(&bytes), CounterInputs(inputs));
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_trivial_air() {
+    let options = ProofOptions::new(4, 4, 0, FieldExtension::None, 2, 1);
+    let start = BaseElement::new(42);
+    let trace = testing::TrivialProver::<Blake3_256<BaseElement>>::build_trace(start, 16);
+    let acceptable = AcceptableOptions::OptionSet(vec![options.clone()]);
+
+    // proofs survive serialization round trip and can be generated with different hash functions
+    let proof = testing::TrivialProver::<Blake3_256<BaseElement>>::new(options.clone())
+        .prove(trace.clone())
+        .unwrap();
+    let proof = Proof::from_bytes(&proof.to_bytes()).unwrap();
+    testing::verify_trivial_proof::<Blake3_256<BaseElement>>(proof.clone(), start, &acceptable)
+        .unwrap();
+
+    let rp_proof = testing::TrivialProver::<Rp64_256>::new(options).prove(trace).unwrap();
+    testing::verify_trivial_proof::<Rp64_256>(rp_proof, start, &acceptable).unwrap();
+
+    // a proof does not verify against a different starting value or with a different hash function
+    let result = testing::verify_trivial_proof::<Blake3_256<BaseElement>>(
+        proof.clone(),
+        start + BaseElement::ONE,
+        &acceptable,
+    );
+    assert!(result.is_err());
+    assert!(testing::verify_trivial_proof::<Rp64_256>(proof, start, &acceptable).is_err());
+}
+
+#[test]
+fn test_verify_with_context() {
+    type H = Blake3_256<BaseElement>;
+
+    let options = ProofOptions::new(4, 4, 0, FieldExtension::None, 2, 1);
+    let acceptable = AcceptableOptions::OptionSet(vec![options.clone()]);
+    let prover = testing::TrivialProver::<H>::new(options.clone());
+
+    // a single context is reused for proofs with different public inputs
+    let proofs = [BaseElement::new(3), BaseElement::new(42)].map(|start| {
+        (
+            start,
+            prover.prove(testing::TrivialProver::<H>::build_trace(start, 16)).unwrap(),
+        )
+    });
+    let air = testing::TrivialAir::new(proofs[0].1.trace_info().clone(), proofs[0].0, options);
+    let context = air.context().clone();
+
+    for (start, proof) in proofs.iter().cloned() {
+        verify_with_context::<testing::TrivialAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+            proof.clone(),
+            start,
+            &context,
+            &acceptable,
+        )
+        .unwrap();
+
+        let result = verify_with_context::<
+            testing::TrivialAir,
+            H,
+            DefaultRandomCoin<H>,
+            MerkleTree<H>,
+        >(proof, start + BaseElement::ONE, &context, &acceptable);
+        assert!(result.is_err());
+    }
+
+    // a context built for different proof options is rejected
+    let other_options = ProofOptions::new(5, 4, 0, FieldExtension::None, 2, 1);
+    let other_context =
+        testing::TrivialAir::new(proofs[0].1.trace_info().clone(), proofs[0].0, other_options)
+            .context()
+            .clone();
+    let (start, proof) = proofs[0].clone();
+    let result = verify_with_context::<testing::TrivialAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+        proof,
+        start,
+        &other_context,
+        &acceptable,
+    );
+    assert_eq!(result, Err(VerifierError::MismatchedAirContext));
+}
+
+#[test]
+fn test_fn_hasher() {
+    struct Blake3Functions;
+
+    impl HashFunctions for Blake3Functions {
+        type BaseField = BaseElement;
+        const COLLISION_RESISTANCE: u32 = 128;
+        const HASH: fn(&[u8]) -> [u8; 32] =
+            |bytes| Blake3_256::<BaseElement>::hash(bytes).as_bytes();
+        const MERGE: fn(&[u8; 32], &[u8; 32]) -> [u8; 32] =
+            |a, b| Blake3_256::<BaseElement>::hash(&[*a, *b].concat()).as_bytes();
+        const HASH_ELEMENTS: fn(&[BaseElement]) -> [u8; 32] =
+            |elements| Blake3_256::hash_elements(elements).as_bytes();
+    }
+
+    type H = FnHasher<Blake3Functions>;
+
+    let options = ProofOptions::new(4, 4, 0, FieldExtension::Quadratic, 2, 1);
+    let start = BaseElement::new(7);
+    let trace = testing::TrivialProver::<H>::build_trace(start, 16);
+    let proof = testing::TrivialProver::<H>::new(options.clone()).prove(trace).unwrap();
+
+    let acceptable = AcceptableOptions::OptionSet(vec![options]);
+    testing::verify_trivial_proof::<H>(proof.clone(), start, &acceptable).unwrap();
+    assert!(
+        testing::verify_trivial_proof::<H>(proof, start + BaseElement::ONE, &acceptable).is_err()
+    );
+}
+
+#[test]
+fn test_prove_with_key() {
+    let inputs = (0..8_u32).map(BaseElement::from).collect::<Vec<_>>();
+    let prover = CounterProver::new(inputs.clone());
+    let trace = CounterProver::build_trace(16);
+
+    // the prover relies on the default evaluator, to which the key is attached by the prover;
+    // proofs generated with and without the key are identical
+    let air = CounterAir::new(
+        trace.info().clone(),
+        CounterInputs(inputs.clone()),
+        prover.options.clone(),
+    );
+    let key = ProvingKey::new(&air);
+    let expected = prover.prove(trace.clone()).unwrap();
+    assert_eq!(expected, prover.prove_with_key(trace.clone(), &key).unwrap());
+
+    // the key can be reused across proofs against different public inputs
+    let other_prover = CounterProver::new(inputs[..4].to_vec());
+    assert_eq!(
+        other_prover.prove(trace.clone()).unwrap(),
+        other_prover.prove_with_key(trace, &key).unwrap()
+    );
+
+    // the key cannot be used for traces of a different length
+    assert_eq!(
+        Err(ProverError::MismatchedProvingKey),
+        prover.prove_with_key(CounterProver::build_trace(32), &key)
+    );
+}
+
+#[test]
+fn test_verify_with_key() {
+    type H = Blake3_256<BaseElement>;
+
+    let inputs = (0..8_u32).map(BaseElement::from).collect::<Vec<_>>();
+    let mut prover = CounterProver::new(inputs.clone());
+    let trace = CounterProver::build_trace(16);
+    let key = VerificationKey::<BaseElement>::new(trace.info().clone(), prover.options.clone());
+
+    // the key survives serialization round trip
+    let key = VerificationKey::<BaseElement>::read_from_bytes(&key.to_bytes()).unwrap();
+
+    // the same key can be reused with proofs against different public inputs
+    let proof = prover.prove(trace.clone()).unwrap();
+    verify_with_key::<CounterAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+        proof,
+        CounterInputs(inputs.clone()),
+        &key,
+    )
+    .unwrap();
+
+    let mut other_prover = CounterProver::new(inputs[..4].to_vec());
+    let proof = other_prover.prove(trace.clone()).unwrap();
+    verify_with_key::<CounterAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+        proof.clone(),
+        CounterInputs(inputs[..4].to_vec()),
+        &key,
+    )
+    .unwrap();
+    assert!(verify_with_key::<CounterAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+        proof,
+        CounterInputs(inputs.clone()),
+        &key,
+    )
+    .is_err());
+
+    // proofs with a context different from the one pinned by the key are rejected
+    other_prover.options = ProofOptions::new(2, 4, 0, FieldExtension::None, 2, 1);
+    let proof = other_prover.prove(trace.clone()).unwrap();
+    assert_eq!(
+        Err(VerifierError::MismatchedVerificationKey),
+        verify_with_key::<CounterAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+            proof,
+            CounterInputs(inputs[..4].to_vec()),
+            &key,
+        )
+    );
+
+    // a key can cache the AIR context of the statement
+    let air =
+        CounterAir::new(trace.info().clone(), CounterInputs(inputs.clone()), key.options().clone());
+    let cached_key = key.clone().with_air_context(air.context().clone());
+    assert!(cached_key.air_context().is_some());
+    let proof = prover.prove(trace.clone()).unwrap();
+    verify_with_key::<CounterAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+        proof,
+        CounterInputs(inputs.clone()),
+        &cached_key,
+    )
+    .unwrap();
+
+    // proofs generated using a custom proof-of-work are verified against a key pinning its id
+    let mut pow_prover = CounterProver::new(inputs.clone());
+    pow_prover.xor_pow = true;
+    let proof = pow_prover.prove(trace.clone()).unwrap();
+    let pow_key = VerificationKey::<BaseElement>::from_context(
+        air::proof::Context::new::<BaseElement>(trace.info().clone(), prover.options.clone())
+            .with_pow_id(1),
+    );
+    assert_eq!(
+        Err(VerifierError::UnsupportedProofOfWork(Some(1))),
+        verify_with_key::<CounterAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+            proof.clone(),
+            CounterInputs(inputs.clone()),
+            &pow_key,
+        )
+    );
+    assert_eq!(
+        Err(VerifierError::UnsupportedProofOfWork(Some(1))),
+        verify_with_key_and_proof_of_work::<CounterAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+            proof.clone(),
+            CounterInputs(inputs.clone()),
+            &pow_key,
+            &crypto::DefaultProofOfWork,
+        )
+    );
+    verify_with_key_and_proof_of_work::<CounterAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+        proof,
+        CounterInputs(inputs.clone()),
+        &pow_key,
+        &XorProofOfWork,
+    )
+    .unwrap();
+
+    prover.hash_id = Some(1);
+    let proof = prover.prove(trace).unwrap();
+    assert_eq!(
+        Err(VerifierError::MismatchedVerificationKey),
+        verify_with_key::<CounterAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+            proof,
+            CounterInputs(inputs),
+            &key,
+        )
+    );
+}
+
+#[test]
+fn test_proof_to_elements() {
+    type H = Rp64_256;
+
+    // the proof contains a Lagrange kernel column and values in a quadratic extension field
+    let trace = LagrangeComplexTrace::new(2_usize.pow(6), AUX_TRACE_WIDTH);
+    let prover = LagrangeComplexProver::new(AUX_TRACE_WIDTH).with_hasher::<H>();
+    let proof = prover.prove(trace).unwrap();
+
+    // the decoded proof is identical to the original one and can be verified
+    let elements = proof.to_elements::<H>();
+    let decoded = Proof::from_elements::<H>(&elements).unwrap();
+    assert_eq!(proof, decoded);
+    verify::<LagrangeKernelComplexAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+        decoded,
+        (),
+        &AcceptableOptions::MinConjecturedSecurity(0),
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_verify_with_assertions() {
+    type H = Blake3_256<BaseElement>;
+    type Verify = fn(
+        Proof,
+        CounterInputs,
+        Vec<Assertion<BaseElement>>,
+        &AcceptableOptions,
+    ) -> Result<(), VerifierError>;
+    let verify_with_assertions: Verify =
+        verify_with_assertions::<CounterAir, H, DefaultRandomCoin<H>, MerkleTree<H>>;
+
+    let inputs = (0..4_u32).map(BaseElement::from).collect::<Vec<_>>();
+    let assertions = vec![Assertion::single(0, 15, BaseElement::new(15))];
+    let prover = CounterAssertionsProver::new(inputs.clone(), assertions.clone());
+    let proof = prover.prove(CounterProver::build_trace(16)).unwrap();
+    let acceptable = AcceptableOptions::OptionSet(vec![prover.options.clone()]);
+
+    verify_with_assertions(proof.clone(), CounterInputs(inputs.clone()), assertions, &acceptable)
+        .unwrap();
+
+    // the proof does not verify against different assertions, even if the trace satisfies them
+    let other_assertions = vec![Assertion::single(0, 14, BaseElement::new(14))];
+    let result = verify_with_assertions(
+        proof.clone(),
+        CounterInputs(inputs.clone()),
+        other_assertions,
+        &acceptable,
+    );
+    assert!(result.is_err());
+
+    // nor does it verify without the additional assertions
+    let result =
+        verify_with_assertions(proof.clone(), CounterInputs(inputs.clone()), vec![], &acceptable);
+    assert!(result.is_err());
+    let result = verify::<CounterAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+        proof.clone(),
+        CounterInputs(inputs.clone()),
+        &acceptable,
+    );
+    assert!(result.is_err());
+
+    // assertions which are invalid for the trace are rejected
+    let invalid_assertions = vec![Assertion::single(1, 15, BaseElement::new(15))];
+    let result = verify_with_assertions(
+        proof.clone(),
+        CounterInputs(inputs.clone()),
+        invalid_assertions,
+        &acceptable,
+    );
+    assert!(matches!(result, Err(VerifierError::InvalidAssertion(_))));
+    let overlapping_assertions = vec![Assertion::single(0, 0, BaseElement::ZERO)];
+    let result =
+        verify_with_assertions(proof, CounterInputs(inputs), overlapping_assertions, &acceptable);
+    assert!(matches!(result, Err(VerifierError::InvalidAssertion(_))));
+}
+
+#[test]
+fn test_inconsistent_ood_constraint_evaluation() {
+    type H = Blake3_256<BaseElement>;
+    let inputs = (0..4_u32).map(BaseElement::from).collect::<Vec<_>>();
+    let assertions = vec![Assertion::single(0, 15, BaseElement::new(15))];
+    let prover = CounterAssertionsProver::new(inputs.clone(), assertions);
+    let proof = prover.prove(CounterProver::build_trace(16)).unwrap();
+
+    // the verifier does not include the additional assertion into the constraint composition
+    // polynomial, and thus, disagrees with the prover about its value at the OOD point
+    let err = verify::<CounterAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+        proof,
+        CounterInputs(inputs),
+        &AcceptableOptions::MinConjecturedSecurity(0),
+    )
+    .unwrap_err();
+    match &err {
+        VerifierError::InconsistentOodConstraintEvaluation { expected, actual } => {
+            assert_ne!(expected, actual);
+            assert!(err.to_string().contains(expected.as_str()));
+            assert!(err.to_string().contains(actual.as_str()));
+        },
+        _ => panic!("unexpected error: {err}"),
+    }
+    assert_eq!(6, err.code());
+}
+
+#[test]
+fn test_unsatisfied_assertion() {
+    let inputs = (0..4_u32).map(BaseElement::from).collect::<Vec<_>>();
+    let assertions = vec![Assertion::single(0, 15, BaseElement::new(14))];
+    let prover = CounterAssertionsProver::new(inputs, assertions);
+    let result = prover.prove(CounterProver::build_trace(16));
+    assert_eq!(Err(ProverError::UnsatisfiedAssertion { column: 0, step: 15 }), result);
+}
+
+#[test]
+fn test_validate_pub_inputs() {
+    let inputs = (0..4_u32).map(BaseElement::from).collect::<Vec<_>>();
+    let trace = CounterProver::build_trace(16);
+
+    let assertions = vec![Assertion::single(0, 15, BaseElement::new(15))];
+    let prover = CounterAssertionsProver::new(inputs.clone(), assertions);
+    prover.validate_pub_inputs(&trace).unwrap();
+
+    // public inputs which assert against the wrong cell are reported along with both values
+    let assertions = vec![Assertion::single(0, 15, BaseElement::new(14))];
+    let prover = CounterAssertionsProver::new(inputs, assertions);
+    assert_eq!(
+        Err(ProverError::MismatchedPublicInputs {
+            column: 0,
+            step: 15,
+            expected: "14".to_string(),
+            actual: "15".to_string(),
+        }),
+        prover.validate_pub_inputs(&trace)
+    );
+}
+
+#[test]
+fn test_deep_composition_coefficients() {
+    type H = Blake3_256<BaseElement>;
+
+    let inputs = (0..4_u32).map(BaseElement::from).collect::<Vec<_>>();
+    let mut prover = CounterProver::new(inputs.clone());
+    let (proof, transcript) = prover
+        .prove_with_transcript::<BaseElement>(CounterProver::build_trace(16))
+        .unwrap();
+    let drawn = transcript.deep_composition_coeffs;
+
+    // injecting the coefficients drawn from the public coin does not change the proof
+    prover.deep_coefficients = Some(drawn.clone());
+    assert_eq!(proof, prover.prove(CounterProver::build_trace(16)).unwrap());
+
+    // injecting different coefficients results in a proof which does not verify
+    let mut other = drawn.clone();
+    other.trace[0] += BaseElement::ONE;
+    prover.deep_coefficients = Some(other);
+    let other_proof = prover.prove(CounterProver::build_trace(16)).unwrap();
+    assert_ne!(proof, other_proof);
+    let acceptable = AcceptableOptions::OptionSet(vec![prover.options.clone()]);
+    let result = verify::<CounterAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+        other_proof,
+        CounterInputs(inputs),
+        &acceptable,
+    );
+    assert!(result.is_err());
+
+    // coefficients of a wrong shape are rejected
+    let mut malformed = drawn;
+    malformed.constraints.push(BaseElement::ONE);
+    prover.deep_coefficients = Some(malformed);
+    let result = prover.prove(CounterProver::build_trace(16));
+    assert_eq!(Err(ProverError::MismatchedDeepCompositionCoefficients), result);
+}
+
+#[test]
+fn test_hash_registry() {
+    type Blake3 = Blake3_256<BaseElement>;
+
+    let inputs = (0..8_u32).map(BaseElement::from).collect::<Vec<_>>();
+    let mut prover = CounterProver::new(inputs.clone());
+    let acceptable = AcceptableOptions::MinConjecturedSecurity(0);
+
+    let registry = HashRegistry::<CounterAir>::new()
+        .with_default_hash::<Blake3, DefaultRandomCoin<Blake3>, MerkleTree<Blake3>>()
+        .with_hash::<Blake3, DefaultRandomCoin<Blake3>, MerkleTree<Blake3>>(1)
+        .with_hash::<Rp64_256, DefaultRandomCoin<Rp64_256>, MerkleTree<Rp64_256>>(2);
+    assert!(registry.contains(None));
+    assert!(registry.contains(Some(1)));
+    assert!(!registry.contains(Some(3)));
+
+    // proofs which do not record a hash function identifier are verified with the default hash
+    let proof = prover.prove(CounterProver::build_trace(16)).unwrap();
+    assert_eq!(None, proof.context.hash_id());
+    registry
+        .verify(proof.clone(), CounterInputs(inputs.clone()), &acceptable)
+        .unwrap();
+    assert_eq!(
+        Err(VerifierError::UnsupportedHashFunction(None)),
+        HashRegistry::<CounterAir>::new().verify(proof, CounterInputs(inputs.clone()), &acceptable)
+    );
+
+    // proofs which record an identifier are verified with the hash registered under it
+    prover.hash_id = Some(1);
+    let proof = prover.prove(CounterProver::build_trace(16)).unwrap();
+    assert_eq!(Some(1), proof.context.hash_id());
+    registry
+        .verify(proof.clone(), CounterInputs(inputs.clone()), &acceptable)
+        .unwrap();
+
+    // the identifier is bound to the proof, and so, it cannot be changed after the fact
+    let mut tampered = proof.clone();
+    tampered.context = tampered.context.with_hash_id(2);
+    assert!(registry.verify(tampered, CounterInputs(inputs.clone()), &acceptable).is_err());
+
+    // proofs generated with a hash different from the one registered under the identifier fail
+    prover.hash_id = Some(2);
+    let proof = prover.prove(CounterProver::build_trace(16)).unwrap();
+    assert!(registry.verify(proof, CounterInputs(inputs.clone()), &acceptable).is_err());
+
+    prover.hash_id = Some(3);
+    let proof = prover.prove(CounterProver::build_trace(16)).unwrap();
+    assert_eq!(
+        Err(VerifierError::UnsupportedHashFunction(Some(3))),
+        registry.verify(proof, CounterInputs(inputs), &acceptable)
+    );
+}
+
+#[test]
+#[should_panic(expected = "hash function 1 already registered")]
+fn test_hash_registry_duplicate_id() {
+    type Blake3 = Blake3_256<BaseElement>;
+
+    let _ = HashRegistry::<CounterAir>::new()
+        .with_hash::<Blake3, DefaultRandomCoin<Blake3>, MerkleTree<Blake3>>(1)
+        .with_hash::<Rp64_256, DefaultRandomCoin<Rp64_256>, MerkleTree<Rp64_256>>(1);
+}
+
+#[test]
+fn test_verify_spot_check() {
+    type H = Blake3_256<BaseElement>;
+
+    let inputs = (0..4_u32).map(BaseElement::from).collect::<Vec<_>>();
+    let mut prover = CounterProver::new(inputs.clone());
+    let (proof, transcript) = prover
+        .prove_with_transcript::<BaseElement>(CounterProver::build_trace(16))
+        .unwrap();
+
+    // a valid proof passes spot checks against any number of queries
+    for num_queries in [0, 1, 2, prover.options.num_queries() + 1] {
+        verify_spot_check::<CounterAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+            proof.clone(),
+            CounterInputs(inputs.clone()),
+            num_queries,
+        )
+        .unwrap();
+    }
+
+    // a proof does not pass a spot check against different public inputs
+    let result = verify_spot_check::<CounterAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+        proof,
+        CounterInputs(inputs[..2].to_vec()),
+        1,
+    );
+    assert!(result.is_err());
+
+    // a proof built with invalid DEEP composition coefficients is detected by a single query
+    let mut coefficients = transcript.deep_composition_coeffs;
+    coefficients.trace[0] += BaseElement::ONE;
+    prover.deep_coefficients = Some(coefficients);
+    let invalid_proof = prover.prove(CounterProver::build_trace(16)).unwrap();
+    let result = verify_spot_check::<CounterAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+        invalid_proof,
+        CounterInputs(inputs),
+        1,
+    );
+    assert!(matches!(result, Err(VerifierError::FriVerificationFailed(_))));
+}
+
+#[test]
+fn test_assert_proof_stable() {
+    let inputs = (0..8_u32).map(BaseElement::from).collect::<Vec<_>>();
+    let prover = CounterProver::new(inputs);
+
+    testing::assert_proof_stable(
+        &prover,
+        CounterProver::build_trace(16),
+        "ef8736c2bf8680d2943ccf3bf0c66d8303042bf15404d214a09a18c8c6a0b38b",
+    );
+}
+
+#[test]
+#[should_panic(expected = "proof hash does not match the pinned value")]
+fn test_assert_proof_stable_mismatch() {
+    let inputs = (0..8_u32).map(BaseElement::from).collect::<Vec<_>>();
+    let prover = CounterProver::new(inputs);
+
+    testing::assert_proof_stable(&prover, CounterProver::build_trace(16), &"0".repeat(64));
+}
+
+#[test]
+fn test_verify_with_proof_of_work() {
+    type H = Blake3_256<BaseElement>;
+
+    let inputs = (0..8_u32).map(BaseElement::from).collect::<Vec<_>>();
+    let mut prover = CounterProver::new(inputs.clone());
+    prover.options = ProofOptions::new(1, 2, 8, FieldExtension::None, 2, 1);
+    prover.xor_pow = true;
+    let acceptable = AcceptableOptions::MinConjecturedSecurity(0);
+
+    let proof = prover.prove(CounterProver::build_trace(16)).unwrap();
+    assert_eq!(Some(1), proof.context.pow_id());
+    let nonce = proof.pow_nonce;
+    verify_with_proof_of_work::<CounterAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+        proof.clone(),
+        CounterInputs(inputs.clone()),
+        &acceptable,
+        &XorProofOfWork,
+    )
+    .unwrap();
+
+    // the default verifier does not accept proofs generated with a custom proof-of-work
+    assert_eq!(
+        Err(VerifierError::UnsupportedProofOfWork(Some(1))),
+        verify::<CounterAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+            proof.clone(),
+            CounterInputs(inputs.clone()),
+            &acceptable,
+        )
+    );
+
+    // a nonce which does not satisfy the custom proof-of-work is rejected
+    let mut tampered = proof;
+    tampered.pow_nonce = nonce ^ 0xffff;
+    assert_eq!(
+        Err(VerifierError::QuerySeedProofOfWorkVerificationFailed),
+        verify_with_proof_of_work::<CounterAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+            tampered,
+            CounterInputs(inputs.clone()),
+            &acceptable,
+            &XorProofOfWork,
+        )
+    );
+
+    // proofs generated with the default proof-of-work are not accepted by the custom one
+    prover.xor_pow = false;
+    let proof = prover.prove(CounterProver::build_trace(16)).unwrap();
+    assert_eq!(
+        Err(VerifierError::UnsupportedProofOfWork(None)),
+        verify_with_proof_of_work::<CounterAir, H, DefaultRandomCoin<H>, MerkleTree<H>>(
+            proof,
+            CounterInputs(inputs),
+            &acceptable,
+            &XorProofOfWork,
+        )
+    );
+}